@@ -1,6 +1,8 @@
 use rcd_client_wasm::{client::RcdClient, token::Token};
-use rcd_http_common::url::client::{GET_DATABASES, REVOKE_TOKEN};
-use rcd_messages::client::{DatabaseSchema, GetDatabasesReply, GetDatabasesRequest, RevokeReply};
+use rcd_http_common::url::client::{GET_DATABASES, GET_VERSIONS, REVOKE_TOKEN};
+use rcd_messages::client::{
+    DatabaseSchema, GetDatabasesReply, GetDatabasesRequest, RevokeReply, VersionReply,
+};
 use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
@@ -31,6 +33,90 @@ pub fn Home() -> Html {
             <div class="tile is-parent container">
                 <Connect />
             </div>
+
+            <div class="tile is-parent container">
+                <Versions />
+            </div>
+        </div>
+    }
+}
+
+#[function_component]
+pub fn Versions() -> Html {
+    let versions = use_state_eq(move || VersionReply {
+        authentication_result: None,
+        rcdx: "".to_string(),
+        rcd_core: "".to_string(),
+        rcdproto: "".to_string(),
+        protocol_version: 0,
+        rcd_schema_version: 0,
+    });
+
+    let get_versions_onclick = {
+        let versions = versions.clone();
+        Callback::from(move |_| {
+            let versions = versions.clone();
+            let token = get_token();
+            let url = format!("{}{}", token.addr, GET_VERSIONS);
+
+            let request_json = token.auth_json();
+
+            let cb = Callback::from(move |response: Result<AttrValue, String>| {
+                if let Ok(ref x) = response {
+                    clear_status();
+                    log_to_console(x.to_string());
+                    let versions = versions.clone();
+                    let reply: VersionReply = serde_json::from_str(x).unwrap();
+
+                    let is_authenticated = reply
+                        .authentication_result
+                        .as_ref()
+                        .unwrap()
+                        .is_authenticated;
+                    update_token_login_status(is_authenticated);
+
+                    if is_authenticated {
+                        versions.set(reply);
+                    }
+                } else {
+                    let error_message = response.err().unwrap();
+                    set_status(error_message);
+                }
+            });
+
+            request::post(url, request_json, cb);
+        })
+    };
+
+    html! {
+        <div class="container">
+            <div class="box">
+                <h1 class="subtitle"> {"Instance Versions"} </h1>
+                <p>{"After connecting, shows the build and schema versions of the rcd instance."}</p>
+                <p>
+                    <button class="button is-primary" onclick={get_versions_onclick}>
+                        <span class="mdi mdi-eye">{" Get Versions"}</span>
+                    </button>
+                </p>
+                <div class="table-container">
+                    <table class="table is-narrow">
+                        <thead>
+                            <th>{"rcdx"}</th>
+                            <th>{"rcd-core"}</th>
+                            <th>{"rcdproto"}</th>
+                            <th>{"Protocol"}</th>
+                            <th>{"Schema"}</th>
+                        </thead>
+                        <tr>
+                            <td>{versions.rcdx.clone()}</td>
+                            <td>{versions.rcd_core.clone()}</td>
+                            <td>{versions.rcdproto.clone()}</td>
+                            <td>{versions.protocol_version}</td>
+                            <td>{versions.rcd_schema_version}</td>
+                        </tr>
+                    </table>
+                </div>
+            </div>
         </div>
     }
 }
@@ -0,0 +1,144 @@
+use rcdproto::rcdp::*;
+
+/// A request that carries an `AuthRequest`, so that it can be re-sent with new credentials
+pub(crate) trait AuthenticatedRequest {
+    fn set_authentication(&mut self, auth: AuthRequest);
+}
+
+/// A reply that reports if the request it answers was authenticated
+pub(crate) trait AuthenticatedReply {
+    fn is_authentication_rejected(&self) -> bool;
+}
+
+impl AuthenticatedRequest for AuthRequest {
+    fn set_authentication(&mut self, auth: AuthRequest) {
+        *self = auth;
+    }
+}
+
+/// replies that arrive in parts, such as exports over HTTP
+impl<T: AuthenticatedReply> AuthenticatedReply for Vec<T> {
+    fn is_authentication_rejected(&self) -> bool {
+        self.iter().any(|reply| reply.is_authentication_rejected())
+    }
+}
+
+macro_rules! authenticated_request {
+    ($($request:ty),* $(,)?) => {
+        $(
+            impl AuthenticatedRequest for $request {
+                fn set_authentication(&mut self, auth: AuthRequest) {
+                    self.authentication = Some(auth);
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! authenticated_reply {
+    ($($reply:ty),* $(,)?) => {
+        $(
+            impl AuthenticatedReply for $reply {
+                fn is_authentication_rejected(&self) -> bool {
+                    matches!(&self.authentication_result, Some(result) if !result.is_authenticated)
+                }
+            }
+        )*
+    };
+}
+
+authenticated_request!(
+    AcceptPendingActionRequest,
+    AcceptPendingContractRequest,
+    AddParticipantRequest,
+    ChangeDeletesFromHostBehaviorRequest,
+    ChangeDeletesToHostBehaviorRequest,
+    ChangeHostStatusRequest,
+    ChangeUpdatesFromHostBehaviorRequest,
+    ChangeUpdatesToHostBehaviorRequest,
+    CreateUserDatabaseRequest,
+    EnableCoooperativeFeaturesRequest,
+    ExecuteCooperativeWriteRequest,
+    ExecuteReadRequest,
+    ExecuteWriteRequest,
+    ExportDatabaseRequest,
+    GenerateContractRequest,
+    GenerateHostInfoRequest,
+    GetActiveContractRequest,
+    GetCooperativeHostsRequest,
+    GetDataHashRequest,
+    GetDataLogTableStatusRequest,
+    GetDatabasesRequest,
+    GetDeletesFromHostBehaviorRequest,
+    GetDeletesToHostBehaviorRequest,
+    GetLogicalStoragePolicyRequest,
+    GetLogsByLastNumberRequest,
+    GetParticipantsRequest,
+    GetPendingActionTtlRequest,
+    GetPendingActionsRequest,
+    GetPendingWritesAtHostRequest,
+    GetReadRowIdsRequest,
+    GetSettingsRequest,
+    GetUpdatesFromHostBehaviorRequest,
+    GetUpdatesToHostBehaviorRequest,
+    HasTableRequest,
+    RehashPartialDatabaseRequest,
+    RejectPendingActionRequest,
+    RejectPendingContractRequest,
+    SendParticipantContractRequest,
+    SetDataLogTableStatusRequest,
+    SetLogicalStoragePolicyRequest,
+    SetPendingActionTtlRequest,
+    TryAuthAtParticipantRequest,
+    UnlockDatabaseRequest,
+    ViewPendingContractsRequest,
+);
+
+authenticated_reply!(
+    AcceptPendingActionReply,
+    AcceptPendingContractReply,
+    AddParticipantReply,
+    ChangeDeletesFromHostBehaviorReply,
+    ChangeDeletesToHostBehaviorReply,
+    ChangeHostStatusReply,
+    ChangeUpdatesToHostBehaviorReply,
+    ChangesUpdatesFromHostBehaviorReply,
+    CreateUserDatabaseReply,
+    EnableCoooperativeFeaturesReply,
+    ExecuteCooperativeWriteReply,
+    ExecuteReadReply,
+    ExecuteWriteReply,
+    ExportDatabaseReply,
+    GenerateContractReply,
+    GenerateHostInfoReply,
+    GetActiveContractReply,
+    GetCooperativeHostsReply,
+    GetDataHashReply,
+    GetDataLogTableStatusReply,
+    GetDatabasesReply,
+    GetDeletesFromHostBehaviorReply,
+    GetDeletesToHostBehaviorReply,
+    GetLogicalStoragePolicyReply,
+    GetLogsByLastNumberReply,
+    GetParticipantsReply,
+    GetPendingActionTtlReply,
+    GetPendingActionsReply,
+    GetPendingWritesAtHostReply,
+    GetReadRowIdsReply,
+    GetSettingsReply,
+    GetUpdatesFromHostBehaviorReply,
+    GetUpdatesToHostBehaviorReply,
+    HasTableReply,
+    HostInfoReply,
+    RehashPartialDatabaseReply,
+    RejectPendingActionReply,
+    RejectPendingContractReply,
+    SendParticipantContractReply,
+    SetDataLogTableStatusReply,
+    SetLogicalStoragePolicyReply,
+    SetPendingActionTtlReply,
+    TryAuthAtPartipantReply,
+    UnlockDatabaseReply,
+    VersionReply,
+    ViewPendingContractsReply,
+);
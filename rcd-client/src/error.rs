@@ -6,6 +6,10 @@ pub enum RcdClientError {
     GrpcError(String),
     #[error("Http Connection Failed: `{0}` ")]
     HttpError(String),
+    #[error("Profile Not Found: `{0}` ")]
+    ProfileNotFound(String),
+    #[error("Unable To Read Profiles: `{0}` ")]
+    ProfileError(String),
     #[error("Unknown Error")]
     Unknown,
 }
@@ -1,9 +1,13 @@
+use authenticated::{AuthenticatedReply, AuthenticatedRequest};
+use chrono::{DateTime, Utc};
 use client_type::RcdClientType;
 use error::RcdClientError;
+use profile::{RcdClientProfile, RcdClientProfiles};
 use rcd_enum::deletes_to_host_behavior::DeletesToHostBehavior;
 use rcd_enum::{
     database_type::DatabaseType, deletes_from_host_behavior::DeletesFromHostBehavior,
-    logical_storage_policy::LogicalStoragePolicy, remote_delete_behavior::RemoteDeleteBehavior,
+    export_format::ExportFormat, logical_storage_policy::LogicalStoragePolicy,
+    remote_delete_behavior::RemoteDeleteBehavior,
     updates_from_host_behavior::UpdatesFromHostBehavior,
     updates_to_host_behavior::UpdatesToHostBehavior,
 };
@@ -11,14 +15,17 @@ use rcd_http_common::url::client::{
     ACCEPT_PENDING_ACTION, ACCEPT_PENDING_CONTRACT, ADD_PARTICIPANT, AUTH_FOR_TOKEN,
     CHANGE_DELETES_FROM_HOST_BEHAVIOR, CHANGE_DELETES_TO_HOST_BEHAVIOR, CHANGE_HOST_STATUS_ID,
     CHANGE_HOST_STATUS_NAME, CHANGE_UPDATES_FROM_HOST_BEHAVIOR, CHANGE_UPDATES_TO_HOST_BEHAVIOR,
-    COOPERATIVE_WRITE_SQL_AT_HOST, ENABLE_COOPERATIVE_FEATURES, GENERATE_CONTRACT,
+    COOPERATIVE_WRITE_SQL_AT_HOST, ENABLE_COOPERATIVE_FEATURES, EXPORT_DATABASE, GENERATE_CONTRACT,
     GENERATE_HOST_INFO, GET_ACTIVE_CONTRACT, GET_COOP_HOSTS, GET_DATABASES, GET_DATA_HASH_AT_HOST,
-    GET_DATA_HASH_AT_PARTICIPANT, GET_DELETES_FROM_HOST_BEHAVIOR, GET_DELETES_TO_HOST_BEHAVIOR,
-    GET_HOST_INFO, GET_LAST_LOGS, GET_PARTICIPANTS, GET_PENDING_ACTIONS, GET_POLICY,
+    GET_DATA_HASH_AT_PARTICIPANT, GET_DATA_LOG_TABLE_STATUS, GET_DELETES_FROM_HOST_BEHAVIOR,
+    GET_DELETES_TO_HOST_BEHAVIOR, GET_HOST_INFO, GET_LAST_LOGS, GET_PARTICIPANTS,
+    GET_PENDING_ACTIONS, GET_PENDING_ACTION_TTL, GET_PENDING_WRITES_AT_HOST, GET_POLICY,
     GET_ROW_AT_PARTICIPANT, GET_SETTINGS, GET_UPDATES_FROM_HOST_BEHAVIOR,
-    GET_UPDATES_TO_HOST_BEHAVIOR, HAS_TABLE, IS_ONLINE, NEW_DATABASE, READ_SQL_AT_HOST,
-    READ_SQL_AT_PARTICIPANT, REVOKE_TOKEN, SEND_CONTRACT_TO_PARTICIPANT, SET_POLICY,
-    TRY_AUTH_PARTICIPANT, VIEW_PENDING_CONTRACTS, WRITE_SQL_AT_HOST, WRITE_SQL_AT_PARTICIPANT,
+    GET_UPDATES_TO_HOST_BEHAVIOR, GET_VERSIONS, HAS_TABLE, IS_ONLINE, NEW_DATABASE,
+    READ_SQL_AT_HOST, READ_SQL_AT_PARTICIPANT, REHASH_PARTIAL_DATABASE, REJECT_PENDING_ACTION,
+    REJECT_PENDING_CONTRACT, REVOKE_TOKEN, SEND_CONTRACT_TO_PARTICIPANT, SET_DATA_LOG_TABLE_STATUS,
+    SET_PENDING_ACTION_TTL, SET_POLICY, TRY_AUTH_PARTICIPANT, UNLOCK_DATABASE,
+    VIEW_PENDING_CONTRACTS, WRITE_SQL_AT_HOST, WRITE_SQL_AT_PARTICIPANT,
 };
 use rcdproto::rcdp::sql_client_client::SqlClientClient;
 use rcdproto::rcdp::{
@@ -31,32 +38,69 @@ use rcdproto::rcdp::{
     ChangesUpdatesFromHostBehaviorReply, Contract, CreateUserDatabaseReply,
     CreateUserDatabaseRequest, EnableCoooperativeFeaturesReply, EnableCoooperativeFeaturesRequest,
     ExecuteCooperativeWriteReply, ExecuteCooperativeWriteRequest, ExecuteReadReply,
-    ExecuteReadRequest, ExecuteWriteReply, ExecuteWriteRequest, GenerateContractReply,
-    GenerateContractRequest, GenerateHostInfoReply, GenerateHostInfoRequest,
-    GetActiveContractReply, GetActiveContractRequest, GetCooperativeHostsReply,
-    GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest, GetDatabasesReply,
+    ExecuteReadRequest, ExecuteWriteReply, ExecuteWriteRequest, ExportDatabaseReply,
+    ExportDatabaseRequest, GenerateContractReply, GenerateContractRequest, GenerateHostInfoReply,
+    GenerateHostInfoRequest, GetActiveContractReply, GetActiveContractRequest,
+    GetCooperativeHostsReply, GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest,
+    GetDataLogTableStatusReply, GetDataLogTableStatusRequest, GetDatabasesReply,
     GetDatabasesRequest, GetDeletesFromHostBehaviorReply, GetDeletesFromHostBehaviorRequest,
     GetDeletesToHostBehaviorReply, GetDeletesToHostBehaviorRequest, GetLogicalStoragePolicyReply,
     GetLogicalStoragePolicyRequest, GetLogsByLastNumberReply, GetLogsByLastNumberRequest,
-    GetParticipantsReply, GetParticipantsRequest, GetPendingActionsReply, GetPendingActionsRequest,
-    GetReadRowIdsReply, GetReadRowIdsRequest, GetSettingsReply, GetSettingsRequest,
-    GetUpdatesFromHostBehaviorReply, GetUpdatesFromHostBehaviorRequest,
-    GetUpdatesToHostBehaviorReply, GetUpdatesToHostBehaviorRequest, HasTableReply, HasTableRequest,
-    HostInfoReply, RevokeReply, SendParticipantContractReply, SendParticipantContractRequest,
-    SetLogicalStoragePolicyReply, SetLogicalStoragePolicyRequest, StatementResultset, TestReply,
-    TestRequest, TokenReply, TryAuthAtParticipantRequest, TryAuthAtPartipantReply,
+    GetParticipantsReply, GetParticipantsRequest, GetPendingActionTtlReply,
+    GetPendingActionTtlRequest, GetPendingActionsReply, GetPendingActionsRequest,
+    GetPendingWritesAtHostReply, GetPendingWritesAtHostRequest, GetReadRowIdsReply,
+    GetReadRowIdsRequest, GetSettingsReply, GetSettingsRequest, GetUpdatesFromHostBehaviorReply,
+    GetUpdatesFromHostBehaviorRequest, GetUpdatesToHostBehaviorReply,
+    GetUpdatesToHostBehaviorRequest, HasTableReply, HasTableRequest, HostInfoReply,
+    RehashPartialDatabaseReply, RehashPartialDatabaseRequest, RejectPendingActionReply,
+    RejectPendingActionRequest, RejectPendingContractReply, RejectPendingContractRequest,
+    RevokeReply, SendParticipantContractReply, SendParticipantContractRequest,
+    SetDataLogTableStatusReply, SetDataLogTableStatusRequest, SetLogicalStoragePolicyReply,
+    SetLogicalStoragePolicyRequest, SetPendingActionTtlReply, SetPendingActionTtlRequest,
+    StatementResultset, TestReply, TestRequest, TokenReply, TryAuthAtParticipantRequest,
+    TryAuthAtPartipantReply, UnlockDatabaseReply, UnlockDatabaseRequest, VersionReply,
     ViewPendingContractsReply, ViewPendingContractsRequest,
 };
 use reqwest::Client;
 use serde::de;
-use tracing::{trace, debug, info, instrument};
 use std::error::Error;
 use std::time::Duration;
 use stdext::function_name;
+use token_cache::{CachedToken, TokenCache};
 use tonic::transport::Channel;
+use tracing::{debug, instrument, trace};
 
+mod authenticated;
 pub mod client_type;
 pub mod error;
+pub mod profile;
+pub mod token_cache;
+
+/// how long before a cached token expires that we ask for a new one
+const TOKEN_REFRESH_MARGIN_IN_SECONDS: i64 = 30;
+
+/// Sends a request over gRPC. If it was sent with a token and the instance refused it, logs in
+/// again and re-sends the request once with the new token.
+macro_rules! grpc_call {
+    ($self:ident, $method:ident, $request:expr) => {{
+        let request = $request;
+        let is_using_jwt = $self.is_using_jwt();
+        let result = $self.get_client().$method(request.clone()).await;
+
+        let is_rejected =
+            matches!(&result, Ok(response) if response.get_ref().is_authentication_rejected());
+
+        if is_using_jwt && is_rejected {
+            debug!("[{}]: token was rejected, retrying", function_name!());
+            $self.refresh_token().await;
+            let mut request = request;
+            request.set_authentication($self.gen_auth_request());
+            $self.get_client().$method(request).await
+        } else {
+            result
+        }
+    }};
+}
 
 /// An abstraction over the protobuff definition in Rust. Effectively exposes all the calls to the
 /// `SQLClient` service and is used to talk to an rcd instance as a client
@@ -78,6 +122,10 @@ pub struct RcdClient {
     http_client: Option<Client>,
     send_jwt_if_available: bool,
     host_id: Option<String>,
+    /// when `jwt` stops being accepted, if known
+    jwt_expires_utc: Option<DateTime<Utc>>,
+    /// where tokens for the profile this client was created from are kept
+    token_cache: Option<TokenCache>,
 }
 
 impl RcdClient {
@@ -86,7 +134,6 @@ impl RcdClient {
         grpc_client_addr_port: String,
         timeout_in_seconds: u32,
     ) -> SqlClientClient<Channel> {
-
         let endpoint = tonic::transport::Channel::builder(grpc_client_addr_port.parse().unwrap())
             .timeout(Duration::from_secs(timeout_in_seconds.into()));
         let channel = endpoint.connect().await.unwrap();
@@ -122,6 +169,8 @@ impl RcdClient {
             jwt: String::from(""),
             send_jwt_if_available: false,
             host_id: None,
+            jwt_expires_utc: None,
+            token_cache: None,
         }
     }
 
@@ -150,6 +199,8 @@ impl RcdClient {
             jwt: String::from(""),
             send_jwt_if_available: false,
             host_id: None,
+            jwt_expires_utc: None,
+            token_cache: None,
         }
     }
 
@@ -178,7 +229,69 @@ impl RcdClient {
             jwt: String::from(""),
             send_jwt_if_available: false,
             host_id: None,
+            jwt_expires_utc: None,
+            token_cache: None,
+        }
+    }
+
+    /// Creates a client from the named profile in the default profiles file. See
+    /// [`RcdClientProfiles::default_path`]
+    pub async fn from_profile(profile_name: &str) -> Result<RcdClient, RcdClientError> {
+        let profiles = RcdClientProfiles::load_default()?;
+        Self::from_profiles(&profiles, profile_name).await
+    }
+
+    pub async fn from_profiles(
+        profiles: &RcdClientProfiles,
+        profile_name: &str,
+    ) -> Result<RcdClient, RcdClientError> {
+        let profile = profiles.get(profile_name)?;
+        let token_cache = TokenCache::new(profiles.token_cache_path(), profile_name);
+        Ok(Self::new_from_profile(profile, Some(token_cache)).await)
+    }
+
+    /// Creates a client from a profile. If the profile uses tokens and a `token_cache` is given,
+    /// an unexpired token in the cache is used instead of logging in again
+    pub async fn new_from_profile(
+        profile: &RcdClientProfile,
+        token_cache: Option<TokenCache>,
+    ) -> RcdClient {
+        let mut client = match profile.client_type {
+            RcdClientType::Grpc => {
+                Self::new_grpc_client(
+                    profile.grpc_addr_port.clone(),
+                    profile.user_name.clone(),
+                    profile.pw.clone(),
+                    profile.timeout_in_seconds,
+                )
+                .await
+            }
+            RcdClientType::Http => Self::new_http_client(
+                profile.user_name.clone(),
+                profile.pw.clone(),
+                profile.timeout_in_seconds,
+                profile.http_addr.clone(),
+                profile.http_port,
+            ),
+        };
+
+        client.host_id = profile.host_id.clone();
+
+        if profile.use_token {
+            client.send_jwt_if_available(true);
+
+            if let Some(cache) = token_cache {
+                if let Some(token) = cache.load() {
+                    if !token.expires_within(TOKEN_REFRESH_MARGIN_IN_SECONDS) {
+                        client.jwt = token.jwt;
+                        client.jwt_expires_utc = Some(token.expires_utc);
+                    }
+                }
+                client.token_cache = Some(cache);
+            }
         }
+
+        client
     }
 
     pub async fn is_online_reply(&mut self, message: String) -> TestReply {
@@ -247,7 +360,7 @@ impl RcdClient {
         &mut self,
         number_of_entries: u32,
     ) -> Result<GetLogsByLastNumberReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = GetLogsByLastNumberRequest {
             authentication: Some(auth),
             number_of_logs: number_of_entries,
@@ -255,12 +368,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_logs_by_last_number(request)
-                    .await
+                let response = grpc_call!(self, get_logs_by_last_number, request)
                     .unwrap()
                     .into_inner();
 
@@ -268,26 +376,21 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_LAST_LOGS);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
                 Ok(result)
             }
         }
     }
 
     pub async fn get_settings(&mut self) -> Result<GetSettingsReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = GetSettingsRequest {
             authentication: Some(auth),
         };
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_settings(request)
-                    .await
+                let response = grpc_call!(self, get_settings, request)
                     .unwrap()
                     .into_inner();
 
@@ -295,7 +398,7 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_SETTINGS);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
                 Ok(result)
             }
         }
@@ -304,24 +407,36 @@ impl RcdClient {
     pub async fn get_host_info(&mut self) -> Result<HostInfoReply, Box<dyn Error>> {
         match self.client_type {
             RcdClientType::Grpc => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_host_info(auth)
-                    .await
-                    .unwrap()
-                    .into_inner();
+                let response = grpc_call!(self, get_host_info, auth).unwrap().into_inner();
 
                 Ok(response)
             }
             RcdClientType::Http => {
-                let request = self.gen_auth_request();
+                let request = self.auth_request().await;
 
                 let url = self.get_http_url(GET_HOST_INFO);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
+                Ok(result)
+            }
+        }
+    }
+
+    pub async fn get_versions(&mut self) -> Result<VersionReply, Box<dyn Error>> {
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let auth = self.auth_request().await;
+
+                let response = grpc_call!(self, get_versions, auth).unwrap().into_inner();
+
+                Ok(response)
+            }
+            RcdClientType::Http => {
+                let request = self.auth_request().await;
+
+                let url = self.get_http_url(GET_VERSIONS);
+                let result = self.get_authenticated_http_result(url, request).await;
                 Ok(result)
             }
         }
@@ -333,26 +448,21 @@ impl RcdClient {
     ) -> Result<GetActiveContractReply, Box<dyn Error>> {
         match self.client_type {
             RcdClientType::Grpc => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let request = GetActiveContractRequest {
                     authentication: Some(auth),
                     database_name: db_name.to_string(),
                 };
 
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_active_contract(request)
-                    .await
+                let response = grpc_call!(self, get_active_contract, request)
                     .unwrap()
                     .into_inner();
 
                 Ok(response)
             }
             RcdClientType::Http => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let request = GetActiveContractRequest {
                     authentication: Some(auth),
@@ -360,7 +470,7 @@ impl RcdClient {
                 };
 
                 let url = self.get_http_url(GET_ACTIVE_CONTRACT);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -370,7 +480,7 @@ impl RcdClient {
     pub async fn revoke_token(&mut self) -> Result<RevokeReply, Box<dyn Error>> {
         match self.client_type {
             RcdClientType::Grpc => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let response = self
                     .grpc_client
@@ -381,13 +491,21 @@ impl RcdClient {
                     .unwrap()
                     .into_inner();
 
+                if response.is_successful {
+                    self.clear_token();
+                }
+
                 Ok(response)
             }
             RcdClientType::Http => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let url = self.get_http_url(REVOKE_TOKEN);
-                let result = self.get_http_result(url, auth).await;
+                let result: RevokeReply = self.get_http_result(url, auth).await;
+
+                if result.is_successful {
+                    self.clear_token();
+                }
 
                 Ok(result)
             }
@@ -408,12 +526,7 @@ impl RcdClient {
                     .unwrap()
                     .into_inner();
 
-                if response.is_successful {
-                    let x = response.clone();
-                    self.jwt = x.jwt;
-                } else {
-                    self.jwt = "".to_string();
-                }
+                self.set_token(&response);
 
                 Ok(response)
             }
@@ -423,12 +536,7 @@ impl RcdClient {
                 let url = self.get_http_url(AUTH_FOR_TOKEN);
                 let result: TokenReply = self.get_http_result(url, auth).await;
 
-                if result.is_successful {
-                    let x = result.clone();
-                    self.jwt = x.jwt;
-                } else {
-                    self.jwt = "".to_string();
-                }
+                self.set_token(&result);
 
                 Ok(result)
             }
@@ -443,7 +551,7 @@ impl RcdClient {
     ) -> Result<AcceptPendingActionReply, Box<dyn Error>> {
         match self.client_type {
             RcdClientType::Grpc => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let request = AcceptPendingActionRequest {
                     authentication: Some(auth),
@@ -452,19 +560,14 @@ impl RcdClient {
                     row_id,
                 };
 
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .accept_pending_action_at_participant(request)
-                    .await
+                let response = grpc_call!(self, accept_pending_action_at_participant, request)
                     .unwrap()
                     .into_inner();
 
                 Ok(response)
             }
             RcdClientType::Http => {
-                let auth = self.gen_auth_request();
+                let auth = self.auth_request().await;
 
                 let request = AcceptPendingActionRequest {
                     authentication: Some(auth),
@@ -474,17 +577,116 @@ impl RcdClient {
                 };
 
                 let url = self.get_http_url(ACCEPT_PENDING_ACTION);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
+
+                Ok(result)
+            }
+        }
+    }
+
+    pub async fn reject_pending_action_at_participant(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<RejectPendingActionReply, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = RejectPendingActionRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            row_id,
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, reject_pending_action_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(REJECT_PENDING_ACTION);
+                let result = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
         }
     }
 
+    /// Returns how many seconds an action can sit in the queue for the table before it is
+    /// rejected on the host's behalf. Zero means actions never expire.
+    pub async fn get_pending_action_ttl_at_participant(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<u32, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+        let request = GetPendingActionTtlRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, get_pending_action_ttl_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response.ttl_seconds)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(GET_PENDING_ACTION_TTL);
+                let result: GetPendingActionTtlReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.ttl_seconds)
+            }
+        }
+    }
+
+    pub async fn set_pending_action_ttl_at_participant(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        ttl_seconds: u32,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+        let request = SetPendingActionTtlRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            ttl_seconds,
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, set_pending_action_ttl_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response.is_successful)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(SET_PENDING_ACTION_TTL);
+                let result: SetPendingActionTtlReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.is_successful)
+            }
+        }
+    }
+
     pub async fn get_cooperative_hosts(
         &mut self,
     ) -> Result<GetCooperativeHostsReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetCooperativeHostsRequest {
             authentication: Some(auth),
@@ -492,12 +694,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_cooperative_hosts(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_cooperative_hosts, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
@@ -506,7 +703,7 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_COOP_HOSTS);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -517,7 +714,7 @@ impl RcdClient {
         &mut self,
         db_name: &str,
     ) -> Result<GetParticipantsReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetParticipantsRequest {
             authentication: Some(auth),
@@ -526,12 +723,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_participants(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_participants, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
@@ -540,7 +732,7 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_PARTICIPANTS);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -553,7 +745,7 @@ impl RcdClient {
         table_name: &str,
         action: &str,
     ) -> Result<GetPendingActionsReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetPendingActionsRequest {
             authentication: Some(auth),
@@ -564,12 +756,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_pending_actions_at_participant(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_pending_actions_at_participant, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
@@ -578,7 +765,36 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_PENDING_ACTIONS);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
+
+                Ok(result)
+            }
+        }
+    }
+
+    pub async fn get_pending_writes_at_host(
+        &mut self,
+        db_name: &str,
+    ) -> Result<GetPendingWritesAtHostReply, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = GetPendingWritesAtHostRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, get_pending_writes_at_host, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(GET_PENDING_WRITES_AT_HOST);
+                let result = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -591,7 +807,7 @@ impl RcdClient {
         table_name: &str,
         where_clause: &str,
     ) -> Result<Vec<u32>, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetReadRowIdsRequest {
             authentication: Some(auth),
@@ -602,12 +818,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .read_row_id_at_participant(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, read_row_id_at_participant, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
@@ -616,7 +827,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_ROW_AT_PARTICIPANT);
-                let result: GetReadRowIdsReply = self.get_http_result(url, request).await;
+                let result: GetReadRowIdsReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.row_ids)
             }
@@ -629,7 +841,7 @@ impl RcdClient {
         table_name: &str,
         row_id: u32,
     ) -> Result<u64, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetDataHashRequest {
             authentication: Some(auth),
@@ -640,23 +852,128 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
+                let response = grpc_call!(self, get_data_hash_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                debug!("RESPONSE={:?}", response);
+
+                Ok(response.data_hash)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(GET_DATA_HASH_AT_PARTICIPANT);
+                let result: GetDataHashReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.data_hash)
+            }
+        }
+    }
+
+    /// Recomputes the hash of every row in the partial database and sends the new hashes to
+    /// the host. Used to migrate partial databases after the row hash algorithm has changed.
+    pub async fn rehash_partial_database(
+        &mut self,
+        db_name: &str,
+    ) -> Result<RehashPartialDatabaseReply, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = RehashPartialDatabaseRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, rehash_partial_database, request)
+                    .unwrap()
+                    .into_inner();
+                debug!("RESPONSE={:?}", response);
+
+                Ok(response)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(REHASH_PARTIAL_DATABASE);
+                let result: RehashPartialDatabaseReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result)
+            }
+        }
+    }
+
+    /// Supplies the key for a database that is encrypted at rest
+    pub async fn unlock_database(
+        &mut self,
+        db_name: &str,
+        database_key: &str,
+    ) -> Result<UnlockDatabaseReply, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = UnlockDatabaseRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            database_key: database_key.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, unlock_database, request)
+                    .unwrap()
+                    .into_inner();
+                debug!("RESPONSE={:?}", response);
+
+                Ok(response)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(UNLOCK_DATABASE);
+                let result: UnlockDatabaseReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result)
+            }
+        }
+    }
+
+    /// Exports a host or partial database in `format`. The first reply has the manifest of what
+    /// was exported and each reply after it carries the next chunk of one of the exported files.
+    pub async fn export_database(
+        &mut self,
+        db_name: &str,
+        format: ExportFormat,
+    ) -> Result<Vec<ExportDatabaseReply>, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = ExportDatabaseRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            format: ExportFormat::to_u32(format),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let mut stream = self
                     .grpc_client
                     .as_mut()
                     .unwrap()
-                    .get_data_hash_at_participant(tonic::Request::new(request))
+                    .export_database(tonic::Request::new(request))
                     .await
                     .unwrap()
                     .into_inner();
-                debug!("RESPONSE={:?}", response);
 
-                Ok(response.data_hash)
+                let mut replies: Vec<ExportDatabaseReply> = Vec::new();
+                while let Some(reply) = stream.message().await? {
+                    replies.push(reply);
+                }
+                debug!("RESPONSE={:?}", replies.first());
+
+                Ok(replies)
             }
             RcdClientType::Http => {
-                let url = self.get_http_url(GET_DATA_HASH_AT_PARTICIPANT);
-                let result: GetDataHashReply = self.get_http_result(url, request).await;
+                let url = self.get_http_url(EXPORT_DATABASE);
+                let result: Vec<ExportDatabaseReply> =
+                    self.get_authenticated_http_results(url, request).await;
 
-                Ok(result.data_hash)
+                Ok(result)
             }
         }
     }
@@ -667,7 +984,7 @@ impl RcdClient {
         table_name: &str,
         row_id: u32,
     ) -> Result<u64, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetDataHashRequest {
             authentication: Some(auth),
@@ -678,12 +995,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_data_hash_at_host(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_data_hash_at_host, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -692,7 +1004,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_DATA_HASH_AT_HOST);
-                let result: GetDataHashReply = self.get_http_result(url, request).await;
+                let result: GetDataHashReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.data_hash)
             }
@@ -704,7 +1017,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<GetDeletesToHostBehaviorReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = GetDeletesToHostBehaviorRequest {
             authentication: Some(auth),
             database_name: db_name.to_string(),
@@ -713,12 +1026,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_deletes_to_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_deletes_to_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -728,7 +1036,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_DELETES_TO_HOST_BEHAVIOR);
                 let result: GetDeletesToHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -741,7 +1049,7 @@ impl RcdClient {
         table_name: &str,
         behavior: DeletesToHostBehavior,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ChangeDeletesToHostBehaviorRequest {
             authentication: Some(auth),
@@ -752,12 +1060,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .change_deletes_to_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, change_deletes_to_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -767,7 +1070,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_DELETES_TO_HOST_BEHAVIOR);
                 let result: ChangeDeletesToHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
                 Ok(result.is_successful)
             }
         }
@@ -778,7 +1081,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<GetUpdatesToHostBehaviorReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = GetUpdatesToHostBehaviorRequest {
             authentication: Some(auth),
             database_name: db_name.to_string(),
@@ -787,12 +1090,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_updates_to_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_updates_to_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -802,7 +1100,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_UPDATES_TO_HOST_BEHAVIOR);
                 let result: GetUpdatesToHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -815,7 +1113,7 @@ impl RcdClient {
         table_name: &str,
         behavior: UpdatesToHostBehavior,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ChangeUpdatesToHostBehaviorRequest {
             authentication: Some(auth),
@@ -826,12 +1124,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .change_updates_to_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, change_updates_to_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -841,7 +1134,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_UPDATES_TO_HOST_BEHAVIOR);
                 let result: ChangeUpdatesToHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -853,7 +1146,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<GetDeletesFromHostBehaviorReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetDeletesFromHostBehaviorRequest {
             authentication: Some(auth),
@@ -863,12 +1156,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .get_deletes_from_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, get_deletes_from_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -878,7 +1166,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_DELETES_FROM_HOST_BEHAVIOR);
                 let result: GetDeletesFromHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result)
             }
@@ -891,7 +1179,7 @@ impl RcdClient {
         table_name: &str,
         behavior: DeletesFromHostBehavior,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ChangeDeletesFromHostBehaviorRequest {
             authentication: Some(auth),
@@ -902,12 +1190,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .change_deletes_from_host_behavior(tonic::Request::new(request))
-                    .await
+                let response = grpc_call!(self, change_deletes_from_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 debug!("RESPONSE={:?}", response);
@@ -917,7 +1200,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_DELETES_FROM_HOST_BEHAVIOR);
                 let result: ChangeDeletesFromHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
                 Ok(result.is_successful)
             }
         }
@@ -928,7 +1211,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<GetUpdatesFromHostBehaviorReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetUpdatesFromHostBehaviorRequest {
             authentication: Some(auth),
@@ -938,11 +1221,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .get_updates_from_host_behavior(request)
-                    .await
+                let response = grpc_call!(self, get_updates_from_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -951,7 +1230,7 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_UPDATES_FROM_HOST_BEHAVIOR);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
                 Ok(result)
             }
         }
@@ -963,7 +1242,7 @@ impl RcdClient {
         table_name: &str,
         behavior: UpdatesFromHostBehavior,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = ChangeUpdatesFromHostBehaviorRequest {
             authentication: Some(auth),
             database_name: db_name.to_string(),
@@ -973,11 +1252,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .change_updates_from_host_behavior(request)
-                    .await
+                let response = grpc_call!(self, change_updates_from_host_behavior, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
@@ -987,7 +1262,73 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_UPDATES_FROM_HOST_BEHAVIOR);
                 let result: ChangesUpdatesFromHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.is_successful)
+            }
+        }
+    }
+
+    pub async fn get_data_log_table_status_at_participant(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+        let request = GetDataLogTableStatusRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, get_data_log_table_status_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response.use_data_log)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(GET_DATA_LOG_TABLE_STATUS);
+                let result: GetDataLogTableStatusReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.use_data_log)
+            }
+        }
+    }
+
+    /// Switches the data log table for the table in the partial database on or off. While it's
+    /// on, the row as it was before each change is kept in the `<table>_COOP_DATA_LOG` table.
+    pub async fn set_data_log_table_status_at_participant(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        use_data_log: bool,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+        let request = SetDataLogTableStatusRequest {
+            authentication: Some(auth),
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            use_data_log,
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, set_data_log_table_status_at_participant, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={:?}", function_name!(), response);
+
+                Ok(response.is_successful)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(SET_DATA_LOG_TABLE_STATUS);
+                let result: SetDataLogTableStatusReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -999,7 +1340,7 @@ impl RcdClient {
         host_id: &str,
         status: u32,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = ChangeHostStatusRequest {
             authentication: Some(auth),
             host_alias: String::from(""),
@@ -1009,11 +1350,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .change_host_status(request)
-                    .await
+                let response = grpc_call!(self, change_host_status, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1023,7 +1360,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_HOST_STATUS_ID);
                 let result: ChangesUpdatesFromHostBehaviorReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1035,7 +1372,7 @@ impl RcdClient {
         host_name: &str,
         status: u32,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = ChangeHostStatusRequest {
             authentication: Some(auth),
             host_alias: host_name.to_string(),
@@ -1045,11 +1382,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .change_host_status(request)
-                    .await
+                let response = grpc_call!(self, change_host_status, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1058,7 +1391,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(CHANGE_HOST_STATUS_NAME);
-                let result: ChangeHostStatusReply = self.get_http_result(url, request).await;
+                let result: ChangeHostStatusReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1066,7 +1400,7 @@ impl RcdClient {
     }
 
     pub async fn generate_host_info(&mut self, host_name: &str) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
         let request = GenerateHostInfoRequest {
             authentication: Some(auth),
             host_name: host_name.to_string(),
@@ -1074,11 +1408,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .generate_host_info(request)
-                    .await
+                let response = grpc_call!(self, generate_host_info, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1087,7 +1417,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GENERATE_HOST_INFO);
-                let result: GenerateHostInfoReply = self.get_http_result(url, request).await;
+                let result: GenerateHostInfoReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1095,7 +1426,7 @@ impl RcdClient {
     }
 
     pub async fn get_databases(&mut self) -> Result<GetDatabasesReply, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetDatabasesRequest {
             authentication: Some(auth),
@@ -1103,8 +1434,9 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-                let response = client.get_databases(request).await.unwrap().into_inner();
+                let response = grpc_call!(self, get_databases, request)
+                    .unwrap()
+                    .into_inner();
 
                 trace!("[{}]: {:?}", function_name!(), response);
 
@@ -1112,7 +1444,7 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_DATABASES);
-                let result = self.get_http_result(url, request).await;
+                let result = self.get_authenticated_http_result(url, request).await;
                 Ok(result)
             }
         }
@@ -1125,7 +1457,28 @@ impl RcdClient {
         participant_alias: &str,
         where_clause: &str,
     ) -> Result<bool, String> {
-        let auth = self.gen_auth_request();
+        let reply = self
+            .execute_cooperative_write_at_host_with_reply(
+                db_name,
+                cmd,
+                participant_alias,
+                where_clause,
+            )
+            .await?;
+
+        Ok(reply.is_successful)
+    }
+
+    /// Same as `execute_cooperative_write_at_host`, but returns the whole reply, including what
+    /// happened to the write at the participant
+    pub async fn execute_cooperative_write_at_host_with_reply(
+        &mut self,
+        db_name: &str,
+        cmd: &str,
+        participant_alias: &str,
+        where_clause: &str,
+    ) -> Result<ExecuteCooperativeWriteReply, String> {
+        let auth = self.auth_request().await;
 
         let request = ExecuteCooperativeWriteRequest {
             authentication: Some(auth),
@@ -1141,28 +1494,26 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-                let response = client
-                    .execute_cooperative_write_at_host(request)
-                    .await
+                let response = grpc_call!(self, execute_cooperative_write_at_host, request)
                     .unwrap()
                     .into_inner();
 
                 trace!("[{}]: {:?}", function_name!(), response);
 
-                Ok(response.is_successful)
+                Ok(response)
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(COOPERATIVE_WRITE_SQL_AT_HOST);
-                let result: ExecuteCooperativeWriteReply = self.get_http_result(url, request).await;
+                let result: ExecuteCooperativeWriteReply =
+                    self.get_authenticated_http_result(url, request).await;
 
-                Ok(result.is_successful)
+                Ok(result)
             }
         }
     }
 
     pub async fn view_pending_contracts(&mut self) -> Result<Vec<Contract>, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ViewPendingContractsRequest {
             authentication: Some(auth),
@@ -1170,11 +1521,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .review_pending_contracts(request)
-                    .await
+                let response = grpc_call!(self, review_pending_contracts, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1183,7 +1530,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(VIEW_PENDING_CONTRACTS);
-                let result: ViewPendingContractsReply = self.get_http_result(url, request).await;
+                let result: ViewPendingContractsReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.contracts)
             }
@@ -1194,20 +1542,29 @@ impl RcdClient {
         &mut self,
         host_alias: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        self.accept_pending_contract_with_key(host_alias, None)
+            .await
+    }
+
+    /// Accepts the contract and, if `database_key` is set, encrypts the partial database at rest
+    /// with it. The key isn't stored by rcd and has to be given to `unlock_database` after a
+    /// restart.
+    pub async fn accept_pending_contract_with_key(
+        &mut self,
+        host_alias: &str,
+        database_key: Option<&str>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
 
         let request = AcceptPendingContractRequest {
             authentication: Some(auth),
             host_alias: host_alias.to_string(),
+            database_key: database_key.map(|k| k.to_string()),
         };
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .accept_pending_contract(request)
-                    .await
+                let response = grpc_call!(self, accept_pending_contract, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1216,7 +1573,38 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(ACCEPT_PENDING_CONTRACT);
-                let result: AcceptPendingContractReply = self.get_http_result(url, request).await;
+                let result: AcceptPendingContractReply =
+                    self.get_authenticated_http_result(url, request).await;
+
+                Ok(result.is_successful)
+            }
+        }
+    }
+
+    pub async fn reject_pending_contract(
+        &mut self,
+        host_alias: &str,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
+
+        let request = RejectPendingContractRequest {
+            authentication: Some(auth),
+            host_alias: host_alias.to_string(),
+        };
+
+        match self.client_type {
+            RcdClientType::Grpc => {
+                let response = grpc_call!(self, reject_pending_contract, request)
+                    .unwrap()
+                    .into_inner();
+                trace!("[{}]: RESPONSE={response:?}", function_name!());
+
+                Ok(response.is_successful)
+            }
+            RcdClientType::Http => {
+                let url = self.get_http_url(REJECT_PENDING_CONTRACT);
+                let result: RejectPendingContractReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1228,7 +1616,7 @@ impl RcdClient {
         db_name: &str,
         participant_alias: &str,
     ) -> Result<bool, RcdClientError> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = SendParticipantContractRequest {
             authentication: Some(auth),
@@ -1238,11 +1626,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .send_participant_contract(request)
-                    .await
+                let response = grpc_call!(self, send_participant_contract, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1251,7 +1635,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(SEND_CONTRACT_TO_PARTICIPANT);
-                let result: SendParticipantContractReply = self.get_http_result(url, request).await;
+                let result: SendParticipantContractReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_sent)
             }
@@ -1268,7 +1653,7 @@ impl RcdClient {
         participant_http_port: u16,
         participant_id: Option<String>,
     ) -> Result<bool, RcdClientError> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = AddParticipantRequest {
             authentication: Some(auth),
@@ -1283,16 +1668,17 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client.add_participant(request).await.unwrap().into_inner();
+                let response = grpc_call!(self, add_participant, request)
+                    .unwrap()
+                    .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
 
                 Ok(response.is_successful)
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(ADD_PARTICIPANT);
-                let result: AddParticipantReply = self.get_http_result(url, request).await;
+                let result: AddParticipantReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1306,7 +1692,7 @@ impl RcdClient {
         desc: &str,
         remote_delete_behavior: RemoteDeleteBehavior,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GenerateContractRequest {
             authentication: Some(auth),
@@ -1318,11 +1704,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .generate_contract(request)
-                    .await
+                let response = grpc_call!(self, generate_contract, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1331,7 +1713,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GENERATE_CONTRACT);
-                let result: GenerateContractReply = self.get_http_result(url, request).await;
+                let result: GenerateContractReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1343,7 +1726,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = HasTableRequest {
             authentication: Some(auth),
@@ -1353,16 +1736,14 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client.has_table(request).await.unwrap().into_inner();
+                let response = grpc_call!(self, has_table, request).unwrap().into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
 
                 Ok(response.has_table)
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(HAS_TABLE);
-                let result: HasTableReply = self.get_http_result(url, request).await;
+                let result: HasTableReply = self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.has_table)
             }
@@ -1374,7 +1755,7 @@ impl RcdClient {
         db_name: &str,
         table_name: &str,
     ) -> Result<LogicalStoragePolicy, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = GetLogicalStoragePolicyRequest {
             authentication: Some(auth),
@@ -1384,11 +1765,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .get_logical_storage_policy(request)
-                    .await
+                let response = grpc_call!(self, get_logical_storage_policy, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1399,7 +1776,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(GET_POLICY);
-                let result: GetLogicalStoragePolicyReply = self.get_http_result(url, request).await;
+                let result: GetLogicalStoragePolicyReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(LogicalStoragePolicy::from_i64(result.policy_mode as i64))
             }
@@ -1412,22 +1790,52 @@ impl RcdClient {
         table_name: &str,
         policy: LogicalStoragePolicy,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        self.send_logical_storage_policy(db_name, table_name, policy, Vec::new())
+            .await
+    }
+
+    /// Sets the table to `Shared`, where the host keeps each row but the values of
+    /// `participant_owned_columns` are only kept at the participant
+    pub async fn set_shared_logical_storage_policy(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        participant_owned_columns: &[&str],
+    ) -> Result<bool, Box<dyn Error>> {
+        let participant_owned_columns = participant_owned_columns
+            .iter()
+            .map(|column_name| column_name.to_string())
+            .collect();
+
+        self.send_logical_storage_policy(
+            db_name,
+            table_name,
+            LogicalStoragePolicy::Shared,
+            participant_owned_columns,
+        )
+        .await
+    }
+
+    async fn send_logical_storage_policy(
+        &mut self,
+        db_name: &str,
+        table_name: &str,
+        policy: LogicalStoragePolicy,
+        participant_owned_columns: Vec<String>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
 
         let request = SetLogicalStoragePolicyRequest {
             authentication: Some(auth),
             database_name: db_name.to_string(),
             table_name: table_name.to_string(),
             policy_mode: LogicalStoragePolicy::to_u32(policy),
+            participant_owned_columns,
         };
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .set_logical_storage_policy(request)
-                    .await
+                let response = grpc_call!(self, set_logical_storage_policy, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1436,7 +1844,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(SET_POLICY);
-                let result: SetLogicalStoragePolicyReply = self.get_http_result(url, request).await;
+                let result: SetLogicalStoragePolicyReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1450,7 +1859,7 @@ impl RcdClient {
         db_type: u32,
         where_clause: &str,
     ) -> Result<bool, RcdClientError> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ExecuteWriteRequest {
             authentication: Some(auth),
@@ -1462,11 +1871,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .execute_write_at_host(request)
-                    .await
+                let response = grpc_call!(self, execute_write_at_host, request)
                     .unwrap()
                     .into_inner();
 
@@ -1476,7 +1881,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(WRITE_SQL_AT_HOST);
-                let result: ExecuteWriteReply = self.get_http_result(url, request).await;
+                let result: ExecuteWriteReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1490,7 +1896,7 @@ impl RcdClient {
         db_type: u32,
         where_clause: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ExecuteWriteRequest {
             authentication: Some(auth),
@@ -1502,11 +1908,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .execute_write_at_participant(request)
-                    .await
+                let response = grpc_call!(self, execute_write_at_participant, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1515,7 +1917,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(WRITE_SQL_AT_PARTICIPANT);
-                let result: ExecuteWriteReply = self.get_http_result(url, request).await;
+                let result: ExecuteWriteReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1523,7 +1926,20 @@ impl RcdClient {
     }
 
     pub async fn try_auth_at_participant(&mut self, alias: &str, id: &str, db_name: &str) -> bool {
-        let auth = self.gen_auth_request();
+        self.try_auth_at_participant_with_reply(alias, id, db_name)
+            .await
+            .is_successful
+    }
+
+    /// Same as `try_auth_at_participant`, but returns the whole reply, including the protocol
+    /// version agreed with the participant
+    pub async fn try_auth_at_participant_with_reply(
+        &mut self,
+        alias: &str,
+        id: &str,
+        db_name: &str,
+    ) -> TryAuthAtPartipantReply {
+        let auth = self.auth_request().await;
 
         let request = TryAuthAtParticipantRequest {
             authentication: Some(auth),
@@ -1534,22 +1950,16 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .try_auth_at_participant(request)
-                    .await
+                let response = grpc_call!(self, try_auth_at_participant, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={:?}", function_name!(), response);
 
-                response.is_successful
+                response
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(TRY_AUTH_PARTICIPANT);
-                let result: TryAuthAtPartipantReply = self.get_http_result(url, request).await;
-
-                result.is_successful
+                self.get_authenticated_http_result(url, request).await
             }
         }
     }
@@ -1560,7 +1970,7 @@ impl RcdClient {
         sql_statement: &str,
         db_type: u32,
     ) -> Result<StatementResultset, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ExecuteReadRequest {
             authentication: Some(auth),
@@ -1573,11 +1983,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .execute_read_at_participant(request)
-                    .await
+                let response = grpc_call!(self, execute_read_at_participant, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1586,7 +1992,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(READ_SQL_AT_PARTICIPANT);
-                let result: ExecuteReadReply = self.get_http_result(url, request).await;
+                let result: ExecuteReadReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.results[0].clone())
             }
@@ -1599,7 +2006,7 @@ impl RcdClient {
         sql_statement: &str,
         db_type: u32,
     ) -> Result<StatementResultset, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = ExecuteReadRequest {
             authentication: Some(auth),
@@ -1612,11 +2019,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .execute_read_at_host(request)
-                    .await
+                let response = grpc_call!(self, execute_read_at_host, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1625,7 +2028,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(READ_SQL_AT_HOST);
-                let result: ExecuteReadReply = self.get_http_result(url, request).await;
+                let result: ExecuteReadReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.results[0].clone())
             }
@@ -1636,7 +2040,7 @@ impl RcdClient {
         &mut self,
         db_name: &str,
     ) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        let auth = self.auth_request().await;
 
         let request = EnableCoooperativeFeaturesRequest {
             authentication: Some(auth),
@@ -1645,11 +2049,7 @@ impl RcdClient {
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let client = self.get_client();
-
-                let response = client
-                    .enable_coooperative_features(request)
-                    .await
+                let response = grpc_call!(self, enable_coooperative_features, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1659,7 +2059,7 @@ impl RcdClient {
             RcdClientType::Http => {
                 let url = self.get_http_url(ENABLE_COOPERATIVE_FEATURES);
                 let result: EnableCoooperativeFeaturesReply =
-                    self.get_http_result(url, request).await;
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_successful)
             }
@@ -1667,21 +2067,27 @@ impl RcdClient {
     }
 
     pub async fn create_user_database(&mut self, db_name: &str) -> Result<bool, Box<dyn Error>> {
-        let auth = self.gen_auth_request();
+        self.create_user_database_with_key(db_name, None).await
+    }
+
+    /// Creates the database and, if `database_key` is set, encrypts it at rest with it. The key
+    /// isn't stored by rcd and has to be given to `unlock_database` after a restart.
+    pub async fn create_user_database_with_key(
+        &mut self,
+        db_name: &str,
+        database_key: Option<&str>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let auth = self.auth_request().await;
 
         let request = CreateUserDatabaseRequest {
             authentication: Some(auth),
             database_name: db_name.to_string(),
+            database_key: database_key.map(|k| k.to_string()),
         };
 
         match self.client_type {
             RcdClientType::Grpc => {
-                let response = self
-                    .grpc_client
-                    .as_mut()
-                    .unwrap()
-                    .create_user_database(request)
-                    .await
+                let response = grpc_call!(self, create_user_database, request)
                     .unwrap()
                     .into_inner();
                 trace!("[{}]: RESPONSE={response:?}", function_name!());
@@ -1690,7 +2096,8 @@ impl RcdClient {
             }
             RcdClientType::Http => {
                 let url = self.get_http_url(NEW_DATABASE);
-                let result: CreateUserDatabaseReply = self.get_http_result(url, request).await;
+                let result: CreateUserDatabaseReply =
+                    self.get_authenticated_http_result(url, request).await;
 
                 Ok(result.is_created)
             }
@@ -1702,6 +2109,71 @@ impl RcdClient {
         return self.grpc_client.as_mut().unwrap();
     }
 
+    fn is_using_jwt(&self) -> bool {
+        self.send_jwt_if_available && !self.jwt.is_empty()
+    }
+
+    /// Returns the credentials to send with a request, first getting a new token if the one we
+    /// have is about to expire. Clients created from a profile also log in here the first time
+    async fn auth_request(&mut self) -> AuthRequest {
+        if self.send_jwt_if_available {
+            let is_expiring = match self.jwt_expires_utc {
+                Some(expires_utc) => {
+                    expires_utc - chrono::Duration::seconds(TOKEN_REFRESH_MARGIN_IN_SECONDS)
+                        <= Utc::now()
+                }
+                None => false,
+            };
+
+            let needs_login = self.jwt.is_empty() && self.token_cache.is_some();
+
+            if (self.is_using_jwt() && is_expiring) || needs_login {
+                self.refresh_token().await;
+            }
+        }
+
+        self.gen_auth_request()
+    }
+
+    /// Drops our token and logs in again with our user name and pw
+    async fn refresh_token(&mut self) {
+        self.jwt = String::from("");
+        self.jwt_expires_utc = None;
+
+        if let Err(e) = self.auth_for_token().await {
+            debug!("[{}]: unable to refresh token: {e}", function_name!());
+        }
+    }
+
+    fn set_token(&mut self, reply: &TokenReply) {
+        if !reply.is_successful {
+            self.clear_token();
+            return;
+        }
+
+        self.jwt = reply.jwt.clone();
+
+        match CachedToken::from_reply(&reply.jwt, &reply.expiration_utc) {
+            Some(token) => {
+                self.jwt_expires_utc = Some(token.expires_utc);
+
+                if let Some(cache) = &self.token_cache {
+                    cache.save(&token);
+                }
+            }
+            None => self.jwt_expires_utc = None,
+        }
+    }
+
+    fn clear_token(&mut self) {
+        self.jwt = String::from("");
+        self.jwt_expires_utc = None;
+
+        if let Some(cache) = &self.token_cache {
+            cache.remove();
+        }
+    }
+
     fn gen_auth_request(&self) -> AuthRequest {
         let auth: AuthRequest;
 
@@ -1768,6 +2240,84 @@ impl RcdClient {
         value
     }
 
+    /// Same as [`RcdClient::get_http_result`], but if the request was sent with a token and the
+    /// instance refused it, logs in again and re-sends the request once with the new token
+    async fn get_authenticated_http_result<T, U>(&mut self, url: String, request: U) -> T
+    where
+        T: de::DeserializeOwned + std::clone::Clone + AuthenticatedReply,
+        U: de::DeserializeOwned + serde::Serialize + std::clone::Clone + AuthenticatedRequest,
+    {
+        let is_using_jwt = self.is_using_jwt();
+        let result: T = self.get_http_result(url.clone(), request.clone()).await;
+
+        if is_using_jwt && result.is_authentication_rejected() {
+            debug!("[{}]: token was rejected, retrying", function_name!());
+            self.refresh_token().await;
+            let mut request = request;
+            request.set_authentication(self.gen_auth_request());
+            return self.get_http_result(url, request).await;
+        }
+
+        result
+    }
+
+    /// Same as [`RcdClient::get_http_result`], for endpoints that stream their replies back one
+    /// JSON object per line. Each reply is parsed as its line arrives.
+    async fn get_http_results<T, U>(&mut self, url: String, request: U) -> Vec<T>
+    where
+        T: de::DeserializeOwned,
+        U: serde::Serialize,
+    {
+        let request_json = serde_json::to_string(&request).unwrap();
+        let client = self.http_client.as_ref().unwrap();
+
+        trace!("[{}]: {request_json}", function_name!());
+        trace!("[{}]: {url}", function_name!());
+
+        let mut response = client
+            .post(url)
+            .header("Content-Type", "application/json")
+            .body(request_json)
+            .send()
+            .await
+            .unwrap();
+
+        let mut results: Vec<T> = Vec::new();
+        let mut buffer: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = response.chunk().await.unwrap() {
+            buffer.extend_from_slice(&chunk);
+
+            while let Some(end) = buffer.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=end).collect();
+                results.push(serde_json::from_slice(&line).unwrap());
+            }
+        }
+
+        results
+    }
+
+    /// Same as [`RcdClient::get_authenticated_http_result`], for endpoints that stream their
+    /// replies back one JSON object per line
+    async fn get_authenticated_http_results<T, U>(&mut self, url: String, request: U) -> Vec<T>
+    where
+        T: de::DeserializeOwned + AuthenticatedReply,
+        U: serde::Serialize + AuthenticatedRequest,
+    {
+        let is_using_jwt = self.is_using_jwt();
+        let result: Vec<T> = self.get_http_results(url.clone(), &request).await;
+
+        if is_using_jwt && result.is_authentication_rejected() {
+            debug!("[{}]: token was rejected, retrying", function_name!());
+            self.refresh_token().await;
+            let mut request = request;
+            request.set_authentication(self.gen_auth_request());
+            return self.get_http_results(url, request).await;
+        }
+
+        result
+    }
+
     fn get_http_url(&self, action_url: &str) -> String {
         let http_base = format!("{}{}:{}", "http://", self.http_addr, self.http_port);
 
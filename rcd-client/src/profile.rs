@@ -0,0 +1,120 @@
+use crate::{client_type::RcdClientType, error::RcdClientError};
+use config::Config;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+};
+
+/// environment variable that overrides where profiles are read from
+pub const PROFILES_ENV_VAR: &str = "RCD_PROFILES";
+const PROFILES_DIR: &str = ".rcd";
+const PROFILES_FILE: &str = "profiles.toml";
+const TOKEN_CACHE_FILE: &str = "tokens.json";
+const DEFAULT_TIMEOUT_IN_SECONDS: u32 = 60;
+
+/// A named set of connection details for an `rcd` instance. Profiles are kept in a TOML file
+/// as tables under `[profiles.<name>]`, for example:
+///
+/// ```toml
+/// [profiles.local]
+/// client_type = "grpc"
+/// grpc_addr_port = "http://127.0.0.1:50051"
+/// user_name = "tester"
+/// pw = "123456"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RcdClientProfile {
+    pub client_type: RcdClientType,
+    #[serde(default)]
+    pub grpc_addr_port: String,
+    #[serde(default)]
+    pub http_addr: String,
+    #[serde(default)]
+    pub http_port: u32,
+    pub user_name: String,
+    #[serde(default)]
+    pub pw: String,
+    #[serde(default = "default_timeout_in_seconds")]
+    pub timeout_in_seconds: u32,
+    #[serde(default)]
+    pub host_id: Option<String>,
+    /// if the client should log in for a token and send that instead of the user name and pw
+    #[serde(default = "default_use_token")]
+    pub use_token: bool,
+}
+
+/// The profiles loaded from a profiles file
+#[derive(Debug, Clone)]
+pub struct RcdClientProfiles {
+    path: PathBuf,
+    profiles: HashMap<String, RcdClientProfile>,
+}
+
+impl RcdClientProfiles {
+    /// `$RCD_PROFILES` if it is set, otherwise `~/.rcd/profiles.toml`
+    pub fn default_path() -> PathBuf {
+        if let Ok(path) = env::var(PROFILES_ENV_VAR) {
+            return PathBuf::from(path);
+        }
+
+        let home = env::var("HOME")
+            .or_else(|_| env::var("USERPROFILE"))
+            .unwrap_or_else(|_| ".".to_string());
+
+        Path::new(&home).join(PROFILES_DIR).join(PROFILES_FILE)
+    }
+
+    pub fn load_default() -> Result<Self, RcdClientError> {
+        Self::load(&Self::default_path())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, RcdClientError> {
+        let location = path
+            .to_str()
+            .ok_or_else(|| RcdClientError::ProfileError(format!("invalid path: {path:?}")))?;
+
+        let settings = Config::builder()
+            .add_source(config::File::new(location, config::FileFormat::Toml))
+            .build()
+            .map_err(|e| RcdClientError::ProfileError(e.to_string()))?;
+
+        let profiles = settings
+            .get::<HashMap<String, RcdClientProfile>>("profiles")
+            .map_err(|e| RcdClientError::ProfileError(e.to_string()))?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            profiles,
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Result<&RcdClientProfile, RcdClientError> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| RcdClientError::ProfileNotFound(name.to_string()))
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.profiles.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// tokens for these profiles are cached in a file next to the profiles file
+    pub fn token_cache_path(&self) -> PathBuf {
+        match self.path.parent() {
+            Some(dir) => dir.join(TOKEN_CACHE_FILE),
+            None => PathBuf::from(TOKEN_CACHE_FILE),
+        }
+    }
+}
+
+fn default_timeout_in_seconds() -> u32 {
+    DEFAULT_TIMEOUT_IN_SECONDS
+}
+
+fn default_use_token() -> bool {
+    true
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+use tracing::warn;
+
+/// A token issued by `auth_for_token` and when it stops being accepted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CachedToken {
+    pub jwt: String,
+    pub expires_utc: DateTime<Utc>,
+}
+
+impl CachedToken {
+    /// builds a token from a `TokenReply`, whose expiration is either RFC 3339 or the `Display`
+    /// of a `DateTime<Utc>`
+    pub fn from_reply(jwt: &str, expiration_utc: &str) -> Option<Self> {
+        let expires_utc = match DateTime::parse_from_rfc3339(expiration_utc.trim()) {
+            Ok(expires_utc) => expires_utc.with_timezone(&Utc),
+            Err(_) => {
+                let expiration = expiration_utc.trim().trim_end_matches("UTC").trim();
+                let expires_utc =
+                    NaiveDateTime::parse_from_str(expiration, "%Y-%m-%d %H:%M:%S%.f").ok()?;
+                Utc.from_utc_datetime(&expires_utc)
+            }
+        };
+
+        Some(Self {
+            jwt: jwt.to_string(),
+            expires_utc,
+        })
+    }
+
+    /// if the token expires within `margin_in_seconds` from now
+    pub fn expires_within(&self, margin_in_seconds: i64) -> bool {
+        self.expires_utc - chrono::Duration::seconds(margin_in_seconds) <= Utc::now()
+    }
+}
+
+/// Tokens cached on disk by profile name, so that separate runs of a program can share a login
+#[derive(Debug, Clone)]
+pub struct TokenCache {
+    path: PathBuf,
+    profile_name: String,
+}
+
+impl TokenCache {
+    pub fn new(path: PathBuf, profile_name: &str) -> Self {
+        Self {
+            path,
+            profile_name: profile_name.to_string(),
+        }
+    }
+
+    pub fn load(&self) -> Option<CachedToken> {
+        self.read_all().remove(&self.profile_name)
+    }
+
+    pub fn save(&self, token: &CachedToken) {
+        let mut tokens = self.read_all();
+        tokens.insert(self.profile_name.clone(), token.clone());
+        self.write_all(&tokens);
+    }
+
+    pub fn remove(&self) {
+        let mut tokens = self.read_all();
+        if tokens.remove(&self.profile_name).is_some() {
+            self.write_all(&tokens);
+        }
+    }
+
+    fn read_all(&self) -> HashMap<String, CachedToken> {
+        fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn write_all(&self, tokens: &HashMap<String, CachedToken>) {
+        if let Some(dir) = self.path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+
+        let json = serde_json::to_string_pretty(tokens).unwrap();
+
+        // the cache holds live tokens, so it's created readable by the owner only rather than
+        // opened up to the umask and narrowed after the tokens are written
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let result = options.open(&self.path).and_then(|mut file| {
+            // `mode` only applies to a new file, so narrow one cached by an earlier version too
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                file.set_permissions(fs::Permissions::from_mode(0o600))?;
+            }
+
+            file.write_all(json.as_bytes())
+        });
+
+        if let Err(e) = result {
+            warn!("unable to cache token at {:?}: {e}", self.path);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use rcd_client::client_type::RcdClientType;
+use rcd_client::profile::RcdClientProfiles;
+use rcd_client::token_cache::{CachedToken, TokenCache};
+use std::{env, fs};
+
+#[test]
+/// Reads profiles from a TOML file, filling in defaults for settings that are left out
+fn read_profiles_from_file() {
+    let dir = env::temp_dir().join("rcd_client_read_profiles_from_file");
+    let _ = fs::create_dir_all(&dir);
+    let path = dir.join("profiles.toml");
+
+    let profiles = r#"
+[profiles.local]
+client_type = "grpc"
+grpc_addr_port = "http://127.0.0.1:50051"
+user_name = "tester"
+pw = "123456"
+
+[profiles.web]
+client_type = "http"
+http_addr = "127.0.0.1"
+http_port = 50055
+user_name = "tester"
+pw = "123456"
+timeout_in_seconds = 5
+use_token = false
+"#;
+
+    fs::write(&path, profiles).unwrap();
+
+    let profiles = RcdClientProfiles::load(&path).unwrap();
+    assert_eq!(
+        profiles.names(),
+        vec!["local".to_string(), "web".to_string()]
+    );
+
+    let local = profiles.get("local").unwrap();
+    assert!(matches!(local.client_type, RcdClientType::Grpc));
+    assert_eq!(local.timeout_in_seconds, 60);
+    assert!(local.use_token);
+
+    let web = profiles.get("web").unwrap();
+    assert!(matches!(web.client_type, RcdClientType::Http));
+    assert_eq!(web.http_port, 50055);
+    assert_eq!(web.timeout_in_seconds, 5);
+    assert!(!web.use_token);
+
+    assert!(profiles.get("missing").is_err());
+    assert_eq!(profiles.token_cache_path(), dir.join("tokens.json"));
+}
+
+#[test]
+/// Caches a token by profile name and reads back when it expires
+fn cache_token_by_profile() {
+    let path = env::temp_dir().join("rcd_client_cache_token_by_profile.json");
+    let _ = fs::remove_file(&path);
+
+    let expiration = (chrono::Utc::now() + chrono::Duration::minutes(20)).to_rfc3339();
+    let token = CachedToken::from_reply("abc", &expiration).unwrap();
+    assert!(!token.expires_within(30));
+    assert!(token.expires_within(60 * 60));
+
+    let local = TokenCache::new(path.clone(), "local");
+    let web = TokenCache::new(path, "web");
+
+    local.save(&token);
+    assert_eq!(local.load(), Some(token));
+    assert_eq!(web.load(), None);
+
+    local.remove();
+    assert_eq!(local.load(), None);
+}
+
+#[cfg(unix)]
+#[test]
+/// Keeps the cached tokens readable by the owner only, including in a cache created with looser
+/// permissions by an earlier version
+fn cache_token_readable_by_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = env::temp_dir().join("rcd_client_cache_token_readable_by_owner_only.json");
+    let _ = fs::remove_file(&path);
+
+    let expiration = (chrono::Utc::now() + chrono::Duration::minutes(20)).to_rfc3339();
+    let token = CachedToken::from_reply("abc", &expiration).unwrap();
+    let cache = TokenCache::new(path.clone(), "local");
+    let mode = |path: &std::path::Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+
+    cache.save(&token);
+    assert_eq!(mode(&path), 0o600);
+
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+    cache.save(&token);
+    assert_eq!(mode(&path), 0o600);
+    assert_eq!(cache.load(), Some(token));
+}
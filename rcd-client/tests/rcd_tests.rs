@@ -1,6 +1,6 @@
-use tracing::info;
 use rcd_common::db::DbiConfigSqlite;
 use rcd_enum::database_type::DatabaseType;
+use tracing::info;
 
 use rcd_common::rcd_settings::RcdSettings;
 use rcd_core::dbi::Dbi;
@@ -22,9 +22,13 @@ fn read_settings_from_config() {
         grpc_client_service_addr_port: String::from("127.0.0.1:50051"),
         grpc_data_service_addr_port: String::from(""),
         data_grpc_timeout_in_seconds: 60,
+        max_concurrent_participant_reads: 8,
         client_grpc_timeout_in_seconds: 60,
         http_addr: "".to_string(),
         http_port: 0,
+        postgres_config: None,
+        mysql_config: None,
+        jwt_config: None,
     };
 
     // ACT
@@ -50,9 +54,13 @@ fn configure_backing_db() {
         grpc_client_service_addr_port: String::from("127.0.0.1:50051"),
         grpc_data_service_addr_port: String::from(""),
         data_grpc_timeout_in_seconds: 60,
+        max_concurrent_participant_reads: 8,
         client_grpc_timeout_in_seconds: 60,
         http_addr: "".to_string(),
         http_port: 0,
+        postgres_config: None,
+        mysql_config: None,
+        jwt_config: None,
     };
 
     let cwd = env::current_dir().unwrap();
@@ -90,25 +98,20 @@ fn hash() {
         rcd_db_name: backing_database_name,
     };
 
-    let dbi = Dbi {
-        db_type: DatabaseType::Sqlite,
-        mysql_config: None,
-        postgres_config: None,
-        sqlite_config: Some(config),
-    };
+    let dbi = Dbi::new_with_sqlite(config);
 
-    dbi.configure_rcd_db();
+    dbi.configure_rcd_db().unwrap();
 
     let un = String::from("tester");
     let pw = String::from("1234");
 
     // ACT
-    dbi.create_login(&un, &pw);
-    let has_login = dbi.has_login(&un);
+    dbi.create_login(&un, &pw).unwrap();
+    let has_login = dbi.has_login(&un).unwrap();
 
     info!("test_hash: has_login {}", &has_login);
 
-    let is_valid = dbi.verify_login(&un, &pw);
+    let is_valid = dbi.verify_login(&un, &pw).unwrap();
 
     info!("test_hash: is_valid {}", is_valid);
 
@@ -136,21 +139,16 @@ fn hash_negative() {
         rcd_db_name: backing_database_name.clone(),
     };
 
-    let dbi = Dbi {
-        db_type: DatabaseType::Sqlite,
-        mysql_config: None,
-        postgres_config: None,
-        sqlite_config: Some(config),
-    };
+    let dbi = Dbi::new_with_sqlite(config);
 
-    dbi.configure_rcd_db();
+    dbi.configure_rcd_db().unwrap();
 
     let un = String::from("tester_fail");
     let pw = String::from("1234");
 
     // ACT
-    dbi.create_login(&un, &pw);
-    let has_login = dbi.has_login(&un);
+    dbi.create_login(&un, &pw).unwrap();
+    let has_login = dbi.has_login(&un).unwrap();
 
     info!("test_hash_false: has_login {}", &has_login);
 
@@ -159,15 +157,10 @@ fn hash_negative() {
         rcd_db_name: backing_database_name,
     };
 
-    let dbi = Dbi {
-        db_type: DatabaseType::Sqlite,
-        mysql_config: None,
-        postgres_config: None,
-        sqlite_config: Some(config),
-    };
+    let dbi = Dbi::new_with_sqlite(config);
 
     let wrong_pw = String::from("43210");
-    let is_valid = dbi.verify_login(&un, &wrong_pw);
+    let is_valid = dbi.verify_login(&un, &wrong_pw).unwrap();
 
     info!("test_hash_false: is_valid {}", is_valid);
 
@@ -29,6 +29,21 @@ pub struct CoopDatabaseParticipant {
     pub id: GUID,
     pub http_addr: String,
     pub http_port: u16,
+    /// The protocol version the participant reported when we last authenticated against it, or 0
+    /// if we never have
+    pub protocol_version: u32,
+}
+
+/// A participant being added to a database, before we have sent it a contract
+#[derive(Clone, Debug)]
+pub struct NewCoopDatabaseParticipant {
+    pub alias: String,
+    pub ip4addr: String,
+    pub db_port: u32,
+    pub http_addr: String,
+    pub http_port: u16,
+    /// The participant's id, if it is already known
+    pub id: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -36,5 +51,17 @@ pub struct CoopDatabaseParticipantData {
     pub participant: CoopDatabaseParticipant,
     pub db_name: String,
     pub table_name: String,
-    pub row_data: Vec<(u32, Vec<u8>)>,
+    pub row_data: Vec<CoopDatabaseParticipantRow>,
+}
+
+/// A row the participant holds, as recorded in the host's metadata table
+#[derive(Clone, Debug)]
+pub struct CoopDatabaseParticipantRow {
+    pub row_id: u32,
+    /// The hash we saved for the row
+    pub hash: Vec<u8>,
+    /// If the row has been flagged as out of sync with the host
+    pub is_out_of_sync: bool,
+    /// If the participant has deleted the row
+    pub is_remote_deleted: bool,
 }
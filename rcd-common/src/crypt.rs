@@ -1,5 +1,28 @@
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use sha2::{Digest, Sha256};
+
+/// The version of the byte layout hashed by `calculate_hash_for_row`. If the layout ever
+/// changes this must be bumped and existing partial databases rehashed.
+pub const ROW_HASH_VERSION: u8 = 2;
+
+/// A single value of a row as it is hashed by `calculate_hash_for_row`. Backends hand over
+/// numbers, dates and text as `Text` so that the same row hashes the same way on every backend;
+/// NULLs and binary values keep their own tags so that neither collides with an empty string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowValue {
+    Null,
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+impl RowValue {
+    fn tag(&self) -> u8 {
+        match self {
+            RowValue::Null => 0,
+            RowValue::Text(_) => 1,
+            RowValue::Blob(_) => 2,
+        }
+    }
+}
 
 pub fn hash(
     passwd: &str,
@@ -26,8 +49,70 @@ pub fn verify(hash: [u8; 128], passwd: &str) -> bool {
     }
 }
 
-pub fn calculate_hash_for_struct<T: Hash>(t: &T) -> u64 {
-    let mut s = DefaultHasher::new();
-    t.hash(&mut s);
-    s.finish()
+/// Hashes the values of a row with SHA-256 so that the result is the same across platforms and
+/// Rust versions. The bytes hashed are the `ROW_HASH_VERSION`, the number of values, and then
+/// each value as a one byte type tag, followed for text and blobs by the big-endian `u32` length
+/// and the raw bytes, so that values can't run into each other. Row hashes are stored and sent as
+/// a `u64`, so this is the first 8 bytes of the digest.
+pub fn calculate_hash_for_row(values: &[RowValue]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update([ROW_HASH_VERSION]);
+    hasher.update((values.len() as u32).to_be_bytes());
+
+    for value in values {
+        hasher.update([value.tag()]);
+
+        let bytes: &[u8] = match value {
+            RowValue::Null => continue,
+            RowValue::Text(text) => text.as_bytes(),
+            RowValue::Blob(blob) => blob,
+        };
+
+        hasher.update((bytes.len() as u32).to_be_bytes());
+        hasher.update(bytes);
+    }
+
+    let digest = hasher.finalize();
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(prefix)
+}
+
+#[test]
+fn test_calculate_hash_for_row_golden_values() {
+    // these values are fixed: if one changes, every stored row hash is invalidated and
+    // `ROW_HASH_VERSION` must be bumped
+    assert_eq!(calculate_hash_for_row(&[]), 0x395c_2f55_98a1_643a);
+    assert_eq!(
+        calculate_hash_for_row(&[
+            RowValue::Text(String::from("999")),
+            RowValue::Text(String::from("ASDF")),
+        ]),
+        0xed5b_dc19_c236_8480
+    );
+    assert_eq!(
+        calculate_hash_for_row(&[
+            RowValue::Text(String::from("1")),
+            RowValue::Null,
+            RowValue::Blob(vec![0xde, 0xad, 0xbe, 0xef]),
+        ]),
+        0xf49d_e85e_e41c_1b8c
+    );
+}
+
+#[test]
+fn test_calculate_hash_for_row_distinguishes_null_empty_and_blob() {
+    let null = calculate_hash_for_row(&[RowValue::Null]);
+    let empty_text = calculate_hash_for_row(&[RowValue::Text(String::new())]);
+    let empty_blob = calculate_hash_for_row(&[RowValue::Blob(Vec::new())]);
+
+    assert_ne!(null, empty_text);
+    assert_ne!(null, empty_blob);
+    assert_ne!(empty_text, empty_blob);
+
+    // the blob's bytes are hashed, not dropped
+    assert_ne!(
+        calculate_hash_for_row(&[RowValue::Blob(vec![1])]),
+        calculate_hash_for_row(&[RowValue::Blob(vec![2])])
+    );
 }
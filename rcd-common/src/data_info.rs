@@ -1,3 +1,5 @@
+use crate::db::AffectedRow;
+
 pub struct DataInfo {
     pub db_name: String,
     pub table_name: String,
@@ -5,3 +7,14 @@ pub struct DataInfo {
     pub hash: Option<u64>,
     pub is_deleted: bool,
 }
+
+/// A queued action at the participant that has been accepted or rejected, along with
+/// the rows it changed if it was accepted
+pub struct PendingActionInfo {
+    pub db_name: String,
+    pub table_name: String,
+    pub pending_action_id: u32,
+    pub action: String,
+    pub is_accepted: bool,
+    pub rows: Vec<AffectedRow>,
+}
@@ -4,6 +4,7 @@ use rcd_enum::{
 };
 
 use crate::defaults;
+use serde::{Deserialize, Serialize};
 // objects moved to here from rcdx.dbi
 
 #[derive(Clone, Debug)]
@@ -61,6 +62,9 @@ pub struct CdsHosts {
     pub http_addr: String,
     pub http_port: u32,
     pub status: HostStatus,
+    /// The protocol version the host reported when it last authenticated against us, or 0 if it
+    /// never has
+    pub protocol_version: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +74,23 @@ pub struct PartialDataResult {
     pub data_hash: Option<u64>,
     pub partial_data_status: Option<u32>,
     pub action: Option<PartialDataResultAction>,
+    /// Every row the write touched, since a statement can match more than the one in `row_id`
+    pub affected_rows: Vec<AffectedRow>,
+}
+
+/// A row that was written to in a partial database and its hash afterwards, if it still exists
+#[derive(Debug, Clone)]
+pub struct AffectedRow {
+    pub row_id: u32,
+    pub data_hash: Option<u64>,
+}
+
+/// A row in a partial database whose hash was recomputed
+#[derive(Debug, Clone)]
+pub struct RehashedRow {
+    pub table_name: String,
+    pub row_id: u32,
+    pub hash: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -78,22 +99,31 @@ pub struct DbiConfigSqlite {
     pub rcd_db_name: String,
 }
 
-#[derive(Debug, Clone)]
+/// Settings for a MySQL backing store. Each rcd database is created as its own
+/// MySQL database on the server pointed at by `connection_string` (a `mysql://` url)
+/// or by `host`, `user_name` and `pw`; the rcd system tables live in `rcd_db_name`
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DbiConfigMySql {
     pub user_name: String,
     pub pw: String,
     pub connection_string: String,
     pub host: String,
     pub connect_options: String,
+    pub rcd_db_name: String,
 }
 
-#[derive(Debug, Clone)]
+/// Settings for a Postgres backing store. Each rcd database is created as a
+/// schema in the Postgres database pointed at by `connection_string`
+/// (or `host`, `user_name` and `pw` if no connection string is supplied);
+/// the rcd system tables live in the schema named `rcd_db_name`
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DbiConfigPostgres {
     pub user_name: String,
     pub pw: String,
     pub connection_string: String,
     pub host: String,
     pub connect_options: String,
+    pub rcd_db_name: String,
 }
 
 pub fn get_data_queue_table_name(table_name: &str) -> String {
@@ -107,3 +137,21 @@ pub fn get_metadata_table_name(table_name: &str) -> String {
 pub fn get_data_log_table_name(table_name: &str) -> String {
     format!("{}{}", table_name, defaults::DATA_LOG_TABLE_SUFFIX)
 }
+
+/// Returns true for the tables rcd creates to keep track of a database (and the ones sqlite
+/// keeps for itself), as opposed to the tables a user has created
+pub fn is_reserved_table_name(table_name: &str) -> bool {
+    let table_name = table_name.to_uppercase();
+
+    table_name.starts_with("SQLITE_")
+        || table_name.starts_with("CDS_")
+        || table_name.starts_with("COOP_")
+        || [
+            defaults::DATA_LOG_TABLE_SUFFIX,
+            defaults::DATA_QUEUE_TABLE_SUFFIX,
+            defaults::METADATA_TABLE_SUFFIX,
+            defaults::SHOWS_TABLE_SUFFIX,
+        ]
+        .iter()
+        .any(|suffix| table_name.ends_with(suffix))
+}
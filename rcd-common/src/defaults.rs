@@ -4,4 +4,17 @@ pub const DATA_LOG_TABLE_SUFFIX: &str = "_COOP_DATA_LOG";
 pub const DATA_QUEUE_TABLE_SUFFIX: &str = "_COOP_DATA_QUEUE";
 pub const METADATA_TABLE_SUFFIX: &str = "_COOP_METADATA";
 pub const SHOWS_TABLE_SUFFIX: &str = "_COOP_SHADOWS";
+/// Suffix of the hidden column a cooperative read adds to each table it fills with rows from
+/// participants, which holds the index of the row so it can be traced through to the result
+pub const ROW_KEY_COLUMN_SUFFIX: &str = "_COOP_ROW_KEY";
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The version of the layout of the rcd system database, saved when it is first configured
+pub const RCD_SCHEMA_VERSION: u32 = 2;
+pub const MAX_CONCURRENT_PARTICIPANT_READS: u32 = 8;
+pub const JWT_ALGORITHM: &str = "HS384";
+pub const JWT_TOKEN_LIFETIME_IN_MINUTES: u32 = 20;
+pub const EXPORT_CHUNK_SIZE_IN_BYTES: usize = 1024 * 1024;
+pub const EXPORT_PAGE_SIZE_IN_ROWS: u32 = 1000;
+/// How many times we try to tell a host about a change to its contract before giving up
+pub const NOTIFY_HOST_ATTEMPTS: u32 = 3;
+pub const NOTIFY_HOST_RETRY_DELAY_IN_MS: u64 = 500;
@@ -7,5 +7,6 @@ pub mod defaults;
 pub mod host_info;
 pub mod rcd_settings;
 pub mod save_contract_result;
+pub mod storage_backend;
 pub mod table;
 pub mod user;
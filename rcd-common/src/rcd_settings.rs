@@ -1,5 +1,10 @@
+use crate::{
+    db::{DbiConfigMySql, DbiConfigPostgres},
+    defaults,
+};
 use rcd_enum::database_type::DatabaseType;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents settings for rcd that can be passed in on a test case
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -12,6 +17,50 @@ pub struct RcdSettings {
     pub grpc_data_service_addr_port: String,
     pub client_grpc_timeout_in_seconds: u32,
     pub data_grpc_timeout_in_seconds: u32,
+    /// how many participants a host will fetch rows from at once when reading cooperative tables
+    pub max_concurrent_participant_reads: u32,
     pub http_addr: String,
     pub http_port: u16,
+    pub postgres_config: Option<DbiConfigPostgres>,
+    pub mysql_config: Option<DbiConfigMySql>,
+    pub jwt_config: Option<JwtConfig>,
+}
+
+/// How rcd signs the JWTs it hands out to clients. If this isn't set, tokens are signed with a
+/// random secret that only lasts as long as the process.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct JwtConfig {
+    /// one of HS256, HS384, HS512, RS256, RS384, RS512, ES256, ES384 or EdDSA
+    #[serde(default = "default_jwt_algorithm")]
+    pub algorithm: String,
+    /// the shared secret for the HS algorithms
+    pub secret: Option<String>,
+    /// a file holding the shared secret for the HS algorithms or the PEM encoded private key for
+    /// the others; takes precedence over `secret`
+    pub secret_file: Option<String>,
+    /// the PEM encoded public key for the asymmetric algorithms
+    pub public_key_file: Option<String>,
+    #[serde(default = "default_jwt_token_lifetime")]
+    pub token_lifetime_in_minutes: u32,
+}
+
+// the settings are printed at startup, so keep the secret out of them
+impl fmt::Debug for JwtConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtConfig")
+            .field("algorithm", &self.algorithm)
+            .field("secret", &self.secret.as_ref().map(|_| "<redacted>"))
+            .field("secret_file", &self.secret_file)
+            .field("public_key_file", &self.public_key_file)
+            .field("token_lifetime_in_minutes", &self.token_lifetime_in_minutes)
+            .finish()
+    }
+}
+
+fn default_jwt_algorithm() -> String {
+    defaults::JWT_ALGORITHM.to_string()
+}
+
+fn default_jwt_token_lifetime() -> u32 {
+    defaults::JWT_TOKEN_LIFETIME_IN_MINUTES
 }
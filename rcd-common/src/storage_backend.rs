@@ -0,0 +1,568 @@
+use crate::{
+    coop_database_contract::CoopDatabaseContract,
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, NewCoopDatabaseParticipant,
+    },
+    db::{CdsHosts, PartialDataResult, RehashedRow},
+    host_info::HostInfo,
+    save_contract_result::RcdSaveContractResult,
+    table::Table,
+};
+use chrono::{DateTime, Utc};
+use rcd_enum::{
+    database_type::DatabaseType, deletes_from_host_behavior::DeletesFromHostBehavior,
+    deletes_to_host_behavior::DeletesToHostBehavior, logical_storage_policy::LogicalStoragePolicy,
+    rcd_database_type::RcdDatabaseType, rcd_generate_contract_error::RcdGenerateContractError,
+    remote_delete_behavior::RemoteDeleteBehavior,
+    updates_from_host_behavior::UpdatesFromHostBehavior,
+    updates_to_host_behavior::UpdatesToHostBehavior,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+use rcd_sqlite_log::log_entry::LogEntry;
+use rcdproto::rcdp::{
+    ColumnSchema, Contract, DatabaseSchema, Participant, ParticipantStatus, PendingStatement,
+    PendingWrite, Row, RowValue,
+};
+use std::{fmt::Debug, path::PathBuf};
+
+/// The storage operations that `rcd-core` needs from a backing database. `rcd-sqlite`,
+/// `rcd-mysql` and `rcd-postgres` each implement this, and `Dbi` holds one as a
+/// `Box<dyn RcdStorageBackend>`, so other backends (an in-memory one for tests, for example)
+/// can be handed to `Dbi::new` without changes to `rcd-core`.
+///
+/// A call that can't be completed, such as when the database server can't be reached, returns
+/// an `RcdDbError` rather than a default value, so that callers can tell it apart from an empty
+/// or negative answer.
+pub trait RcdStorageBackend: Debug + Send + Sync {
+    /// The kind of database behind this backend, used to pick the SQL dialect when parsing
+    /// statements
+    fn db_type(&self) -> DatabaseType;
+
+    /// Returns a boxed copy of this backend, so that `Dbi` can be cloned
+    fn box_clone(&self) -> Box<dyn RcdStorageBackend>;
+
+    // Host database: the user databases that an rcd instance is hosting and the
+    // metadata it keeps about rows held by participants
+    fn get_data_hash_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<u64, RcdDbError>;
+
+    /// Hashes each of the rows in the host's own copy of a table, used to reconcile a `Mirror`
+    /// table against the hashes its participants have sent
+    fn get_row_hashes_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<u64>, RcdDbError>;
+
+    fn remove_remote_row_reference_from_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn delete_metadata_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_ids: &[u32],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Saves the new hash for each of the `(row_id, hash)` rows
+    fn update_metadata_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[(u32, u64)],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Flags the rows as no longer matching the participant's copy, e.g. when it ignored a write
+    fn mark_metadata_out_of_sync_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_ids: &[u32],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Keeps the row's metadata as a tombstone, flagged as deleted by the participant
+    fn mark_metadata_remote_deleted_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    fn insert_metadata_into_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+        hash: u64,
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Records a write the participant has queued for review instead of applying
+    fn add_pending_write_at_host(
+        &self,
+        db_name: &str,
+        internal_participant_id: &str,
+        pending_write: &PendingWrite,
+    ) -> Result<bool, RcdDbError>;
+
+    fn remove_pending_write_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        internal_participant_id: &str,
+        pending_action_id: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_pending_writes_at_host(&self, db_name: &str) -> Result<Vec<PendingWrite>, RcdDbError>;
+
+    fn update_participant_accepts_contract(
+        &self,
+        db_name: &str,
+        participant: CoopDatabaseParticipant,
+        participant_message: Participant,
+        accepted_contract_id: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    fn update_participant_rejects_contract(
+        &self,
+        db_name: &str,
+        participant: CoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError>;
+
+    fn add_participant(
+        &self,
+        db_name: &str,
+        participant: NewCoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_database_schema(&self, db_name: &str) -> Result<DatabaseSchema, RcdDbError>;
+
+    fn get_participant_by_alias(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError>;
+
+    fn get_participant_by_id(
+        &self,
+        db_name: &str,
+        participant_id: &str,
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError>;
+
+    fn has_participant(&self, db_name: &str, participant_alias: &str) -> Result<bool, RcdDbError>;
+
+    /// Saves the protocol version the participant reported when we authenticated against it
+    fn update_participant_protocol_version(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_active_contract(&self, db_name: &str) -> Result<CoopDatabaseContract, RcdDbError>;
+
+    fn get_logical_storage_policy(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<LogicalStoragePolicy, RcdDbError>;
+
+    fn set_logical_storage_policy(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        policy: LogicalStoragePolicy,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Returns the columns of a `Shared` table whose values are kept at the participant
+    fn get_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<String>, RcdDbError>;
+
+    /// Replaces the columns of a `Shared` table whose values are kept at the participant
+    fn set_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        column_names: &[String],
+    ) -> Result<bool, RcdDbError>;
+
+    fn has_table(&self, db_name: &str, table_name: &str) -> Result<bool, RcdDbError>;
+
+    fn execute_write_at_host(&self, db_name: &str, cmd: &str) -> Result<usize, RcdDbError>;
+
+    /// Runs the statements against a host database in a single transaction, so that either all
+    /// of them are applied or none are. Returns the total number of rows affected.
+    fn execute_writes_at_host(&self, db_name: &str, cmds: &[String]) -> Result<usize, RcdDbError>;
+
+    fn execute_read_at_host(&self, db_name: &str, cmd: &str) -> Result<Table, RcdDbError>;
+
+    /// Deletes every row of a host table and inserts `rows` in their place, in a single
+    /// transaction. Each value is bound as the type of its column. Returns the number of rows
+    /// inserted.
+    fn replace_rows_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[Row],
+    ) -> Result<usize, RcdDbError>;
+
+    /// Reads the rows of a host table whose key columns hold one of `keys`. Each key has a
+    /// value (with its column) for each key column, which is bound as the type of its column.
+    fn get_rows_at_host_by_key(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        keys: &[Vec<RowValue>],
+    ) -> Result<Table, RcdDbError>;
+
+    /// Runs `cmd` at the host with each of `cooperative_tables` replaced by the rows fetched
+    /// from participants, so that the statement's filters, joins and ordering apply to them.
+    /// Each of those tables also has a `<table>_COOP_ROW_KEY` column holding the index of the
+    /// row in `cooperative_rows`.
+    fn execute_read_at_host_with_cooperative_rows(
+        &self,
+        db_name: &str,
+        cmd: &str,
+        cooperative_tables: &[String],
+        cooperative_rows: &[Row],
+    ) -> Result<Table, RcdDbError>;
+
+    fn has_cooperative_tables(&self, db_name: &str, cmd: &str) -> Result<bool, RcdDbError>;
+
+    fn get_participants_for_table(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<CoopDatabaseParticipantData>, RcdDbError>;
+
+    fn get_participants_for_database(
+        &self,
+        db_name: &str,
+    ) -> Result<Vec<ParticipantStatus>, RcdDbError>;
+
+    fn get_cooperative_tables(&self, db_name: &str, cmd: &str) -> Result<Vec<String>, RcdDbError>;
+
+    fn create_database(&self, db_name: &str) -> Result<(), RcdDbError>;
+
+    fn enable_coooperative_features(&self, db_name: &str) -> Result<(), RcdDbError>;
+
+    fn generate_contract(
+        &self,
+        db_name: &str,
+        desc: &str,
+        remote_delete_behavior: RemoteDeleteBehavior,
+    ) -> Result<bool, RcdGenerateContractError>;
+
+    // Partial database: the `.dbpart` databases this instance holds as a participant
+    // in someone else's contract
+    fn accept_pending_action_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn reject_pending_action_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn get_pending_actions(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        action: &str,
+    ) -> Result<Vec<PendingStatement>, RcdDbError>;
+
+    fn get_data_hash_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<u64, RcdDbError>;
+
+    /// Recomputes the stored hash of every row in the partial database, for after the way row
+    /// hashes are calculated has changed
+    fn rehash_partial_database(&self, db_name: &str) -> Result<Vec<RehashedRow>, RcdDbError>;
+
+    /// Returns the `ROW_HASH_VERSION` the hashes saved in the partial database were calculated
+    /// with, or `None` if it was created before the version was recorded
+    fn get_row_hash_version(&self, db_name: &str) -> Result<Option<u8>, RcdDbError>;
+
+    fn read_row_ids_from_part_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        where_clause: &str,
+    ) -> Result<Vec<u32>, RcdDbError>;
+
+    fn get_row_from_partial_database(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<Row, RcdDbError>;
+
+    fn delete_data_in_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        where_clause: &str,
+        host_id: &str,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn update_data_into_partial_db_queue(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        where_clause: &str,
+        host: &CdsHosts,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn update_data_into_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        host_id: &str,
+        where_clause: &str,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn insert_data_into_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+    ) -> Result<PartialDataResult, RcdDbError>;
+
+    fn create_partial_database_from_contract(
+        &self,
+        contract: &Contract,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_table_id(&self, db_name: &str, table_name: &str) -> Result<String, RcdDbError>;
+
+    fn create_table_in_partial_database(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        schema: Vec<ColumnSchema>,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_db_id(&self, db_name: &str) -> Result<String, RcdDbError>;
+
+    fn create_partial_database(&self, db_name: &str) -> Result<(), RcdDbError>;
+
+    fn execute_write_at_partipant(&self, db_name: &str, cmd: &str) -> Result<usize, RcdDbError>;
+
+    fn execute_read_at_participant(&self, db_name: &str, cmd: &str) -> Result<Table, RcdDbError>;
+
+    // rcd system database: logins, roles, tokens, contracts and cooperating hosts
+    fn login_has_token(&self, login: &str) -> Result<bool, RcdDbError>;
+
+    fn get_last_log_entries(&self, number_of_entries: u32) -> Result<Vec<LogEntry>, RcdDbError>;
+
+    fn revoke_token(&self, jwt: &str) -> Result<bool, RcdDbError>;
+
+    fn get_cooperative_hosts(&self) -> Result<Vec<CdsHosts>, RcdDbError>;
+
+    fn verify_token(&self, token: String) -> Result<bool, RcdDbError>;
+
+    fn delete_expired_tokens(&self) -> Result<(), RcdDbError>;
+
+    fn save_token(
+        &self,
+        login: &str,
+        token: &str,
+        expiration: DateTime<Utc>,
+    ) -> Result<(), RcdDbError>;
+
+    fn get_cds_host_for_part_db(&self, db_name: &str) -> Result<Option<CdsHosts>, RcdDbError>;
+
+    fn get_rcd_db_type(&self, db_name: &str) -> Result<RcdDatabaseType, RcdDbError>;
+
+    fn get_updates_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<UpdatesToHostBehavior, RcdDbError>;
+
+    fn get_deletes_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<DeletesToHostBehavior, RcdDbError>;
+
+    fn get_deletes_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<DeletesFromHostBehavior, RcdDbError>;
+
+    fn get_updates_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<UpdatesFromHostBehavior, RcdDbError>;
+
+    fn change_updates_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn change_deletes_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn change_updates_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn change_deletes_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    /// Returns if changes to the table in the partial database are copied to its data log table
+    fn get_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<bool, RcdDbError>;
+
+    fn set_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        use_data_log: bool,
+    ) -> Result<bool, RcdDbError>;
+
+    fn get_pending_action_ttl(&self, db_name: &str, table_name: &str) -> Result<u32, RcdDbError>;
+
+    fn set_pending_action_ttl(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        ttl_seconds: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn change_host_status_by_id(&self, host_id: &str, status: u32) -> Result<bool, RcdDbError>;
+
+    fn change_host_status_by_name(&self, host_name: &str, status: u32) -> Result<bool, RcdDbError>;
+
+    /// Saves the protocol version a host reported when it authenticated against us, where the
+    /// host is identified by either its id or its name
+    fn update_host_protocol_version(
+        &self,
+        host_id_or_name: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError>;
+
+    fn verify_host_by_id(&self, host_id: &str, token: Vec<u8>) -> Result<bool, RcdDbError>;
+
+    fn verify_host_by_name(&self, host_name: &str, token: Vec<u8>) -> Result<bool, RcdDbError>;
+
+    fn accept_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError>;
+
+    fn reject_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError>;
+
+    fn get_pending_contracts(&self) -> Result<Vec<Contract>, RcdDbError>;
+
+    fn get_accepted_contracts(&self) -> Result<Vec<Contract>, RcdDbError>;
+
+    fn save_contract(&self, contract: Contract) -> Result<RcdSaveContractResult, RcdDbError>;
+
+    fn has_role_name(&self, role_name: &str) -> Result<bool, RcdDbError>;
+
+    fn add_login_to_role(&self, login: &str, role_name: &str) -> Result<(), RcdDbError>;
+
+    fn login_is_in_role(&self, login: &str, role_name: &str) -> Result<bool, RcdDbError>;
+
+    fn create_login(&self, login: &str, pw: &str) -> Result<(), RcdDbError>;
+
+    fn get_database_names(&self) -> Result<Vec<String>, RcdDbError>;
+
+    fn has_login(&self, login: &str) -> Result<bool, RcdDbError>;
+
+    fn rcd_get_host_info(&self) -> Result<Option<HostInfo>, RcdDbError>;
+
+    fn get_rcd_schema_version(&self) -> Result<u32, RcdDbError>;
+
+    fn rcd_generate_host_info(&self, host_name: &str) -> Result<(), RcdDbError>;
+
+    fn if_rcd_host_info_exists(&self) -> Result<bool, RcdDbError>;
+
+    fn configure_admin_hash(&self, login: &str, hash: Vec<u8>) -> Result<(), RcdDbError>;
+
+    fn configure_admin(&self, login: &str, pw: &str) -> Result<(), RcdDbError>;
+
+    fn verify_login(&self, login: &str, pw: &str) -> Result<bool, RcdDbError>;
+
+    fn configure_rcd_db(&self) -> Result<(), RcdDbError>;
+
+    // Encryption at rest: keys are supplied by clients and only held in memory
+
+    /// Encrypts a host or partial database that is about to be created with `key`
+    fn set_database_key(
+        &self,
+        db_name: &str,
+        db_type: RcdDatabaseType,
+        key: &str,
+    ) -> Result<(), RcdDbError>;
+
+    /// Forgets a key set with `set_database_key` for a database that was never created, so that
+    /// a failed create doesn't leave the name marked as encrypted
+    fn clear_database_key(&self, db_name: &str, db_type: RcdDatabaseType)
+        -> Result<(), RcdDbError>;
+
+    /// Checks `key` against an existing encrypted database and keeps it for later requests
+    fn unlock_database(&self, db_name: &str, key: &str) -> Result<(), RcdDbError>;
+
+    /// Returns `RcdDbError::DatabaseLocked` if the database is encrypted and its key hasn't
+    /// been supplied
+    fn check_database_key(&self, db_name: &str) -> Result<(), RcdDbError>;
+
+    /// Writes a consistent copy of a host or partial database to a new file and returns its
+    /// path, for backends that keep each database in a file of its own. The caller removes
+    /// the copy once it is done with it.
+    fn snapshot_database(&self, db_name: &str) -> Result<PathBuf, RcdDbError>;
+
+    /// Counts the rows of a host or partial database's own tables, leaving out the tables rcd
+    /// keeps for itself
+    fn count_user_rows(&self, db_name: &str) -> Result<u64, RcdDbError>;
+}
+
+impl Clone for Box<dyn RcdStorageBackend> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
@@ -53,6 +53,19 @@ pub struct Column {
 }
 
 impl Column {
+    pub fn to_cdata_column_schema(&self) -> rcdproto::rcdp::ColumnSchema {
+        rcdproto::rcdp::ColumnSchema {
+            column_name: self.name.to_string(),
+            column_type: self.data_type_to_enum_u32(),
+            column_length: self.data_type_len(),
+            column_id: GUID::rand().to_string(),
+            is_nullable: self.is_nullable,
+            ordinal: self.idx as u32,
+            table_id: GUID::rand().to_string(),
+            is_primary_key: self.is_primary_key,
+        }
+    }
+
     pub fn data_type_to_enum_u32(&self) -> u32 {
         let ct = ColumnType::try_parse(&self.data_type).unwrap();
         ColumnType::to_u32(ct)
@@ -154,16 +167,7 @@ impl Table {
             for t_val in &t_row.vals {
                 let t_col_item = &t_val.col;
 
-                let c_col_schema_item = rcdproto::rcdp::ColumnSchema {
-                    column_name: t_col_item.name.to_string(),
-                    column_type: t_col_item.data_type_to_enum_u32(),
-                    column_length: t_col_item.data_type_len(),
-                    column_id: GUID::rand().to_string(),
-                    is_nullable: t_col_item.is_nullable,
-                    ordinal: t_col_item.idx as u32,
-                    table_id: GUID::rand().to_string(),
-                    is_primary_key: t_col_item.is_primary_key,
-                };
+                let c_col_schema_item = t_col_item.to_cdata_column_schema();
 
                 let mut c_bin_data = &t_val.data.as_ref().unwrap().data_byte;
                 let c_str_data = &t_val.data.as_ref().unwrap().data_string;
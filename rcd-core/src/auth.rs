@@ -1,30 +1,231 @@
 use chrono::{DateTime, Duration, Utc};
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use rcd_common::{defaults, rcd_settings::JwtConfig};
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, str::FromStr, sync::OnceLock};
+use thiserror::Error;
 
-use hmac::{Hmac, Mac};
-use jwt::{AlgorithmType, Header, SignWithKey, Token};
-use sha2::Sha384;
-use std::collections::BTreeMap;
-
-pub fn create_jwt(host_name: &str, login: &str) -> (String, DateTime<Utc>) {
-    // this duration should be a config item
-    let expiration = Utc::now() + Duration::minutes(20);
-    let exp_string = expiration.to_rfc3339();
-
-    // this secret should be stored in a config file: "rcd_item"
-    let key: Hmac<Sha384> = Hmac::new_from_slice(b"rcd_item").unwrap();
-    let header = Header {
-        algorithm: AlgorithmType::Hs384,
-        ..Default::default()
+#[derive(Error, Debug)]
+pub enum RcdJwtError {
+    #[error("Unsupported JWT algorithm: `{0}`")]
+    UnsupportedAlgorithm(String),
+    #[error("Missing JWT setting: `{0}`")]
+    MissingSetting(String),
+    #[error("Unable to read JWT key file `{0}`: {1}")]
+    KeyFile(String, String),
+    #[error("Invalid JWT key: `{0}`")]
+    InvalidKey(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    sub: String,
+    iss: String,
+    iat: i64,
+    exp: i64,
+}
+
+/// Signs the JWTs rcd hands out to clients and checks the ones it is given back
+#[derive(Clone)]
+pub struct JwtAuth {
+    algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    token_lifetime: Duration,
+}
+
+impl fmt::Debug for JwtAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JwtAuth")
+            .field("algorithm", &self.algorithm)
+            .field("token_lifetime", &self.token_lifetime)
+            .finish()
+    }
+}
+
+impl Default for JwtAuth {
+    /// Signs with HS384 and a random secret that is shared by everything in this process, so
+    /// tokens stop verifying once it restarts
+    fn default() -> Self {
+        static SECRET: OnceLock<Vec<u8>> = OnceLock::new();
+
+        let secret = SECRET.get_or_init(|| {
+            sodiumoxide::init().unwrap();
+            sodiumoxide::randombytes::randombytes(64)
+        });
+
+        Self {
+            algorithm: Algorithm::HS384,
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            token_lifetime: Duration::minutes(defaults::JWT_TOKEN_LIFETIME_IN_MINUTES.into()),
+        }
+    }
+}
+
+impl JwtAuth {
+    pub fn from_config(config: &JwtConfig) -> Result<Self, RcdJwtError> {
+        let algorithm = Algorithm::from_str(&config.algorithm)
+            .map_err(|_| RcdJwtError::UnsupportedAlgorithm(config.algorithm.clone()))?;
+
+        let (encoding_key, decoding_key) = match algorithm {
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+                let secret = match &config.secret_file {
+                    Some(path) => read_key_file(path)?,
+                    None => config
+                        .secret
+                        .clone()
+                        .ok_or_else(|| RcdJwtError::MissingSetting("secret".to_string()))?
+                        .into_bytes(),
+                };
+
+                (
+                    EncodingKey::from_secret(&secret),
+                    DecodingKey::from_secret(&secret),
+                )
+            }
+            _ => {
+                let private_key = match &config.secret_file {
+                    Some(path) => read_key_file(path)?,
+                    None => return Err(RcdJwtError::MissingSetting("secret_file".to_string())),
+                };
+
+                let public_key = match &config.public_key_file {
+                    Some(path) => read_key_file(path)?,
+                    None => return Err(RcdJwtError::MissingSetting("public_key_file".to_string())),
+                };
+
+                let keys = match algorithm {
+                    Algorithm::ES256 | Algorithm::ES384 => EncodingKey::from_ec_pem(&private_key)
+                        .and_then(|e| Ok((e, DecodingKey::from_ec_pem(&public_key)?))),
+                    Algorithm::EdDSA => EncodingKey::from_ed_pem(&private_key)
+                        .and_then(|e| Ok((e, DecodingKey::from_ed_pem(&public_key)?))),
+                    _ => EncodingKey::from_rsa_pem(&private_key)
+                        .and_then(|e| Ok((e, DecodingKey::from_rsa_pem(&public_key)?))),
+                };
+
+                keys.map_err(|e| RcdJwtError::InvalidKey(e.to_string()))?
+            }
+        };
+
+        Ok(Self {
+            algorithm,
+            encoding_key,
+            decoding_key,
+            token_lifetime: Duration::minutes(config.token_lifetime_in_minutes.into()),
+        })
+    }
+
+    /// Returns a token for the login and when it expires
+    pub fn create_jwt(&self, host_name: &str, login: &str) -> (String, DateTime<Utc>) {
+        let issued = Utc::now();
+        let expiration = issued + self.token_lifetime;
+
+        let claims = Claims {
+            sub: login.to_string(),
+            iss: host_name.to_string(),
+            iat: issued.timestamp(),
+            exp: expiration.timestamp(),
+        };
+
+        let token = encode(&Header::new(self.algorithm), &claims, &self.encoding_key).unwrap();
+
+        (token, expiration)
+    }
+
+    /// Checks that the token was signed with our key and hasn't expired
+    pub fn verify_jwt(&self, token: &str) -> bool {
+        let validation = Validation::new(self.algorithm);
+        decode::<Claims>(token, &self.decoding_key, &validation).is_ok()
+    }
+}
+
+fn read_key_file(path: &str) -> Result<Vec<u8>, RcdJwtError> {
+    fs::read(path).map_err(|e| RcdJwtError::KeyFile(path.to_string(), e.to_string()))
+}
+
+#[test]
+fn test_verify_jwt_accepts_own_token() {
+    let auth = JwtAuth::default();
+    let (token, _) = auth.create_jwt("host", "tester");
+    assert!(auth.verify_jwt(&token));
+}
+
+#[test]
+fn test_verify_jwt_rejects_forged_token() {
+    let auth = JwtAuth::default();
+    let (token, _) = auth.create_jwt("host", "tester");
+
+    // swap in claims for another login but keep the original signature
+    let parts: Vec<&str> = token.split('.').collect();
+    let (forged, _) = auth.create_jwt("host", "admin");
+    let forged_claims = forged.split('.').nth(1).unwrap();
+    let forged = format!("{}.{}.{}", parts[0], forged_claims, parts[2]);
+    assert!(!auth.verify_jwt(&forged));
+
+    // an unsigned token
+    let unsigned = format!("eyJhbGciOiJub25lIiwidHlwIjoiSldUIn0.{}.", parts[1]);
+    assert!(!auth.verify_jwt(&unsigned));
+
+    assert!(!auth.verify_jwt("not a token"));
+}
+
+#[test]
+fn test_verify_jwt_rejects_expired_token() {
+    // well past the default leeway of a minute
+    let auth = JwtAuth {
+        token_lifetime: Duration::minutes(-5),
+        ..JwtAuth::default()
+    };
+
+    let (token, expiration) = auth.create_jwt("host", "tester");
+    assert!(expiration < Utc::now());
+    assert!(!auth.verify_jwt(&token));
+}
+
+#[test]
+fn test_verify_jwt_rejects_token_signed_with_another_key() {
+    let config = |secret: &str| JwtConfig {
+        algorithm: "HS256".to_string(),
+        secret: Some(secret.to_string()),
+        secret_file: None,
+        public_key_file: None,
+        token_lifetime_in_minutes: 20,
     };
 
-    let mut claims = BTreeMap::new();
+    let ours = JwtAuth::from_config(&config("our secret")).unwrap();
+    let theirs = JwtAuth::from_config(&config("their secret")).unwrap();
+
+    let (token, _) = theirs.create_jwt("host", "tester");
+    assert!(!ours.verify_jwt(&token));
+    assert!(theirs.verify_jwt(&token));
+}
+
+#[test]
+fn test_from_config_rejects_bad_config() {
+    let mut config = JwtConfig {
+        algorithm: "HS999".to_string(),
+        secret: Some("secret".to_string()),
+        secret_file: None,
+        public_key_file: None,
+        token_lifetime_in_minutes: 20,
+    };
 
-    claims.insert("sub", login);
-    claims.insert("iss", host_name);
-    claims.insert("exp", &exp_string);
+    assert!(matches!(
+        JwtAuth::from_config(&config),
+        Err(RcdJwtError::UnsupportedAlgorithm(_))
+    ));
 
-    let token = Token::new(header, claims).sign_with_key(&key).unwrap();
-    let token_str = token.as_str();
+    config.algorithm = "HS256".to_string();
+    config.secret = None;
+    assert!(matches!(
+        JwtAuth::from_config(&config),
+        Err(RcdJwtError::MissingSetting(_))
+    ));
 
-    (token_str.to_string(), expiration)
+    config.secret_file = Some("/no/such/key/file".to_string());
+    assert!(matches!(
+        JwtAuth::from_config(&config),
+        Err(RcdJwtError::KeyFile(..))
+    ));
 }
@@ -1,16 +1,22 @@
 use rcd_common::{
     coop_database_contract::CoopDatabaseContract,
     coop_database_participant::{CoopDatabaseParticipant, CoopDatabaseParticipantData},
-    data_info::DataInfo,
+    data_info::{DataInfo, PendingActionInfo},
     db::CdsHosts,
     host_info::HostInfo,
     save_contract_result::RcdSaveContractResult,
 };
+use rcd_enum::contract_status::ContractStatus;
 use rcdproto::rcdp::{
-    Contract, DatabaseSchema, DeleteDataResult, GetRowFromPartialDatabaseResult, InsertDataResult,
-    UpdateDataResult,
+    Contract, DatabaseSchema, DeleteDataResult, GetRowFromPartialDatabaseResult,
+    GetRowsFromPartialDatabaseResult, InsertDataResult, RcdError, TryAuthResult, UpdateDataResult,
+};
+use rcdproto::{
+    MIN_SUPPORTED_PROTOCOL_VERSION, PROTOCOL_VERSION, UNKNOWN_PROTOCOL_VERSION,
+    UNVERSIONED_PROTOCOL_VERSION,
 };
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use crate::{remote_grpc::RemoteGrpc, remote_http::RemoteHttp};
 
@@ -40,6 +46,57 @@ impl RcdCommunication {
     }
 }
 
+/// Returns an error if we no longer support the protocol version a peer reported during
+/// `TryAuth`. Each side only checks the other against its own minimum: a peer on a newer version
+/// is the one to refuse us if it has dropped support for ours. A peer that has not reported a
+/// version is taken to speak `UNVERSIONED_PROTOCOL_VERSION`.
+pub fn check_protocol_version(peer_version: u32) -> Result<(), RcdError> {
+    check_protocol_version_against(peer_version, MIN_SUPPORTED_PROTOCOL_VERSION)
+}
+
+fn check_protocol_version_against(peer_version: u32, min_version: u32) -> Result<(), RcdError> {
+    let is_unknown = peer_version == UNKNOWN_PROTOCOL_VERSION;
+
+    let effective_version = if is_unknown {
+        UNVERSIONED_PROTOCOL_VERSION
+    } else {
+        peer_version
+    };
+
+    if effective_version >= min_version {
+        return Ok(());
+    }
+
+    if is_unknown {
+        return Err(RcdError {
+            number: 0,
+            message: format!(
+                "peer has not reported a protocol version, this instance supports {min_version} through {PROTOCOL_VERSION}"
+            ),
+            help: String::from("authenticate against the peer so that it reports its version"),
+        });
+    }
+
+    Err(RcdError {
+        number: 0,
+        message: format!(
+            "peer speaks protocol version {peer_version}, this instance supports {min_version} through {PROTOCOL_VERSION}"
+        ),
+        help: String::from("upgrade the peer, then authenticate against it again"),
+    })
+}
+
+/// Logs and returns the error if we should refuse to talk to the named peer
+fn check_peer_protocol_version(peer: &str, peer_version: u32) -> Result<(), RcdError> {
+    let result = check_protocol_version(peer_version);
+
+    if let Err(e) = &result {
+        warn!("refusing to call {peer}: {}", e.message);
+    }
+
+    result
+}
+
 #[derive(Debug, Clone)]
 pub struct RcdRemoteDbClient {
     pub comm_type: RcdCommunication,
@@ -52,7 +109,7 @@ impl RcdRemoteDbClient {
         &self,
         participant: CoopDatabaseParticipant,
         own_host_info: &HostInfo,
-    ) -> bool {
+    ) -> TryAuthResult {
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -77,6 +134,14 @@ impl RcdRemoteDbClient {
         contract: CoopDatabaseContract,
         db_schema: DatabaseSchema,
     ) -> RcdSaveContractResult {
+        if check_peer_protocol_version(&participant.alias, participant.protocol_version).is_err() {
+            return RcdSaveContractResult {
+                is_successful: false,
+                contract_status: ContractStatus::Unknown,
+                participant_information: None,
+            };
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -102,6 +167,10 @@ impl RcdRemoteDbClient {
         table_name: &str,
         row_id: u32,
     ) -> bool {
+        if check_peer_protocol_version(&host.host_name, host.protocol_version).is_err() {
+            return false;
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -128,6 +197,18 @@ impl RcdRemoteDbClient {
         sql: &str,
         where_clause: &str,
     ) -> DeleteDataResult {
+        if let Err(e) =
+            check_peer_protocol_version(&participant.alias, participant.protocol_version)
+        {
+            return DeleteDataResult {
+                authentication_result: None,
+                is_successful: false,
+                message: e.message,
+                rows: Vec::new(),
+                pending_action_id: 0,
+            };
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -168,6 +249,19 @@ impl RcdRemoteDbClient {
         sql: &str,
         where_clause: &str,
     ) -> UpdateDataResult {
+        if let Err(e) =
+            check_peer_protocol_version(&participant.alias, participant.protocol_version)
+        {
+            return UpdateDataResult {
+                authentication_result: None,
+                is_successful: false,
+                message: e.message,
+                rows: Vec::new(),
+                update_status: 0,
+                pending_action_id: 0,
+            };
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -207,6 +301,18 @@ impl RcdRemoteDbClient {
         table_name: &str,
         sql: &str,
     ) -> InsertDataResult {
+        if let Err(e) =
+            check_peer_protocol_version(&participant.alias, participant.protocol_version)
+        {
+            return InsertDataResult {
+                authentication_result: None,
+                is_successful: false,
+                data_hash: 0,
+                message: e.message,
+                row_id: 0,
+            };
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -230,6 +336,16 @@ impl RcdRemoteDbClient {
         own_host_info: HostInfo,
         row_id: u32,
     ) -> GetRowFromPartialDatabaseResult {
+        let peer = &participant.participant;
+        if let Err(e) = check_peer_protocol_version(&peer.alias, peer.protocol_version) {
+            return GetRowFromPartialDatabaseResult {
+                authentication_result: None,
+                is_successful: false,
+                result_message: e.message,
+                row: None,
+            };
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -247,12 +363,49 @@ impl RcdRemoteDbClient {
         };
     }
 
+    pub async fn get_rows_from_participant(
+        &self,
+        participant: CoopDatabaseParticipantData,
+        own_host_info: HostInfo,
+        row_ids: Vec<u32>,
+    ) -> GetRowsFromPartialDatabaseResult {
+        let peer = &participant.participant;
+        if let Err(e) = check_peer_protocol_version(&peer.alias, peer.protocol_version) {
+            return GetRowsFromPartialDatabaseResult {
+                authentication_result: None,
+                is_successful: false,
+                result_message: e.message,
+                rows: Vec::new(),
+            };
+        }
+
+        match self.comm_type {
+            RcdCommunication::Unknown => todo!(),
+            RcdCommunication::Grpc => {
+                return self
+                    .grpc()
+                    .get_rows_from_participant(participant, own_host_info, row_ids)
+                    .await;
+            }
+            RcdCommunication::Http => {
+                return self
+                    .http()
+                    .get_rows_from_participant(participant, own_host_info, row_ids)
+                    .await;
+            }
+        };
+    }
+
     pub async fn notify_host_of_updated_hash(
         &self,
         host: &CdsHosts,
         own_host_info: &HostInfo,
         data_info: &DataInfo,
     ) -> bool {
+        if check_peer_protocol_version(&host.host_name, host.protocol_version).is_err() {
+            return false;
+        }
+
         match self.comm_type {
             RcdCommunication::Unknown => todo!(),
             RcdCommunication::Grpc => {
@@ -270,6 +423,60 @@ impl RcdRemoteDbClient {
         };
     }
 
+    pub async fn notify_host_of_pending_action(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        pending_action_info: &PendingActionInfo,
+    ) -> bool {
+        if check_peer_protocol_version(&host.host_name, host.protocol_version).is_err() {
+            return false;
+        }
+
+        match self.comm_type {
+            RcdCommunication::Unknown => todo!(),
+            RcdCommunication::Grpc => {
+                return self
+                    .grpc()
+                    .notify_host_of_pending_action(host, own_host_info, pending_action_info)
+                    .await;
+            }
+            RcdCommunication::Http => {
+                return self
+                    .http()
+                    .notify_host_of_pending_action(host, own_host_info, pending_action_info)
+                    .await;
+            }
+        };
+    }
+
+    pub async fn notify_host_of_inserted_row(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        data_info: &DataInfo,
+    ) -> bool {
+        if check_peer_protocol_version(&host.host_name, host.protocol_version).is_err() {
+            return false;
+        }
+
+        match self.comm_type {
+            RcdCommunication::Unknown => todo!(),
+            RcdCommunication::Grpc => {
+                return self
+                    .grpc()
+                    .notify_host_of_inserted_row(host, own_host_info, data_info)
+                    .await;
+            }
+            RcdCommunication::Http => {
+                return self
+                    .http()
+                    .notify_host_of_inserted_row(host, own_host_info, data_info)
+                    .await;
+            }
+        };
+    }
+
     pub async fn notify_host_of_acceptance_of_contract(
         &self,
         accepted_contract: &Contract,
@@ -292,6 +499,28 @@ impl RcdRemoteDbClient {
         };
     }
 
+    pub async fn notify_host_of_rejection_of_contract(
+        &self,
+        rejected_contract: &Contract,
+        own_host_info: &HostInfo,
+    ) -> Result<bool, String> {
+        match self.comm_type {
+            RcdCommunication::Unknown => todo!(),
+            RcdCommunication::Grpc => {
+                return self
+                    .grpc()
+                    .notify_host_of_rejection_of_contract(rejected_contract, own_host_info)
+                    .await;
+            }
+            RcdCommunication::Http => {
+                return self
+                    .http()
+                    .notify_host_of_rejection_of_contract(rejected_contract, own_host_info)
+                    .await;
+            }
+        };
+    }
+
     fn grpc(&self) -> RemoteGrpc {
         return self.grpc.as_ref().unwrap().clone();
     }
@@ -300,3 +529,32 @@ impl RcdRemoteDbClient {
         return self.http.as_ref().unwrap().clone();
     }
 }
+
+#[test]
+fn test_check_protocol_version_accepts_supported_versions() {
+    assert!(check_protocol_version(PROTOCOL_VERSION).is_ok());
+    assert!(check_protocol_version(MIN_SUPPORTED_PROTOCOL_VERSION).is_ok());
+    assert!(check_protocol_version(PROTOCOL_VERSION + 1).is_ok());
+}
+
+#[test]
+fn test_check_protocol_version_rejects_unsupported_versions() {
+    let result = check_protocol_version_against(1, 2);
+    assert!(result.unwrap_err().message.contains("protocol version 1"));
+
+    assert!(check_protocol_version_against(2, 2).is_ok());
+}
+
+#[test]
+fn test_check_protocol_version_treats_unknown_as_unversioned() {
+    // builds from before versions were exchanged speak version 1, which we still support
+    assert!(check_protocol_version(UNKNOWN_PROTOCOL_VERSION).is_ok());
+
+    // and are refused along with version 1 once support for it is dropped
+    let min_version = UNVERSIONED_PROTOCOL_VERSION + 1;
+    let result = check_protocol_version_against(UNKNOWN_PROTOCOL_VERSION, min_version);
+    assert!(result
+        .unwrap_err()
+        .message
+        .contains("has not reported a protocol version"));
+}
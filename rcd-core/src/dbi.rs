@@ -1,14 +1,21 @@
 use ::rcd_enum::rcd_database_type::RcdDatabaseType;
 use chrono::{DateTime, Utc};
-use tracing::trace;
 use rcd_common::{
     coop_database_contract::CoopDatabaseContract,
-    coop_database_participant::{CoopDatabaseParticipant, CoopDatabaseParticipantData},
-    db::{CdsHosts, DbiConfigMySql, DbiConfigPostgres, DbiConfigSqlite, PartialDataResult},
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, NewCoopDatabaseParticipant,
+    },
+    db::{
+        CdsHosts, DbiConfigMySql, DbiConfigPostgres, DbiConfigSqlite, PartialDataResult,
+        RehashedRow,
+    },
     host_info::HostInfo,
     save_contract_result::RcdSaveContractResult,
     table::Table,
 };
+use tracing::{debug, trace};
+
+pub use rcd_common::storage_backend::RcdStorageBackend;
 
 use rcd_enum::{
     contract_status::ContractStatus, database_type::DatabaseType,
@@ -21,152 +28,166 @@ use rcd_enum::{
 };
 
 use rcd_error::rcd_db_error::RcdDbError;
-use rcd_sqlite::sqlite::{self};
+use rcd_mysql::mysql::backend::MySqlBackend;
+use rcd_postgres::postgres::backend::PostgresBackend;
+use rcd_sqlite::sqlite::backend::SqliteBackend;
 use rcd_sqlite_log::log_entry::LogEntry;
 use rcdproto::rcdp::{
-    ColumnSchema, Contract, DatabaseSchema, Participant, ParticipantStatus, PendingStatement, Row,
-    TokenReply,
+    ColumnSchema, Contract, DatabaseSchema, Participant, ParticipantStatus, PendingStatement,
+    PendingWrite, Row, RowValue, TokenReply,
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{Arc, Mutex},
 };
-use rusqlite::{Connection, Error};
 
-use crate::auth;
+use crate::auth::JwtAuth;
 
 #[derive(Debug, Clone)]
-/// Database Interface: an abstraction over the underlying database layer. Supports:
+/// Database Interface: an abstraction over the underlying database layer. Calls are handed to
+/// an `RcdStorageBackend`; the backends that ship with rcd are:
 /// - Sqlite
 /// - MySQL
 /// - Postgres
-/// - SQL Server
 pub struct Dbi {
-    pub db_type: DatabaseType,
-    pub mysql_config: Option<DbiConfigMySql>,
-    pub postgres_config: Option<DbiConfigPostgres>,
-    pub sqlite_config: Option<DbiConfigSqlite>,
+    backend: Box<dyn RcdStorageBackend>,
+    jwt: JwtAuth,
+    /// The fingerprint of the participants' hashes that the host's copy of each `Mirror` table
+    /// last matched, by database and table name. Shared between clones, and forgotten for a
+    /// database whenever the host writes to it.
+    verified_mirrors: Arc<Mutex<HashMap<(String, String), u64>>>,
 }
 
 impl Dbi {
-    pub fn auth_for_token(&self, login: &str, pw: &str) -> TokenReply {
+    pub fn new(backend: Box<dyn RcdStorageBackend>) -> Self {
+        Self {
+            backend,
+            jwt: JwtAuth::default(),
+            verified_mirrors: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Signs and verifies tokens with `jwt` instead of the per-process default
+    pub fn with_jwt_auth(mut self, jwt: JwtAuth) -> Self {
+        self.jwt = jwt;
+        self
+    }
+
+    pub fn new_with_sqlite(config: DbiConfigSqlite) -> Self {
+        Self::new(Box::new(SqliteBackend::new(config)))
+    }
+
+    pub fn new_with_mysql(config: DbiConfigMySql) -> Self {
+        Self::new(Box::new(MySqlBackend::new(config)))
+    }
+
+    pub fn new_with_postgres(config: DbiConfigPostgres) -> Self {
+        Self::new(Box::new(PostgresBackend::new(config)))
+    }
+
+    /// Issues a token to the login. A login can hold a token for each of its clients, so only
+    /// the expired tokens and the token presented with the request, which the new one replaces,
+    /// are revoked.
+    pub fn auth_for_token(
+        &self,
+        login: &str,
+        pw: &str,
+        presented_jwt: &str,
+    ) -> Result<TokenReply, RcdDbError> {
         let mut is_authorized = false;
         let mut jwt = String::from("");
         let mut expiration_utc = String::from("");
 
-        if self.verify_login(login, pw) {
+        if self.verify_login(login, pw)? {
             is_authorized = true;
 
-            if !self.login_has_token(login) {
-                let token_data = self.create_token_for_login(login);
-                jwt = token_data.0;
-                expiration_utc = token_data.1.to_string();
-            }
+            let token_data = self.refresh_token_for_login(login, presented_jwt)?;
+            jwt = token_data.0;
+            expiration_utc = token_data.1.to_string();
         }
 
-        TokenReply {
+        Ok(TokenReply {
             is_successful: is_authorized,
             expiration_utc,
             jwt,
-        }
+        })
     }
 
-    pub fn login_has_token(&self, login: &str) -> bool {
-        self.delete_expired_tokens();
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::login_has_token(login, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
+    /// Issues another token to an authenticated login, revoking the expired tokens and the
+    /// token it presented, if any
+    pub fn refresh_token_for_login(
+        &self,
+        login: &str,
+        presented_jwt: &str,
+    ) -> Result<(String, DateTime<Utc>), RcdDbError> {
+        self.delete_expired_tokens()?;
+
+        let token_data = self.create_token_for_login(login)?;
+
+        if !presented_jwt.is_empty() && self.revoke_token(presented_jwt)? {
+            debug!("revoked the token presented by login {login}");
         }
+
+        Ok(token_data)
     }
 
-    pub fn get_last_log_entries(&self, number_of_entries: u32) -> Vec<LogEntry> {
-        self.delete_expired_tokens();
-        match self.db_type {
-            DatabaseType::Sqlite => rcd_sqlite::sqlite::get_last_log_entries(
-                number_of_entries,
-                &self.get_sqlite_settings(),
-            ),
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn login_has_token(&self, login: &str) -> Result<bool, RcdDbError> {
+        self.delete_expired_tokens()?;
+        self.backend.login_has_token(login)
     }
 
-    pub fn revoke_token(&self, jwt: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::revoke_token(jwt, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_last_log_entries(
+        &self,
+        number_of_entries: u32,
+    ) -> Result<Vec<LogEntry>, RcdDbError> {
+        self.delete_expired_tokens()?;
+        self.backend.get_last_log_entries(number_of_entries)
     }
 
-    pub fn create_token_for_login(&self, login: &str) -> (String, DateTime<Utc>) {
-        let host_info = self.rcd_get_host_info().expect("no host info is set");
-        let token_data = auth::create_jwt(&host_info.name, login);
-        self.save_token(login, &token_data.0, token_data.1);
-        token_data
+    pub fn revoke_token(&self, jwt: &str) -> Result<bool, RcdDbError> {
+        self.backend.revoke_token(jwt)
     }
 
-    pub fn get_cooperative_hosts(&self) -> Vec<CdsHosts> {
-        self.delete_expired_tokens();
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::get_cooperative_hosts(&settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn create_token_for_login(
+        &self,
+        login: &str,
+    ) -> Result<(String, DateTime<Utc>), RcdDbError> {
+        let host_info = self.get_host_info_or_err()?;
+        let token_data = self.jwt.create_jwt(&host_info.name, login);
+        self.save_token(login, &token_data.0, token_data.1)?;
+        Ok(token_data)
     }
 
-    pub fn verify_token(&self, token: String) -> bool {
-        self.delete_expired_tokens();
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::verify_token(&token, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_cooperative_hosts(&self) -> Result<Vec<CdsHosts>, RcdDbError> {
+        self.delete_expired_tokens()?;
+        self.backend.get_cooperative_hosts()
     }
 
-    pub fn delete_expired_tokens(&self) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::delete_expired_tokens(&settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
+    pub fn verify_token(&self, token: String) -> Result<bool, RcdDbError> {
+        self.delete_expired_tokens()?;
+
+        // a token that no longer verifies (e.g. the signing key was changed) is dropped so that
+        // the login can be issued a new one
+        if !self.jwt.verify_jwt(&token) {
+            self.revoke_token(&token)?;
+            return Ok(false);
         }
+
+        self.backend.verify_token(token)
     }
 
-    pub fn save_token(&self, login: &str, token: &str, expiration: DateTime<Utc>) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::rcd_db::save_token(login, token, expiration, &settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn delete_expired_tokens(&self) -> Result<(), RcdDbError> {
+        self.backend.delete_expired_tokens()
+    }
+
+    pub fn save_token(
+        &self,
+        login: &str,
+        token: &str,
+        expiration: DateTime<Utc>,
+    ) -> Result<(), RcdDbError> {
+        self.backend.save_token(login, token, expiration)
     }
 
     pub fn accept_pending_action_at_participant(
@@ -174,19 +195,21 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         row_id: u32,
-    ) -> PartialDataResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                rcd_sqlite::sqlite::db_part::accept_pending_action_at_participant(
-                    db_name, table_name, row_id, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend
+            .accept_pending_action_at_participant(db_name, table_name, row_id)
+    }
+
+    pub fn reject_pending_action_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend
+            .reject_pending_action_at_participant(db_name, table_name, row_id)
     }
 
     pub fn get_pending_actions(
@@ -194,30 +217,29 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         action: &str,
-    ) -> Vec<PendingStatement> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::get_pending_actions(db_name, table_name, action, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Vec<PendingStatement>, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend
+            .get_pending_actions(db_name, table_name, action)
     }
 
-    pub fn get_data_hash_at_host(&self, db_name: &str, table_name: &str, row_id: u32) -> u64 {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::metadata::get_data_hash_at_host(db_name, table_name, row_id, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_data_hash_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<u64, RcdDbError> {
+        self.backend
+            .get_data_hash_at_host(db_name, table_name, row_id)
+    }
+
+    pub fn get_row_hashes_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<u64>, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend.get_row_hashes_at_host(db_name, table_name)
     }
 
     pub fn get_data_hash_at_participant(
@@ -225,42 +247,30 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         row_id: u32,
-    ) -> u64 {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::get_data_hash_at_participant(
-                    db_name, table_name, row_id, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<u64, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend
+            .get_data_hash_at_participant(db_name, table_name, row_id)
     }
 
-    pub fn read_row_id_from_part_db(
+    pub fn rehash_partial_database(&self, db_name: &str) -> Result<Vec<RehashedRow>, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend.rehash_partial_database(db_name)
+    }
+
+    pub fn get_row_hash_version(&self, db_name: &str) -> Result<Option<u8>, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend.get_row_hash_version(db_name)
+    }
+
+    pub fn read_row_ids_from_part_db(
         &self,
         db_name: &str,
         table_name: &str,
         where_clause: &str,
-    ) -> u32 {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::read_row_id_from_part_db(
-                    db_name,
-                    table_name,
-                    where_clause,
-                    &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Vec<u32>, RcdDbError> {
+        self.backend
+            .read_row_ids_from_part_db(db_name, table_name, where_clause)
     }
 
     pub fn remove_remote_row_reference_from_host(
@@ -268,117 +278,57 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         row_id: u32,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::metadata::remove_remote_row_reference_from_host(
-                    db_name, table_name, row_id, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .remove_remote_row_reference_from_host(db_name, table_name, row_id)
     }
 
-    pub fn get_cds_host_for_part_db(&self, db_name: &str) -> Option<CdsHosts> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_cds_host_for_part_db(db_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_cds_host_for_part_db(&self, db_name: &str) -> Result<Option<CdsHosts>, RcdDbError> {
+        self.backend.get_cds_host_for_part_db(db_name)
     }
 
-    pub fn get_rcd_db_type(&self, db_name: &str) -> RcdDatabaseType {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_rcd_db_type(db_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_rcd_db_type(&self, db_name: &str) -> Result<RcdDatabaseType, RcdDbError> {
+        self.backend.get_rcd_db_type(db_name)
     }
 
     pub fn db_type(&self) -> DatabaseType {
-        self.db_type
+        self.backend.db_type()
     }
 
     pub fn get_updates_to_host_behavior(
         &self,
         db_name: &str,
         table_name: &str,
-    ) -> UpdatesToHostBehavior {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_updates_to_host_behavior(db_name, table_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<UpdatesToHostBehavior, RcdDbError> {
+        self.backend
+            .get_updates_to_host_behavior(db_name, table_name)
     }
 
     pub fn get_deletes_to_host_behavior(
         &self,
         db_name: &str,
         table_name: &str,
-    ) -> DeletesToHostBehavior {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_deletes_to_host_behavior(db_name, table_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<DeletesToHostBehavior, RcdDbError> {
+        self.backend
+            .get_deletes_to_host_behavior(db_name, table_name)
     }
 
     pub fn get_deletes_from_host_behavior(
         &self,
         db_name: &str,
         table_name: &str,
-    ) -> DeletesFromHostBehavior {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_deletes_from_host_behavior(db_name, table_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<DeletesFromHostBehavior, RcdDbError> {
+        self.backend
+            .get_deletes_from_host_behavior(db_name, table_name)
     }
 
     pub fn get_updates_from_host_behavior(
         &self,
         db_name: &str,
         table_name: &str,
-    ) -> UpdatesFromHostBehavior {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_updates_from_host_behavior(db_name, table_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<UpdatesFromHostBehavior, RcdDbError> {
+        self.backend
+            .get_updates_from_host_behavior(db_name, table_name)
     }
 
     pub fn change_updates_from_host_behavior(
@@ -386,19 +336,9 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         behavior: u32,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_updates_from_host_behavior(
-                    db_name, table_name, behavior, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .change_updates_from_host_behavior(db_name, table_name, behavior)
     }
 
     pub fn change_deletes_from_host_behavior(
@@ -406,19 +346,9 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         behavior: u32,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_deletes_from_host_behavior(
-                    db_name, table_name, behavior, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .change_deletes_from_host_behavior(db_name, table_name, behavior)
     }
 
     pub fn change_updates_to_host_behavior(
@@ -426,19 +356,9 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         behavior: u32,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_updates_to_host_behavior(
-                    db_name, table_name, behavior, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .change_updates_to_host_behavior(db_name, table_name, behavior)
     }
 
     pub fn change_deletes_to_host_behavior(
@@ -446,19 +366,45 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         behavior: u32,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_deletes_to_host_behavior(
-                    db_name, table_name, behavior, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .change_deletes_to_host_behavior(db_name, table_name, behavior)
+    }
+
+    pub fn get_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.get_data_log_table_status(db_name, table_name)
+    }
+
+    pub fn set_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        use_data_log: bool,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .set_data_log_table_status(db_name, table_name, use_data_log)
+    }
+
+    pub fn get_pending_action_ttl(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<u32, RcdDbError> {
+        self.backend.get_pending_action_ttl(db_name, table_name)
+    }
+
+    pub fn set_pending_action_ttl(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        ttl_seconds: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .set_pending_action_ttl(db_name, table_name, ttl_seconds)
     }
 
     pub fn get_row_from_partial_database(
@@ -466,123 +412,94 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         row_id: u32,
-    ) -> Row {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::get_row_from_partial_database(
-                    db_name, table_name, row_id, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Row, RcdDbError> {
+        self.backend
+            .get_row_from_partial_database(db_name, table_name, row_id)
     }
 
-    pub fn change_host_status_by_id(&self, host_id: &str, status: u32) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_host_status_by_id(host_id, status, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn change_host_status_by_id(&self, host_id: &str, status: u32) -> Result<bool, RcdDbError> {
+        self.backend.change_host_status_by_id(host_id, status)
     }
 
-    pub fn change_host_status_by_name(&self, host_name: &str, status: u32) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::change_host_status_by_name(host_name, status, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn update_host_protocol_version(
+        &self,
+        host_id_or_name: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .update_host_protocol_version(host_id_or_name, protocol_version)
     }
 
-    pub fn verify_host_by_id(&self, host_id: &str, token: Vec<u8>) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::verify_host_by_id(host_id, token, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn change_host_status_by_name(
+        &self,
+        host_name: &str,
+        status: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.change_host_status_by_name(host_name, status)
     }
 
-    pub fn verify_host_by_name(&self, host_name: &str, token: Vec<u8>) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::verify_host_by_name(host_name, token, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn verify_host_by_id(&self, host_id: &str, token: Vec<u8>) -> Result<bool, RcdDbError> {
+        self.backend.verify_host_by_id(host_id, token)
+    }
+
+    pub fn verify_host_by_name(&self, host_name: &str, token: Vec<u8>) -> Result<bool, RcdDbError> {
+        self.backend.verify_host_by_name(host_name, token)
     }
 
     pub fn delete_metadata_in_host_db(
         &self,
         db_name: &str,
         table_name: &str,
-        row_id: u32,
+        row_ids: &[u32],
         internal_participant_id: &str,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::metadata::delete_metadata_in_host_db(
-                    db_name,
-                    table_name,
-                    row_id,
-                    internal_participant_id,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend.delete_metadata_in_host_db(
+            db_name,
+            table_name,
+            row_ids,
+            internal_participant_id,
+        )
     }
 
     pub fn update_metadata_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[(u32, u64)],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .update_metadata_in_host_db(db_name, table_name, rows, internal_participant_id)
+    }
+
+    pub fn mark_metadata_out_of_sync_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_ids: &[u32],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.mark_metadata_out_of_sync_in_host_db(
+            db_name,
+            table_name,
+            row_ids,
+            internal_participant_id,
+        )
+    }
+
+    pub fn mark_metadata_remote_deleted_in_host_db(
         &self,
         db_name: &str,
         table_name: &str,
         row_id: u32,
-        hash: u64,
         internal_participant_id: &str,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::metadata::update_metadata_in_host_db(
-                    db_name,
-                    table_name,
-                    row_id,
-                    hash,
-                    internal_participant_id,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend.mark_metadata_remote_deleted_in_host_db(
+            db_name,
+            table_name,
+            row_id,
+            internal_participant_id,
+        )
     }
 
     pub fn insert_metadata_into_host_db(
@@ -592,24 +509,46 @@ impl Dbi {
         row_id: u32,
         hash: u64,
         internal_participant_id: &str,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::metadata::insert_metadata_into_host_db(
-                    db_name,
-                    table_name,
-                    row_id,
-                    hash,
-                    internal_participant_id,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend.insert_metadata_into_host_db(
+            db_name,
+            table_name,
+            row_id,
+            hash,
+            internal_participant_id,
+        )
+    }
+
+    pub fn add_pending_write_at_host(
+        &self,
+        db_name: &str,
+        internal_participant_id: &str,
+        pending_write: &PendingWrite,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .add_pending_write_at_host(db_name, internal_participant_id, pending_write)
+    }
+
+    pub fn remove_pending_write_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        internal_participant_id: &str,
+        pending_action_id: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.remove_pending_write_at_host(
+            db_name,
+            table_name,
+            internal_participant_id,
+            pending_action_id,
+        )
+    }
+
+    pub fn get_pending_writes_at_host(
+        &self,
+        db_name: &str,
+    ) -> Result<Vec<PendingWrite>, RcdDbError> {
+        self.backend.get_pending_writes_at_host(db_name)
     }
 
     pub fn delete_data_in_partial_db(
@@ -619,24 +558,9 @@ impl Dbi {
         cmd: &str,
         where_clause: &str,
         host_id: &str,
-    ) -> PartialDataResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::delete::delete_data_in_partial_db(
-                    part_db_name,
-                    table_name,
-                    cmd,
-                    where_clause,
-                    host_id,
-                    &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.backend
+            .delete_data_in_partial_db(part_db_name, table_name, cmd, where_clause, host_id)
     }
 
     pub fn update_data_into_partial_db_queue(
@@ -646,24 +570,14 @@ impl Dbi {
         cmd: &str,
         where_clause: &str,
         host: &CdsHosts,
-    ) -> PartialDataResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::update::update_data_into_partial_db_queue(
-                    part_db_name,
-                    table_name,
-                    cmd,
-                    where_clause,
-                    &host.host_id,
-                    &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.backend.update_data_into_partial_db_queue(
+            part_db_name,
+            table_name,
+            cmd,
+            where_clause,
+            host,
+        )
     }
 
     pub fn update_data_into_partial_db(
@@ -673,24 +587,14 @@ impl Dbi {
         cmd: &str,
         host_id: &str,
         where_clause: &str,
-    ) -> PartialDataResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::update::update_data_into_partial_db(
-                    part_db_name,
-                    table_name,
-                    cmd,
-                    where_clause,
-                    host_id,
-                    &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.backend.update_data_into_partial_db(
+            part_db_name,
+            table_name,
+            cmd,
+            host_id,
+            where_clause,
+        )
     }
 
     pub fn insert_data_into_partial_db(
@@ -698,22 +602,9 @@ impl Dbi {
         part_db_name: &str,
         table_name: &str,
         cmd: &str,
-    ) -> PartialDataResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::insert::insert_data_into_partial_db(
-                    part_db_name,
-                    table_name,
-                    cmd,
-                    &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.backend
+            .insert_data_into_partial_db(part_db_name, table_name, cmd)
     }
 
     pub fn update_participant_accepts_contract(
@@ -722,108 +613,53 @@ impl Dbi {
         participant: CoopDatabaseParticipant,
         participant_message: Participant,
         accepted_contract_id: &str,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::contract::update_participant_accepts_contract(
-                    db_name,
-                    participant,
-                    participant_message,
-                    accepted_contract_id,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend.update_participant_accepts_contract(
+            db_name,
+            participant,
+            participant_message,
+            accepted_contract_id,
+        )
     }
 
-    pub fn create_partial_database_from_contract(&self, contract: &Contract) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::create_partial_database_from_contract(contract, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn update_participant_rejects_contract(
+        &self,
+        db_name: &str,
+        participant: CoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .update_participant_rejects_contract(db_name, participant)
     }
 
-    pub fn accept_pending_contract(&self, host_name: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::contract::accept_pending_contract(host_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn create_partial_database_from_contract(
+        &self,
+        contract: &Contract,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.create_partial_database_from_contract(contract)
     }
 
-    pub fn get_pending_contracts(&self) -> Vec<Contract> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::contract::get_contracts_by_status(
-                    &settings,
-                    ContractStatus::Pending,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn accept_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError> {
+        self.backend.accept_pending_contract(host_name)
     }
 
-    pub fn get_accepted_contracts(&self) -> Vec<Contract> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::contract::get_contracts_by_status(
-                    &settings,
-                    ContractStatus::Accepted,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn reject_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError> {
+        self.backend.reject_pending_contract(host_name)
     }
 
-    pub fn save_contract(&self, contract: Contract) -> RcdSaveContractResult {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
+    pub fn get_pending_contracts(&self) -> Result<Vec<Contract>, RcdDbError> {
+        self.backend.get_pending_contracts()
+    }
 
-                sqlite::rcd_db::contract::save_contract(contract, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_accepted_contracts(&self) -> Result<Vec<Contract>, RcdDbError> {
+        self.backend.get_accepted_contracts()
     }
 
-    pub fn get_table_id(&self, db_name: &str, table_name: &str) -> String {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::get_table_id(db_name, table_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn save_contract(&self, contract: Contract) -> Result<RcdSaveContractResult, RcdDbError> {
+        self.backend.save_contract(contract)
+    }
+
+    pub fn get_table_id(&self, db_name: &str, table_name: &str) -> Result<String, RcdDbError> {
+        self.backend.get_table_id(db_name, table_name)
     }
 
     pub fn create_table_in_partial_database(
@@ -831,228 +667,99 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
         schema: Vec<ColumnSchema>,
-    ) -> rusqlite::Result<bool> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::create_table_in_partial_database(
-                    db_name, table_name, schema, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .create_table_in_partial_database(db_name, table_name, schema)
     }
 
-    pub fn get_db_id(&self, db_name: &str) -> String {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::get_db_id(db_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_db_id(&self, db_name: &str) -> Result<String, RcdDbError> {
+        self.backend.get_db_id(db_name)
     }
 
-    pub fn create_partial_database(&self, db_name: &str) -> Result<Connection, rusqlite::Error> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db_part::create_partial_database(db_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn create_partial_database(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.backend.create_partial_database(db_name)
     }
 
-    pub fn has_role_name(&self, role_name: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::role::has_role_name(role_name, &settings).unwrap()
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn has_role_name(&self, role_name: &str) -> Result<bool, RcdDbError> {
+        self.backend.has_role_name(role_name)
     }
 
     /// Associates the specified login to the specified role
-    pub fn add_login_to_role(&self, login: &str, role_name: &str) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::role::add_login_to_role(login, role_name, &settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn add_login_to_role(&self, login: &str, role_name: &str) -> Result<(), RcdDbError> {
+        self.backend.add_login_to_role(login, role_name)
     }
 
     /// Checks if the specified login is in the specified role
-    pub fn login_is_in_role(&self, login: &str, role_name: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::role::login_is_in_role(login, role_name, &settings).unwrap()
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn login_is_in_role(&self, login: &str, role_name: &str) -> Result<bool, RcdDbError> {
+        self.backend.login_is_in_role(login, role_name)
     }
 
     /// Creates a login with the specific values
-    pub fn create_login(&self, login: &str, pw: &str) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::create_login(login, pw, &settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn create_login(&self, login: &str, pw: &str) -> Result<(), RcdDbError> {
+        self.backend.create_login(login, pw)
     }
 
     /// Returns the names of all the database at this instance
-    pub fn get_database_names(&self) -> Vec<String> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_database_names(&settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_database_names(&self) -> Result<Vec<String>, RcdDbError> {
+        self.backend.get_database_names()
     }
 
-    pub fn has_login(&self, login: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::has_login_via_config(login, settings).unwrap()
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn has_login(&self, login: &str) -> Result<bool, RcdDbError> {
+        self.backend.has_login(login)
     }
 
     pub fn add_participant(
         &self,
         db_name: &str,
-        alias: &str,
-        ip4addr: &str,
-        db_port: u32,
-        http_addr: String,
-        http_port: u16,
-        id: Option<String>,
-    ) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::add_participant(
-                    db_name, alias, ip4addr, db_port, settings, http_addr, http_port, id,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        participant: NewCoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.add_participant(db_name, participant)
     }
 
-    pub fn get_database_schema(&self, db_name: &str) -> DatabaseSchema {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::get_db_schema(db_name, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_database_schema(&self, db_name: &str) -> Result<DatabaseSchema, RcdDbError> {
+        self.backend.get_database_schema(db_name)
     }
 
     pub fn get_participant_by_alias(
         &self,
         db_name: &str,
         participant_alias: &str,
-    ) -> Option<CoopDatabaseParticipant> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::get_participant_by_alias(
-                    db_name,
-                    participant_alias,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+        self.backend
+            .get_participant_by_alias(db_name, participant_alias)
     }
 
     pub fn get_participant_by_id(
         &self,
         db_name: &str,
         participant_id: &str,
-    ) -> Option<CoopDatabaseParticipant> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::get_participant_by_id(db_name, participant_id, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+        self.backend.get_participant_by_id(db_name, participant_id)
     }
 
-    pub fn has_participant(&self, db_name: &str, participant_alias: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::has_participant(db_name, participant_alias, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn has_participant(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.has_participant(db_name, participant_alias)
     }
 
-    pub fn get_active_contract(&self, db_name: &str) -> CoopDatabaseContract {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::contract::get_active_contract(db_name, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn update_participant_protocol_version(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.backend.update_participant_protocol_version(
+            db_name,
+            participant_alias,
+            protocol_version,
+        )
+    }
+
+    pub fn get_active_contract(&self, db_name: &str) -> Result<CoopDatabaseContract, RcdDbError> {
+        self.backend.get_active_contract(db_name)
     }
 
     pub fn get_logical_storage_policy(
@@ -1060,18 +767,7 @@ impl Dbi {
         db_name: &str,
         table_name: &str,
     ) -> Result<LogicalStoragePolicy, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::logical_storage_policy::get_logical_storage_policy(
-                    db_name, table_name, &settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.backend.get_logical_storage_policy(db_name, table_name)
     }
 
     pub fn set_logical_storage_policy(
@@ -1080,57 +776,116 @@ impl Dbi {
         table_name: &str,
         policy: LogicalStoragePolicy,
     ) -> Result<bool, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::logical_storage_policy::set_logical_storage_policy(
-                    db_name, table_name, policy, settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.backend
+            .set_logical_storage_policy(db_name, table_name, policy)
     }
 
-    pub fn has_table(&self, db_name: &str, table_name: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::has_table_client_service(db_name, table_name, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
+    pub fn get_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<String>, RcdDbError> {
+        self.backend
+            .get_participant_owned_columns(db_name, table_name)
+    }
+
+    pub fn set_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        column_names: &[String],
+    ) -> Result<bool, RcdDbError> {
+        self.backend
+            .set_participant_owned_columns(db_name, table_name, column_names)
+    }
+
+    /// Keeps the host's own copy of a `Mirror` or `Shared` table in step with a write that a
+    /// participant has made, and does nothing for other tables. The host doesn't keep the
+    /// participant's columns of a shared table, so those are cleared once the statement has run;
+    /// a statement that filters on one of them won't match any of the host's rows.
+    pub fn apply_cooperative_write_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        statement: &str,
+    ) -> Result<(), RcdDbError> {
+        let participant_owned_columns =
+            match self.get_logical_storage_policy(db_name, table_name)? {
+                LogicalStoragePolicy::Mirror => Vec::new(),
+                LogicalStoragePolicy::Shared => {
+                    self.get_participant_owned_columns(db_name, table_name)?
+                }
+                _ => return Ok(()),
+            };
+
+        let mut cmds = vec![statement.to_string()];
+
+        if !participant_owned_columns.is_empty() {
+            let cleared_columns: Vec<String> = participant_owned_columns
+                .iter()
+                .map(|column_name| format!("{column_name} = NULL"))
+                .collect();
+
+            cmds.push(format!(
+                "UPDATE {table_name} SET {};",
+                cleared_columns.join(", ")
+            ));
         }
+
+        self.execute_writes_at_host(db_name, &cmds)?;
+
+        Ok(())
+    }
+
+    /// Returns true if the host's copy of a `Mirror` table matched the participants' hashes
+    /// with this fingerprint when it was last checked, and the host hasn't written to it since
+    pub fn is_mirror_verified(&self, db_name: &str, table_name: &str, fingerprint: u64) -> bool {
+        let key = (db_name.to_string(), table_name.to_lowercase());
+        self.verified_mirrors.lock().unwrap().get(&key) == Some(&fingerprint)
+    }
+
+    pub fn set_mirror_verified(&self, db_name: &str, table_name: &str, fingerprint: u64) {
+        let key = (db_name.to_string(), table_name.to_lowercase());
+        self.verified_mirrors
+            .lock()
+            .unwrap()
+            .insert(key, fingerprint);
+    }
+
+    fn clear_verified_mirrors(&self, db_name: &str) {
+        self.verified_mirrors
+            .lock()
+            .unwrap()
+            .retain(|(verified_db_name, _), _| verified_db_name != db_name);
+    }
+
+    pub fn has_table(&self, db_name: &str, table_name: &str) -> Result<bool, RcdDbError> {
+        self.backend.has_table(db_name, table_name)
     }
 
     pub fn execute_write_at_host(&self, db_name: &str, cmd: &str) -> Result<usize, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::execute_write_on_connection_at_host(db_name, cmd, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.check_database_key(db_name)?;
+        self.clear_verified_mirrors(db_name);
+        self.backend.execute_write_at_host(db_name, cmd)
     }
 
-    pub fn execute_write_at_partipant(&self, db_name: &str, cmd: &str) -> usize {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::execute_write_on_connection_at_participant(db_name, cmd, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn execute_writes_at_host(
+        &self,
+        db_name: &str,
+        cmds: &[String],
+    ) -> Result<usize, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.clear_verified_mirrors(db_name);
+        self.backend.execute_writes_at_host(db_name, cmds)
+    }
+
+    pub fn execute_write_at_partipant(
+        &self,
+        db_name: &str,
+        cmd: &str,
+    ) -> Result<usize, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend.execute_write_at_partipant(db_name, cmd)
     }
 
     pub fn execute_read_at_participant(
@@ -1138,16 +893,8 @@ impl Dbi {
         db_name: &str,
         cmd: &str,
     ) -> Result<Table, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::execute_read_at_participant(db_name, cmd, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.check_database_key(db_name)?;
+        self.backend.execute_read_at_participant(db_name, cmd)
     }
 
     pub fn execute_read_at_host(
@@ -1155,56 +902,73 @@ impl Dbi {
         db_name: &str,
         cmd: &str,
     ) -> core::result::Result<Table, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::execute_read_at_host(db_name, cmd, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.check_database_key(db_name)?;
+        self.backend.execute_read_at_host(db_name, cmd)
+    }
+
+    /// Replaces every row of a host table with `rows`, binding each value as its column's type
+    pub fn replace_rows_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[Row],
+    ) -> Result<usize, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.clear_verified_mirrors(db_name);
+        self.backend.replace_rows_at_host(db_name, table_name, rows)
+    }
+
+    /// Reads the rows of a host table whose key columns hold one of `keys`
+    pub fn get_rows_at_host_by_key(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        keys: &[Vec<RowValue>],
+    ) -> Result<Table, RcdDbError> {
+        self.check_database_key(db_name)?;
+        self.backend
+            .get_rows_at_host_by_key(db_name, table_name, keys)
+    }
+
+    /// Runs a read at the host against the rows we've fetched from participants for the
+    /// cooperative tables in the statement, instead of the (empty) host copies of those tables
+    pub fn execute_read_at_host_with_cooperative_rows(
+        &self,
+        db_name: &str,
+        cmd: &str,
+        cooperative_tables: &[String],
+        cooperative_rows: &[Row],
+    ) -> core::result::Result<Table, RcdDbError> {
+        self.backend.execute_read_at_host_with_cooperative_rows(
+            db_name,
+            cmd,
+            cooperative_tables,
+            cooperative_rows,
+        )
     }
 
     /// Will scan the supplied SQL statement for table names and return
-    /// if any of the found table names has a logical storage policy that is remote
+    /// if any of the found table names has a logical storage policy that is remote.
+    /// A `Mirror` table is kept at the host as well, so it is read locally and isn't counted.
     pub fn has_cooperative_tables(&self, db_name: &str, cmd: &str) -> Result<bool, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::has_cooperative_tables(db_name, cmd, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.check_database_key(db_name)?;
+        self.backend.has_cooperative_tables(db_name, cmd)
     }
 
     pub fn get_participants_for_table(
         &self,
         db_name: &str,
         table_name: &str,
-    ) -> Vec<CoopDatabaseParticipantData> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::get_participants_for_table(db_name, table_name, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    ) -> Result<Vec<CoopDatabaseParticipantData>, RcdDbError> {
+        self.backend.get_participants_for_table(db_name, table_name)
     }
 
-    pub fn get_active_contract_proto(&self, db_name: &str) -> Contract {
+    pub fn get_active_contract_proto(&self, db_name: &str) -> Result<Contract, RcdDbError> {
         if !db_name.contains(".dbpart") {
-            let active_contract = self.get_active_contract(db_name);
-            let db_schema = self.get_database_schema(db_name);
-            let host_info = self.rcd_get_host_info().expect("no host info is set");
-            active_contract.to_cdata_contract(
+            let active_contract = self.get_active_contract(db_name)?;
+            let db_schema = self.get_database_schema(db_name)?;
+            let host_info = self.get_host_info_or_err()?;
+            Ok(active_contract.to_cdata_contract(
                 &host_info,
                 "",
                 "",
@@ -1213,9 +977,9 @@ impl Dbi {
                 db_schema,
                 "",
                 0,
-            )
+            ))
         } else {
-            let contracts = self.get_accepted_contracts();
+            let contracts = self.get_accepted_contracts()?;
 
             trace!("{contracts:?}");
 
@@ -1235,7 +999,7 @@ impl Dbi {
                 .filter(|&(_, c)| c.schema.as_ref().unwrap().database_name == name)
                 .map(|(_, c)| c);
 
-            return contracts.last().unwrap().clone();
+            return Ok(contracts.last().unwrap().clone());
         }
     }
 
@@ -1243,56 +1007,23 @@ impl Dbi {
         &self,
         db_name: &str,
     ) -> Result<Vec<ParticipantStatus>, RcdDbError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::participant::get_participants_for_database(db_name, &settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.backend.get_participants_for_database(db_name)
     }
 
-    pub fn get_cooperative_tables(&self, db_name: &str, cmd: &str) -> Vec<String> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::get_cooperative_tables(db_name, cmd, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_cooperative_tables(
+        &self,
+        db_name: &str,
+        cmd: &str,
+    ) -> Result<Vec<String>, RcdDbError> {
+        self.backend.get_cooperative_tables(db_name, cmd)
     }
 
-    pub fn create_database(&self, db_name: &str) -> Result<Connection, Error> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::db::create_database(db_name, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn create_database(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.backend.create_database(db_name)
     }
 
-    pub fn enable_coooperative_features(&self, db_name: &str) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-
-                sqlite::db::enable_coooperative_features(db_name, &settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn enable_coooperative_features(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.backend.enable_coooperative_features(db_name)
     }
 
     pub fn generate_contract(
@@ -1302,125 +1033,89 @@ impl Dbi {
         desc: &str,
         remote_delete_behavior: RemoteDeleteBehavior,
     ) -> Result<bool, RcdGenerateContractError> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-
-                let _ = self.generate_and_get_host_info(host_name);
-
-                sqlite::db::contract::generate_contract(
-                    db_name,
-                    desc,
-                    remote_delete_behavior,
-                    settings,
-                )
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+        self.generate_and_get_host_info(host_name)
+            .map_err(|e| RcdGenerateContractError::General(e.to_string()))?;
+        self.backend
+            .generate_contract(db_name, desc, remote_delete_behavior)
     }
 
-    pub fn rcd_get_host_info(&self) -> Option<HostInfo> {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::get_host_info(settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn rcd_get_host_info(&self) -> Result<Option<HostInfo>, RcdDbError> {
+        self.backend.rcd_get_host_info()
     }
 
-    pub fn rcd_generate_host_info(&self, host_name: &str) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::generate_host_info(host_name, settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn get_rcd_schema_version(&self) -> Result<u32, RcdDbError> {
+        self.backend.get_rcd_schema_version()
     }
 
-    pub fn if_rcd_host_info_exists(&self) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::if_host_info_exists(settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn rcd_generate_host_info(&self, host_name: &str) -> Result<(), RcdDbError> {
+        self.backend.rcd_generate_host_info(host_name)
+    }
+
+    pub fn if_rcd_host_info_exists(&self) -> Result<bool, RcdDbError> {
+        self.backend.if_rcd_host_info_exists()
     }
 
     /// Generates the host info and saves it to our rcd_db, overwriting the previous host_name and token (but not the host_id)
     /// Will always return the current `HostInfo`
-    pub fn generate_and_get_host_info(&self, host_name: &str) -> HostInfo {
-        self.rcd_generate_host_info(host_name);
-        self.rcd_get_host_info().expect("no host info is set")
+    pub fn generate_and_get_host_info(&self, host_name: &str) -> Result<HostInfo, RcdDbError> {
+        self.rcd_generate_host_info(host_name)?;
+        self.get_host_info_or_err()
     }
 
-    pub fn configure_admin_hash(&self, login: &str, hash: Vec<u8>) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::configure_admin_with_hash(login, hash, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    /// Returns this instance's `HostInfo`, or an error if it hasn't been generated yet
+    fn get_host_info_or_err(&self) -> Result<HostInfo, RcdDbError> {
+        self.rcd_get_host_info()?
+            .ok_or_else(|| RcdDbError::General(String::from("no host info is set")))
     }
 
-    pub fn configure_admin(&self, login: &str, pw: &str) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::configure_admin(login, pw, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn configure_admin_hash(&self, login: &str, hash: Vec<u8>) -> Result<(), RcdDbError> {
+        self.backend.configure_admin_hash(login, hash)
     }
 
-    pub fn verify_login(&self, login: &str, pw: &str) -> bool {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::verify_login(login, pw, settings)
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn configure_admin(&self, login: &str, pw: &str) -> Result<(), RcdDbError> {
+        self.backend.configure_admin(login, pw)
     }
 
-    pub fn configure_rcd_db(&self) {
-        match self.db_type {
-            DatabaseType::Sqlite => {
-                let settings = self.get_sqlite_settings();
-                sqlite::rcd_db::configure_rcd_db(&settings);
-            }
-            DatabaseType::Unknown => unimplemented!(),
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
-            DatabaseType::Sqlserver => unimplemented!(),
-        }
+    pub fn verify_login(&self, login: &str, pw: &str) -> Result<bool, RcdDbError> {
+        self.backend.verify_login(login, pw)
+    }
+
+    pub fn configure_rcd_db(&self) -> Result<(), RcdDbError> {
+        self.backend.configure_rcd_db()
+    }
+
+    /// Encrypts the host or partial database `db_name`, which must not exist yet, with `key`
+    pub fn set_database_key(
+        &self,
+        db_name: &str,
+        db_type: RcdDatabaseType,
+        key: &str,
+    ) -> Result<(), RcdDbError> {
+        self.backend.set_database_key(db_name, db_type, key)
+    }
+
+    /// Takes back a key set with `set_database_key` for a database that was never created
+    pub fn clear_database_key(
+        &self,
+        db_name: &str,
+        db_type: RcdDatabaseType,
+    ) -> Result<(), RcdDbError> {
+        self.backend.clear_database_key(db_name, db_type)
+    }
+
+    pub fn unlock_database(&self, db_name: &str, key: &str) -> Result<(), RcdDbError> {
+        self.backend.unlock_database(db_name, key)
+    }
+
+    pub fn check_database_key(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.backend.check_database_key(db_name)
+    }
+
+    pub fn snapshot_database(&self, db_name: &str) -> Result<PathBuf, RcdDbError> {
+        self.backend.snapshot_database(db_name)
     }
 
-    fn get_sqlite_settings(&self) -> DbiConfigSqlite {
-        return self.sqlite_config.as_ref().unwrap().clone();
+    pub fn count_user_rows(&self, db_name: &str) -> Result<u64, RcdDbError> {
+        self.backend.count_user_rows(db_name)
     }
 }
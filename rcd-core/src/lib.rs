@@ -8,3 +8,5 @@ pub mod rcd;
 pub mod rcd_data;
 pub mod remote_grpc;
 pub mod remote_http;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -10,9 +10,9 @@ This 'core' will handle most client actions by way of the defined proto types.
 */
 
 use chrono::Utc;
-use tracing::trace;
-use rcd_common::defaults;
+use tracing::{error, trace, warn};
 use rcd_common::rcd_settings::RcdSettings;
+use rcd_error::rcd_db_error::RcdDbError;
 use rcdproto::rcdp::{
     AcceptPendingActionReply, AcceptPendingActionRequest, AcceptPendingContractReply,
     AcceptPendingContractRequest, AddParticipantReply, AddParticipantRequest, AuthRequest,
@@ -23,22 +23,30 @@ use rcdproto::rcdp::{
     ChangesUpdatesFromHostBehaviorReply, CreateUserDatabaseReply, CreateUserDatabaseRequest,
     EnableCoooperativeFeaturesReply, EnableCoooperativeFeaturesRequest,
     ExecuteCooperativeWriteReply, ExecuteCooperativeWriteRequest, ExecuteReadReply,
-    ExecuteReadRequest, ExecuteWriteReply, ExecuteWriteRequest, GenerateContractReply,
-    GenerateContractRequest, GenerateHostInfoReply, GenerateHostInfoRequest,
-    GetActiveContractReply, GetActiveContractRequest, GetCooperativeHostsReply,
-    GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest, GetDatabasesReply,
+    ExecuteReadRequest, ExecuteWriteReply, ExecuteWriteRequest, ExportDatabaseReply,
+    ExportDatabaseRequest, GenerateContractReply, GenerateContractRequest, GenerateHostInfoReply,
+    GenerateHostInfoRequest, GetActiveContractReply, GetActiveContractRequest,
+    GetCooperativeHostsReply, GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest,
+    GetDataLogTableStatusReply, GetDataLogTableStatusRequest, GetDatabasesReply,
     GetDatabasesRequest, GetDeletesFromHostBehaviorReply, GetDeletesFromHostBehaviorRequest,
     GetDeletesToHostBehaviorReply, GetDeletesToHostBehaviorRequest, GetLogicalStoragePolicyReply,
     GetLogicalStoragePolicyRequest, GetLogsByLastNumberReply, GetLogsByLastNumberRequest,
-    GetParticipantsReply, GetParticipantsRequest, GetPendingActionsReply, GetPendingActionsRequest,
-    GetReadRowIdsReply, GetReadRowIdsRequest, GetSettingsReply, GetSettingsRequest,
-    GetUpdatesFromHostBehaviorReply, GetUpdatesFromHostBehaviorRequest,
-    GetUpdatesToHostBehaviorReply, GetUpdatesToHostBehaviorRequest, HasTableReply, HasTableRequest,
-    HostInfoReply, RcdLogEntry, RevokeReply, SendParticipantContractReply,
-    SendParticipantContractRequest, SetLogicalStoragePolicyReply, SetLogicalStoragePolicyRequest,
+    GetParticipantsReply, GetParticipantsRequest, GetPendingActionTtlReply,
+    GetPendingActionTtlRequest, GetPendingActionsReply, GetPendingActionsRequest,
+    GetPendingWritesAtHostReply, GetPendingWritesAtHostRequest, GetReadRowIdsReply,
+    GetReadRowIdsRequest, GetSettingsReply, GetSettingsRequest, GetUpdatesFromHostBehaviorReply,
+    GetUpdatesFromHostBehaviorRequest, GetUpdatesToHostBehaviorReply,
+    GetUpdatesToHostBehaviorRequest, HasTableReply, HasTableRequest, HostInfoReply, RcdLogEntry,
+    RehashPartialDatabaseReply, RehashPartialDatabaseRequest, RejectPendingActionReply,
+    RejectPendingActionRequest, RejectPendingContractReply, RejectPendingContractRequest,
+    RevokeReply, SendParticipantContractReply, SendParticipantContractRequest,
+    SetDataLogTableStatusReply, SetDataLogTableStatusRequest, SetLogicalStoragePolicyReply,
+    SetLogicalStoragePolicyRequest, SetPendingActionTtlReply, SetPendingActionTtlRequest,
     TestReply, TestRequest, TokenReply, TryAuthAtParticipantRequest, TryAuthAtPartipantReply,
-    ViewPendingContractsReply, ViewPendingContractsRequest,
+    UnlockDatabaseReply, UnlockDatabaseRequest, VersionReply, ViewPendingContractsReply,
+    ViewPendingContractsRequest,
 };
+use tokio::sync::mpsc::Receiver;
 
 use crate::comm::RcdRemoteDbClient;
 use crate::dbi::Dbi;
@@ -46,6 +54,7 @@ use crate::dbi::Dbi;
 mod auth;
 mod contract;
 mod db;
+mod export;
 mod io;
 mod logical_storage_policy;
 mod participant;
@@ -55,6 +64,8 @@ pub struct Rcd {
     pub db_interface: Option<Dbi>,
     pub remote_client: Option<RcdRemoteDbClient>,
     pub settings: Option<RcdSettings>,
+    /// The version of the rcdx build hosting this core, reported back by `get_versions`
+    pub rcdx_version: String,
 }
 
 impl Rcd {
@@ -144,6 +155,13 @@ impl Rcd {
         return contract::accept_pending_contract(self, request).await;
     }
 
+    pub async fn reject_pending_contract(
+        &self,
+        request: RejectPendingContractRequest,
+    ) -> RejectPendingContractReply {
+        return contract::reject_pending_contract(self, request).await;
+    }
+
     pub async fn get_data_hash_at_participant(
         &self,
         request: GetDataHashRequest,
@@ -151,6 +169,24 @@ impl Rcd {
         return db::get_data_hash_at_participant(self, request).await;
     }
 
+    pub async fn rehash_partial_database(
+        &self,
+        request: RehashPartialDatabaseRequest,
+    ) -> RehashPartialDatabaseReply {
+        return db::rehash_partial_database(self, request).await;
+    }
+
+    pub async fn unlock_database(&self, request: UnlockDatabaseRequest) -> UnlockDatabaseReply {
+        return db::unlock_database(self, request).await;
+    }
+
+    pub async fn export_database(
+        &self,
+        request: ExportDatabaseRequest,
+    ) -> Receiver<ExportDatabaseReply> {
+        return export::export_database(self, request).await;
+    }
+
     pub async fn change_updates_from_host_behavior(
         &self,
         request: ChangeUpdatesFromHostBehaviorRequest,
@@ -179,6 +215,20 @@ impl Rcd {
         return db::get_cooperative_hosts(self, request).await;
     }
 
+    pub async fn get_data_log_table_status_at_participant(
+        &self,
+        request: GetDataLogTableStatusRequest,
+    ) -> GetDataLogTableStatusReply {
+        return db::get_data_log_table_status_at_participant(self, request).await;
+    }
+
+    pub async fn set_data_log_table_status_at_participant(
+        &self,
+        request: SetDataLogTableStatusRequest,
+    ) -> SetDataLogTableStatusReply {
+        return db::set_data_log_table_status_at_participant(self, request).await;
+    }
+
     pub async fn get_updates_from_host_behavior(
         &self,
         request: GetUpdatesFromHostBehaviorRequest,
@@ -211,6 +261,10 @@ impl Rcd {
         return db::get_host_info(self, request).await;
     }
 
+    pub async fn get_versions(&self, request: AuthRequest) -> VersionReply {
+        return db::get_versions(self, request).await;
+    }
+
     pub async fn change_host_status(
         &self,
         request: ChangeHostStatusRequest,
@@ -232,6 +286,34 @@ impl Rcd {
         return db::accept_pending_action_at_participant(self, request).await;
     }
 
+    pub async fn get_pending_writes_at_host(
+        &self,
+        request: GetPendingWritesAtHostRequest,
+    ) -> GetPendingWritesAtHostReply {
+        return db::get_pending_writes_at_host(self, request).await;
+    }
+
+    pub async fn reject_pending_action_at_participant(
+        &self,
+        request: RejectPendingActionRequest,
+    ) -> RejectPendingActionReply {
+        return db::reject_pending_action_at_participant(self, request).await;
+    }
+
+    pub async fn get_pending_action_ttl_at_participant(
+        &self,
+        request: GetPendingActionTtlRequest,
+    ) -> GetPendingActionTtlReply {
+        return db::get_pending_action_ttl_at_participant(self, request).await;
+    }
+
+    pub async fn set_pending_action_ttl_at_participant(
+        &self,
+        request: SetPendingActionTtlRequest,
+    ) -> SetPendingActionTtlReply {
+        return db::set_pending_action_ttl_at_participant(self, request).await;
+    }
+
     pub async fn has_table(&self, request: HasTableRequest) -> HasTableReply {
         return db::has_table(self, request).await;
     }
@@ -244,7 +326,7 @@ impl Rcd {
         TestReply {
             reply_time_utc: Utc::now().to_rfc2822(),
             reply_echo_message: item,
-            rcdx_version: defaults::VERSION.to_string(),
+            rcdx_version: self.rcdx_version.clone(),
         }
     }
 
@@ -331,7 +413,13 @@ impl Rcd {
         let mut entries: Vec<RcdLogEntry> = Vec::new();
 
         if auth_result.0 {
-            let log_entries = self.dbi().get_last_log_entries(request.number_of_logs);
+            let log_entries = match self.dbi().get_last_log_entries(request.number_of_logs) {
+                Ok(log_entries) => log_entries,
+                Err(e) => {
+                    warn!("could not read the last log entries: {e}");
+                    Vec::new()
+                }
+            };
 
             for entry in &log_entries {
                 let x = RcdLogEntry {
@@ -351,23 +439,40 @@ impl Rcd {
         }
     }
 
-    fn verify_login(&self, request: AuthRequest) -> (bool, AuthResult) {
-        let is_authenticated: bool = if !request.jwt.is_empty() {
+    /// Checks the request's login, or its token if it has one, against this instance's logins.
+    /// If the check itself can't be made, the request isn't authenticated and the reason is
+    /// returned in the `authentication_message`.
+    pub fn verify_login(&self, request: AuthRequest) -> (bool, AuthResult) {
+        let result = if !request.jwt.is_empty() {
             self.dbi().verify_token(request.jwt)
         } else {
             self.dbi().verify_login(&request.user_name, &request.pw)
         };
 
+        let (is_authenticated, authentication_message) = match result {
+            Ok(is_authenticated) => (is_authenticated, String::from("")),
+            Err(e) => {
+                error!("could not verify login: {e}");
+                (false, format!("could not verify login: {e}"))
+            }
+        };
+
         let auth_response = AuthResult {
             is_authenticated,
             user_name: String::from(""),
             token: String::from(""),
-            authentication_message: String::from(""),
+            authentication_message,
         };
 
         (is_authenticated, auth_response)
     }
 
+    /// Counts the rows of a host or partial database's own tables, reading an encrypted database
+    /// with the key it was unlocked with
+    pub fn count_user_rows(&self, db_name: &str) -> Result<u64, RcdDbError> {
+        self.dbi().count_user_rows(db_name)
+    }
+
     fn dbi(&self) -> Dbi {
         return self.db_interface.as_ref().unwrap().clone();
     }
@@ -1,4 +1,4 @@
-use tracing::debug;
+use tracing::{debug, error};
 use rcdproto::rcdp::{AuthRequest, RevokeReply, TokenReply};
 
 use crate::rcd::Rcd;
@@ -10,12 +10,19 @@ pub async fn auth_for_token(core: &Rcd, request: AuthRequest) -> TokenReply {
     let mut is_successful = false;
 
     if auth_result.0 {
-        let result = core.dbi().create_token_for_login(&request.user_name);
-        jwt = result.0;
-        expiration = result.1.to_rfc3339();
-        is_successful = true;
-
-        debug!("created rcd token for {}", &request.user_name);
+        match core
+            .dbi()
+            .refresh_token_for_login(&request.user_name, &request.jwt)
+        {
+            Ok(result) => {
+                jwt = result.0;
+                expiration = result.1.to_rfc3339();
+                is_successful = true;
+
+                debug!("created rcd token for {}", &request.user_name);
+            }
+            Err(e) => error!("could not create a token for {}: {e}", &request.user_name),
+        }
     }
 
     TokenReply {
@@ -31,8 +38,10 @@ pub async fn revoke_token(core: &Rcd, request: AuthRequest) -> RevokeReply {
     let mut is_successful = false;
 
     if auth_result.0 {
-        let result = core.dbi().revoke_token(&jwt);
-        is_successful = result;
+        match core.dbi().revoke_token(&jwt) {
+            Ok(result) => is_successful = result,
+            Err(e) => error!("could not revoke token: {e}"),
+        }
     }
 
     RevokeReply { is_successful }
@@ -1,8 +1,12 @@
-use tracing::debug;
+use tracing::{debug, warn};
+use rcd_common::{defaults, host_info::HostInfo};
+use rcd_enum::rcd_database_type::RcdDatabaseType;
 use rcdproto::rcdp::{
-    AcceptPendingContractReply, AcceptPendingContractRequest, Contract, ViewPendingContractsReply,
-    ViewPendingContractsRequest,
+    AcceptPendingContractReply, AcceptPendingContractRequest, Contract, RejectPendingContractReply,
+    RejectPendingContractRequest, ViewPendingContractsReply, ViewPendingContractsRequest,
 };
+use std::time::Duration;
+use tokio::time::sleep;
 
 use super::Rcd;
 
@@ -23,7 +27,16 @@ pub async fn accept_pending_contract(
 
         debug!("requested host_alias: {}", request.host_alias);
 
-        let contracts = core.dbi().get_pending_contracts();
+        let contracts = match core.dbi().get_pending_contracts() {
+            Ok(contracts) => contracts,
+            Err(e) => {
+                return AcceptPendingContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: e.to_string(),
+                };
+            }
+        };
 
         debug!("{contracts:?}");
 
@@ -35,16 +48,69 @@ pub async fn accept_pending_contract(
 
         let param_contract = pending_contract.last().unwrap().clone();
 
+        let db_name = &param_contract.schema.as_ref().unwrap().database_name;
+        let mut is_key_set = false;
+
+        // the partial database can only be encrypted as it is created, so the key is
+        // checked before we agree to anything
+        if let Some(key) = request.database_key.as_ref().filter(|k| !k.is_empty()) {
+            if let Err(e) = core
+                .dbi()
+                .set_database_key(db_name, RcdDatabaseType::Partial, key)
+            {
+                return AcceptPendingContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: e.to_string(),
+                };
+            }
+
+            is_key_set = true;
+        }
+
         // 1 - accept the contract
-        let is_contract_updated = core.dbi().accept_pending_contract(&request.host_alias);
+        let is_contract_updated = match core.dbi().accept_pending_contract(&request.host_alias) {
+            Ok(is_contract_updated) => is_contract_updated,
+            Err(e) => {
+                warn!("failed to update contract in rcd db: {e}");
+                false
+            }
+        };
 
         // 2 - create the database with the properties of the contract
         // make the database
-        let db_is_created = core
+        let db_is_created = match core
             .dbi()
-            .create_partial_database_from_contract(&param_contract);
+            .create_partial_database_from_contract(&param_contract)
+        {
+            Ok(db_is_created) => db_is_created,
+            Err(e) => {
+                warn!("failed to create partial db from contract: {e}");
+                false
+            }
+        };
+
+        // without a database there is nothing for the key to open, so don't leave the name
+        // marked as encrypted for the next attempt
+        if is_key_set && !db_is_created {
+            if let Err(e) = core
+                .dbi()
+                .clear_database_key(db_name, RcdDatabaseType::Partial)
+            {
+                warn!("unable to clear the key for {db_name}: {e}");
+            }
+        }
 
-        let self_host_info = core.dbi().rcd_get_host_info().expect("no host info is set");
+        let self_host_info = match core.dbi().rcd_get_host_info() {
+            Ok(Some(host_info)) => host_info,
+            _ => {
+                return AcceptPendingContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: String::from("no host info is set"),
+                };
+            }
+        };
         // 3 - notify the host that we've accepted the contract
         let is_host_notified = core
             .remote()
@@ -70,6 +136,127 @@ pub async fn accept_pending_contract(
     }
 }
 
+pub async fn reject_pending_contract(
+    core: &Rcd,
+    request: RejectPendingContractRequest,
+) -> RejectPendingContractReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+
+    let mut is_rejected = false;
+    let mut return_message = String::from("");
+
+    if auth_result.0 {
+        // 1 - we need to update the rcd_db record that we are rejecting this contract
+        // 2 - we need to notify the host that we have rejected the contract
+        // the rejection is ours to make, so it is recorded even if the host can't be told
+
+        debug!("requested host_alias: {}", request.host_alias);
+
+        let pending_contracts = match core.dbi().get_pending_contracts() {
+            Ok(pending_contracts) => pending_contracts,
+            Err(e) => {
+                return RejectPendingContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: e.to_string(),
+                };
+            }
+        };
+
+        let pending_contract = pending_contracts
+            .into_iter()
+            .rev()
+            .find(|c| c.host_info.as_ref().unwrap().host_name == request.host_alias);
+
+        match pending_contract {
+            Some(param_contract) => {
+                let self_host_info = match core.dbi().rcd_get_host_info() {
+                    Ok(Some(host_info)) => host_info,
+                    _ => {
+                        return RejectPendingContractReply {
+                            authentication_result: Some(auth_result.1),
+                            is_successful: false,
+                            message: String::from("no host info is set"),
+                        };
+                    }
+                };
+
+                // 1 - reject the contract
+                match core.dbi().reject_pending_contract(&request.host_alias) {
+                    Ok(true) => {
+                        is_rejected = true;
+                    }
+                    Ok(false) => {
+                        return_message = String::from("failed to update contract in rcd db");
+                    }
+                    Err(e) => {
+                        return_message = format!("failed to update contract in rcd db: {e}");
+                    }
+                }
+
+                // 2 - notify the host that we've rejected the contract
+                if is_rejected {
+                    let notify_result =
+                        notify_host_of_rejection(core, &param_contract, &self_host_info).await;
+
+                    return_message = match notify_result {
+                        Ok(()) => String::from("rejected contract successfuly"),
+                        Err(e) => {
+                            warn!("failed to notify host of rejection of contract: {e}");
+                            format!("rejected contract, but failed to notify host: {e}")
+                        }
+                    };
+                }
+            }
+            None => {
+                return_message = format!(
+                    "no pending contract was found from host {}",
+                    request.host_alias
+                );
+            }
+        }
+    };
+
+    RejectPendingContractReply {
+        authentication_result: Some(auth_result.1),
+        is_successful: is_rejected,
+        message: return_message,
+    }
+}
+
+/// Tells the host that we've rejected its contract, trying again a few times if the host
+/// can't be reached or doesn't acknowledge it
+async fn notify_host_of_rejection(
+    core: &Rcd,
+    contract: &Contract,
+    self_host_info: &HostInfo,
+) -> Result<(), String> {
+    let mut last_error = String::from("");
+
+    for attempt in 1..=defaults::NOTIFY_HOST_ATTEMPTS {
+        match core
+            .remote()
+            .notify_host_of_rejection_of_contract(contract, self_host_info)
+            .await
+        {
+            Ok(true) => return Ok(()),
+            Ok(false) => {
+                last_error = String::from("host did not acknowledge the rejection of the contract")
+            }
+            Err(e) => last_error = e,
+        }
+
+        debug!("attempt {attempt} to notify host of rejection failed: {last_error}");
+
+        if attempt < defaults::NOTIFY_HOST_ATTEMPTS {
+            let delay = Duration::from_millis(defaults::NOTIFY_HOST_RETRY_DELAY_IN_MS);
+            sleep(delay).await;
+        }
+    }
+
+    Err(last_error)
+}
+
 pub async fn review_pending_contracts(
     core: &Rcd,
     request: ViewPendingContractsRequest,
@@ -78,7 +265,10 @@ pub async fn review_pending_contracts(
     let mut pending_contracts: Vec<Contract> = Vec::new();
 
     if auth_result.0 {
-        pending_contracts = core.dbi().get_pending_contracts();
+        match core.dbi().get_pending_contracts() {
+            Ok(contracts) => pending_contracts = contracts,
+            Err(e) => warn!("could not read pending contracts: {e}"),
+        }
     };
 
     ViewPendingContractsReply {
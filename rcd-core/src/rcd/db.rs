@@ -1,11 +1,16 @@
 use tracing::{trace, warn};
-use rcd_common::{data_info::DataInfo, host_info::HostInfo};
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use rcd_common::{
+    data_info::{DataInfo, PendingActionInfo},
+    db::{CdsHosts, PartialDataResult},
+    host_info::HostInfo,
+};
 
 use super::Rcd;
 use rcd_enum::{
     deletes_from_host_behavior::DeletesFromHostBehavior,
     deletes_to_host_behavior::DeletesToHostBehavior, host_status::HostStatus,
-    partial_data_result_action::PartialDataResultAction,
+    partial_data_result_action::PartialDataResultAction, rcd_database_type::RcdDatabaseType,
     rcd_generate_contract_error::RcdGenerateContractError,
     remote_delete_behavior::RemoteDeleteBehavior,
     updates_from_host_behavior::UpdatesFromHostBehavior,
@@ -21,13 +26,20 @@ use rcdproto::rcdp::{
     DatabaseSchema, EnableCoooperativeFeaturesReply, EnableCoooperativeFeaturesRequest,
     GenerateContractReply, GenerateContractRequest, GenerateHostInfoReply, GenerateHostInfoRequest,
     GetActiveContractReply, GetActiveContractRequest, GetCooperativeHostsReply,
-    GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest, GetDatabasesReply,
-    GetDatabasesRequest, GetDeletesFromHostBehaviorReply, GetDeletesFromHostBehaviorRequest,
+    GetCooperativeHostsRequest, GetDataHashReply, GetDataHashRequest, GetDataLogTableStatusReply,
+    GetDataLogTableStatusRequest, GetDatabasesReply, GetDatabasesRequest,
+    GetDeletesFromHostBehaviorReply, GetDeletesFromHostBehaviorRequest,
     GetDeletesToHostBehaviorReply, GetDeletesToHostBehaviorRequest, GetParticipantsReply,
-    GetParticipantsRequest, GetPendingActionsReply, GetPendingActionsRequest, GetReadRowIdsReply,
-    GetReadRowIdsRequest, GetUpdatesFromHostBehaviorReply, GetUpdatesFromHostBehaviorRequest,
+    GetParticipantsRequest, GetPendingActionTtlReply, GetPendingActionTtlRequest,
+    GetPendingActionsReply, GetPendingActionsRequest, GetPendingWritesAtHostReply,
+    GetPendingWritesAtHostRequest, GetReadRowIdsReply, GetReadRowIdsRequest,
+    GetUpdatesFromHostBehaviorReply, GetUpdatesFromHostBehaviorRequest,
     GetUpdatesToHostBehaviorReply, GetUpdatesToHostBehaviorRequest, HasTableReply, HasTableRequest,
-    Host, HostInfoReply, HostInfoStatus, ParticipantStatus, PendingStatement, RcdError,
+    Host, HostInfoReply, HostInfoStatus, ParticipantStatus, PendingStatement, PendingWrite,
+    RcdError, RehashPartialDatabaseReply, RehashPartialDatabaseRequest, RejectPendingActionReply,
+    RejectPendingActionRequest, SetDataLogTableStatusReply, SetDataLogTableStatusRequest,
+    SetPendingActionTtlReply, SetPendingActionTtlRequest, UnlockDatabaseReply,
+    UnlockDatabaseRequest, VersionReply,
 };
 
 pub async fn create_user_database(
@@ -35,22 +47,36 @@ pub async fn create_user_database(
     request: CreateUserDatabaseRequest,
 ) -> CreateUserDatabaseReply {
     let mut is_database_created = false;
+    let mut message = String::from("");
 
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     let db_name = request.database_name;
 
     if auth_result.0 {
-        let result = core.dbi().create_database(&db_name);
-        if result.is_ok() {
-            is_database_created = true;
+        let key_result = match &request.database_key {
+            Some(key) if !key.is_empty() => {
+                core.dbi()
+                    .set_database_key(&db_name, RcdDatabaseType::Host, key)
+            }
+            _ => Ok(()),
+        };
+
+        match key_result {
+            Ok(_) => {
+                let result = core.dbi().create_database(&db_name);
+                if result.is_ok() {
+                    is_database_created = true;
+                }
+            }
+            Err(e) => message = e.to_string(),
         }
     }
 
     CreateUserDatabaseReply {
         authentication_result: Some(auth_result.1),
         is_created: is_database_created,
-        message: String::from(""),
+        message,
     }
 }
 
@@ -62,7 +88,10 @@ pub async fn get_cooperative_hosts(
     let mut hosts: Vec<HostInfoStatus> = Vec::new();
 
     if auth_result.0 {
-        let result = core.dbi().get_cooperative_hosts();
+        let result = core.dbi().get_cooperative_hosts().unwrap_or_else(|e| {
+            warn!("unable to read cooperative hosts: {e}");
+            Vec::new()
+        });
 
         if !result.is_empty() {
             for host in &result {
@@ -99,7 +128,10 @@ pub async fn get_host_info(core: &Rcd, request: AuthRequest) -> HostInfoReply {
     let mut host_info: Option<HostInfo> = None;
 
     if auth_result.0 {
-        host_info = core.dbi().rcd_get_host_info();
+        host_info = core.dbi().rcd_get_host_info().unwrap_or_else(|e| {
+            warn!("unable to read host info: {e}");
+            None
+        });
     }
 
     let host = if host_info.is_some() {
@@ -132,6 +164,33 @@ pub async fn get_host_info(core: &Rcd, request: AuthRequest) -> HostInfoReply {
     }
 }
 
+pub async fn get_versions(core: &Rcd, request: AuthRequest) -> VersionReply {
+    let auth_result = core.verify_login(request);
+
+    if !auth_result.0 {
+        return VersionReply {
+            authentication_result: Some(auth_result.1),
+            rcdx: String::from(""),
+            rcd_core: String::from(""),
+            rcdproto: String::from(""),
+            protocol_version: 0,
+            rcd_schema_version: 0,
+        };
+    }
+
+    VersionReply {
+        authentication_result: Some(auth_result.1),
+        rcdx: core.rcdx_version.clone(),
+        rcd_core: crate::VERSION.to_string(),
+        rcdproto: rcdproto::VERSION.to_string(),
+        protocol_version: rcdproto::PROTOCOL_VERSION,
+        rcd_schema_version: core.dbi().get_rcd_schema_version().unwrap_or_else(|e| {
+            warn!("unable to read rcd schema version: {e}");
+            0
+        }),
+    }
+}
+
 pub async fn generate_host_info(
     core: &Rcd,
     request: GenerateHostInfoRequest,
@@ -143,8 +202,10 @@ pub async fn generate_host_info(
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     if auth_result.0 {
-        core.dbi().rcd_generate_host_info(&host_name);
-        is_generate_successful = true;
+        match core.dbi().rcd_generate_host_info(&host_name) {
+            Ok(()) => is_generate_successful = true,
+            Err(e) => warn!("unable to generate host info: {e}"),
+        }
     }
 
     GenerateHostInfoReply {
@@ -167,10 +228,22 @@ pub async fn change_host_status(
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     if auth_result.0 {
-        name_result = core.dbi().change_host_status_by_name(&host_name, status);
+        name_result = core
+            .dbi()
+            .change_host_status_by_name(&host_name, status)
+            .unwrap_or_else(|e| {
+                warn!("unable to change status of host {host_name}: {e}");
+                false
+            });
 
         if !name_result {
-            id_result = core.dbi().change_host_status_by_id(&host_id, status);
+            id_result = core
+                .dbi()
+                .change_host_status_by_id(&host_id, status)
+                .unwrap_or_else(|e| {
+                    warn!("unable to change status of host {host_id}: {e}");
+                    false
+                });
         }
     }
 
@@ -193,7 +266,14 @@ pub async fn get_pending_updates_at_participant(
     let mut pending_statements: Vec<PendingStatement> = Vec::new();
 
     if auth_result.0 {
-        pending_statements = core.dbi().get_pending_actions(db_name, table_name, action);
+        expire_pending_actions(core, db_name, table_name).await;
+        pending_statements = core
+            .dbi()
+            .get_pending_actions(db_name, table_name, action)
+            .unwrap_or_else(|e| {
+                warn!("unable to read pending actions for {db_name}.{table_name}: {e}");
+                Vec::new()
+            });
     }
 
     GetPendingActionsReply {
@@ -216,9 +296,21 @@ pub async fn accept_pending_action_at_participant(
         let table_name = &request.table_name;
         let row_id = request.row_id;
 
-        let data_result = core
+        expire_pending_actions(core, db_name, table_name).await;
+
+        let data_result = match core
             .dbi()
-            .accept_pending_action_at_participant(db_name, table_name, row_id);
+            .accept_pending_action_at_participant(db_name, table_name, row_id)
+        {
+            Ok(data_result) => data_result,
+            Err(e) => {
+                warn!("unable to accept pending action {row_id} in {db_name}.{table_name}: {e}");
+                return AcceptPendingActionReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                };
+            }
+        };
 
         trace!("{data_result:?}");
         trace!("is_local_update_successful: {is_local_update_successful}");
@@ -226,36 +318,9 @@ pub async fn accept_pending_action_at_participant(
         if data_result.is_successful {
             is_local_update_successful = true;
 
-            let remote_host = core.dbi().get_cds_host_for_part_db(db_name).unwrap();
-            let own_host_info = core
-                .dbi()
-                .rcd_get_host_info()
-                .expect("no host info is set")
-                .clone();
-            let hash = data_result.data_hash;
-
-            let is_deleted = match data_result.action {
-                Some(action) => match action {
-                    PartialDataResultAction::Unknown => false,
-                    PartialDataResultAction::Insert => false,
-                    PartialDataResultAction::Update => false,
-                    PartialDataResultAction::Delete => true,
-                },
-                None => false,
-            };
-
-            let data_info = DataInfo {
-                db_name: db_name.to_string(),
-                table_name: table_name.to_string(),
-                row_id,
-                hash,
-                is_deleted,
-            };
-
-            let notify_is_successful = core
-                .remote()
-                .notify_host_of_updated_hash(&remote_host, &own_host_info, &data_info)
-                .await;
+            let notify_is_successful =
+                notify_host_of_pending_action(core, db_name, table_name, row_id, data_result, true)
+                    .await;
 
             trace!("notify_is_successful: {notify_is_successful}");
 
@@ -273,6 +338,280 @@ pub async fn accept_pending_action_at_participant(
     }
 }
 
+/// Removes a queued action without running it and lets the host know it was rejected
+pub async fn reject_pending_action_at_participant(
+    core: &Rcd,
+    request: RejectPendingActionRequest,
+) -> RejectPendingActionReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+
+    let mut is_local_update_successful = false;
+    let mut is_remote_update_successful = false;
+
+    if auth_result.0 {
+        let db_name = &request.database_name;
+        let table_name = &request.table_name;
+
+        let data_result = match core.dbi().reject_pending_action_at_participant(
+            db_name,
+            table_name,
+            request.row_id,
+        ) {
+            Ok(data_result) => data_result,
+            Err(e) => {
+                warn!(
+                    "unable to reject pending action {} in {db_name}.{table_name}: {e}",
+                    request.row_id
+                );
+                return RejectPendingActionReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                };
+            }
+        };
+
+        trace!("{data_result:?}");
+
+        if data_result.is_successful {
+            is_local_update_successful = true;
+            is_remote_update_successful = notify_host_of_pending_action(
+                core,
+                db_name,
+                table_name,
+                request.row_id,
+                data_result,
+                false,
+            )
+            .await;
+        }
+    } else {
+        trace!("not authenticated");
+    }
+
+    RejectPendingActionReply {
+        authentication_result: Some(auth_result.1),
+        is_successful: is_local_update_successful && is_remote_update_successful,
+    }
+}
+
+pub async fn get_pending_action_ttl_at_participant(
+    core: &Rcd,
+    request: GetPendingActionTtlRequest,
+) -> GetPendingActionTtlReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut ttl_seconds = 0;
+
+    if auth_result.0 {
+        ttl_seconds = core
+            .dbi()
+            .get_pending_action_ttl(&request.database_name, &request.table_name)
+            .unwrap_or_else(|e| {
+                warn!("unable to read pending action ttl: {e}");
+                0
+            });
+    }
+
+    GetPendingActionTtlReply {
+        authentication_result: Some(auth_result.1),
+        ttl_seconds,
+    }
+}
+
+/// Sets how long an action can wait in a table's queue before it is rejected. A TTL of 0 keeps
+/// queued actions until they are accepted or rejected.
+pub async fn set_pending_action_ttl_at_participant(
+    core: &Rcd,
+    request: SetPendingActionTtlRequest,
+) -> SetPendingActionTtlReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut is_successful = false;
+
+    if auth_result.0 {
+        is_successful = core
+            .dbi()
+            .set_pending_action_ttl(
+                &request.database_name,
+                &request.table_name,
+                request.ttl_seconds,
+            )
+            .unwrap_or_else(|e| {
+                warn!("unable to set pending action ttl: {e}");
+                false
+            });
+    }
+
+    SetPendingActionTtlReply {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+    }
+}
+
+/// Rejects every action that has waited in the table's queue for longer than the table's TTL.
+/// Actions aren't expired on a timer, only the next time the queue is looked at or acted on.
+async fn expire_pending_actions(core: &Rcd, db_name: &str, table_name: &str) {
+    let ttl_seconds = match core.dbi().get_pending_action_ttl(db_name, table_name) {
+        Ok(ttl_seconds) => ttl_seconds,
+        Err(e) => {
+            warn!("unable to read pending action ttl for {db_name}.{table_name}: {e}");
+            return;
+        }
+    };
+
+    if ttl_seconds == 0 {
+        return;
+    }
+
+    let now = Utc::now();
+
+    for action in ["UPDATE", "DELETE"] {
+        let pending_statements = match core.dbi().get_pending_actions(db_name, table_name, action) {
+            Ok(pending_statements) => pending_statements,
+            Err(e) => {
+                warn!("unable to expire pending actions for {db_name}.{table_name}: {e}");
+                return;
+            }
+        };
+
+        for pending_statement in pending_statements {
+            let requested_ts = match NaiveDateTime::parse_from_str(
+                pending_statement.requested_ts_utc.trim_end_matches(" UTC"),
+                "%Y-%m-%d %H:%M:%S%.f",
+            ) {
+                Ok(requested_ts) => Utc.from_utc_datetime(&requested_ts),
+                Err(e) => {
+                    warn!(
+                        "unable to read when action {} was queued: {e}",
+                        pending_statement.row_id
+                    );
+                    continue;
+                }
+            };
+
+            if (now - requested_ts).num_seconds() < i64::from(ttl_seconds) {
+                continue;
+            }
+
+            let data_result = match core.dbi().reject_pending_action_at_participant(
+                db_name,
+                table_name,
+                pending_statement.row_id,
+            ) {
+                Ok(data_result) => data_result,
+                Err(e) => {
+                    warn!("unable to expire action {}: {e}", pending_statement.row_id);
+                    continue;
+                }
+            };
+
+            if data_result.is_successful {
+                let notify_is_successful = notify_host_of_pending_action(
+                    core,
+                    db_name,
+                    table_name,
+                    pending_statement.row_id,
+                    data_result,
+                    false,
+                )
+                .await;
+
+                trace!(
+                    "expired action {} in {table_name}, host notified: {notify_is_successful}",
+                    pending_statement.row_id
+                );
+            }
+        }
+    }
+}
+
+/// Tells the host that the action queued under `pending_action_id` has been accepted or rejected,
+/// along with the rows it changed
+async fn notify_host_of_pending_action(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+    pending_action_id: u32,
+    data_result: PartialDataResult,
+    is_accepted: bool,
+) -> bool {
+    let Some((remote_host, own_host_info)) = get_hosts_for_part_db(core, db_name) else {
+        return false;
+    };
+
+    let action = match data_result.action {
+        Some(PartialDataResultAction::Delete) => "DELETE",
+        _ => "UPDATE",
+    };
+
+    let pending_action_info = PendingActionInfo {
+        db_name: db_name.to_string(),
+        table_name: table_name.to_string(),
+        pending_action_id,
+        action: action.to_string(),
+        is_accepted,
+        rows: data_result.affected_rows,
+    };
+
+    core.remote()
+        .notify_host_of_pending_action(&remote_host, &own_host_info, &pending_action_info)
+        .await
+}
+
+/// Returns the host of a partial database along with our own host info, which are needed to
+/// send the host anything about the database
+fn get_hosts_for_part_db(core: &Rcd, db_name: &str) -> Option<(CdsHosts, HostInfo)> {
+    let remote_host = match core.dbi().get_cds_host_for_part_db(db_name) {
+        Ok(Some(remote_host)) => remote_host,
+        Ok(None) => {
+            warn!("no host is set for {db_name}");
+            return None;
+        }
+        Err(e) => {
+            warn!("unable to read host for {db_name}: {e}");
+            return None;
+        }
+    };
+
+    match core.dbi().rcd_get_host_info() {
+        Ok(Some(own_host_info)) => Some((remote_host, own_host_info)),
+        Ok(None) => {
+            warn!("no host info is set");
+            None
+        }
+        Err(e) => {
+            warn!("unable to read host info: {e}");
+            None
+        }
+    }
+}
+
+/// Returns the writes that participants have queued for review and not yet accepted or
+/// rejected
+pub async fn get_pending_writes_at_host(
+    core: &Rcd,
+    request: GetPendingWritesAtHostRequest,
+) -> GetPendingWritesAtHostReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut pending_writes: Vec<PendingWrite> = Vec::new();
+
+    if auth_result.0 {
+        pending_writes = core
+            .dbi()
+            .get_pending_writes_at_host(&request.database_name)
+            .unwrap_or_else(|e| {
+                warn!(
+                    "unable to read pending writes for {}: {e}",
+                    request.database_name
+                );
+                Vec::new()
+            });
+    }
+
+    GetPendingWritesAtHostReply {
+        authentication_result: Some(auth_result.1),
+        pending_writes,
+    }
+}
+
 pub async fn has_table(core: &Rcd, request: HasTableRequest) -> HasTableReply {
     let mut has_table = false;
 
@@ -282,7 +621,13 @@ pub async fn has_table(core: &Rcd, request: HasTableRequest) -> HasTableReply {
     let table_name = request.table_name;
 
     if auth_result.0 {
-        has_table = core.dbi().has_table(&db_name, table_name.as_str())
+        has_table = core
+            .dbi()
+            .has_table(&db_name, table_name.as_str())
+            .unwrap_or_else(|e| {
+                warn!("unable to check for {table_name} in {db_name}: {e}");
+                false
+            });
     }
 
     HasTableReply {
@@ -344,9 +689,13 @@ pub async fn get_data_hash_at_participant(
     let mut row_hash: u64 = 0;
 
     if auth_result.0 {
-        row_hash = core
+        match core
             .dbi()
-            .get_data_hash_at_participant(&db_name, &table_name, requested_row_id);
+            .get_data_hash_at_participant(&db_name, &table_name, requested_row_id)
+        {
+            Ok(hash) => row_hash = hash,
+            Err(e) => warn!("unable to read hash of row {requested_row_id} in {db_name}: {e}"),
+        }
     }
 
     GetDataHashReply {
@@ -355,6 +704,99 @@ pub async fn get_data_hash_at_participant(
     }
 }
 
+/// Hands rcd the key for a database that is encrypted at rest, so that it can be read and
+/// written again (and, for a partial database, serve the host) after a restart
+pub async fn unlock_database(core: &Rcd, request: UnlockDatabaseRequest) -> UnlockDatabaseReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut is_successful = false;
+    let mut message = String::from("");
+
+    if auth_result.0 {
+        match core
+            .dbi()
+            .unlock_database(&request.database_name, &request.database_key)
+        {
+            Ok(_) => is_successful = true,
+            Err(e) => message = e.to_string(),
+        }
+    }
+
+    UnlockDatabaseReply {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+        message,
+    }
+}
+
+/// Recomputes the hash of every row in a partial database, for when the way row hashes are
+/// calculated has changed, and sends each new hash to the host so that it can still verify
+/// the rows it reads from us
+pub async fn rehash_partial_database(
+    core: &Rcd,
+    request: RehashPartialDatabaseRequest,
+) -> RehashPartialDatabaseReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let db_name = request.database_name;
+    let mut is_successful = false;
+    let mut total_rows_rehashed: u32 = 0;
+
+    if auth_result.0 {
+        let rehashed_rows = match core.dbi().rehash_partial_database(&db_name) {
+            Ok(rehashed_rows) => rehashed_rows,
+            Err(e) => {
+                warn!("unable to rehash {db_name}: {e}");
+                return RehashPartialDatabaseReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    total_rows_rehashed,
+                };
+            }
+        };
+
+        total_rows_rehashed = rehashed_rows.len() as u32;
+        is_successful = true;
+
+        if !rehashed_rows.is_empty() {
+            let Some((remote_host, own_host_info)) = get_hosts_for_part_db(core, &db_name) else {
+                return RehashPartialDatabaseReply {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    total_rows_rehashed,
+                };
+            };
+
+            for row in rehashed_rows {
+                let data_info = DataInfo {
+                    db_name: db_name.clone(),
+                    table_name: row.table_name,
+                    row_id: row.row_id,
+                    hash: Some(row.hash),
+                    is_deleted: false,
+                };
+
+                let notify_is_successful = core
+                    .remote()
+                    .notify_host_of_updated_hash(&remote_host, &own_host_info, &data_info)
+                    .await;
+
+                if !notify_is_successful {
+                    warn!(
+                        "unable to notify host of new hash for row {} in {}",
+                        data_info.row_id, data_info.table_name
+                    );
+                    is_successful = false;
+                }
+            }
+        }
+    }
+
+    RehashPartialDatabaseReply {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+        total_rows_rehashed,
+    }
+}
+
 pub async fn change_updates_from_host_behavior(
     core: &Rcd,
     request: ChangeUpdatesFromHostBehaviorRequest,
@@ -366,9 +808,13 @@ pub async fn change_updates_from_host_behavior(
     let mut is_successful = false;
 
     if auth_result.0 {
-        is_successful =
-            core.dbi()
-                .change_updates_from_host_behavior(&db_name, &table_name, behavior);
+        is_successful = core
+            .dbi()
+            .change_updates_from_host_behavior(&db_name, &table_name, behavior)
+            .unwrap_or_else(|e| {
+                warn!("unable to change updates from host behavior for {table_name}: {e}");
+                false
+            });
     }
 
     ChangesUpdatesFromHostBehaviorReply {
@@ -388,10 +834,13 @@ pub async fn get_updates_to_host_behavior(
     let mut behavior = 0;
 
     if auth_result.0 {
-        let x = core
+        match core
             .dbi()
-            .get_updates_to_host_behavior(&db_name, &table_name);
-        behavior = UpdatesToHostBehavior::to_u32(x);
+            .get_updates_to_host_behavior(&db_name, &table_name)
+        {
+            Ok(x) => behavior = UpdatesToHostBehavior::to_u32(x),
+            Err(e) => warn!("unable to read updates to host behavior for {table_name}: {e}"),
+        }
     }
 
     GetUpdatesToHostBehaviorReply {
@@ -410,10 +859,13 @@ pub async fn get_updates_from_host_behavior(
     let mut behavior = 0;
 
     if auth_result.0 {
-        let x = core
+        match core
             .dbi()
-            .get_updates_from_host_behavior(&db_name, &table_name);
-        behavior = UpdatesFromHostBehavior::to_u32(x);
+            .get_updates_from_host_behavior(&db_name, &table_name)
+        {
+            Ok(x) => behavior = UpdatesFromHostBehavior::to_u32(x),
+            Err(e) => warn!("unable to read updates from host behavior for {table_name}: {e}"),
+        }
     }
 
     GetUpdatesFromHostBehaviorReply {
@@ -422,6 +874,59 @@ pub async fn get_updates_from_host_behavior(
     }
 }
 
+pub async fn get_data_log_table_status_at_participant(
+    core: &Rcd,
+    request: GetDataLogTableStatusRequest,
+) -> GetDataLogTableStatusReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut use_data_log = false;
+
+    if auth_result.0 {
+        use_data_log = core
+            .dbi()
+            .get_data_log_table_status(&request.database_name, &request.table_name)
+            .unwrap_or_else(|e| {
+                warn!("unable to read data log table status: {e}");
+                false
+            });
+    }
+
+    GetDataLogTableStatusReply {
+        authentication_result: Some(auth_result.1),
+        use_data_log,
+    }
+}
+
+/// Switches the data log table on or off for a table in a partial database. While it's on, a
+/// copy of every row changed by the host or by us is kept in the table's data log table, which
+/// can be read with `execute_read_at_participant`.
+pub async fn set_data_log_table_status_at_participant(
+    core: &Rcd,
+    request: SetDataLogTableStatusRequest,
+) -> SetDataLogTableStatusReply {
+    let auth_result = core.verify_login(request.authentication.unwrap());
+    let mut is_successful = false;
+
+    if auth_result.0 {
+        is_successful = core
+            .dbi()
+            .set_data_log_table_status(
+                &request.database_name,
+                &request.table_name,
+                request.use_data_log,
+            )
+            .unwrap_or_else(|e| {
+                warn!("unable to set data log table status: {e}");
+                false
+            });
+    }
+
+    SetDataLogTableStatusReply {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+    }
+}
+
 pub async fn get_active_contract(
     core: &Rcd,
     request: GetActiveContractRequest,
@@ -429,12 +934,15 @@ pub async fn get_active_contract(
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     if auth_result.0 {
-        let contract = core.dbi().get_active_contract_proto(&request.database_name);
-
-        return GetActiveContractReply {
-            authentication_result: Some(auth_result.1),
-            contract: Some(contract),
-        };
+        match core.dbi().get_active_contract_proto(&request.database_name) {
+            Ok(contract) => {
+                return GetActiveContractReply {
+                    authentication_result: Some(auth_result.1),
+                    contract: Some(contract),
+                };
+            }
+            Err(e) => warn!("get_active_contract: {e}"),
+        }
     }
 
     GetActiveContractReply {
@@ -488,11 +996,18 @@ pub async fn get_databases(core: &Rcd, request: GetDatabasesRequest) -> GetDatab
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     if auth_result.0 {
-        let db_names = core.dbi().get_database_names();
+        let db_names = core.dbi().get_database_names().unwrap_or_else(|e| {
+            warn!("get_databases: could not read database names: {e}");
+            Vec::new()
+        });
         for name in &db_names {
-            let db_schema = core.dbi().get_database_schema(name);
-            trace!("{db_schema:?}");
-            db_result.push(db_schema);
+            match core.dbi().get_database_schema(name) {
+                Ok(db_schema) => {
+                    trace!("{db_schema:?}");
+                    db_result.push(db_schema);
+                }
+                Err(e) => warn!("get_databases: could not read schema of {name}: {e}"),
+            }
         }
     }
 
@@ -510,9 +1025,13 @@ pub async fn get_data_hash_at_host(core: &Rcd, request: GetDataHashRequest) -> G
     let mut row_hash: u64 = 0;
 
     if auth_result.0 {
-        row_hash = core
+        match core
             .dbi()
-            .get_data_hash_at_host(&db_name, &table_name, requested_row_id);
+            .get_data_hash_at_host(&db_name, &table_name, requested_row_id)
+        {
+            Ok(hash) => row_hash = hash,
+            Err(e) => warn!("unable to read hash of row {requested_row_id} in {db_name}: {e}"),
+        }
     }
 
     GetDataHashReply {
@@ -532,9 +1051,13 @@ pub async fn change_deletes_from_host_behavior(
     let mut is_successful = false;
 
     if auth_result.0 {
-        is_successful =
-            core.dbi()
-                .change_deletes_from_host_behavior(&db_name, &table_name, behavior);
+        is_successful = core
+            .dbi()
+            .change_deletes_from_host_behavior(&db_name, &table_name, behavior)
+            .unwrap_or_else(|e| {
+                warn!("unable to change deletes from host behavior for {table_name}: {e}");
+                false
+            });
     }
 
     ChangeDeletesFromHostBehaviorReply {
@@ -556,10 +1079,13 @@ pub async fn get_deletes_from_host_behavior(
     let mut behavior = 0;
 
     if auth_result.0 {
-        let x = core
+        match core
             .dbi()
-            .get_deletes_from_host_behavior(&db_name, &table_name);
-        behavior = DeletesFromHostBehavior::to_u32(x);
+            .get_deletes_from_host_behavior(&db_name, &table_name)
+        {
+            Ok(x) => behavior = DeletesFromHostBehavior::to_u32(x),
+            Err(e) => warn!("unable to read deletes from host behavior for {table_name}: {e}"),
+        }
     }
 
     GetDeletesFromHostBehaviorReply {
@@ -580,10 +1106,13 @@ pub async fn get_deletes_to_host_behavior(
     let mut behavior = 0;
 
     if auth_result.0 {
-        let x = core
+        match core
             .dbi()
-            .get_deletes_to_host_behavior(&db_name, &table_name);
-        behavior = DeletesToHostBehavior::to_u32(x);
+            .get_deletes_to_host_behavior(&db_name, &table_name)
+        {
+            Ok(x) => behavior = DeletesToHostBehavior::to_u32(x),
+            Err(e) => warn!("unable to read deletes to host behavior for {table_name}: {e}"),
+        }
     }
 
     GetDeletesToHostBehaviorReply {
@@ -606,7 +1135,11 @@ pub async fn change_deletes_to_host_behavior(
     if auth_result.0 {
         is_successful = core
             .dbi()
-            .change_deletes_to_host_behavior(&db_name, &table_name, behavior);
+            .change_deletes_to_host_behavior(&db_name, &table_name, behavior)
+            .unwrap_or_else(|e| {
+                warn!("unable to change deletes to host behavior for {table_name}: {e}");
+                false
+            });
     }
 
     ChangeDeletesToHostBehaviorReply {
@@ -629,7 +1162,11 @@ pub async fn change_updates_to_host_behavior(
     if auth_result.0 {
         is_successful = core
             .dbi()
-            .change_updates_to_host_behavior(&db_name, &table_name, behavior);
+            .change_updates_to_host_behavior(&db_name, &table_name, behavior)
+            .unwrap_or_else(|e| {
+                warn!("unable to change updates to host behavior for {table_name}: {e}");
+                false
+            });
     }
 
     ChangeUpdatesToHostBehaviorReply {
@@ -647,18 +1184,17 @@ pub async fn read_row_id_at_participant(
     let db_name = request.database_name;
     let table_name = request.table_name;
     let where_clause = request.where_clause;
-    let mut row_id = 0;
 
     let mut row_ids: Vec<u32> = Vec::new();
 
     if auth_result.0 {
-        row_id = core
+        match core
             .dbi()
-            .read_row_id_from_part_db(&db_name, &table_name, &where_clause);
-    }
-
-    if row_id > 0 {
-        row_ids.push(row_id);
+            .read_row_ids_from_part_db(&db_name, &table_name, &where_clause)
+        {
+            Ok(ids) => row_ids = ids,
+            Err(e) => warn!("unable to read row ids from {db_name}.{table_name}: {e:?}"),
+        }
     }
 
     GetReadRowIdsReply {
@@ -675,13 +1211,19 @@ pub async fn enable_coooperative_features(
 
     let db_name = request.database_name;
 
+    let mut is_successful = false;
+    let mut message = String::from("");
+
     if auth_result.0 {
-        core.dbi().enable_coooperative_features(&db_name);
+        match core.dbi().enable_coooperative_features(&db_name) {
+            Ok(()) => is_successful = true,
+            Err(e) => message = e.to_string(),
+        }
     }
 
     EnableCoooperativeFeaturesReply {
         authentication_result: Some(auth_result.1),
-        is_successful: true,
-        message: String::from(""),
+        is_successful,
+        message,
     }
 }
@@ -0,0 +1,485 @@
+use chrono::Utc;
+use rcd_common::{
+    db::{get_metadata_table_name, is_reserved_table_name},
+    defaults,
+    table::Table,
+};
+use rcd_enum::{export_format::ExportFormat, rcd_database_type::RcdDatabaseType};
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{
+    Contract, ExportDatabaseReply, ExportDatabaseRequest, ExportManifest, ExportRowHash,
+    TableSchema,
+};
+use serde_json::{Map, Value};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use tokio::{
+    fs::File,
+    io::AsyncReadExt,
+    sync::mpsc::{self, Receiver, Sender},
+};
+use tracing::{error, trace, warn};
+
+use super::Rcd;
+
+/// An exported file: its name and where its contents come from
+type ExportFile = (String, ExportSource);
+
+enum ExportSource {
+    /// A table, which is read a page of rows at a time and written out as json or csv as it
+    /// is sent
+    Table(String),
+    /// A copy of the database file, which is read a chunk at a time as it is sent
+    Snapshot(Snapshot),
+}
+
+/// A copy of a database file taken for an export, which is removed once the export is done
+/// with it, whether it was sent or not
+struct Snapshot(PathBuf);
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.0) {
+            warn!("unable to remove export snapshot {:?}: {e}", self.0);
+        }
+    }
+}
+
+/// How many replies can be waiting to be sent before we stop reading the next chunk
+const EXPORT_BUFFERED_CHUNKS: usize = 4;
+
+/// Exports a host or partial database so that a user can take a copy of their data. The first
+/// reply carries a manifest of the active contract, the table schemas and (for a partial
+/// database) the hash of every row we hold, and each reply after it carries a chunk of one of
+/// the exported files. The chunks are read as the receiver takes them, so a large database
+/// isn't held in memory; if reading a file fails the last reply is unsuccessful.
+pub async fn export_database(
+    core: &Rcd,
+    request: ExportDatabaseRequest,
+) -> Receiver<ExportDatabaseReply> {
+    let (tx, rx) = mpsc::channel(EXPORT_BUFFERED_CHUNKS);
+    let auth_result = core.verify_login(request.authentication.unwrap());
+
+    // the channel is empty, so the first reply always fits
+    if !auth_result.0 {
+        let _ = tx.try_send(ExportDatabaseReply {
+            authentication_result: Some(auth_result.1),
+            is_successful: false,
+            message: String::from(""),
+            manifest: None,
+            file_name: String::from(""),
+            data: Vec::new(),
+        });
+
+        return rx;
+    }
+
+    let format = ExportFormat::from_u32(request.format);
+
+    let db_name = request.database_name;
+
+    match export(core, &db_name, format) {
+        Ok((manifest, files)) => {
+            let _ = tx.try_send(ExportDatabaseReply {
+                authentication_result: Some(auth_result.1),
+                is_successful: true,
+                message: String::from(""),
+                manifest: Some(manifest),
+                file_name: String::from(""),
+                data: Vec::new(),
+            });
+
+            tokio::spawn(send_files(core.clone(), db_name, format, files, tx));
+        }
+        Err(e) => {
+            let _ = tx.try_send(ExportDatabaseReply {
+                authentication_result: Some(auth_result.1),
+                is_successful: false,
+                message: e.to_string(),
+                manifest: None,
+                file_name: String::from(""),
+                data: Vec::new(),
+            });
+        }
+    }
+
+    rx
+}
+
+async fn send_files(
+    core: Rcd,
+    db_name: String,
+    format: ExportFormat,
+    files: Vec<ExportFile>,
+    tx: Sender<ExportDatabaseReply>,
+) {
+    for (file_name, source) in files {
+        // the client has gone away
+        if tx.is_closed() {
+            return;
+        }
+
+        let result = match source {
+            ExportSource::Table(table_name) => {
+                send_table(&core, &db_name, &table_name, format, &tx, &file_name)
+                    .await
+                    .map_err(|e| e.to_string())
+            }
+            ExportSource::Snapshot(snapshot) => send_file(&tx, &file_name, &snapshot.0)
+                .await
+                .map_err(|e| e.to_string()),
+        };
+
+        if let Err(e) = result {
+            error!("unable to export {file_name}: {e}");
+
+            let _ = tx
+                .send(ExportDatabaseReply {
+                    authentication_result: None,
+                    is_successful: false,
+                    message: format!("unable to export {file_name}: {e}"),
+                    manifest: None,
+                    file_name,
+                    data: Vec::new(),
+                })
+                .await;
+
+            return;
+        }
+    }
+}
+
+/// Reads the table a page of rows at a time, so that a large table isn't held in memory, and
+/// sends each page as it is written out
+async fn send_table(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+    format: ExportFormat,
+    tx: &Sender<ExportDatabaseReply>,
+    file_name: &str,
+) -> Result<(), RcdDbError> {
+    let db_type = core.dbi().get_rcd_db_type(db_name)?;
+    let page_size = defaults::EXPORT_PAGE_SIZE_IN_ROWS;
+    let mut offset = 0;
+
+    loop {
+        let cmd = format!("SELECT * FROM {table_name} LIMIT {page_size} OFFSET {offset}");
+
+        let data = match db_type {
+            RcdDatabaseType::Partial => core.dbi().execute_read_at_participant(db_name, &cmd)?,
+            _ => core.dbi().execute_read_at_host(db_name, &cmd)?,
+        };
+
+        // the first page is always sent, so that an empty table still shows up in the export
+        if data.rows.is_empty() && offset > 0 {
+            return Ok(());
+        }
+
+        let contents = match format {
+            ExportFormat::Json => table_to_ndjson(&data),
+            _ => table_to_csv(&data, offset == 0),
+        };
+
+        if !send_contents(tx, file_name, &contents).await {
+            return Ok(());
+        }
+
+        if data.rows.len() < page_size as usize {
+            return Ok(());
+        }
+
+        offset += page_size;
+    }
+}
+
+/// Sends the contents a chunk at a time, returning false if the client has gone away
+async fn send_contents(tx: &Sender<ExportDatabaseReply>, file_name: &str, contents: &[u8]) -> bool {
+    // an empty file still gets a reply, so that it shows up in the export
+    if contents.is_empty() {
+        return tx.send(file_chunk(file_name, Vec::new())).await.is_ok();
+    }
+
+    for chunk in contents.chunks(defaults::EXPORT_CHUNK_SIZE_IN_BYTES) {
+        if tx
+            .send(file_chunk(file_name, chunk.to_vec()))
+            .await
+            .is_err()
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+async fn send_file(
+    tx: &Sender<ExportDatabaseReply>,
+    file_name: &str,
+    path: &Path,
+) -> io::Result<()> {
+    let mut file = File::open(path).await?;
+    let mut has_sent_chunk = false;
+
+    loop {
+        let mut chunk = Vec::with_capacity(defaults::EXPORT_CHUNK_SIZE_IN_BYTES);
+        (&mut file)
+            .take(defaults::EXPORT_CHUNK_SIZE_IN_BYTES as u64)
+            .read_to_end(&mut chunk)
+            .await?;
+
+        let is_last_chunk = chunk.len() < defaults::EXPORT_CHUNK_SIZE_IN_BYTES;
+
+        // an empty file still gets a reply, but a file that ends on a chunk boundary
+        // doesn't get an empty one at the end
+        if chunk.is_empty() && has_sent_chunk {
+            return Ok(());
+        }
+
+        if tx.send(file_chunk(file_name, chunk)).await.is_err() {
+            return Ok(());
+        }
+
+        has_sent_chunk = true;
+
+        if is_last_chunk {
+            return Ok(());
+        }
+    }
+}
+
+fn file_chunk(file_name: &str, data: Vec<u8>) -> ExportDatabaseReply {
+    ExportDatabaseReply {
+        authentication_result: None,
+        is_successful: true,
+        message: String::from(""),
+        manifest: None,
+        file_name: file_name.to_string(),
+        data,
+    }
+}
+
+fn export(
+    core: &Rcd,
+    db_name: &str,
+    format: ExportFormat,
+) -> Result<(ExportManifest, Vec<ExportFile>), RcdDbError> {
+    if format == ExportFormat::Unknown {
+        return Err(RcdDbError::General(
+            "an export format of sqlite, json or csv is required".to_string(),
+        ));
+    }
+
+    core.dbi().check_database_key(db_name)?;
+
+    let db_type = core.dbi().get_rcd_db_type(db_name)?;
+
+    let (active_contract, tables, row_hashes) = match db_type {
+        RcdDatabaseType::Partial => {
+            let contract = get_accepted_contract(core, db_name)?
+                .ok_or_else(|| RcdDbError::DbNotFound(db_name.to_string()))?;
+
+            let tables = get_exported_tables(&contract.schema.as_ref().unwrap().tables);
+            let row_hashes = get_row_hashes_at_participant(core, db_name, &tables)?;
+
+            (Some(contract), tables, row_hashes)
+        }
+        RcdDatabaseType::Host => {
+            let contract = if has_active_contract(core, db_name) {
+                Some(core.dbi().get_active_contract_proto(db_name)?)
+            } else {
+                None
+            };
+
+            let tables = get_exported_tables(&core.dbi().get_database_schema(db_name)?.tables);
+
+            (contract, tables, Vec::new())
+        }
+        _ => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let extension = ExportFormat::file_extension(format);
+    let mut files: Vec<ExportFile> = Vec::new();
+
+    if format == ExportFormat::Sqlite {
+        let snapshot = Snapshot(core.dbi().snapshot_database(db_name)?);
+        files.push((
+            format!("{}.{extension}", get_database_stem(db_name)),
+            ExportSource::Snapshot(snapshot),
+        ));
+    } else {
+        for table in &tables {
+            files.push((
+                format!("{}.{extension}", table.table_name),
+                ExportSource::Table(table.table_name.clone()),
+            ));
+        }
+    }
+
+    trace!("exporting {} files for {db_name}", files.len());
+
+    let manifest = ExportManifest {
+        database_name: db_name.to_string(),
+        format: ExportFormat::to_u32(format),
+        exported_utc: Utc::now().to_string(),
+        active_contract,
+        tables,
+        row_hashes,
+        file_names: files
+            .iter()
+            .map(|(file_name, _)| file_name.clone())
+            .collect(),
+    };
+
+    Ok((manifest, files))
+}
+
+/// The contract we accepted for the partial database, which has the table schemas we were
+/// given by the host
+fn get_accepted_contract(core: &Rcd, db_name: &str) -> Result<Option<Contract>, RcdDbError> {
+    let stem = get_database_stem(db_name);
+
+    Ok(core
+        .dbi()
+        .get_accepted_contracts()?
+        .into_iter()
+        .rev()
+        .find(|c| get_database_stem(&c.schema.as_ref().unwrap().database_name) == stem))
+}
+
+fn has_active_contract(core: &Rcd, db_name: &str) -> bool {
+    let cmd = "SELECT COUNT(*) FROM COOP_DATABASE_CONTRACT WHERE RETIRED_DATE_UTC IS NULL";
+
+    match core.dbi().execute_read_at_host(db_name, cmd) {
+        Ok(table) => table
+            .rows
+            .first()
+            .and_then(|row| row.vals.first())
+            .and_then(|val| val.data.as_ref())
+            .map(|data| data.data_string.parse::<u32>().unwrap_or_default() > 0)
+            .unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+/// Leaves out the tables rcd keeps for itself
+fn get_exported_tables(tables: &[TableSchema]) -> Vec<TableSchema> {
+    tables
+        .iter()
+        .filter(|t| !is_reserved_table_name(&t.table_name))
+        .cloned()
+        .collect()
+}
+
+fn get_row_hashes_at_participant(
+    core: &Rcd,
+    db_name: &str,
+    tables: &[TableSchema],
+) -> Result<Vec<ExportRowHash>, RcdDbError> {
+    let mut row_hashes: Vec<ExportRowHash> = Vec::new();
+
+    for table in tables {
+        let cmd = format!(
+            "SELECT ROW_ID FROM {}",
+            get_metadata_table_name(&table.table_name)
+        );
+
+        let row_ids = core.dbi().execute_read_at_participant(db_name, &cmd)?;
+
+        for row in &row_ids.rows {
+            let row_id: u32 = row
+                .vals
+                .first()
+                .and_then(|val| val.data.as_ref())
+                .ok_or_else(|| {
+                    RcdDbError::General(format!("missing ROW_ID in {}", table.table_name))
+                })?
+                .data_string
+                .parse()
+                .map_err(|e| {
+                    RcdDbError::General(format!(
+                        "unable to read ROW_ID in {}: {e}",
+                        table.table_name
+                    ))
+                })?;
+
+            row_hashes.push(ExportRowHash {
+                table_name: table.table_name.clone(),
+                row_id,
+                hash: core.dbi().get_data_hash_at_participant(
+                    db_name,
+                    &table.table_name,
+                    row_id,
+                )?,
+            });
+        }
+    }
+
+    Ok(row_hashes)
+}
+
+/// The database name without the `.db` or `.dbpart` extension
+fn get_database_stem(db_name: &str) -> String {
+    db_name.replace(".dbpart", "").replace(".db", "")
+}
+
+/// Writes each row as a JSON object on its own line, keyed by column name
+fn table_to_ndjson(data: &Table) -> Vec<u8> {
+    let mut contents = String::from("");
+
+    for row in &data.rows {
+        let mut object = Map::new();
+
+        for val in &row.vals {
+            let value = match &val.data {
+                Some(d) => Value::String(d.data_string.clone()),
+                None => Value::Null,
+            };
+
+            object.insert(val.col.name.clone(), value);
+        }
+
+        contents.push_str(&Value::Object(object).to_string());
+        contents.push('\n');
+    }
+
+    contents.into_bytes()
+}
+
+/// Writes a line per row, quoting values as needed, after a header line of column names if
+/// this is the first page of the table
+fn table_to_csv(data: &Table, include_header: bool) -> Vec<u8> {
+    let mut contents = String::from("");
+
+    if include_header {
+        let header: Vec<String> = data.cols.iter().map(|c| csv_field(&c.name)).collect();
+        contents.push_str(&header.join(","));
+        contents.push('\n');
+    }
+
+    for row in &data.rows {
+        let values: Vec<String> = row
+            .vals
+            .iter()
+            .map(|val| match &val.data {
+                Some(d) => csv_field(&d.data_string),
+                None => String::from(""),
+            })
+            .collect();
+
+        contents.push_str(&values.join(","));
+        contents.push('\n');
+    }
+
+    contents.into_bytes()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') || value.contains('\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -1,23 +1,48 @@
 use super::Rcd;
 use ::rcd_enum::rcd_database_type::RcdDatabaseType;
+use chrono::Utc;
 use conv::UnwrapOk;
 use conv::ValueFrom;
+use rcd_common::coop_database_participant::{
+    CoopDatabaseParticipant, CoopDatabaseParticipantData, CoopDatabaseParticipantRow,
+};
 use rcd_common::data_info::DataInfo;
+use rcd_common::defaults;
+use rcd_common::host_info::HostInfo;
 use rcd_enum::deletes_to_host_behavior::DeletesToHostBehavior;
 use rcd_enum::dml_type::DmlType;
+use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
 use rcd_enum::partial_data_status::PartialDataStatus;
 use rcd_enum::updates_to_host_behavior::UpdatesToHostBehavior;
+use rcd_error::rcd_db_error::RcdDbError;
 use rcd_query::query_parser::determine_dml_type;
 use rcd_query::query_parser::get_table_name;
+use rcd_query::query_parser::get_table_names;
+use rcdproto::rcdp::AuthResult;
+use rcdproto::rcdp::ColumnSchema;
 use rcdproto::rcdp::ExecuteCooperativeWriteReply;
 use rcdproto::rcdp::ExecuteCooperativeWriteRequest;
 use rcdproto::rcdp::ExecuteWriteReply;
 use rcdproto::rcdp::ExecuteWriteRequest;
+use rcdproto::rcdp::ParticipantWriteStatus;
+use rcdproto::rcdp::PendingWrite;
 use rcdproto::rcdp::RcdError;
+use rcdproto::rcdp::Row;
+use rcdproto::rcdp::RowRemoteMetadata;
+use rcdproto::rcdp::RowValue;
 use rcdproto::rcdp::{ExecuteReadReply, ExecuteReadRequest, StatementResultset};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 use stdext::function_name;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 use tracing::{error, info, trace, warn};
 
+/// The number of host rows `apply_host_owned_columns` reads by primary key in one statement
+const HOST_ROW_LOOKUP_BATCH_SIZE: usize = 100;
+
 pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> ExecuteReadReply {
     let auth_result = core.verify_login(request.authentication.unwrap());
 
@@ -26,8 +51,6 @@ pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> Ex
     let mut is_error = false;
     let mut error: Option<RcdError> = None;
 
-    let mut result_table = Vec::new();
-
     let mut statement_result_set = StatementResultset {
         is_error: true,
         result_message: String::from(""),
@@ -37,10 +60,13 @@ pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> Ex
     };
 
     if auth_result.0 {
-        let result = core.dbi().has_cooperative_tables(&db_name, &sql);
+        let result = get_cooperative_tables_for_read(core, &db_name, &sql);
         match result {
-            Ok(has_cooperative_tables) => {
-                if has_cooperative_tables {
+            Ok(cooperative_read) => {
+                let (reconciled_mirrors, is_mirror_out_of_sync) =
+                    reconcile_mirrored_tables(core, &db_name, &sql).await;
+
+                if let Some((participants_by_table, host_info)) = cooperative_read {
                     trace!(
                         "[{}]: found cooperative tables for: {} w/ sql {}",
                         function_name!(),
@@ -48,76 +74,281 @@ pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> Ex
                         &sql
                     );
 
-                    let cooperative_tables = core.dbi().get_cooperative_tables(&db_name, &sql);
+                    let cooperative_tables: Vec<String> = participants_by_table
+                        .iter()
+                        .map(|(ct, _)| ct.clone())
+                        .collect();
 
-                    trace!("[{}]: cooperative_tables: {cooperative_tables:?}", function_name!());
-
-                    for ct in &cooperative_tables {
-                        let participants_for_table =
-                            core.dbi().get_participants_for_table(&db_name, ct.as_str());
+                    trace!(
+                        "[{}]: cooperative_tables: {cooperative_tables:?}",
+                        function_name!()
+                    );
 
+                    let mut cooperative_rows = Vec::new();
+                    let mut has_hash_mismatch = is_mirror_out_of_sync;
+                    let mut failed_participants: Vec<String> = Vec::new();
+                    let mut participant_warnings: Vec<String> = Vec::new();
+                    let mut remote_deleted_rows: Vec<(String, CoopDatabaseParticipantRow)> =
+                        Vec::new();
+
+                    // rows are fetched a participant at a time, with up to the configured number of
+                    // participants being asked at once
+                    let max_concurrent_reads = core
+                        .settings
+                        .as_ref()
+                        .map(|settings| settings.max_concurrent_participant_reads)
+                        .unwrap_or(defaults::MAX_CONCURRENT_PARTICIPANT_READS)
+                        .max(1);
+
+                    let permits = Arc::new(Semaphore::new(max_concurrent_reads as usize));
+                    let mut remote_reads = JoinSet::new();
+
+                    for (ct, participants_for_table) in participants_by_table {
                         trace!("[{}]: execute_read_at_host: participants_for_table: {participants_for_table:?}", function_name!());
 
                         if participants_for_table.is_empty() {
                             warn!("[{}]: execute_read_at_host: no participants found for table: {ct:?}", function_name!());
                         }
 
-                        for participant in &participants_for_table {
+                        for participant in participants_for_table {
                             trace!("execute_read_at_host: participant: {participant:?}");
 
-                            for row in &participant.row_data {
-                                // we would need to get rows for that table from the participant
-                                let host_info =
-                                    core.dbi().rcd_get_host_info().expect("no host info is set");
+                            // under UpdateStatusOnly the host keeps a tombstone for rows the
+                            // participant has deleted. They have no values to run the statement
+                            // against, so they are kept out of it and reported after its rows.
+                            let tombstones = participant
+                                .row_data
+                                .iter()
+                                .filter(|row| row.is_remote_deleted);
+                            for row in tombstones {
+                                remote_deleted_rows.push((ct.clone(), row.clone()));
+                            }
+
+                            if !participant
+                                .row_data
+                                .iter()
+                                .any(|row| !row.is_remote_deleted)
+                            {
+                                continue;
+                            }
+
+                            let remote = core.remote();
+                            let host_info = host_info.clone();
+                            let permits = permits.clone();
+
+                            remote_reads.spawn(async move {
+                                let _permit = permits.acquire_owned().await;
+
+                                // the call runs in a task of its own, so a panic while talking to
+                                // one participant comes back as an error for that participant
+                                // instead of taking down the whole read
+                                let row_ids = participant
+                                    .row_data
+                                    .iter()
+                                    .filter(|row| !row.is_remote_deleted)
+                                    .map(|row| row.row_id)
+                                    .collect();
+                                let read_participant = participant.clone();
+                                let remote_data_result = tokio::spawn(async move {
+                                    remote
+                                        .get_rows_from_participant(
+                                            read_participant,
+                                            host_info,
+                                            row_ids,
+                                        )
+                                        .await
+                                })
+                                .await;
 
-                                let remote_data_result = core
-                                    .remote()
-                                    .get_row_from_participant(participant.clone(), host_info, row.0)
-                                    .await;
+                                (participant, remote_data_result)
+                            });
+                        }
+                    }
 
-                                trace!(
-                                    "[{}]: execute_read_at_host: remote_data_result: {remote_data_result:?}", function_name!()
+                    while let Some(remote_read) = remote_reads.join_next().await {
+                        let (participant, remote_data_result) = match remote_read {
+                            Ok((participant, Ok(remote_data_result))) => {
+                                (participant, remote_data_result)
+                            }
+                            Ok((participant, Err(e))) => {
+                                error!(
+                                    "[{}]: read from participant {} failed: {e:?}",
+                                    function_name!(),
+                                    participant.participant.alias
+                                );
+                                failed_participants.push(participant.participant.alias);
+                                continue;
+                            }
+                            Err(e) => {
+                                error!(
+                                    "[{}]: participant read task failed: {e:?}",
+                                    function_name!()
                                 );
+                                continue;
+                            }
+                        };
 
-                                if !remote_data_result.is_successful {
-                                    warn!(
-                                        "[{}]: remote data result failed: {remote_data_result:?}",
-                                        function_name!()
-                                    );
-                                }
+                        trace!(
+                            "[{}]: execute_read_at_host: remote_data_result: {remote_data_result:?}", function_name!()
+                        );
 
-                                let data_hash_for_row =
-                                    remote_data_result.row.as_ref().unwrap().hash.clone();
+                        if !remote_data_result.is_successful {
+                            warn!(
+                                "[{}]: remote data result failed: {remote_data_result:?}",
+                                function_name!()
+                            );
+                            failed_participants.push(participant.participant.alias.clone());
+                        } else if !remote_data_result.result_message.is_empty() {
+                            participant_warnings.push(format!(
+                                "{}: {}",
+                                participant.participant.alias, remote_data_result.result_message
+                            ));
+                        }
 
-                                let saved_hash_for_row = row.1.clone();
+                        for remote_row in remote_data_result.rows {
+                            let saved_row = participant
+                                .row_data
+                                .iter()
+                                .find(|row| row.row_id == remote_row.row_id);
 
-                                if data_hash_for_row == saved_hash_for_row {
-                                    let row = remote_data_result.row.as_ref().unwrap().clone();
-                                    result_table.push(row);
-                                    statement_result_set.is_error = false;
-                                } else {
-                                    let row = remote_data_result.row.as_ref().unwrap().clone();
-                                    result_table.push(row);
+                            // the participant didn't apply a write the host made to the row
+                            let is_remote_out_of_sync =
+                                saved_row.map(|row| row.is_out_of_sync).unwrap_or(false);
 
-                                    warn!(
-                                        "[{}]: data hashes for host and participant rows do not match!",
-                                        function_name!()
-                                    );
+                            // the participant no longer has the row
+                            if remote_row.values.is_empty() {
+                                continue;
+                            }
 
-                                    statement_result_set.result_message = String::from(
-                                        "warning: data hashes for host and participant rows do not match!",
-                                    );
-                                }
+                            let saved_hash_for_row = saved_row.map(|row| row.hash.clone());
+
+                            let is_hash_out_of_sync =
+                                saved_hash_for_row.as_ref() != Some(&remote_row.hash);
+
+                            if is_hash_out_of_sync {
+                                warn!(
+                                    "[{}]: data hashes for host and participant rows do not match!",
+                                    function_name!()
+                                );
+
+                                has_hash_mismatch = true;
                             }
+
+                            cooperative_rows.push(Row {
+                                remote_metadata: Some(RowRemoteMetadata {
+                                    is_remote_out_of_sync_with_host: is_remote_out_of_sync,
+                                    is_hash_out_of_sync_with_host: is_hash_out_of_sync,
+                                    is_remote_deleted: false,
+                                    is_local_deleted: false,
+                                }),
+                                ..remote_row
+                            });
                         }
                     }
 
-                    if sql.contains("WHERE") {
-                        warn!("[{}]: tables with cooperative data will not be filterd, statement has a filter: {sql:?}", function_name!());
-                        warn!("[{}]: this functionality has yet to be implemented", function_name!());
+                    // the host keeps its own columns of a shared table, so its values stand in
+                    // for whatever the participant holds for them
+                    for ct in &cooperative_tables {
+                        let policy = core.dbi().get_logical_storage_policy(&db_name, ct);
+
+                        if let Ok(LogicalStoragePolicy::Shared) = policy {
+                            apply_host_owned_columns(core, &db_name, ct, &mut cooperative_rows);
+                        }
+                    }
+
+                    // the rows from participants stand in for the host's copies of the cooperative
+                    // tables, and the statement is run as written so that its filters, projections,
+                    // joins and ordering all apply. Where it can be, the statement also returns the
+                    // hidden key of the participant rows each result row was built from.
+                    let keyed_sql = get_statement_with_row_keys(&sql, &cooperative_tables);
+                    let mut query_result = core.dbi().execute_read_at_host_with_cooperative_rows(
+                        &db_name,
+                        keyed_sql.as_ref().unwrap_or(&sql),
+                        &cooperative_tables,
+                        &cooperative_rows,
+                    );
+
+                    if let (Some(keyed_sql), Err(e)) = (&keyed_sql, &query_result) {
+                        trace!(
+                            "[{}]: {keyed_sql:?} failed, running the statement as written: {e}",
+                            function_name!()
+                        );
+
+                        query_result = core.dbi().execute_read_at_host_with_cooperative_rows(
+                            &db_name,
+                            &sql,
+                            &cooperative_tables,
+                            &cooperative_rows,
+                        );
                     }
 
-                    statement_result_set.rows = result_table;
+                    match query_result {
+                        Ok(result) => {
+                            let mut result_rows = result.to_cdata_rows();
+                            let row_keys = take_row_keys(&mut result_rows, &cooperative_tables);
+                            carry_remote_metadata(&mut result_rows, row_keys, &cooperative_rows);
+
+                            // a statement that returns a row per row of its tables also returns
+                            // the tombstones, in the shape of its results
+                            if keyed_sql.is_some() {
+                                let key_suffix = defaults::ROW_KEY_COLUMN_SUFFIX.to_uppercase();
+                                let columns: Vec<ColumnSchema> = result
+                                    .cols
+                                    .iter()
+                                    .filter(|col| !col.name.to_uppercase().ends_with(&key_suffix))
+                                    .map(|col| col.to_cdata_column_schema())
+                                    .collect();
+
+                                for (table_name, row_data) in &remote_deleted_rows {
+                                    result_rows.push(get_remote_deleted_row(
+                                        &db_name, table_name, &columns, row_data,
+                                    ));
+                                }
+                            }
+
+                            statement_result_set.number_of_rows_affected =
+                                u64::value_from(result_rows.len()).unwrap_ok();
+                            statement_result_set.rows = result_rows;
+                            statement_result_set.is_error = false;
+
+                            let mut warnings: Vec<String> = Vec::new();
+
+                            if has_hash_mismatch {
+                                warnings.push(String::from(
+                                    "warning: data hashes for host and participant rows do not match!",
+                                ));
+                            }
+
+                            if !reconciled_mirrors.is_empty() {
+                                warnings.push(get_reconciled_warning(&reconciled_mirrors));
+                            }
+
+                            if !failed_participants.is_empty() {
+                                warnings.push(format!(
+                                    "warning: rows could not be read from participants: {}",
+                                    failed_participants.join(", ")
+                                ));
+                            }
+
+                            if !participant_warnings.is_empty() {
+                                warnings
+                                    .push(format!("warning: {}", participant_warnings.join(", ")));
+                            }
+
+                            if !remote_deleted_rows.is_empty() {
+                                warnings.push(format!(
+                                    "warning: rows have been deleted at participants: {}",
+                                    remote_deleted_rows.len()
+                                ));
+                            }
+
+                            statement_result_set.result_message = warnings.join(" ");
+                        }
+                        Err(e) => {
+                            error!("execute_read_at_host: {}", &e.to_string());
+                            statement_result_set.execution_error_message = e.to_string();
+                        }
+                    }
                 } else {
                     let query_result = core.dbi().execute_read_at_host(&db_name, &sql);
 
@@ -128,6 +359,20 @@ pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> Ex
                                 u64::value_from(result_rows.len()).unwrap_ok();
                             statement_result_set.rows = result_rows;
                             statement_result_set.is_error = false;
+
+                            let mut warnings: Vec<String> = Vec::new();
+
+                            if is_mirror_out_of_sync {
+                                warnings.push(String::from(
+                                    "warning: data hashes for host and participant rows do not match!",
+                                ));
+                            }
+
+                            if !reconciled_mirrors.is_empty() {
+                                warnings.push(get_reconciled_warning(&reconciled_mirrors));
+                            }
+
+                            statement_result_set.result_message = warnings.join(" ");
                         }
                         Err(e) => {
                             error!("execute_read_at_host: {}", &e.to_string());
@@ -159,6 +404,578 @@ pub async fn execute_read_at_host(core: &Rcd, request: ExecuteReadRequest) -> Ex
     }
 }
 
+/// The cooperative tables named in a statement, each with the participants that hold its rows
+type ParticipantsByTable = Vec<(String, Vec<CoopDatabaseParticipantData>)>;
+
+/// Returns the participants that hold rows for each cooperative table in the statement, along
+/// with our own host info to ask them with, or `None` if the statement has no cooperative tables
+fn get_cooperative_tables_for_read(
+    core: &Rcd,
+    db_name: &str,
+    sql: &str,
+) -> Result<Option<(ParticipantsByTable, HostInfo)>, RcdDbError> {
+    if !core.dbi().has_cooperative_tables(db_name, sql)? {
+        return Ok(None);
+    }
+
+    let host_info = core
+        .dbi()
+        .rcd_get_host_info()?
+        .ok_or_else(|| RcdDbError::General(String::from("no host info is set")))?;
+
+    let mut participants_by_table = Vec::new();
+
+    for ct in core.dbi().get_cooperative_tables(db_name, sql)? {
+        let participants_for_table = core.dbi().get_participants_for_table(db_name, &ct)?;
+        participants_by_table.push((ct, participants_for_table));
+    }
+
+    Ok(Some((participants_by_table, host_info)))
+}
+
+/// Compares the host's own copy of each `Mirror` table in the statement against the hashes its
+/// participants have sent for their copies, and replaces a copy that doesn't hold the same rows
+/// with the participants' rows. A copy is only hashed again once the participants' hashes or the
+/// host's database have changed. Returns the tables that were reconciled, and true if any table
+/// still doesn't match its participants.
+async fn reconcile_mirrored_tables(core: &Rcd, db_name: &str, sql: &str) -> (Vec<String>, bool) {
+    let mut reconciled_tables = Vec::new();
+    let mut has_hash_mismatch = false;
+
+    for table_name in get_table_names(sql, core.dbi().db_type()) {
+        let policy = core.dbi().get_logical_storage_policy(db_name, &table_name);
+
+        if !matches!(policy, Ok(LogicalStoragePolicy::Mirror)) {
+            continue;
+        }
+
+        let participants = match core.dbi().get_participants_for_table(db_name, &table_name) {
+            Ok(participants) => participants,
+            Err(e) => {
+                warn!(
+                    "[{}]: unable to read participants for {table_name}: {e:?}",
+                    function_name!()
+                );
+                has_hash_mismatch = true;
+                continue;
+            }
+        };
+
+        let mut participant_hashes: Vec<Vec<u8>> = participants
+            .iter()
+            .flat_map(|participant| participant.row_data.iter())
+            .filter(|row| !row.is_remote_deleted)
+            .map(|row| row.hash.clone())
+            .collect();
+        participant_hashes.sort();
+
+        let mut hasher = DefaultHasher::new();
+        participant_hashes.hash(&mut hasher);
+        let fingerprint = hasher.finish();
+
+        if core
+            .dbi()
+            .is_mirror_verified(db_name, &table_name, fingerprint)
+        {
+            continue;
+        }
+
+        match is_host_copy_in_sync(core, db_name, &table_name, &participant_hashes) {
+            Ok(true) => {
+                core.dbi()
+                    .set_mirror_verified(db_name, &table_name, fingerprint);
+                continue;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                warn!("[{}]: unable to hash {table_name}: {e:?}", function_name!());
+                continue;
+            }
+        }
+
+        warn!(
+            "[{}]: mirrored table {table_name} does not match the copies held by participants",
+            function_name!()
+        );
+
+        if let Err(e) = reconcile_mirrored_table(core, db_name, &table_name).await {
+            warn!(
+                "[{}]: unable to reconcile {table_name}: {e:?}",
+                function_name!()
+            );
+            has_hash_mismatch = true;
+            continue;
+        }
+
+        // a copy that still doesn't hash the same as the participants' is reported, rather than
+        // copied again on every read
+        match is_host_copy_in_sync(core, db_name, &table_name, &participant_hashes) {
+            Ok(true) => {
+                core.dbi()
+                    .set_mirror_verified(db_name, &table_name, fingerprint);
+                reconciled_tables.push(table_name);
+            }
+            _ => has_hash_mismatch = true,
+        }
+    }
+
+    (reconciled_tables, has_hash_mismatch)
+}
+
+/// Returns true if the rows of the host's copy of a table hash the same as the sorted
+/// `participant_hashes`
+fn is_host_copy_in_sync(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+    participant_hashes: &[Vec<u8>],
+) -> Result<bool, RcdDbError> {
+    let mut host_hashes: Vec<Vec<u8>> = core
+        .dbi()
+        .get_row_hashes_at_host(db_name, table_name)?
+        .iter()
+        .map(|hash| hash.to_ne_bytes().to_vec())
+        .collect();
+    host_hashes.sort();
+
+    Ok(host_hashes == participant_hashes)
+}
+
+/// Replaces the host's copy of a `Mirror` table with the rows held by its participants, which
+/// had each write made to them before the host's copy did. The copy is replaced in a single
+/// transaction, and is left as it was if any participant can't be read.
+async fn reconcile_mirrored_table(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+) -> Result<(), RcdDbError> {
+    let mut rows: Vec<Row> = Vec::new();
+
+    let host_info = core
+        .dbi()
+        .rcd_get_host_info()?
+        .ok_or_else(|| RcdDbError::General(String::from("no host info is set")))?;
+
+    for participant in core.dbi().get_participants_for_table(db_name, table_name)? {
+        let row_ids: Vec<u32> = participant
+            .row_data
+            .iter()
+            .filter(|row| !row.is_remote_deleted)
+            .map(|row| row.row_id)
+            .collect();
+
+        if row_ids.is_empty() {
+            continue;
+        }
+
+        let alias = participant.participant.alias.clone();
+
+        let result = core
+            .remote()
+            .get_rows_from_participant(participant, host_info.clone(), row_ids)
+            .await;
+
+        if !result.is_successful {
+            return Err(RcdDbError::General(format!(
+                "unable to read {table_name} from participant {alias}"
+            )));
+        }
+
+        // a row the participant has deleted comes back without any values
+        rows.extend(result.rows.into_iter().filter(|row| !row.values.is_empty()));
+    }
+
+    let total_rows = core
+        .dbi()
+        .replace_rows_at_host(db_name, table_name, &rows)?;
+
+    info!(
+        "[{}]: replaced the host's copy of {table_name} with {total_rows} rows from participants",
+        function_name!()
+    );
+
+    Ok(())
+}
+
+fn get_reconciled_warning(reconciled_tables: &[String]) -> String {
+    format!(
+        "warning: the host's copy of {} did not match its participants and was replaced with theirs",
+        reconciled_tables.join(", ")
+    )
+}
+
+/// Replaces the values a participant sent for the host's own columns of a `Shared` table with
+/// the host's copy of the row, matched on the table's primary key. The host's rows are read by
+/// key, [`HOST_ROW_LOOKUP_BATCH_SIZE`] at a time
+fn apply_host_owned_columns(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+    cooperative_rows: &mut [Row],
+) {
+    let participant_owned_columns = core
+        .dbi()
+        .get_participant_owned_columns(db_name, table_name)
+        .unwrap_or_default();
+
+    let db_schema = match core.dbi().get_database_schema(db_name) {
+        Ok(db_schema) => db_schema,
+        Err(e) => {
+            warn!(
+                "[{}]: unable to read schema of {db_name}: {e}",
+                function_name!()
+            );
+            return;
+        }
+    };
+
+    let key_columns: Vec<ColumnSchema> = db_schema
+        .tables
+        .iter()
+        .filter(|table| table.table_name.eq_ignore_ascii_case(table_name))
+        .flat_map(|table| table.columns.iter())
+        .filter(|column| column.is_primary_key)
+        .cloned()
+        .collect();
+
+    if key_columns.is_empty() {
+        warn!(
+            "[{}]: shared table {table_name} has no primary key to match the host's rows on",
+            function_name!()
+        );
+        return;
+    }
+
+    let get_key = |row: &Row| -> Option<Vec<String>> {
+        key_columns
+            .iter()
+            .map(|key| {
+                get_row_value(row, &key.column_name)
+                    .filter(|value| !value.is_null_value)
+                    .map(|value| value.string_value.clone())
+            })
+            .collect()
+    };
+
+    let mut keys: Vec<Vec<String>> = cooperative_rows
+        .iter()
+        .filter(|row| row.table_name.eq_ignore_ascii_case(table_name))
+        .filter_map(get_key)
+        .collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut host_rows: HashMap<Vec<String>, Row> = HashMap::new();
+
+    for batch in keys.chunks(HOST_ROW_LOOKUP_BATCH_SIZE) {
+        // the host's schema gives the type each value is bound as
+        let lookup_keys: Vec<Vec<RowValue>> = batch
+            .iter()
+            .map(|key| {
+                key_columns
+                    .iter()
+                    .zip(key)
+                    .map(|(column, value)| RowValue {
+                        column: Some(column.clone()),
+                        is_null_value: false,
+                        value: Vec::new(),
+                        string_value: value.clone(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        match core
+            .dbi()
+            .get_rows_at_host_by_key(db_name, table_name, &lookup_keys)
+        {
+            Ok(result) => {
+                for host_row in result.to_cdata_rows() {
+                    if let Some(key) = get_key(&host_row) {
+                        host_rows.insert(key, host_row);
+                    }
+                }
+            }
+            Err(e) => {
+                warn!("[{}]: unable to read {table_name}: {e:?}", function_name!());
+                return;
+            }
+        }
+    }
+
+    for row in cooperative_rows
+        .iter_mut()
+        .filter(|row| row.table_name.eq_ignore_ascii_case(table_name))
+    {
+        let host_row = match get_key(row).and_then(|key| host_rows.get(&key)) {
+            Some(host_row) => host_row,
+            None => continue,
+        };
+
+        for value in row.values.iter_mut() {
+            let column_name = value.column.as_ref().unwrap().column_name.clone();
+
+            if participant_owned_columns
+                .iter()
+                .any(|owned| owned.eq_ignore_ascii_case(&column_name))
+            {
+                continue;
+            }
+
+            if let Some(host_value) = get_row_value(host_row, &column_name) {
+                value.is_null_value = host_value.is_null_value;
+                value.value = host_value.value.clone();
+                value.string_value = host_value.string_value.clone();
+            }
+        }
+    }
+}
+
+/// Builds the tombstone of a row the participant has deleted, with a NULL for each of the
+/// result's columns
+fn get_remote_deleted_row(
+    db_name: &str,
+    table_name: &str,
+    columns: &[ColumnSchema],
+    row_data: &CoopDatabaseParticipantRow,
+) -> Row {
+    Row {
+        row_id: row_data.row_id,
+        database_name: db_name.to_string(),
+        table_name: table_name.to_string(),
+        values: columns
+            .iter()
+            .map(|column| RowValue {
+                column: Some(column.clone()),
+                is_null_value: true,
+                value: Vec::new(),
+                string_value: String::new(),
+            })
+            .collect(),
+        is_remoteable: true,
+        remote_metadata: Some(RowRemoteMetadata {
+            is_remote_out_of_sync_with_host: row_data.is_out_of_sync,
+            is_hash_out_of_sync_with_host: false,
+            is_remote_deleted: true,
+            is_local_deleted: false,
+        }),
+        hash: row_data.hash.clone(),
+    }
+}
+
+/// Statements that don't return a row per row of their tables, which the row keys would either
+/// change the results of or misstate the source of
+const UNKEYED_SELECT_WORDS: [&str; 14] = [
+    " DISTINCT",
+    "GROUP BY",
+    " UNION ",
+    " INTERSECT ",
+    " EXCEPT ",
+    "COUNT(",
+    "SUM(",
+    "AVG(",
+    "MIN(",
+    "MAX(",
+    "TOTAL(",
+    "GROUP_CONCAT(",
+    "STRING_AGG(",
+    "ARRAY_AGG(",
+];
+
+/// Adds the hidden row key of each cooperative table to the front of the result columns of a
+/// plain `SELECT`. Returns `None` for any other statement, which is run as written.
+fn get_statement_with_row_keys(sql: &str, cooperative_tables: &[String]) -> Option<String> {
+    let sql = sql.trim_start();
+    let words = sql
+        .to_uppercase()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .replace(" (", "(");
+
+    if !words.starts_with("SELECT ") || words.starts_with("SELECT ALL ") {
+        return None;
+    }
+
+    if UNKEYED_SELECT_WORDS.iter().any(|word| words.contains(word)) {
+        return None;
+    }
+
+    let row_keys: Vec<String> = cooperative_tables
+        .iter()
+        .map(|table| format!("{table}{}", defaults::ROW_KEY_COLUMN_SUFFIX))
+        .collect();
+
+    Some(format!(
+        "SELECT {}, {}",
+        row_keys.join(", "),
+        sql["SELECT".len()..].trim_start()
+    ))
+}
+
+/// Removes the hidden row key columns from the result rows, returning the keys of the
+/// participant rows each result row was built from, or `None` if the result has no row keys
+fn take_row_keys(
+    result_rows: &mut [Row],
+    cooperative_tables: &[String],
+) -> Option<Vec<Vec<usize>>> {
+    let key_columns: Vec<String> = cooperative_tables
+        .iter()
+        .map(|table| format!("{table}{}", defaults::ROW_KEY_COLUMN_SUFFIX))
+        .collect();
+
+    let is_key_column = |value: &RowValue| {
+        value
+            .column
+            .as_ref()
+            .map(|column| {
+                key_columns
+                    .iter()
+                    .any(|key| key.eq_ignore_ascii_case(&column.column_name))
+            })
+            .unwrap_or(false)
+    };
+
+    if !result_rows
+        .first()
+        .map(|row| row.values.iter().any(is_key_column))
+        .unwrap_or(false)
+    {
+        return None;
+    }
+
+    let mut row_keys = Vec::new();
+
+    for row in result_rows.iter_mut() {
+        let (keys, values): (Vec<RowValue>, Vec<RowValue>) =
+            row.values.drain(..).partition(is_key_column);
+
+        row_keys.push(
+            keys.iter()
+                .filter_map(|key| key.string_value.parse().ok())
+                .collect(),
+        );
+
+        row.values = values;
+        for (ordinal, value) in row.values.iter_mut().enumerate() {
+            if let Some(column) = value.column.as_mut() {
+                column.ordinal = ordinal as u32;
+            }
+        }
+    }
+
+    Some(row_keys)
+}
+
+/// Carries the remote metadata of the participants' rows through to the rows of the statement's
+/// result. A result row with row keys takes the metadata of the participant rows they point to.
+/// Without them, a result row takes the metadata of every participant row that has the same
+/// values in the columns the two have in common, which flags a result row if any row it could
+/// have come from is flagged.
+fn carry_remote_metadata(
+    result_rows: &mut [Row],
+    row_keys: Option<Vec<Vec<usize>>>,
+    cooperative_rows: &[Row],
+) {
+    if let Some(row_keys) = row_keys {
+        for (result_row, keys) in result_rows.iter_mut().zip(row_keys) {
+            for key in keys {
+                let metadata = cooperative_rows
+                    .get(key)
+                    .and_then(|row| row.remote_metadata.as_ref());
+
+                if let Some(metadata) = metadata {
+                    let merged = result_row
+                        .remote_metadata
+                        .get_or_insert_with(Default::default);
+                    merge_remote_metadata(merged, metadata);
+                }
+            }
+        }
+
+        return;
+    }
+
+    let result_columns: Vec<String> = match result_rows.first() {
+        Some(row) => row
+            .values
+            .iter()
+            .filter_map(|value| value.column.as_ref())
+            .map(|column| column.column_name.to_lowercase())
+            .collect(),
+        None => return,
+    };
+
+    let mut rows_by_table: HashMap<String, Vec<&Row>> = HashMap::new();
+    for row in cooperative_rows {
+        rows_by_table
+            .entry(row.table_name.to_lowercase())
+            .or_default()
+            .push(row);
+    }
+
+    for rows in rows_by_table.values() {
+        let shared_columns: Vec<&String> = result_columns
+            .iter()
+            .filter(|column| get_row_value(rows[0], column).is_some())
+            .collect();
+
+        if shared_columns.is_empty() {
+            continue;
+        }
+
+        let mut metadata_by_values: HashMap<Vec<Option<String>>, RowRemoteMetadata> =
+            HashMap::new();
+        for row in rows {
+            if let Some(metadata) = &row.remote_metadata {
+                let key = get_value_key(row, &shared_columns);
+                let merged = metadata_by_values.entry(key).or_default();
+                merge_remote_metadata(merged, metadata);
+            }
+        }
+
+        for result_row in result_rows.iter_mut() {
+            let key = get_value_key(result_row, &shared_columns);
+
+            if let Some(metadata) = metadata_by_values.get(&key) {
+                let merged = result_row
+                    .remote_metadata
+                    .get_or_insert_with(Default::default);
+                merge_remote_metadata(merged, metadata);
+            }
+        }
+    }
+}
+
+/// The row's values for the columns, with `None` standing for a missing or NULL value
+fn get_value_key(row: &Row, columns: &[&String]) -> Vec<Option<String>> {
+    columns
+        .iter()
+        .map(|column| {
+            get_row_value(row, column)
+                .filter(|value| !value.is_null_value)
+                .map(|value| value.string_value.clone())
+        })
+        .collect()
+}
+
+fn merge_remote_metadata(into: &mut RowRemoteMetadata, from: &RowRemoteMetadata) {
+    into.is_remote_out_of_sync_with_host |= from.is_remote_out_of_sync_with_host;
+    into.is_hash_out_of_sync_with_host |= from.is_hash_out_of_sync_with_host;
+    into.is_remote_deleted |= from.is_remote_deleted;
+    into.is_local_deleted |= from.is_local_deleted;
+}
+
+fn get_row_value<'a>(row: &'a Row, column_name: &str) -> Option<&'a RowValue> {
+    row.values.iter().find(|value| {
+        value
+            .column
+            .as_ref()
+            .map(|column| column.column_name.eq_ignore_ascii_case(column_name))
+            .unwrap_or(false)
+    })
+}
+
 pub async fn execute_read_at_participant(
     core: &Rcd,
     request: ExecuteReadRequest,
@@ -218,6 +1035,8 @@ pub async fn execute_write_at_participant(
 ) -> ExecuteWriteReply {
     let mut rows_affected: u32 = 0;
     let mut is_overall_successful = false;
+    let mut is_error = false;
+    let mut rcd_error: Option<RcdError> = None;
 
     let auth_result = core.verify_login(request.authentication.unwrap());
 
@@ -226,29 +1045,132 @@ pub async fn execute_write_at_participant(
     let where_clause = request.where_clause;
 
     if auth_result.0 {
+        if let Err(e) = core.dbi().check_database_key(&db_name) {
+            return get_write_error_reply(auth_result.1, e);
+        }
+
         let db_type = core.dbi().db_type();
-        let rcd_db_type = core.dbi().get_rcd_db_type(&db_name);
-        let known_host = core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
+        let rcd_db_type = match core.dbi().get_rcd_db_type(&db_name) {
+            Ok(rcd_db_type) => rcd_db_type,
+            Err(e) => return get_write_error_reply(auth_result.1, e),
+        };
 
         if rcd_db_type == RcdDatabaseType::Partial {
+            let known_host = match core.dbi().get_cds_host_for_part_db(&db_name) {
+                Ok(Some(known_host)) => known_host,
+                Ok(None) => {
+                    let e = RcdDbError::General(format!("no host is set for {db_name}"));
+                    return get_write_error_reply(auth_result.1, e);
+                }
+                Err(e) => return get_write_error_reply(auth_result.1, e),
+            };
+
+            let own_host_info = match core.dbi().rcd_get_host_info() {
+                Ok(Some(own_host_info)) => own_host_info,
+                Ok(None) => {
+                    let e = RcdDbError::General(String::from("no host info is set"));
+                    return get_write_error_reply(auth_result.1, e);
+                }
+                Err(e) => return get_write_error_reply(auth_result.1, e),
+            };
+
             let statement_type = determine_dml_type(&statement, db_type);
             let table_name = get_table_name(&statement, db_type);
 
             match statement_type {
-                DmlType::Unknown => todo!(),
-                DmlType::Insert => todo!(),
+                DmlType::Unknown | DmlType::Select => {
+                    is_error = true;
+                    rcd_error = Some(RcdError {
+                        number: 0,
+                        message: format!(
+                            "unsupported statement for participant write: {statement}"
+                        ),
+                        help: String::from("only INSERT, UPDATE and DELETE are supported"),
+                    });
+                }
+                DmlType::Insert => {
+                    let data_result = match core.dbi().insert_data_into_partial_db(
+                        &db_name,
+                        &table_name,
+                        &statement,
+                    ) {
+                        Ok(data_result) => data_result,
+                        Err(e) => return get_write_error_reply(auth_result.1, e),
+                    };
+
+                    if data_result.is_successful {
+                        let data_info = DataInfo {
+                            db_name: db_name.clone(),
+                            table_name: table_name.clone(),
+                            row_id: data_result.row_id,
+                            hash: data_result.data_hash,
+                            is_deleted: false,
+                        };
+
+                        // the host needs a reference to the new row in its metadata table,
+                        // otherwise it will never know to ask us for it
+                        let notify_result = core
+                            .remote()
+                            .notify_host_of_inserted_row(&known_host, &own_host_info, &data_info)
+                            .await;
+
+                        if notify_result {
+                            is_overall_successful = true;
+                            rows_affected = 1;
+                        } else {
+                            warn!("notify host {known_host:?} of insert was not successful");
+
+                            // a row the host has no reference to would never be read, so take
+                            // it back out rather than leave it behind
+                            let cmd = format!(
+                                "DELETE FROM {table_name} WHERE ROWID = {}",
+                                data_result.row_id
+                            );
+                            if let Err(e) = core.dbi().execute_write_at_partipant(&db_name, &cmd) {
+                                error!("unable to remove row the host did not accept: {e}");
+                            }
+
+                            is_error = true;
+                            rcd_error = Some(RcdError {
+                                number: 0,
+                                message: format!(
+                                    "the host did not accept the row inserted into {table_name}, it has been removed"
+                                ),
+                                help: String::from(
+                                    "check that the host is reachable and that the table is participant owned",
+                                ),
+                            });
+                        }
+                    } else {
+                        is_error = true;
+                        rcd_error = Some(RcdError {
+                            number: 0,
+                            message: format!("unable to insert the row into {table_name}"),
+                            help: String::from(
+                                "a participant write must insert exactly one row, use a separate INSERT for each row",
+                            ),
+                        });
+                    }
+                }
                 DmlType::Update => {
-                    let update_behavior = core
+                    let update_behavior = match core
                         .dbi()
-                        .get_updates_to_host_behavior(&db_name, &table_name);
+                        .get_updates_to_host_behavior(&db_name, &table_name)
+                    {
+                        Ok(update_behavior) => update_behavior,
+                        Err(e) => return get_write_error_reply(auth_result.1, e),
+                    };
 
-                    let data_result = core.dbi().update_data_into_partial_db(
+                    let data_result = match core.dbi().update_data_into_partial_db(
                         &db_name,
                         &table_name,
                         &statement,
                         &known_host.host_id,
                         &where_clause,
-                    );
+                    ) {
+                        Ok(data_result) => data_result,
+                        Err(e) => return get_write_error_reply(auth_result.1, e),
+                    };
 
                     let data_info = DataInfo {
                         db_name: db_name.clone(),
@@ -261,15 +1183,10 @@ pub async fn execute_write_at_participant(
                     match update_behavior {
                         UpdatesToHostBehavior::Unknown => todo!(),
                         UpdatesToHostBehavior::SendDataHashChange => {
-                            let remote_host =
-                                core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
-                            let own_host_info =
-                                core.dbi().rcd_get_host_info().expect("no host info is set");
-
                             let notify_result = core
                                 .remote()
                                 .notify_host_of_updated_hash(
-                                    &remote_host,
+                                    &known_host,
                                     &own_host_info,
                                     &data_info,
                                 )
@@ -287,32 +1204,32 @@ pub async fn execute_write_at_participant(
                     }
                 }
                 DmlType::Delete => {
-                    let known_host = core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
-
-                    let delete_behavior = core
+                    let delete_behavior = match core
                         .dbi()
-                        .get_deletes_to_host_behavior(&db_name, &table_name);
+                        .get_deletes_to_host_behavior(&db_name, &table_name)
+                    {
+                        Ok(delete_behavior) => delete_behavior,
+                        Err(e) => return get_write_error_reply(auth_result.1, e),
+                    };
 
-                    let delete_result = core.dbi().delete_data_in_partial_db(
+                    let delete_result = match core.dbi().delete_data_in_partial_db(
                         &db_name,
                         &table_name,
                         &statement,
                         &where_clause,
                         &known_host.host_id,
-                    );
+                    ) {
+                        Ok(delete_result) => delete_result,
+                        Err(e) => return get_write_error_reply(auth_result.1, e),
+                    };
 
                     match delete_behavior {
                         DeletesToHostBehavior::Unknown => todo!(),
                         DeletesToHostBehavior::SendNotification => {
-                            let remote_host =
-                                core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
-                            let own_host_info =
-                                core.dbi().rcd_get_host_info().expect("no host info is set");
-
                             let notify_result = core
                                 .remote()
                                 .notify_host_of_removed_row(
-                                    &remote_host,
+                                    &known_host,
                                     &own_host_info,
                                     &db_name,
                                     &table_name,
@@ -321,7 +1238,7 @@ pub async fn execute_write_at_participant(
                                 .await;
 
                             if !notify_result {
-                                warn!("notify host {remote_host:?} of delete was not successful");
+                                warn!("notify host {known_host:?} of delete was not successful");
                             }
 
                             if delete_result.is_successful && notify_result {
@@ -338,7 +1255,6 @@ pub async fn execute_write_at_participant(
                         }
                     }
                 }
-                DmlType::Select => todo!(),
             }
 
             // we need to determine the statement type (INSERT/UPDATE/DELETE)
@@ -353,8 +1269,22 @@ pub async fn execute_write_at_participant(
         authentication_result: Some(auth_result.1),
         is_successful: is_overall_successful,
         total_rows_affected: rows_affected,
-        is_error: false,
-        error: None,
+        is_error,
+        error: rcd_error,
+    }
+}
+
+fn get_write_error_reply(authentication_result: AuthResult, e: RcdDbError) -> ExecuteWriteReply {
+    ExecuteWriteReply {
+        authentication_result: Some(authentication_result),
+        is_successful: false,
+        total_rows_affected: 0,
+        is_error: true,
+        error: Some(RcdError {
+            number: 0,
+            message: e.to_string(),
+            help: String::from(""),
+        }),
     }
 }
 
@@ -398,23 +1328,58 @@ pub async fn execute_write_at_host(core: &Rcd, request: ExecuteWriteRequest) ->
     }
 }
 
+/// Returns the participant with the given alias along with our own host info, which are needed to
+/// send the participant a write
+fn get_participant_and_host_info(
+    core: &Rcd,
+    db_name: &str,
+    alias: &str,
+) -> Option<(CoopDatabaseParticipant, HostInfo)> {
+    let db_participant = match core.dbi().get_participant_by_alias(db_name, alias) {
+        Ok(Some(db_participant)) => db_participant,
+        Ok(None) => {
+            warn!("no participant {alias} in {db_name}");
+            return None;
+        }
+        Err(e) => {
+            warn!("unable to read participant {alias} in {db_name}: {e}");
+            return None;
+        }
+    };
+
+    match core.dbi().rcd_get_host_info() {
+        Ok(Some(host_info)) => Some((db_participant, host_info)),
+        Ok(None) => {
+            warn!("no host info is set");
+            None
+        }
+        Err(e) => {
+            warn!("unable to read host info: {e}");
+            None
+        }
+    }
+}
+
 pub async fn execute_cooperative_write_at_host(
     core: &Rcd,
     request: ExecuteCooperativeWriteRequest,
 ) -> ExecuteCooperativeWriteReply {
     let mut is_remote_action_successful = false;
+    let mut participant_statuses: Vec<ParticipantWriteStatus> = Vec::new();
 
     let auth_result = core.verify_login(request.authentication.unwrap());
     let db_name = request.database_name;
     let statement = request.sql_statement;
 
-    if auth_result.0 && core.dbi().has_participant(&db_name, &request.alias) {
+    let participant_and_host_info = if auth_result.0 {
+        get_participant_and_host_info(core, &db_name, &request.alias)
+    } else {
+        None
+    };
+
+    if let Some((db_participant, host_info)) = participant_and_host_info {
+        let mut write_status = PartialDataStatus::Unknown;
         let dml_type = determine_dml_type(&statement, core.dbi().db_type());
-        let db_participant = core
-            .dbi()
-            .get_participant_by_alias(&db_name, &request.alias)
-            .unwrap();
-        let host_info = core.dbi().rcd_get_host_info().expect("no host info is set");
         let cmd_table_name = get_table_name(&statement, core.dbi().db_type());
         let where_clause = request.where_clause.clone();
 
@@ -443,17 +1408,25 @@ pub async fn execute_cooperative_write_at_host(
 
                     let internal_participant_id = db_participant_reference.internal_id.to_string();
 
-                    let local_insert_is_successful = core.dbi().insert_metadata_into_host_db(
-                        &db_name,
-                        &cmd_table_name,
-                        row_id,
-                        data_hash,
-                        &internal_participant_id,
-                    );
+                    let local_insert_is_successful = core
+                        .dbi()
+                        .insert_metadata_into_host_db(
+                            &db_name,
+                            &cmd_table_name,
+                            row_id,
+                            data_hash,
+                            &internal_participant_id,
+                        )
+                        .unwrap_or_else(|e| {
+                            warn!("unable to save metadata for row {row_id}: {e}");
+                            false
+                        });
 
                     if local_insert_is_successful {
                         is_remote_action_successful = true;
                     }
+
+                    write_status = PartialDataStatus::SucessOverwriteOrLog;
                 } else {
                     warn!("remote insert was not successful: {remote_insert_result:?}");
                 }
@@ -471,39 +1444,106 @@ pub async fn execute_cooperative_write_at_host(
                     )
                     .await;
 
-                if remote_update_result.is_successful {
-                    let data_hash: u64;
-                    let row_id: u32;
-
-                    let update_result =
-                        PartialDataStatus::from_u32(remote_update_result.update_status);
-
-                    match update_result {
-                        PartialDataStatus::Unknown => todo!(),
-                        PartialDataStatus::SucessOverwriteOrLog => {
-                            data_hash = remote_update_result.rows.first().unwrap().data_hash;
-                            row_id = remote_update_result.rows.first().unwrap().rowid;
-                            let internal_participant_id =
-                                db_participant_reference.internal_id.to_string();
+                write_status = PartialDataStatus::from_u32(remote_update_result.update_status);
 
-                            let local_update_is_successful = core.dbi().update_metadata_in_host_db(
+                match write_status {
+                    PartialDataStatus::Unknown => {
+                        warn!(
+                            "[{}]: participant did not say what happened to the update: {remote_update_result:?}",
+                            function_name!()
+                        );
+                    }
+                    PartialDataStatus::SucessOverwriteOrLog => {
+                        // the statement may have matched more than one row, and each of them
+                        // has a new hash
+                        let rows: Vec<(u32, u64)> = remote_update_result
+                            .rows
+                            .iter()
+                            .map(|row| (row.rowid, row.data_hash))
+                            .collect();
+
+                        let internal_participant_id =
+                            db_participant_reference.internal_id.to_string();
+
+                        let local_update_is_successful = core
+                            .dbi()
+                            .update_metadata_in_host_db(
                                 &db_name,
                                 &cmd_table_name,
-                                row_id,
-                                data_hash,
+                                &rows,
                                 &internal_participant_id,
-                            );
+                            )
+                            .unwrap_or_else(|e| {
+                                warn!("unable to update metadata for {cmd_table_name}: {e}");
+                                false
+                            });
 
-                            trace!("local update is successful: {local_update_is_successful}");
+                        trace!("local update is successful: {local_update_is_successful}");
 
-                            if local_update_is_successful {
-                                is_remote_action_successful = true;
-                            }
+                        if local_update_is_successful {
+                            is_remote_action_successful = true;
                         }
-                        PartialDataStatus::Pending => {
+                    }
+                    PartialDataStatus::Pending => {
+                        // the participant queued the update for review; remember it so we
+                        // can show it until the participant accepts or rejects it
+                        let pending_write = PendingWrite {
+                            table_name: cmd_table_name.clone(),
+                            participant_alias: request.alias.clone(),
+                            pending_action_id: remote_update_result.pending_action_id,
+                            statement: statement.clone(),
+                            where_clause: where_clause.clone(),
+                            action: String::from("UPDATE"),
+                            requested_ts_utc: Utc::now().to_string(),
+                        };
+
+                        let internal_participant_id =
+                            db_participant_reference.internal_id.to_string();
+
+                        let local_pending_is_successful = core
+                            .dbi()
+                            .add_pending_write_at_host(
+                                &db_name,
+                                &internal_participant_id,
+                                &pending_write,
+                            )
+                            .unwrap_or_else(|e| {
+                                warn!("unable to save pending write: {e}");
+                                false
+                            });
+
+                        trace!("local pending write is successful: {local_pending_is_successful}");
+
+                        if local_pending_is_successful {
                             is_remote_action_successful = true;
                         }
-                        PartialDataStatus::Ignored => todo!(),
+                    }
+                    PartialDataStatus::Ignored => {
+                        // the participant refused the update, so the rows it matched no longer
+                        // hold what we asked for
+                        let row_ids: Vec<u32> = remote_update_result
+                            .rows
+                            .iter()
+                            .map(|row| row.rowid)
+                            .collect();
+
+                        let internal_participant_id =
+                            db_participant_reference.internal_id.to_string();
+
+                        let local_mark_is_successful = core
+                            .dbi()
+                            .mark_metadata_out_of_sync_in_host_db(
+                                &db_name,
+                                &cmd_table_name,
+                                &row_ids,
+                                &internal_participant_id,
+                            )
+                            .unwrap_or_else(|e| {
+                                warn!("unable to mark rows of {cmd_table_name} out of sync: {e}");
+                                false
+                            });
+
+                        trace!("local mark out of sync is successful: {local_mark_is_successful}");
                     }
                 }
             }
@@ -520,41 +1560,114 @@ pub async fn execute_cooperative_write_at_host(
                     )
                     .await;
 
-                trace!("[{}]: remote_delete_result: {remote_delete_result:?}", function_name!());
-
-                if remote_delete_result.is_successful {
-                    let row_id: u32 = if remote_delete_result.rows.is_empty() {
-                        0
-                    } else {
-                        remote_delete_result.rows.first().unwrap().rowid
+                trace!(
+                    "[{}]: remote_delete_result: {remote_delete_result:?}",
+                    function_name!()
+                );
+
+                if remote_delete_result.is_successful && remote_delete_result.pending_action_id > 0
+                {
+                    // the participant queued the delete for review, so nothing has been removed yet
+                    let pending_write = PendingWrite {
+                        table_name: cmd_table_name.clone(),
+                        participant_alias: request.alias.clone(),
+                        pending_action_id: remote_delete_result.pending_action_id,
+                        statement: statement.clone(),
+                        where_clause: where_clause.clone(),
+                        action: String::from("DELETE"),
+                        requested_ts_utc: Utc::now().to_string(),
                     };
 
                     let internal_participant_id = db_participant_reference.internal_id.to_string();
 
-                    let local_delete_is_successful = core.dbi().delete_metadata_in_host_db(
-                        &db_name,
-                        &cmd_table_name,
-                        row_id,
-                        &internal_participant_id,
-                    );
+                    is_remote_action_successful = core
+                        .dbi()
+                        .add_pending_write_at_host(
+                            &db_name,
+                            &internal_participant_id,
+                            &pending_write,
+                        )
+                        .unwrap_or_else(|e| {
+                            warn!("unable to save pending write: {e}");
+                            false
+                        });
+
+                    write_status = PartialDataStatus::Pending;
+                } else if remote_delete_result.is_successful {
+                    let row_ids: Vec<u32> = remote_delete_result
+                        .rows
+                        .iter()
+                        .map(|row| row.rowid)
+                        .collect();
+
+                    let internal_participant_id = db_participant_reference.internal_id.to_string();
 
-                    trace!("[{}]: local_delete_is_successful: {local_delete_is_successful:?}", function_name!());
+                    let local_delete_is_successful = core
+                        .dbi()
+                        .delete_metadata_in_host_db(
+                            &db_name,
+                            &cmd_table_name,
+                            &row_ids,
+                            &internal_participant_id,
+                        )
+                        .unwrap_or_else(|e| {
+                            warn!("unable to remove metadata for {cmd_table_name}: {e}");
+                            false
+                        });
+
+                    trace!(
+                        "[{}]: local_delete_is_successful: {local_delete_is_successful:?}",
+                        function_name!()
+                    );
 
                     if local_delete_is_successful {
                         is_remote_action_successful = true;
                     }
+
+                    write_status = PartialDataStatus::SucessOverwriteOrLog;
                 } else {
                     warn!("remote delete was not successful");
                 }
             }
             DmlType::Select => panic!(),
         }
+
+        if is_remote_action_successful && write_status == PartialDataStatus::SucessOverwriteOrLog {
+            // mirrored and shared tables keep a copy of the row at the host as well
+            let local_write_result =
+                core.dbi()
+                    .apply_cooperative_write_at_host(&db_name, &cmd_table_name, &statement);
+
+            if let Err(e) = local_write_result {
+                error!(
+                    "[{}]: unable to apply write to host copy of {cmd_table_name}: {e:?}",
+                    function_name!()
+                );
+
+                // the participant already has the write, so a mirrored copy is brought back in
+                // line with it instead; the host's copy was left as it was before the write
+                let policy = core
+                    .dbi()
+                    .get_logical_storage_policy(&db_name, &cmd_table_name);
+
+                is_remote_action_successful = matches!(policy, Ok(LogicalStoragePolicy::Mirror))
+                    && reconcile_mirrored_table(core, &db_name, &cmd_table_name)
+                        .await
+                        .is_ok();
+            }
+        }
+
+        participant_statuses.push(ParticipantWriteStatus {
+            participant_alias: request.alias.clone(),
+            write_status: PartialDataStatus::to_u32(write_status),
+        });
     }
 
     let execute_write_reply = ExecuteCooperativeWriteReply {
         authentication_result: Some(auth_result.1),
         is_successful: is_remote_action_successful,
         total_rows_affected: 0,
+        participant_statuses,
     };
 
     trace!("{execute_write_reply:?}");
@@ -11,6 +11,7 @@ pub async fn set_logical_storage_policy(
     request: SetLogicalStoragePolicyRequest,
 ) -> SetLogicalStoragePolicyReply {
     let mut policy_is_set = false;
+    let mut message = String::from("");
 
     let auth_result = core.verify_login(request.authentication.unwrap());
 
@@ -19,25 +20,79 @@ pub async fn set_logical_storage_policy(
     let policy = LogicalStoragePolicy::from_i64(policy_num as i64);
     let table_name = request.table_name;
 
+    // only a shared table splits its columns between the host and the participant
+    let participant_owned_columns = if policy == LogicalStoragePolicy::Shared {
+        request.participant_owned_columns
+    } else {
+        Vec::new()
+    };
+
     if auth_result.0 {
-        policy_is_set = core
-            .dbi()
-            .set_logical_storage_policy(&db_name, table_name.as_str(), policy)
-            .unwrap();
+        let unknown_columns =
+            get_unknown_columns(core, &db_name, &table_name, &participant_owned_columns);
+
+        if unknown_columns.is_empty() {
+            policy_is_set = core
+                .dbi()
+                .set_logical_storage_policy(&db_name, table_name.as_str(), policy)
+                .unwrap();
+
+            if policy_is_set {
+                policy_is_set = core
+                    .dbi()
+                    .set_participant_owned_columns(
+                        &db_name,
+                        &table_name,
+                        &participant_owned_columns,
+                    )
+                    .unwrap();
+            }
+        } else {
+            message = format!(
+                "columns not found in table {table_name}: {}",
+                unknown_columns.join(", ")
+            );
+        }
     }
 
     SetLogicalStoragePolicyReply {
         authentication_result: Some(auth_result.1),
         is_successful: policy_is_set,
-        message: String::from(""),
+        message,
     }
 }
 
+/// Returns any of the columns that aren't in the table
+fn get_unknown_columns(
+    core: &Rcd,
+    db_name: &str,
+    table_name: &str,
+    column_names: &[String],
+) -> Vec<String> {
+    if column_names.is_empty() {
+        return Vec::new();
+    }
+
+    // an empty read still describes the columns of the table
+    let cmd = format!("SELECT * FROM {table_name} WHERE 1 = 0");
+    let table_columns: Vec<String> = match core.dbi().execute_read_at_host(db_name, &cmd) {
+        Ok(table) => table.cols.iter().map(|c| c.name.to_uppercase()).collect(),
+        Err(_) => Vec::new(),
+    };
+
+    column_names
+        .iter()
+        .filter(|column_name| !table_columns.contains(&column_name.to_uppercase()))
+        .cloned()
+        .collect()
+}
+
 pub async fn get_logical_storage_policy(
     core: &Rcd,
     request: GetLogicalStoragePolicyRequest,
 ) -> GetLogicalStoragePolicyReply {
     let mut policy = LogicalStoragePolicy::None;
+    let mut participant_owned_columns: Vec<String> = Vec::new();
 
     let auth_result = core.verify_login(request.authentication.unwrap());
 
@@ -51,10 +106,16 @@ pub async fn get_logical_storage_policy(
             .unwrap();
 
         policy = LogicalStoragePolicy::from_i64(i_policy as i64);
+
+        participant_owned_columns = core
+            .dbi()
+            .get_participant_owned_columns(&db_name, &table_name)
+            .unwrap_or_default();
     }
 
     GetLogicalStoragePolicyReply {
         authentication_result: Some(auth_result.1),
         policy_mode: LogicalStoragePolicy::to_u32(policy),
+        participant_owned_columns,
     }
 }
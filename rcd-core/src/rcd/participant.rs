@@ -1,11 +1,14 @@
-use tracing::debug;
+use tracing::{debug, warn};
+use rcd_common::coop_database_participant::NewCoopDatabaseParticipant;
 use rcd_enum::contract_status::ContractStatus;
 use rcdproto::rcdp::{
-    AddParticipantReply, AddParticipantRequest, Participant, SendParticipantContractReply,
-    SendParticipantContractRequest, TryAuthAtParticipantRequest, TryAuthAtPartipantReply,
+    AddParticipantReply, AddParticipantRequest, AuthResult,
+    SendParticipantContractReply, SendParticipantContractRequest, TryAuthAtParticipantRequest,
+    TryAuthAtPartipantReply,
 };
 
 use super::Rcd;
+use crate::comm::check_protocol_version;
 
 const AUTO_UPDATE_PARTICIPANT_STATUS: bool = true;
 
@@ -15,23 +18,87 @@ pub async fn try_auth_at_participant(
 ) -> TryAuthAtPartipantReply {
     let auth_result = core.verify_login(request.authentication.unwrap());
 
-    let db_participant = core
+    let participant_and_host_info = core
         .dbi()
         .get_participant_by_alias(&request.db_name, &request.participant_alias)
-        .unwrap();
+        .and_then(|participant| Ok((participant, core.dbi().rcd_get_host_info()?)));
+
+    let (db_participant, host_info) = match participant_and_host_info {
+        Ok((Some(db_participant), Some(host_info))) => (db_participant, host_info),
+        Ok((None, _)) => {
+            let message = format!("no participant {} is found", request.participant_alias);
+            return get_try_auth_failed_reply(auth_result.1, message);
+        }
+        Ok((_, None)) => {
+            let message = String::from("no host info is set");
+            return get_try_auth_failed_reply(auth_result.1, message);
+        }
+        Err(e) => {
+            warn!("try_auth_at_participant: {e}");
+            return get_try_auth_failed_reply(auth_result.1, e.to_string());
+        }
+    };
 
     let result = core
         .remote()
-        .try_auth_at_participant(
-            db_participant,
-            &core.dbi().rcd_get_host_info().expect("no host info is set"),
-        )
+        .try_auth_at_participant(db_participant, &host_info)
         .await;
 
+    let is_authenticated = result
+        .authentication_result
+        .map(|auth| auth.is_authenticated)
+        .unwrap_or(false);
+
+    let mut protocol_version = 0;
+    let mut error = result.error;
+
+    if is_authenticated {
+        if let Err(e) = core.dbi().update_participant_protocol_version(
+            &request.db_name,
+            &request.participant_alias,
+            result.protocol_version,
+        ) {
+            warn!(
+                "unable to save protocol version of {}: {e}",
+                request.participant_alias
+            );
+        }
+
+        // the participant refuses us if it no longer supports our version, otherwise it's on us
+        // to decide if we still support theirs
+        if error.is_none() {
+            if let Err(e) = check_protocol_version(result.protocol_version) {
+                error = Some(e);
+            }
+        }
+
+        protocol_version = result.protocol_version;
+    }
+
+    let message = error
+        .as_ref()
+        .map(|e| e.message.clone())
+        .unwrap_or_default();
+
     TryAuthAtPartipantReply {
         authentication_result: Some(auth_result.1),
-        is_successful: result,
-        message: String::from(""),
+        is_successful: is_authenticated && error.is_none(),
+        message,
+        protocol_version,
+        error,
+    }
+}
+
+fn get_try_auth_failed_reply(
+    authentication_result: AuthResult,
+    message: String,
+) -> TryAuthAtPartipantReply {
+    TryAuthAtPartipantReply {
+        authentication_result: Some(authentication_result),
+        is_successful: false,
+        message,
+        protocol_version: 0,
+        error: None,
     }
 }
 
@@ -39,26 +106,26 @@ pub async fn add_participant(core: &Rcd, request: AddParticipantRequest) -> AddP
     let auth_result = core.verify_login(request.authentication.unwrap());
 
     let db_name = request.database_name;
-    let alias = request.alias;
-    let ip4addr = request.ip4_address;
-    let db_port: u32 = request.port;
-    let http_addr = request.http_addr;
-    let http_port = request.http_port;
-    let id = request.id;
+    let participant = NewCoopDatabaseParticipant {
+        alias: request.alias,
+        ip4addr: request.ip4_address,
+        db_port: request.port,
+        http_addr: request.http_addr,
+        http_port: request.http_port as u16,
+        id: request.id,
+    };
 
     let reply_message = String::from("");
     let mut is_successful = false;
 
     if auth_result.0 {
-        is_successful = core.dbi().add_participant(
-            &db_name,
-            &alias,
-            &ip4addr,
-            db_port,
-            http_addr,
-            http_port as u16,
-            id,
-        );
+        is_successful = core
+            .dbi()
+            .add_participant(&db_name, participant)
+            .unwrap_or_else(|e| {
+                warn!("unable to add participant to {db_name}: {e}");
+                false
+            });
     };
 
     AddParticipantReply {
@@ -80,14 +147,50 @@ pub async fn send_participant_contract(
     let mut is_successful = false;
     let mut contract_status: u32 = 0;
 
-    if auth_result.0 && core.dbi().has_participant(&db_name, &participant_alias) {
-        let participant = core
-            .dbi()
+    let participant = if auth_result.0 {
+        core.dbi()
             .get_participant_by_alias(&db_name, &participant_alias)
-            .unwrap();
-        let active_contract = core.dbi().get_active_contract(&db_name);
-        let db_schema = core.dbi().get_database_schema(&db_name);
-        let host_info = core.dbi().rcd_get_host_info().expect("no host info is set");
+            .unwrap_or_else(|e| {
+                warn!("send_participant_contract: {e}");
+                None
+            })
+    } else {
+        None
+    };
+
+    if let Some(participant) = participant {
+        let contract_and_schema = core
+            .dbi()
+            .get_active_contract(&db_name)
+            .and_then(|contract| {
+                Ok((
+                    contract,
+                    core.dbi().get_database_schema(&db_name)?,
+                    core.dbi().rcd_get_host_info()?,
+                ))
+            });
+
+        let (active_contract, db_schema, host_info) = match contract_and_schema {
+            Ok((active_contract, db_schema, Some(host_info))) => {
+                (active_contract, db_schema, host_info)
+            }
+            Ok((_, _, None)) => {
+                warn!("send_participant_contract: no host info is set");
+                return SendParticipantContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_sent: false,
+                    contract_status,
+                };
+            }
+            Err(e) => {
+                warn!("send_participant_contract: {e}");
+                return SendParticipantContractReply {
+                    authentication_result: Some(auth_result.1),
+                    is_sent: false,
+                    contract_status,
+                };
+            }
+        };
         let result = core
             .remote()
             .send_participant_contract(
@@ -121,12 +224,14 @@ pub async fn send_participant_contract(
             p.http_addr = _participant.http_addr;
             p.http_port = _participant.http_port as u32;
 
-            core.dbi().update_participant_accepts_contract(
+            if let Err(e) = core.dbi().update_participant_accepts_contract(
                 &db_name,
                 participant.clone(),
                 p,
                 &active_contract.contract_id.to_string(),
-            );
+            ) {
+                warn!("unable to save contract status for participant: {e}");
+            }
         }
     };
 
@@ -14,14 +14,19 @@ use rcd_common::defaults;
 use rcdproto::rcdp::{
     AuthRequest, AuthResult, CreateDatabaseRequest, CreateDatabaseResult, CreateTableRequest,
     CreateTableResult, DeleteDataRequest, DeleteDataResult, GetRowFromPartialDatabaseRequest,
-    GetRowFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    GetRowFromPartialDatabaseResult, GetRowsFromPartialDatabaseRequest,
+    GetRowsFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    NotifyHostOfInsertedRowRequest, NotifyHostOfInsertedRowResponse,
+    NotifyHostOfPendingActionRequest, NotifyHostOfPendingActionResponse,
     NotifyHostOfRemovedRowRequest, NotifyHostOfRemovedRowResponse,
-    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult, SaveContractRequest,
+    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult,
+    ParticipantRejectsContractRequest, ParticipantRejectsContractResult, SaveContractRequest,
     SaveContractResult, TestReply, TestRequest, TryAuthRequest, TryAuthResult, UpdateDataRequest,
     UpdateDataResult, UpdateRowDataHashForHostRequest, UpdateRowDataHashForHostResponse,
 };
 
-use crate::dbi::Dbi;
+use crate::{comm::check_protocol_version, dbi::Dbi};
+use tracing::warn;
 
 mod contract;
 mod crud;
@@ -43,6 +48,13 @@ impl RcdData {
         return crud::notify_host_of_removed_row(self, request).await;
     }
 
+    pub async fn notify_host_of_inserted_row(
+        &self,
+        request: NotifyHostOfInsertedRowRequest,
+    ) -> NotifyHostOfInsertedRowResponse {
+        return crud::notify_host_of_inserted_row(self, request).await;
+    }
+
     pub async fn save_contract(&self, request: SaveContractRequest) -> SaveContractResult {
         return contract::save_contract(self, request).await;
     }
@@ -54,6 +66,13 @@ impl RcdData {
         return crud::get_row_from_partial_database(self, request).await;
     }
 
+    pub async fn get_rows_from_partial_database(
+        &self,
+        request: GetRowsFromPartialDatabaseRequest,
+    ) -> GetRowsFromPartialDatabaseResult {
+        return crud::get_rows_from_partial_database(self, request).await;
+    }
+
     pub async fn accept_contract(
         &self,
         request: ParticipantAcceptsContractRequest,
@@ -61,6 +80,13 @@ impl RcdData {
         return contract::accept_contract(self, request).await;
     }
 
+    pub async fn reject_contract(
+        &self,
+        request: ParticipantRejectsContractRequest,
+    ) -> ParticipantRejectsContractResult {
+        return contract::reject_contract(self, request).await;
+    }
+
     pub async fn update_row_data_hash_for_host(
         &self,
         request: UpdateRowDataHashForHostRequest,
@@ -68,6 +94,13 @@ impl RcdData {
         return crud::update_row_data_hash_for_host(self, request).await;
     }
 
+    pub async fn notify_host_of_pending_action(
+        &self,
+        request: NotifyHostOfPendingActionRequest,
+    ) -> NotifyHostOfPendingActionResponse {
+        return crud::notify_host_of_pending_action(self, request).await;
+    }
+
     pub async fn update_command_into_table(&self, request: UpdateDataRequest) -> UpdateDataResult {
         return crud::update_command_into_table(self, request).await;
     }
@@ -102,38 +135,50 @@ impl RcdData {
 
         let db_name = request.database_name;
         let mut db_id = String::from("");
+        let mut is_successful = false;
+        let mut result_message = String::from("");
 
         if auth_result.0 {
             let result = self.dbi().create_partial_database(&db_name);
             if result.is_ok() {
-                db_id = self.dbi().get_db_id(db_name.as_str());
+                match self.dbi().get_db_id(db_name.as_str()) {
+                    Ok(id) => {
+                        db_id = id;
+                        is_successful = true;
+                    }
+                    Err(e) => result_message = e.to_string(),
+                }
             }
         }
 
         CreateDatabaseResult {
             authentication_result: Some(auth_result.1),
-            is_successful: auth_result.0,
+            is_successful,
             database_name: db_name,
-            result_message: String::from(""),
+            result_message,
             database_id: db_id,
         }
     }
 
-    fn authenticate_host(&self, authentication: AuthRequest) -> (bool, AuthResult) {
+    /// Checks that the request comes from a host this instance knows
+    pub fn authenticate_host(&self, authentication: AuthRequest) -> (bool, AuthResult) {
         let mut is_authenticated = false;
 
         let host_id = authentication.user_name;
         let host_token = authentication.token;
 
-        if self.dbi().verify_host_by_id(&host_id, host_token.to_vec()) {
-            is_authenticated = true;
+        // a host we can't look up because of an error is treated the same as one we don't know
+        match self.dbi().verify_host_by_id(&host_id, host_token.to_vec()) {
+            Ok(is_verified) => is_authenticated |= is_verified,
+            Err(e) => warn!("unable to verify host {host_id} by id: {e}"),
         }
 
-        if self
+        match self
             .dbi()
             .verify_host_by_name(&host_id, host_token.to_vec())
         {
-            is_authenticated = true;
+            Ok(is_verified) => is_authenticated |= is_verified,
+            Err(e) => warn!("unable to verify host {host_id} by name: {e}"),
         }
 
         let auth_response = AuthResult {
@@ -146,14 +191,21 @@ impl RcdData {
         (is_authenticated, auth_response)
     }
 
-    fn authenticate_participant(
+    /// Checks that the request comes from a participant of the database
+    pub fn authenticate_participant(
         &self,
         authentication: AuthRequest,
         db_name: &str,
     ) -> (bool, AuthResult) {
         let host_id = authentication.user_name;
         let host_token = authentication.token;
-        let participant = self.dbi().get_participant_by_alias(db_name, &host_id);
+        let participant = self
+            .dbi()
+            .get_participant_by_alias(db_name, &host_id)
+            .unwrap_or_else(|e| {
+                warn!("unable to look up participant {host_id} in {db_name}: {e}");
+                None
+            });
 
         match participant {
             Some(p) => {
@@ -181,10 +233,28 @@ impl RcdData {
     }
 
     pub async fn try_auth(&self, request: TryAuthRequest) -> TryAuthResult {
-        let is_authenticated = self.authenticate_host(request.authentication.unwrap());
+        let authentication = request.authentication.unwrap();
+        let host_id = authentication.user_name.clone();
+        let is_authenticated = self.authenticate_host(authentication);
+        let mut error = None;
+
+        if is_authenticated.0 {
+            if let Err(e) = self
+                .dbi()
+                .update_host_protocol_version(&host_id, request.protocol_version)
+            {
+                warn!("unable to save protocol version of host {host_id}: {e}");
+            }
+
+            if let Err(e) = check_protocol_version(request.protocol_version) {
+                error = Some(e);
+            }
+        }
 
         TryAuthResult {
             authentication_result: Some(is_authenticated.1),
+            protocol_version: rcdproto::PROTOCOL_VERSION,
+            error,
         }
     }
 }
@@ -1,12 +1,14 @@
 use rcd_common::coop_database_participant::CoopDatabaseParticipant;
 use rcd_enum::contract_status::ContractStatus;
+use rcd_error::rcd_db_error::RcdDbError;
 use rcdproto::rcdp::{
-    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult, SaveContractRequest,
+    Participant, ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult,
+    ParticipantRejectsContractRequest, ParticipantRejectsContractResult, SaveContractRequest,
     SaveContractResult,
 };
 
 use super::RcdData;
-use tracing::{error, trace};
+use tracing::{error, trace, warn};
 
 pub async fn accept_contract(
     core: &RcdData,
@@ -19,59 +21,145 @@ pub async fn accept_contract(
 
     let participant_message = request.participant.as_ref().unwrap().clone();
 
-    let coop_db_participant: CoopDatabaseParticipant;
+    let coop_db_participant =
+        match find_participant(core, &request.database_name, &participant_message) {
+            Ok(Some(participant)) => participant,
+            Err(e) => {
+                error!("unable to look up participant for accepted contract: {e}");
 
-    let accepted_participant = core.dbi().get_participant_by_alias(
-        &request.database_name,
-        &request.participant.as_ref().unwrap().alias,
-    );
-
-    if accepted_participant.is_none() {
-        let _participant = core.dbi().get_participant_by_id(
-            &request.database_name,
-            &request.participant.as_ref().unwrap().participant_guid,
-        );
-
-        if _participant.is_some() {
-            trace!(
-                "found participant: {:?}",
-                _participant.as_ref().unwrap().clone()
-            );
-            coop_db_participant = _participant.unwrap();
-        } else {
-            error!("could not find participant by alias or id, about to panic");
-            panic!();
-        }
-    } else {
-        trace!(
-            "found participant: {:?}",
-            accepted_participant.as_ref().unwrap().clone()
-        );
-        coop_db_participant = accepted_participant.unwrap();
-    }
+                return ParticipantAcceptsContractResult {
+                    contract_acceptance_is_acknowledged: false,
+                    error_message: e.to_string(),
+                };
+            }
+            Ok(None) => {
+                error!(
+                    "could not find participant {} for accepted contract",
+                    participant_message.alias
+                );
 
-    let is_successful = core.dbi().update_participant_accepts_contract(
+                return ParticipantAcceptsContractResult {
+                    contract_acceptance_is_acknowledged: false,
+                    error_message: format!(
+                        "participant {} was not found in {}",
+                        participant_message.alias, request.database_name
+                    ),
+                };
+            }
+        };
+
+    match core.dbi().update_participant_accepts_contract(
         &request.database_name,
         coop_db_participant,
         participant_message,
         &request.contract_version_guid,
-    );
+    ) {
+        Ok(is_successful) => ParticipantAcceptsContractResult {
+            contract_acceptance_is_acknowledged: is_successful,
+            error_message: String::from(""),
+        },
+        Err(e) => {
+            error!("unable to save accepted contract: {e}");
 
-    ParticipantAcceptsContractResult {
-        contract_acceptance_is_acknowledged: is_successful,
-        error_message: String::from(""),
+            ParticipantAcceptsContractResult {
+                contract_acceptance_is_acknowledged: false,
+                error_message: e.to_string(),
+            }
+        }
     }
 }
 
+pub async fn reject_contract(
+    core: &RcdData,
+    request: ParticipantRejectsContractRequest,
+) -> ParticipantRejectsContractResult {
+    trace!("{request:?}");
+
+    let participant_message = request.participant.as_ref().unwrap().clone();
+
+    match find_participant(core, &request.database_name, &participant_message) {
+        Ok(Some(participant)) => {
+            match core
+                .dbi()
+                .update_participant_rejects_contract(&request.database_name, participant)
+            {
+                Ok(is_successful) => ParticipantRejectsContractResult {
+                    contract_rejection_is_acknowledged: is_successful,
+                    error_message: String::from(""),
+                },
+                Err(e) => {
+                    error!("unable to save rejected contract: {e}");
+
+                    ParticipantRejectsContractResult {
+                        contract_rejection_is_acknowledged: false,
+                        error_message: e.to_string(),
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            error!("unable to look up participant for rejected contract: {e}");
+
+            ParticipantRejectsContractResult {
+                contract_rejection_is_acknowledged: false,
+                error_message: e.to_string(),
+            }
+        }
+        Ok(None) => {
+            warn!(
+                "could not find participant {} for rejected contract",
+                participant_message.alias
+            );
+
+            ParticipantRejectsContractResult {
+                contract_rejection_is_acknowledged: false,
+                error_message: format!(
+                    "participant {} was not found in {}",
+                    participant_message.alias, request.database_name
+                ),
+            }
+        }
+    }
+}
+
+/// Looks up the participant who sent us a message about a contract, first by alias and then by id
+fn find_participant(
+    core: &RcdData,
+    db_name: &str,
+    participant: &Participant,
+) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+    let coop_db_participant = match core
+        .dbi()
+        .get_participant_by_alias(db_name, &participant.alias)?
+    {
+        Some(coop_db_participant) => Some(coop_db_participant),
+        None => core
+            .dbi()
+            .get_participant_by_id(db_name, &participant.participant_guid)?,
+    };
+
+    trace!("found participant: {coop_db_participant:?}");
+
+    Ok(coop_db_participant)
+}
+
 pub async fn save_contract(core: &RcdData, request: SaveContractRequest) -> SaveContractResult {
     let contract = request.contract.unwrap();
 
-    let save_result = core.dbi().save_contract(contract);
-    let status = ContractStatus::to_u32(save_result.contract_status);
+    match core.dbi().save_contract(contract) {
+        Ok(save_result) => SaveContractResult {
+            is_saved: save_result.is_successful,
+            contract_status: ContractStatus::to_u32(save_result.contract_status),
+            participant_info: save_result.participant_information,
+        },
+        Err(e) => {
+            error!("unable to save contract: {e}");
 
-    SaveContractResult {
-        is_saved: save_result.is_successful,
-        contract_status: status,
-        participant_info: save_result.participant_information,
+            SaveContractResult {
+                is_saved: false,
+                contract_status: ContractStatus::to_u32(ContractStatus::Unknown),
+                participant_info: None,
+            }
+        }
     }
 }
@@ -1,15 +1,21 @@
 use super::RcdData;
 use stdext::function_name;
 use tracing::{trace, warn};
+use rcd_common::crypt::ROW_HASH_VERSION;
 use rcd_common::db::PartialDataResult;
 use rcd_enum::deletes_from_host_behavior::DeletesFromHostBehavior;
+use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
+use rcd_enum::remote_delete_behavior::RemoteDeleteBehavior;
 use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
 use rcd_enum::{
     partial_data_result_action::PartialDataResultAction, partial_data_status::PartialDataStatus,
 };
 use rcdproto::rcdp::{
     DeleteDataRequest, DeleteDataResult, GetRowFromPartialDatabaseRequest,
-    GetRowFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    GetRowFromPartialDatabaseResult, GetRowsFromPartialDatabaseRequest,
+    GetRowsFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    NotifyHostOfInsertedRowRequest, NotifyHostOfInsertedRowResponse,
+    NotifyHostOfPendingActionRequest, NotifyHostOfPendingActionResponse,
     NotifyHostOfRemovedRowRequest, NotifyHostOfRemovedRowResponse, Row, RowInfo, UpdateDataRequest,
     UpdateDataResult, UpdateRowDataHashForHostRequest, UpdateRowDataHashForHostResponse,
 };
@@ -28,21 +34,31 @@ pub async fn insert_command_into_table(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Insert),
+        affected_rows: Vec::new(),
     };
 
+    let mut message = String::from("");
+
     if auth_result.0 {
         let cmd = &request.cmd;
 
-        result = core
-            .dbi()
-            .insert_data_into_partial_db(&db_name, &table_name, cmd);
+        match core.dbi().check_database_key(&db_name) {
+            Ok(_) => match core
+                .dbi()
+                .insert_data_into_partial_db(&db_name, &table_name, cmd)
+            {
+                Ok(data_result) => result = data_result,
+                Err(e) => message = e.to_string(),
+            },
+            Err(e) => message = e.to_string(),
+        }
     }
 
     InsertDataResult {
         authentication_result: Some(auth_result.1),
         is_successful: result.is_successful,
-        data_hash: result.data_hash.unwrap(),
-        message: String::from(""),
+        data_hash: result.data_hash.unwrap_or_default(),
+        message,
         row_id: result.row_id,
     }
 }
@@ -56,6 +72,7 @@ pub async fn delete_command_into_table(
     let table_name = request.table_name;
     let where_clause = request.where_clause.clone();
     let mut action_message = String::from("");
+    let mut pending_action_id: u32 = 0;
 
     let mut rows: Vec<RowInfo> = Vec::new();
 
@@ -65,17 +82,55 @@ pub async fn delete_command_into_table(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Delete),
+        affected_rows: Vec::new(),
     };
 
     trace!("[{}] auth_result: {auth_result:?}", function_name!());
 
     if auth_result.0 {
-        let known_host = core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
+        if let Err(e) = core.dbi().check_database_key(&db_name) {
+            return DeleteDataResult {
+                authentication_result: Some(auth_result.1),
+                is_successful: false,
+                message: e.to_string(),
+                rows,
+                pending_action_id,
+            };
+        }
 
-        // need to check if this is allowed
-        let behavior = core
+        let host_and_behavior = core
             .dbi()
-            .get_deletes_from_host_behavior(&db_name, &table_name);
+            .get_cds_host_for_part_db(&db_name)
+            .and_then(|host| {
+                Ok((
+                    host,
+                    core.dbi()
+                        .get_deletes_from_host_behavior(&db_name, &table_name)?,
+                ))
+            });
+
+        // need to check if this is allowed
+        let (known_host, behavior) = match host_and_behavior {
+            Ok((Some(known_host), behavior)) => (known_host, behavior),
+            Ok((None, _)) => {
+                return DeleteDataResult {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: format!("no host is set for {db_name}"),
+                    rows,
+                    pending_action_id,
+                };
+            }
+            Err(e) => {
+                return DeleteDataResult {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: e.to_string(),
+                    rows,
+                    pending_action_id,
+                };
+            }
+        };
 
         match behavior {
             DeletesFromHostBehavior::Ignore => {
@@ -87,75 +142,64 @@ pub async fn delete_command_into_table(
                 let cmd = &request.cmd;
                 trace!("[{}] {cmd:?}", function_name!());
 
-                result = core.dbi().delete_data_in_partial_db(
+                match core.dbi().delete_data_in_partial_db(
                     &db_name,
                     &table_name,
                     cmd,
                     &where_clause,
                     &known_host.host_id,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 trace!("[{}] {result:?}", function_name!());
 
-                let hash = match result.data_hash {
-                    Some(_) => result.data_hash.unwrap(),
-                    None => 0,
-                };
-
                 if result.is_successful {
-                    let row = RowInfo {
-                        database_name: db_name,
-                        table_name,
-                        rowid: result.row_id,
-                        data_hash: hash,
-                    };
-                    rows.push(row);
+                    rows = get_affected_rows(&db_name, &table_name, &result);
                 }
             }
             DeletesFromHostBehavior::DeleteWithLog => {
                 let cmd = &request.cmd;
 
-                result = core.dbi().delete_data_in_partial_db(
+                match core.dbi().delete_data_in_partial_db(
                     &db_name,
                     &table_name,
                     cmd,
                     &where_clause,
                     &known_host.host_id,
-                );
-
-                let hash = match result.data_hash {
-                    Some(_) => result.data_hash.unwrap(),
-                    None => 0,
-                };
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
-                    let row = RowInfo {
-                        database_name: db_name,
-                        table_name,
-                        rowid: result.row_id,
-                        data_hash: hash,
-                    };
-                    rows.push(row);
+                    rows = get_affected_rows(&db_name, &table_name, &result);
                 }
             }
-            DeletesFromHostBehavior::QueueForReview => {
+            DeletesFromHostBehavior::QueueForReview
+            | DeletesFromHostBehavior::QueueForReviewAndLog => {
                 let cmd = &request.cmd;
 
-                result = core.dbi().delete_data_in_partial_db(
+                // the rows are logged when the delete is accepted, if the behavior asks for it
+                match core.dbi().delete_data_in_partial_db(
                     &db_name,
                     &table_name,
                     cmd,
                     &where_clause,
                     &known_host.host_id,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
+                    pending_action_id = result.row_id;
                     action_message =
                         String::from("The delete statement has been logged for review");
                 }
             }
             DeletesFromHostBehavior::Unknown => todo!(),
-            DeletesFromHostBehavior::QueueForReviewAndLog => todo!(),
         }
     }
 
@@ -166,6 +210,7 @@ pub async fn delete_command_into_table(
         is_successful: result.is_successful,
         message: action_message,
         rows,
+        pending_action_id,
     }
 }
 
@@ -180,6 +225,7 @@ pub async fn update_command_into_table(
     let where_clause = request.where_clause.clone();
     let mut action_message = String::from("");
     let mut update_status: u32 = 0;
+    let mut pending_action_id: u32 = 0;
     let mut rows: Vec<RowInfo> = Vec::new();
 
     let mut result = PartialDataResult {
@@ -188,16 +234,58 @@ pub async fn update_command_into_table(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Update),
+        affected_rows: Vec::new(),
     };
 
     if auth_result.0 {
-        let known_host = core.dbi().get_cds_host_for_part_db(&db_name).unwrap();
+        if let Err(e) = core.dbi().check_database_key(&db_name) {
+            return UpdateDataResult {
+                authentication_result: Some(auth_result.1),
+                is_successful: false,
+                message: e.to_string(),
+                rows,
+                update_status,
+                pending_action_id,
+            };
+        }
+
         let cmd = &request.cmd;
 
-        // need to check if this is allowed
-        let behavior = core
+        let host_and_behavior = core
             .dbi()
-            .get_updates_from_host_behavior(&db_name, &table_name);
+            .get_cds_host_for_part_db(&db_name)
+            .and_then(|host| {
+                Ok((
+                    host,
+                    core.dbi()
+                        .get_updates_from_host_behavior(&db_name, &table_name)?,
+                ))
+            });
+
+        // need to check if this is allowed
+        let (known_host, behavior) = match host_and_behavior {
+            Ok((Some(known_host), behavior)) => (known_host, behavior),
+            Ok((None, _)) => {
+                return UpdateDataResult {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: format!("no host is set for {db_name}"),
+                    rows,
+                    update_status,
+                    pending_action_id,
+                };
+            }
+            Err(e) => {
+                return UpdateDataResult {
+                    authentication_result: Some(auth_result.1),
+                    is_successful: false,
+                    message: e.to_string(),
+                    rows,
+                    update_status,
+                    pending_action_id,
+                };
+            }
+        };
 
         match behavior {
             UpdatesFromHostBehavior::Ignore => {
@@ -205,76 +293,102 @@ pub async fn update_command_into_table(
                     "The participant does not allow updates for db {db_name} table: {table_name}"
                 );
                 update_status = PartialDataStatus::to_u32(PartialDataStatus::Ignored);
+
+                // let the host know which of its rows no longer match what it asked for
+                if !where_clause.is_empty() {
+                    let row_ids = core
+                        .dbi()
+                        .read_row_ids_from_part_db(&db_name, &table_name, &where_clause)
+                        .unwrap_or_else(|e| {
+                            warn!("unable to read row ids from {db_name}.{table_name}: {e:?}");
+                            Vec::new()
+                        });
+
+                    rows = row_ids
+                        .iter()
+                        .map(|row_id| RowInfo {
+                            database_name: db_name.clone(),
+                            table_name: table_name.clone(),
+                            rowid: *row_id,
+                            data_hash: core
+                                .dbi()
+                                .get_data_hash_at_participant(&db_name, &table_name, *row_id)
+                                .unwrap_or_default(),
+                        })
+                        .collect();
+                }
             }
             UpdatesFromHostBehavior::AllowOverwrite => {
-                result = core.dbi().update_data_into_partial_db(
+                match core.dbi().update_data_into_partial_db(
                     &db_name,
                     &table_name,
                     cmd,
                     &known_host.host_id,
                     &where_clause,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
-                    let row = RowInfo {
-                        database_name: db_name,
-                        table_name,
-                        rowid: result.row_id,
-                        data_hash: result.data_hash.unwrap(),
-                    };
-                    rows.push(row);
+                    rows = get_affected_rows(&db_name, &table_name, &result);
                     update_status =
                         PartialDataStatus::to_u32(PartialDataStatus::SucessOverwriteOrLog);
                 }
             }
             UpdatesFromHostBehavior::OverwriteWithLog => {
-                result = core.dbi().update_data_into_partial_db(
+                match core.dbi().update_data_into_partial_db(
                     &db_name,
                     &table_name,
                     cmd,
                     &known_host.host_id,
                     &where_clause,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
-                    let row = RowInfo {
-                        database_name: db_name,
-                        table_name,
-                        rowid: result.row_id,
-                        data_hash: result.data_hash.unwrap(),
-                    };
-                    rows.push(row);
+                    rows = get_affected_rows(&db_name, &table_name, &result);
                     update_status =
                         PartialDataStatus::to_u32(PartialDataStatus::SucessOverwriteOrLog);
                 }
             }
             UpdatesFromHostBehavior::QueueForReview => {
-                result = core.dbi().update_data_into_partial_db_queue(
+                match core.dbi().update_data_into_partial_db_queue(
                     &db_name,
                     &table_name,
                     cmd,
                     &where_clause,
                     &known_host,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
                     update_status = PartialDataStatus::to_u32(PartialDataStatus::Pending);
+                    pending_action_id = result.row_id;
                     action_message =
                         String::from("The update statement has been logged for review");
                 }
             }
             UpdatesFromHostBehavior::Unknown => unimplemented!(),
             UpdatesFromHostBehavior::QueueForReviewAndLog => {
-                result = core.dbi().update_data_into_partial_db_queue(
+                match core.dbi().update_data_into_partial_db_queue(
                     &db_name,
                     &table_name,
                     cmd,
                     &where_clause,
                     &known_host,
-                );
+                ) {
+                    Ok(data_result) => result = data_result,
+                    Err(e) => action_message = e.to_string(),
+                }
 
                 if result.is_successful {
                     update_status = PartialDataStatus::to_u32(PartialDataStatus::Pending);
+                    pending_action_id = result.row_id;
                     action_message =
                         String::from("The update statement has been logged for review");
                 }
@@ -288,6 +402,7 @@ pub async fn update_command_into_table(
         message: action_message,
         rows,
         update_status,
+        pending_action_id,
     }
 }
 
@@ -298,6 +413,7 @@ pub async fn get_row_from_partial_database(
     let auth_result = core.authenticate_host(request.authentication.as_ref().unwrap().clone());
 
     let mut has_row = false;
+    let mut result_message = String::from("");
 
     let mut result_row = Row {
         row_id: 0,
@@ -314,10 +430,20 @@ pub async fn get_row_from_partial_database(
         let table_name = request.row_address.as_ref().unwrap().table_name.clone();
         let row_id = request.row_address.as_ref().unwrap().row_id;
 
-        result_row = core
-            .dbi()
-            .get_row_from_partial_database(&db_name, &table_name, row_id);
-        has_row = true;
+        match core.dbi().check_database_key(&db_name) {
+            Ok(_) => match core
+                .dbi()
+                .get_row_from_partial_database(&db_name, &table_name, row_id)
+            {
+                Ok(row) => {
+                    result_row = row;
+                    has_row = true;
+                    result_message = get_row_hash_version_warning(core, &db_name);
+                }
+                Err(e) => result_message = e.to_string(),
+            },
+            Err(e) => result_message = e.to_string(),
+        }
     } else {
         let auth = request.authentication.as_ref().unwrap().clone();
         warn!("unable to authenticate {auth:?}");
@@ -326,11 +452,83 @@ pub async fn get_row_from_partial_database(
     GetRowFromPartialDatabaseResult {
         authentication_result: Some(auth_result.1),
         is_successful: has_row,
-        result_message: String::from(""),
+        result_message,
         row: Some(result_row),
     }
 }
 
+pub async fn get_rows_from_partial_database(
+    core: &RcdData,
+    request: GetRowsFromPartialDatabaseRequest,
+) -> GetRowsFromPartialDatabaseResult {
+    let auth_result = core.authenticate_host(request.authentication.as_ref().unwrap().clone());
+
+    let mut is_successful = false;
+    let mut result_message = String::from("");
+    let mut rows = Vec::new();
+
+    if auth_result.0 {
+        let db_name = request.database_name.clone();
+        let table_name = request.table_name.clone();
+
+        match core.dbi().check_database_key(&db_name) {
+            Ok(_) => {
+                let read_rows: Result<Vec<Row>, _> = request
+                    .row_ids
+                    .iter()
+                    .map(|row_id| {
+                        core.dbi()
+                            .get_row_from_partial_database(&db_name, &table_name, *row_id)
+                    })
+                    .collect();
+
+                match read_rows {
+                    Ok(read_rows) => {
+                        rows = read_rows;
+                        is_successful = true;
+                        result_message = get_row_hash_version_warning(core, &db_name);
+                    }
+                    Err(e) => result_message = e.to_string(),
+                }
+            }
+            Err(e) => result_message = e.to_string(),
+        }
+    } else {
+        let auth = request.authentication.as_ref().unwrap().clone();
+        warn!("unable to authenticate {auth:?}");
+    }
+
+    GetRowsFromPartialDatabaseResult {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+        result_message,
+        rows,
+    }
+}
+
+/// Returns a warning for the host if the hashes saved in the partial database weren't calculated
+/// the way this version of rcd calculates them, until the participant rehashes the database
+fn get_row_hash_version_warning(core: &RcdData, db_name: &str) -> String {
+    match core.dbi().get_row_hash_version(db_name) {
+        Ok(Some(ROW_HASH_VERSION)) => String::from(""),
+        Ok(version) => {
+            let version = version
+                .map(|version| version.to_string())
+                .unwrap_or_else(|| String::from("unknown"));
+
+            warn!("the row hashes of {db_name} are at version {version}, it needs to be rehashed");
+
+            format!(
+                "the row hashes of {db_name} are at version {version} instead of {ROW_HASH_VERSION} until the participant rehashes it"
+            )
+        }
+        Err(e) => {
+            warn!("unable to read the row hash version of {db_name}: {e}");
+            String::from("")
+        }
+    }
+}
+
 pub async fn update_row_data_hash_for_host(
     core: &RcdData,
     request: UpdateRowDataHashForHostRequest,
@@ -349,19 +547,31 @@ pub async fn update_row_data_hash_for_host(
         let row_id = request.row_id;
         let hash = request.updated_hash_value;
 
-        let internal_participant_id = core
-            .dbi()
-            .get_participant_by_alias(&db_name, &user_name)
-            .unwrap()
-            .internal_id;
-
-        is_successful = core.dbi().update_metadata_in_host_db(
-            &db_name,
-            &table_name,
-            row_id,
-            hash,
-            &internal_participant_id.to_string(),
-        );
+        is_successful = match core.dbi().get_participant_by_alias(&db_name, &user_name) {
+            Ok(Some(participant)) => core
+                .dbi()
+                .update_metadata_in_host_db(
+                    &db_name,
+                    &table_name,
+                    &[(row_id, hash)],
+                    &participant.internal_id.to_string(),
+                )
+                .unwrap_or_else(|e| {
+                    warn!("[{}]: {e}", function_name!());
+                    false
+                }),
+            Ok(None) => {
+                warn!(
+                    "[{}]: {user_name} is not a participant of {db_name}",
+                    function_name!()
+                );
+                false
+            }
+            Err(e) => {
+                warn!("[{}]: {e}", function_name!());
+                false
+            }
+        };
     } else {
         trace!("not authenticated!");
     }
@@ -372,23 +582,206 @@ pub async fn update_row_data_hash_for_host(
     }
 }
 
+/// Called by a participant once it has accepted or rejected an action it had queued for
+/// review. If accepted, the host's metadata is brought up to date with the rows that changed
+pub async fn notify_host_of_pending_action(
+    core: &RcdData,
+    request: NotifyHostOfPendingActionRequest,
+) -> NotifyHostOfPendingActionResponse {
+    let mut is_successful = false;
+
+    let authentication = request.authentication.unwrap();
+    let user_name = authentication.user_name.clone();
+
+    let auth_result = core.authenticate_participant(authentication, &request.database_name);
+
+    if auth_result.0 {
+        let db_name = &request.database_name;
+        let table_name = &request.table_name;
+
+        let internal_participant_id = match core.dbi().get_participant_by_alias(db_name, &user_name)
+        {
+            Ok(Some(participant)) => participant.internal_id.to_string(),
+            Ok(None) => {
+                warn!(
+                    "[{}]: {user_name} is not a participant of {db_name}",
+                    function_name!()
+                );
+
+                return NotifyHostOfPendingActionResponse {
+                    authentication_result: Some(auth_result.1),
+                    is_successful,
+                };
+            }
+            Err(e) => {
+                warn!("[{}]: {e}", function_name!());
+
+                return NotifyHostOfPendingActionResponse {
+                    authentication_result: Some(auth_result.1),
+                    is_successful,
+                };
+            }
+        };
+
+        let metadata_result = if !request.is_accepted {
+            Ok(true)
+        } else if request.action == "DELETE" {
+            let row_ids: Vec<u32> = request.rows.iter().map(|row| row.rowid).collect();
+            core.dbi().delete_metadata_in_host_db(
+                db_name,
+                table_name,
+                &row_ids,
+                &internal_participant_id,
+            )
+        } else {
+            let rows: Vec<(u32, u64)> = request
+                .rows
+                .iter()
+                .map(|row| (row.rowid, row.data_hash))
+                .collect();
+            core.dbi().update_metadata_in_host_db(
+                db_name,
+                table_name,
+                &rows,
+                &internal_participant_id,
+            )
+        };
+
+        is_successful = metadata_result.unwrap_or_else(|e| {
+            warn!("[{}]: {e}", function_name!());
+            false
+        });
+
+        if is_successful && request.is_accepted {
+            // mirrored and shared tables keep a copy of the row at the host, which only now
+            // takes the write the participant had queued
+            let pending_write = core
+                .dbi()
+                .get_pending_writes_at_host(db_name)
+                .unwrap_or_else(|e| {
+                    warn!("[{}]: {e}", function_name!());
+                    Vec::new()
+                })
+                .into_iter()
+                .find(|write| {
+                    write.participant_alias == user_name
+                        && write.pending_action_id == request.pending_action_id
+                });
+
+            if let Some(pending_write) = pending_write {
+                let local_write_result = core.dbi().apply_cooperative_write_at_host(
+                    db_name,
+                    table_name,
+                    &pending_write.statement,
+                );
+
+                if let Err(e) = local_write_result {
+                    warn!(
+                        "[{}]: unable to apply write to host copy of {table_name}: {e:?}",
+                        function_name!()
+                    );
+                }
+            }
+        }
+
+        let is_removed = core.dbi().remove_pending_write_at_host(
+            db_name,
+            table_name,
+            &internal_participant_id,
+            request.pending_action_id,
+        );
+
+        trace!(
+            "[{}]: pending action {} removed: {is_removed:?}",
+            function_name!(),
+            request.pending_action_id
+        );
+    } else {
+        trace!("not authenticated!");
+    }
+
+    NotifyHostOfPendingActionResponse {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+    }
+}
+
 pub async fn notify_host_of_removed_row(
     core: &RcdData,
     request: NotifyHostOfRemovedRowRequest,
 ) -> NotifyHostOfRemovedRowResponse {
-    let auth_result =
-        core.authenticate_participant(request.authentication.unwrap(), &request.database_name);
     let mut is_successful = false;
 
+    let authentication = request.authentication.unwrap();
+    let user_name = authentication.user_name.clone();
+
+    let auth_result = core.authenticate_participant(authentication, &request.database_name);
+
     if auth_result.0 {
         trace!("is authenticated");
         let db_name = request.database_name.clone();
         let table_name = request.table_name.clone();
         let row_id = request.row_id;
 
-        is_successful =
-            core.dbi()
-                .remove_remote_row_reference_from_host(&db_name, &table_name, row_id);
+        let behavior = match core.dbi().get_active_contract(&db_name) {
+            Ok(contract) => RemoteDeleteBehavior::from_u32(contract.remote_delete_behavior),
+            Err(e) => {
+                warn!("[{}]: {e}", function_name!());
+                return NotifyHostOfRemovedRowResponse {
+                    authentication_result: Some(auth_result.1),
+                    is_successful,
+                };
+            }
+        };
+
+        trace!(
+            "[{}]: remote delete behavior: {behavior:?}",
+            function_name!()
+        );
+
+        is_successful = match behavior {
+            // the participant's delete is acknowledged, but the host keeps its reference as is
+            RemoteDeleteBehavior::Ignore => true,
+            _ => match core.dbi().get_participant_by_alias(&db_name, &user_name) {
+                Ok(Some(participant)) => {
+                    let internal_participant_id = participant.internal_id.to_string();
+
+                    let result = match behavior {
+                        RemoteDeleteBehavior::UpdateStatusOnly => {
+                            core.dbi().mark_metadata_remote_deleted_in_host_db(
+                                &db_name,
+                                &table_name,
+                                row_id,
+                                &internal_participant_id,
+                            )
+                        }
+                        // contracts written before the behavior was enforced still drop the reference
+                        _ => core.dbi().delete_metadata_in_host_db(
+                            &db_name,
+                            &table_name,
+                            &[row_id],
+                            &internal_participant_id,
+                        ),
+                    };
+
+                    result.unwrap_or_else(|e| {
+                        warn!("[{}]: {e}", function_name!());
+                        false
+                    })
+                }
+                Ok(None) => {
+                    warn!(
+                        "[{}]: {user_name} is not a participant of {db_name}",
+                        function_name!()
+                    );
+                    false
+                }
+                Err(e) => {
+                    warn!("[{}]: {e}", function_name!());
+                    false
+                }
+            },
+        };
     } else {
         warn!("notify_host_of_removed_row: not authenticated!");
     }
@@ -398,3 +791,78 @@ pub async fn notify_host_of_removed_row(
         is_successful,
     }
 }
+
+pub async fn notify_host_of_inserted_row(
+    core: &RcdData,
+    request: NotifyHostOfInsertedRowRequest,
+) -> NotifyHostOfInsertedRowResponse {
+    let mut is_successful = false;
+
+    let authentication = request.authentication.unwrap();
+    let user_name = authentication.user_name.clone();
+
+    let auth_result = core.authenticate_participant(authentication, &request.database_name);
+
+    if auth_result.0 {
+        trace!("is authenticated");
+        let db_name = request.database_name.clone();
+        let table_name = request.table_name.clone();
+        let row_id = request.row_id;
+        let hash = request.hash;
+
+        // only participant owned tables keep a reference to rows that live at the participant;
+        // every other policy expects the host to hold the row itself
+        let policy = core.dbi().get_logical_storage_policy(&db_name, &table_name);
+        let participant = core.dbi().get_participant_by_alias(&db_name, &user_name);
+
+        match (policy, participant) {
+            (Ok(LogicalStoragePolicy::ParticpantOwned), Ok(Some(participant))) => {
+                is_successful = core
+                    .dbi()
+                    .insert_metadata_into_host_db(
+                        &db_name,
+                        &table_name,
+                        row_id,
+                        hash,
+                        &participant.internal_id.to_string(),
+                    )
+                    .unwrap_or_else(|e| {
+                        warn!("notify_host_of_inserted_row: {e:?}");
+                        false
+                    });
+            }
+            (Ok(policy), Ok(Some(_))) => {
+                warn!(
+                    "notify_host_of_inserted_row: {db_name}.{table_name} has policy {policy:?}, refusing insert from {user_name}"
+                );
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                warn!("notify_host_of_inserted_row: {e:?}");
+            }
+            (_, Ok(None)) => {
+                warn!("notify_host_of_inserted_row: {user_name} is not a participant of {db_name}");
+            }
+        }
+    } else {
+        warn!("notify_host_of_inserted_row: not authenticated!");
+    }
+
+    NotifyHostOfInsertedRowResponse {
+        authentication_result: Some(auth_result.1),
+        is_successful,
+    }
+}
+
+/// The rows we report back to the host for a write, one for every row the write touched
+fn get_affected_rows(db_name: &str, table_name: &str, result: &PartialDataResult) -> Vec<RowInfo> {
+    result
+        .affected_rows
+        .iter()
+        .map(|row| RowInfo {
+            database_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            rowid: row.row_id,
+            data_hash: row.data_hash.unwrap_or_default(),
+        })
+        .collect()
+}
@@ -14,15 +14,26 @@ pub async fn create_table_in_database(
     let mut table_is_created = false;
     let mut table_id = String::from("");
     let mut db_id = String::from("");
+    let mut result_message = String::from("");
 
     if auth_result.0 {
         let result =
             core.dbi()
                 .create_table_in_partial_database(&db_name, &table_name, table_schema);
         if result.is_ok() {
-            table_is_created = true;
-            table_id = core.dbi().get_table_id(&db_name, &table_name);
-            db_id = core.dbi().get_db_id(db_name.as_str());
+            let ids = core
+                .dbi()
+                .get_table_id(&db_name, &table_name)
+                .and_then(|t| core.dbi().get_db_id(db_name.as_str()).map(|d| (t, d)));
+
+            match ids {
+                Ok((t, d)) => {
+                    table_is_created = true;
+                    table_id = t;
+                    db_id = d;
+                }
+                Err(e) => result_message = e.to_string(),
+            }
         }
     }
 
@@ -30,7 +41,7 @@ pub async fn create_table_in_database(
         authentication_result: Some(auth_result.1),
         is_successful: table_is_created,
         database_name: db_name,
-        result_message: String::from(""),
+        result_message,
         table_id,
         table_name,
         database_id: db_id,
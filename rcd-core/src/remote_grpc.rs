@@ -13,7 +13,7 @@ use tracing::{debug, info, trace};
 use rcd_common::{
     coop_database_contract::CoopDatabaseContract,
     coop_database_participant::{CoopDatabaseParticipant, CoopDatabaseParticipantData},
-    data_info::DataInfo,
+    data_info::{DataInfo, PendingActionInfo},
     host_info::HostInfo,
     save_contract_result::RcdSaveContractResult,
 };
@@ -21,10 +21,13 @@ use rcd_enum::contract_status::ContractStatus;
 use rcdproto::rcdp::{
     data_service_client::DataServiceClient, AuthRequest, Contract, DatabaseSchema,
     DeleteDataRequest, DeleteDataResult, GetRowFromPartialDatabaseRequest,
-    GetRowFromPartialDatabaseResult, Host, InsertDataRequest, InsertDataResult, MessageInfo,
+    GetRowFromPartialDatabaseResult, GetRowsFromPartialDatabaseRequest,
+    GetRowsFromPartialDatabaseResult, Host, InsertDataRequest, InsertDataResult, MessageInfo,
+    NotifyHostOfInsertedRowRequest, NotifyHostOfPendingActionRequest,
     NotifyHostOfRemovedRowRequest, Participant, ParticipantAcceptsContractRequest,
-    RowParticipantAddress, SaveContractRequest, TryAuthRequest, UpdateDataRequest,
-    UpdateDataResult, UpdateRowDataHashForHostRequest,
+    ParticipantRejectsContractRequest, RowInfo, RowParticipantAddress, SaveContractRequest,
+    TryAuthRequest, TryAuthResult, UpdateDataRequest, UpdateDataResult,
+    UpdateRowDataHashForHostRequest,
 };
 use tonic::transport::Channel;
 
@@ -41,16 +44,16 @@ impl RemoteGrpc {
         &self,
         participant: CoopDatabaseParticipant,
         own_host_info: &HostInfo,
-    ) -> bool {
+    ) -> TryAuthResult {
         let auth = get_auth_request(own_host_info, Some(participant.id.to_string()));
         let request = TryAuthRequest {
             authentication: Some(auth),
+            protocol_version: rcdproto::PROTOCOL_VERSION,
         };
 
         let client = get_client(participant, self.timeout_in_seconds);
         let response = client.await.try_auth(request).await;
-        let result = response.unwrap().into_inner();
-        result.authentication_result.unwrap().is_authenticated
+        response.unwrap().into_inner()
     }
 
     pub async fn send_participant_contract(
@@ -151,6 +154,52 @@ impl RemoteGrpc {
         result.is_successful
     }
 
+    pub async fn notify_host_of_inserted_row(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        data_info: &DataInfo,
+    ) -> bool {
+        let auth = get_auth_request(own_host_info, Some(host.host_id.clone()));
+
+        debug!("remote-grpc::notify_host_of_inserted_row::auth: {auth:?}");
+
+        let message_info = get_message_info(own_host_info, self.db_addr_port.clone());
+
+        let chost = Host {
+            host_guid: own_host_info.id.clone(),
+            host_name: own_host_info.name.clone(),
+            ip4_address: String::from(""),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            http_addr: "".to_string(),
+            http_port: 0,
+        };
+
+        let request = NotifyHostOfInsertedRowRequest {
+            authentication: Some(auth),
+            message_info: Some(message_info),
+            host_info: Some(chost),
+            database_name: data_info.db_name.to_string(),
+            database_id: String::from(""),
+            table_name: data_info.table_name.to_string(),
+            table_id: 0,
+            row_id: data_info.row_id,
+            hash: data_info.hash.unwrap_or(0),
+        };
+
+        debug!("{request:?}");
+
+        let client = get_client_from_cds_host(host);
+        let response = client.await.notify_host_of_inserted_row(request).await;
+        let result = response.unwrap().into_inner();
+
+        debug!("{result:?}");
+
+        result.is_successful
+    }
+
     pub async fn remove_row_at_participant(
         &self,
         participant: CoopDatabaseParticipant,
@@ -271,6 +320,35 @@ impl RemoteGrpc {
         response.into_inner()
     }
 
+    pub async fn get_rows_from_participant(
+        &self,
+        participant: CoopDatabaseParticipantData,
+        own_host_info: HostInfo,
+        row_ids: Vec<u32>,
+    ) -> GetRowsFromPartialDatabaseResult {
+        let message_info = get_message_info(&own_host_info, self.db_addr_port.clone());
+        let auth = get_auth_request(&own_host_info, Some(participant.participant.id.to_string()));
+
+        let request = GetRowsFromPartialDatabaseRequest {
+            authentication: Some(auth),
+            database_name: participant.db_name.clone(),
+            table_name: participant.table_name.clone(),
+            row_ids,
+            message_info: Some(message_info),
+        };
+
+        let participant_info = participant.participant.clone();
+
+        let client = get_client(participant_info, self.timeout_in_seconds);
+        let response = client
+            .await
+            .get_rows_from_partial_database(request)
+            .await
+            .unwrap();
+
+        response.into_inner()
+    }
+
     pub async fn notify_host_of_updated_hash(
         &self,
         host: &CdsHosts,
@@ -336,6 +414,44 @@ impl RemoteGrpc {
         }
     }
 
+    pub async fn notify_host_of_pending_action(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        pending_action_info: &PendingActionInfo,
+    ) -> bool {
+        let auth = get_auth_request(own_host_info, Some(host.host_id.clone()));
+        let message_info = get_message_info(own_host_info, self.db_addr_port.clone());
+
+        let chost = Host {
+            host_guid: own_host_info.id.clone(),
+            host_name: own_host_info.name.clone(),
+            ip4_address: String::from(""),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            http_addr: "".to_string(),
+            http_port: 0,
+        };
+
+        let request = NotifyHostOfPendingActionRequest {
+            authentication: Some(auth),
+            message_info: Some(message_info),
+            host_info: Some(chost),
+            database_name: pending_action_info.db_name.clone(),
+            table_name: pending_action_info.table_name.clone(),
+            pending_action_id: pending_action_info.pending_action_id,
+            action: pending_action_info.action.clone(),
+            is_accepted: pending_action_info.is_accepted,
+            rows: get_row_infos(pending_action_info),
+        };
+
+        let client = get_client_from_cds_host(host);
+        let response = client.await.notify_host_of_pending_action(request).await;
+        let result = response.unwrap().into_inner();
+        result.is_successful
+    }
+
     pub async fn notify_host_of_acceptance_of_contract(
         &self,
         accepted_contract: &Contract,
@@ -385,6 +501,62 @@ impl RemoteGrpc {
 
         response.into_inner().contract_acceptance_is_acknowledged
     }
+
+    pub async fn notify_host_of_rejection_of_contract(
+        &self,
+        rejected_contract: &Contract,
+        own_host_info: &HostInfo,
+    ) -> Result<bool, String> {
+        // rpc RejectContract(ParticipantRejectsContractRequest) returns (ParticipantRejectsContractResult);
+
+        let message_info = get_message_info(own_host_info, self.db_addr_port.clone());
+        let host_info = rejected_contract.host_info.as_ref().unwrap().clone();
+
+        let participant = Participant {
+            participant_guid: own_host_info.id.clone(),
+            alias: own_host_info.name.clone(),
+            ip4_address: self.db_addr_port.clone(),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            internal_participant_guid: "".to_string(),
+            http_addr: "".to_string(),
+            http_port: 0,
+        };
+
+        let request = ParticipantRejectsContractRequest {
+            participant: Some(participant),
+            contract_guid: rejected_contract.contract_guid.clone(),
+            contract_version_guid: rejected_contract.contract_version.clone(),
+            database_name: rejected_contract
+                .schema
+                .as_ref()
+                .unwrap()
+                .database_name
+                .clone(),
+            message_info: Some(message_info),
+            id: Some(host_info.host_guid),
+        };
+
+        let message = format!(
+            "sending request to rcd at: {}",
+            host_info.ip4_address.clone()
+        );
+        info!("{}", message);
+        trace!("{message}");
+
+        let addr = host_info.ip4_address;
+        let mut client = try_get_client_with_addr_port(addr.clone(), self.timeout_in_seconds)
+            .await
+            .map_err(|e| format!("unable to connect to host at {addr}: {e}"))?;
+
+        let response = client
+            .reject_contract(request)
+            .await
+            .map_err(|e| format!("host at {addr} returned: {}", e.message()))?;
+
+        Ok(response.into_inner().contract_rejection_is_acknowledged)
+    }
 }
 
 fn get_message_info(host_info: &HostInfo, own_db_addr_port: String) -> MessageInfo {
@@ -404,6 +576,19 @@ fn get_message_info(host_info: &HostInfo, own_db_addr_port: String) -> MessageIn
     }
 }
 
+fn get_row_infos(pending_action_info: &PendingActionInfo) -> Vec<RowInfo> {
+    pending_action_info
+        .rows
+        .iter()
+        .map(|row| RowInfo {
+            database_name: pending_action_info.db_name.clone(),
+            table_name: pending_action_info.table_name.clone(),
+            rowid: row.row_id,
+            data_hash: row.data_hash.unwrap_or_default(),
+        })
+        .collect()
+}
+
 fn is_little_endian() -> bool {
     let v = vec![0, 128, 128, 0];
 
@@ -428,15 +613,25 @@ async fn get_client_with_addr_port(
     addr_port: String,
     timeout_in_seconds: u32,
 ) -> DataServiceClient<Channel> {
+    try_get_client_with_addr_port(addr_port, timeout_in_seconds)
+        .await
+        .unwrap()
+}
+
+/// Connects to the rcd at the address, returning the error if it can't be reached
+async fn try_get_client_with_addr_port(
+    addr_port: String,
+    timeout_in_seconds: u32,
+) -> Result<DataServiceClient<Channel>, tonic::transport::Error> {
     let http_addr_port = format!("{}{}", String::from("http://"), addr_port);
     let message = format!("configuring to connect to rcd at: {addr_port}");
     info!("{}", message);
 
     let endpoint = tonic::transport::Channel::builder(http_addr_port.parse().unwrap())
         .timeout(Duration::from_secs(timeout_in_seconds.into()));
-    let channel = endpoint.connect().await.unwrap();
+    let channel = endpoint.connect().await?;
 
-    DataServiceClient::new(channel)
+    Ok(DataServiceClient::new(channel))
 }
 
 async fn get_client(
@@ -11,22 +11,27 @@ use tracing::{info, trace};
 use rcd_common::{
     coop_database_contract::CoopDatabaseContract,
     coop_database_participant::{CoopDatabaseParticipant, CoopDatabaseParticipantData},
-    data_info::DataInfo,
+    data_info::{DataInfo, PendingActionInfo},
     db::CdsHosts,
     host_info::HostInfo,
     save_contract_result::RcdSaveContractResult,
 };
 use rcd_enum::contract_status::ContractStatus;
 use rcd_http_common::url::data::{
-    GET_ROW_AT_PARTICIPANT, INSERT_ROW_AT_PARTICIPANT, NOTIFY_HOST_OF_REMOVED_ROW,
-    NOTIFY_HOST_OF_UPDATED_HASH, PARTICIPANT_ACCEPTS_CONTRACT, REMOVE_ROW_AT_PARTICIPANT,
-    SAVE_CONTRACT, TRY_AUTH, UPDATE_ROW_AT_PARTICIPANT,
+    GET_ROWS_AT_PARTICIPANT, GET_ROW_AT_PARTICIPANT, INSERT_ROW_AT_PARTICIPANT,
+    NOTIFY_HOST_OF_INSERTED_ROW, NOTIFY_HOST_OF_PENDING_ACTION, NOTIFY_HOST_OF_REMOVED_ROW,
+    NOTIFY_HOST_OF_UPDATED_HASH, PARTICIPANT_ACCEPTS_CONTRACT, PARTICIPANT_REJECTS_CONTRACT,
+    REMOVE_ROW_AT_PARTICIPANT, SAVE_CONTRACT, TRY_AUTH, UPDATE_ROW_AT_PARTICIPANT,
 };
 use rcdproto::rcdp::{
     AuthRequest, Contract, DatabaseSchema, DeleteDataRequest, DeleteDataResult,
-    GetRowFromPartialDatabaseRequest, GetRowFromPartialDatabaseResult, Host, InsertDataRequest,
-    InsertDataResult, MessageInfo, NotifyHostOfRemovedRowRequest, NotifyHostOfRemovedRowResponse,
-    Participant, ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult,
+    GetRowFromPartialDatabaseRequest, GetRowFromPartialDatabaseResult,
+    GetRowsFromPartialDatabaseRequest, GetRowsFromPartialDatabaseResult, Host, InsertDataRequest,
+    InsertDataResult, MessageInfo, NotifyHostOfInsertedRowRequest, NotifyHostOfInsertedRowResponse,
+    NotifyHostOfPendingActionRequest, NotifyHostOfPendingActionResponse,
+    NotifyHostOfRemovedRowRequest, NotifyHostOfRemovedRowResponse, Participant,
+    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult,
+    ParticipantRejectsContractRequest, ParticipantRejectsContractResult, RowInfo,
     RowParticipantAddress, SaveContractRequest, SaveContractResult, TryAuthRequest, TryAuthResult,
     UpdateDataRequest, UpdateDataResult, UpdateRowDataHashForHostRequest,
     UpdateRowDataHashForHostResponse,
@@ -115,6 +120,51 @@ impl RemoteHttp {
         }
     }
 
+    pub async fn notify_host_of_pending_action(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        pending_action_info: &PendingActionInfo,
+    ) -> bool {
+        let auth = get_auth_request(own_host_info);
+        let message_info = get_message_info(own_host_info, "".to_string());
+
+        let chost = Host {
+            host_guid: own_host_info.id.clone(),
+            host_name: own_host_info.name.clone(),
+            ip4_address: String::from(""),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            http_addr: "".to_string(),
+            http_port: 0,
+        };
+
+        let request = NotifyHostOfPendingActionRequest {
+            authentication: Some(auth),
+            message_info: Some(message_info),
+            host_info: Some(chost),
+            database_name: pending_action_info.db_name.clone(),
+            table_name: pending_action_info.table_name.clone(),
+            pending_action_id: pending_action_info.pending_action_id,
+            action: pending_action_info.action.clone(),
+            is_accepted: pending_action_info.is_accepted,
+            rows: get_row_infos(pending_action_info),
+        };
+
+        let request_json = serde_json::to_string(&request).unwrap();
+
+        let addr_port = format!("{}:{}", host.http_addr, host.http_port);
+
+        info!("sending request to rcd at: {}", addr_port);
+
+        let url = format!("http://{addr_port}{NOTIFY_HOST_OF_PENDING_ACTION}");
+        let result = send_message(request_json, url).await;
+        let reply: NotifyHostOfPendingActionResponse = serde_json::from_str(&result).unwrap();
+
+        reply.is_successful
+    }
+
     pub async fn get_row_from_participant(
         &self,
         participant: CoopDatabaseParticipantData,
@@ -152,6 +202,39 @@ impl RemoteHttp {
         reply
     }
 
+    pub async fn get_rows_from_participant(
+        &self,
+        participant: CoopDatabaseParticipantData,
+        own_host_info: HostInfo,
+        row_ids: Vec<u32>,
+    ) -> GetRowsFromPartialDatabaseResult {
+        let message_info = get_message_info(&own_host_info, "".to_string());
+        let auth = get_auth_request(&own_host_info);
+
+        let request = GetRowsFromPartialDatabaseRequest {
+            authentication: Some(auth),
+            database_name: participant.db_name.clone(),
+            table_name: participant.table_name.clone(),
+            row_ids,
+            message_info: Some(message_info),
+        };
+
+        let request_json = serde_json::to_string(&request).unwrap();
+
+        let addr_port = format!(
+            "{}:{}",
+            participant.participant.http_addr, participant.participant.http_port
+        );
+
+        info!("sending request to rcd at: {}", addr_port);
+
+        let url = format!("http://{addr_port}{GET_ROWS_AT_PARTICIPANT}");
+        let result = send_message(request_json, url).await;
+        let reply: GetRowsFromPartialDatabaseResult = serde_json::from_str(&result).unwrap();
+
+        reply
+    }
+
     pub async fn insert_row_at_participant(
         &self,
         participant: CoopDatabaseParticipant,
@@ -218,10 +301,11 @@ impl RemoteHttp {
         &self,
         participant: CoopDatabaseParticipant,
         own_host_info: &HostInfo,
-    ) -> bool {
+    ) -> TryAuthResult {
         let auth = get_auth_request(own_host_info);
         let request = TryAuthRequest {
             authentication: Some(auth),
+            protocol_version: rcdproto::PROTOCOL_VERSION,
         };
 
         let request_json = serde_json::to_string(&request).unwrap();
@@ -234,7 +318,7 @@ impl RemoteHttp {
         let result = send_message(request_json, url).await;
         let reply: TryAuthResult = serde_json::from_str(&result).unwrap();
 
-        reply.authentication_result.unwrap().is_authenticated
+        reply
     }
 
     pub async fn notify_host_of_removed_row(
@@ -283,6 +367,51 @@ impl RemoteHttp {
         reply.is_successful
     }
 
+    pub async fn notify_host_of_inserted_row(
+        &self,
+        host: &CdsHosts,
+        own_host_info: &HostInfo,
+        data_info: &DataInfo,
+    ) -> bool {
+        let auth = get_auth_request(own_host_info);
+        let message_info = get_message_info(own_host_info, "".to_string());
+
+        let chost = Host {
+            host_guid: own_host_info.id.clone(),
+            host_name: own_host_info.name.clone(),
+            ip4_address: String::from(""),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            http_addr: "".to_string(),
+            http_port: 0,
+        };
+
+        let request = NotifyHostOfInsertedRowRequest {
+            authentication: Some(auth),
+            message_info: Some(message_info),
+            host_info: Some(chost),
+            database_name: data_info.db_name.to_string(),
+            database_id: String::from(""),
+            table_name: data_info.table_name.to_string(),
+            table_id: 0,
+            row_id: data_info.row_id,
+            hash: data_info.hash.unwrap_or(0),
+        };
+
+        let request_json = serde_json::to_string(&request).unwrap();
+
+        let addr_port = format!("{}:{}", host.http_addr, host.http_port);
+
+        info!("sending request to rcd at: {}", addr_port);
+
+        let url = format!("http://{addr_port}{NOTIFY_HOST_OF_INSERTED_ROW}");
+        let result = send_message(request_json, url).await;
+        let reply: NotifyHostOfInsertedRowResponse = serde_json::from_str(&result).unwrap();
+
+        reply.is_successful
+    }
+
     pub async fn remove_row_at_participant(
         &self,
         participant: CoopDatabaseParticipant,
@@ -362,6 +491,56 @@ impl RemoteHttp {
         reply.contract_acceptance_is_acknowledged
     }
 
+    pub async fn notify_host_of_rejection_of_contract(
+        &self,
+        rejected_contract: &Contract,
+        own_host_info: &HostInfo,
+    ) -> Result<bool, String> {
+        let message_info = get_message_info(own_host_info, "".to_string());
+        let host_info = rejected_contract.host_info.as_ref().unwrap().clone();
+
+        let participant = Participant {
+            participant_guid: own_host_info.id.clone(),
+            alias: own_host_info.name.clone(),
+            ip4_address: self.own_http_addr.clone(),
+            ip6_address: String::from(""),
+            database_port_number: 0,
+            token: own_host_info.token.clone(),
+            internal_participant_guid: "".to_string(),
+            http_addr: self.own_http_addr.clone(),
+            http_port: self.own_http_port,
+        };
+
+        let request = ParticipantRejectsContractRequest {
+            participant: Some(participant),
+            contract_guid: rejected_contract.contract_guid.clone(),
+            contract_version_guid: rejected_contract.contract_version.clone(),
+            database_name: rejected_contract
+                .schema
+                .as_ref()
+                .unwrap()
+                .database_name
+                .clone(),
+            message_info: Some(message_info),
+            id: Some(host_info.host_guid.clone()),
+        };
+
+        let request_json = serde_json::to_string(&request).unwrap();
+
+        let addr_port = format!("{}:{}", host_info.http_addr, host_info.http_port);
+
+        info!("sending request to rcd at: {}", addr_port);
+
+        let url = format!("http://{addr_port}{PARTICIPANT_REJECTS_CONTRACT}");
+        let result = try_send_message(request_json, url)
+            .await
+            .map_err(|e| format!("unable to reach host at {addr_port}: {e}"))?;
+        let reply: ParticipantRejectsContractResult = serde_json::from_str(&result)
+            .map_err(|e| format!("unexpected reply from host at {addr_port}: {e}"))?;
+
+        Ok(reply.contract_rejection_is_acknowledged)
+    }
+
     pub async fn send_participant_contract(
         &self,
         participant: CoopDatabaseParticipant,
@@ -435,6 +614,19 @@ fn get_message_info(host_info: &HostInfo, own_db_addr_port: String) -> MessageIn
     }
 }
 
+fn get_row_infos(pending_action_info: &PendingActionInfo) -> Vec<RowInfo> {
+    pending_action_info
+        .rows
+        .iter()
+        .map(|row| RowInfo {
+            database_name: pending_action_info.db_name.clone(),
+            table_name: pending_action_info.table_name.clone(),
+            rowid: row.row_id,
+            data_hash: row.data_hash.unwrap_or_default(),
+        })
+        .collect()
+}
+
 fn is_little_endian() -> bool {
     let v = vec![0, 128, 128, 0];
 
@@ -445,21 +637,24 @@ fn is_little_endian() -> bool {
 }
 
 async fn send_message(json_message: String, url: String) -> String {
+    try_send_message(json_message, url).await.unwrap()
+}
+
+/// Posts the message, returning the error if the rcd at the url can't be reached
+async fn try_send_message(json_message: String, url: String) -> Result<String, reqwest::Error> {
     let client = reqwest::Client::new();
 
     trace!("{json_message}");
     trace!("{url}");
 
-    return client
+    client
         .post(url)
         .header("Content-Type", "application/json")
         .body(json_message)
         .send()
-        .await
-        .unwrap()
+        .await?
         .text()
         .await
-        .unwrap();
 }
 
 fn get_auth_request(own_host_info: &HostInfo) -> AuthRequest {
@@ -31,6 +31,36 @@ impl ColumnType {
         }
     }
 
+    pub fn data_type_as_string_postgres(&self) -> String {
+        match self {
+            ColumnType::Unknown => panic!(),
+            ColumnType::Int => String::from("INT"),
+            ColumnType::Bit => String::from("BOOLEAN"),
+            ColumnType::Char => String::from("CHAR"),
+            ColumnType::DateTime => String::from("TIMESTAMP"),
+            ColumnType::Decimal => String::from("DECIMAL"),
+            ColumnType::Varchar => String::from("VARCHAR"),
+            ColumnType::Binary => String::from("BYTEA"),
+            ColumnType::Varbinary => String::from("BYTEA"),
+            ColumnType::Text => String::from("TEXT"),
+        }
+    }
+
+    pub fn data_type_as_string_mysql(&self) -> String {
+        match self {
+            ColumnType::Unknown => panic!(),
+            ColumnType::Int => String::from("INT"),
+            ColumnType::Bit => String::from("BIT"),
+            ColumnType::Char => String::from("CHAR"),
+            ColumnType::DateTime => String::from("DATETIME"),
+            ColumnType::Decimal => String::from("DECIMAL"),
+            ColumnType::Varchar => String::from("VARCHAR"),
+            ColumnType::Binary => String::from("BINARY"),
+            ColumnType::Varbinary => String::from("VARBINARY"),
+            ColumnType::Text => String::from("TEXT"),
+        }
+    }
+
     pub fn data_type_to_enum_u32(desc: String) -> u32 {
         trace!("{desc:?}");
         let ct = ColumnType::try_parse(&desc).unwrap();
@@ -0,0 +1,46 @@
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+/// Represents the formats a database can be exported in
+/// # Formats
+/// - 0 - Unknown
+/// - 1 - Sqlite: a raw copy of the database file
+/// - 2 - Json: newline delimited JSON, one file per table
+/// - 3 - Csv: one file per table
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    Unknown = 0,
+    Sqlite = 1,
+    Json = 2,
+    Csv = 3,
+}
+
+impl ExportFormat {
+    pub fn from_u32(value: u32) -> ExportFormat {
+        match value {
+            1 => ExportFormat::Sqlite,
+            2 => ExportFormat::Json,
+            3 => ExportFormat::Csv,
+            _ => ExportFormat::Unknown,
+        }
+    }
+
+    pub fn to_u32(value: ExportFormat) -> u32 {
+        match value {
+            ExportFormat::Unknown => 0,
+            ExportFormat::Sqlite => 1,
+            ExportFormat::Json => 2,
+            ExportFormat::Csv => 3,
+        }
+    }
+
+    /// The extension of the files exported in this format
+    pub fn file_extension(value: ExportFormat) -> String {
+        match value {
+            ExportFormat::Unknown => "".to_string(),
+            ExportFormat::Sqlite => "db".to_string(),
+            ExportFormat::Json => "ndjson".to_string(),
+            ExportFormat::Csv => "csv".to_string(),
+        }
+    }
+}
@@ -4,6 +4,7 @@ pub mod database_type;
 pub mod deletes_from_host_behavior;
 pub mod deletes_to_host_behavior;
 pub mod dml_type;
+pub mod export_format;
 pub mod host_status;
 pub mod logical_storage_policy;
 pub mod partial_data_result_action;
@@ -11,5 +12,6 @@ pub mod partial_data_status;
 pub mod rcd_database_type;
 pub mod rcd_generate_contract_error;
 pub mod remote_delete_behavior;
+pub mod tenant_status;
 pub mod updates_from_host_behavior;
 pub mod updates_to_host_behavior;
@@ -4,12 +4,12 @@
 /// * 1 - HostOnly - Data is only kept at the host.
 /// * 2 - ParticpantOwned - Data is kept at the participant. Hashes of the data are kept at the host. If the participant
 /// changes the data, the hash will no longer match unless the host has configured the table to accept changes.
-/// * 3 - Shared - Data is at the host, and changes are automatically pushed to the participant. If data is deleted at the host,
-/// it is not automatically deleted at the participant but rather a record marker showing it's been deleted is sent to the
-/// participant, which the participant can act on or ignore (note: the marker will still exist.) This is a 'soft' delete
-/// at the participant.
-/// * 4 - Mirror - This is basically SQL replication - whatever changes happen at the host will automatically be replicated
-/// at the participant. Deletes are permanent.
+/// * 3 - Shared - Each row is kept at the host, except for the columns named as owned by the participant with the policy,
+/// whose values are only kept at the participant. Reads at the host fill those columns in from the participant, matching
+/// rows on the table's primary key.
+/// * 4 - Mirror - A full copy of the data is kept at both the host and the participant. Writes from the host are made to
+/// both copies, reads at the host are served from its own copy, and the hashes of the two copies are compared to catch
+/// any drift between them.
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum LogicalStoragePolicy {
     None = 0,
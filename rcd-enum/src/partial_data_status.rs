@@ -1,5 +1,6 @@
 /// Specifies the UpdateStatus in a UpdateDataResult message
-/// in rcdp.proto
+/// in rcdp.proto, and the WriteStatus reported back for each participant
+/// in an ExecuteCooperativeWriteReply
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PartialDataStatus {
     Unknown = 0,
@@ -1,12 +1,13 @@
 /// Determines how a host will respond to a particpant's delete action.
 /// # Types
-/// * 0 - Unknown
-/// * 1 - Ignore - If the host discovers that the particpant has deleted the row, it will take no action.
-/// * 2 - AutoDelete - If the host discovers that the participant has deleted the row, it will update the reference row with
-/// the delete data then logically delete the row.
-/// * 3 - UpdateStatusOnly - If the host discovers that the particpant has deleted the row then update the reference row
-/// with the delete data but do not perform a logical delete on the row (Note: The row can still be manually deleted
-/// on the host side at a later time.)
+/// * 0 - Unknown - Treated the same as AutoDelete.
+/// * 1 - Ignore - If the host is notified that the particpant has deleted the row, it will take no action and keeps
+/// its reference to the row as is.
+/// * 2 - AutoDelete - If the host is notified that the participant has deleted the row, it will remove its reference
+/// to the row.
+/// * 3 - UpdateStatusOnly - If the host is notified that the particpant has deleted the row, it keeps its reference to
+/// the row as a tombstone flagged as remotely deleted, and reads at the host report the row back with `is_remote_deleted`
+/// set on its remote metadata (Note: The row can still be manually deleted on the host side at a later time.)
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum RemoteDeleteBehavior {
     Unknown = 0,
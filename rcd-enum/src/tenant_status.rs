@@ -0,0 +1,53 @@
+/// Where an account hosted by a rcd-proxy is in its lifecycle. Only `Active` accounts can
+/// authenticate; a `PendingDelete` account is removed once its grace period has passed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TenantStatus {
+    Unknown = 0,
+    Active = 1,
+    Suspended = 2,
+    PendingDelete = 3,
+}
+
+impl TenantStatus {
+    pub fn from_u32(value: u32) -> TenantStatus {
+        match value {
+            0 => TenantStatus::Unknown,
+            1 => TenantStatus::Active,
+            2 => TenantStatus::Suspended,
+            3 => TenantStatus::PendingDelete,
+            _ => panic!("Unknown value: {value}"),
+        }
+    }
+
+    pub fn to_u32(value: TenantStatus) -> u32 {
+        match value {
+            TenantStatus::Unknown => 0,
+            TenantStatus::Active => 1,
+            TenantStatus::Suspended => 2,
+            TenantStatus::PendingDelete => 3,
+        }
+    }
+
+    pub fn to_string(value: TenantStatus) -> String {
+        match value {
+            TenantStatus::Unknown => "Unknown".to_string(),
+            TenantStatus::Active => "Active".to_string(),
+            TenantStatus::Suspended => "Suspended".to_string(),
+            TenantStatus::PendingDelete => "PendingDelete".to_string(),
+        }
+    }
+
+    pub fn from_str(value: &str) -> TenantStatus {
+        match value {
+            "Unknown" => TenantStatus::Unknown,
+            "Active" => TenantStatus::Active,
+            "Suspended" => TenantStatus::Suspended,
+            "PendingDelete" => TenantStatus::PendingDelete,
+            _ => TenantStatus::Unknown,
+        }
+    }
+
+    pub fn as_string(self) -> String {
+        TenantStatus::to_string(self)
+    }
+}
@@ -10,6 +10,16 @@ pub enum RcdDbError {
     TableNotFoundInDatabase(String, String),
     #[error("storage policy not defined for table `{0}`")]
     LogicalStoragePolicyNotSet(String),
+    #[error("the database `{0}` is encrypted and no key has been supplied for it")]
+    DatabaseLocked(String),
+    #[error("the key supplied does not open the database `{0}`")]
+    InvalidDatabaseKey(String),
+    #[error("encrypting databases at rest is not supported by the `{0}` backend")]
+    EncryptionNotSupported(String),
+    #[error("exporting a raw copy of a database is not supported by the `{0}` backend")]
+    RawExportNotSupported(String),
+    #[error("`{0}` is not supported by the `{1}` backend")]
+    NotSupported(String, String),
 }
 
 impl From<rusqlite::Error> for RcdDbError {
@@ -17,3 +27,15 @@ impl From<rusqlite::Error> for RcdDbError {
         RcdDbError::General(error.to_string())
     }
 }
+
+impl From<postgres::Error> for RcdDbError {
+    fn from(error: postgres::Error) -> Self {
+        RcdDbError::General(error.to_string())
+    }
+}
+
+impl From<mysql::Error> for RcdDbError {
+    fn from(error: mysql::Error) -> Self {
+        RcdDbError::General(error.to_string())
+    }
+}
@@ -109,6 +109,20 @@ impl DataService for DataServiceImpl {
         Ok(Response::new(result))
     }
 
+    async fn get_rows_from_partial_database(
+        &self,
+        request: Request<GetRowsFromPartialDatabaseRequest>,
+    ) -> Result<Response<GetRowsFromPartialDatabaseResult>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+
+        let result = self
+            .core()
+            .get_rows_from_partial_database(request.into_inner())
+            .await;
+
+        Ok(Response::new(result))
+    }
+
     async fn save_contract(
         &self,
         request: Request<SaveContractRequest>,
@@ -130,6 +144,17 @@ impl DataService for DataServiceImpl {
         Ok(Response::new(result))
     }
 
+    async fn reject_contract(
+        &self,
+        request: Request<ParticipantRejectsContractRequest>,
+    ) -> Result<Response<ParticipantRejectsContractResult>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+
+        let result = self.core().reject_contract(request.into_inner()).await;
+
+        Ok(Response::new(result))
+    }
+
     async fn update_row_data_hash_for_host(
         &self,
         request: Request<UpdateRowDataHashForHostRequest>,
@@ -148,6 +173,23 @@ impl DataService for DataServiceImpl {
         Ok(Response::new(result))
     }
 
+    async fn notify_host_of_pending_action(
+        &self,
+        request: Request<NotifyHostOfPendingActionRequest>,
+    ) -> Result<Response<NotifyHostOfPendingActionResponse>, Status> {
+        debug!(
+            "notify_host_of_pending_action: Request from {:?}",
+            request.remote_addr()
+        );
+
+        let result = self
+            .core()
+            .notify_host_of_pending_action(request.into_inner())
+            .await;
+
+        Ok(Response::new(result))
+    }
+
     async fn notify_host_of_removed_row(
         &self,
         request: Request<NotifyHostOfRemovedRowRequest>,
@@ -165,6 +207,23 @@ impl DataService for DataServiceImpl {
         Ok(Response::new(result))
     }
 
+    async fn notify_host_of_inserted_row(
+        &self,
+        request: Request<NotifyHostOfInsertedRowRequest>,
+    ) -> Result<Response<NotifyHostOfInsertedRowResponse>, Status> {
+        debug!(
+            "notify_host_of_inserted_row: Request from {:?}",
+            request.remote_addr()
+        );
+
+        let result = self
+            .core()
+            .notify_host_of_inserted_row(request.into_inner())
+            .await;
+
+        Ok(Response::new(result))
+    }
+
     async fn try_auth(
         &self,
         request: Request<TryAuthRequest>,
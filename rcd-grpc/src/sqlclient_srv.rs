@@ -6,6 +6,8 @@ use rcdproto::rcdp::{
     CreateUserDatabaseReply, RejectPendingContractReply, RejectPendingContractRequest,
 };
 use rusqlite::Result;
+use std::pin::Pin;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
 use tonic::{transport::Server, Request, Response, Status};
 
 #[derive(Default, Debug)]
@@ -117,8 +119,8 @@ impl SqlClient for SqlClientImpl {
         request: Request<AuthRequest>,
     ) -> Result<Response<VersionReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        // need to write an HTTP version as well
-        todo!()
+        let response = self.core().get_versions(request.into_inner()).await;
+        Ok(Response::new(response))
     }
 
     async fn get_host_info(
@@ -187,6 +189,54 @@ impl SqlClient for SqlClientImpl {
         Ok(Response::new(result))
     }
 
+    async fn get_pending_writes_at_host(
+        &self,
+        request: Request<GetPendingWritesAtHostRequest>,
+    ) -> Result<Response<GetPendingWritesAtHostReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self
+            .core()
+            .get_pending_writes_at_host(request.into_inner())
+            .await;
+        Ok(Response::new(result))
+    }
+
+    async fn reject_pending_action_at_participant(
+        &self,
+        request: Request<RejectPendingActionRequest>,
+    ) -> Result<Response<RejectPendingActionReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self
+            .core()
+            .reject_pending_action_at_participant(request.into_inner())
+            .await;
+        Ok(Response::new(result))
+    }
+
+    async fn get_pending_action_ttl_at_participant(
+        &self,
+        request: Request<GetPendingActionTtlRequest>,
+    ) -> Result<Response<GetPendingActionTtlReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self
+            .core()
+            .get_pending_action_ttl_at_participant(request.into_inner())
+            .await;
+        Ok(Response::new(result))
+    }
+
+    async fn set_pending_action_ttl_at_participant(
+        &self,
+        request: Request<SetPendingActionTtlRequest>,
+    ) -> Result<Response<SetPendingActionTtlReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self
+            .core()
+            .set_pending_action_ttl_at_participant(request.into_inner())
+            .await;
+        Ok(Response::new(result))
+    }
+
     async fn get_pending_actions_at_participant(
         &self,
         request: Request<GetPendingActionsRequest>,
@@ -204,7 +254,11 @@ impl SqlClient for SqlClientImpl {
         request: Request<GetDataLogTableStatusRequest>,
     ) -> Result<Response<GetDataLogTableStatusReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        unimplemented!();
+        let status_reply = self
+            .core()
+            .get_data_log_table_status_at_participant(request.into_inner())
+            .await;
+        Ok(Response::new(status_reply))
     }
 
     async fn set_data_log_table_status_at_participant(
@@ -212,7 +266,11 @@ impl SqlClient for SqlClientImpl {
         request: Request<SetDataLogTableStatusRequest>,
     ) -> Result<Response<SetDataLogTableStatusReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        unimplemented!();
+        let status_reply = self
+            .core()
+            .set_data_log_table_status_at_participant(request.into_inner())
+            .await;
+        Ok(Response::new(status_reply))
     }
 
     async fn generate_host_info(
@@ -392,9 +450,14 @@ impl SqlClient for SqlClientImpl {
 
     async fn reject_pending_contract(
         &self,
-        _request: tonic::Request<RejectPendingContractRequest>,
-    ) -> Result<tonic::Response<RejectPendingContractReply>, tonic::Status> {
-        unimplemented!();
+        request: Request<RejectPendingContractRequest>,
+    ) -> Result<Response<RejectPendingContractReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let rejected_reply = self
+            .core()
+            .reject_pending_contract(request.into_inner())
+            .await;
+        Ok(Response::new(rejected_reply))
     }
 
     async fn change_host_status(
@@ -503,6 +566,39 @@ impl SqlClient for SqlClientImpl {
             .await;
         Ok(Response::new(result))
     }
+
+    async fn rehash_partial_database(
+        &self,
+        request: Request<RehashPartialDatabaseRequest>,
+    ) -> Result<Response<RehashPartialDatabaseReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self
+            .core()
+            .rehash_partial_database(request.into_inner())
+            .await;
+        Ok(Response::new(result))
+    }
+
+    async fn unlock_database(
+        &self,
+        request: Request<UnlockDatabaseRequest>,
+    ) -> Result<Response<UnlockDatabaseReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let result = self.core().unlock_database(request.into_inner()).await;
+        Ok(Response::new(result))
+    }
+
+    type ExportDatabaseStream =
+        Pin<Box<dyn Stream<Item = Result<ExportDatabaseReply, Status>> + Send>>;
+
+    async fn export_database(
+        &self,
+        request: Request<ExportDatabaseRequest>,
+    ) -> Result<Response<Self::ExportDatabaseStream>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let replies = self.core().export_database(request.into_inner()).await;
+        Ok(Response::new(Box::pin(ReceiverStream::new(replies).map(Ok))))
+    }
 }
 
 #[tokio::main]
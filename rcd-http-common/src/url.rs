@@ -14,6 +14,9 @@ pub mod client {
     pub const GET_ROW_AT_PARTICIPANT: &str = "/client/databases/participant/io/get";
     pub const GET_DATA_HASH_AT_PARTICIPANT: &str = "/client/databases/participant/io/get-hash";
     pub const GET_DATA_HASH_AT_HOST: &str = "/client/databases/host/io/get-hash";
+    pub const REHASH_PARTIAL_DATABASE: &str = "/client/databases/participant/io/rehash";
+    pub const UNLOCK_DATABASE: &str = "/client/databases/unlock";
+    pub const EXPORT_DATABASE: &str = "/client/databases/export";
     pub const IS_ONLINE: &str = "/client/version";
     pub const AUTH_FOR_TOKEN: &str = "/client/token";
     pub const REVOKE_TOKEN: &str = "/client/token-revoke";
@@ -24,10 +27,16 @@ pub mod client {
     pub const GENERATE_HOST_INFO: &str = "/client/host/generate";
     pub const GET_COOP_HOSTS: &str = "/client/host/get-coop-hosts";
     pub const GET_HOST_INFO: &str = "/client/host/get";
+    pub const GET_VERSIONS: &str = "/client/host/versions";
     pub const VIEW_PENDING_CONTRACTS: &str = "/client/contract/review";
     pub const ACCEPT_PENDING_CONTRACT: &str = "/client/contract/accept/";
+    pub const REJECT_PENDING_CONTRACT: &str = "/client/contract/reject/";
     pub const ACCEPT_PENDING_ACTION: &str = "/client/databases/actions/accept-pending";
     pub const GET_PENDING_ACTIONS: &str = "/client/databases/actions/get-pending";
+    pub const GET_PENDING_WRITES_AT_HOST: &str = "/client/databases/actions/get-pending-writes";
+    pub const REJECT_PENDING_ACTION: &str = "/client/databases/actions/reject-pending";
+    pub const GET_PENDING_ACTION_TTL: &str = "/client/databases/actions/get-ttl";
+    pub const SET_PENDING_ACTION_TTL: &str = "/client/databases/actions/set-ttl";
     pub const CHANGE_DELETES_TO_HOST_BEHAVIOR: &str =
         "/client/databases/behavior/change-deletes-to-host";
     pub const GET_DELETES_TO_HOST_BEHAVIOR: &str = "/client/databases/behavior/get-deletes-to-host";
@@ -42,6 +51,8 @@ pub mod client {
         "/client/databases/behavior/change-updates-from-host";
     pub const GET_UPDATES_FROM_HOST_BEHAVIOR: &str =
         "/client/databases/behavior/get-updates-from-host";
+    pub const GET_DATA_LOG_TABLE_STATUS: &str = "/client/databases/data-log/get-status";
+    pub const SET_DATA_LOG_TABLE_STATUS: &str = "/client/databases/data-log/set-status";
     pub const CHANGE_HOST_STATUS_ID: &str = "/client/change-host-status-id";
     pub const CHANGE_HOST_STATUS_NAME: &str = "/client/change-host-status-name";
     pub const GET_DATABASES: &str = "/client/databases";
@@ -54,11 +65,15 @@ pub mod client {
 pub mod data {
     pub const SAVE_CONTRACT: &str = "/data/contract/save/";
     pub const PARTICIPANT_ACCEPTS_CONTRACT: &str = "/data/contract/accepted-by-participant";
+    pub const PARTICIPANT_REJECTS_CONTRACT: &str = "/data/contract/rejected-by-participant";
     pub const REMOVE_ROW_AT_PARTICIPANT: &str = "/data/io/remove-row";
     pub const UPDATE_ROW_AT_PARTICIPANT: &str = "/data/io/update-row";
     pub const INSERT_ROW_AT_PARTICIPANT: &str = "/data/io/insert-row";
     pub const GET_ROW_AT_PARTICIPANT: &str = "/data/io/get-row";
+    pub const GET_ROWS_AT_PARTICIPANT: &str = "/data/io/get-rows";
     pub const NOTIFY_HOST_OF_REMOVED_ROW: &str = "/data/io/notify-host-removed-row";
+    pub const NOTIFY_HOST_OF_INSERTED_ROW: &str = "/data/io/notify-host-inserted-row";
     pub const NOTIFY_HOST_OF_UPDATED_HASH: &str = "/data/io/notify-host-updated-hash";
+    pub const NOTIFY_HOST_OF_PENDING_ACTION: &str = "/data/io/notify-host-pending-action";
     pub const TRY_AUTH: &str = "/data/try-auth";
 }
@@ -126,6 +126,7 @@ pub async fn start() -> Result<(), rocket::Error> {
                 shutdown,
                 client::host::generate_host_info,
                 client::host::get_host_info,
+                client::host::get_versions,
                 client::host::get_cooperative_hosts,
                 client::change_host_status_id,
                 client::change_host_status_name,
@@ -143,6 +144,9 @@ pub async fn start() -> Result<(), rocket::Error> {
                 client::database::get_row_id_at_participant,
                 client::database::get_data_hash_at_participant,
                 client::database::get_data_hash_at_host,
+                client::database::rehash_partial_database,
+                client::database::unlock_database,
+                client::database::export_database,
                 client::database::participant::add_participant,
                 client::database::participant::send_contract_to_participant,
                 client::database::participant::get_participants,
@@ -150,6 +154,10 @@ pub async fn start() -> Result<(), rocket::Error> {
                 client::database::enable_coooperative_features,
                 client::database::actions::accept_pending_action_at_participant,
                 client::database::actions::get_pending_actions_at_participant,
+                client::database::actions::get_pending_writes_at_host,
+                client::database::actions::reject_pending_action_at_participant,
+                client::database::actions::get_pending_action_ttl_at_participant,
+                client::database::actions::set_pending_action_ttl_at_participant,
                 client::database::behavior::change_deletes_to_host_behavior,
                 client::database::behavior::change_updates_to_host_behavior,
                 client::database::behavior::change_deletes_from_host_behavior,
@@ -158,6 +166,8 @@ pub async fn start() -> Result<(), rocket::Error> {
                 client::database::behavior::get_updates_to_host_behavior,
                 client::database::behavior::get_deletes_from_host_behavior,
                 client::database::behavior::get_updates_from_host_behavior,
+                client::database::behavior::get_data_log_table_status,
+                client::database::behavior::set_data_log_table_status,
                 client::sql::read_at_host,
                 client::sql::write_at_host,
                 client::sql::cooperative_write_at_host,
@@ -165,17 +175,22 @@ pub async fn start() -> Result<(), rocket::Error> {
                 client::sql::read_at_participant,
                 client::contract::review_pending_contracts,
                 client::contract::accept_pending_contract,
+                client::contract::reject_pending_contract,
                 data::status,
                 data::version,
                 data::try_auth,
                 data::contract::save_contract,
                 data::contract::participant_accepts_contract,
+                data::contract::participant_rejects_contract,
                 data::io::remove_row_at_participant,
                 data::io::notify_host_of_removed_row,
+                data::io::notify_host_of_inserted_row,
                 data::io::update_row_at_participant,
                 data::io::insert_row_at_participant,
                 data::io::get_row_at_participant,
+                data::io::get_rows_at_participant,
                 data::io::notify_host_of_updated_hash,
+                data::io::notify_host_of_pending_action,
             ],
         )
         .manage(core)
@@ -1,6 +1,6 @@
 use rcdproto::rcdp::{
-    AcceptPendingContractReply, AcceptPendingContractRequest, ViewPendingContractsReply,
-    ViewPendingContractsRequest,
+    AcceptPendingContractReply, AcceptPendingContractRequest, RejectPendingContractReply,
+    RejectPendingContractRequest, ViewPendingContractsReply, ViewPendingContractsRequest,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
 
@@ -35,3 +35,18 @@ pub async fn accept_pending_contract(
 
     (Status::Ok, Json(result))
 }
+
+#[post(
+    "/client/contract/reject",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn reject_pending_contract(
+    request: Json<RejectPendingContractRequest>,
+    state: &State<Core>,
+) -> (Status, Json<RejectPendingContractReply>) {
+    let core = state.get_core();
+    let result = core.reject_pending_contract(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
@@ -1,12 +1,20 @@
 use rcdproto::rcdp::{
     CreateUserDatabaseReply, CreateUserDatabaseRequest, EnableCoooperativeFeaturesReply,
-    EnableCoooperativeFeaturesRequest, GenerateContractReply, GenerateContractRequest,
-    GetActiveContractReply, GetActiveContractRequest, GetDataHashReply, GetDataHashRequest,
-    GetDatabasesReply, GetDatabasesRequest, GetLogicalStoragePolicyReply,
+    EnableCoooperativeFeaturesRequest, ExportDatabaseRequest, GenerateContractReply,
+    GenerateContractRequest, GetActiveContractReply, GetActiveContractRequest, GetDataHashReply,
+    GetDataHashRequest, GetDatabasesReply, GetDatabasesRequest, GetLogicalStoragePolicyReply,
     GetLogicalStoragePolicyRequest, GetReadRowIdsReply, GetReadRowIdsRequest, HasTableReply,
-    HasTableRequest, SetLogicalStoragePolicyReply, SetLogicalStoragePolicyRequest,
+    HasTableRequest, RehashPartialDatabaseReply, RehashPartialDatabaseRequest,
+    SetLogicalStoragePolicyReply, SetLogicalStoragePolicyRequest, UnlockDatabaseReply,
+    UnlockDatabaseRequest,
+};
+use rocket::{
+    http::Status,
+    post,
+    response::stream::TextStream,
+    serde::json::{self, Json},
+    State,
 };
-use rocket::{http::Status, post, serde::json::Json, State};
 
 use crate::http_srv::Core;
 
@@ -157,6 +165,56 @@ pub async fn get_data_hash_at_participant(
     (Status::Ok, Json(result))
 }
 
+#[post(
+    "/client/databases/participant/io/rehash",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn rehash_partial_database(
+    request: Json<RehashPartialDatabaseRequest>,
+    state: &State<Core>,
+) -> (Status, Json<RehashPartialDatabaseReply>) {
+    let core = state.get_core();
+    let result = core.rehash_partial_database(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
+
+#[post(
+    "/client/databases/unlock",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn unlock_database(
+    request: Json<UnlockDatabaseRequest>,
+    state: &State<Core>,
+) -> (Status, Json<UnlockDatabaseReply>) {
+    let core = state.get_core();
+    let result = core.unlock_database(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
+
+/// Streams the replies back as they are read, one JSON object per line
+#[post(
+    "/client/databases/export",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn export_database(
+    request: Json<ExportDatabaseRequest>,
+    state: &State<Core>,
+) -> TextStream![String] {
+    let core = state.get_core();
+    let mut replies = core.export_database(request.into_inner()).await;
+
+    TextStream! {
+        while let Some(reply) = replies.recv().await {
+            yield json::to_string(&reply).unwrap() + "\n";
+        }
+    }
+}
+
 #[post(
     "/client/databases/host/io/get-hash",
     format = "application/json",
@@ -1,6 +1,8 @@
 use rcdproto::rcdp::{
-    AcceptPendingActionReply, AcceptPendingActionRequest, GetPendingActionsReply,
-    GetPendingActionsRequest,
+    AcceptPendingActionReply, AcceptPendingActionRequest, GetPendingActionTtlReply,
+    GetPendingActionTtlRequest, GetPendingActionsReply, GetPendingActionsRequest,
+    GetPendingWritesAtHostReply, GetPendingWritesAtHostRequest, RejectPendingActionReply,
+    RejectPendingActionRequest, SetPendingActionTtlReply, SetPendingActionTtlRequest,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
 
@@ -39,3 +41,71 @@ pub async fn get_pending_actions_at_participant(
 
     (Status::Ok, Json(result))
 }
+
+#[post(
+    "/client/databases/actions/get-pending-writes",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn get_pending_writes_at_host(
+    request: Json<GetPendingWritesAtHostRequest>,
+    state: &State<Core>,
+) -> (Status, Json<GetPendingWritesAtHostReply>) {
+    let result = state
+        .get_core()
+        .get_pending_writes_at_host(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
+
+#[post(
+    "/client/databases/actions/reject-pending",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn reject_pending_action_at_participant(
+    request: Json<RejectPendingActionRequest>,
+    state: &State<Core>,
+) -> (Status, Json<RejectPendingActionReply>) {
+    let result = state
+        .get_core()
+        .reject_pending_action_at_participant(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
+
+#[post(
+    "/client/databases/actions/get-ttl",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn get_pending_action_ttl_at_participant(
+    request: Json<GetPendingActionTtlRequest>,
+    state: &State<Core>,
+) -> (Status, Json<GetPendingActionTtlReply>) {
+    let result = state
+        .get_core()
+        .get_pending_action_ttl_at_participant(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
+
+#[post(
+    "/client/databases/actions/set-ttl",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn set_pending_action_ttl_at_participant(
+    request: Json<SetPendingActionTtlRequest>,
+    state: &State<Core>,
+) -> (Status, Json<SetPendingActionTtlReply>) {
+    let result = state
+        .get_core()
+        .set_pending_action_ttl_at_participant(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
@@ -4,10 +4,11 @@ use rcdproto::rcdp::{
     ChangeDeletesToHostBehaviorReply, ChangeDeletesToHostBehaviorRequest,
     ChangeUpdatesFromHostBehaviorRequest, ChangeUpdatesToHostBehaviorReply,
     ChangeUpdatesToHostBehaviorRequest, ChangesUpdatesFromHostBehaviorReply,
-    GetDeletesFromHostBehaviorReply, GetDeletesFromHostBehaviorRequest,
-    GetDeletesToHostBehaviorReply, GetDeletesToHostBehaviorRequest,
-    GetUpdatesFromHostBehaviorReply, GetUpdatesFromHostBehaviorRequest,
-    GetUpdatesToHostBehaviorReply, GetUpdatesToHostBehaviorRequest,
+    GetDataLogTableStatusReply, GetDataLogTableStatusRequest, GetDeletesFromHostBehaviorReply,
+    GetDeletesFromHostBehaviorRequest, GetDeletesToHostBehaviorReply,
+    GetDeletesToHostBehaviorRequest, GetUpdatesFromHostBehaviorReply,
+    GetUpdatesFromHostBehaviorRequest, GetUpdatesToHostBehaviorReply,
+    GetUpdatesToHostBehaviorRequest, SetDataLogTableStatusReply, SetDataLogTableStatusRequest,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
 
@@ -146,3 +147,37 @@ pub async fn get_deletes_to_host_behavior(
 
     (Status::Ok, Json(result))
 }
+
+#[post(
+    "/client/databases/data-log/get-status",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn get_data_log_table_status(
+    request: Json<GetDataLogTableStatusRequest>,
+    state: &State<Core>,
+) -> (Status, Json<GetDataLogTableStatusReply>) {
+    let result = state
+        .get_core()
+        .get_data_log_table_status_at_participant(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
+
+#[post(
+    "/client/databases/data-log/set-status",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn set_data_log_table_status(
+    request: Json<SetDataLogTableStatusRequest>,
+    state: &State<Core>,
+) -> (Status, Json<SetDataLogTableStatusReply>) {
+    let result = state
+        .get_core()
+        .set_data_log_table_status_at_participant(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
@@ -1,6 +1,6 @@
 use rcdproto::rcdp::{
     AuthRequest, GenerateHostInfoReply, GenerateHostInfoRequest, GetCooperativeHostsReply,
-    GetCooperativeHostsRequest, HostInfoReply,
+    GetCooperativeHostsRequest, HostInfoReply, VersionReply,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
 
@@ -33,6 +33,20 @@ pub async fn get_host_info(
     (Status::Ok, Json(result))
 }
 
+#[post(
+    "/client/host/versions",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn get_versions(
+    request: Json<AuthRequest>,
+    state: &State<Core>,
+) -> (Status, Json<VersionReply>) {
+    let result = state.get_core().get_versions(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
+
 #[post(
     "/client/host/get-coop-hosts",
     format = "application/json",
@@ -1,5 +1,6 @@
 use rcdproto::rcdp::{
-    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult, SaveContractRequest,
+    ParticipantAcceptsContractRequest, ParticipantAcceptsContractResult,
+    ParticipantRejectsContractRequest, ParticipantRejectsContractResult, SaveContractRequest,
     SaveContractResult,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
@@ -31,3 +32,18 @@ pub async fn participant_accepts_contract(
 
     (Status::Ok, Json(result))
 }
+
+#[post(
+    "/data/contract/rejected-by-participant",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn participant_rejects_contract(
+    request: Json<ParticipantRejectsContractRequest>,
+    state: &State<Core>,
+) -> (Status, Json<ParticipantRejectsContractResult>) {
+    let core = state.get_data();
+    let result = core.reject_contract(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
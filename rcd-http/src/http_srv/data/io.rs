@@ -1,6 +1,9 @@
 use rcdproto::rcdp::{
     DeleteDataRequest, DeleteDataResult, GetRowFromPartialDatabaseRequest,
-    GetRowFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    GetRowFromPartialDatabaseResult, GetRowsFromPartialDatabaseRequest,
+    GetRowsFromPartialDatabaseResult, InsertDataRequest, InsertDataResult,
+    NotifyHostOfInsertedRowRequest, NotifyHostOfInsertedRowResponse,
+    NotifyHostOfPendingActionRequest, NotifyHostOfPendingActionResponse,
     NotifyHostOfRemovedRowRequest, NotifyHostOfRemovedRowResponse, UpdateDataRequest,
     UpdateDataResult, UpdateRowDataHashForHostRequest, UpdateRowDataHashForHostResponse,
 };
@@ -34,6 +37,21 @@ pub async fn notify_host_of_removed_row(
     (Status::Ok, Json(result))
 }
 
+#[post(
+    "/data/io/notify-host-inserted-row",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn notify_host_of_inserted_row(
+    request: Json<NotifyHostOfInsertedRowRequest>,
+    state: &State<Core>,
+) -> (Status, Json<NotifyHostOfInsertedRowResponse>) {
+    let core = state.get_data();
+    let result = core.notify_host_of_inserted_row(request.into_inner()).await;
+
+    (Status::Ok, Json(result))
+}
+
 #[post("/data/io/update-row", format = "application/json", data = "<request>")]
 pub async fn update_row_at_participant(
     request: Json<UpdateDataRequest>,
@@ -69,6 +87,19 @@ pub async fn get_row_at_participant(
     (Status::Ok, Json(result))
 }
 
+#[post("/data/io/get-rows", format = "application/json", data = "<request>")]
+pub async fn get_rows_at_participant(
+    request: Json<GetRowsFromPartialDatabaseRequest>,
+    state: &State<Core>,
+) -> (Status, Json<GetRowsFromPartialDatabaseResult>) {
+    let core = state.get_data();
+    let result = core
+        .get_rows_from_partial_database(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
+
 #[post(
     "/data/io/notify-host-updated-hash",
     format = "application/json",
@@ -85,3 +116,20 @@ pub async fn notify_host_of_updated_hash(
 
     (Status::Ok, Json(result))
 }
+
+#[post(
+    "/data/io/notify-host-pending-action",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn notify_host_of_pending_action(
+    request: Json<NotifyHostOfPendingActionRequest>,
+    state: &State<Core>,
+) -> (Status, Json<NotifyHostOfPendingActionResponse>) {
+    let core = state.get_data();
+    let result = core
+        .notify_host_of_pending_action(request.into_inner())
+        .await;
+
+    (Status::Ok, Json(result))
+}
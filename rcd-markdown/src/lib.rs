@@ -1 +1,2 @@
 pub mod markdown_kv_table;
+pub mod markdown_table;
@@ -0,0 +1,48 @@
+/// takes a list of column headers and rows of values and returns a markdown table with one
+/// column per header. rows shorter than the header list are padded with empty cells
+pub fn build_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut max_lengths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+
+    for row in rows {
+        for (i, value) in row.iter().enumerate().take(headers.len()) {
+            if value.len() > max_lengths[i] {
+                max_lengths[i] = value.len();
+            }
+        }
+    }
+
+    let mut markdown_table = String::new();
+
+    let header_values: Vec<String> = headers.iter().map(|h| h.to_string()).collect();
+    markdown_table += &build_markdown_row(&header_values, &max_lengths);
+    markdown_table += "\n";
+
+    for length in &max_lengths {
+        markdown_table += "|";
+        markdown_table += format!(" {:-<width$} ", "", width = length).as_str();
+    }
+
+    markdown_table += "|";
+    markdown_table += "\n";
+
+    for row in rows {
+        markdown_table += &build_markdown_row(row, &max_lengths);
+        markdown_table += "\n";
+    }
+
+    markdown_table
+}
+
+fn build_markdown_row(values: &[String], max_lengths: &[usize]) -> String {
+    let mut string_row = String::new();
+
+    for (i, max_length) in max_lengths.iter().enumerate() {
+        let value = values.get(i).map(|v| v.as_str()).unwrap_or("");
+        string_row += "| ";
+        string_row += format!("{:<width$} ", value, width = max_length).as_str();
+    }
+
+    string_row += "|";
+
+    string_row
+}
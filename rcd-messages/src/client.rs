@@ -90,8 +90,10 @@ pub struct GetUpdatesFromHostBehaviorReply {
 pub struct VersionReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     pub rcdx: String,
-    /// ... so on
     pub rcd_core: String,
+    pub rcdproto: String,
+    pub protocol_version: u32,
+    pub rcd_schema_version: u32,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -302,6 +304,8 @@ pub struct TryAuthAtPartipantReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     pub is_successful: bool,
     pub message: String,
+    pub protocol_version: u32,
+    pub error: Option<RcdError>,
 }
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct ChangeHostStatusRequest {
@@ -419,6 +423,7 @@ pub struct SetLogicalStoragePolicyRequest {
     pub database_name: String,
     pub table_name: String,
     pub policy_mode: u32,
+    pub participant_owned_columns: Vec<String>,
 }
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct SetLogicalStoragePolicyReply {
@@ -436,6 +441,7 @@ pub struct GetLogicalStoragePolicyRequest {
 pub struct GetLogicalStoragePolicyReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     pub policy_mode: u32,
+    pub participant_owned_columns: Vec<String>,
 }
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct ExecuteCooperativeWriteRequest {
@@ -452,6 +458,12 @@ pub struct ExecuteCooperativeWriteReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     pub is_successful: bool,
     pub total_rows_affected: u32,
+    pub participant_statuses: Vec<ParticipantWriteStatus>,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct ParticipantWriteStatus {
+    pub participant_alias: String,
+    pub write_status: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct AddParticipantRequest {
@@ -516,10 +528,13 @@ pub struct EnableCoooperativeFeaturesReply {
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct TryAuthRequest {
     pub authentication: ::core::option::Option<AuthRequest>,
+    pub protocol_version: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct TryAuthResult {
     pub authentication_result: ::core::option::Option<AuthResult>,
+    pub protocol_version: u32,
+    pub error: Option<RcdError>,
 }
 /// a message for creating a table in a database
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -616,6 +631,21 @@ pub struct GetRowFromPartialDatabaseResult {
     pub result_message: String,
     pub row: ::core::option::Option<Row>,
 }
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct GetRowsFromPartialDatabaseRequest {
+    pub authentication: ::core::option::Option<AuthRequest>,
+    pub database_name: String,
+    pub table_name: String,
+    pub row_ids: Vec<u32>,
+    pub message_info: ::core::option::Option<MessageInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct GetRowsFromPartialDatabaseResult {
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    pub is_successful: bool,
+    pub result_message: String,
+    pub rows: Vec<Row>,
+}
 /// a message from a host to a participant to save a contract
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct SaveContractRequest {
@@ -679,6 +709,23 @@ pub struct NotifyHostOfRemovedRowResponse {
     pub authentication_result: ::core::option::Option<AuthResult>,
     pub is_successful: bool,
 }
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct NotifyHostOfInsertedRowRequest {
+    pub authentication: ::core::option::Option<AuthRequest>,
+    pub message_info: ::core::option::Option<MessageInfo>,
+    pub host_info: ::core::option::Option<Host>,
+    pub database_name: String,
+    pub database_id: String,
+    pub table_name: String,
+    pub table_id: u32,
+    pub row_id: u32,
+    pub hash: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct NotifyHostOfInsertedRowResponse {
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    pub is_successful: bool,
+}
 /// A message for basic online testing
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
 pub struct TestRequest {
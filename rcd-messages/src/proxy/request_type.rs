@@ -47,4 +47,12 @@ pub enum RequestType {
     GetCooperativeHosts,
     GetSettings,
     GetLogsByLastNumber,
+    RehashPartialDatabase,
+    UnlockDatabase,
+    ExportDatabase,
+    GetPendingWritesAtHost,
+    RejectPendingAction,
+    GetPendingActionTtl,
+    SetPendingActionTtl,
+    GetVersions,
 }
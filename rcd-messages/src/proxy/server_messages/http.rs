@@ -2,3 +2,8 @@ pub const TOKEN_URL: &str = "account/token";
 pub const REVOKE_TOKEN_URL: &str = "account/token/revoke";
 pub const REGISTER_URL: &str = "account/register";
 pub const EXECUTE: &str = "execute";
+pub const USAGE_URL: &str = "account/usage";
+pub const CORE_CACHE_METRICS: &str = "metrics/core-cache";
+pub const TENANT_STATUS_URL: &str = "admin/tenant/status";
+pub const CHANGE_TENANT_STATUS_URL: &str = "admin/tenant/status/change";
+pub const EXPORT_TENANT_URL: &str = "admin/tenant/export";
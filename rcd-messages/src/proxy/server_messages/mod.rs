@@ -41,4 +41,119 @@ pub struct ExecuteReply {
     pub login_success: bool,
     pub execute_success: bool,
     pub reply: Option<String>,
+    // set when the request was refused because the login is over one of its quotas
+    pub quota_error: Option<QuotaError>,
+}
+
+/// how well the proxy's cache of per-host `Rcd` instances is doing
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct CoreCacheMetrics {
+    pub capacity: u64,
+    pub entries: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub expirations: u64,
+    pub invalidations: u64,
+    pub hit_rate: f64,
+}
+
+/// an operator's request for the core cache metrics; `jwt` is the operator's token
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct CoreCacheMetricsRequest {
+    pub jwt: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct CoreCacheMetricsReply {
+    pub is_successful: bool,
+    pub metrics: Option<CoreCacheMetrics>,
+    pub error: Option<String>,
+}
+
+/// the limits a proxy places on a single login. a limit that is `None` is not enforced
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct TenantQuota {
+    pub max_databases: Option<u64>,
+    pub max_storage_bytes: Option<u64>,
+    pub max_rows: Option<u64>,
+    pub max_requests_per_second: Option<u32>,
+}
+
+/// what a login is currently using of its quota
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct TenantUsage {
+    pub databases: u64,
+    pub storage_bytes: u64,
+    pub rows: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum QuotaKind {
+    Databases,
+    StorageBytes,
+    Rows,
+    RequestsPerSecond,
+    /// the login's quota or usage could not be read, so nothing that could add to its usage is
+    /// let through
+    Unmeasured,
+}
+
+/// returned in place of a reply when a request would take a login over one of its quotas
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct QuotaError {
+    pub login: String,
+    pub kind: QuotaKind,
+    pub limit: u64,
+    pub usage: u64,
+    pub message: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct UsageRequest {
+    pub jwt: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct UsageReply {
+    pub is_successful: bool,
+    pub quota: Option<TenantQuota>,
+    pub usage: Option<TenantUsage>,
+    pub error: Option<String>,
+}
+
+/// an operator's request about another account at the proxy; `jwt` is the operator's token
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct TenantRequest {
+    pub jwt: String,
+    pub login: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct ChangeTenantStatusRequest {
+    pub jwt: String,
+    pub login: String,
+    // rcd_enum::tenant_status::TenantStatus
+    pub status: u32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct TenantStatusReply {
+    pub is_successful: bool,
+    pub login: String,
+    pub status: u32,
+    // when the account will be removed, if it is pending delete
+    pub delete_after_utc: Option<String>,
+    pub error: Option<String>,
+}
+
+/// The export is sent back as a stream of these: the first one names the archive of the
+/// account's databases and gives its size, and each one after it carries a chunk of the archive
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+pub struct ExportTenantReply {
+    pub is_successful: bool,
+    pub archive_name: Option<String>,
+    pub archive_size_in_bytes: u64,
+    pub data: Vec<u8>,
+    pub error: Option<String>,
 }
@@ -85,6 +85,7 @@ pub fn SetTablePolicy(ColumnProps { table }: &ColumnProps) -> Html {
                     database_name,
                     table_name,
                     policy_mode: policy_num,
+                    participant_owned_columns: Vec::new(),
                 };
 
                 let request_json = serde_json::to_string(&request).unwrap();
@@ -1,10 +1,235 @@
+use gloo::{
+    file::{Blob, ObjectUrl},
+    utils::document,
+};
+use rcd_enum::tenant_status::TenantStatus;
+use rcd_messages::proxy::server_messages::{TenantStatusReply, UsageReply};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlElement, HtmlInputElement};
 use yew::prelude::*;
+use yew_router::prelude::use_navigator;
+
+use crate::{
+    app::Route,
+    request::proxy::{get_proxy, has_proxy_token},
+};
 
 #[function_component]
 pub fn SiteAdmin() -> Html {
+    let usage = use_state_eq(|| None::<Result<UsageReply, String>>);
+
+    let reload_usage_onclick = {
+        let usage = usage.clone();
+        Callback::from(move |_| {
+            let usage = usage.clone();
+            spawn_local(async move {
+                let mut proxy = get_proxy();
+                let result = proxy.get_usage().await;
+                usage.set(Some(result));
+            })
+        })
+    };
+
+    let navigator = use_navigator().unwrap();
+    if !has_proxy_token() {
+        navigator.push(&Route::Login);
+        return html! {
+            <div>
+                <p>{"You are not logged in, redirecting to login page."}</p>
+            </div>
+        };
+    }
+
+    let details = match (*usage).clone() {
+        None => html! { <p>{"Click Reload to see your account's usage."}</p> },
+        Some(Err(e)) => html! { <p>{format!("Unable to get usage: {e}")}</p> },
+        Some(Ok(reply)) if !reply.is_successful => {
+            html! { <p>{format!("Unable to get usage: {}", reply.error.unwrap_or_default())}</p> }
+        }
+        Some(Ok(reply)) => {
+            let quota = reply.quota.unwrap_or_default();
+            let usage = reply.usage.unwrap_or_default();
+
+            html! {
+                <div class="table-container">
+                    <table class="table is-narrow">
+                        <thead>
+                            <tr>
+                                <th>{"Quota"}</th>
+                                <th>{"Used"}</th>
+                                <th>{"Limit"}</th>
+                            </tr>
+                        </thead>
+                        <tr>
+                            <td>{"Databases"}</td>
+                            <td>{usage.databases}</td>
+                            <td>{limit_text(quota.max_databases)}</td>
+                        </tr>
+                        <tr>
+                            <td>{"Storage (bytes)"}</td>
+                            <td>{usage.storage_bytes}</td>
+                            <td>{limit_text(quota.max_storage_bytes)}</td>
+                        </tr>
+                        <tr>
+                            <td>{"Rows"}</td>
+                            <td>{usage.rows}</td>
+                            <td>{limit_text(quota.max_rows)}</td>
+                        </tr>
+                        <tr>
+                            <td>{"Requests Per Second"}</td>
+                            <td>{"-"}</td>
+                            <td>{limit_text(quota.max_requests_per_second.map(|limit| limit as u64))}</td>
+                        </tr>
+                    </table>
+                </div>
+            }
+        }
+    };
+
     html! {
         <div>
-            {"Site Admin Placeholder"}
+            <div class="container">
+                <div class="box">
+                    <h1 class="subtitle"> {"Usage"} </h1>
+                    <p>{"View how much of your account's quota is being used."}</p>
+                    <button type="button" class="button is-primary" id="get_usage" value="Reload usage"
+                    onclick={reload_usage_onclick}>
+                    <span class="mdi mdi-refresh">{" Reload"}</span>
+                    </button>
+                    {details}
+                </div>
+                <TenantAdmin />
+            </div>
         </div>
     }
 }
+
+/// Lets an operator look up, suspend, resume, delete and export other accounts at the proxy.
+/// The proxy refuses these unless the logged in account is one of its `admin_logins`.
+#[function_component]
+pub fn TenantAdmin() -> Html {
+    let ui_login = use_node_ref();
+    let result = use_state_eq(|| String::from(""));
+
+    let on_status_change = |status: Option<TenantStatus>| {
+        let ui_login = ui_login.clone();
+        let result = result.clone();
+
+        Callback::from(move |_| {
+            let login = ui_login.cast::<HtmlInputElement>().unwrap().value();
+            let result = result.clone();
+
+            spawn_local(async move {
+                let mut proxy = get_proxy();
+                let reply = match status {
+                    Some(status) => proxy.change_tenant_status(&login, status).await,
+                    None => proxy.get_tenant_status(&login).await,
+                };
+                result.set(status_text(reply));
+            })
+        })
+    };
+
+    let onclick_export = {
+        let ui_login = ui_login.clone();
+        let result = result.clone();
+
+        Callback::from(move |_| {
+            let login = ui_login.cast::<HtmlInputElement>().unwrap().value();
+            let result = result.clone();
+
+            spawn_local(async move {
+                let mut proxy = get_proxy();
+                let message = match proxy.export_tenant(&login).await {
+                    Ok(reply) if reply.is_successful => {
+                        let archive_name = reply.archive_name.unwrap_or_default();
+                        match download(&archive_name, &reply.data) {
+                            Ok(()) => {
+                                format!("Exported {} bytes as {}", reply.data.len(), archive_name)
+                            }
+                            Err(e) => format!("Unable to save the export: {e}"),
+                        }
+                    }
+                    Ok(reply) => format!("Unable to export: {}", reply.error.unwrap_or_default()),
+                    Err(e) => format!("Unable to export: {e}"),
+                };
+                result.set(message);
+            })
+        })
+    };
+
+    html! {
+        <div class="box">
+            <h1 class="subtitle"> {"Accounts"} </h1>
+            <p>{"Suspending or deleting an account signs it out and refuses it until it is resumed. A deleted account is removed once its grace period has passed."}</p>
+            <label for="tenant_login">{"Login"}</label>
+            <input type="text" class="input" id="tenant_login" placeholder="Login" ref={&ui_login} />
+            <div class="buttons">
+                <button type="button" class="button is-info" onclick={on_status_change(None)}>
+                    <span class="mdi mdi-account-search">{" Status"}</span>
+                </button>
+                <button type="button" class="button is-warning" onclick={on_status_change(Some(TenantStatus::Suspended))}>
+                    <span class="mdi mdi-account-cancel">{" Suspend"}</span>
+                </button>
+                <button type="button" class="button is-primary" onclick={on_status_change(Some(TenantStatus::Active))}>
+                    <span class="mdi mdi-account-check">{" Resume"}</span>
+                </button>
+                <button type="button" class="button is-danger" onclick={on_status_change(Some(TenantStatus::PendingDelete))}>
+                    <span class="mdi mdi-account-remove">{" Delete"}</span>
+                </button>
+                <button type="button" class="button is-link" onclick={onclick_export}>
+                    <span class="mdi mdi-archive-arrow-down">{" Export"}</span>
+                </button>
+            </div>
+            <p>{(*result).clone()}</p>
+        </div>
+    }
+}
+
+fn status_text(reply: Result<TenantStatusReply, String>) -> String {
+    match reply {
+        Ok(reply) if reply.is_successful => {
+            let status = TenantStatus::from_u32(reply.status).as_string();
+            match reply.delete_after_utc {
+                Some(delete_after) => {
+                    format!("{}: {status}, removed after {delete_after}", reply.login)
+                }
+                None => format!("{}: {status}", reply.login),
+            }
+        }
+        Ok(reply) => format!(
+            "Unable to update {}: {}",
+            reply.login,
+            reply.error.unwrap_or_default()
+        ),
+        Err(e) => format!("Unable to reach the proxy: {e}"),
+    }
+}
+
+fn limit_text(limit: Option<u64>) -> String {
+    match limit {
+        Some(limit) => limit.to_string(),
+        None => "Unlimited".to_string(),
+    }
+}
+
+/// hands the file to the browser to save, as if a link to it had been clicked
+fn download(file_name: &str, data: &[u8]) -> Result<(), String> {
+    let blob = Blob::new_with_options(data, Some("application/x-tar"));
+    let url = ObjectUrl::from(blob);
+
+    let link = document()
+        .create_element("a")
+        .map_err(|e| format!("{e:?}"))?
+        .dyn_into::<HtmlElement>()
+        .map_err(|e| format!("{e:?}"))?;
+
+    link.set_attribute("href", &url)
+        .map_err(|e| format!("{e:?}"))?;
+    link.set_attribute("download", file_name)
+        .map_err(|e| format!("{e:?}"))?;
+    link.click();
+
+    Ok(())
+}
@@ -3,12 +3,17 @@ use gloo::{
     storage::{SessionStorage, Storage},
 };
 use rcd_client_wasm::token::Token;
+use rcd_enum::tenant_status::TenantStatus;
 use rcd_messages::proxy::{
     request_type::RequestType,
     server_messages::{
-        http::{EXECUTE, REGISTER_URL, REVOKE_TOKEN_URL, TOKEN_URL},
-        AuthForTokenReply, AuthForTokenRequest, ExecuteReply, ExecuteRequest, RegisterLoginReply,
-        RegisterLoginRequest,
+        http::{
+            CHANGE_TENANT_STATUS_URL, EXECUTE, EXPORT_TENANT_URL, REGISTER_URL, REVOKE_TOKEN_URL,
+            TENANT_STATUS_URL, TOKEN_URL, USAGE_URL,
+        },
+        AuthForTokenReply, AuthForTokenRequest, ChangeTenantStatusRequest, ExecuteReply,
+        ExecuteRequest, ExportTenantReply, RegisterLoginReply, RegisterLoginRequest, TenantRequest,
+        TenantStatusReply, UsageReply, UsageRequest,
     },
 };
 use serde::{de, Deserialize, Serialize};
@@ -67,6 +72,71 @@ impl RcdProxy {
         log_to_console(&debug);
     }
 
+    /// gets the quota for the logged in account and how much of it is being used
+    pub async fn get_usage(&mut self) -> Result<UsageReply, String> {
+        let token = get_proxy_token();
+        let request = UsageRequest {
+            jwt: token.jwt.clone(),
+        };
+
+        let url = self.get_http_url(USAGE_URL);
+        self.get_http_result_error(url, request).await
+    }
+
+    /// gets where another account is in its lifecycle. the logged in account must be an admin
+    pub async fn get_tenant_status(&mut self, login: &str) -> Result<TenantStatusReply, String> {
+        let request = TenantRequest {
+            jwt: get_proxy_token().jwt,
+            login: login.to_string(),
+        };
+
+        let url = self.get_http_url(TENANT_STATUS_URL);
+        self.get_http_result_error(url, request).await
+    }
+
+    /// suspends, resumes or deletes another account. the logged in account must be an admin
+    pub async fn change_tenant_status(
+        &mut self,
+        login: &str,
+        status: TenantStatus,
+    ) -> Result<TenantStatusReply, String> {
+        let request = ChangeTenantStatusRequest {
+            jwt: get_proxy_token().jwt,
+            login: login.to_string(),
+            status: TenantStatus::to_u32(status),
+        };
+
+        let url = self.get_http_url(CHANGE_TENANT_STATUS_URL);
+        self.get_http_result_error(url, request).await
+    }
+
+    /// archives another account's databases at the proxy. the logged in account must be an admin.
+    /// the archive comes back a chunk per line, which are put back together into the first reply
+    pub async fn export_tenant(&mut self, login: &str) -> Result<ExportTenantReply, String> {
+        let request = TenantRequest {
+            jwt: get_proxy_token().jwt,
+            login: login.to_string(),
+        };
+
+        let url = self.get_http_url(EXPORT_TENANT_URL);
+        let body = serde_json::to_string(&request).unwrap();
+        let replies = post_result(&url, &body).await?;
+
+        let mut export: Option<ExportTenantReply> = None;
+        for line in replies.lines().filter(|line| !line.is_empty()) {
+            let reply: ExportTenantReply = serde_json::from_str(line).map_err(|e| e.to_string())?;
+
+            match export.as_mut() {
+                None => export = Some(reply),
+                // reading the archive failed part way through
+                Some(_) if !reply.is_successful => return Ok(reply),
+                Some(export) => export.data.extend(reply.data),
+            }
+        }
+
+        export.ok_or_else(|| String::from("The proxy did not reply"))
+    }
+
     pub async fn execute_request(
         &mut self,
         request_json: &str,
@@ -0,0 +1,820 @@
+use self::db_part::{get_partial_db_connection, get_partial_db_name};
+use mysql::consts::ColumnType as MySqlType;
+use mysql::prelude::{FromValue, Queryable};
+use mysql::{from_value_opt, Conn, Opts, OptsBuilder, Row, TxOpts};
+use rcd_common::{
+    db::{is_reserved_table_name, DbiConfigMySql},
+    defaults,
+    table::*,
+};
+use rcd_enum::column_type::ColumnType;
+use rcd_enum::rcd_database_type::RcdDatabaseType;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcd_sqlite_log::log_entry::LogEntry;
+use rcdproto::rcdp::{ColumnSchema, RowValue};
+use std::collections::HashMap;
+use tracing::{info, trace, warn};
+pub mod backend;
+pub mod db;
+pub mod db_part;
+pub mod rcd_db;
+mod sql_text;
+use stdext::function_name;
+use tracing::instrument;
+
+/// The character set MySQL reports for binary columns
+const BINARY_CHARACTER_SET: u16 = 63;
+
+/// Log entries are written by `rcd-sqlite-log` to the root folder of a sqlite backed instance.
+/// A MySQL backed instance has no root folder, so there are no entries to return.
+pub fn get_last_log_entries(_number_of_entries: u32, _config: &DbiConfigMySql) -> Vec<LogEntry> {
+    Vec::new()
+}
+
+/// Takes a SELECT COUNT(*) SQL statement and returns if the result is > 0. Usually used to see if a table that has been
+/// created has also populated any data in it.
+pub fn has_any_rows(cmd: String, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    Ok(total_count(cmd, conn)? > 0)
+}
+
+/// Takes a SELECT COUNT(*) SQL statement and returns the value
+pub fn total_count(cmd: String, conn: &mut Conn) -> Result<u32, RcdDbError> {
+    get_scalar_as_u32(cmd, conn)
+}
+
+pub fn get_scalar_as_vec_u8(cmd: String, conn: &mut Conn) -> Result<Option<Vec<u8>>, RcdDbError> {
+    Ok(conn.query_first::<Option<Vec<u8>>, _>(cmd)?.flatten())
+}
+
+pub fn get_scalar_as_u64(cmd: String, conn: &mut Conn) -> Result<Option<u64>, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    match get_scalar_as_vec_u8(cmd, conn)? {
+        Some(array) => {
+            let len = array.len();
+            let array: [u8; 8] = array.try_into().map_err(|_| {
+                RcdDbError::General(format!("expected a hash of 8 bytes but it was {len}"))
+            })?;
+            Ok(Some(u64::from_ne_bytes(array)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Runs any SQL statement that returns a single value and attempts
+/// to return the result as a u32
+pub fn get_scalar_as_u32(cmd: String, conn: &mut Conn) -> Result<u32, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    match get_scalar_as_text(&cmd, conn)? {
+        Some(value) => Ok(value.parse().unwrap_or_default()),
+        None => Ok(0),
+    }
+}
+
+pub fn get_scalar_as_bool(cmd: String, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    trace!("get_scalar_as_bool: {cmd:?}");
+
+    Ok(matches!(
+        get_scalar_as_text(&cmd, conn)?.as_deref(),
+        Some("1") | Some("true")
+    ))
+}
+
+/// Reads the value at `index` of a row, returning an error instead of panicking if the value is
+/// missing or can't be converted to `T`
+pub fn get_value<T: FromValue>(row: &Row, index: usize) -> Result<T, RcdDbError> {
+    match row.get_opt::<T, _>(index) {
+        Some(Ok(value)) => Ok(value),
+        Some(Err(e)) => Err(RcdDbError::General(e.to_string())),
+        None => Err(RcdDbError::General(format!("no value at column {index}"))),
+    }
+}
+
+pub fn execute_write(conn: &mut Conn, cmd: &str) -> Result<usize, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+    conn.query_drop(cmd)?;
+    Ok(conn.affected_rows() as usize)
+}
+
+pub fn execute_read_on_connection_for_row(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    cmd: String,
+    conn: &mut Conn,
+) -> Result<rcdproto::rcdp::Row, RcdDbError> {
+    let (cols, rows) = read_as_strings(&cmd, conn)?;
+
+    let mut values: Vec<RowValue> = Vec::new();
+    let mut columns: Vec<ColumnSchema> = Vec::new();
+
+    for (col_idx, col) in cols.iter().enumerate() {
+        let col_type = data_type_as_string(col.1, col.2);
+
+        let c = ColumnSchema {
+            column_name: col.0.clone(),
+            column_type: ColumnType::to_u32(ColumnType::try_parse(&col_type).ok_or_else(|| {
+                RcdDbError::General(format!("unknown type for column {}", col.0))
+            })?),
+            column_length: 0,
+            is_nullable: false,
+            ordinal: col_idx as u32,
+            table_id: String::from(""),
+            column_id: col_idx.to_string(),
+            is_primary_key: false,
+        };
+        columns.push(c);
+    }
+
+    for row in rows {
+        for (i, string_value) in row.into_iter().enumerate() {
+            let row_value = RowValue {
+                column: Some(columns[i].clone()),
+                is_null_value: false,
+                value: string_value.as_bytes().to_vec(),
+                string_value,
+            };
+
+            values.push(row_value);
+        }
+    }
+
+    let mut cmd = String::from("SELECT HASH FROM :table_name WHERE ROW_ID = :rid");
+    let metadata_table_name = format!("{}{}", table_name, defaults::METADATA_TABLE_SUFFIX);
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let hash = get_scalar_as_vec_u8(cmd, conn)?.unwrap_or_default();
+
+    let result = rcdproto::rcdp::Row {
+        database_name: db_name.to_string(),
+        table_name: table_name.to_string(),
+        row_id,
+        values,
+        is_remoteable: true,
+        remote_metadata: None,
+        hash,
+    };
+
+    trace!("[{}]: {result:?}", function_name!());
+
+    Ok(result)
+}
+
+pub fn execute_read(cmd: &str, conn: &mut Conn) -> Result<Table, RcdDbError> {
+    trace!("[{}]: {:?}", function_name!(), cmd);
+
+    let (cols, rows) = read_as_strings(cmd, conn)?;
+    let mut table = Table::new();
+
+    for (col_idx, col) in cols.iter().enumerate() {
+        let c = Column {
+            name: col.0.clone(),
+            is_nullable: false,
+            idx: col_idx,
+            data_type: data_type_as_string(col.1, col.2),
+            is_primary_key: false,
+        };
+
+        trace!("[{}]: adding col {}", function_name!(), c.name);
+
+        table.add_column(c);
+    }
+
+    for row in rows {
+        let mut data_row = rcd_common::table::Row::new();
+
+        for (i, string_value) in row.into_iter().enumerate() {
+            let col = table.get_column_by_index(i).ok_or_else(|| {
+                RcdDbError::General(format!("no column at index {i} in the result"))
+            })?;
+
+            let data_item = Data {
+                data_string: string_value,
+                data_byte: Vec::new(),
+            };
+
+            let data_value = Value {
+                data: Some(data_item),
+                col,
+            };
+
+            data_row.add_value(data_value);
+        }
+
+        table.add_row(data_row);
+    }
+
+    Ok(table)
+}
+
+pub fn execute_read_at_participant(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigMySql,
+) -> Result<Table, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    execute_read(cmd, &mut conn)
+}
+
+pub fn execute_read_at_host(
+    db_name: &str,
+    cmd: &str,
+    config: DbiConfigMySql,
+) -> core::result::Result<Table, RcdDbError> {
+    if !has_database(&config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(&config, db_name)?;
+    execute_read(cmd, &mut conn)
+}
+
+/// Copies the rows we got from participants into temporary tables named after each cooperative
+/// table. A temporary table hides the regular table of the same name for the session, so `cmd`
+/// can run as written and read those rows instead of the host's copy of the table.
+///
+/// MySQL won't open a temporary table twice in one statement, so a self-join on a cooperative
+/// table will fail here.
+pub fn execute_read_at_host_with_cooperative_rows(
+    db_name: &str,
+    cmd: &str,
+    cooperative_tables: &[String],
+    cooperative_rows: &[rcdproto::rcdp::Row],
+    config: DbiConfigMySql,
+) -> core::result::Result<Table, RcdDbError> {
+    if !has_database(&config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(&config, db_name)?;
+
+    for table_name in cooperative_tables {
+        let create_temp_table =
+            format!("CREATE TEMPORARY TABLE `{table_name}` LIKE `{db_name}`.`{table_name}`;");
+        conn.query_drop(&create_temp_table)?;
+
+        let add_row_key = format!(
+            "ALTER TABLE `{table_name}` ADD COLUMN `{table_name}{}` INT;",
+            defaults::ROW_KEY_COLUMN_SUFFIX
+        );
+        conn.query_drop(&add_row_key)?;
+    }
+
+    for (row_key, row) in cooperative_rows.iter().enumerate() {
+        let (insert_row, params) = get_insert_for_cooperative_row(row, row_key)?;
+        trace!("[{}]: {insert_row:?}", function_name!());
+        conn.exec_drop(&insert_row, params)?;
+    }
+
+    execute_read(cmd, &mut conn)
+}
+
+/// Builds an `INSERT` of a participant's row into the temporary table of the same name, along
+/// with the row's values to bind to it
+fn get_insert_for_cooperative_row(
+    row: &rcdproto::rcdp::Row,
+    row_key: usize,
+) -> Result<(String, Vec<mysql::Value>), RcdDbError> {
+    let (mut col_names, mut params) = get_row_params(row)?;
+
+    col_names.push(format!(
+        "`{}{}`",
+        row.table_name,
+        defaults::ROW_KEY_COLUMN_SUFFIX
+    ));
+    params.push(mysql::Value::from(row_key));
+
+    Ok((get_insert_with_params(&row.table_name, &col_names), params))
+}
+
+/// Returns the quoted column names of a row along with its values to bind to them
+fn get_row_params(
+    row: &rcdproto::rcdp::Row,
+) -> Result<(Vec<String>, Vec<mysql::Value>), RcdDbError> {
+    let mut col_names: Vec<String> = Vec::new();
+    let mut params: Vec<mysql::Value> = Vec::new();
+
+    for value in &row.values {
+        let column = value.column.as_ref().ok_or_else(|| {
+            RcdDbError::General(format!("a value of {} has no column", row.table_name))
+        })?;
+
+        col_names.push(format!("`{}`", column.column_name));
+        params.push(get_typed_value(column, value));
+    }
+
+    Ok((col_names, params))
+}
+
+/// Returns the value to bind for a column. MySQL converts the bytes of a value to its column's
+/// type, so only NULLs and blobs need telling apart.
+fn get_typed_value(column: &ColumnSchema, value: &RowValue) -> mysql::Value {
+    if value.is_null_value {
+        return mysql::Value::NULL;
+    }
+
+    match ColumnType::from_u32(column.column_type) {
+        ColumnType::Binary | ColumnType::Varbinary => mysql::Value::Bytes(value.value.clone()),
+        _ => mysql::Value::Bytes(value.string_value.as_bytes().to_vec()),
+    }
+}
+
+/// Builds an `INSERT` into `table_name` with a `?` placeholder for each column
+fn get_insert_with_params(table_name: &str, col_names: &[String]) -> String {
+    let placeholders = vec!["?"; col_names.len()];
+
+    format!(
+        "INSERT INTO `{table_name}` ({}) VALUES ({});",
+        col_names.join(", "),
+        placeholders.join(", ")
+    )
+}
+
+/// Deletes every row of the host's table and inserts `rows` in their place, in a single
+/// transaction
+pub fn replace_rows_at_host(
+    db_name: &str,
+    table_name: &str,
+    rows: &[rcdproto::rcdp::Row],
+    config: &DbiConfigMySql,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(config, db_name)?;
+    let mut transaction = conn.start_transaction(TxOpts::default())?;
+    transaction.query_drop(format!("DELETE FROM `{table_name}`;"))?;
+
+    let mut total_rows = 0;
+
+    for row in rows {
+        let (col_names, params) = get_row_params(row)?;
+        let cmd = get_insert_with_params(table_name, &col_names);
+        trace!("[{}]: {cmd:?}", function_name!());
+        transaction.exec_drop(&cmd, params)?;
+        total_rows += transaction.affected_rows() as usize;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
+/// Reads the rows of the host's table whose key columns hold one of `keys`. The matching rows
+/// are copied into a temporary table with the keys bound, and then read from there as text like
+/// any other read. The temporary table can't share the table's name, as it would hide the table
+/// from the copy.
+pub fn get_rows_at_host_by_key(
+    db_name: &str,
+    table_name: &str,
+    keys: &[Vec<RowValue>],
+    config: &DbiConfigMySql,
+) -> Result<Table, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut predicates: Vec<String> = Vec::new();
+    let mut params: Vec<mysql::Value> = Vec::new();
+
+    for key in keys {
+        let mut terms: Vec<String> = Vec::new();
+
+        for value in key {
+            let column = value.column.as_ref().ok_or_else(|| {
+                RcdDbError::General(format!("a key of {table_name} has no column"))
+            })?;
+
+            params.push(get_typed_value(column, value));
+            terms.push(format!("`{}` = ?", column.column_name));
+        }
+
+        predicates.push(format!("({})", terms.join(" AND ")));
+    }
+
+    if predicates.is_empty() {
+        return Ok(Table::new());
+    }
+
+    let mut conn = get_db_conn(config, db_name)?;
+    let lookup_table = format!("{table_name}_lookup");
+
+    let create_temp_table =
+        format!("CREATE TEMPORARY TABLE `{lookup_table}` LIKE `{db_name}`.`{table_name}`;");
+    conn.query_drop(&create_temp_table)?;
+
+    let copy_rows = format!(
+        "INSERT INTO `{lookup_table}` SELECT * FROM `{db_name}`.`{table_name}` WHERE {}",
+        predicates.join(" OR ")
+    );
+    conn.exec_drop(&copy_rows, params)?;
+
+    execute_read(&format!("SELECT * FROM `{lookup_table}`"), &mut conn)
+}
+
+/// Runs any SQL statement that returns a single value and returns
+/// the result as a string
+fn get_scalar_as_string(cmd: String, conn: &mut Conn) -> Result<String, RcdDbError> {
+    Ok(get_scalar_as_text(&cmd, conn)?.unwrap_or_default())
+}
+
+/// Runs the statement over the text protocol and returns the value of the first column
+/// of the first row as a string
+fn get_scalar_as_text(cmd: &str, conn: &mut Conn) -> Result<Option<String>, RcdDbError> {
+    let row: Option<Row> = conn.query_first(cmd)?;
+    Ok(row.and_then(|r| r.as_ref(0).and_then(value_as_string)))
+}
+
+fn value_as_string(value: &mysql::Value) -> Option<String> {
+    from_value_opt::<String>(value.clone()).ok()
+}
+
+/// The name and type of a column in a result set, and whether it holds binary data
+type ColumnInfo = (String, MySqlType, bool);
+
+/// Runs the statement over the text protocol and returns the name and type of each column
+/// along with each value as text. NULLs and binary values are returned as empty strings,
+/// the same as the sqlite backend.
+fn read_as_strings(
+    cmd: &str,
+    conn: &mut Conn,
+) -> Result<(Vec<ColumnInfo>, Vec<Vec<String>>), RcdDbError> {
+    let mut result = conn.query_iter(cmd)?;
+
+    let cols: Vec<ColumnInfo> = result
+        .columns()
+        .as_ref()
+        .iter()
+        .map(|c| {
+            (
+                c.name_str().to_string(),
+                c.column_type(),
+                c.character_set() == BINARY_CHARACTER_SET,
+            )
+        })
+        .collect();
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    for row in result.by_ref() {
+        let row = row?;
+        let mut values: Vec<String> = Vec::new();
+
+        for (i, col) in cols.iter().enumerate() {
+            let string_value = if col.2 && is_string_type(col.1) {
+                String::from("")
+            } else {
+                row.as_ref(i).and_then(value_as_string).unwrap_or_default()
+            };
+
+            values.push(string_value);
+        }
+
+        rows.push(values);
+    }
+
+    Ok((cols, rows))
+}
+
+fn is_string_type(data_type: MySqlType) -> bool {
+    matches!(
+        data_type,
+        MySqlType::MYSQL_TYPE_STRING
+            | MySqlType::MYSQL_TYPE_VAR_STRING
+            | MySqlType::MYSQL_TYPE_VARCHAR
+            | MySqlType::MYSQL_TYPE_BLOB
+            | MySqlType::MYSQL_TYPE_TINY_BLOB
+            | MySqlType::MYSQL_TYPE_MEDIUM_BLOB
+            | MySqlType::MYSQL_TYPE_LONG_BLOB
+    )
+}
+
+/// Maps a MySQL column type to the type names used by the sqlite backend so that
+/// `ColumnType::try_parse` understands them
+fn data_type_as_string(data_type: MySqlType, is_binary: bool) -> String {
+    let name = match data_type {
+        MySqlType::MYSQL_TYPE_TINY
+        | MySqlType::MYSQL_TYPE_SHORT
+        | MySqlType::MYSQL_TYPE_INT24
+        | MySqlType::MYSQL_TYPE_LONG
+        | MySqlType::MYSQL_TYPE_LONGLONG => "INT",
+        MySqlType::MYSQL_TYPE_BIT => "BIT",
+        MySqlType::MYSQL_TYPE_STRING if is_binary => "BLOB",
+        MySqlType::MYSQL_TYPE_STRING => "CHAR",
+        MySqlType::MYSQL_TYPE_VAR_STRING | MySqlType::MYSQL_TYPE_VARCHAR if is_binary => "BLOB",
+        MySqlType::MYSQL_TYPE_VAR_STRING | MySqlType::MYSQL_TYPE_VARCHAR => "VARCHAR",
+        MySqlType::MYSQL_TYPE_BLOB
+        | MySqlType::MYSQL_TYPE_TINY_BLOB
+        | MySqlType::MYSQL_TYPE_MEDIUM_BLOB
+        | MySqlType::MYSQL_TYPE_LONG_BLOB
+            if is_binary =>
+        {
+            "BLOB"
+        }
+        MySqlType::MYSQL_TYPE_BLOB
+        | MySqlType::MYSQL_TYPE_TINY_BLOB
+        | MySqlType::MYSQL_TYPE_MEDIUM_BLOB
+        | MySqlType::MYSQL_TYPE_LONG_BLOB => "TEXT",
+        MySqlType::MYSQL_TYPE_DATETIME
+        | MySqlType::MYSQL_TYPE_TIMESTAMP
+        | MySqlType::MYSQL_TYPE_DATE => "DATETIME",
+        MySqlType::MYSQL_TYPE_DECIMAL
+        | MySqlType::MYSQL_TYPE_NEWDECIMAL
+        | MySqlType::MYSQL_TYPE_FLOAT
+        | MySqlType::MYSQL_TYPE_DOUBLE => "DECIMAL",
+        _ => "",
+    };
+
+    name.to_string()
+}
+
+fn has_table(table_name: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let mut cmd = String::from(
+        "SELECT count(*) AS TABLECOUNT FROM information_schema.tables WHERE table_schema = DATABASE() AND UPPER(table_name) = UPPER(':table_name')",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    has_any_rows(cmd, conn)
+}
+
+fn has_column(table_name: &str, column_name: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let mut cmd = String::from(
+        "SELECT count(*) AS COLUMNCOUNT FROM information_schema.columns WHERE table_schema = DATABASE() AND UPPER(table_name) = UPPER(':table_name') AND UPPER(column_name) = UPPER(':column_name')",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":column_name", column_name);
+    has_any_rows(cmd, conn)
+}
+
+/// Adds a column to a table created by an earlier version of rcd
+fn add_column_if_missing(
+    table_name: &str,
+    column_name: &str,
+    column_type: &str,
+    conn: &mut Conn,
+) -> Result<(), RcdDbError> {
+    if !has_column(table_name, column_name, conn)? {
+        info!(
+            "[{}]: adding {column_name} to {table_name}",
+            function_name!()
+        );
+
+        let cmd = format!("ALTER TABLE {table_name} ADD COLUMN {column_name} {column_type};");
+        conn.query_drop(&cmd)?;
+    }
+
+    Ok(())
+}
+
+fn has_schema(schema_name: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let cmd = "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = ?";
+    let count: Option<i64> = conn.exec_first(cmd, (schema_name,))?;
+    Ok(count.unwrap_or_default() > 0)
+}
+
+#[instrument]
+pub fn has_database(config: &DbiConfigMySql, db_name: &str) -> Result<bool, RcdDbError> {
+    let mut conn = get_conn(config)?;
+
+    let mut names: Vec<String> = vec![db_name.to_string()];
+
+    if !db_name.ends_with(".db") {
+        names.push(db_name.to_owned() + ".db");
+    }
+
+    if !db_name.ends_with(".dbpart") {
+        names.push(get_partial_db_name(db_name));
+    }
+
+    for name in &names {
+        if has_schema(name, &mut conn)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns a connection to the MySQL server in the settings, without a default database selected
+fn get_conn(config: &DbiConfigMySql) -> Result<Conn, RcdDbError> {
+    let opts = get_opts(config)?;
+    Ok(Conn::new(opts)?)
+}
+
+/// Builds the connection options from the `connection_string` (a `mysql://` url) if there is one,
+/// otherwise from the host and credentials. `connect_options` are `key=value` pairs separated
+/// by `&`, the same as the query string of a connection url.
+fn get_opts(config: &DbiConfigMySql) -> Result<Opts, RcdDbError> {
+    if !config.connection_string.is_empty() {
+        return Opts::from_url(&config.connection_string)
+            .map_err(|e| RcdDbError::General(e.to_string()));
+    }
+
+    let mut builder = OptsBuilder::new()
+        .ip_or_hostname(Some(config.host.clone()))
+        .user(Some(config.user_name.clone()))
+        .pass(Some(config.pw.clone()));
+
+    if !config.connect_options.is_empty() {
+        let options: HashMap<String, String> = config
+            .connect_options
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        builder = builder
+            .from_hash_map(&options)
+            .map_err(|e| RcdDbError::General(e.to_string()))?;
+    }
+
+    Ok(builder.into())
+}
+
+/// Every rcd database is its own database on the MySQL server. Returns a connection with that
+/// database selected, creating the database if it doesn't exist yet
+pub fn get_db_conn(config: &DbiConfigMySql, db_name: &str) -> Result<Conn, RcdDbError> {
+    trace!("[{}]: {db_name:?}", function_name!());
+    let mut conn = get_conn(config)?;
+    conn.query_drop(format!("CREATE DATABASE IF NOT EXISTS `{db_name}`"))?;
+    conn.select_db(db_name)?;
+    Ok(conn)
+}
+
+/// Counts the rows of a host or partial database's own tables, leaving out the tables rcd keeps
+/// for itself
+pub fn count_user_rows(db_name: &str, config: &DbiConfigMySql) -> Result<u64, RcdDbError> {
+    let mut conn = match rcd_db::get_rcd_db_type(db_name, config)? {
+        RcdDatabaseType::Host => get_db_conn(config, db_name)?,
+        RcdDatabaseType::Partial => get_partial_db_connection(db_name, config)?,
+        _ => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let cmd = "SELECT table_name FROM information_schema.tables WHERE table_schema = DATABASE() AND table_type = 'BASE TABLE'";
+    let tables: Vec<String> = conn.query(cmd)?;
+
+    let mut rows = 0;
+
+    for table in tables.iter().filter(|table| !is_reserved_table_name(table)) {
+        let count: Option<u64> = conn.query_first(format!("SELECT COUNT(*) FROM `{table}`"))?;
+        rows += count.unwrap_or_default();
+    }
+
+    Ok(rows)
+}
+
+pub fn execute_write_on_connection_at_host(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigMySql,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(config, db_name)?;
+
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    let result = conn.query_drop(cmd);
+
+    match result {
+        Ok(_) => Ok(conn.affected_rows() as usize),
+        Err(e) => Err(RcdDbError::General(e.to_string())),
+    }
+}
+
+/// Runs the statements in a single transaction, which is rolled back if any of them fail
+pub fn execute_writes_on_connection_at_host(
+    db_name: &str,
+    cmds: &[String],
+    config: &DbiConfigMySql,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(config, db_name)?;
+    let mut transaction = conn.start_transaction(TxOpts::default())?;
+    let mut total_rows = 0;
+
+    for cmd in cmds {
+        trace!("[{}]: {cmd:?}", function_name!());
+        transaction.query_drop(cmd)?;
+        total_rows += transaction.affected_rows() as usize;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
+pub fn execute_write_on_connection_at_participant(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigMySql,
+) -> Result<usize, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    conn.query_drop(cmd)?;
+    Ok(conn.affected_rows() as usize)
+}
+
+/// Returns the column definitions of the table as a comma separated list of
+/// MySQL types, used to create a copy of the table (such as the data log table)
+pub fn get_table_col_names_with_data_type_as_string(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<String, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let table = get_schema_of_table(table_name.to_string(), &mut conn)?;
+
+    let mut col_names = String::from("");
+
+    for row in &table.rows {
+        let col_name = get_data_string(&row.vals[1]);
+        let data_type = get_data_string(&row.vals[2]);
+
+        let col_type = ColumnType::from_u32(ColumnType::data_type_to_enum_u32(data_type.clone()));
+        let mut mysql_data_type = col_type.data_type_as_string_mysql();
+        let col_length = ColumnType::data_type_len(data_type);
+
+        if col_length > 0 {
+            mysql_data_type = format!("{mysql_data_type}({col_length})");
+        }
+
+        col_names = format!("{} {} {}{}", col_names, col_name, mysql_data_type, ",");
+    }
+
+    trace!("[{}]: {col_names:?}", function_name!());
+
+    let result: &str = &col_names[1..col_names.len() - 1];
+
+    trace!("[{}]: {result:?}", function_name!());
+
+    Ok(result.to_string())
+}
+
+/// Returns a table describing the schema of the table, shaped like sqlite's `PRAGMA table_info`.
+/// The `ROWID` column that rcd adds to partial tables is not included.
+/// # Columns:
+/// 1. cid
+/// 2. name
+/// 3. type
+/// 4. notnull
+/// 5. dflt_value
+/// 6. pk
+pub fn get_schema_of_table(
+    table_name: String,
+    conn: &mut Conn,
+) -> core::result::Result<Table, RcdDbError> {
+    let mut cmd = sql_text::Coop::text_get_schema_of_table();
+    cmd = cmd.replace(":table_name", &table_name);
+    execute_read(&cmd, conn)
+}
+
+pub fn get_table_col_names(table_name: String, conn: &mut Conn) -> Result<Vec<String>, RcdDbError> {
+    let mut result: Vec<String> = Vec::new();
+
+    let mut cmd = String::from(
+        "
+        SELECT
+            column_name
+        FROM
+            information_schema.columns
+        WHERE
+            table_schema = DATABASE()
+        AND
+            UPPER(table_name) = UPPER(':table_name')
+        AND
+            UPPER(column_name) <> 'ROWID'
+        ORDER BY
+            ordinal_position
+        ;",
+    );
+    cmd = cmd.replace(":table_name", &table_name);
+
+    let names = execute_read(&cmd, conn)?;
+
+    for row in names.rows {
+        for val in row.vals {
+            result.push(get_data_string(&val));
+        }
+    }
+
+    Ok(result)
+}
+
+fn get_data_string(value: &Value) -> String {
+    value
+        .data
+        .as_ref()
+        .map(|data| data.data_string.clone())
+        .unwrap_or_default()
+}
@@ -0,0 +1,198 @@
+use crate::mysql::{
+    execute_read, execute_write, execute_write_on_connection_at_host, get_db_conn,
+    get_scalar_as_u32, has_any_rows, has_table, sql_text,
+};
+use rcd_common::db::DbiConfigMySql;
+use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
+use rcd_error::rcd_db_error::RcdDbError;
+
+use super::{get_all_user_table_names_in_db, populate_data_host_tables};
+
+/// Returns a vector of tuples representing the name of the user table and the logical storage policy
+/// attached to it.
+pub fn get_logical_storage_policy_for_all_user_tables(
+    db_name: &str,
+    config: DbiConfigMySql,
+) -> Result<Vec<(String, LogicalStoragePolicy)>, RcdDbError> {
+    let mut conn = get_db_conn(&config, db_name)?;
+
+    let mut result: Vec<(String, LogicalStoragePolicy)> = Vec::new();
+
+    let table_names = get_all_user_table_names_in_db(&mut conn)?;
+
+    for table_name in &table_names {
+        let l_policy = get_logical_storage_policy(db_name, &table_name.to_string(), &config)?;
+        let item = (table_name.to_string(), l_policy);
+        result.push(item);
+    }
+
+    Ok(result)
+}
+
+pub fn set_logical_storage_policy(
+    db_name: &str,
+    table_name: &str,
+    policy: LogicalStoragePolicy,
+    config: DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_db_conn(&config, db_name)?;
+    if has_table(table_name, &mut conn)? {
+        // insert or update on the coop tables
+        let mut cmd = String::from(
+            "SELECT COUNT(*) TOTALCOUNT FROM COOP_REMOTES WHERE UPPER(TABLENAME) = UPPER(':table_name');",
+        );
+        cmd = cmd.replace(":table_name", table_name);
+        if has_any_rows(cmd, &mut conn)? {
+            // then this is an update
+            let mut cmd = String::from(
+                "UPDATE COOP_REMOTES
+            SET LOGICAL_STORAGE_POLICY = :policy
+            WHERE UPPER(TABLENAME) = UPPER(':table_name');
+            ",
+            );
+
+            cmd = cmd.replace(":table_name", table_name);
+            cmd = cmd.replace(":policy", &LogicalStoragePolicy::to_u32(policy).to_string());
+            execute_write_on_connection_at_host(db_name, &cmd, &config)?;
+        } else {
+            // then this is an insert
+            let mut cmd = String::from(
+                "INSERT INTO COOP_REMOTES
+            (
+                TABLENAME,
+                LOGICAL_STORAGE_POLICY
+            )
+            VALUES
+            (
+                ':table_name',
+                :policy
+            );",
+            );
+
+            cmd = cmd.replace(":table_name", table_name);
+            cmd = cmd.replace(":policy", &LogicalStoragePolicy::to_u32(policy).to_string());
+            execute_write_on_connection_at_host(db_name, &cmd, &config)?;
+        }
+
+        populate_data_host_tables(db_name, &mut conn)?;
+    } else {
+        let err = RcdDbError::TableNotFoundInDatabase(table_name.to_string(), db_name.to_string());
+        return Err(err);
+    }
+    Ok(true)
+}
+
+/// Returns the logical storage policy for the specified table. If the table does not exist in the database, it will
+/// return an error. If the table exist but does not have a logical storage policy defined for it, it will default
+/// to `LogicalStoragePolicy::None`
+pub fn get_logical_storage_policy(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<LogicalStoragePolicy, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+
+    if !has_table(table_name, &mut conn)? {
+        let err = RcdDbError::TableNotFoundInDatabase(table_name.to_string(), db_name.to_string());
+        return Err(err);
+    }
+
+    if !has_table("COOP_REMOTES", &mut conn)? {
+        return Ok(LogicalStoragePolicy::None);
+    }
+
+    let mut cmd = String::from(
+        "SELECT COUNT(*) TOTALCOUNT FROM COOP_REMOTES WHERE UPPER(TABLENAME) = UPPER(':table_name');",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    if !has_any_rows(cmd, &mut conn)? {
+        return Ok(LogicalStoragePolicy::None);
+    }
+
+    let mut cmd = String::from(
+        "SELECT LOGICAL_STORAGE_POLICY FROM COOP_REMOTES WHERE UPPER(TABLENAME) = UPPER(':table_name');",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    let i_policy = get_scalar_as_u32(cmd, &mut conn)?;
+
+    Ok(LogicalStoragePolicy::from_i64(i_policy as i64))
+}
+
+/// Replaces the columns of a `Shared` table whose values are kept at the participant. An empty
+/// list means the host keeps every column of the table.
+pub fn set_participant_owned_columns(
+    db_name: &str,
+    table_name: &str,
+    column_names: &[String],
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+
+    if !has_table(table_name, &mut conn)? {
+        let err = RcdDbError::TableNotFoundInDatabase(table_name.to_string(), db_name.to_string());
+        return Err(err);
+    }
+
+    execute_write(
+        &mut conn,
+        &sql_text::Coop::text_create_shared_columns_table(),
+    )?;
+
+    let mut cmd = String::from(
+        "DELETE FROM COOP_SHARED_COLUMNS WHERE UPPER(TABLENAME) = UPPER(':table_name');",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    execute_write_on_connection_at_host(db_name, &cmd, config)?;
+
+    for column_name in column_names {
+        let mut cmd = String::from(
+            "INSERT INTO COOP_SHARED_COLUMNS
+            (
+                TABLENAME,
+                COLUMNNAME
+            )
+            VALUES
+            (
+                ':table_name',
+                ':column_name'
+            );",
+        );
+
+        cmd = cmd.replace(":table_name", table_name);
+        cmd = cmd.replace(":column_name", column_name);
+        execute_write_on_connection_at_host(db_name, &cmd, config)?;
+    }
+
+    Ok(true)
+}
+
+/// Returns the columns of a `Shared` table whose values are kept at the participant
+pub fn get_participant_owned_columns(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<String>, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+    let mut column_names: Vec<String> = Vec::new();
+
+    if !has_table("COOP_SHARED_COLUMNS", &mut conn)? {
+        return Ok(column_names);
+    }
+
+    let mut cmd = String::from(
+        "SELECT COLUMNNAME FROM COOP_SHARED_COLUMNS WHERE UPPER(TABLENAME) = UPPER(':table_name');",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+
+    let columns = execute_read(&cmd, &mut conn)?;
+
+    for row in columns.rows {
+        for val in row.vals {
+            if let Some(data) = val.data {
+                column_names.push(data.data_string);
+            }
+        }
+    }
+
+    Ok(column_names)
+}
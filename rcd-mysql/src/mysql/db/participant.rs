@@ -0,0 +1,440 @@
+use guid_create::GUID;
+use mysql::{prelude::Queryable, Conn, Row};
+use rcd_common::{
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, CoopDatabaseParticipantRow,
+        NewCoopDatabaseParticipant,
+    },
+    db::{get_metadata_table_name, DbiConfigMySql},
+    defaults,
+};
+use rcd_enum::contract_status::ContractStatus;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{Participant, ParticipantStatus};
+
+use super::metadata::create_metadata_table_if_not_exists;
+use crate::mysql::{add_column_if_missing, get_db_conn, get_value, has_any_rows, has_table};
+
+/// The columns of COOP_PARTICIPANT in the order expected by `row_to_participant`
+const PARTICIPANT_COLUMNS: &str = "
+            INTERNAL_PARTICIPANT_ID,
+            ALIAS,
+            IP4ADDRESS,
+            IP6ADDRESS,
+            PORT,
+            CONTRACT_STATUS,
+            ACCEPTED_CONTRACT_VERSION_ID,
+            TOKEN,
+            PARTICIPANT_ID,
+            HTTP_ADDR,
+            HTTP_PORT,
+            PROTOCOL_VERSION";
+
+/// Creates the COOP_PARTICIPANT table if it does not exist. This holds
+/// the participant information that are cooperating with this database.
+pub fn create_participant_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    let cmd = String::from(
+        "CREATE TABLE IF NOT EXISTS COOP_PARTICIPANT
+    (
+        INTERNAL_PARTICIPANT_ID CHAR(36) NOT NULL,
+        ALIAS VARCHAR(50) NOT NULL,
+        IP4ADDRESS VARCHAR(25),
+        IP6ADDRESS VARCHAR(25),
+        PORT INT,
+        CONTRACT_STATUS INT,
+        ACCEPTED_CONTRACT_VERSION_ID CHAR(36),
+        TOKEN BLOB NOT NULL,
+        PARTICIPANT_ID CHAR(36),
+        HTTP_ADDR VARCHAR(50),
+        HTTP_PORT INT,
+        PROTOCOL_VERSION INT DEFAULT 0
+    );",
+    );
+
+    conn.query_drop(&cmd)?;
+    Ok(())
+}
+
+/// Returns a connection to the host database, with COOP_PARTICIPANT brought up to the current
+/// layout if it was created by an earlier version of rcd
+fn get_participant_conn(config: &DbiConfigMySql, db_name: &str) -> Result<Conn, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+
+    if has_table("COOP_PARTICIPANT", &mut conn)? {
+        add_column_if_missing(
+            "COOP_PARTICIPANT",
+            "PROTOCOL_VERSION",
+            "INT DEFAULT 0",
+            &mut conn,
+        )?;
+    }
+
+    Ok(conn)
+}
+
+/// Maps a row selected with `PARTICIPANT_COLUMNS` to a participant
+fn row_to_participant(row: &Row) -> Result<CoopDatabaseParticipant, RcdDbError> {
+    let internal_id: String = get_value(row, 0)?;
+    let accepted_contract_version_id: Option<String> = get_value(row, 6)?;
+    let id: Option<String> = get_value(row, 8)?;
+
+    Ok(CoopDatabaseParticipant {
+        internal_id: parse_guid(&internal_id)?,
+        alias: get_value(row, 1)?,
+        ip4addr: get_value::<Option<String>>(row, 2)?.unwrap_or_default(),
+        ip6addr: get_value::<Option<String>>(row, 3)?.unwrap_or_default(),
+        db_port: get_value::<Option<i32>>(row, 4)?.unwrap_or_default() as u32,
+        contract_status: ContractStatus::from_i64(
+            get_value::<Option<i32>>(row, 5)?.unwrap_or_default() as i64,
+        ),
+        accepted_contract_version: parse_guid(
+            &accepted_contract_version_id.unwrap_or_else(|| defaults::EMPTY_GUID.to_string()),
+        )?,
+        token: get_value(row, 7)?,
+        id: parse_guid(&id.unwrap_or_else(|| defaults::EMPTY_GUID.to_string()))?,
+        http_addr: get_value::<Option<String>>(row, 9)?.unwrap_or_default(),
+        http_port: get_value::<Option<i32>>(row, 10)?.unwrap_or_default() as u16,
+        protocol_version: get_value::<Option<i32>>(row, 11)?.unwrap_or_default() as u32,
+    })
+}
+
+fn parse_guid(value: &str) -> Result<GUID, RcdDbError> {
+    GUID::parse(value.trim())
+        .map_err(|_| RcdDbError::General(format!("`{value}` is not a valid GUID")))
+}
+
+pub fn save_participant(
+    participant: CoopDatabaseParticipant,
+    mut conn: Conn,
+) -> Result<(), RcdDbError> {
+    let contract_status = ContractStatus::to_u32(participant.contract_status) as i32;
+
+    if has_participant_at_conn(&participant.alias, &mut conn)? {
+        // this is an update
+        let cmd = String::from(
+            "
+        UPDATE COOP_PARTICIPANT
+        SET
+            IP4ADDRESS = ?,
+            IP6ADDRESS = ?,
+            PORT = ?,
+            CONTRACT_STATUS = ?,
+            ACCEPTED_CONTRACT_VERSION_ID = ?,
+            TOKEN = ?,
+            PARTICIPANT_ID = ?,
+            HTTP_ADDR = ?,
+            HTTP_PORT = ?
+        WHERE
+            ALIAS = ?
+        ;
+        ",
+        );
+
+        conn.exec_drop(
+            &cmd,
+            (
+                &participant.ip4addr,
+                &participant.ip6addr,
+                &(participant.db_port as i32),
+                &contract_status,
+                &participant.accepted_contract_version.to_string(),
+                &participant.token,
+                &participant.id.to_string(),
+                &participant.http_addr,
+                &(participant.http_port as i32),
+                &participant.alias,
+            ),
+        )?;
+    } else {
+        // this is an insert
+        let cmd = String::from(
+            "
+        INSERT INTO COOP_PARTICIPANT
+        (
+            INTERNAL_PARTICIPANT_ID,
+            ALIAS,
+            IP4ADDRESS,
+            IP6ADDRESS,
+            PORT,
+            CONTRACT_STATUS,
+            ACCEPTED_CONTRACT_VERSION_ID,
+            TOKEN,
+            PARTICIPANT_ID,
+            HTTP_ADDR,
+            HTTP_PORT
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?
+        );
+        ",
+        );
+
+        conn.exec_drop(
+            &cmd,
+            (
+                &participant.internal_id.to_string(),
+                &participant.alias,
+                &participant.ip4addr,
+                &participant.ip6addr,
+                &(participant.db_port as i32),
+                &contract_status,
+                &participant.accepted_contract_version.to_string(),
+                &participant.token,
+                &participant.id.to_string(),
+                &participant.http_addr,
+                &(participant.http_port as i32),
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn add_participant(
+    db_name: &str,
+    participant: NewCoopDatabaseParticipant,
+    config: DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let conn = get_db_conn(&config, db_name)?;
+
+    let NewCoopDatabaseParticipant {
+        alias,
+        ip4addr,
+        db_port,
+        http_addr,
+        http_port,
+        id,
+    } = participant;
+
+    let db_host_id = match id {
+        Some(id) => parse_guid(&id)?,
+        None => parse_guid(defaults::EMPTY_GUID)?,
+    };
+
+    let is_added: bool = if has_participant(db_name, &alias, config)? {
+        false
+    } else {
+        let participant = CoopDatabaseParticipant {
+            internal_id: GUID::rand(),
+            alias,
+            ip4addr,
+            ip6addr: String::from(""),
+            db_port,
+            contract_status: ContractStatus::NotSent,
+            accepted_contract_version: parse_guid(defaults::EMPTY_GUID)?,
+            id: db_host_id,
+            token: Vec::new(),
+            http_addr,
+            http_port,
+            protocol_version: 0,
+        };
+        save_participant(participant, conn)?;
+        true
+    };
+
+    Ok(is_added)
+}
+
+pub fn get_participant_by_internal_id(
+    db_name: &str,
+    internal_id: &str,
+    config: &DbiConfigMySql,
+) -> Result<CoopDatabaseParticipant, RcdDbError> {
+    let mut conn = get_participant_conn(config, db_name)?;
+    let cmd = format!(
+        "SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE INTERNAL_PARTICIPANT_ID = ?;"
+    );
+
+    let rows = conn.exec::<Row, _, _>(&cmd, (&internal_id,))?;
+    match rows.first() {
+        Some(row) => row_to_participant(row),
+        None => Err(RcdDbError::General(format!(
+            "participant {internal_id} not found in {db_name}"
+        ))),
+    }
+}
+
+pub fn get_participant_by_id(
+    db_name: &str,
+    id: &str,
+    config: DbiConfigMySql,
+) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+    let mut conn = get_participant_conn(&config, db_name)?;
+    let cmd =
+        format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE PARTICIPANT_ID = ?;");
+
+    let rows = conn.exec::<Row, _, _>(&cmd, (&id,))?;
+    rows.first().map(row_to_participant).transpose()
+}
+
+pub fn get_participant_by_alias(
+    db_name: &str,
+    alias: &str,
+    config: DbiConfigMySql,
+) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+    let mut conn = get_participant_conn(&config, db_name)?;
+    let cmd = format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE ALIAS = ?;");
+
+    let rows = conn.exec::<Row, _, _>(&cmd, (&alias,))?;
+    rows.first().map(row_to_participant).transpose()
+}
+
+pub fn get_participants_for_database(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<ParticipantStatus>, RcdDbError> {
+    let mut result: Vec<ParticipantStatus> = Vec::new();
+
+    let mut conn = get_participant_conn(config, db_name)?;
+
+    // if the table doesn't exist, we should return an error here
+    if !has_table("COOP_PARTICIPANT", &mut conn)? {
+        return Err(RcdDbError::TableNotFoundInDatabase(
+            "COOP_PARTICIPANT".to_string(),
+            db_name.to_string(),
+        ));
+    }
+
+    let cmd = format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT");
+
+    for row in conn.query::<Row, _>(&cmd)? {
+        let participant = row_to_participant(&row)?;
+
+        let p = Participant {
+            participant_guid: participant.id.to_string(),
+            alias: participant.alias,
+            ip4_address: participant.ip4addr,
+            ip6_address: participant.ip6addr,
+            database_port_number: participant.db_port,
+            token: Vec::new(),
+            internal_participant_guid: participant.internal_id.to_string(),
+            http_addr: participant.http_addr,
+            http_port: participant.http_port as u32,
+        };
+
+        let ps = ParticipantStatus {
+            participant: Some(p),
+            contract_status: ContractStatus::to_u32(participant.contract_status),
+        };
+
+        result.push(ps);
+    }
+
+    Ok(result)
+}
+
+pub fn get_participants_for_table(
+    db_name: &str,
+    table_name: &str,
+    config: DbiConfigMySql,
+) -> Result<Vec<CoopDatabaseParticipantData>, RcdDbError> {
+    let mut conn = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut conn)?;
+
+    let mut result: Vec<CoopDatabaseParticipantData> = Vec::new();
+
+    let mut cmd = String::from(
+        "
+        SELECT DISTINCT
+            INTERNAL_PARTICIPANT_ID
+        FROM
+            :table_name
+        ;",
+    );
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let participant_ids: Vec<String> = conn
+        .query::<Row, _>(&cmd)?
+        .iter()
+        .map(|row| get_value(row, 0))
+        .collect::<Result<_, _>>()?;
+
+    let db_participants: Vec<CoopDatabaseParticipant> = participant_ids
+        .iter()
+        .map(|pid| get_participant_by_internal_id(db_name, pid, &config))
+        .collect::<Result<_, _>>()?;
+
+    for p in &db_participants {
+        let mut cmd = String::from(
+            "
+            SELECT
+                ROW_ID,
+                HASH,
+                COALESCE(IS_OUT_OF_SYNC, 0),
+                COALESCE(IS_REMOTE_DELETED, 0)
+            FROM
+                :table_name
+            WHERE
+                INTERNAL_PARTICIPANT_ID = ?
+            ;",
+        );
+        cmd = cmd.replace(":table_name", &metadata_table_name);
+
+        let row_data_results: Vec<CoopDatabaseParticipantRow> = conn
+            .exec::<Row, _, _>(&cmd, (&p.internal_id.to_string(),))?
+            .iter()
+            .map(|row| {
+                Ok(CoopDatabaseParticipantRow {
+                    row_id: get_value::<i32>(row, 0)? as u32,
+                    hash: get_value(row, 1)?,
+                    is_out_of_sync: get_value::<i32>(row, 2)? > 0,
+                    is_remote_deleted: get_value::<i32>(row, 3)? > 0,
+                })
+            })
+            .collect::<Result<_, RcdDbError>>()?;
+
+        let participant_data = CoopDatabaseParticipantData {
+            participant: p.clone(),
+            db_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            row_data: row_data_results,
+        };
+
+        result.push(participant_data);
+    }
+
+    Ok(result)
+}
+
+pub fn has_participant_at_conn(alias: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let mut cmd =
+        String::from("SELECT COUNT(*) TOTALCOUNT FROM COOP_PARTICIPANT WHERE ALIAS = ':alias'");
+    cmd = cmd.replace(":alias", alias);
+    has_any_rows(cmd, conn)
+}
+
+pub fn has_participant(
+    db_name: &str,
+    alias: &str,
+    config: DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_db_conn(&config, db_name)?;
+    has_participant_at_conn(alias, &mut conn)
+}
+
+pub fn update_participant_protocol_version(
+    db_name: &str,
+    alias: &str,
+    protocol_version: u32,
+    config: DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_participant_conn(&config, db_name)?;
+    let cmd = "UPDATE COOP_PARTICIPANT SET PROTOCOL_VERSION = ? WHERE ALIAS = ?";
+
+    conn.exec_drop(cmd, (&(protocol_version as i32), &alias))?;
+
+    Ok(conn.affected_rows() > 0)
+}
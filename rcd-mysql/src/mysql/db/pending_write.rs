@@ -0,0 +1,138 @@
+use crate::mysql::{execute_write, get_db_conn, get_value, has_table, sql_text};
+use mysql::{prelude::Queryable, Conn, Row};
+use rcd_common::db::DbiConfigMySql;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::PendingWrite;
+use stdext::function_name;
+use tracing::trace;
+
+fn create_pending_writes_table_if_not_exists(conn: &mut Conn) -> Result<(), RcdDbError> {
+    if !has_table("COOP_PENDING_WRITES", conn)? {
+        let cmd = sql_text::Coop::text_create_pending_writes_table();
+        execute_write(conn, &cmd)?;
+    }
+
+    Ok(())
+}
+
+pub fn add_pending_write_at_host(
+    db_name: &str,
+    internal_participant_id: &str,
+    pending_write: &PendingWrite,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut conn)?;
+
+    let cmd = "
+        INSERT INTO COOP_PENDING_WRITES
+        (
+            TABLE_NAME,
+            INTERNAL_PARTICIPANT_ID,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            ?
+        )
+    ;";
+
+    conn.exec_drop(
+        cmd,
+        (
+            &pending_write.table_name,
+            &internal_participant_id,
+            &pending_write.participant_alias,
+            &(pending_write.pending_action_id as i32),
+            &pending_write.statement,
+            &pending_write.where_clause,
+            &pending_write.action,
+            &pending_write.requested_ts_utc,
+        ),
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn remove_pending_write_at_host(
+    db_name: &str,
+    table_name: &str,
+    internal_participant_id: &str,
+    pending_action_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut conn)?;
+
+    let cmd = "
+        DELETE FROM COOP_PENDING_WRITES
+        WHERE
+            TABLE_NAME = ?
+        AND
+            INTERNAL_PARTICIPANT_ID = ?
+        AND
+            PENDING_ACTION_ID = ?
+    ;";
+
+    conn.exec_drop(
+        cmd,
+        (
+            &table_name,
+            &internal_participant_id,
+            &(pending_action_id as i32),
+        ),
+    )?;
+    let rows = conn.affected_rows();
+
+    trace!("[{}]: rows affected: {rows:?}", function_name!());
+
+    Ok(rows > 0)
+}
+
+pub fn get_pending_writes_at_host(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<PendingWrite>, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut conn)?;
+
+    let cmd = "
+        SELECT
+            TABLE_NAME,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        FROM
+            COOP_PENDING_WRITES
+    ;";
+
+    conn.query::<Row, _>(cmd)?
+        .iter()
+        .map(|row| {
+            Ok(PendingWrite {
+                table_name: get_value(row, 0)?,
+                participant_alias: get_value(row, 1)?,
+                pending_action_id: get_value::<i32>(row, 2)? as u32,
+                statement: get_value(row, 3)?,
+                where_clause: get_value::<Option<String>>(row, 4)?.unwrap_or_default(),
+                action: get_value(row, 5)?,
+                requested_ts_utc: get_value(row, 6)?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,527 @@
+use super::{
+    execute_read_at_participant, execute_read_on_connection_for_row, execute_write,
+    get_data_string, get_db_conn, get_scalar_as_string, get_scalar_as_u64, get_table_col_names,
+    get_table_col_names_with_data_type_as_string, has_table, is_string_type, sql_text,
+    value_as_string, BINARY_CHARACTER_SET,
+};
+use chrono::Utc;
+use mysql::{prelude::Queryable, Conn, Value};
+use rcd_common::crypt::{self, RowValue};
+use rcd_common::db::{
+    get_data_log_table_name, get_data_queue_table_name, get_metadata_table_name, DbiConfigMySql,
+    PartialDataResult, RehashedRow,
+};
+use rcd_common::defaults;
+use rcd_enum::column_type::ColumnType;
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{Contract, PendingStatement, TableSchema};
+use stdext::function_name;
+use tracing::{trace, warn};
+
+pub mod delete;
+pub mod insert;
+pub mod update;
+
+pub fn accept_pending_action_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut action_result = PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: None,
+        affected_rows: Vec::new(),
+    };
+
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+    let mut cmd = String::from("SELECT STATEMENT FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let sql_update_statement = get_scalar_as_string(cmd, &mut conn)?;
+    let mut cmd = String::from("SELECT WHERE_CLAUSE FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+    let where_clause = get_scalar_as_string(cmd, &mut conn)?;
+
+    cmd = String::from("SELECT ACTION FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let action = get_scalar_as_string(cmd, &mut conn)?;
+
+    if action == "UPDATE" {
+        action_result = update::handle_update_pending_action(
+            db_name,
+            table_name,
+            &sql_update_statement,
+            &where_clause,
+            row_id,
+            config,
+        )?;
+    }
+
+    if action == "DELETE" {
+        action_result = delete::handle_delete_pending_action(
+            db_name,
+            table_name,
+            &sql_update_statement,
+            &where_clause,
+            row_id,
+            config,
+        )?;
+    }
+
+    Ok(action_result)
+}
+
+/// Removes the action from the table's queue without running it
+pub fn reject_pending_action_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+
+    let mut cmd = String::from("SELECT ACTION FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let action = match get_scalar_as_string(cmd, &mut conn)?.as_str() {
+        "UPDATE" => Some(PartialDataResultAction::Update),
+        "DELETE" => Some(PartialDataResultAction::Delete),
+        _ => None,
+    };
+
+    let mut cmd = String::from("DELETE FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let total_rows = if action.is_some() {
+        execute_write(&mut conn, &cmd)?
+    } else {
+        0
+    };
+
+    trace!("[{}]: total rows rejected: {total_rows}", function_name!());
+
+    Ok(PartialDataResult {
+        is_successful: total_rows > 0,
+        row_id,
+        data_hash: None,
+        partial_data_status: None,
+        action,
+        affected_rows: Vec::new(),
+    })
+}
+
+pub fn get_data_hash_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<u64, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+    let mut cmd = String::from("SELECT HASH FROM :metadata WHERE ROW_ID = :row_id");
+    cmd = cmd.replace(":metadata", &metadata_table_name);
+    cmd = cmd.replace(":row_id", &row_id.to_string());
+
+    get_scalar_as_u64(cmd, &mut conn)?.ok_or_else(|| {
+        RcdDbError::General(format!(
+            "no hash is saved for row {row_id} of {table_name} in {db_name}"
+        ))
+    })
+}
+
+/// Recomputes the hash of every row tracked in the metadata tables of the partial database,
+/// saving the new hashes and returning them so they can be sent to the host
+pub fn rehash_partial_database(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<RehashedRow>, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+
+    // table names keep their case on some platforms, so match the suffix case-insensitively
+    let suffix = defaults::METADATA_TABLE_SUFFIX;
+    let cmd = "SELECT table_name FROM information_schema.tables WHERE table_schema = DATABASE() AND table_type = 'BASE TABLE'";
+    let table_names: Vec<String> = conn
+        .query::<String, _>(cmd)?
+        .into_iter()
+        .filter(|name| name.to_uppercase().ends_with(suffix))
+        .map(|name| name[..name.len() - suffix.len()].to_string())
+        .collect();
+
+    let mut rehashed_rows: Vec<RehashedRow> = Vec::new();
+
+    for table_name in &table_names {
+        let metadata_table_name = get_metadata_table_name(table_name);
+
+        let cmd = format!("SELECT ROW_ID FROM {metadata_table_name}");
+        let row_ids: Vec<u32> = conn.query(&cmd)?;
+
+        let cmd = format!("UPDATE {metadata_table_name} SET HASH = ? WHERE ROW_ID = ?");
+
+        for row_id in row_ids {
+            if let Some(hash) = get_row_hash(table_name, row_id, &mut conn)? {
+                conn.exec_drop(&cmd, (&hash.to_ne_bytes().to_vec(), &(row_id as i32)))?;
+
+                rehashed_rows.push(RehashedRow {
+                    table_name: table_name.clone(),
+                    row_id,
+                    hash,
+                });
+            }
+        }
+    }
+
+    set_row_hash_version(&mut conn)?;
+
+    Ok(rehashed_rows)
+}
+
+/// Records that the hashes saved in the partial database were calculated with the current
+/// `ROW_HASH_VERSION`
+fn set_row_hash_version(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(sql_text::Coop::text_create_row_hash_version_table())?;
+    conn.query_drop("DELETE FROM COOP_ROW_HASH_VERSION;")?;
+    conn.exec_drop(
+        "INSERT INTO COOP_ROW_HASH_VERSION (VERSION) VALUES (?);",
+        (crypt::ROW_HASH_VERSION,),
+    )?;
+
+    Ok(())
+}
+
+/// Returns the `ROW_HASH_VERSION` the hashes saved in the partial database were calculated with,
+/// or `None` if it was created before the version was recorded
+pub fn get_row_hash_version(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Option<u8>, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+
+    if !has_table("COOP_ROW_HASH_VERSION", &mut conn)? {
+        return Ok(None);
+    }
+
+    Ok(conn.query_first("SELECT VERSION FROM COOP_ROW_HASH_VERSION")?)
+}
+
+/// Hashes the values currently stored in the row, or `None` if the row doesn't exist
+pub fn get_row_hash(
+    table_name: &str,
+    row_id: u32,
+    conn: &mut Conn,
+) -> Result<Option<u64>, RcdDbError> {
+    let col_names = get_table_col_names(table_name.to_string(), conn)?;
+    let cmd = format!(
+        "SELECT {} FROM {table_name} WHERE ROWID = {row_id}",
+        col_names.join(",")
+    );
+
+    let rows = read_as_row_values(&cmd, conn)?;
+    Ok(rows
+        .first()
+        .map(|row_values| crypt::calculate_hash_for_row(row_values)))
+}
+
+/// Hashes the values of every row in the table the same way `get_row_hash` does, so that a
+/// host's own copy of a table can be compared against the hashes a participant has sent it
+pub fn get_row_hashes(table_name: &str, conn: &mut Conn) -> Result<Vec<u64>, RcdDbError> {
+    let col_names = get_table_col_names(table_name.to_string(), conn)?;
+    let cmd = format!("SELECT {} FROM {table_name}", col_names.join(","));
+
+    let rows = read_as_row_values(&cmd, conn)?;
+    Ok(rows
+        .iter()
+        .map(|row_values| crypt::calculate_hash_for_row(row_values))
+        .collect())
+}
+
+/// Reads the rows the statement returns as the values that `calculate_hash_for_row` hashes,
+/// keeping the raw bytes of binary columns
+fn read_as_row_values(cmd: &str, conn: &mut Conn) -> Result<Vec<Vec<RowValue>>, RcdDbError> {
+    let mut result = conn.query_iter(cmd)?;
+
+    let is_binary: Vec<bool> = result
+        .columns()
+        .as_ref()
+        .iter()
+        .map(|c| c.character_set() == BINARY_CHARACTER_SET && is_string_type(c.column_type()))
+        .collect();
+
+    let mut rows: Vec<Vec<RowValue>> = Vec::new();
+
+    for row in result.by_ref() {
+        let row = row?;
+        let values = is_binary
+            .iter()
+            .enumerate()
+            .map(|(i, is_binary)| match row.as_ref(i) {
+                None | Some(Value::NULL) => RowValue::Null,
+                Some(Value::Bytes(bytes)) if *is_binary => RowValue::Blob(bytes.clone()),
+                Some(value) => RowValue::Text(value_as_string(value).unwrap_or_default()),
+            })
+            .collect();
+
+        rows.push(values);
+    }
+
+    Ok(rows)
+}
+
+pub fn get_pending_actions(
+    db_name: &str,
+    table_name: &str,
+    action: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<PendingStatement>, RcdDbError> {
+    let update_queue = get_data_queue_table_name(table_name);
+
+    let mut pending_statements: Vec<PendingStatement> = Vec::new();
+
+    // nothing has been queued for the table yet
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    if !has_table(&update_queue, &mut conn)? {
+        return Ok(pending_statements);
+    }
+
+    let mut cmd = String::from(
+        "
+        SELECT
+            ID,
+            STATEMENT,
+            REQUESTED_TS_UTC,
+            HOST_ID
+        FROM
+            :table
+        WHERE
+            ACTION = ':action'
+        ;",
+    );
+    cmd = cmd.replace(":table", &update_queue);
+    cmd = cmd.replace(":action", action);
+
+    let pending_rows = execute_read_at_participant(db_name, &cmd, config)?;
+
+    for row in &pending_rows.rows {
+        let mut rid: u32 = 0;
+        let mut statement: String = String::from("");
+        let mut ts: String = String::from("");
+        let mut host_id: String = String::from("");
+
+        // column names come back in the case the server reports them in, so compare without case
+        for val in &row.vals {
+            if val.col.name.eq_ignore_ascii_case("ID") {
+                let id = get_data_string(val);
+                rid = id.parse::<u32>().map_err(|_| {
+                    RcdDbError::General(format!("`{id}` is not a valid id in {update_queue}"))
+                })?;
+            }
+
+            if val.col.name.eq_ignore_ascii_case("STATEMENT") {
+                statement = get_data_string(val);
+            }
+
+            if val.col.name.eq_ignore_ascii_case("REQUESTED_TS_UTC") {
+                ts = get_data_string(val);
+            }
+
+            if val.col.name.eq_ignore_ascii_case("HOST_ID") {
+                host_id = get_data_string(val);
+            }
+        }
+
+        let ps = PendingStatement {
+            row_id: rid,
+            statement,
+            requested_ts_utc: ts,
+            host_id,
+            action: action.to_string(),
+        };
+
+        pending_statements.push(ps);
+    }
+
+    Ok(pending_statements)
+}
+
+pub fn get_row_from_partial_database(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<rcdproto::rcdp::Row, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+
+    // the ROWID column is ours, not part of the table in the contract, so leave it out
+    let col_names = get_table_col_names(table_name.to_string(), &mut conn)?.join(",");
+
+    let mut cmd = String::from("SELECT :col_names from :table_name WHERE ROWID = :rid");
+    cmd = cmd.replace(":col_names", &col_names);
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    trace!("[{}]: {config:?}", function_name!());
+
+    execute_read_on_connection_for_row(db_name, table_name, row_id, cmd, &mut conn)
+}
+
+pub fn create_partial_database_from_contract(
+    contract: &Contract,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    trace!("{config:?}");
+    trace!("{contract:?}");
+
+    let schema = contract
+        .schema
+        .as_ref()
+        .ok_or_else(|| RcdDbError::General(String::from("the contract has no schema")))?;
+
+    let mut conn = create_partial_database(&schema.database_name, config)?;
+
+    for table in &schema.tables {
+        create_table_from_schema(table, &mut conn)?;
+    }
+
+    set_row_hash_version(&mut conn)?;
+
+    Ok(true)
+}
+
+pub fn create_partial_database(db_name: &str, config: &DbiConfigMySql) -> Result<Conn, RcdDbError> {
+    get_db_conn(config, &get_partial_db_name(db_name))
+}
+
+pub fn read_row_ids_from_part_db(
+    db_name: &str,
+    table_name: &str,
+    where_clause: &str,
+    config: &DbiConfigMySql,
+) -> Result<Vec<u32>, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let mut cmd = String::from("SELECT ROWID FROM :table_name WHERE :where_clause");
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":where_clause", where_clause);
+    Ok(conn.query(&cmd)?)
+}
+
+/// Returns the name of the schema holding the partial database for the specified database
+pub fn get_partial_db_name(db_name: &str) -> String {
+    let mut db_part_name = db_name.replace(".dbpart", "");
+    db_part_name = db_part_name.replace(".db", "");
+    format!("{}{}", db_part_name, String::from(".dbpart"))
+}
+
+pub fn get_partial_db_connection(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Conn, RcdDbError> {
+    get_db_conn(config, &get_partial_db_name(db_name))
+}
+
+/// Creates the table from the contract in the partial database. Sqlite gives every table a ROWID;
+/// MySQL does not, so we add one ourselves to identify the rows the host references.
+fn create_table_from_schema(table_schema: &TableSchema, conn: &mut Conn) -> Result<(), RcdDbError> {
+    trace!("{table_schema:?}");
+
+    let table_name = table_schema.table_name.clone();
+
+    if table_name.contains("_COOP_") {
+        warn!("create_table_from_schema - skipping table: {table_name:?}");
+        return Ok(());
+    }
+
+    let mut cmd = String::from("CREATE TABLE IF NOT EXISTS :tablename ");
+    cmd = cmd.replace(":tablename", &table_name);
+    cmd += " ( ROWID INT NOT NULL AUTO_INCREMENT UNIQUE, ";
+
+    let last_column = table_schema
+        .columns
+        .last()
+        .map(|column| column.column_name.clone())
+        .unwrap_or_default();
+
+    for column in &table_schema.columns {
+        let col_name = column.column_name.clone();
+        let col_type = ColumnType::from_u32(column.column_type).data_type_as_string_mysql();
+        let mut col_length = String::from("");
+
+        if column.column_length > 0 {
+            col_length += " ( ";
+            col_length = col_length + &column.column_length.to_string();
+            col_length += " ) ";
+        }
+
+        let mut col_nullable = String::from("");
+
+        if !column.is_nullable {
+            col_nullable = String::from("NOT NULL");
+        }
+
+        let col_statement: String = if last_column == column.column_name {
+            format!(" {col_name} {col_type} {col_length} {col_nullable} ")
+        } else {
+            format!(" {col_name} {col_type} {col_length} {col_nullable} , ")
+        };
+
+        cmd = cmd + &col_statement;
+    }
+    cmd += " ) ";
+
+    trace!("{cmd:?}");
+
+    execute_write(conn, &cmd)?;
+    Ok(())
+}
+
+fn add_record_to_log_table(
+    db_name: &str,
+    table_name: &str,
+    where_clause: &str,
+    action: &str,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let data_log_table = get_data_log_table_name(table_name);
+    let conn = &mut get_partial_db_connection(db_name, config)?;
+
+    if !has_table(&data_log_table, conn)? {
+        let mut cmd = sql_text::Coop::text_create_data_log_table();
+        let table_col_names =
+            get_table_col_names_with_data_type_as_string(db_name, table_name, config)?;
+        cmd = cmd.replace(":column_list", &table_col_names);
+        cmd = cmd.replace(":table_name", &data_log_table);
+
+        execute_write(conn, &cmd)?;
+    }
+
+    // copy every row that we're about to change into the data_log_table, letting MySQL
+    // carry the values over with their types instead of round tripping them through strings
+    let col_names = get_table_col_names(table_name.to_string(), conn)?.join(",");
+
+    let mut cmd = String::from(
+        "INSERT INTO :data_log_table ( :cols, ROW_ID, ACTION, TS_UTC ) SELECT :cols, ROWID, ':action', ':ts_utc' FROM :table_name WHERE :where_clause",
+    );
+    cmd = cmd.replace(":data_log_table", &data_log_table);
+    cmd = cmd.replace(":cols", &col_names);
+    cmd = cmd.replace(":action", action);
+    cmd = cmd.replace(":ts_utc", &Utc::now().to_string());
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":where_clause", where_clause);
+
+    trace!("{cmd:?}");
+
+    execute_write(conn, &cmd)?;
+
+    Ok(true)
+}
@@ -0,0 +1,111 @@
+use crate::mysql::{
+    db_part::{add_record_to_log_table, get_partial_db_connection, get_row_hash},
+    execute_write, has_table,
+    rcd_db::get_data_log_table_status,
+    sql_text,
+};
+use mysql::{prelude::Queryable, Conn, TxOpts};
+use rcd_common::db::*;
+use rcd_common::db::{AffectedRow, DbiConfigMySql, PartialDataResult};
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rcd_error::rcd_db_error::RcdDbError;
+use tracing::{debug, warn};
+
+pub fn insert_data_into_partial_db(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+
+    // the ROWID column is AUTO_INCREMENT, so the last insert id is the value it was just given
+    let (total_rows, row_id) = insert_single_row(&mut conn, cmd)?;
+
+    if total_rows != 1 {
+        warn!(
+            "the insert into {table_name} would have added {total_rows} rows, it was rolled back"
+        );
+        return Ok(failed_insert_result());
+    }
+
+    // we need to hash the values of this row as they were stored
+    let hash_value = get_row_hash(table_name, row_id, &mut conn)?.unwrap_or_default();
+
+    // we need to determine if there is a metadata table for this table or not
+    // and if there is not one, create it
+    // then we need to save the data hash along with the row id
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    if !has_table(&metadata_table_name, &mut conn)? {
+        //  need to create table
+        let mut cmd = sql_text::Coop::text_create_metadata_table();
+        cmd = cmd.replace(":table_name", &metadata_table_name);
+        execute_write(&mut conn, &cmd)?;
+    }
+
+    let mut cmd = sql_text::Coop::text_insert_row_metadata_table();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    debug!("{row_id:?}");
+    debug!("{hash_value:?}");
+
+    conn.exec_drop(
+        &cmd,
+        (
+            &(row_id as i32),
+            &hash_value.to_ne_bytes().to_vec(),
+            &None::<String>,
+        ),
+    )?;
+
+    // there's nothing to keep from before an insert, so the data log gets the row as it was inserted
+    if get_data_log_table_status(db_name, table_name, config)? {
+        let where_clause = format!("ROWID = {row_id}");
+        add_record_to_log_table(db_name, table_name, &where_clause, "INSERT", config)?;
+    }
+
+    Ok(PartialDataResult {
+        is_successful: true,
+        row_id,
+        data_hash: Some(hash_value),
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Insert),
+        affected_rows: vec![AffectedRow {
+            row_id,
+            data_hash: Some(hash_value),
+        }],
+    })
+}
+
+/// Runs the INSERT in a transaction that is only committed if it added exactly one row. We
+/// track a row's metadata and tell the host about it one row at a time, so the rows of a
+/// multi-row INSERT would otherwise be left behind without a reference at the host.
+/// Returns the number of rows the INSERT added and the id of the last one
+fn insert_single_row(conn: &mut Conn, cmd: &str) -> Result<(u64, u32), RcdDbError> {
+    let mut tx = conn.start_transaction(TxOpts::default())?;
+    tx.query_drop(cmd)?;
+
+    let total_rows = tx.affected_rows();
+    let row_id = tx.last_insert_id().unwrap_or_default() as u32;
+
+    if total_rows == 1 {
+        tx.commit()?;
+    } else {
+        tx.rollback()?;
+    }
+
+    Ok((total_rows, row_id))
+}
+
+/// The result of an INSERT that was rolled back or could not be run
+fn failed_insert_result() -> PartialDataResult {
+    PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Insert),
+        affected_rows: Vec::new(),
+    }
+}
@@ -0,0 +1,281 @@
+use crate::mysql::{
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::{get_data_log_table_status, get_updates_from_host_behavior},
+    sql_text,
+};
+
+use super::{add_record_to_log_table, get_partial_db_connection, get_row_hash};
+use chrono::Utc;
+use mysql::prelude::Queryable;
+use rcd_common::{
+    db::{get_data_queue_table_name, AffectedRow, DbiConfigMySql, PartialDataResult},
+    defaults,
+};
+use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
+use rcd_enum::{
+    partial_data_result_action::PartialDataResultAction, partial_data_status::PartialDataStatus,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+use stdext::function_name;
+use tracing::warn;
+
+pub fn update_data_into_partial_db_queue(
+    db_name: &str,
+    table_name: &str,
+    update_statement: &str,
+    where_clause: &str,
+    host_id: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let queue_log_table = get_data_queue_table_name(table_name);
+    let conn = &mut get_partial_db_connection(db_name, config)?;
+
+    if !has_table(&queue_log_table, conn)? {
+        let mut cmd = sql_text::Coop::text_create_data_queue_table();
+        cmd = cmd.replace(":table_name", &queue_log_table);
+        execute_write(conn, &cmd)?;
+    }
+
+    let mut cmd = String::from("SELECT MAX(ID) FROM :table_name");
+    cmd = cmd.replace(":table_name", &queue_log_table);
+
+    let max_id = get_scalar_as_u32(cmd, conn)?;
+    let next_id = max_id + 1;
+
+    cmd = String::from(
+        "
+        INSERT INTO :table_name
+        (
+            ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            REQUESTED_TS_UTC,
+            HOST_ID,
+            ACTION
+        )
+        VALUES
+        (
+            ?,
+            ?,
+            ?,
+            ?,
+            ?,
+            'UPDATE'
+        )
+    ;",
+    );
+
+    cmd = cmd.replace(":table_name", &queue_log_table);
+
+    conn.exec_drop(
+        &cmd,
+        (
+            &(next_id as i32),
+            &update_statement,
+            &where_clause,
+            &Utc::now().to_string(),
+            &host_id,
+        ),
+    )?;
+    let rows_inserted = conn.affected_rows();
+
+    Ok(PartialDataResult {
+        is_successful: rows_inserted > 0,
+        row_id: next_id,
+        data_hash: None,
+        partial_data_status: Some(PartialDataStatus::to_u32(PartialDataStatus::Pending)),
+        action: Some(PartialDataResultAction::Update),
+        affected_rows: Vec::new(),
+    })
+}
+
+pub fn update_data_into_partial_db(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    host_id: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let behavior = get_updates_from_host_behavior(db_name, table_name, config)?;
+    match behavior {
+        UpdatesFromHostBehavior::AllowOverwrite => {
+            execute_update_and_log_if_enabled(db_name, table_name, cmd, where_clause, config)
+        }
+        UpdatesFromHostBehavior::QueueForReview | UpdatesFromHostBehavior::QueueForReviewAndLog => {
+            update_data_into_partial_db_queue(
+                db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                config,
+            )
+        }
+        UpdatesFromHostBehavior::OverwriteWithLog => {
+            execute_update_with_log(db_name, table_name, cmd, where_clause, config)
+        }
+        UpdatesFromHostBehavior::Unknown | UpdatesFromHostBehavior::Ignore => {
+            warn!(
+                "[{}]: not applying update to {db_name}.{table_name}, behavior is {behavior:?}",
+                function_name!()
+            );
+            Ok(PartialDataResult {
+                is_successful: false,
+                row_id: 0,
+                data_hash: None,
+                partial_data_status: Some(PartialDataStatus::to_u32(PartialDataStatus::Ignored)),
+                action: Some(PartialDataResultAction::Update),
+                affected_rows: Vec::new(),
+            })
+        }
+    }
+}
+
+fn execute_update_overwrite(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let original_cmd = cmd;
+    let mut cmd;
+    cmd = String::from("SELECT ROWID FROM :table_name WHERE :where_clause")
+        .replace(":table_name", table_name);
+
+    if !where_clause.is_empty() {
+        cmd = cmd.replace(":where_clause", where_clause);
+    } else {
+        cmd = cmd.replace("WHERE", "");
+        cmd = cmd.replace(":where_clause", "");
+    }
+
+    // we need to determine the row_ids that we're going to update because we're going to need to update
+    // the data hashes for them
+    let conn = &mut get_partial_db_connection(db_name, config)?;
+
+    let row_ids: Vec<u32> = conn.query(&cmd)?;
+
+    let total_rows = execute_write(conn, original_cmd)?;
+
+    if total_rows != row_ids.len() {
+        return Err(RcdDbError::General(String::from(
+            "the update statement did not match the expected count of affected rows",
+        )));
+    }
+
+    // once we have the row ids, then we will need to get the hash of the rows after they've been updated.
+    let mut row_hashes: Vec<(u32, u64)> = Vec::new();
+
+    for id in &row_ids {
+        if let Some(hash_value) = get_row_hash(table_name, *id, conn)? {
+            row_hashes.push((*id, hash_value));
+        }
+    }
+
+    // now that we have the row hashes, we should save them back to the table
+    let metadata_table_name = format!("{}{}", table_name, defaults::METADATA_TABLE_SUFFIX);
+    let mut cmd = String::from("UPDATE :table_name SET HASH = ? WHERE ROW_ID = ?");
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    for row in &row_hashes {
+        conn.exec_drop(&cmd, (&row.1.to_ne_bytes().to_vec(), &(row.0 as i32)))?;
+    }
+
+    let (row_id, data_hash) = match row_hashes.first() {
+        Some(row_data) => (row_data.0, Some(row_data.1)),
+        None => (0, None),
+    };
+
+    Ok(PartialDataResult {
+        is_successful: true,
+        row_id,
+        data_hash,
+        partial_data_status: Some(1),
+        action: Some(PartialDataResultAction::Update),
+        affected_rows: row_hashes
+            .iter()
+            .map(|row| AffectedRow {
+                row_id: row.0,
+                data_hash: Some(row.1),
+            })
+            .collect(),
+    })
+}
+
+fn execute_update_with_log(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    add_record_to_log_table(db_name, table_name, where_clause, "UPDATE", config)?;
+    execute_update_overwrite(db_name, table_name, cmd, where_clause, config)
+}
+
+/// Overwrites the rows, logging them first if the data log table is switched on for the table
+fn execute_update_and_log_if_enabled(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    if get_data_log_table_status(db_name, table_name, config)? {
+        execute_update_with_log(db_name, table_name, cmd, where_clause, config)
+    } else {
+        execute_update_overwrite(db_name, table_name, cmd, where_clause, config)
+    }
+}
+
+pub fn handle_update_pending_action(
+    db_name: &str,
+    table_name: &str,
+    sql_update_statement: &str,
+    where_clause: &str,
+    row_id: u32,
+    config: &DbiConfigMySql,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut conn = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+
+    let behavior = get_updates_from_host_behavior(db_name, table_name, config)?;
+
+    let mut update_result = PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Update),
+        affected_rows: Vec::new(),
+    };
+
+    if behavior == UpdatesFromHostBehavior::QueueForReview {
+        update_result = execute_update_and_log_if_enabled(
+            db_name,
+            table_name,
+            sql_update_statement,
+            where_clause,
+            config,
+        )?;
+    } else if behavior == UpdatesFromHostBehavior::QueueForReviewAndLog {
+        update_result = execute_update_with_log(
+            db_name,
+            table_name,
+            sql_update_statement,
+            where_clause,
+            config,
+        )?;
+    }
+
+    if update_result.is_successful {
+        let mut cmd = String::from("DELETE FROM :table_name WHERE ID = :rid");
+        cmd = cmd.replace(":table_name", &queue_table_name);
+        cmd = cmd.replace(":rid", &row_id.to_string());
+        execute_write(&mut conn, &cmd)?;
+    }
+
+    Ok(update_result)
+}
@@ -0,0 +1,837 @@
+use super::{
+    add_column_if_missing, db_part::get_partial_db_name, get_conn, get_scalar_as_string,
+    get_scalar_as_u32, get_value, has_any_rows, has_schema, has_table, sql_text::Cds,
+};
+use crate::mysql::get_db_conn;
+use ::rcd_enum::rcd_database_type::RcdDatabaseType;
+use chrono::DateTime;
+use chrono::Utc;
+use guid_create::GUID;
+use mysql::{prelude::Queryable, Conn, Row};
+use rcd_common::crypt;
+use rcd_common::db::*;
+use rcd_common::defaults;
+use rcd_common::host_info::*;
+use rcd_enum::deletes_from_host_behavior::DeletesFromHostBehavior;
+use rcd_enum::deletes_to_host_behavior::DeletesToHostBehavior;
+use rcd_enum::host_status::HostStatus;
+use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
+use rcd_enum::updates_to_host_behavior::UpdatesToHostBehavior;
+use rcd_error::rcd_db_error::RcdDbError;
+use stdext::function_name;
+use tracing::instrument;
+use tracing::{info, trace, warn};
+
+pub mod contract;
+pub mod role;
+
+/// The columns of CDS_HOSTS in the order expected by `row_to_host`
+const CDS_HOSTS_COLUMNS: &str = "
+        HOST_ID,
+        HOST_NAME,
+        TOKEN,
+        IP4ADDRESS,
+        IP6ADDRESS,
+        PORT,
+        LAST_COMMUNICATION_UTC,
+        HTTP_ADDR,
+        HTTP_PORT,
+        HOST_STATUS,
+        PROTOCOL_VERSION";
+
+pub fn get_rcd_db_type(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<RcdDatabaseType, RcdDbError> {
+    if db_name == config.rcd_db_name {
+        return Ok(RcdDatabaseType::Rcd);
+    }
+
+    if db_name.contains("dbpart") {
+        return Ok(RcdDatabaseType::Partial);
+    }
+
+    let mut conn = get_conn(config)?;
+
+    if has_schema(&get_partial_db_name(db_name), &mut conn)? {
+        return Ok(RcdDatabaseType::Partial);
+    }
+
+    if has_schema(db_name, &mut conn)? {
+        return Ok(RcdDatabaseType::Host);
+    }
+
+    Ok(RcdDatabaseType::Unknown)
+}
+
+pub fn login_has_token(login: &str, config: &DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let mut cmd = String::from("SELECT COUNT(*) FROM CDS_USER_TOKENS WHERE USERNAME = ':login'");
+    cmd = cmd.replace(":login", login);
+    has_any_rows(cmd, &mut conn)
+}
+
+pub fn revoke_token(token: &str, config: &DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let mut cmd = String::from("DELETE FROM CDS_USER_TOKENS WHERE TOKEN = ':token'");
+    cmd = cmd.replace(":token", token);
+    Ok(execute_write_on_connection(&config.rcd_db_name, &cmd, config)? > 0)
+}
+
+pub fn verify_token(token: &str, config: &DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let mut cmd = String::from("SELECT COUNT(*) FROM CDS_USER_TOKENS WHERE TOKEN = ':token'");
+    cmd = cmd.replace(":token", token);
+    has_any_rows(cmd, &mut conn)
+}
+
+pub fn delete_expired_tokens(config: &DbiConfigMySql) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let now = Utc::now().to_rfc3339();
+
+    let mut cmd = String::from("DELETE FROM CDS_USER_TOKENS WHERE EXPIRATION_UTC < ':now'");
+    cmd = cmd.replace(":now", &now);
+
+    conn.query_drop(&cmd)?;
+    Ok(())
+}
+
+pub fn save_token(
+    login: &str,
+    token: &str,
+    expiration: DateTime<Utc>,
+    config: &DbiConfigMySql,
+) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = String::from(
+        "
+            INSERT INTO CDS_USER_TOKENS
+            (
+                USERNAME,
+                TOKEN,
+                ISSUED_UTC,
+                EXPIRATION_UTC
+            )
+            VALUES
+            (
+                ?,
+                ?,
+                ?,
+                ?
+            );",
+    );
+
+    let issued = Utc::now().to_rfc3339();
+    let expiration = expiration.to_rfc3339();
+
+    conn.exec_drop(&cmd, (&login, &token, &issued, &expiration))?;
+    Ok(())
+}
+
+/// Returns the value of the behavior column in CDS_CONTRACTS_TABLES for the table
+fn get_behavior(
+    behavior_column: &str,
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<u32, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let db_name = check_database_name_for_contract_format(db_name, &mut conn)?;
+    let mut cmd = String::from(
+        "
+        SELECT
+            :behavior_column
+        FROM
+            CDS_CONTRACTS_TABLES
+        WHERE
+            DATABASE_NAME = ':db_name'
+        AND
+            TABLE_NAME = ':table_name'
+        ;",
+    );
+    cmd = cmd.replace(":behavior_column", behavior_column);
+    cmd = cmd.replace(":db_name", &db_name);
+    cmd = cmd.replace(":table_name", table_name);
+
+    get_scalar_as_u32(cmd, &mut conn)
+}
+
+/// Sets the value of the behavior column in CDS_CONTRACTS_TABLES for the table
+fn change_behavior(
+    behavior_column: &str,
+    db_name: &str,
+    table_name: &str,
+    behavior: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let db_name = check_database_name_for_contract_format(db_name, &mut conn)?;
+
+    let mut cmd = String::from(
+        "
+        UPDATE CDS_CONTRACTS_TABLES
+        SET :behavior_column = ?
+        WHERE
+            DATABASE_NAME = ?
+        AND
+            TABLE_NAME = ?
+        ;",
+    );
+    cmd = cmd.replace(":behavior_column", behavior_column);
+
+    conn.exec_drop(&cmd, (&(behavior as i32), &db_name, &table_name))?;
+    let result = conn.affected_rows();
+
+    Ok(result > 0)
+}
+
+pub fn get_updates_to_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<UpdatesToHostBehavior, RcdDbError> {
+    let result = get_behavior("UPDATES_TO_HOST_BEHAVIOR", db_name, table_name, config)?;
+    Ok(UpdatesToHostBehavior::from_u32(result))
+}
+
+pub fn get_deletes_to_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<DeletesToHostBehavior, RcdDbError> {
+    let result = get_behavior("DELETES_TO_HOST_BEHAVIOR", db_name, table_name, config)?;
+    Ok(DeletesToHostBehavior::from_u32(result))
+}
+
+pub fn get_deletes_from_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<DeletesFromHostBehavior, RcdDbError> {
+    let result = get_behavior("DELETES_FROM_HOST_BEHAVIOR", db_name, table_name, config)?;
+    Ok(DeletesFromHostBehavior::from_u32(result))
+}
+
+pub fn get_updates_from_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<UpdatesFromHostBehavior, RcdDbError> {
+    let result = get_behavior("UPDATES_FROM_HOST_BEHAVIOR", db_name, table_name, config)?;
+    Ok(UpdatesFromHostBehavior::from_u32(result))
+}
+
+pub fn check_database_name_for_contract_format(
+    db_name: &str,
+    conn: &mut Conn,
+) -> Result<String, RcdDbError> {
+    let mut db_name = db_name.to_string();
+
+    let mut cmd =
+        String::from("SELECT COUNT(*) FROM CDS_CONTRACTS_TABLES WHERE DATABASE_NAME = ':db_name'");
+    cmd = cmd.replace(":db_name", &db_name);
+    if !has_any_rows(cmd, conn)? {
+        let message = format!(
+            "{}{}",
+            "WARNING: check_database_name_for_contract_format no database named: ", db_name
+        );
+        warn!("[{}]: {}", function_name!(), message);
+
+        if db_name.contains(".dbpart") {
+            let message = "check_database_name_for_contract_format: renaming database name to contract version of database";
+            info!("[{}]: {}", function_name!(), message);
+            db_name = db_name.replace(".dbpart", ".db");
+            let message = format!("New database name is: {db_name}");
+            info!("[{}]: {}", function_name!(), message);
+        }
+    }
+
+    Ok(db_name)
+}
+
+pub fn change_updates_from_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    behavior: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "UPDATES_FROM_HOST_BEHAVIOR",
+        db_name,
+        table_name,
+        behavior,
+        config,
+    )
+}
+
+pub fn change_deletes_from_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    behavior: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "DELETES_FROM_HOST_BEHAVIOR",
+        db_name,
+        table_name,
+        behavior,
+        config,
+    )
+}
+
+pub fn change_updates_to_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    behavior: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "UPDATES_TO_HOST_BEHAVIOR",
+        db_name,
+        table_name,
+        behavior,
+        config,
+    )
+}
+
+pub fn change_deletes_to_host_behavior(
+    db_name: &str,
+    table_name: &str,
+    behavior: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "DELETES_TO_HOST_BEHAVIOR",
+        db_name,
+        table_name,
+        behavior,
+        config,
+    )
+}
+
+/// Returns if changes to the table in the partial database are copied to its data log table
+pub fn get_data_log_table_status(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    Ok(get_behavior("USE_DATA_LOG_TABLE", db_name, table_name, config)? > 0)
+}
+
+pub fn set_data_log_table_status(
+    db_name: &str,
+    table_name: &str,
+    use_data_log: bool,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "USE_DATA_LOG_TABLE",
+        db_name,
+        table_name,
+        use_data_log as u32,
+        config,
+    )
+}
+
+/// Returns how many seconds an action can sit in the table's queue before it expires, or 0 if
+/// queued actions never expire
+pub fn get_pending_action_ttl(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<u32, RcdDbError> {
+    get_behavior("PENDING_ACTION_TTL_SECONDS", db_name, table_name, config)
+}
+
+pub fn set_pending_action_ttl(
+    db_name: &str,
+    table_name: &str,
+    ttl_seconds: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    change_behavior(
+        "PENDING_ACTION_TTL_SECONDS",
+        db_name,
+        table_name,
+        ttl_seconds,
+        config,
+    )
+}
+
+pub fn change_host_status_by_id(
+    host_id: &str,
+    status: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = String::from("UPDATE CDS_HOSTS SET HOST_STATUS = ? WHERE HOST_ID = ?");
+    conn.exec_drop(&cmd, (&(status as i32), &host_id))?;
+    let result = conn.affected_rows();
+
+    Ok(result > 0)
+}
+
+pub fn update_host_protocol_version(
+    host_id_or_name: &str,
+    protocol_version: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = String::from(
+        "UPDATE CDS_HOSTS SET PROTOCOL_VERSION = ? WHERE HOST_ID = ? OR HOST_NAME = ?",
+    );
+    conn.exec_drop(
+        &cmd,
+        (
+            &(protocol_version as i32),
+            &host_id_or_name,
+            &host_id_or_name,
+        ),
+    )?;
+    let result = conn.affected_rows();
+
+    Ok(result > 0)
+}
+
+pub fn change_host_status_by_name(
+    host_name: &str,
+    status: u32,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = String::from("UPDATE CDS_HOSTS SET HOST_STATUS = ? WHERE HOST_NAME = ?");
+    conn.exec_drop(&cmd, (&(status as i32), &host_name))?;
+    let result = conn.affected_rows();
+
+    Ok(result > 0)
+}
+
+/// Returns if the token matches the last token saved for the active host found by the specified column
+fn verify_host_by_column(
+    column: &str,
+    value: &str,
+    token: Vec<u8>,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let mut cmd = String::from("SELECT TOKEN FROM CDS_HOSTS WHERE :column = ? AND HOST_STATUS = 1");
+    cmd = cmd.replace(":column", column);
+
+    let returned_tokens: Vec<Vec<u8>> = conn
+        .exec::<Option<Vec<u8>>, _, _>(&cmd, (value,))?
+        .into_iter()
+        .map(|token| token.unwrap_or_default())
+        .collect();
+
+    Ok(match returned_tokens.last() {
+        Some(returned_token) => do_vecs_match(&token, returned_token),
+        None => false,
+    })
+}
+
+pub fn verify_host_by_id(
+    host_id: &str,
+    token: Vec<u8>,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    trace!("host_id: {host_id}");
+    verify_host_by_column("HOST_ID", host_id, token, config)
+}
+
+#[instrument]
+pub fn verify_host_by_name(
+    host_name: &str,
+    token: Vec<u8>,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    verify_host_by_column("HOST_NAME", host_name, token, config)
+}
+
+pub fn create_login_with_hash(
+    login: &str,
+    hash: Vec<u8>,
+    config: &DbiConfigMySql,
+) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = Cds::text_add_user();
+    conn.exec_drop(&cmd, (&login, &hash))?;
+    Ok(())
+}
+
+pub fn create_login(login: &str, pw: &str, config: &DbiConfigMySql) -> Result<(), RcdDbError> {
+    let login_hash = crypt::hash(pw);
+    create_login_with_hash(login, login_hash.0.as_bytes().to_vec(), config)
+}
+
+/// Returns the names of the databases on the MySQL server that hold rcd databases
+pub fn get_database_names(config: &DbiConfigMySql) -> Result<Vec<String>, RcdDbError> {
+    let mut conn = get_conn(config)?;
+
+    let cmd = "SELECT schema_name FROM information_schema.schemata WHERE schema_name LIKE '%.db%'";
+
+    Ok(conn.query(cmd)?)
+}
+
+pub fn has_login_via_config(login: &str, config: DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(&config)?;
+    has_login(login, &mut conn)
+}
+
+fn get_rcd_conn(config: &DbiConfigMySql) -> Result<Conn, RcdDbError> {
+    get_db_conn(config, &config.rcd_db_name)
+}
+
+pub fn configure_admin_with_hash(
+    login: &str,
+    hash: Vec<u8>,
+    config: DbiConfigMySql,
+) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(&config)?;
+
+    if !has_login(login, &mut conn)? {
+        create_login_with_hash(login, hash, &config)?;
+    }
+
+    if !role::login_is_in_role(login, &String::from("SysAdmin"), &config)? {
+        role::add_login_to_role(login, &String::from("SysAdmin"), &config)?;
+    }
+
+    Ok(())
+}
+
+pub fn configure_admin(login: &str, pw: &str, config: DbiConfigMySql) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(&config)?;
+
+    if !has_login(login, &mut conn)? {
+        create_login(login, pw, &config)?;
+    }
+
+    if !role::login_is_in_role(login, &String::from("SysAdmin"), &config)? {
+        role::add_login_to_role(login, &String::from("SysAdmin"), &config)?;
+    }
+
+    Ok(())
+}
+
+pub fn if_host_info_exists(config: DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let cmd = String::from("SELECT COUNT(*) TOTALCOUNT FROM CDS_HOST_INFO");
+    has_any_rows(cmd, &mut get_rcd_conn(&config)?)
+}
+
+pub fn has_login(login: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let cmd = "SELECT count(*) AS USERCOUNT FROM CDS_USER WHERE USERNAME = ?";
+    let count: Option<i64> = conn.exec_first(cmd, (login,))?;
+    Ok(count.unwrap_or_default() > 0)
+}
+
+pub fn execute_write_on_connection(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigMySql,
+) -> Result<usize, RcdDbError> {
+    let mut conn = get_db_conn(config, db_name)?;
+    conn.query_drop(cmd)?;
+    Ok(conn.affected_rows() as usize)
+}
+
+pub fn configure_rcd_db(config: &DbiConfigMySql) -> Result<(), RcdDbError> {
+    let db_name = &config.rcd_db_name;
+
+    trace!("[{}]: db_name is {}", function_name!(), &db_name);
+
+    if !has_schema(db_name, &mut get_conn(config)?)? {
+        let mut conn = get_rcd_conn(config)?;
+        create_user_table(&mut conn)?;
+        create_role_table(&mut conn)?;
+        create_user_role_table(&mut conn)?;
+        create_host_info_table(&mut conn)?;
+        create_contracts_table(&mut conn)?;
+        create_cds_hosts_table(&mut conn)?;
+        create_contracts_table_table(&mut conn)?;
+        create_contracts_table_table_schemas(&mut conn)?;
+        create_user_tokens_table(&mut conn)?;
+        create_schema_version_table(&mut conn)?;
+
+        let db_has_role = role::has_role_name(&String::from("SysAdmin"), config)?;
+
+        if !db_has_role {
+            let statement = String::from("INSERT INTO CDS_ROLE (ROLENAME) VALUES ('SysAdmin');");
+            execute_write_on_connection(db_name, &statement, config)?;
+        }
+    } else {
+        trace!("[{}]: schema already exists: {db_name:?}", function_name!());
+        trace!(
+            "[{}]: this can happen if RCD is running via a proxy",
+            function_name!()
+        );
+
+        migrate_rcd_db(config)?;
+    }
+
+    Ok(())
+}
+
+/// Brings an rcd database configured by an earlier version of rcd up to the current layout
+fn migrate_rcd_db(config: &DbiConfigMySql) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    add_column_if_missing(
+        "CDS_CONTRACTS_TABLES",
+        "PENDING_ACTION_TTL_SECONDS",
+        "INT",
+        &mut conn,
+    )?;
+
+    add_column_if_missing("CDS_HOSTS", "PROTOCOL_VERSION", "INT DEFAULT 0", &mut conn)?;
+
+    set_schema_version(&mut conn)
+}
+
+/// Maps a row selected with `CDS_HOSTS_COLUMNS` to a host
+fn row_to_host(row: &Row) -> Result<CdsHosts, RcdDbError> {
+    Ok(CdsHosts {
+        host_id: get_value(row, 0)?,
+        host_name: get_value::<Option<String>>(row, 1)?.unwrap_or_default(),
+        token: get_value::<Option<Vec<u8>>>(row, 2)?.unwrap_or_default(),
+        ip4: get_value::<Option<String>>(row, 3)?.unwrap_or_default(),
+        ip6: get_value::<Option<String>>(row, 4)?.unwrap_or_default(),
+        port: get_value::<Option<i32>>(row, 5)?.unwrap_or_default() as u32,
+        last_comm_utc: get_value::<Option<String>>(row, 6)?.unwrap_or_default(),
+        http_addr: get_value::<Option<String>>(row, 7)?.unwrap_or_default(),
+        http_port: get_value::<Option<i32>>(row, 8)?.unwrap_or_default() as u32,
+        status: HostStatus::from_u32(get_value::<Option<i32>>(row, 9)?.unwrap_or_default() as u32),
+        protocol_version: get_value::<Option<i32>>(row, 10)?.unwrap_or_default() as u32,
+    })
+}
+
+pub fn get_cooperative_hosts(config: &DbiConfigMySql) -> Result<Vec<CdsHosts>, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let cmd = format!("SELECT {CDS_HOSTS_COLUMNS} FROM CDS_HOSTS;");
+
+    conn.query::<Row, _>(&cmd)?
+        .iter()
+        .map(row_to_host)
+        .collect()
+}
+
+pub fn get_cds_host_for_part_db(
+    db_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<Option<CdsHosts>, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let mut cmd = String::from(
+        "
+    SELECT
+        HOST_ID
+    FROM
+        CDS_CONTRACTS
+    WHERE
+        DATABASE_NAME = ':db_name'
+    ;",
+    );
+
+    cmd = cmd.replace(":db_name", db_name);
+    let host_id = get_scalar_as_string(cmd, &mut conn)?;
+
+    let cmd = format!("SELECT {CDS_HOSTS_COLUMNS} FROM CDS_HOSTS WHERE HOST_ID = ?;");
+
+    conn.exec::<Row, _, _>(&cmd, (&host_id,))?
+        .first()
+        .map(row_to_host)
+        .transpose()
+}
+
+fn create_user_tokens_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_user_tokens_table())?;
+    Ok(())
+}
+
+fn create_schema_version_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_cds_schema_version_table())?;
+
+    conn.exec_drop(
+        "INSERT INTO CDS_SCHEMA_VERSION (VERSION) VALUES (?);",
+        (defaults::RCD_SCHEMA_VERSION,),
+    )?;
+    Ok(())
+}
+
+/// Records that the rcd database is at the current layout, for an rcd database configured before
+/// the version was recorded or brought up from an older one
+fn set_schema_version(conn: &mut Conn) -> Result<(), RcdDbError> {
+    if !has_table("CDS_SCHEMA_VERSION", conn)? {
+        return create_schema_version_table(conn);
+    }
+
+    conn.exec_drop(
+        "UPDATE CDS_SCHEMA_VERSION SET VERSION = ? WHERE VERSION < ?;",
+        (defaults::RCD_SCHEMA_VERSION, defaults::RCD_SCHEMA_VERSION),
+    )?;
+    Ok(())
+}
+
+/// Returns the version of the layout of the rcd database, or 0 if it was configured before
+/// the version was recorded
+pub fn get_rcd_schema_version(config: &DbiConfigMySql) -> Result<u32, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    if !has_table("CDS_SCHEMA_VERSION", &mut conn)? {
+        return Ok(0);
+    }
+
+    get_scalar_as_u32(
+        String::from("SELECT VERSION FROM CDS_SCHEMA_VERSION"),
+        &mut conn,
+    )
+}
+
+fn create_user_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_user_table())?;
+    Ok(())
+}
+
+fn create_role_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_role_table())?;
+    Ok(())
+}
+
+fn create_user_role_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_user_role_table())?;
+    Ok(())
+}
+
+fn create_host_info_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_host_info_table())?;
+    Ok(())
+}
+
+fn create_contracts_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_cds_contracts_table())?;
+    Ok(())
+}
+
+fn create_contracts_table_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_cds_contracts_tables_table())?;
+    Ok(())
+}
+
+fn create_contracts_table_table_schemas(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_cds_contracts_tables_schemas_table())?;
+    Ok(())
+}
+
+fn create_cds_hosts_table(conn: &mut Conn) -> Result<(), RcdDbError> {
+    conn.query_drop(Cds::text_create_cds_hosts_table())?;
+    Ok(())
+}
+
+pub fn get_host_info(config: DbiConfigMySql) -> Result<Option<HostInfo>, RcdDbError> {
+    let mut conn = get_rcd_conn(&config)?;
+    let cmd = String::from(
+        "
+    SELECT
+        HOST_ID,
+        HOST_NAME,
+        TOKEN
+    FROM
+        CDS_HOST_INFO;",
+    );
+
+    match conn.query::<Row, _>(&cmd)?.first() {
+        Some(row) => {
+            let token: String = get_value(row, 2)?;
+
+            Ok(Some(HostInfo {
+                id: get_value(row, 0)?,
+                name: get_value(row, 1)?,
+                token: token.as_bytes().to_vec(),
+            }))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn generate_host_info(host_name: &str, config: DbiConfigMySql) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(&config)?;
+    let token_gen = GUID::rand();
+    let token = crypt::hash(&token_gen.to_string());
+
+    let cmd = "SELECT COUNT(*) HOSTS FROM CDS_HOST_INFO".to_string();
+    let has_rows = has_any_rows(cmd, &mut conn)?;
+
+    if has_rows {
+        let cmd = String::from(
+            "
+                UPDATE CDS_HOST_INFO
+                SET
+                    HOST_NAME = ?,
+                    TOKEN = ?
+                ;",
+        );
+        conn.exec_drop(&cmd, (&host_name, &token.0))?;
+        let total_rows = conn.affected_rows();
+
+        if total_rows == 0 {
+            return Err(RcdDbError::General("host info not updated".to_string()));
+        }
+    } else {
+        let id = GUID::rand();
+        let cmd = String::from(
+            "
+                INSERT INTO CDS_HOST_INFO
+                (
+                    HOST_ID,
+                    HOST_NAME,
+                    TOKEN
+                )
+                VALUES
+                (
+                    ?,
+                    ?,
+                    ?
+                );",
+        );
+        conn.exec_drop(&cmd, (&id.to_string(), &host_name, &token.0))?;
+    }
+
+    Ok(())
+}
+
+pub fn verify_login(login: &str, pw: &str, config: DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let cmd = Cds::text_get_user();
+    let mut conn = get_rcd_conn(&config)?;
+
+    for row in conn.exec::<Row, _, _>(&cmd, (&login,))? {
+        let hash: Vec<u8> = get_value(&row, 1)?;
+
+        let mut padded = [0u8; 128];
+        hash.iter().enumerate().for_each(|(i, val)| {
+            padded[i] = *val;
+        });
+
+        if crypt::verify(padded, pw) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// checks rcd_db's CDS_CONTRACTS table to see if there already is a record
+/// for this contract by contract_id
+fn has_contract(contract_id: &str, conn: &mut Conn) -> Result<bool, RcdDbError> {
+    let mut cmd =
+        String::from("SELECT COUNT(*) TOTALCOUNT FROM CDS_CONTRACTS WHERE CONTRACT_ID = ':cid'");
+    cmd = cmd.replace(":cid", contract_id);
+
+    has_any_rows(cmd, conn)
+}
+
+fn do_vecs_match<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    let matching = a.iter().zip(b.iter()).filter(|&(a, b)| a == b).count();
+    matching == a.len() && matching == b.len()
+}
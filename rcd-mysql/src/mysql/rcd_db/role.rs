@@ -0,0 +1,40 @@
+use crate::mysql::sql_text::Cds;
+
+use super::get_rcd_conn;
+use mysql::prelude::Queryable;
+use rcd_common::db::DbiConfigMySql;
+use rcd_error::rcd_db_error::RcdDbError;
+
+pub fn has_role_name(role_name: &str, config: &DbiConfigMySql) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+
+    let cmd = Cds::text_get_role();
+    let count: i64 = conn.exec_first(&cmd, (role_name,))?.unwrap_or_default();
+
+    Ok(count > 0)
+}
+
+pub fn add_login_to_role(
+    login: &str,
+    role_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<(), RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let cmd = Cds::text_add_user_role();
+    conn.exec_drop(&cmd, (&login, &role_name))?;
+    Ok(())
+}
+
+pub fn login_is_in_role(
+    login: &str,
+    role_name: &str,
+    config: &DbiConfigMySql,
+) -> Result<bool, RcdDbError> {
+    let mut conn = get_rcd_conn(config)?;
+    let cmd = Cds::text_get_user_role();
+    let count: i64 = conn
+        .exec_first(&cmd, (login, role_name))?
+        .unwrap_or_default();
+
+    Ok(count > 0)
+}
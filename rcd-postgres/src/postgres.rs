@@ -0,0 +1,797 @@
+use self::db_part::{get_partial_db_connection, get_partial_db_name};
+use bytes::BytesMut;
+use postgres::{
+    types::{to_sql_checked, Format, IsNull, ToSql, Type},
+    Client, NoTls, SimpleQueryMessage,
+};
+use rcd_common::{
+    db::{is_reserved_table_name, DbiConfigPostgres},
+    defaults,
+    table::*,
+};
+use rcd_enum::column_type::ColumnType;
+use rcd_enum::rcd_database_type::RcdDatabaseType;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcd_sqlite_log::log_entry::LogEntry;
+use rcdproto::rcdp::{ColumnSchema, RowValue};
+use std::error::Error;
+use tracing::{info, trace};
+pub mod backend;
+pub mod db;
+pub mod db_part;
+pub mod rcd_db;
+mod sql_text;
+use stdext::function_name;
+use tracing::instrument;
+
+/// Log entries are written by `rcd-sqlite-log` to the root folder of a sqlite backed instance.
+/// A Postgres backed instance has no root folder, so there are no entries to return.
+pub fn get_last_log_entries(_number_of_entries: u32, _config: &DbiConfigPostgres) -> Vec<LogEntry> {
+    Vec::new()
+}
+
+/// Takes a SELECT COUNT(*) SQL statement and returns if the result is > 0. Usually used to see if a table that has been
+/// created has also populated any data in it.
+pub fn has_any_rows(cmd: String, client: &mut Client) -> Result<bool, RcdDbError> {
+    Ok(total_count(cmd, client)? > 0)
+}
+
+/// Takes a SELECT COUNT(*) SQL statement and returns the value
+pub fn total_count(cmd: String, client: &mut Client) -> Result<u32, RcdDbError> {
+    get_scalar_as_u32(cmd, client)
+}
+
+pub fn get_scalar_as_vec_u8(
+    cmd: String,
+    client: &mut Client,
+) -> Result<Option<Vec<u8>>, RcdDbError> {
+    let rows = client.query(&cmd, &[])?;
+    match rows.first() {
+        Some(row) => Ok(row.try_get::<_, Option<Vec<u8>>>(0)?),
+        None => Ok(None),
+    }
+}
+
+pub fn get_scalar_as_u64(cmd: String, client: &mut Client) -> Result<Option<u64>, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    match get_scalar_as_vec_u8(cmd, client)? {
+        Some(array) => Ok(Some(u64::from_ne_bytes(vec_to_array(array)?))),
+        None => Ok(None),
+    }
+}
+
+/// Runs any SQL statement that returns a single value and attempts
+/// to return the result as a u32
+pub fn get_scalar_as_u32(cmd: String, client: &mut Client) -> Result<u32, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    match get_scalar_as_text(&cmd, client)? {
+        Some(value) => Ok(value.parse().unwrap_or_default()),
+        None => Ok(0),
+    }
+}
+
+pub fn get_scalar_as_bool(cmd: String, client: &mut Client) -> Result<bool, RcdDbError> {
+    trace!("get_scalar_as_bool: {cmd:?}");
+
+    Ok(matches!(
+        get_scalar_as_text(&cmd, client)?.as_deref(),
+        Some("t") | Some("true") | Some("1")
+    ))
+}
+
+pub fn execute_write(client: &mut Client, cmd: &str) -> Result<usize, RcdDbError> {
+    trace!("[{}]: {cmd:?}", function_name!());
+    let rows = client.execute(cmd, &[])?;
+    Ok(rows as usize)
+}
+
+pub fn execute_read_on_connection_for_row(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    cmd: String,
+    client: &mut Client,
+) -> Result<rcdproto::rcdp::Row, RcdDbError> {
+    let (cols, rows) = read_as_strings(&cmd, client)?;
+
+    let mut values: Vec<RowValue> = Vec::new();
+    let mut columns: Vec<ColumnSchema> = Vec::new();
+
+    for (col_idx, col) in cols.iter().enumerate() {
+        let col_type = data_type_as_string(&col.1);
+
+        let c = ColumnSchema {
+            column_name: col.0.clone(),
+            column_type: ColumnType::to_u32(
+                ColumnType::try_parse(&col_type).unwrap_or(ColumnType::Unknown),
+            ),
+            column_length: 0,
+            is_nullable: false,
+            ordinal: col_idx as u32,
+            table_id: String::from(""),
+            column_id: col_idx.to_string(),
+            is_primary_key: false,
+        };
+        columns.push(c);
+    }
+
+    for row in rows {
+        for (i, string_value) in row.into_iter().enumerate() {
+            let row_value = RowValue {
+                column: Some(columns[i].clone()),
+                is_null_value: false,
+                value: string_value.as_bytes().to_vec(),
+                string_value,
+            };
+
+            values.push(row_value);
+        }
+    }
+
+    let mut cmd = String::from("SELECT HASH FROM :table_name WHERE ROW_ID = :rid");
+    let metadata_table_name = format!("{}{}", table_name, defaults::METADATA_TABLE_SUFFIX);
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let hash = get_scalar_as_vec_u8(cmd, client)?.unwrap_or_default();
+
+    let result = rcdproto::rcdp::Row {
+        database_name: db_name.to_string(),
+        table_name: table_name.to_string(),
+        row_id,
+        values,
+        is_remoteable: true,
+        remote_metadata: None,
+        hash,
+    };
+
+    trace!("[{}]: {result:?}", function_name!());
+
+    Ok(result)
+}
+
+pub fn execute_read(cmd: &str, client: &mut Client) -> Result<Table, RcdDbError> {
+    trace!("[{}]: {:?}", function_name!(), cmd);
+
+    let (cols, rows) = read_as_strings(cmd, client)?;
+    let mut table = Table::new();
+
+    for (col_idx, col) in cols.iter().enumerate() {
+        let c = Column {
+            name: col.0.clone(),
+            is_nullable: false,
+            idx: col_idx,
+            data_type: data_type_as_string(&col.1),
+            is_primary_key: false,
+        };
+
+        trace!("[{}]: adding col {}", function_name!(), c.name);
+
+        table.add_column(c);
+    }
+
+    for row in rows {
+        let mut data_row = rcd_common::table::Row::new();
+
+        for (i, string_value) in row.into_iter().enumerate() {
+            let col = table.get_column_by_index(i).ok_or_else(|| {
+                RcdDbError::General(format!("no column at index {i} in the result of {cmd}"))
+            })?;
+
+            let data_item = Data {
+                data_string: string_value,
+                data_byte: Vec::new(),
+            };
+
+            let data_value = Value {
+                data: Some(data_item),
+                col,
+            };
+
+            data_row.add_value(data_value);
+        }
+
+        table.add_row(data_row);
+    }
+
+    Ok(table)
+}
+
+pub fn execute_read_at_participant(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Table, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_partial_db_connection(db_name, config)?;
+    execute_read(cmd, &mut client)
+}
+
+pub fn execute_read_at_host(
+    db_name: &str,
+    cmd: &str,
+    config: DbiConfigPostgres,
+) -> core::result::Result<Table, RcdDbError> {
+    if !has_database(&config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_db_conn(&config, db_name)?;
+    execute_read(cmd, &mut client)
+}
+
+/// Copies the rows we got from participants into temp tables named after each cooperative table.
+/// `pg_temp` is searched before the database's schema, so `cmd` can run as written and read those
+/// rows instead of the host's copy of the table.
+pub fn execute_read_at_host_with_cooperative_rows(
+    db_name: &str,
+    cmd: &str,
+    cooperative_tables: &[String],
+    cooperative_rows: &[rcdproto::rcdp::Row],
+    config: DbiConfigPostgres,
+) -> core::result::Result<Table, RcdDbError> {
+    if !has_database(&config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_db_conn(&config, db_name)?;
+
+    for table_name in cooperative_tables {
+        let create_temp_table = format!(
+            "CREATE TEMP TABLE {table_name} (LIKE \"{db_name}\".{table_name}, {table_name}{} INT);",
+            defaults::ROW_KEY_COLUMN_SUFFIX
+        );
+        client.batch_execute(&create_temp_table)?;
+    }
+
+    for (row_key, row) in cooperative_rows.iter().enumerate() {
+        let insert_row = get_insert_for_cooperative_row(row, row_key);
+        trace!("[{}]: {insert_row:?}", function_name!());
+        client.batch_execute(&insert_row)?;
+    }
+
+    execute_read(cmd, &mut client)
+}
+
+/// Builds an `INSERT` of a participant's row into the temp table of the same name
+fn get_insert_for_cooperative_row(row: &rcdproto::rcdp::Row, row_key: usize) -> String {
+    let mut col_names: Vec<String> = Vec::new();
+    let mut values: Vec<String> = Vec::new();
+
+    for value in &row.values {
+        let column = match &value.column {
+            Some(column) => column,
+            None => continue,
+        };
+
+        col_names.push(column.column_name.clone());
+
+        if value.is_null_value {
+            values.push(String::from("NULL"));
+        } else {
+            values.push(format!("'{}'", value.string_value.replace('\'', "''")));
+        }
+    }
+
+    col_names.push(format!(
+        "{}{}",
+        row.table_name,
+        defaults::ROW_KEY_COLUMN_SUFFIX
+    ));
+    values.push(row_key.to_string());
+
+    format!(
+        "INSERT INTO pg_temp.{} ({}) VALUES ({});",
+        row.table_name,
+        col_names.join(", "),
+        values.join(", ")
+    )
+}
+
+/// A value bound in the text format, so that Postgres parses it as the type of the column it is
+/// stored in or compared with. A blob is bound as its bytes.
+#[derive(Debug)]
+struct TypedValue<'a>(&'a RowValue);
+
+impl ToSql for TypedValue<'_> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if self.0.is_null_value {
+            return Ok(IsNull::Yes);
+        }
+
+        if *ty == Type::BYTEA {
+            out.extend_from_slice(&self.0.value);
+        } else {
+            out.extend_from_slice(self.0.string_value.as_bytes());
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    fn encode_format(&self, ty: &Type) -> Format {
+        if *ty == Type::BYTEA {
+            Format::Binary
+        } else {
+            Format::Text
+        }
+    }
+
+    to_sql_checked!();
+}
+
+fn as_params<'a>(values: &'a [TypedValue]) -> Vec<&'a (dyn ToSql + Sync)> {
+    values
+        .iter()
+        .map(|value| value as &(dyn ToSql + Sync))
+        .collect()
+}
+
+/// Deletes every row of the host's table and inserts `rows` in their place, in a single
+/// transaction
+pub fn replace_rows_at_host(
+    db_name: &str,
+    table_name: &str,
+    rows: &[rcdproto::rcdp::Row],
+    config: &DbiConfigPostgres,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_db_conn(config, db_name)?;
+    let mut transaction = client.transaction()?;
+    transaction.execute(format!("DELETE FROM {table_name};").as_str(), &[])?;
+
+    let mut total_rows = 0;
+
+    for row in rows {
+        let mut col_names: Vec<String> = Vec::new();
+        let mut values: Vec<TypedValue> = Vec::new();
+
+        for value in &row.values {
+            let column = value.column.as_ref().ok_or_else(|| {
+                RcdDbError::General(format!("a value of {table_name} has no column"))
+            })?;
+
+            col_names.push(column.column_name.clone());
+            values.push(TypedValue(value));
+        }
+
+        let placeholders: Vec<String> = (1..=values.len()).map(|i| format!("${i}")).collect();
+
+        let cmd = format!(
+            "INSERT INTO {table_name} ({}) VALUES ({});",
+            col_names.join(", "),
+            placeholders.join(", ")
+        );
+
+        trace!("[{}]: {cmd:?}", function_name!());
+        total_rows += transaction.execute(cmd.as_str(), &as_params(&values))? as usize;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
+/// Reads the rows of the host's table whose key columns hold one of `keys`. The matching rows
+/// are copied into a temp table with the keys bound, and then read from there as text like
+/// any other read.
+pub fn get_rows_at_host_by_key(
+    db_name: &str,
+    table_name: &str,
+    keys: &[Vec<RowValue>],
+    config: &DbiConfigPostgres,
+) -> Result<Table, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut predicates: Vec<String> = Vec::new();
+    let mut values: Vec<TypedValue> = Vec::new();
+
+    for key in keys {
+        let mut terms: Vec<String> = Vec::new();
+
+        for value in key {
+            let column = value.column.as_ref().ok_or_else(|| {
+                RcdDbError::General(format!("a key of {table_name} has no column"))
+            })?;
+
+            values.push(TypedValue(value));
+            terms.push(format!("{} = ${}", column.column_name, values.len()));
+        }
+
+        predicates.push(format!("({})", terms.join(" AND ")));
+    }
+
+    if predicates.is_empty() {
+        return Ok(Table::new());
+    }
+
+    let mut client = get_db_conn(config, db_name)?;
+
+    let create_temp_table =
+        format!("CREATE TEMP TABLE {table_name} (LIKE \"{db_name}\".{table_name});");
+    client.batch_execute(&create_temp_table)?;
+
+    let copy_rows = format!(
+        "INSERT INTO pg_temp.{table_name} SELECT * FROM \"{db_name}\".{table_name} WHERE {}",
+        predicates.join(" OR ")
+    );
+    client.execute(copy_rows.as_str(), &as_params(&values))?;
+
+    execute_read(&format!("SELECT * FROM pg_temp.{table_name}"), &mut client)
+}
+
+/// Runs any SQL statement that returns a single value and returns
+/// the result as a string
+fn get_scalar_as_string(cmd: String, client: &mut Client) -> Result<String, RcdDbError> {
+    Ok(get_scalar_as_text(&cmd, client)?.unwrap_or_default())
+}
+
+/// Runs the statement over the simple query protocol, which hands back every value
+/// as text, and returns the value of the first column of the last row
+fn get_scalar_as_text(cmd: &str, client: &mut Client) -> Result<Option<String>, RcdDbError> {
+    let messages = client.simple_query(cmd)?;
+    let mut value = None;
+
+    for message in messages {
+        if let SimpleQueryMessage::Row(row) = message {
+            value = row.try_get(0)?.map(|v| v.to_string());
+        }
+    }
+
+    Ok(value)
+}
+
+/// Prepares the statement to learn the name and type of each column, then runs it
+/// over the simple query protocol to get each value as text. NULLs and binary
+/// values are returned as empty strings, the same as the sqlite backend.
+fn read_as_strings(
+    cmd: &str,
+    client: &mut Client,
+) -> Result<(Vec<(String, Type)>, Vec<Vec<String>>), RcdDbError> {
+    let statement = client.prepare(cmd)?;
+    let cols: Vec<(String, Type)> = statement
+        .columns()
+        .iter()
+        .map(|c| (c.name().to_string(), c.type_().clone()))
+        .collect();
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    for message in client.simple_query(cmd)? {
+        if let SimpleQueryMessage::Row(row) = message {
+            let mut values: Vec<String> = Vec::new();
+
+            for (i, col) in cols.iter().enumerate() {
+                let string_value = if col.1 == Type::BYTEA {
+                    String::from("")
+                } else {
+                    row.try_get(i)?.unwrap_or_default().to_string()
+                };
+
+                values.push(string_value);
+            }
+
+            rows.push(values);
+        }
+    }
+
+    Ok((cols, rows))
+}
+
+/// Maps a Postgres column type to the type names used by the sqlite backend so that
+/// `ColumnType::try_parse` understands them
+fn data_type_as_string(data_type: &Type) -> String {
+    let name = match *data_type {
+        Type::INT2 | Type::INT4 | Type::INT8 => "INT",
+        Type::BOOL => "BIT",
+        Type::BPCHAR => "CHAR",
+        Type::VARCHAR => "VARCHAR",
+        Type::TEXT | Type::NAME => "TEXT",
+        Type::TIMESTAMP | Type::TIMESTAMPTZ | Type::DATE => "DATETIME",
+        Type::NUMERIC | Type::FLOAT4 | Type::FLOAT8 => "DECIMAL",
+        Type::BYTEA => "BLOB",
+        _ => "",
+    };
+
+    name.to_string()
+}
+
+fn has_table(table_name: &str, client: &mut Client) -> Result<bool, RcdDbError> {
+    let mut cmd = String::from(
+        "SELECT count(*) AS TABLECOUNT FROM information_schema.tables WHERE table_schema = current_schema() AND table_name = lower(':table_name')",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    has_any_rows(cmd, client)
+}
+
+fn has_column(
+    table_name: &str,
+    column_name: &str,
+    client: &mut Client,
+) -> Result<bool, RcdDbError> {
+    let mut cmd = String::from(
+        "SELECT count(*) AS COLUMNCOUNT FROM information_schema.columns WHERE table_schema = current_schema() AND table_name = lower(':table_name') AND column_name = lower(':column_name')",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":column_name", column_name);
+    has_any_rows(cmd, client)
+}
+
+/// Adds a column to a table created by an earlier version of rcd
+fn add_column_if_missing(
+    table_name: &str,
+    column_name: &str,
+    column_type: &str,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    if !has_column(table_name, column_name, client)? {
+        info!(
+            "[{}]: adding {column_name} to {table_name}",
+            function_name!()
+        );
+
+        let cmd = format!("ALTER TABLE {table_name} ADD COLUMN {column_name} {column_type};");
+        client.execute(&cmd, &[])?;
+    }
+
+    Ok(())
+}
+
+fn has_schema(schema_name: &str, client: &mut Client) -> Result<bool, RcdDbError> {
+    let cmd = "SELECT COUNT(*) FROM information_schema.schemata WHERE schema_name = $1";
+    let count: i64 = client.query_one(cmd, &[&schema_name])?.try_get(0)?;
+    Ok(count > 0)
+}
+
+#[instrument]
+pub fn has_database(config: &DbiConfigPostgres, db_name: &str) -> Result<bool, RcdDbError> {
+    let mut client = get_client(config)?;
+
+    let mut names: Vec<String> = vec![db_name.to_string()];
+
+    if !db_name.ends_with(".db") {
+        names.push(db_name.to_owned() + ".db");
+    }
+
+    if !db_name.ends_with(".dbpart") {
+        names.push(get_partial_db_name(db_name));
+    }
+
+    for name in &names {
+        if has_schema(name, &mut client)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Returns a connection to the Postgres database in the settings, without a search path set
+fn get_client(config: &DbiConfigPostgres) -> Result<Client, RcdDbError> {
+    Ok(Client::connect(&get_connection_string(config), NoTls)?)
+}
+
+fn get_connection_string(config: &DbiConfigPostgres) -> String {
+    if !config.connection_string.is_empty() {
+        return config.connection_string.clone();
+    }
+
+    format!(
+        "host={} user={} password={} {}",
+        config.host, config.user_name, config.pw, config.connect_options
+    )
+    .trim()
+    .to_string()
+}
+
+/// Every rcd database is a schema in the Postgres database. Returns a connection whose search path
+/// is that schema, creating the schema if it doesn't exist yet
+pub fn get_db_conn(config: &DbiConfigPostgres, db_name: &str) -> Result<Client, RcdDbError> {
+    trace!("[{}]: {db_name:?}", function_name!());
+    let mut client = get_client(config)?;
+    let cmd =
+        format!("CREATE SCHEMA IF NOT EXISTS \"{db_name}\"; SET search_path TO \"{db_name}\";");
+    client.batch_execute(&cmd)?;
+    Ok(client)
+}
+
+/// Counts the rows of a host or partial database's own tables, leaving out the tables rcd keeps
+/// for itself
+pub fn count_user_rows(db_name: &str, config: &DbiConfigPostgres) -> Result<u64, RcdDbError> {
+    let mut client = match rcd_db::get_rcd_db_type(db_name, config)? {
+        RcdDatabaseType::Host => get_db_conn(config, db_name)?,
+        RcdDatabaseType::Partial => get_partial_db_connection(db_name, config)?,
+        _ => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let cmd = "SELECT table_name FROM information_schema.tables WHERE table_schema = current_schema() AND table_type = 'BASE TABLE'";
+    let mut rows = 0;
+
+    for row in client.query(cmd, &[])? {
+        let table: String = row.try_get(0)?;
+
+        if is_reserved_table_name(&table) {
+            continue;
+        }
+
+        let cmd = format!("SELECT COUNT(*) FROM \"{table}\"");
+        let count: i64 = client.query_one(&cmd, &[])?.try_get(0)?;
+        rows += count as u64;
+    }
+
+    Ok(rows)
+}
+
+pub fn execute_write_on_connection_at_host(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigPostgres,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_db_conn(config, db_name)?;
+
+    trace!("[{}]: {cmd:?}", function_name!());
+
+    Ok(client.execute(cmd, &[])? as usize)
+}
+
+/// Runs the statements in a single transaction, which is rolled back if any of them fail
+pub fn execute_writes_on_connection_at_host(
+    db_name: &str,
+    cmds: &[String],
+    config: &DbiConfigPostgres,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name)? {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut client = get_db_conn(config, db_name)?;
+    let mut transaction = client.transaction()?;
+    let mut total_rows = 0;
+
+    for cmd in cmds {
+        trace!("[{}]: {cmd:?}", function_name!());
+        total_rows += transaction.execute(cmd.as_str(), &[])? as usize;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
+pub fn execute_write_on_connection_at_participant(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigPostgres,
+) -> Result<usize, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    Ok(client.execute(cmd, &[])? as usize)
+}
+
+/// Returns the column definitions of the table as a comma separated list of
+/// Postgres types, used to create a copy of the table (such as the data log table)
+pub fn get_table_col_names_with_data_type_as_string(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<String, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let table = get_schema_of_table(table_name.to_string(), &mut client)?;
+
+    let mut col_names = String::from("");
+
+    for row in &table.rows {
+        let col_name = get_data_string(&row.vals[1]);
+        let data_type = get_data_string(&row.vals[2]);
+
+        let col_type = ColumnType::from_u32(ColumnType::data_type_to_enum_u32(data_type.clone()));
+        let mut pg_data_type = col_type.data_type_as_string_postgres();
+        let col_length = ColumnType::data_type_len(data_type);
+
+        if col_length > 0 {
+            pg_data_type = format!("{pg_data_type}({col_length})");
+        }
+
+        col_names = format!("{} {} {}{}", col_names, col_name, pg_data_type, ",");
+    }
+
+    trace!("[{}]: {col_names:?}", function_name!());
+
+    let result: &str = &col_names[1..col_names.len() - 1];
+
+    trace!("[{}]: {result:?}", function_name!());
+
+    Ok(result.to_string())
+}
+
+/// Returns a table describing the schema of the table, shaped like sqlite's `PRAGMA table_info`.
+/// The `ROWID` column that rcd adds to partial tables is not included.
+/// # Columns:
+/// 1. cid
+/// 2. name
+/// 3. type
+/// 4. notnull
+/// 5. dflt_value
+/// 6. pk
+pub fn get_schema_of_table(
+    table_name: String,
+    client: &mut Client,
+) -> core::result::Result<Table, RcdDbError> {
+    let mut cmd = sql_text::Coop::text_get_schema_of_table();
+    cmd = cmd.replace(":table_name", &table_name);
+    execute_read(&cmd, client)
+}
+
+pub fn get_table_col_names(
+    table_name: String,
+    client: &mut Client,
+) -> Result<Vec<String>, RcdDbError> {
+    let mut result: Vec<String> = Vec::new();
+
+    let mut cmd = String::from(
+        "
+        SELECT
+            column_name
+        FROM
+            information_schema.columns
+        WHERE
+            table_schema = current_schema()
+        AND
+            table_name = lower(':table_name')
+        AND
+            column_name <> 'rowid'
+        ORDER BY
+            ordinal_position
+        ;",
+    );
+    cmd = cmd.replace(":table_name", &table_name);
+
+    let names = execute_read(&cmd, client)?;
+
+    for row in names.rows {
+        for val in row.vals {
+            result.push(get_data_string(&val));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the text of a value read with `execute_read`, which is empty for a NULL
+fn get_data_string(value: &Value) -> String {
+    value
+        .data
+        .as_ref()
+        .map(|data| data.data_string.clone())
+        .unwrap_or_default()
+}
+
+fn vec_to_array<T, const N: usize>(v: Vec<T>) -> Result<[T; N], RcdDbError> {
+    v.try_into().map_err(|v: Vec<T>| {
+        RcdDbError::General(format!(
+            "Expected a Vec of length {} but it was {}",
+            N,
+            v.len()
+        ))
+    })
+}
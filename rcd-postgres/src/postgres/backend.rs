@@ -0,0 +1,1198 @@
+use chrono::{DateTime, Utc};
+use rcd_common::{
+    coop_database_contract::CoopDatabaseContract,
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, NewCoopDatabaseParticipant,
+    },
+    db::{CdsHosts, DbiConfigPostgres, PartialDataResult, RehashedRow},
+    host_info::HostInfo,
+    save_contract_result::RcdSaveContractResult,
+    storage_backend::RcdStorageBackend,
+    table::Table,
+};
+use rcd_enum::{
+    contract_status::ContractStatus, database_type::DatabaseType,
+    deletes_from_host_behavior::DeletesFromHostBehavior,
+    deletes_to_host_behavior::DeletesToHostBehavior, logical_storage_policy::LogicalStoragePolicy,
+    rcd_database_type::RcdDatabaseType, rcd_generate_contract_error::RcdGenerateContractError,
+    remote_delete_behavior::RemoteDeleteBehavior,
+    updates_from_host_behavior::UpdatesFromHostBehavior,
+    updates_to_host_behavior::UpdatesToHostBehavior,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+use rcd_sqlite_log::log_entry::LogEntry;
+use rcdproto::rcdp::{
+    ColumnSchema, Contract, DatabaseSchema, Participant, ParticipantStatus, PendingStatement,
+    PendingWrite, Row, RowValue,
+};
+use std::path::PathBuf;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+/// `RcdStorageBackend` over the Postgres server in the config
+#[derive(Debug, Clone)]
+pub struct PostgresBackend {
+    config: DbiConfigPostgres,
+}
+
+impl PostgresBackend {
+    pub fn new(config: DbiConfigPostgres) -> Self {
+        Self { config }
+    }
+
+    /// The postgres client blocks on its own runtime, which panics if called from inside
+    /// one of our async handlers. On a multi-threaded runtime the call is moved off the executor
+    /// with `block_in_place`; on a current thread runtime it runs on a thread of its own.
+    fn run_postgres<T: Send>(
+        &self,
+        f: impl FnOnce() -> Result<T, RcdDbError> + Send,
+    ) -> Result<T, RcdDbError> {
+        match Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+                tokio::task::block_in_place(f)
+            }
+            Ok(_) => std::thread::scope(|s| {
+                s.spawn(f)
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            }),
+            Err(_) => f(),
+        }
+    }
+}
+
+impl RcdStorageBackend for PostgresBackend {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::Postgres
+    }
+
+    fn box_clone(&self) -> Box<dyn RcdStorageBackend> {
+        Box::new(self.clone())
+    }
+
+    fn get_data_hash_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<u64, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::get_data_hash_at_host(db_name, table_name, row_id, &settings)
+        })
+    }
+
+    fn get_row_hashes_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<u64>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::get_row_hashes_at_host(db_name, table_name, &settings)
+        })
+    }
+
+    fn remove_remote_row_reference_from_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::remove_remote_row_reference_from_host(
+                db_name, table_name, row_id, &settings,
+            )
+        })
+    }
+
+    fn delete_metadata_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_ids: &[u32],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::delete_metadata_in_host_db(
+                db_name,
+                table_name,
+                row_ids,
+                internal_participant_id,
+                settings,
+            )
+        })
+    }
+
+    fn update_metadata_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[(u32, u64)],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::update_metadata_in_host_db(
+                db_name,
+                table_name,
+                rows,
+                internal_participant_id,
+                settings,
+            )
+        })
+    }
+
+    fn mark_metadata_out_of_sync_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_ids: &[u32],
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::mark_metadata_out_of_sync_in_host_db(
+                db_name,
+                table_name,
+                row_ids,
+                internal_participant_id,
+                settings,
+            )
+        })
+    }
+
+    fn mark_metadata_remote_deleted_in_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::mark_metadata_remote_deleted_in_host_db(
+                db_name,
+                table_name,
+                row_id,
+                internal_participant_id,
+                settings,
+            )
+        })
+    }
+
+    fn insert_metadata_into_host_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+        hash: u64,
+        internal_participant_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::metadata::insert_metadata_into_host_db(
+                db_name,
+                table_name,
+                row_id,
+                hash,
+                internal_participant_id,
+                settings,
+            )
+        })
+    }
+
+    fn add_pending_write_at_host(
+        &self,
+        db_name: &str,
+        internal_participant_id: &str,
+        pending_write: &PendingWrite,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::pending_write::add_pending_write_at_host(
+                db_name,
+                internal_participant_id,
+                pending_write,
+                &settings,
+            )
+        })
+    }
+
+    fn remove_pending_write_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        internal_participant_id: &str,
+        pending_action_id: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::pending_write::remove_pending_write_at_host(
+                db_name,
+                table_name,
+                internal_participant_id,
+                pending_action_id,
+                &settings,
+            )
+        })
+    }
+
+    fn get_pending_writes_at_host(&self, db_name: &str) -> Result<Vec<PendingWrite>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::pending_write::get_pending_writes_at_host(db_name, &settings)
+        })
+    }
+
+    fn update_participant_accepts_contract(
+        &self,
+        db_name: &str,
+        participant: CoopDatabaseParticipant,
+        participant_message: Participant,
+        accepted_contract_id: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::contract::update_participant_accepts_contract(
+                db_name,
+                participant,
+                participant_message,
+                accepted_contract_id,
+                settings,
+            )
+        })
+    }
+
+    fn update_participant_rejects_contract(
+        &self,
+        db_name: &str,
+        participant: CoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::contract::update_participant_rejects_contract(db_name, participant, settings)
+        })
+    }
+
+    fn add_participant(
+        &self,
+        db_name: &str,
+        participant: NewCoopDatabaseParticipant,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::add_participant(db_name, participant, settings)
+        })
+    }
+
+    fn get_database_schema(&self, db_name: &str) -> Result<DatabaseSchema, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::get_db_schema(db_name, settings)
+        })
+    }
+
+    fn get_participant_by_alias(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::get_participant_by_alias(db_name, participant_alias, settings)
+        })
+    }
+
+    fn get_participant_by_id(
+        &self,
+        db_name: &str,
+        participant_id: &str,
+    ) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::get_participant_by_id(db_name, participant_id, settings)
+        })
+    }
+
+    fn has_participant(&self, db_name: &str, participant_alias: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::has_participant(db_name, participant_alias, settings)
+        })
+    }
+
+    fn update_participant_protocol_version(
+        &self,
+        db_name: &str,
+        participant_alias: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::update_participant_protocol_version(
+                db_name,
+                participant_alias,
+                protocol_version,
+                settings,
+            )
+        })
+    }
+
+    fn get_active_contract(&self, db_name: &str) -> Result<CoopDatabaseContract, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::contract::get_active_contract(db_name, settings)
+        })
+    }
+
+    fn get_logical_storage_policy(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<LogicalStoragePolicy, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::logical_storage_policy::get_logical_storage_policy(
+                db_name, table_name, &settings,
+            )
+        })
+    }
+
+    fn set_logical_storage_policy(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        policy: LogicalStoragePolicy,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::logical_storage_policy::set_logical_storage_policy(
+                db_name, table_name, policy, settings,
+            )
+        })
+    }
+
+    fn get_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<String>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::logical_storage_policy::get_participant_owned_columns(
+                db_name, table_name, &settings,
+            )
+        })
+    }
+
+    fn set_participant_owned_columns(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        column_names: &[String],
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::logical_storage_policy::set_participant_owned_columns(
+                db_name,
+                table_name,
+                column_names,
+                &settings,
+            )
+        })
+    }
+
+    fn has_table(&self, db_name: &str, table_name: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::has_table_client_service(db_name, table_name, settings)
+        })
+    }
+
+    fn execute_write_at_host(&self, db_name: &str, cmd: &str) -> Result<usize, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_write_on_connection_at_host(db_name, cmd, &settings)
+        })
+    }
+
+    fn execute_writes_at_host(&self, db_name: &str, cmds: &[String]) -> Result<usize, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_writes_on_connection_at_host(db_name, cmds, &settings)
+        })
+    }
+
+    fn execute_read_at_host(&self, db_name: &str, cmd: &str) -> Result<Table, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_read_at_host(db_name, cmd, settings)
+        })
+    }
+
+    fn replace_rows_at_host(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        rows: &[Row],
+    ) -> Result<usize, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::replace_rows_at_host(db_name, table_name, rows, &settings)
+        })
+    }
+
+    fn get_rows_at_host_by_key(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        keys: &[Vec<RowValue>],
+    ) -> Result<Table, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::get_rows_at_host_by_key(db_name, table_name, keys, &settings)
+        })
+    }
+
+    fn execute_read_at_host_with_cooperative_rows(
+        &self,
+        db_name: &str,
+        cmd: &str,
+        cooperative_tables: &[String],
+        cooperative_rows: &[Row],
+    ) -> Result<Table, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_read_at_host_with_cooperative_rows(
+                db_name,
+                cmd,
+                cooperative_tables,
+                cooperative_rows,
+                settings,
+            )
+        })
+    }
+
+    fn has_cooperative_tables(&self, db_name: &str, cmd: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::has_cooperative_tables(db_name, cmd, &settings)
+        })
+    }
+
+    fn get_participants_for_table(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<Vec<CoopDatabaseParticipantData>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::get_participants_for_table(db_name, table_name, settings)
+        })
+    }
+
+    fn get_participants_for_database(
+        &self,
+        db_name: &str,
+    ) -> Result<Vec<ParticipantStatus>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::participant::get_participants_for_database(db_name, &settings)
+        })
+    }
+
+    fn get_cooperative_tables(&self, db_name: &str, cmd: &str) -> Result<Vec<String>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            Ok(super::db::get_cooperative_tables(db_name, cmd, settings))
+        })
+    }
+
+    fn create_database(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db::create_database(db_name, settings)?;
+            Ok(())
+        })
+    }
+
+    fn enable_coooperative_features(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+
+            super::db::enable_coooperative_features(db_name, &settings)
+        })
+    }
+
+    fn generate_contract(
+        &self,
+        db_name: &str,
+        desc: &str,
+        remote_delete_behavior: RemoteDeleteBehavior,
+    ) -> Result<bool, RcdGenerateContractError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+
+            Ok(super::db::contract::generate_contract(
+                db_name,
+                desc,
+                remote_delete_behavior,
+                settings,
+            ))
+        })
+        .unwrap_or_else(|e| Err(RcdGenerateContractError::General(e.to_string())))
+    }
+
+    fn accept_pending_action_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::accept_pending_action_at_participant(
+                db_name, table_name, row_id, &settings,
+            )
+        })
+    }
+
+    fn reject_pending_action_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::reject_pending_action_at_participant(
+                db_name, table_name, row_id, &settings,
+            )
+        })
+    }
+
+    fn get_pending_actions(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        action: &str,
+    ) -> Result<Vec<PendingStatement>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::get_pending_actions(db_name, table_name, action, &settings)
+        })
+    }
+
+    fn get_data_hash_at_participant(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<u64, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::get_data_hash_at_participant(db_name, table_name, row_id, &settings)
+        })
+    }
+
+    fn rehash_partial_database(&self, db_name: &str) -> Result<Vec<RehashedRow>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::rehash_partial_database(db_name, &settings)
+        })
+    }
+
+    fn get_row_hash_version(&self, db_name: &str) -> Result<Option<u8>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::get_row_hash_version(db_name, &settings)
+        })
+    }
+
+    fn read_row_ids_from_part_db(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        where_clause: &str,
+    ) -> Result<Vec<u32>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::read_row_ids_from_part_db(db_name, table_name, where_clause, &settings)
+        })
+    }
+
+    fn get_row_from_partial_database(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        row_id: u32,
+    ) -> Result<Row, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::get_row_from_partial_database(db_name, table_name, row_id, &settings)
+        })
+    }
+
+    fn delete_data_in_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        where_clause: &str,
+        host_id: &str,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::delete::delete_data_in_partial_db(
+                part_db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                &settings,
+            )
+        })
+    }
+
+    fn update_data_into_partial_db_queue(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        where_clause: &str,
+        host: &CdsHosts,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::update::update_data_into_partial_db_queue(
+                part_db_name,
+                table_name,
+                cmd,
+                where_clause,
+                &host.host_id,
+                &settings,
+            )
+        })
+    }
+
+    fn update_data_into_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+        host_id: &str,
+        where_clause: &str,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::update::update_data_into_partial_db(
+                part_db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                &settings,
+            )
+        })
+    }
+
+    fn insert_data_into_partial_db(
+        &self,
+        part_db_name: &str,
+        table_name: &str,
+        cmd: &str,
+    ) -> Result<PartialDataResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::insert::insert_data_into_partial_db(
+                part_db_name,
+                table_name,
+                cmd,
+                &settings,
+            )
+        })
+    }
+
+    fn create_partial_database_from_contract(
+        &self,
+        contract: &Contract,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::create_partial_database_from_contract(contract, &settings)
+        })
+    }
+
+    fn get_table_id(&self, _db_name: &str, _table_name: &str) -> Result<String, RcdDbError> {
+        Err(RcdDbError::NotSupported(
+            "get_table_id".to_string(),
+            "postgres".to_string(),
+        ))
+    }
+
+    /// Partial tables are created from the contract in `create_partial_database_from_contract`
+    fn create_table_in_partial_database(
+        &self,
+        _db_name: &str,
+        _table_name: &str,
+        _schema: Vec<ColumnSchema>,
+    ) -> Result<bool, RcdDbError> {
+        Err(RcdDbError::NotSupported(
+            "create_table_in_partial_database".to_string(),
+            "postgres".to_string(),
+        ))
+    }
+
+    fn get_db_id(&self, _db_name: &str) -> Result<String, RcdDbError> {
+        Err(RcdDbError::NotSupported(
+            "get_db_id".to_string(),
+            "postgres".to_string(),
+        ))
+    }
+
+    fn create_partial_database(&self, db_name: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::db_part::create_partial_database(db_name, &settings)?;
+            Ok(())
+        })
+    }
+
+    fn execute_write_at_partipant(&self, db_name: &str, cmd: &str) -> Result<usize, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_write_on_connection_at_participant(db_name, cmd, &settings)
+        })
+    }
+
+    fn execute_read_at_participant(&self, db_name: &str, cmd: &str) -> Result<Table, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::execute_read_at_participant(db_name, cmd, &settings)
+        })
+    }
+
+    fn login_has_token(&self, login: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::login_has_token(login, &settings)
+        })
+    }
+
+    fn get_last_log_entries(&self, number_of_entries: u32) -> Result<Vec<LogEntry>, RcdDbError> {
+        self.run_postgres(|| {
+            Ok(super::get_last_log_entries(
+                number_of_entries,
+                &self.config.clone(),
+            ))
+        })
+    }
+
+    fn revoke_token(&self, jwt: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::revoke_token(jwt, &settings)
+        })
+    }
+
+    fn get_cooperative_hosts(&self) -> Result<Vec<CdsHosts>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_cooperative_hosts(&settings)
+        })
+    }
+
+    fn verify_token(&self, token: String) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::verify_token(&token, &settings)
+        })
+    }
+
+    fn delete_expired_tokens(&self) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::delete_expired_tokens(&settings)
+        })
+    }
+
+    fn save_token(
+        &self,
+        login: &str,
+        token: &str,
+        expiration: DateTime<Utc>,
+    ) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::save_token(login, token, expiration, &settings)
+        })
+    }
+
+    fn get_cds_host_for_part_db(&self, db_name: &str) -> Result<Option<CdsHosts>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_cds_host_for_part_db(db_name, &settings)
+        })
+    }
+
+    fn get_rcd_db_type(&self, db_name: &str) -> Result<RcdDatabaseType, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_rcd_db_type(db_name, &settings)
+        })
+    }
+
+    fn get_updates_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<UpdatesToHostBehavior, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_updates_to_host_behavior(db_name, table_name, &settings)
+        })
+    }
+
+    fn get_deletes_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<DeletesToHostBehavior, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_deletes_to_host_behavior(db_name, table_name, &settings)
+        })
+    }
+
+    fn get_deletes_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<DeletesFromHostBehavior, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_deletes_from_host_behavior(db_name, table_name, &settings)
+        })
+    }
+
+    fn get_updates_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<UpdatesFromHostBehavior, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_updates_from_host_behavior(db_name, table_name, &settings)
+        })
+    }
+
+    fn change_updates_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_updates_from_host_behavior(
+                db_name, table_name, behavior, &settings,
+            )
+        })
+    }
+
+    fn change_deletes_from_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_deletes_from_host_behavior(
+                db_name, table_name, behavior, &settings,
+            )
+        })
+    }
+
+    fn change_updates_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_updates_to_host_behavior(db_name, table_name, behavior, &settings)
+        })
+    }
+
+    fn change_deletes_to_host_behavior(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        behavior: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_deletes_to_host_behavior(db_name, table_name, behavior, &settings)
+        })
+    }
+
+    fn get_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_data_log_table_status(db_name, table_name, &settings)
+        })
+    }
+
+    fn set_data_log_table_status(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        use_data_log: bool,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::set_data_log_table_status(db_name, table_name, use_data_log, &settings)
+        })
+    }
+
+    fn get_pending_action_ttl(&self, db_name: &str, table_name: &str) -> Result<u32, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_pending_action_ttl(db_name, table_name, &settings)
+        })
+    }
+
+    fn set_pending_action_ttl(
+        &self,
+        db_name: &str,
+        table_name: &str,
+        ttl_seconds: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::set_pending_action_ttl(db_name, table_name, ttl_seconds, &settings)
+        })
+    }
+
+    fn change_host_status_by_id(&self, host_id: &str, status: u32) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_host_status_by_id(host_id, status, &settings)
+        })
+    }
+
+    fn update_host_protocol_version(
+        &self,
+        host_id_or_name: &str,
+        protocol_version: u32,
+    ) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::update_host_protocol_version(
+                host_id_or_name,
+                protocol_version,
+                &settings,
+            )
+        })
+    }
+
+    fn change_host_status_by_name(&self, host_name: &str, status: u32) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::change_host_status_by_name(host_name, status, &settings)
+        })
+    }
+
+    fn verify_host_by_id(&self, host_id: &str, token: Vec<u8>) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::verify_host_by_id(host_id, token, &settings)
+        })
+    }
+
+    fn verify_host_by_name(&self, host_name: &str, token: Vec<u8>) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::verify_host_by_name(host_name, token, &settings)
+        })
+    }
+
+    fn accept_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::contract::accept_pending_contract(host_name, &settings)
+        })
+    }
+
+    fn reject_pending_contract(&self, host_name: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::contract::reject_pending_contract(host_name, &settings)
+        })
+    }
+
+    fn get_pending_contracts(&self) -> Result<Vec<Contract>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::contract::get_contracts_by_status(&settings, ContractStatus::Pending)
+        })
+    }
+
+    fn get_accepted_contracts(&self) -> Result<Vec<Contract>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::contract::get_contracts_by_status(&settings, ContractStatus::Accepted)
+        })
+    }
+
+    fn save_contract(&self, contract: Contract) -> Result<RcdSaveContractResult, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+
+            super::rcd_db::contract::save_contract(contract, &settings)
+        })
+    }
+
+    fn has_role_name(&self, role_name: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::role::has_role_name(role_name, &settings)
+        })
+    }
+
+    fn add_login_to_role(&self, login: &str, role_name: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::role::add_login_to_role(login, role_name, &settings)
+        })
+    }
+
+    fn login_is_in_role(&self, login: &str, role_name: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::role::login_is_in_role(login, role_name, &settings)
+        })
+    }
+
+    fn create_login(&self, login: &str, pw: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::create_login(login, pw, &settings)
+        })
+    }
+
+    fn get_database_names(&self) -> Result<Vec<String>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_database_names(&settings)
+        })
+    }
+
+    fn has_login(&self, login: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::has_login_via_config(login, settings)
+        })
+    }
+
+    fn rcd_get_host_info(&self) -> Result<Option<HostInfo>, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_host_info(settings)
+        })
+    }
+
+    fn get_rcd_schema_version(&self) -> Result<u32, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::get_rcd_schema_version(&settings)
+        })
+    }
+
+    fn rcd_generate_host_info(&self, host_name: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::generate_host_info(host_name, settings)
+        })
+    }
+
+    fn if_rcd_host_info_exists(&self) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::if_host_info_exists(settings)
+        })
+    }
+
+    fn configure_admin_hash(&self, login: &str, hash: Vec<u8>) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::configure_admin_with_hash(login, hash, settings)
+        })
+    }
+
+    fn configure_admin(&self, login: &str, pw: &str) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::configure_admin(login, pw, settings)
+        })
+    }
+
+    fn verify_login(&self, login: &str, pw: &str) -> Result<bool, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::verify_login(login, pw, settings)
+        })
+    }
+
+    fn configure_rcd_db(&self) -> Result<(), RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::rcd_db::configure_rcd_db(&settings)
+        })
+    }
+
+    fn set_database_key(
+        &self,
+        _db_name: &str,
+        _db_type: RcdDatabaseType,
+        _key: &str,
+    ) -> Result<(), RcdDbError> {
+        Err(RcdDbError::EncryptionNotSupported("postgres".to_string()))
+    }
+
+    fn clear_database_key(
+        &self,
+        _db_name: &str,
+        _db_type: RcdDatabaseType,
+    ) -> Result<(), RcdDbError> {
+        Err(RcdDbError::EncryptionNotSupported("postgres".to_string()))
+    }
+
+    fn unlock_database(&self, _db_name: &str, _key: &str) -> Result<(), RcdDbError> {
+        Err(RcdDbError::EncryptionNotSupported("postgres".to_string()))
+    }
+
+    fn check_database_key(&self, _db_name: &str) -> Result<(), RcdDbError> {
+        // databases are never encrypted by rcd on this backend
+        Ok(())
+    }
+
+    fn snapshot_database(&self, _db_name: &str) -> Result<PathBuf, RcdDbError> {
+        Err(RcdDbError::RawExportNotSupported("postgres".to_string()))
+    }
+
+    fn count_user_rows(&self, db_name: &str) -> Result<u64, RcdDbError> {
+        self.run_postgres(|| {
+            let settings = self.config.clone();
+            super::count_user_rows(db_name, &settings)
+        })
+    }
+}
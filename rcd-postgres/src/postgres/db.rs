@@ -0,0 +1,505 @@
+use self::participant::create_participant_table;
+
+use super::{
+    execute_read, execute_write, get_db_conn, get_scalar_as_string, get_schema_of_table,
+    has_database, has_table, sql_text, DbiConfigPostgres,
+};
+use crate::postgres::has_any_rows;
+use guid_create::GUID;
+use postgres::Client;
+use rcd_common::table::*;
+use rcd_enum::{
+    column_type::ColumnType, database_type::DatabaseType,
+    logical_storage_policy::LogicalStoragePolicy, rcd_database_type::RcdDatabaseType,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{ColumnSchema, DatabaseSchema, TableSchema};
+use stdext::function_name;
+use tracing::trace;
+
+pub mod contract;
+pub mod logical_storage_policy;
+pub mod metadata;
+pub mod participant;
+pub mod pending_write;
+
+pub fn create_database(db_name: &str, config: DbiConfigPostgres) -> Result<Client, RcdDbError> {
+    super::get_db_conn(&config, db_name)
+}
+
+pub fn has_table_client_service(
+    db_name: &str,
+    table_name: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    has_table(table_name, &mut client)
+}
+
+pub fn has_cooperative_tables(
+    db_name: &str,
+    cmd: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    if !has_database(config, db_name)? {
+        let e = RcdDbError::DbNotFound(db_name.to_string());
+        return Err(e);
+    }
+
+    let mut has_cooperative_tables = false;
+
+    let tables = rcd_query::query_parser::get_table_names(cmd, DatabaseType::Postgres);
+
+    for table in tables {
+        let result = logical_storage_policy::get_logical_storage_policy(db_name, &table, config);
+
+        if let Ok(policy) = result {
+            match policy {
+                // a mirrored table is read from the host's own copy
+                LogicalStoragePolicy::ParticpantOwned | LogicalStoragePolicy::Shared => {
+                    has_cooperative_tables = true;
+                    break;
+                }
+                _ => {}
+            }
+        } else {
+            break;
+        }
+    }
+
+    Ok(has_cooperative_tables)
+}
+
+pub fn get_cooperative_tables(db_name: &str, cmd: &str, config: DbiConfigPostgres) -> Vec<String> {
+    let mut cooperative_tables: Vec<String> = Vec::new();
+
+    let tables = rcd_query::query_parser::get_table_names(cmd, DatabaseType::Postgres);
+
+    for table in &tables {
+        let result = logical_storage_policy::get_logical_storage_policy(db_name, table, &config);
+
+        if let Ok(policy) = result {
+            match policy {
+                // a mirrored table is read from the host's own copy
+                LogicalStoragePolicy::ParticpantOwned | LogicalStoragePolicy::Shared => {
+                    cooperative_tables.push(table.clone());
+                }
+                _ => {}
+            }
+        } else {
+            break;
+        }
+    }
+
+    cooperative_tables
+}
+
+/// Returns the user tables in the current schema. Postgres folds unquoted names to lower case,
+/// so the names are returned upper cased to line up with how they are written in SQL statements.
+fn get_all_user_table_names_in_db(client: &mut Client) -> Result<Vec<String>, RcdDbError> {
+    let mut result: Vec<String> = Vec::new();
+    let cmd = String::from(
+        "SELECT UPPER(table_name) FROM information_schema.tables WHERE table_schema = current_schema() AND table_type = 'BASE TABLE' AND table_name NOT LIKE 'coop_%'",
+    );
+    let names = execute_read(&cmd, client)?;
+
+    for row in names.rows {
+        for val in row.vals {
+            if let Some(data) = val.data {
+                result.push(data.data_string);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn save_schema_to_data_host_tables(
+    table_id: String,
+    schema: &Table,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    /*
+    Columns:
+        cid
+        name
+        type
+        notnull
+        dflt_value
+        pk
+     */
+
+    let rows = &schema.rows;
+    for row in rows {
+        if row.vals[1].col.name == "name" {
+            let col_name = match row.vals[1].data.as_ref() {
+                Some(data) => &data.data_string,
+                None => continue,
+            };
+
+            let mut col_check = String::from(
+                "SELECT
+                    COUNT(*) COUNT
+                FROM
+                    COOP_DATA_HOST_TABLE_COLUMNS
+                WHERE
+                    COLUMN_NAME = ':col_name'
+            ;",
+            );
+
+            col_check = col_check.replace(":col_name", col_name);
+            if !has_any_rows(col_check, client)? {
+                // we need to add the column schema to the data host tables
+                let col_id = GUID::rand();
+
+                let mut cmd = String::from(
+                    "
+                    INSERT INTO COOP_DATA_HOST_TABLE_COLUMNS
+                    (
+                        TABLE_ID,
+                        COLUMN_ID,
+                        COLUMN_NAME
+                    )
+                    VALUES
+                    (
+                        ':table_id',
+                        ':col_id',
+                        ':col_name'
+                    )
+                ;",
+                );
+
+                cmd = cmd.replace(":table_id", &table_id);
+                cmd = cmd.replace(":col_id", &col_id.to_string());
+                cmd = cmd.replace(":col_name", col_name);
+                client.execute(&cmd, &[])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Queries the COOP_REMOTES table for the table name and policy for each table in the database.
+/// If this returns an empty vector it means either this is a new database or we haven't audited the
+/// tables in the database. Generally, whenever we create a new table we should be adding the policy
+/// to this table an defaulting the policy to NONE.
+fn get_remote_status_for_tables(
+    client: &mut Client,
+) -> Result<Vec<(String, LogicalStoragePolicy)>, RcdDbError> {
+    let cmd = sql_text::Coop::text_get_logical_storage_policy_tables();
+    let mut table_policies: Vec<(String, LogicalStoragePolicy)> = Vec::new();
+
+    for row in client.query(&cmd, &[])? {
+        let table_name: String = row.try_get(0)?;
+        let policy: i32 = row.try_get(1)?;
+        table_policies.push((table_name, LogicalStoragePolicy::from_i64(policy as i64)));
+    }
+
+    Ok(table_policies)
+}
+
+/// Checks the COOP_DATA_HOST table to see if a database id has been generated and if not, creates and saves one.
+/// This is the id we will use to identify this database as having cooperative tables to participants
+fn populate_database_id(db_name: &str, client: &mut Client) -> Result<(), RcdDbError> {
+    let cmd = sql_text::Coop::text_get_count_from_data_host();
+    let has_database_id = has_any_rows(cmd, client)?;
+
+    if !has_database_id {
+        let cmd = sql_text::Coop::text_add_database_id_to_host();
+        let db_id = GUID::rand().to_string();
+        client.execute(&cmd, &[&db_id, &db_name])?;
+    }
+
+    Ok(())
+}
+
+/// Populates the COOP_DATA_HOST_* tables with the needed information such as database_id and
+/// the current database schema, if applicable.
+fn populate_data_host_tables(db_name: &str, client: &mut Client) -> Result<(), RcdDbError> {
+    populate_database_id(db_name, client)?;
+    let table_statuses = get_remote_status_for_tables(client)?;
+
+    for status in table_statuses {
+        // for each table that we have a logical storage policy
+        // we want to make sure that the contract tables (COOP_DATA_HOST_*)
+        // have the latest correct schema for each table. Note that
+        // we add tables even if the logical storage policy is NONE, because in rcd
+        // we want to be transparent about all the tables in the database
+
+        let table_name = &status.0;
+        let table_id = GUID::rand();
+
+        let statement = sql_text::Coop::text_get_count_from_data_host_tables_for_table(table_name);
+        if !has_any_rows(statement, client)? {
+            let cmd = sql_text::Coop::text_add_table_to_data_host_table(
+                table_name.to_string(),
+                table_id.to_string(),
+            );
+            client.execute(&cmd, &[])?;
+        }
+
+        // need to get schema and save it to the table
+        let schema = get_schema_of_table(table_name.to_string(), client)?;
+        save_schema_to_data_host_tables(table_id.to_string(), &schema, client)?;
+    }
+
+    Ok(())
+}
+
+/// Creates the COOP_DATA_HOST_* tables if they do not exist in the current database. These tables are used
+/// to store schema information and the database_id that we send to participants of this database. This
+/// data is usually contained at the participant in the database contract.
+fn create_data_host_tables(client: &mut Client) -> Result<(), RcdDbError> {
+    let mut cmd = sql_text::Coop::text_create_data_host_table();
+    client.execute(&cmd, &[])?;
+    cmd = sql_text::Coop::text_create_data_host_tables_table();
+    client.execute(&cmd, &[])?;
+    cmd = sql_text::Coop::text_create_data_host_tables_columns_table();
+    client.execute(&cmd, &[])?;
+    cmd = sql_text::Coop::text_create_data_remotes_table();
+    client.execute(&cmd, &[])?;
+
+    Ok(())
+}
+
+/// Reads the columns of the table into `ColumnSchema`s, using the output of `get_schema_of_table`
+fn get_column_schemas(
+    table_name: &str,
+    table_id: &str,
+    client: &mut Client,
+) -> Result<Vec<ColumnSchema>, RcdDbError> {
+    let mut columns: Vec<ColumnSchema> = Vec::new();
+    let schema = get_schema_of_table(table_name.to_string(), client)?;
+
+    trace!(
+        "[{}]: schema of table: {table_name:?} {schema:?}",
+        function_name!()
+    );
+
+    for row in schema.rows {
+        let mut cs = ColumnSchema {
+            column_id: String::from(""),
+            column_name: String::from(""),
+            column_type: 0,
+            column_length: 0,
+            is_nullable: false,
+            ordinal: 0,
+            table_id: table_id.to_string(),
+            is_primary_key: false,
+        };
+
+        for val in row.vals {
+            let item = match val.data.clone() {
+                Some(item) => item,
+                None => continue,
+            };
+
+            if val.col.name == "cid" {
+                cs.ordinal = item.data_string.parse().map_err(|_| {
+                    RcdDbError::General(format!(
+                        "`{}` is not a valid ordinal for table {table_name}",
+                        item.data_string
+                    ))
+                })?;
+            }
+
+            if val.col.name == "name" {
+                cs.column_name = item.data_string.clone();
+            }
+
+            if val.col.name == "type" {
+                let ct = ColumnType::data_type_to_enum_u32(item.data_string.clone());
+                let len = ColumnType::data_type_len(item.data_string.clone());
+
+                cs.column_type = ct;
+                cs.column_length = len;
+            }
+
+            if val.col.name == "notnull" {
+                cs.is_nullable = item.data_string == "0";
+            }
+
+            if val.col.name == "pk" {
+                cs.is_primary_key = item.data_string == "1";
+            }
+        }
+
+        columns.push(cs);
+    }
+
+    Ok(columns)
+}
+
+pub fn get_db_schema(db_name: &str, config: DbiConfigPostgres) -> Result<DatabaseSchema, RcdDbError> {
+    let mut cooperation_enabled = false;
+    let mut db_has_participants = false;
+
+    let client = &mut get_db_conn(&config, db_name)?;
+
+    if let Ok(is_enabled) = has_enable_coooperative_features(db_name, &config) {
+        cooperation_enabled = is_enabled;
+    }
+
+    if cooperation_enabled {
+        if let Ok(x) = has_participants(db_name, &config) {
+            db_has_participants = x;
+        }
+    }
+
+    // if this is a host db
+    if has_table("COOP_DATA_HOST", client)? {
+        let cmd = String::from("SELECT DATABASE_ID FROM COOP_DATA_HOST");
+        let db_id = get_scalar_as_string(cmd, client)?;
+
+        let mut db_schema = DatabaseSchema {
+            database_id: db_id.clone(),
+            database_name: db_name.to_string(),
+            tables: Vec::new(),
+            database_type: DatabaseType::to_u32(DatabaseType::Postgres),
+            rcd_database_type: RcdDatabaseType::to_u32(RcdDatabaseType::Host),
+            cooperation_enabled,
+            has_participants: db_has_participants,
+        };
+
+        let mut tables_in_db: Vec<(String, String)> = Vec::new();
+
+        for row in client.query("SELECT TABLE_ID, TABLE_NAME FROM COOP_DATA_TABLES", &[])? {
+            tables_in_db.push((row.try_get(0)?, row.try_get(1)?));
+        }
+
+        trace!("tables_in_db: {:?}", tables_in_db);
+
+        for t in &tables_in_db {
+            let policy =
+                logical_storage_policy::get_logical_storage_policy(db_name, &t.1, &config)?;
+
+            let ts = TableSchema {
+                table_name: t.1.clone(),
+                table_id: t.0.clone(),
+                database_id: db_id.clone(),
+                database_name: db_name.to_string(),
+                columns: get_column_schemas(&t.1, &t.0, client)?,
+                logical_storage_policy: LogicalStoragePolicy::to_u32(policy),
+            };
+
+            db_schema.tables.push(ts);
+        }
+
+        trace!("[{}]: {db_schema:?}", function_name!());
+
+        // get all remaining tables that don't have a policy defined, because we may want to set them
+        let table_names = get_all_user_table_names_in_db(client)?;
+
+        let existing_tables: Vec<String> =
+            tables_in_db.iter().map(|t| t.1.to_uppercase()).collect();
+
+        for table_name in &table_names {
+            if !existing_tables.contains(table_name) {
+                let ts = TableSchema {
+                    table_name: table_name.clone(),
+                    table_id: String::from(""),
+                    database_id: String::from(""),
+                    database_name: db_name.to_string(),
+                    columns: get_column_schemas(table_name, "", client)?,
+                    logical_storage_policy: LogicalStoragePolicy::to_u32(
+                        LogicalStoragePolicy::None,
+                    ),
+                };
+
+                db_schema.tables.push(ts);
+            }
+        }
+
+        return Ok(db_schema);
+    }
+
+    let mut db_schema = DatabaseSchema {
+        database_id: String::from(""),
+        database_name: db_name.to_string(),
+        tables: Vec::new(),
+        database_type: DatabaseType::to_u32(DatabaseType::Postgres),
+        rcd_database_type: RcdDatabaseType::to_u32(RcdDatabaseType::Partial),
+        cooperation_enabled,
+        has_participants: db_has_participants,
+    };
+
+    let table_names = get_all_user_table_names_in_db(client)?;
+
+    for table_name in &table_names {
+        let ts = TableSchema {
+            table_name: table_name.clone(),
+            table_id: String::from(""),
+            database_id: String::from(""),
+            database_name: db_name.to_string(),
+            columns: get_column_schemas(table_name, "", client)?,
+            logical_storage_policy: LogicalStoragePolicy::to_u32(LogicalStoragePolicy::None),
+        };
+
+        db_schema.tables.push(ts);
+    }
+
+    Ok(db_schema)
+}
+
+pub fn has_participants(db_name: &str, config: &DbiConfigPostgres) -> Result<bool, RcdDbError> {
+    if !has_database(config, db_name)? {
+        Err(RcdDbError::DbNotFound(db_name.to_string()))
+    } else {
+        let mut client = get_db_conn(config, db_name)?;
+        if has_table("COOP_PARTICIPANT", &mut client)? {
+            has_any_rows(
+                "SELECT COUNT(*) PARTICIPANTS FROM COOP_PARTICIPANT".to_string(),
+                &mut client,
+            )
+        } else {
+            Err(RcdDbError::TableNotFoundInDatabase(
+                "COOP_PARTICIPANT".to_string(),
+                db_name.to_string(),
+            ))
+        }
+    }
+}
+
+pub fn has_enable_coooperative_features(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    if !has_database(config, db_name)? {
+        Err(RcdDbError::DbNotFound(db_name.to_string()))
+    } else if db_name.contains(".dbpart") {
+        Ok(true)
+    } else {
+        let mut client = get_db_conn(config, db_name)?;
+        has_table("COOP_REMOTES", &mut client)
+    }
+}
+
+pub fn enable_coooperative_features(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<(), RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+
+    create_data_host_tables(&mut client)?;
+    create_participant_table(&mut client)?;
+    create_coop_contracts_table(&mut client)?;
+    populate_data_host_tables(db_name, &mut client)
+}
+
+fn create_coop_contracts_table(client: &mut Client) -> Result<(), RcdDbError> {
+    let cmd = String::from(
+        "CREATE TABLE IF NOT EXISTS COOP_DATABASE_CONTRACT
+    (
+        CONTRACT_ID CHAR(36) NOT NULL,
+        GENERATED_DATE_UTC TEXT NOT NULL,
+        DESCRIPTION VARCHAR(255),
+        RETIRED_DATE_UTC TEXT,
+        VERSION_ID CHAR(36) NOT NULL,
+        REMOTE_DELETE_BEHAVIOR INT
+    );",
+    );
+    execute_write(client, &cmd)?;
+
+    Ok(())
+}
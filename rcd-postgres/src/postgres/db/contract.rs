@@ -0,0 +1,338 @@
+use chrono::{DateTime, TimeZone, Utc};
+use guid_create::GUID;
+use postgres::{Client, Row};
+use rcd_common::{
+    coop_database_contract::CoopDatabaseContract,
+    coop_database_participant::CoopDatabaseParticipant, db::DbiConfigPostgres, defaults,
+};
+use tracing::{error, trace};
+
+use rcd_enum::{
+    logical_storage_policy::LogicalStoragePolicy,
+    rcd_generate_contract_error::RcdGenerateContractError,
+    remote_delete_behavior::RemoteDeleteBehavior,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::Participant;
+
+use crate::postgres::{execute_write, get_db_conn, has_any_rows};
+
+use super::logical_storage_policy::get_logical_storage_policy_for_all_user_tables;
+
+/// Attempts to generate a contract for the user database. This will first validate if all user
+/// tables have a logical storage policy set. If not it will return a generate contract error.
+/// If there is no existing contract, it will generate one. If there is an already existing active contract,
+/// it will retire that contract and generate a new one.
+pub fn generate_contract(
+    db_name: &str,
+    desc: &str,
+    remote_delete_behavior: RemoteDeleteBehavior,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdGenerateContractError> {
+    let client = &mut get_db_conn(&config, db_name).map_err(to_contract_error)?;
+    let policies = get_logical_storage_policy_for_all_user_tables(db_name, config)
+        .map_err(to_contract_error)?;
+
+    // check to see if all user tables have a logical storage policy set
+    // if any don't, return an error.
+    if policies.iter().any(|p| p.1 == LogicalStoragePolicy::None) {
+        let mut missing_policies = String::from("policy not set for ");
+
+        for p in policies {
+            if p.1 == LogicalStoragePolicy::None {
+                let message = format!("table {}, ", p.0);
+                missing_policies.push_str(&message);
+            }
+        }
+
+        let error = RcdGenerateContractError::NotAllTablesSet(missing_policies);
+        return Err(error);
+    }
+
+    let cmd = String::from("SELECT COUNT(*) TOTALCONTRACTS FROM COOP_DATABASE_CONTRACT");
+    if has_any_rows(cmd, client).map_err(to_contract_error)? {
+        // there are other contracts, we need to find the active one and retire it
+        // then generate a new contract
+        let contracts = get_all_database_contracts(client).map_err(to_contract_error)?;
+        trace!(
+            "generate contract: retire contracts count: {}",
+            contracts.len()
+        );
+        for con in contracts {
+            if !con.is_retired() {
+                trace!(
+                    "generate contract: retire contract {}",
+                    &con.contract_id.to_string()
+                );
+                retire_contract(con.version_id, client).map_err(to_contract_error)?;
+            }
+        }
+    }
+
+    let contract = CoopDatabaseContract {
+        contract_id: GUID::rand(),
+        generated_date: Utc::now(),
+        description: desc.to_string(),
+        retired_date: None,
+        version_id: GUID::rand(),
+        remote_delete_behavior: RemoteDeleteBehavior::to_u32(remote_delete_behavior),
+    };
+    save_contract_at_connection(contract, client).map_err(to_contract_error)?;
+
+    Ok(true)
+}
+
+fn to_contract_error(error: RcdDbError) -> RcdGenerateContractError {
+    RcdGenerateContractError::General(error.to_string())
+}
+
+pub fn save_contract_at_connection(
+    contract: CoopDatabaseContract,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    let mut cmd = String::from(
+        "SELECT COUNT(*) TOTALCOUNT FROM COOP_DATABASE_CONTRACT WHERE VERSION_ID = ':vid'",
+    );
+    cmd = cmd.replace(":vid", &contract.version_id.to_string());
+
+    let retired_date = contract.retired_date.map(|d| d.to_string());
+    let remote_delete_behavior = contract.remote_delete_behavior as i32;
+
+    if has_any_rows(cmd, client)? {
+        // this is an update
+        let cmd = String::from(
+            "
+            UPDATE COOP_DATABASE_CONTRACT
+            SET
+                CONTRACT_ID = $1,
+                GENERATED_DATE_UTC = $2,
+                DESCRIPTION = $3,
+                RETIRED_DATE_UTC = $4,
+                REMOTE_DELETE_BEHAVIOR = $5
+            WHERE
+                VERSION_ID = $6",
+        );
+
+        client.execute(
+            &cmd,
+            &[
+                &contract.contract_id.to_string(),
+                &contract.generated_date.to_string(),
+                &contract.description,
+                &retired_date,
+                &remote_delete_behavior,
+                &contract.version_id.to_string(),
+            ],
+        )?;
+    } else {
+        // this is an insert
+        let cmd = String::from(
+            "
+            INSERT INTO COOP_DATABASE_CONTRACT
+            (
+                CONTRACT_ID,
+                GENERATED_DATE_UTC,
+                DESCRIPTION,
+                RETIRED_DATE_UTC,
+                VERSION_ID,
+                REMOTE_DELETE_BEHAVIOR
+            )
+            VALUES
+            (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            );
+            ",
+        );
+
+        client.execute(
+            &cmd,
+            &[
+                &contract.contract_id.to_string(),
+                &contract.generated_date.to_string(),
+                &contract.description,
+                &retired_date,
+                &contract.version_id.to_string(),
+                &remote_delete_behavior,
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Maps a row of `CONTRACT_ID, GENERATED_DATE_UTC, DESCRIPTION, RETIRED_DATE_UTC, VERSION_ID,
+/// REMOTE_DELETE_BEHAVIOR` to a contract
+fn row_to_contract(row: &Row) -> Result<CoopDatabaseContract, RcdDbError> {
+    let contract_id: String = row.try_get(0)?;
+    let generated_date: String = row.try_get(1)?;
+    let retired_date: Option<String> = row.try_get(3)?;
+    let version_id: String = row.try_get(4)?;
+
+    let retired_date = match retired_date.filter(|d| !d.is_empty()) {
+        Some(d) => Some(parse_date(&d)?),
+        None => None,
+    };
+
+    Ok(CoopDatabaseContract {
+        contract_id: parse_guid(&contract_id)?,
+        generated_date: parse_date(&generated_date)?,
+        description: row.try_get::<_, Option<String>>(2)?.unwrap_or_default(),
+        retired_date,
+        version_id: parse_guid(&version_id)?,
+        remote_delete_behavior: row.try_get::<_, Option<i32>>(5)?.unwrap_or_default() as u32,
+    })
+}
+
+fn parse_guid(value: &str) -> Result<GUID, RcdDbError> {
+    GUID::parse(value.trim())
+        .map_err(|_| RcdDbError::General(format!("`{value}` is not a valid GUID")))
+}
+
+fn parse_date(value: &str) -> Result<DateTime<Utc>, RcdDbError> {
+    Utc::datetime_from_str(&Utc, value, defaults::DATETIME_STRING_FORMAT)
+        .map_err(|e| RcdDbError::General(format!("`{value}` is not a valid date: {e}")))
+}
+
+pub fn get_all_database_contracts(
+    client: &mut Client,
+) -> Result<Vec<CoopDatabaseContract>, RcdDbError> {
+    let cmd = String::from(
+        "SELECT
+        CONTRACT_ID,
+        GENERATED_DATE_UTC,
+        DESCRIPTION,
+        RETIRED_DATE_UTC,
+        VERSION_ID,
+        REMOTE_DELETE_BEHAVIOR
+    FROM
+        COOP_DATABASE_CONTRACT
+        ;
+        ",
+    );
+
+    client
+        .query(&cmd, &[])?
+        .iter()
+        .map(row_to_contract)
+        .collect()
+}
+
+pub fn update_participant_accepts_contract(
+    db_name: &str,
+    participant: CoopDatabaseParticipant,
+    participant_message: Participant,
+    accepted_contract_version_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+
+    let internal_id = participant.internal_id;
+    let participant_id = participant_message.participant_guid.clone();
+    let token = participant_message.token;
+
+    let cmd = String::from(
+        "
+    UPDATE
+        COOP_PARTICIPANT
+    SET
+        CONTRACT_STATUS = 3,
+        ACCEPTED_CONTRACT_VERSION_ID = $1,
+        PARTICIPANT_ID = $2,
+        TOKEN = $3
+    WHERE
+        INTERNAL_PARTICIPANT_ID = $4
+    ;
+    ",
+    );
+
+    let rows_affected = client.execute(
+        &cmd,
+        &[
+            &accepted_contract_version_id,
+            &participant_id,
+            &token,
+            &internal_id.to_string(),
+        ],
+    )?;
+
+    Ok(rows_affected > 0)
+}
+
+/// Records that the participant rejected the contract we sent it
+pub fn update_participant_rejects_contract(
+    db_name: &str,
+    participant: CoopDatabaseParticipant,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+
+    let cmd = String::from(
+        "
+    UPDATE
+        COOP_PARTICIPANT
+    SET
+        CONTRACT_STATUS = 4
+    WHERE
+        INTERNAL_PARTICIPANT_ID = $1
+    ;
+    ",
+    );
+
+    let rows_affected = client.execute(&cmd, &[&participant.internal_id.to_string()])?;
+
+    Ok(rows_affected > 0)
+}
+
+pub fn get_active_contract(
+    db_name: &str,
+    config: DbiConfigPostgres,
+) -> Result<CoopDatabaseContract, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+
+    let cmd = String::from(
+        "
+        SELECT
+            CONTRACT_ID,
+            GENERATED_DATE_UTC,
+            DESCRIPTION,
+            RETIRED_DATE_UTC,
+            VERSION_ID,
+            REMOTE_DELETE_BEHAVIOR
+        FROM
+            COOP_DATABASE_CONTRACT
+        WHERE
+            RETIRED_DATE_UTC IS NULL
+        ;",
+    );
+
+    let results: Vec<CoopDatabaseContract> = client
+        .query(&cmd, &[])?
+        .iter()
+        .map(row_to_contract)
+        .collect::<Result<_, RcdDbError>>()?;
+
+    match results.first() {
+        Some(contract) => Ok(contract.clone()),
+        None => {
+            error!("there is no active contract!");
+            Err(RcdDbError::General(format!(
+                "there is no active contract for {db_name}"
+            )))
+        }
+    }
+}
+
+/// Marks this contract as retired in the database with today's UTC date
+pub fn retire_contract(version_id: GUID, client: &mut Client) -> Result<(), RcdDbError> {
+    let mut cmd = String::from("UPDATE COOP_DATABASE_CONTRACT SET RETIRED_DATE_UTC = ':retire_date' WHERE VERSION_ID = ':vid'");
+    cmd = cmd.replace(":retire_date", &Utc::now().to_string());
+    cmd = cmd.replace(":vid", &version_id.to_string());
+    execute_write(client, &cmd)?;
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+use crate::postgres::{
+    add_column_if_missing, db_part::get_row_hashes, execute_write, get_db_conn, get_scalar_as_u64,
+    has_table, sql_text,
+};
+use postgres::Client;
+use rcd_common::db::{get_metadata_table_name, DbiConfigPostgres};
+use rcd_error::rcd_db_error::RcdDbError;
+use stdext::function_name;
+use tracing::trace;
+
+pub fn get_data_hash_at_host(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<u64, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+    let mut cmd = String::from("SELECT HASH FROM :metadata WHERE ROW_ID = :row_id");
+    cmd = cmd.replace(":metadata", &metadata_table_name);
+    cmd = cmd.replace(":row_id", &row_id.to_string());
+
+    get_scalar_as_u64(cmd, &mut client)?.ok_or_else(|| {
+        RcdDbError::General(format!(
+            "no hash is saved for row {row_id} of {table_name} in {db_name}"
+        ))
+    })
+}
+
+/// Hashes each of the rows in the host's own copy of the table
+pub fn get_row_hashes_at_host(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<u64>, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+
+    if !has_table(table_name, &mut client)? {
+        return Err(RcdDbError::TableNotFoundInDatabase(
+            table_name.to_string(),
+            db_name.to_string(),
+        ));
+    }
+
+    get_row_hashes(table_name, &mut client)
+}
+
+pub fn remove_remote_row_reference_from_host(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    let mut cmd = String::from(
+        "DELETE FROM :table_name
+         WHERE ROW_ID = $1
+    ;",
+    );
+
+    trace!("{cmd}");
+
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let rows = client.execute(&cmd, &[&(row_id as i32)])?;
+
+    trace!("total row_references_deleted: {rows}");
+
+    Ok(rows > 0)
+}
+
+/// Creates the metadata table for the specified table if it does not already exist, or adds
+/// the columns that a metadata table created by an earlier version of rcd is missing
+pub fn create_metadata_table_if_not_exists(
+    metadata_table_name: &str,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    if !has_table(metadata_table_name, client)? {
+        let mut cmd = sql_text::Coop::text_create_metadata_table();
+        cmd = cmd.replace(":table_name", metadata_table_name);
+        execute_write(client, &cmd)?;
+    } else {
+        add_column_if_missing(metadata_table_name, "IS_OUT_OF_SYNC", "INT", client)?;
+        add_column_if_missing(metadata_table_name, "IS_REMOTE_DELETED", "INT", client)?;
+    }
+
+    Ok(())
+}
+
+pub fn insert_metadata_into_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    hash: u64,
+    internal_participant_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut cmd = sql_text::Coop::text_insert_row_metadata_table();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let rows = client.execute(
+        &cmd,
+        &[
+            &(row_id as i32),
+            &hash.to_ne_bytes().to_vec(),
+            &internal_participant_id,
+        ],
+    )?;
+
+    Ok(rows > 0)
+}
+
+/// Removes the metadata for each of the rows, returning if every one of them was removed
+pub fn delete_metadata_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_ids: &[u32],
+    internal_participant_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut cmd = sql_text::Coop::text_delete_row_metadata_table();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    trace!("[{}]: statement: {cmd:?}", function_name!());
+
+    let mut rows = 0;
+    let mut has_every_row = true;
+
+    for row_id in row_ids {
+        let affected_rows = client.execute(&cmd, &[&(*row_id as i32), &internal_participant_id])?;
+        rows += affected_rows;
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
+
+    trace!("[{}]: rows affected: {rows:?}", function_name!());
+
+    Ok(has_every_row)
+}
+
+/// Saves the new hash for each of the `(row_id, hash)` rows, returning if every one of them
+/// was updated
+pub fn update_metadata_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    rows: &[(u32, u64)],
+    internal_participant_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut cmd = sql_text::Coop::text_update_row_metadata_table();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let mut has_every_row = true;
+
+    for (row_id, hash) in rows {
+        let affected_rows = client.execute(
+            &cmd,
+            &[
+                &hash.to_ne_bytes().to_vec(),
+                &(*row_id as i32),
+                &internal_participant_id,
+            ],
+        )?;
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
+
+    Ok(has_every_row)
+}
+
+/// Flags each of the rows as no longer matching what the participant holds, returning if every
+/// one of them was flagged
+pub fn mark_metadata_out_of_sync_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_ids: &[u32],
+    internal_participant_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut cmd = sql_text::Coop::text_mark_row_metadata_out_of_sync();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let mut total_rows = 0;
+    let mut has_every_row = true;
+
+    for row_id in row_ids {
+        let affected_rows = client.execute(&cmd, &[&(*row_id as i32), &internal_participant_id])?;
+        total_rows += affected_rows;
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
+
+    trace!("[{}]: rows affected: {total_rows:?}", function_name!());
+
+    Ok(has_every_row)
+}
+
+/// Keeps the row's metadata but flags it as deleted at the participant
+pub fn mark_metadata_remote_deleted_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    internal_participant_id: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut cmd = sql_text::Coop::text_mark_row_metadata_remote_deleted();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let total_rows = client.execute(&cmd, &[&(row_id as i32), &internal_participant_id])?;
+
+    trace!("[{}]: rows affected: {total_rows:?}", function_name!());
+
+    Ok(total_rows > 0)
+}
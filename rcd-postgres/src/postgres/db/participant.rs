@@ -0,0 +1,441 @@
+use guid_create::GUID;
+use postgres::{Client, Row};
+use rcd_common::{
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, CoopDatabaseParticipantRow,
+        NewCoopDatabaseParticipant,
+    },
+    db::{get_metadata_table_name, DbiConfigPostgres},
+    defaults,
+};
+use rcd_enum::contract_status::ContractStatus;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{Participant, ParticipantStatus};
+
+use super::metadata::create_metadata_table_if_not_exists;
+use crate::postgres::{add_column_if_missing, get_db_conn, has_any_rows, has_table};
+
+/// The columns of COOP_PARTICIPANT in the order expected by `row_to_participant`
+const PARTICIPANT_COLUMNS: &str = "
+            INTERNAL_PARTICIPANT_ID,
+            ALIAS,
+            IP4ADDRESS,
+            IP6ADDRESS,
+            PORT,
+            CONTRACT_STATUS,
+            ACCEPTED_CONTRACT_VERSION_ID,
+            TOKEN,
+            PARTICIPANT_ID,
+            HTTP_ADDR,
+            HTTP_PORT,
+            PROTOCOL_VERSION";
+
+/// Creates the COOP_PARTICIPANT table if it does not exist. This holds
+/// the participant information that are cooperating with this database.
+pub fn create_participant_table(client: &mut Client) -> Result<(), RcdDbError> {
+    let cmd = String::from(
+        "CREATE TABLE IF NOT EXISTS COOP_PARTICIPANT
+    (
+        INTERNAL_PARTICIPANT_ID CHAR(36) NOT NULL,
+        ALIAS VARCHAR(50) NOT NULL,
+        IP4ADDRESS VARCHAR(25),
+        IP6ADDRESS VARCHAR(25),
+        PORT INT,
+        CONTRACT_STATUS INT,
+        ACCEPTED_CONTRACT_VERSION_ID CHAR(36),
+        TOKEN BYTEA NOT NULL,
+        PARTICIPANT_ID CHAR(36),
+        HTTP_ADDR VARCHAR(50),
+        HTTP_PORT INT,
+        PROTOCOL_VERSION INT DEFAULT 0
+    );",
+    );
+
+    client.execute(&cmd, &[])?;
+
+    Ok(())
+}
+
+/// Returns a connection to the host database, with COOP_PARTICIPANT brought up to the current
+/// layout if it was created by an earlier version of rcd
+fn get_participant_conn(config: &DbiConfigPostgres, db_name: &str) -> Result<Client, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+
+    if has_table("COOP_PARTICIPANT", &mut client)? {
+        add_column_if_missing(
+            "COOP_PARTICIPANT",
+            "PROTOCOL_VERSION",
+            "INT DEFAULT 0",
+            &mut client,
+        )?;
+    }
+
+    Ok(client)
+}
+
+/// Maps a row selected with `PARTICIPANT_COLUMNS` to a participant
+fn row_to_participant(row: &Row) -> Result<CoopDatabaseParticipant, RcdDbError> {
+    let internal_id: String = row.try_get(0)?;
+    let accepted_contract_version_id: Option<String> = row.try_get(6)?;
+    let id: Option<String> = row.try_get(8)?;
+
+    Ok(CoopDatabaseParticipant {
+        internal_id: parse_guid(&internal_id)?,
+        alias: row.try_get(1)?,
+        ip4addr: row.try_get::<_, Option<String>>(2)?.unwrap_or_default(),
+        ip6addr: row.try_get::<_, Option<String>>(3)?.unwrap_or_default(),
+        db_port: row.try_get::<_, Option<i32>>(4)?.unwrap_or_default() as u32,
+        contract_status: ContractStatus::from_i64(
+            row.try_get::<_, Option<i32>>(5)?.unwrap_or_default() as i64,
+        ),
+        accepted_contract_version: parse_guid(
+            &accepted_contract_version_id.unwrap_or_else(|| defaults::EMPTY_GUID.to_string()),
+        )?,
+        token: row.try_get(7)?,
+        id: parse_guid(&id.unwrap_or_else(|| defaults::EMPTY_GUID.to_string()))?,
+        http_addr: row.try_get::<_, Option<String>>(9)?.unwrap_or_default(),
+        http_port: row.try_get::<_, Option<i32>>(10)?.unwrap_or_default() as u16,
+        protocol_version: row.try_get::<_, Option<i32>>(11)?.unwrap_or_default() as u32,
+    })
+}
+
+fn parse_guid(value: &str) -> Result<GUID, RcdDbError> {
+    GUID::parse(value.trim())
+        .map_err(|_| RcdDbError::General(format!("`{value}` is not a valid GUID")))
+}
+
+pub fn save_participant(
+    participant: CoopDatabaseParticipant,
+    mut client: Client,
+) -> Result<(), RcdDbError> {
+    let contract_status = ContractStatus::to_u32(participant.contract_status) as i32;
+
+    if has_participant_at_conn(&participant.alias, &mut client)? {
+        // this is an update
+        let cmd = String::from(
+            "
+        UPDATE COOP_PARTICIPANT
+        SET
+            IP4ADDRESS = $1,
+            IP6ADDRESS = $2,
+            PORT = $3,
+            CONTRACT_STATUS = $4,
+            ACCEPTED_CONTRACT_VERSION_ID = $5,
+            TOKEN = $6,
+            PARTICIPANT_ID = $7,
+            HTTP_ADDR = $8,
+            HTTP_PORT = $9
+        WHERE
+            ALIAS = $10
+        ;
+        ",
+        );
+
+        client.execute(
+            &cmd,
+            &[
+                &participant.ip4addr,
+                &participant.ip6addr,
+                &(participant.db_port as i32),
+                &contract_status,
+                &participant.accepted_contract_version.to_string(),
+                &participant.token,
+                &participant.id.to_string(),
+                &participant.http_addr,
+                &(participant.http_port as i32),
+                &participant.alias,
+            ],
+        )?;
+    } else {
+        // this is an insert
+        let cmd = String::from(
+            "
+        INSERT INTO COOP_PARTICIPANT
+        (
+            INTERNAL_PARTICIPANT_ID,
+            ALIAS,
+            IP4ADDRESS,
+            IP6ADDRESS,
+            PORT,
+            CONTRACT_STATUS,
+            ACCEPTED_CONTRACT_VERSION_ID,
+            TOKEN,
+            PARTICIPANT_ID,
+            HTTP_ADDR,
+            HTTP_PORT
+        )
+        VALUES
+        (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6,
+            $7,
+            $8,
+            $9,
+            $10,
+            $11
+        );
+        ",
+        );
+
+        client.execute(
+            &cmd,
+            &[
+                &participant.internal_id.to_string(),
+                &participant.alias,
+                &participant.ip4addr,
+                &participant.ip6addr,
+                &(participant.db_port as i32),
+                &contract_status,
+                &participant.accepted_contract_version.to_string(),
+                &participant.token,
+                &participant.id.to_string(),
+                &participant.http_addr,
+                &(participant.http_port as i32),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn add_participant(
+    db_name: &str,
+    participant: NewCoopDatabaseParticipant,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let client = get_db_conn(&config, db_name)?;
+
+    let NewCoopDatabaseParticipant {
+        alias,
+        ip4addr,
+        db_port,
+        http_addr,
+        http_port,
+        id,
+    } = participant;
+
+    let db_host_id = match id {
+        Some(id) => parse_guid(&id)?,
+        None => parse_guid(defaults::EMPTY_GUID)?,
+    };
+
+    let is_added: bool = if has_participant(db_name, &alias, config)? {
+        false
+    } else {
+        let participant = CoopDatabaseParticipant {
+            internal_id: GUID::rand(),
+            alias,
+            ip4addr,
+            ip6addr: String::from(""),
+            db_port,
+            contract_status: ContractStatus::NotSent,
+            accepted_contract_version: parse_guid(defaults::EMPTY_GUID)?,
+            id: db_host_id,
+            token: Vec::new(),
+            http_addr,
+            http_port,
+            protocol_version: 0,
+        };
+        save_participant(participant, client)?;
+        true
+    };
+
+    Ok(is_added)
+}
+
+pub fn get_participant_by_internal_id(
+    db_name: &str,
+    internal_id: &str,
+    config: &DbiConfigPostgres,
+) -> Result<CoopDatabaseParticipant, RcdDbError> {
+    let mut client = get_participant_conn(config, db_name)?;
+    let cmd = format!(
+        "SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE INTERNAL_PARTICIPANT_ID = $1;"
+    );
+
+    let rows = client.query(&cmd, &[&internal_id])?;
+    match rows.first() {
+        Some(row) => row_to_participant(row),
+        None => Err(RcdDbError::General(format!(
+            "no participant with internal id {internal_id} in {db_name}"
+        ))),
+    }
+}
+
+pub fn get_participant_by_id(
+    db_name: &str,
+    id: &str,
+    config: DbiConfigPostgres,
+) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+    let mut client = get_participant_conn(&config, db_name)?;
+    let cmd =
+        format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE PARTICIPANT_ID = $1;");
+
+    let rows = client.query(&cmd, &[&id])?;
+    rows.first().map(row_to_participant).transpose()
+}
+
+pub fn get_participant_by_alias(
+    db_name: &str,
+    alias: &str,
+    config: DbiConfigPostgres,
+) -> Result<Option<CoopDatabaseParticipant>, RcdDbError> {
+    let mut client = get_participant_conn(&config, db_name)?;
+    let cmd = format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT WHERE ALIAS = $1;");
+
+    let rows = client.query(&cmd, &[&alias])?;
+    rows.first().map(row_to_participant).transpose()
+}
+
+pub fn get_participants_for_database(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<ParticipantStatus>, RcdDbError> {
+    let mut result: Vec<ParticipantStatus> = Vec::new();
+
+    let mut client = get_participant_conn(config, db_name)?;
+
+    // if the table doesn't exist, we should return an error here
+    if !has_table("COOP_PARTICIPANT", &mut client)? {
+        return Err(RcdDbError::TableNotFoundInDatabase(
+            "COOP_PARTICIPANT".to_string(),
+            db_name.to_string(),
+        ));
+    }
+
+    let cmd = format!("SELECT {PARTICIPANT_COLUMNS} FROM COOP_PARTICIPANT");
+
+    for row in client.query(&cmd, &[])? {
+        let participant = row_to_participant(&row)?;
+
+        let p = Participant {
+            participant_guid: participant.id.to_string(),
+            alias: participant.alias,
+            ip4_address: participant.ip4addr,
+            ip6_address: participant.ip6addr,
+            database_port_number: participant.db_port,
+            token: Vec::new(),
+            internal_participant_guid: participant.internal_id.to_string(),
+            http_addr: participant.http_addr,
+            http_port: participant.http_port as u32,
+        };
+
+        let ps = ParticipantStatus {
+            participant: Some(p),
+            contract_status: ContractStatus::to_u32(participant.contract_status),
+        };
+
+        result.push(ps);
+    }
+
+    Ok(result)
+}
+
+pub fn get_participants_for_table(
+    db_name: &str,
+    table_name: &str,
+    config: DbiConfigPostgres,
+) -> Result<Vec<CoopDatabaseParticipantData>, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &mut client)?;
+
+    let mut result: Vec<CoopDatabaseParticipantData> = Vec::new();
+
+    let mut cmd = String::from(
+        "
+        SELECT DISTINCT
+            INTERNAL_PARTICIPANT_ID
+        FROM
+            :table_name
+        ;",
+    );
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    let participant_ids: Vec<String> = client
+        .query(&cmd, &[])?
+        .iter()
+        .map(|row| row.try_get(0))
+        .collect::<Result<Vec<String>, _>>()?;
+
+    let db_participants: Vec<CoopDatabaseParticipant> = participant_ids
+        .iter()
+        .map(|pid| get_participant_by_internal_id(db_name, pid, &config))
+        .collect::<Result<Vec<CoopDatabaseParticipant>, RcdDbError>>()?;
+
+    for p in &db_participants {
+        let mut cmd = String::from(
+            "
+            SELECT
+                ROW_ID,
+                HASH,
+                COALESCE(IS_OUT_OF_SYNC, 0),
+                COALESCE(IS_REMOTE_DELETED, 0)
+            FROM
+                :table_name
+            WHERE
+                INTERNAL_PARTICIPANT_ID = $1
+            ;",
+        );
+        cmd = cmd.replace(":table_name", &metadata_table_name);
+
+        let row_data_results: Vec<CoopDatabaseParticipantRow> = client
+            .query(&cmd, &[&p.internal_id.to_string()])?
+            .iter()
+            .map(|row| {
+                Ok(CoopDatabaseParticipantRow {
+                    row_id: row.try_get::<_, i32>(0)? as u32,
+                    hash: row.try_get(1)?,
+                    is_out_of_sync: row.try_get::<_, i32>(2)? > 0,
+                    is_remote_deleted: row.try_get::<_, i32>(3)? > 0,
+                })
+            })
+            .collect::<Result<_, postgres::Error>>()?;
+
+        let participant_data = CoopDatabaseParticipantData {
+            participant: p.clone(),
+            db_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+            row_data: row_data_results,
+        };
+
+        result.push(participant_data);
+    }
+
+    Ok(result)
+}
+
+pub fn has_participant_at_conn(alias: &str, client: &mut Client) -> Result<bool, RcdDbError> {
+    let mut cmd =
+        String::from("SELECT COUNT(*) TOTALCOUNT FROM COOP_PARTICIPANT WHERE ALIAS = ':alias'");
+    cmd = cmd.replace(":alias", alias);
+    has_any_rows(cmd, client)
+}
+
+pub fn has_participant(
+    db_name: &str,
+    alias: &str,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(&config, db_name)?;
+    has_participant_at_conn(alias, &mut client)
+}
+
+pub fn update_participant_protocol_version(
+    db_name: &str,
+    alias: &str,
+    protocol_version: u32,
+    config: DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_participant_conn(&config, db_name)?;
+    let cmd = "UPDATE COOP_PARTICIPANT SET PROTOCOL_VERSION = $1 WHERE ALIAS = $2";
+
+    let result = client.execute(cmd, &[&(protocol_version as i32), &alias])?;
+
+    Ok(result > 0)
+}
@@ -0,0 +1,140 @@
+use crate::postgres::{execute_write, get_db_conn, has_table, sql_text};
+use postgres::Client;
+use rcd_common::db::DbiConfigPostgres;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::PendingWrite;
+use stdext::function_name;
+use tracing::trace;
+
+fn create_pending_writes_table_if_not_exists(client: &mut Client) -> Result<(), RcdDbError> {
+    if !has_table("COOP_PENDING_WRITES", client)? {
+        let cmd = sql_text::Coop::text_create_pending_writes_table();
+        execute_write(client, &cmd)?;
+    }
+
+    Ok(())
+}
+
+pub fn add_pending_write_at_host(
+    db_name: &str,
+    internal_participant_id: &str,
+    pending_write: &PendingWrite,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut client)?;
+
+    let cmd = "
+        INSERT INTO COOP_PENDING_WRITES
+        (
+            TABLE_NAME,
+            INTERNAL_PARTICIPANT_ID,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        )
+        VALUES
+        (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6,
+            $7,
+            $8
+        )
+    ;";
+
+    let rows = client.execute(
+        cmd,
+        &[
+            &pending_write.table_name,
+            &internal_participant_id,
+            &pending_write.participant_alias,
+            &(pending_write.pending_action_id as i32),
+            &pending_write.statement,
+            &pending_write.where_clause,
+            &pending_write.action,
+            &pending_write.requested_ts_utc,
+        ],
+    )?;
+
+    Ok(rows > 0)
+}
+
+pub fn remove_pending_write_at_host(
+    db_name: &str,
+    table_name: &str,
+    internal_participant_id: &str,
+    pending_action_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut client)?;
+
+    let cmd = "
+        DELETE FROM COOP_PENDING_WRITES
+        WHERE
+            TABLE_NAME = $1
+        AND
+            INTERNAL_PARTICIPANT_ID = $2
+        AND
+            PENDING_ACTION_ID = $3
+    ;";
+
+    let rows = client.execute(
+        cmd,
+        &[
+            &table_name,
+            &internal_participant_id,
+            &(pending_action_id as i32),
+        ],
+    )?;
+
+    trace!("[{}]: rows affected: {rows:?}", function_name!());
+
+    Ok(rows > 0)
+}
+
+pub fn get_pending_writes_at_host(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<PendingWrite>, RcdDbError> {
+    let mut client = get_db_conn(config, db_name)?;
+    create_pending_writes_table_if_not_exists(&mut client)?;
+
+    let cmd = "
+        SELECT
+            TABLE_NAME,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        FROM
+            COOP_PENDING_WRITES
+    ;";
+
+    let pending_writes = client
+        .query(cmd, &[])?
+        .iter()
+        .map(|row| {
+            Ok(PendingWrite {
+                table_name: row.try_get(0)?,
+                participant_alias: row.try_get(1)?,
+                pending_action_id: row.try_get::<_, i32>(2)? as u32,
+                statement: row.try_get(3)?,
+                where_clause: row.try_get::<_, Option<String>>(4)?.unwrap_or_default(),
+                action: row.try_get(5)?,
+                requested_ts_utc: row.try_get(6)?,
+            })
+        })
+        .collect::<Result<Vec<PendingWrite>, postgres::Error>>()?;
+
+    Ok(pending_writes)
+}
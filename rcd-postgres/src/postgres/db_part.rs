@@ -0,0 +1,558 @@
+use super::{
+    execute_read_at_participant, execute_read_on_connection_for_row, execute_write, get_data_string,
+    get_db_conn, get_scalar_as_string, get_scalar_as_u64, get_table_col_names,
+    get_table_col_names_with_data_type_as_string, has_table, sql_text,
+};
+use chrono::Utc;
+use postgres::{types::Type, Client, SimpleQueryMessage};
+use rcd_common::crypt::{self, RowValue};
+use rcd_common::db::{
+    get_data_log_table_name, get_data_queue_table_name, get_metadata_table_name, DbiConfigPostgres,
+    PartialDataResult, RehashedRow,
+};
+use rcd_common::defaults;
+use rcd_enum::column_type::ColumnType;
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcdproto::rcdp::{Contract, PendingStatement, TableSchema};
+use stdext::function_name;
+use tracing::{trace, warn};
+
+pub mod delete;
+pub mod insert;
+pub mod update;
+
+pub fn accept_pending_action_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut action_result = PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: None,
+        affected_rows: Vec::new(),
+    };
+
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+    let mut cmd = String::from("SELECT STATEMENT FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let sql_update_statement = get_scalar_as_string(cmd, &mut client)?;
+    let mut cmd = String::from("SELECT WHERE_CLAUSE FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+    let where_clause = get_scalar_as_string(cmd, &mut client)?;
+
+    cmd = String::from("SELECT ACTION FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let action = get_scalar_as_string(cmd, &mut client)?;
+
+    if action == "UPDATE" {
+        action_result = update::handle_update_pending_action(
+            db_name,
+            table_name,
+            &sql_update_statement,
+            &where_clause,
+            row_id,
+            config,
+        )?;
+    }
+
+    if action == "DELETE" {
+        action_result = delete::handle_delete_pending_action(
+            db_name,
+            table_name,
+            &sql_update_statement,
+            &where_clause,
+            row_id,
+            config,
+        )?;
+    }
+
+    Ok(action_result)
+}
+
+/// Removes the action from the table's queue without running it
+pub fn reject_pending_action_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+
+    let mut cmd = String::from("SELECT ACTION FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let action = match get_scalar_as_string(cmd, &mut client)?.as_str() {
+        "UPDATE" => Some(PartialDataResultAction::Update),
+        "DELETE" => Some(PartialDataResultAction::Delete),
+        _ => None,
+    };
+
+    let mut cmd = String::from("DELETE FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let total_rows = if action.is_some() {
+        execute_write(&mut client, &cmd)?
+    } else {
+        0
+    };
+
+    trace!("[{}]: total rows rejected: {total_rows}", function_name!());
+
+    Ok(PartialDataResult {
+        is_successful: total_rows > 0,
+        row_id,
+        data_hash: None,
+        partial_data_status: None,
+        action,
+        affected_rows: Vec::new(),
+    })
+}
+
+pub fn get_data_hash_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<u64, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let metadata_table_name = get_metadata_table_name(table_name);
+    let mut cmd = String::from("SELECT HASH FROM :metadata WHERE ROW_ID = :row_id");
+    cmd = cmd.replace(":metadata", &metadata_table_name);
+    cmd = cmd.replace(":row_id", &row_id.to_string());
+
+    get_scalar_as_u64(cmd, &mut client)?.ok_or_else(|| {
+        RcdDbError::General(format!(
+            "no hash is saved for row {row_id} of {table_name} in {db_name}"
+        ))
+    })
+}
+
+/// Recomputes the hash of every row tracked in the metadata tables of the partial database,
+/// saving the new hashes and returning them so they can be sent to the host
+pub fn rehash_partial_database(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<RehashedRow>, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+
+    // unquoted names are folded to lower case by Postgres
+    let cmd = "SELECT UPPER(table_name) FROM information_schema.tables WHERE table_schema = current_schema() AND table_type = 'BASE TABLE'";
+    let mut table_names: Vec<String> = Vec::new();
+
+    for row in client.query(cmd, &[])? {
+        let name: String = row.try_get(0)?;
+
+        if let Some(table_name) = name.strip_suffix(defaults::METADATA_TABLE_SUFFIX) {
+            table_names.push(table_name.to_string());
+        }
+    }
+
+    let mut rehashed_rows: Vec<RehashedRow> = Vec::new();
+
+    for table_name in &table_names {
+        let metadata_table_name = get_metadata_table_name(table_name);
+
+        let cmd = format!("SELECT ROW_ID FROM {metadata_table_name}");
+        let row_ids: Vec<u32> = client
+            .query(&cmd, &[])?
+            .iter()
+            .map(|row| Ok(row.try_get::<_, i32>(0)? as u32))
+            .collect::<Result<_, RcdDbError>>()?;
+
+        let cmd = format!("UPDATE {metadata_table_name} SET HASH = $1 WHERE ROW_ID = $2");
+
+        for row_id in row_ids {
+            if let Some(hash) = get_row_hash(table_name, row_id, &mut client)? {
+                client.execute(&cmd, &[&hash.to_ne_bytes().to_vec(), &(row_id as i32)])?;
+
+                rehashed_rows.push(RehashedRow {
+                    table_name: table_name.clone(),
+                    row_id,
+                    hash,
+                });
+            }
+        }
+    }
+
+    set_row_hash_version(&mut client)?;
+
+    Ok(rehashed_rows)
+}
+
+/// Records that the hashes saved in the partial database were calculated with the current
+/// `ROW_HASH_VERSION`
+fn set_row_hash_version(client: &mut Client) -> Result<(), RcdDbError> {
+    client.batch_execute(&sql_text::Coop::text_create_row_hash_version_table())?;
+    client.batch_execute("DELETE FROM COOP_ROW_HASH_VERSION;")?;
+    client.execute(
+        "INSERT INTO COOP_ROW_HASH_VERSION (VERSION) VALUES ($1);",
+        &[&i32::from(crypt::ROW_HASH_VERSION)],
+    )?;
+
+    Ok(())
+}
+
+/// Returns the `ROW_HASH_VERSION` the hashes saved in the partial database were calculated with,
+/// or `None` if it was created before the version was recorded
+pub fn get_row_hash_version(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Option<u8>, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+
+    if !has_table("COOP_ROW_HASH_VERSION", &mut client)? {
+        return Ok(None);
+    }
+
+    let row = client.query_opt("SELECT VERSION FROM COOP_ROW_HASH_VERSION", &[])?;
+    row.map(|row| {
+        let version: i32 = row.try_get(0)?;
+        u8::try_from(version).map_err(|e| RcdDbError::General(e.to_string()))
+    })
+    .transpose()
+}
+
+/// Hashes the values currently stored in the row, or `None` if the row doesn't exist
+pub fn get_row_hash(
+    table_name: &str,
+    row_id: u32,
+    client: &mut Client,
+) -> Result<Option<u64>, RcdDbError> {
+    let col_names = get_table_col_names(table_name.to_string(), client)?;
+    let cmd = format!(
+        "SELECT {} FROM {table_name} WHERE ROWID = {row_id}",
+        col_names.join(",")
+    );
+
+    let rows = read_as_row_values(&cmd, client)?;
+    Ok(rows
+        .first()
+        .map(|row_values| crypt::calculate_hash_for_row(row_values)))
+}
+
+/// Hashes the values of every row in the table the same way `get_row_hash` does, so that a
+/// host's own copy of a table can be compared against the hashes a participant has sent it
+pub fn get_row_hashes(table_name: &str, client: &mut Client) -> Result<Vec<u64>, RcdDbError> {
+    let col_names = get_table_col_names(table_name.to_string(), client)?;
+    let cmd = format!("SELECT {} FROM {table_name}", col_names.join(","));
+
+    let rows = read_as_row_values(&cmd, client)?;
+    Ok(rows
+        .iter()
+        .map(|row_values| crypt::calculate_hash_for_row(row_values))
+        .collect())
+}
+
+/// Reads the rows the statement returns as the values that `calculate_hash_for_row` hashes.
+/// Values come back over the simple query protocol as text, so `bytea` values are decoded
+/// from the hex format Postgres returns them in.
+fn read_as_row_values(cmd: &str, client: &mut Client) -> Result<Vec<Vec<RowValue>>, RcdDbError> {
+    let statement = client.prepare(cmd)?;
+    let is_bytea: Vec<bool> = statement
+        .columns()
+        .iter()
+        .map(|c| *c.type_() == Type::BYTEA)
+        .collect();
+
+    let mut rows: Vec<Vec<RowValue>> = Vec::new();
+
+    for message in client.simple_query(cmd)? {
+        if let SimpleQueryMessage::Row(row) = message {
+            let values = is_bytea
+                .iter()
+                .enumerate()
+                .map(|(i, is_bytea)| {
+                    Ok(match row.try_get(i)? {
+                        None => RowValue::Null,
+                        Some(text) if *is_bytea => RowValue::Blob(decode_bytea_hex(text)),
+                        Some(text) => RowValue::Text(text.to_string()),
+                    })
+                })
+                .collect::<Result<_, RcdDbError>>()?;
+
+            rows.push(values);
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Decodes a `bytea` value in Postgres' hex output format, e.g. `\xdeadbeef`
+fn decode_bytea_hex(text: &str) -> Vec<u8> {
+    let hex = text.strip_prefix("\\x").unwrap_or(text);
+    (0..hex.len())
+        .step_by(2)
+        .filter_map(|i| hex.get(i..i + 2))
+        .filter_map(|byte| u8::from_str_radix(byte, 16).ok())
+        .collect()
+}
+
+pub fn get_pending_actions(
+    db_name: &str,
+    table_name: &str,
+    action: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<PendingStatement>, RcdDbError> {
+    let update_queue = get_data_queue_table_name(table_name);
+
+    let mut pending_statements: Vec<PendingStatement> = Vec::new();
+
+    // nothing has been queued for the table yet
+    let mut client = get_partial_db_connection(db_name, config)?;
+    if !has_table(&update_queue, &mut client)? {
+        return Ok(pending_statements);
+    }
+
+    let mut cmd = String::from(
+        "
+        SELECT
+            ID,
+            STATEMENT,
+            REQUESTED_TS_UTC,
+            HOST_ID
+        FROM
+            :table
+        WHERE
+            ACTION = ':action'
+        ;",
+    );
+    cmd = cmd.replace(":table", &update_queue);
+    cmd = cmd.replace(":action", action);
+
+    let pending_rows = execute_read_at_participant(db_name, &cmd, config)?;
+
+    for row in &pending_rows.rows {
+        let mut rid: u32 = 0;
+        let mut statement: String = String::from("");
+        let mut ts: String = String::from("");
+        let mut host_id: String = String::from("");
+
+        // Postgres folds unquoted column names to lower case
+        for val in &row.vals {
+            if val.col.name.eq_ignore_ascii_case("ID") {
+                rid = get_data_string(val).parse::<u32>().unwrap_or_default();
+            }
+
+            if val.col.name.eq_ignore_ascii_case("STATEMENT") {
+                statement = get_data_string(val);
+            }
+
+            if val.col.name.eq_ignore_ascii_case("REQUESTED_TS_UTC") {
+                ts = get_data_string(val);
+            }
+
+            if val.col.name.eq_ignore_ascii_case("HOST_ID") {
+                host_id = get_data_string(val);
+            }
+        }
+
+        let ps = PendingStatement {
+            row_id: rid,
+            statement,
+            requested_ts_utc: ts,
+            host_id,
+            action: action.to_string(),
+        };
+
+        pending_statements.push(ps);
+    }
+
+    Ok(pending_statements)
+}
+
+pub fn get_row_from_partial_database(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<rcdproto::rcdp::Row, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+
+    // the ROWID column is ours, not part of the table in the contract, so leave it out
+    let col_names = get_table_col_names(table_name.to_string(), &mut client)?.join(",");
+
+    let mut cmd = String::from("SELECT :col_names from :table_name WHERE ROWID = :rid");
+    cmd = cmd.replace(":col_names", &col_names);
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    trace!("[{}]: {config:?}", function_name!());
+
+    execute_read_on_connection_for_row(db_name, table_name, row_id, cmd, &mut client)
+}
+
+pub fn create_partial_database_from_contract(
+    contract: &Contract,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    trace!("{config:?}");
+    trace!("{contract:?}");
+
+    let schema = contract
+        .schema
+        .as_ref()
+        .ok_or_else(|| RcdDbError::General(String::from("the contract has no schema")))?;
+
+    let mut client = create_partial_database(&schema.database_name, config)?;
+
+    for table in &schema.tables {
+        create_table_from_schema(table, &mut client)?;
+    }
+
+    set_row_hash_version(&mut client)?;
+
+    Ok(true)
+}
+
+pub fn create_partial_database(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Client, RcdDbError> {
+    get_db_conn(config, &get_partial_db_name(db_name))
+}
+
+pub fn read_row_ids_from_part_db(
+    db_name: &str,
+    table_name: &str,
+    where_clause: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Vec<u32>, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let mut cmd = String::from("SELECT ROWID FROM :table_name WHERE :where_clause");
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":where_clause", where_clause);
+
+    Ok(client
+        .query(&cmd, &[])?
+        .iter()
+        .map(|row| row.try_get::<_, i32>(0).map(|row_id| row_id as u32))
+        .collect::<Result<Vec<u32>, _>>()?)
+}
+
+/// Returns the name of the schema holding the partial database for the specified database
+pub fn get_partial_db_name(db_name: &str) -> String {
+    let mut db_part_name = db_name.replace(".dbpart", "");
+    db_part_name = db_part_name.replace(".db", "");
+    format!("{}{}", db_part_name, String::from(".dbpart"))
+}
+
+pub fn get_partial_db_connection(
+    db_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<Client, RcdDbError> {
+    get_db_conn(config, &get_partial_db_name(db_name))
+}
+
+/// Creates the table from the contract in the partial database. Sqlite gives every table a ROWID;
+/// Postgres does not, so we add one ourselves to identify the rows the host references.
+fn create_table_from_schema(
+    table_schema: &TableSchema,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    trace!("{table_schema:?}");
+
+    let table_name = table_schema.table_name.clone();
+
+    if table_name.contains("_COOP_") {
+        warn!("create_table_from_schema - skipping table: {table_name:?}");
+        return Ok(());
+    }
+
+    let mut cmd = String::from("CREATE TABLE IF NOT EXISTS :tablename ");
+    cmd = cmd.replace(":tablename", &table_name);
+    cmd += " ( ROWID SERIAL, ";
+
+    let last_column = table_schema
+        .columns
+        .last()
+        .map(|column| column.column_name.clone())
+        .unwrap_or_default();
+
+    for column in &table_schema.columns {
+        let col_name = column.column_name.clone();
+        let col_type = ColumnType::from_u32(column.column_type).data_type_as_string_postgres();
+        let mut col_length = String::from("");
+
+        if column.column_length > 0 {
+            col_length += " ( ";
+            col_length = col_length + &column.column_length.to_string();
+            col_length += " ) ";
+        }
+
+        let mut col_nullable = String::from("");
+
+        if !column.is_nullable {
+            col_nullable = String::from("NOT NULL");
+        }
+
+        let col_statement: String = if last_column == column.column_name {
+            format!(" {col_name} {col_type} {col_length} {col_nullable} ")
+        } else {
+            format!(" {col_name} {col_type} {col_length} {col_nullable} , ")
+        };
+
+        cmd = cmd + &col_statement;
+    }
+    cmd += " ) ";
+
+    trace!("{cmd:?}");
+
+    execute_write(client, &cmd)?;
+
+    Ok(())
+}
+
+fn add_record_to_log_table(
+    db_name: &str,
+    table_name: &str,
+    where_clause: &str,
+    action: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let data_log_table = get_data_log_table_name(table_name);
+    let client = &mut get_partial_db_connection(db_name, config)?;
+
+    if !has_table(&data_log_table, client)? {
+        let mut cmd = sql_text::Coop::text_create_data_log_table();
+        let table_col_names =
+            get_table_col_names_with_data_type_as_string(db_name, table_name, config)?;
+        cmd = cmd.replace(":column_list", &table_col_names);
+        cmd = cmd.replace(":table_name", &data_log_table);
+
+        execute_write(client, &cmd)?;
+    }
+
+    // copy every row that we're about to change into the data_log_table, letting Postgres
+    // carry the values over with their types instead of round tripping them through strings
+    let col_names = get_table_col_names(table_name.to_string(), client)?.join(",");
+
+    let mut cmd = String::from(
+        "INSERT INTO :data_log_table ( :cols, ROW_ID, ACTION, TS_UTC ) SELECT :cols, ROWID, ':action', ':ts_utc' FROM :table_name WHERE :where_clause",
+    );
+    cmd = cmd.replace(":data_log_table", &data_log_table);
+    cmd = cmd.replace(":cols", &col_names);
+    cmd = cmd.replace(":action", action);
+    cmd = cmd.replace(":ts_utc", &Utc::now().to_string());
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":where_clause", where_clause);
+
+    trace!("{cmd:?}");
+
+    execute_write(client, &cmd)?;
+
+    Ok(true)
+}
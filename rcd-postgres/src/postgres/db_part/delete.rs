@@ -0,0 +1,292 @@
+use chrono::Utc;
+use stdext::function_name;
+use tracing::{trace, warn};
+
+use crate::postgres::{
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::{get_data_log_table_status, get_deletes_from_host_behavior},
+    sql_text,
+};
+use rcd_common::{
+    db::{get_data_queue_table_name, AffectedRow, DbiConfigPostgres, PartialDataResult},
+    defaults,
+};
+
+use super::{add_record_to_log_table, get_partial_db_connection};
+use rcd_enum::{
+    deletes_from_host_behavior::DeletesFromHostBehavior,
+    partial_data_result_action::PartialDataResultAction, partial_data_status::PartialDataStatus,
+};
+use rcd_error::rcd_db_error::RcdDbError;
+
+pub fn delete_data_into_partial_db_queue(
+    db_name: &str,
+    table_name: &str,
+    delete_statement: &str,
+    where_clause: &str,
+    host_id: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let queue_log_table = get_data_queue_table_name(table_name);
+    let client = &mut get_partial_db_connection(db_name, config)?;
+
+    if !has_table(&queue_log_table, client)? {
+        let mut cmd = sql_text::Coop::text_create_data_queue_table();
+        cmd = cmd.replace(":table_name", &queue_log_table);
+        execute_write(client, &cmd)?;
+    }
+
+    let mut cmd = String::from("SELECT MAX(ID) FROM :table_name");
+    cmd = cmd.replace(":table_name", &queue_log_table);
+
+    let max_id = get_scalar_as_u32(cmd, client)?;
+    let next_id = max_id + 1;
+
+    cmd = String::from(
+        "
+        INSERT INTO :table_name
+        (
+            ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            REQUESTED_TS_UTC,
+            HOST_ID,
+            ACTION
+        )
+        VALUES
+        (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            'DELETE'
+        )
+    ;",
+    );
+
+    cmd = cmd.replace(":table_name", &queue_log_table);
+
+    trace!("[{}] {cmd:?}", function_name!());
+
+    let rows_affected = client.execute(
+        &cmd,
+        &[
+            &(next_id as i32),
+            &delete_statement,
+            &where_clause,
+            &Utc::now().to_string(),
+            &host_id,
+        ],
+    )?;
+
+    trace!("[{}] rows_affected: {rows_affected:?}", function_name!());
+
+    Ok(PartialDataResult {
+        is_successful: rows_affected > 0,
+        row_id: next_id,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Delete),
+        affected_rows: Vec::new(),
+    })
+}
+
+pub fn delete_data_in_partial_db(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    host_id: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let behavior = get_deletes_from_host_behavior(db_name, table_name, config)?;
+
+    trace!("[{}] behavior: {behavior:?}", function_name!());
+
+    match behavior {
+        DeletesFromHostBehavior::Unknown | DeletesFromHostBehavior::Ignore => {
+            warn!(
+                "[{}]: not applying delete to {db_name}.{table_name}, behavior is {behavior:?}",
+                function_name!()
+            );
+            Ok(PartialDataResult {
+                is_successful: false,
+                row_id: 0,
+                data_hash: None,
+                partial_data_status: Some(PartialDataStatus::to_u32(PartialDataStatus::Ignored)),
+                action: Some(PartialDataResultAction::Delete),
+                affected_rows: Vec::new(),
+            })
+        }
+        DeletesFromHostBehavior::AllowRemoval => {
+            execute_delete_and_log_if_enabled(db_name, table_name, cmd, where_clause, config)
+        }
+        DeletesFromHostBehavior::QueueForReview | DeletesFromHostBehavior::QueueForReviewAndLog => {
+            delete_data_into_partial_db_queue(
+                db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                config,
+            )
+        }
+        DeletesFromHostBehavior::DeleteWithLog => {
+            execute_delete_with_log(db_name, table_name, cmd, where_clause, config)
+        }
+    }
+}
+
+fn execute_delete(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let original_cmd = cmd;
+
+    trace!("[{}] {cmd:?}", function_name!());
+
+    let mut cmd;
+    cmd = String::from("SELECT ROWID FROM :table_name WHERE :where_clause")
+        .replace(":table_name", table_name);
+
+    if !where_clause.is_empty() {
+        cmd = cmd.replace(":where_clause", where_clause);
+    } else {
+        cmd = cmd.replace("WHERE", "");
+        cmd = cmd.replace(":where_clause", "");
+    }
+
+    // we need to determine the row_ids that we're going to update because we're going to need to delete them
+    let client = &mut get_partial_db_connection(db_name, config)?;
+
+    // once we have the row ids, then we will delete the rows in the actual and metadata table
+    let row_ids: Vec<u32> = client
+        .query(&cmd, &[])?
+        .iter()
+        .map(|row| row.try_get::<_, i32>(0).map(|row_id| row_id as u32))
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    trace!("[{}]: {row_ids:?}", function_name!());
+
+    let total_rows = execute_write(client, original_cmd)?;
+
+    trace!("[{}]: total rows deleted: {total_rows}", function_name!());
+
+    if total_rows != row_ids.len() {
+        return Err(RcdDbError::General(String::from(
+            "the delete statement did not match the expected count of affected rows",
+        )));
+    }
+
+    // now we need to delete data from the metadata table
+    let metadata_table_name = format!("{}{}", table_name, defaults::METADATA_TABLE_SUFFIX);
+    let mut cmd = String::from("DELETE FROM :table_name WHERE ROW_ID = $1");
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    for row in &row_ids {
+        client.execute(&cmd, &[&(*row as i32)])?;
+    }
+
+    let deleted_row_id = row_ids.first().copied().unwrap_or_default();
+
+    trace!("[{}]: deleted_row_id: {deleted_row_id:?}", function_name!());
+
+    let result = PartialDataResult {
+        is_successful: true,
+        row_id: deleted_row_id,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Delete),
+        affected_rows: row_ids
+            .iter()
+            .map(|row_id| AffectedRow {
+                row_id: *row_id,
+                data_hash: None,
+            })
+            .collect(),
+    };
+
+    trace!("[{}]: {result:?}", function_name!());
+
+    Ok(result)
+}
+
+pub fn handle_delete_pending_action(
+    db_name: &str,
+    table_name: &str,
+    sql_update_statement: &str,
+    where_clause: &str,
+    row_id: u32,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+    let queue_table_name = get_data_queue_table_name(table_name);
+
+    let behavior = get_deletes_from_host_behavior(db_name, table_name, config)?;
+
+    let mut update_result = PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Delete),
+        affected_rows: Vec::new(),
+    };
+
+    if behavior == DeletesFromHostBehavior::QueueForReview {
+        update_result = execute_delete_and_log_if_enabled(
+            db_name,
+            table_name,
+            sql_update_statement,
+            where_clause,
+            config,
+        )?;
+    } else if behavior == DeletesFromHostBehavior::QueueForReviewAndLog {
+        update_result = execute_delete_with_log(
+            db_name,
+            table_name,
+            sql_update_statement,
+            where_clause,
+            config,
+        )?;
+    }
+
+    if update_result.is_successful {
+        let mut cmd = String::from("DELETE FROM :table_name WHERE ID = :rid");
+        cmd = cmd.replace(":table_name", &queue_table_name);
+        cmd = cmd.replace(":rid", &row_id.to_string());
+        execute_write(&mut client, &cmd)?;
+    }
+
+    Ok(update_result)
+}
+
+fn execute_delete_with_log(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    add_record_to_log_table(db_name, table_name, where_clause, "DELETE", config)?;
+    execute_delete(db_name, table_name, cmd, where_clause, config)
+}
+
+/// Removes the rows, logging them first if the data log table is switched on for the table
+fn execute_delete_and_log_if_enabled(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    if get_data_log_table_status(db_name, table_name, config)? {
+        execute_delete_with_log(db_name, table_name, cmd, where_clause, config)
+    } else {
+        execute_delete(db_name, table_name, cmd, where_clause, config)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::postgres::{
+    db_part::{add_record_to_log_table, get_partial_db_connection, get_row_hash},
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::get_data_log_table_status,
+    sql_text,
+};
+use postgres::Client;
+use rcd_common::db::*;
+use rcd_common::db::{AffectedRow, DbiConfigPostgres, PartialDataResult};
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rcd_error::rcd_db_error::RcdDbError;
+use tracing::{debug, warn};
+
+pub fn insert_data_into_partial_db(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    config: &DbiConfigPostgres,
+) -> Result<PartialDataResult, RcdDbError> {
+    let mut client = get_partial_db_connection(db_name, config)?;
+
+    let total_rows = insert_single_row(&mut client, cmd)?;
+    if total_rows != 1 {
+        warn!(
+            "the insert into {table_name} would have added {total_rows} rows, it was rolled back"
+        );
+        return Ok(failed_insert_result());
+    }
+
+    // the ROWID column is a SERIAL, so this is the value it was just given
+    let cmd = String::from("SELECT lastval()");
+    let row_id = get_scalar_as_u32(cmd, &mut client)?;
+
+    // we need to hash the values of this row as they were stored
+    let hash_value = get_row_hash(table_name, row_id, &mut client)?.unwrap_or_default();
+
+    // we need to determine if there is a metadata table for this table or not
+    // and if there is not one, create it
+    // then we need to save the data hash along with the row id
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    if !has_table(&metadata_table_name, &mut client)? {
+        //  need to create table
+        let mut cmd = sql_text::Coop::text_create_metadata_table();
+        cmd = cmd.replace(":table_name", &metadata_table_name);
+        execute_write(&mut client, &cmd)?;
+    }
+
+    let mut cmd = sql_text::Coop::text_insert_row_metadata_table();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+
+    debug!("{row_id:?}");
+    debug!("{hash_value:?}");
+
+    client.execute(
+        &cmd,
+        &[
+            &(row_id as i32),
+            &hash_value.to_ne_bytes().to_vec(),
+            &None::<String>,
+        ],
+    )?;
+
+    // there's nothing to keep from before an insert, so the data log gets the row as it was inserted
+    if get_data_log_table_status(db_name, table_name, config)? {
+        let where_clause = format!("ROWID = {row_id}");
+        add_record_to_log_table(db_name, table_name, &where_clause, "INSERT", config)?;
+    }
+
+    Ok(PartialDataResult {
+        is_successful: true,
+        row_id,
+        data_hash: Some(hash_value),
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Insert),
+        affected_rows: vec![AffectedRow {
+            row_id,
+            data_hash: Some(hash_value),
+        }],
+    })
+}
+
+/// Runs the INSERT in a transaction that is only committed if it added exactly one row. We
+/// track a row's metadata and tell the host about it one row at a time, so the rows of a
+/// multi-row INSERT would otherwise be left behind without a reference at the host
+fn insert_single_row(client: &mut Client, cmd: &str) -> Result<u64, RcdDbError> {
+    let mut tx = client.transaction()?;
+    let total_rows = tx.execute(cmd, &[])?;
+
+    if total_rows == 1 {
+        tx.commit()?;
+    } else {
+        tx.rollback()?;
+    }
+
+    Ok(total_rows)
+}
+
+/// The result of an INSERT that was rolled back or could not be run
+fn failed_insert_result() -> PartialDataResult {
+    PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Insert),
+        affected_rows: Vec::new(),
+    }
+}
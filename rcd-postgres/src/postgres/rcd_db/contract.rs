@@ -0,0 +1,605 @@
+use postgres::Client;
+use rcdproto::rcdp::{ColumnSchema, Contract, DatabaseSchema, Host, Participant, TableSchema};
+use tracing::{debug, warn};
+
+use crate::postgres::{
+    db::{has_enable_coooperative_features, has_participants},
+    execute_write, get_scalar_as_string, get_scalar_as_u32, has_any_rows,
+};
+
+use super::{get_host_info, get_rcd_conn, has_contract, row_to_host, CDS_HOSTS_COLUMNS};
+use chrono::Utc;
+use rcd_common::{
+    db::{
+        CdsContracts, CdsContractsTables, CdsContractsTablesColumns, CdsHosts, DbiConfigPostgres,
+    },
+    save_contract_result::RcdSaveContractResult,
+};
+use rcd_enum::contract_status::ContractStatus;
+use rcd_error::rcd_db_error::RcdDbError;
+
+pub fn accept_pending_contract(
+    host_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    update_pending_contract_status(host_name, ContractStatus::Accepted, config)
+}
+
+pub fn reject_pending_contract(
+    host_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    update_pending_contract_status(host_name, ContractStatus::Rejected, config)
+}
+
+/// Sets the status of the pending contract from the host, returning `false` if there isn't one
+fn update_pending_contract_status(
+    host_name: &str,
+    contract_status: ContractStatus,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+
+    let mut cmd = String::from("SELECT HOST_ID FROM CDS_HOSTS WHERE HOST_NAME = ':hostname'");
+    cmd = cmd.replace(":hostname", host_name);
+
+    let db_host_id = get_scalar_as_string(cmd, &mut client)?;
+    cmd = String::from(
+        "SELECT COUNT(*) TOTALCOUNT FROM CDS_CONTRACTS WHERE HOST_ID = ':hid'
+    AND CONTRACT_STATUS = 2",
+    );
+    cmd = cmd.replace(":hid", &db_host_id);
+
+    let has_pending_contract = has_any_rows(cmd, &mut client)?;
+
+    if has_pending_contract {
+        cmd = String::from(
+            "SELECT CONTRACT_ID FROM CDS_CONTRACTS WHERE HOST_ID = ':hid' AND CONTRACT_STATUS = 2",
+        );
+        cmd = cmd.replace(":hid", &db_host_id);
+
+        let cid = get_scalar_as_string(cmd, &mut client)?;
+
+        cmd = String::from(
+            "UPDATE CDS_CONTRACTS SET CONTRACT_STATUS = :status WHERE CONTRACT_ID = ':cid'",
+        );
+        cmd = cmd.replace(
+            ":status",
+            &ContractStatus::to_u32(contract_status).to_string(),
+        );
+        cmd = cmd.replace(":cid", &cid);
+
+        let total_count = execute_write(&mut client, &cmd)?;
+        return Ok(total_count > 0);
+    }
+
+    Ok(false)
+}
+
+pub fn get_contracts_by_status(
+    config: &DbiConfigPostgres,
+    contract_status: ContractStatus,
+) -> Result<Vec<Contract>, RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+
+    let u32_contract_status = ContractStatus::to_u32(contract_status);
+
+    let cmd = String::from(
+        "
+        SELECT
+            HOST_ID,
+            CONTRACT_ID,
+            CONTRACT_VERSION_ID,
+            DATABASE_NAME,
+            DATABASE_ID,
+            DESCRIPTION,
+            GENERATED_DATE_UTC,
+            CONTRACT_STATUS
+        FROM
+            CDS_CONTRACTS
+        WHERE
+            CONTRACT_STATUS = $1",
+    );
+
+    let mut pending_contracts: Vec<Contract> = Vec::new();
+
+    let mut cds_tables: Vec<CdsContractsTables> = Vec::new();
+    let mut cds_tables_columns: Vec<CdsContractsTablesColumns> = Vec::new();
+    let mut cds_host_infos: Vec<CdsHosts> = Vec::new();
+
+    let mut cds_contracts: Vec<CdsContracts> = Vec::new();
+
+    for row in client.query(&cmd, &[&(u32_contract_status as i32)])? {
+        cds_contracts.push(CdsContracts {
+            host_id: row.try_get(0)?,
+            contract_id: row.try_get(1)?,
+            contract_version_id: row.try_get(2)?,
+            database_name: row.try_get(3)?,
+            database_id: row.try_get(4)?,
+            description: row.try_get::<_, Option<String>>(5)?.unwrap_or_default(),
+            generated_date: row.try_get::<_, Option<String>>(6)?.unwrap_or_default(),
+            contract_status: ContractStatus::from_u32(
+                row.try_get::<_, Option<i32>>(7)?.unwrap_or_default() as u32,
+            ),
+        });
+    }
+
+    for cdata in &cds_contracts {
+        let cmd = String::from(
+            "
+        SELECT
+            DATABASE_ID,
+            DATABASE_NAME,
+            TABLE_ID,
+            TABLE_NAME,
+            LOGICAL_STORAGE_POLICY
+        FROM
+            CDS_CONTRACTS_TABLES
+        WHERE
+            DATABASE_ID = $1",
+        );
+
+        for row in client.query(&cmd, &[&cdata.database_id])? {
+            cds_tables.push(CdsContractsTables {
+                database_id: row.try_get(0)?,
+                database_name: row.try_get(1)?,
+                table_id: row.try_get(2)?,
+                table_name: row.try_get(3)?,
+                logical_storage_policy: row.try_get::<_, Option<i32>>(4)?.unwrap_or_default()
+                    as u32,
+            });
+        }
+    }
+
+    for table in &cds_tables {
+        let cmd = String::from(
+            "
+        SELECT
+            TABLE_ID,
+            COLUMN_ID,
+            COLUMN_NAME,
+            COLUMN_TYPE,
+            COLUMN_LENGTH,
+            COLUMN_ORDINAL,
+            IS_NULLABLE
+        FROM
+            CDS_CONTRACTS_TABLE_SCHEMAS
+        WHERE
+            TABLE_ID = $1",
+        );
+
+        for row in client.query(&cmd, &[&table.table_id])? {
+            cds_tables_columns.push(CdsContractsTablesColumns {
+                table_id: row.try_get(0)?,
+                column_id: row.try_get(1)?,
+                column_name: row.try_get(2)?,
+                column_type: row.try_get::<_, i32>(3)? as u32,
+                column_length: row.try_get::<_, i32>(4)? as u32,
+                column_ordinal: row.try_get::<_, i32>(5)? as u32,
+                is_nullable: row.try_get::<_, Option<i32>>(6)?.unwrap_or_default() == 1,
+            });
+        }
+    }
+
+    let cmd = format!("SELECT {CDS_HOSTS_COLUMNS} FROM CDS_HOSTS WHERE HOST_ID = $1;");
+
+    for c in &cds_contracts {
+        for row in client.query(&cmd, &[&c.host_id])? {
+            cds_host_infos.push(row_to_host(&row)?);
+        }
+    }
+
+    let mut db_schema: Vec<DatabaseSchema> = Vec::new();
+
+    for contract in &cds_contracts {
+        let dbid = contract.database_id.clone();
+        let tables = cds_tables.iter().filter(|t| t.database_id == dbid);
+
+        let mut table_schema: Vec<TableSchema> = Vec::new();
+
+        for t in tables {
+            let mut col_schema: Vec<ColumnSchema> = Vec::new();
+
+            let tid = t.table_id.clone();
+            let cols = cds_tables_columns.iter().filter(|c| c.table_id == tid);
+
+            for c in cols {
+                let cs = ColumnSchema {
+                    column_name: c.column_name.clone(),
+                    column_type: c.column_type,
+                    column_length: c.column_length,
+                    is_nullable: c.is_nullable,
+                    ordinal: c.column_ordinal,
+                    table_id: c.table_id.clone(),
+                    column_id: c.column_id.clone(),
+                    is_primary_key: false,
+                };
+                col_schema.push(cs);
+            }
+
+            let ts = TableSchema {
+                table_name: t.table_name.clone(),
+                table_id: t.table_id.clone(),
+                database_name: t.database_name.clone(),
+                database_id: t.database_id.clone(),
+                columns: col_schema,
+                logical_storage_policy: t.logical_storage_policy,
+            };
+
+            table_schema.push(ts);
+        }
+
+        let mut cooperation_enabled = false;
+        let mut db_has_participants = false;
+
+        if let Ok(is_enabled) = has_enable_coooperative_features(&contract.database_name, config) {
+            cooperation_enabled = is_enabled;
+        }
+
+        if cooperation_enabled {
+            if let Ok(x) = has_participants(&contract.database_name, config) {
+                db_has_participants = x;
+            }
+        }
+
+        let ds = DatabaseSchema {
+            database_name: contract.database_name.clone(),
+            database_id: contract.database_id.clone(),
+            tables: table_schema,
+            database_type: 0,
+            rcd_database_type: 0,
+            cooperation_enabled,
+            has_participants: db_has_participants,
+        };
+
+        db_schema.push(ds);
+    }
+
+    for c in &cds_contracts {
+        let schema = db_schema
+            .iter()
+            .rev()
+            .find(|s| s.database_id == c.database_id)
+            .cloned();
+
+        let h = match cds_host_infos.iter().rev().find(|h| h.host_id == c.host_id) {
+            Some(h) => h.clone(),
+            None => {
+                return Err(RcdDbError::General(format!(
+                    "no host {} saved for contract {}",
+                    c.host_id, c.contract_id
+                )))
+            }
+        };
+
+        let i = Host {
+            host_guid: h.host_id.clone(),
+            host_name: h.host_name.clone(),
+            ip4_address: h.ip4.clone(),
+            ip6_address: h.ip6.clone(),
+            database_port_number: h.port,
+            token: h.token.clone(),
+            http_addr: h.http_addr.clone(),
+            http_port: h.http_port,
+        };
+
+        let pc = Contract {
+            contract_guid: c.contract_id.clone(),
+            description: c.description.clone(),
+            schema,
+            contract_version: c.contract_version_id.clone(),
+            host_info: Some(i.clone()),
+            status: ContractStatus::to_u32(c.contract_status),
+        };
+
+        pending_contracts.push(pc);
+    }
+
+    Ok(pending_contracts)
+}
+
+/// Saves a contract sent from a host to our local rcd_db instance. This lets us
+/// later review the contract for us to accept or reject it. If we accept it
+/// this means that we'll create a partial database with the contract's schema
+/// and also notify the host that we are willing to be a participant of the database.
+pub fn save_contract(
+    contract: Contract,
+    config: &DbiConfigPostgres,
+) -> Result<RcdSaveContractResult, RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+
+    if !has_contract(&contract.contract_guid, &mut client)? {
+        save_contract_metadata(&contract, &mut client)?;
+        save_contract_table_data(&contract, &mut client)?;
+        save_contract_table_schema_data(&contract, &mut client)?;
+        save_contract_host_data(&contract, &mut client)?;
+
+        return Ok(RcdSaveContractResult {
+            is_successful: true,
+            contract_status: ContractStatus::from_u32(contract.status),
+            participant_information: None,
+        });
+    }
+
+    warn!(
+        "contract already exists for: {} version: {}",
+        contract.contract_guid, contract.contract_version
+    );
+
+    let cmd = "SELECT COUNT(*) cnt from CDS_CONTRACTS WHERE CONTRACT_ID = ':cid' AND CONTRACT_VERSION_ID = ':vid'";
+    let cmd = cmd
+        .replace(":cid", &contract.contract_guid)
+        .replace(":vid", &contract.contract_version);
+
+    if !has_any_rows(cmd, &mut client)? {
+        let result = RcdSaveContractResult {
+            is_successful: false,
+            contract_status: ContractStatus::Unknown,
+            participant_information: None,
+        };
+        debug!("{result:?}");
+        return Ok(result);
+    }
+
+    let cmd = "SELECT CONTRACT_STATUS FROM CDS_CONTRACTS WHERE CONTRACT_ID = ':cid' AND CONTRACT_VERSION_ID = ':vid'";
+    let cmd = cmd
+        .replace(":cid", &contract.contract_guid)
+        .replace(":vid", &contract.contract_version);
+
+    let status = get_scalar_as_u32(cmd, &mut client)?;
+    let contract_status = ContractStatus::from_u32(status);
+
+    let mut participant_information = None;
+
+    if contract_status == ContractStatus::Accepted {
+        debug!("contract was already accepted, sending back acceptance info");
+
+        if let Some(host_info) = get_host_info(config.clone())? {
+            participant_information = Some(Participant {
+                participant_guid: host_info.id.clone(),
+                alias: host_info.name.clone(),
+                ip4_address: "".to_string(),
+                ip6_address: String::from(""),
+                database_port_number: 0,
+                token: host_info.token,
+                internal_participant_guid: "".to_string(),
+                http_addr: "".to_string(),
+                http_port: 0,
+            });
+        }
+    }
+
+    let result = RcdSaveContractResult {
+        is_successful: false,
+        contract_status,
+        participant_information,
+    };
+
+    debug!("{result:?}");
+    Ok(result)
+}
+
+/// saves a contract's table information to CDS_CONTRACTS_TABLES
+fn save_contract_table_data(contract: &Contract, client: &mut Client) -> Result<(), RcdDbError> {
+    let cmd = String::from(
+        "INSERT INTO CDS_CONTRACTS_TABLES
+    (
+        DATABASE_ID,
+        DATABASE_NAME,
+        TABLE_ID,
+        TABLE_NAME,
+        LOGICAL_STORAGE_POLICY,
+        UPDATES_FROM_HOST_BEHAVIOR,
+        DELETES_FROM_HOST_BEHAVIOR,
+        UPDATES_TO_HOST_BEHAVIOR,
+        DELETES_TO_HOST_BEHAVIOR,
+        USE_DATA_LOG_TABLE,
+        PENDING_ACTION_TTL_SECONDS
+    )
+    VALUES
+    (
+        $1,
+        $2,
+        $3,
+        $4,
+        $5,
+        1,
+        1,
+        1,
+        1,
+        0,
+        0
+    )
+    ;
+    ",
+    );
+
+    let schema = get_contract_schema(contract)?;
+
+    let db_name = schema.database_name.clone();
+    let db_id = schema.database_id.clone();
+
+    for t in &schema.tables {
+        client.execute(
+            &cmd,
+            &[
+                &db_id,
+                &db_name,
+                &t.table_id,
+                &t.table_name,
+                &(t.logical_storage_policy as i32),
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// saves top level contract data to rcd_db's CDS_CONTRACTS table
+fn save_contract_metadata(contract: &Contract, client: &mut Client) -> Result<(), RcdDbError> {
+    let host = get_contract_host(contract)?;
+    let db = get_contract_schema(contract)?;
+
+    let cmd = String::from(
+        "INSERT INTO CDS_CONTRACTS
+    (
+        HOST_ID,
+        CONTRACT_ID,
+        CONTRACT_VERSION_ID,
+        DATABASE_NAME,
+        DATABASE_ID,
+        DESCRIPTION,
+        GENERATED_DATE_UTC,
+        CONTRACT_STATUS
+    )
+    VALUES
+    (
+        $1,
+        $2,
+        $3,
+        $4,
+        $5,
+        $6,
+        $7,
+        $8
+    )
+    ;",
+    );
+
+    client.execute(
+        &cmd,
+        &[
+            &host.host_guid,
+            &contract.contract_guid,
+            &contract.contract_version,
+            &db.database_name,
+            &db.database_id,
+            &contract.description,
+            &Utc::now().to_string(),
+            &(contract.status as i32),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// save's a contract's table schema information to CDS_CONTRACTS_TABLE_SCHEMAS
+fn save_contract_table_schema_data(
+    contract: &Contract,
+    client: &mut Client,
+) -> Result<(), RcdDbError> {
+    let tables = get_contract_schema(contract)?.tables.clone();
+
+    let cmd = String::from(
+        "INSERT INTO CDS_CONTRACTS_TABLE_SCHEMAS
+        (
+            TABLE_ID,
+            COLUMN_ID,
+            COLUMN_NAME,
+            COLUMN_TYPE,
+            COLUMN_LENGTH,
+            COLUMN_ORDINAL,
+            IS_NULLABLE
+        )
+        VALUES
+        (
+            $1,
+            $2,
+            $3,
+            $4,
+            $5,
+            $6,
+            $7
+        )
+        ;",
+    );
+
+    for table in &tables {
+        for column in &table.columns {
+            client.execute(
+                &cmd,
+                &[
+                    &table.table_id,
+                    &column.column_id,
+                    &column.column_name,
+                    &(column.column_type as i32),
+                    &(column.column_length as i32),
+                    &(column.ordinal as i32),
+                    &i32::from(column.is_nullable),
+                ],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+// save a contract's host information to CDS_HOSTS
+fn save_contract_host_data(contract: &Contract, client: &mut Client) -> Result<(), RcdDbError> {
+    let cmd = String::from(
+        "INSERT INTO CDS_HOSTS
+    (
+        HOST_ID,
+        HOST_NAME,
+        TOKEN,
+        IP4ADDRESS,
+        IP6ADDRESS,
+        PORT,
+        LAST_COMMUNICATION_UTC,
+        HOST_STATUS,
+        HTTP_ADDR,
+        HTTP_PORT
+    )
+    VALUES
+    (
+        $1,
+        $2,
+        $3,
+        $4,
+        $5,
+        $6,
+        $7,
+        1,
+        $8,
+        $9
+    )
+    ;",
+    );
+
+    let host = get_contract_host(contract)?;
+
+    client.execute(
+        &cmd,
+        &[
+            &host.host_guid,
+            &host.host_name,
+            &host.token,
+            &host.ip4_address,
+            &host.ip6_address,
+            &(host.database_port_number as i32),
+            &Utc::now().to_string(),
+            &host.http_addr,
+            &(host.http_port as i32),
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn get_contract_schema(contract: &Contract) -> Result<&DatabaseSchema, RcdDbError> {
+    contract.schema.as_ref().ok_or_else(|| {
+        RcdDbError::General(format!(
+            "contract {} does not have a schema",
+            contract.contract_guid
+        ))
+    })
+}
+
+fn get_contract_host(contract: &Contract) -> Result<&Host, RcdDbError> {
+    contract.host_info.as_ref().ok_or_else(|| {
+        RcdDbError::General(format!(
+            "contract {} does not have host info",
+            contract.contract_guid
+        ))
+    })
+}
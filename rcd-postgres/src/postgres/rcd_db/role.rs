@@ -0,0 +1,38 @@
+use crate::postgres::sql_text::Cds;
+
+use super::get_rcd_conn;
+use rcd_common::db::DbiConfigPostgres;
+use rcd_error::rcd_db_error::RcdDbError;
+
+pub fn has_role_name(role_name: &str, config: &DbiConfigPostgres) -> Result<bool, RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+
+    let cmd = Cds::text_get_role();
+    let count: i64 = client.query_one(&cmd, &[&role_name])?.try_get(0)?;
+
+    Ok(count > 0)
+}
+
+pub fn add_login_to_role(
+    login: &str,
+    role_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<(), RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+    let cmd = Cds::text_add_user_role();
+    client.execute(&cmd, &[&login, &role_name])?;
+
+    Ok(())
+}
+
+pub fn login_is_in_role(
+    login: &str,
+    role_name: &str,
+    config: &DbiConfigPostgres,
+) -> Result<bool, RcdDbError> {
+    let mut client = get_rcd_conn(config)?;
+    let cmd = Cds::text_get_user_role();
+    let count: i64 = client.query_one(&cmd, &[&login, &role_name])?.try_get(0)?;
+
+    Ok(count > 0)
+}
@@ -0,0 +1,241 @@
+//! Runs the Postgres backend against a real server. These tests only run when
+//! `RCD_TEST_POSTGRES_CONNECTION` is set to a connection string for a database the tests can
+//! create and drop schemas in, e.g. `host=localhost user=rcd password=rcd dbname=rcd_test`
+
+use postgres::{Client, NoTls};
+use rcd_common::{db::DbiConfigPostgres, defaults, storage_backend::RcdStorageBackend};
+use rcd_enum::rcd_database_type::RcdDatabaseType;
+use rcd_error::rcd_db_error::RcdDbError;
+use rcd_postgres::postgres::backend::PostgresBackend;
+
+const CONNECTION_ENV: &str = "RCD_TEST_POSTGRES_CONNECTION";
+
+/// Returns a backend for the test, with the schemas it uses dropped, or `None` if the tests
+/// are not configured to run against a Postgres server
+fn setup(rcd_db_name: &str, db_names: &[&str]) -> Option<PostgresBackend> {
+    let connection_string = match std::env::var(CONNECTION_ENV) {
+        Ok(value) => value,
+        Err(_) => {
+            println!("{CONNECTION_ENV} is not set, skipping");
+            return None;
+        }
+    };
+
+    let mut client = Client::connect(&connection_string, NoTls).unwrap();
+    let mut schemas = vec![rcd_db_name.to_string()];
+    for db_name in db_names {
+        schemas.push(format!("{db_name}.db"));
+        schemas.push(format!("{db_name}.dbpart"));
+    }
+
+    for schema in schemas {
+        client
+            .batch_execute(&format!("DROP SCHEMA IF EXISTS \"{schema}\" CASCADE;"))
+            .unwrap();
+    }
+
+    Some(PostgresBackend::new(DbiConfigPostgres {
+        user_name: String::new(),
+        pw: String::new(),
+        connection_string,
+        host: String::new(),
+        connect_options: String::new(),
+        rcd_db_name: rcd_db_name.to_string(),
+    }))
+}
+
+#[test]
+fn configure_rcd_db_and_verify_login() {
+    let backend = match setup("rcd_pg_test_login", &[]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    backend.configure_rcd_db().unwrap();
+    backend.create_login("tester", "1234").unwrap();
+
+    assert!(backend.has_login("tester").unwrap());
+    assert!(backend.verify_login("tester", "1234").unwrap());
+    assert!(!backend.verify_login("tester", "wrong").unwrap());
+}
+
+#[test]
+fn write_and_read_at_host() {
+    let backend = match setup("rcd_pg_test_host", &["pg_host"]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    backend.configure_rcd_db().unwrap();
+    backend.create_database("pg_host.db").unwrap();
+
+    backend
+        .execute_write_at_host(
+            "pg_host.db",
+            "CREATE TABLE employees (id INT, name TEXT, hired DATE);",
+        )
+        .unwrap();
+
+    let rows = backend
+        .execute_write_at_host(
+            "pg_host.db",
+            "INSERT INTO employees (id, name, hired) VALUES (1, 'Randy', '2023-01-01'::date), (2, 'Mark', NULL);",
+        )
+        .unwrap();
+    assert_eq!(rows, 2);
+
+    let table = backend
+        .execute_read_at_host("pg_host.db", "SELECT name FROM employees WHERE id = 1")
+        .unwrap();
+    assert_eq!(table.rows.len(), 1);
+
+    let value = table.rows[0].vals[0].data.as_ref().unwrap();
+    assert_eq!(value.data_string, "Randy");
+
+    let result = backend.execute_read_at_host("pg_host.db", "SELECT * FROM not_a_table");
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_row_ids_from_partial_database() {
+    let backend = match setup("rcd_pg_test_part", &["pg_part"]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    backend.configure_rcd_db().unwrap();
+    backend.create_partial_database("pg_part.db").unwrap();
+    backend
+        .execute_write_at_partipant(
+            "pg_part.db",
+            "CREATE TABLE employees (ROWID SERIAL, name TEXT);",
+        )
+        .unwrap();
+    backend
+        .execute_write_at_partipant(
+            "pg_part.db",
+            "INSERT INTO employees (name) VALUES ('Randy'), ('Mark'), ('Randy');",
+        )
+        .unwrap();
+
+    let row_ids = backend
+        .read_row_ids_from_part_db("pg_part.db", "employees", "name = 'Randy'")
+        .unwrap();
+    assert_eq!(row_ids, vec![1, 3]);
+
+    let result = backend.read_row_ids_from_part_db("pg_part.db", "employees", "no_such_col = 1");
+    assert!(result.is_err());
+}
+
+#[test]
+fn unsupported_calls_return_errors() {
+    let backend = match setup("rcd_pg_test_unsupported", &["pg_unsupported"]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    assert!(matches!(
+        backend.get_db_id("pg_unsupported.db"),
+        Err(RcdDbError::NotSupported(..))
+    ));
+    assert!(matches!(
+        backend.get_table_id("pg_unsupported.db", "employees"),
+        Err(RcdDbError::NotSupported(..))
+    ));
+    assert!(matches!(
+        backend.set_database_key("pg_unsupported.db", RcdDatabaseType::Host, "key"),
+        Err(RcdDbError::EncryptionNotSupported(..))
+    ));
+}
+
+#[test]
+fn unreachable_server_returns_errors() {
+    // nothing listens on port 1, so every call fails to connect
+    let backend = PostgresBackend::new(DbiConfigPostgres {
+        user_name: String::new(),
+        pw: String::new(),
+        connection_string: String::from("host=127.0.0.1 port=1 user=rcd connect_timeout=1"),
+        host: String::new(),
+        connect_options: String::new(),
+        rcd_db_name: String::from("rcd_pg_test_unreachable"),
+    });
+
+    assert!(backend
+        .get_data_hash_at_host("pg_unreachable.db", "employees", 1)
+        .is_err());
+    assert!(backend.verify_host_by_id("host", Vec::new()).is_err());
+    assert!(backend.revoke_token("token").is_err());
+}
+
+#[test]
+fn configure_rcd_db_adds_missing_columns() {
+    let backend = match setup("rcd_pg_test_migrate", &[]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    backend.configure_rcd_db().unwrap();
+
+    let has_ttl_column = |client: &mut Client| -> bool {
+        let count: i64 = client
+            .query_one(
+                "SELECT count(*) FROM information_schema.columns WHERE table_schema = $1 AND table_name = 'cds_contracts_tables' AND column_name = 'pending_action_ttl_seconds'",
+                &[&"rcd_pg_test_migrate"],
+            )
+            .unwrap()
+            .get(0);
+        count > 0
+    };
+
+    // CDS_CONTRACTS_TABLES as it was before PENDING_ACTION_TTL_SECONDS was added
+    let connection_string = std::env::var(CONNECTION_ENV).unwrap();
+    let mut client = Client::connect(&connection_string, NoTls).unwrap();
+    client
+        .batch_execute(
+            "ALTER TABLE \"rcd_pg_test_migrate\".cds_contracts_tables DROP COLUMN pending_action_ttl_seconds;",
+        )
+        .unwrap();
+    assert!(!has_ttl_column(&mut client));
+
+    backend.configure_rcd_db().unwrap();
+    assert!(has_ttl_column(&mut client));
+}
+
+#[test]
+fn configure_rcd_db_migrates_schema_version_1() {
+    let backend = match setup("rcd_pg_test_migrate_v1", &[]) {
+        Some(backend) => backend,
+        None => return,
+    };
+
+    backend.configure_rcd_db().unwrap();
+
+    let has_protocol_version_column = |client: &mut Client| -> bool {
+        let count: i64 = client
+            .query_one(
+                "SELECT count(*) FROM information_schema.columns WHERE table_schema = $1 AND table_name = 'cds_hosts' AND column_name = 'protocol_version'",
+                &[&"rcd_pg_test_migrate_v1"],
+            )
+            .unwrap()
+            .get(0);
+        count > 0
+    };
+
+    // CDS_HOSTS and CDS_SCHEMA_VERSION as they were at schema version 1
+    let connection_string = std::env::var(CONNECTION_ENV).unwrap();
+    let mut client = Client::connect(&connection_string, NoTls).unwrap();
+    client
+        .batch_execute(
+            "ALTER TABLE \"rcd_pg_test_migrate_v1\".cds_hosts DROP COLUMN protocol_version; UPDATE \"rcd_pg_test_migrate_v1\".cds_schema_version SET version = 1;",
+        )
+        .unwrap();
+    assert!(!has_protocol_version_column(&mut client));
+    assert_eq!(backend.get_rcd_schema_version().unwrap(), 1);
+
+    backend.configure_rcd_db().unwrap();
+    assert!(has_protocol_version_column(&mut client));
+    assert_eq!(
+        backend.get_rcd_schema_version().unwrap(),
+        defaults::RCD_SCHEMA_VERSION
+    );
+}
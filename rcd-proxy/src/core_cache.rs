@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use rcd_core::{rcd::Rcd, rcd_data::RcdData};
+use rcd_messages::proxy::server_messages::CoreCacheMetrics;
+use stdext::function_name;
+use tracing::trace;
+
+/// the warm `Rcd` and `RcdData` for a single host, along with what they were built with
+#[derive(Debug, Clone)]
+struct CachedCore {
+    core: Rcd,
+    core_data: RcdData,
+    db_addr_port: String,
+    timeout_in_sec: u32,
+    last_used: Instant,
+}
+
+#[derive(Debug, Default)]
+struct CoreCacheState {
+    entries: HashMap<String, CachedCore>,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+    invalidations: u64,
+}
+
+/// A bounded, least recently used cache of the `Rcd` and `RcdData` instances for the hosts
+/// that the proxy serves, keyed by host id. Entries that have not been used within the idle
+/// timeout are dropped the next time the cache is touched. Clones share the same cache.
+#[derive(Debug, Clone)]
+pub struct CoreCache {
+    capacity: usize,
+    idle_timeout: Duration,
+    state: Arc<Mutex<CoreCacheState>>,
+}
+
+impl CoreCache {
+    pub fn new(capacity: usize, idle_timeout_in_seconds: u64) -> Self {
+        Self {
+            capacity,
+            idle_timeout: Duration::from_secs(idle_timeout_in_seconds),
+            state: Arc::new(Mutex::new(CoreCacheState::default())),
+        }
+    }
+
+    /// returns the cached `Rcd` for the host, if there is one that was built for the same
+    /// data service address and timeout
+    pub fn get_core(&self, host_id: &str, db_addr_port: &str, timeout_in_sec: u32) -> Option<Rcd> {
+        self.get(host_id, db_addr_port, timeout_in_sec)
+            .map(|cached| cached.core)
+    }
+
+    /// returns the cached `RcdData` for the host, if there is one that was built for the same
+    /// data service address and timeout
+    pub fn get_core_data(
+        &self,
+        host_id: &str,
+        db_addr_port: &str,
+        timeout_in_sec: u32,
+    ) -> Option<RcdData> {
+        self.get(host_id, db_addr_port, timeout_in_sec)
+            .map(|cached| cached.core_data)
+    }
+
+    fn get(&self, host_id: &str, db_addr_port: &str, timeout_in_sec: u32) -> Option<CachedCore> {
+        let mut state = self.state.lock().unwrap();
+        self.remove_expired(&mut state);
+
+        let cached = state.entries.get_mut(host_id).filter(|cached| {
+            cached.db_addr_port == db_addr_port && cached.timeout_in_sec == timeout_in_sec
+        });
+
+        match cached {
+            Some(cached) => {
+                cached.last_used = Instant::now();
+                let cached = cached.clone();
+                state.hits += 1;
+                Some(cached)
+            }
+            None => {
+                state.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// caches the cores for the host, evicting the least recently used host if the cache is full
+    pub fn insert(
+        &self,
+        host_id: &str,
+        db_addr_port: &str,
+        timeout_in_sec: u32,
+        core: Rcd,
+        core_data: RcdData,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        self.remove_expired(&mut state);
+
+        if !state.entries.contains_key(host_id) && state.entries.len() >= self.capacity {
+            let least_recently_used = state
+                .entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(id, _)| id.clone());
+
+            if let Some(id) = least_recently_used {
+                trace!("[{}]: evicting host: {id}", function_name!());
+                state.entries.remove(&id);
+                state.evictions += 1;
+            }
+        }
+
+        state.entries.insert(
+            host_id.to_string(),
+            CachedCore {
+                core,
+                core_data,
+                db_addr_port: db_addr_port.to_string(),
+                timeout_in_sec,
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    /// drops the cached cores for the host, so that the next call for it rebuilds them from
+    /// the account as it is now
+    pub fn invalidate(&self, host_id: &str) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.entries.remove(host_id).is_some() {
+            trace!("[{}]: invalidated host: {host_id}", function_name!());
+            state.invalidations += 1;
+        }
+    }
+
+    pub fn metrics(&self) -> CoreCacheMetrics {
+        let mut state = self.state.lock().unwrap();
+        self.remove_expired(&mut state);
+
+        let lookups = state.hits + state.misses;
+        let hit_rate = if lookups == 0 {
+            0.0
+        } else {
+            state.hits as f64 / lookups as f64
+        };
+
+        CoreCacheMetrics {
+            capacity: self.capacity as u64,
+            entries: state.entries.len() as u64,
+            hits: state.hits,
+            misses: state.misses,
+            evictions: state.evictions,
+            expirations: state.expirations,
+            invalidations: state.invalidations,
+            hit_rate,
+        }
+    }
+
+    fn remove_expired(&self, state: &mut CoreCacheState) {
+        let idle_timeout = self.idle_timeout;
+        let before = state.entries.len();
+
+        state
+            .entries
+            .retain(|_, cached| cached.last_used.elapsed() < idle_timeout);
+
+        state.expirations += (before - state.entries.len()) as u64;
+    }
+}
@@ -1,21 +1,38 @@
-use std::{fs, path::Path};
-
-use crate::proxy_grpc::{ProxyClientGrpc, ProxyDbGrpc};
-use chrono::{DateTime, Utc};
-use config::Config;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::core_cache::CoreCache;
+use crate::proxy_grpc::{ProxyAdminGrpc, ProxyClientGrpc, ProxyDbGrpc};
+use crate::quota::{QuotaAction, RateLimiter, UsageCache};
+use crate::tenant::TenantState;
+use chrono::{DateTime, Duration, Utc};
+use config::{Config, ConfigError};
 use tracing::{warn, instrument};
 #[allow(unused_imports)]
 use tracing::{debug, error, info, trace};
 use proxy_db::ProxyDb;
-use rcd_common::{crypt, rcd_settings::RcdSettings};
-use rcd_core::{auth, rcd::Rcd, rcd_data::RcdData};
-use rcd_enum::database_type::DatabaseType;
-use rcd_messages::proxy::server_messages::AuthForTokenReply;
-use rcdproto::rcdp::{data_service_server::DataServiceServer, sql_client_server::SqlClientServer};
+use rcd_common::{
+    crypt,
+    db::DbiConfigMySql,
+    defaults,
+    rcd_settings::{JwtConfig, RcdSettings},
+};
+use rcd_core::{auth::JwtAuth, rcd::Rcd, rcd_data::RcdData};
+use rcd_enum::{database_type::DatabaseType, tenant_status::TenantStatus};
+use rcd_messages::proxy::server_messages::{
+    AuthForTokenReply, CoreCacheMetrics, QuotaError, QuotaKind, TenantQuota, TenantUsage,
+};
+use rcdproto::rcdp::{
+    data_service_server::DataServiceServer, proxy_admin_server::ProxyAdminServer,
+    sql_client_server::SqlClientServer,
+};
 use rcdx::rcd_service::RcdService;
 #[cfg(test)]
 use simple_logger::SimpleLogger;
 use thiserror::Error;
+use tokio::sync::mpsc::Receiver;
 use tonic::transport::Server;
 use triggered::{Listener, Trigger};
 use user_info::UserInfo;
@@ -24,13 +41,24 @@ use stdext::function_name;
 
 const SETTINGS: &str = "Settings.toml";
 const PROXY_DB: &str = "Proxy.db";
-
+const CORE_CACHE_CAPACITY: usize = 256;
+const CORE_CACHE_IDLE_TIMEOUT_IN_SECONDS: u64 = 600;
+const TENANT_DELETE_GRACE_PERIOD_IN_SECONDS: u64 = 604800;
+const TENANT_PURGE_INTERVAL_IN_SECONDS: u64 = 60;
+const EXPORTS_DIR: &str = "exports";
+const EXPORT_MAX_AGE_IN_SECONDS: u64 = 3600;
+const USAGE_MAX_AGE_IN_SECONDS: u64 = 5;
+
+mod core_cache;
 mod grpc_client;
 mod proxy_db;
+mod proxy_db_mysql;
 mod proxy_db_sqlite;
 mod proxy_grpc;
 pub mod proxy_server;
+mod quota;
 mod sql_text;
+mod tenant;
 mod user_info;
 
 #[derive(Error, Debug, PartialEq)]
@@ -51,12 +79,30 @@ pub enum RcdProxyErr {
     NoRowsAffected,
     #[error("User `{0}` folder not set")]
     UserFolderNotSet(String),
+    #[error("User `{0}` is suspended or pending delete")]
+    TenantNotActive(String),
+    #[error("User `{0}` is not an admin of this proxy")]
+    NotAnAdmin(String),
+    #[error("Tenant status `{0}` can not be set")]
+    InvalidTenantStatus(u32),
+    #[error("Folder could not be removed: `{0}`")]
+    FolderNotRemoved(String),
+    #[error("Export failed: `{0}`")]
+    ExportFailed(String),
+    #[error("Invalid setting `{0}`: {1}")]
+    InvalidSetting(String, String),
+    #[error("Usage could not be measured: `{0}`")]
+    UsageUnavailable(String),
 }
 
 #[derive(Debug, Clone)]
 pub struct RcdProxy {
     settings: RcdProxySettings,
     db: ProxyDb,
+    jwt: JwtAuth,
+    cores: CoreCache,
+    limiter: RateLimiter,
+    usage: UsageCache,
 }
 
 #[derive(Debug, Clone)]
@@ -82,6 +128,19 @@ pub struct RcdProxySettings {
     // for the webpage to talk to this instance
     pub proxy_http_addr: String,
     pub proxy_http_port: usize,
+    // the server to use when `database_type` is Mysql
+    pub mysql_config: Option<DbiConfigMySql>,
+    // signs the proxy's tokens and those of the rcd instances it hosts
+    pub jwt_config: Option<JwtConfig>,
+    // how many hosts to keep a warm `Rcd` for, and how long an unused one is kept
+    pub core_cache_capacity: usize,
+    pub core_cache_idle_timeout_in_seconds: u64,
+    // the quota for logins that don't have one of their own; unlimited if not set
+    pub default_quota: Option<TenantQuota>,
+    // the logins allowed to suspend, delete and export other accounts
+    pub admin_logins: Vec<String>,
+    // how long a deleted account is kept, suspended, before its folder and login are removed
+    pub tenant_delete_grace_period_in_seconds: u64,
 }
 
 impl RcdProxy {
@@ -94,12 +153,27 @@ impl RcdProxy {
             DatabaseType::Sqlite => {
                 ProxyDb::new_with_sqlite(db_name, settings.root_dir.to_string())
             }
-            DatabaseType::Mysql => todo!(),
+            DatabaseType::Mysql => ProxyDb::new_with_mysql(
+                db_name,
+                settings
+                    .mysql_config
+                    .clone()
+                    .expect("missing setting: 'mysql_config'"),
+            ),
             DatabaseType::Postgres => todo!(),
             DatabaseType::Sqlserver => todo!(),
         };
 
-        RcdProxy { settings, db }
+        let jwt = Self::get_jwt_auth(&settings);
+        let cores = Self::get_core_cache(&settings);
+        RcdProxy {
+            settings,
+            db,
+            jwt,
+            cores,
+            limiter: RateLimiter::default(),
+            usage: UsageCache::new(USAGE_MAX_AGE_IN_SECONDS),
+        }
     }
 
     pub fn http_endpoint_addr(&self) -> String {
@@ -126,12 +200,27 @@ impl RcdProxy {
                 let db = match db_type {
                     DatabaseType::Unknown => todo!(),
                     DatabaseType::Sqlite => ProxyDb::new_with_sqlite(db_name, root_dir.to_string()),
-                    DatabaseType::Mysql => todo!(),
+                    DatabaseType::Mysql => ProxyDb::new_with_mysql(
+                        db_name,
+                        settings
+                            .mysql_config
+                            .clone()
+                            .expect("missing setting: 'mysql_config'"),
+                    ),
                     DatabaseType::Postgres => todo!(),
                     DatabaseType::Sqlserver => todo!(),
                 };
 
-                let service = RcdProxy { settings, db };
+                let jwt = Self::get_jwt_auth(&settings);
+                let cores = Self::get_core_cache(&settings);
+                let service = RcdProxy {
+                    settings,
+                    db,
+                    jwt,
+                    cores,
+                    limiter: RateLimiter::default(),
+                    usage: UsageCache::new(USAGE_MAX_AGE_IN_SECONDS),
+                };
 
                 return Ok(service);
             }
@@ -151,12 +240,27 @@ impl RcdProxy {
                 let db = match db_type {
                     DatabaseType::Unknown => todo!(),
                     DatabaseType::Sqlite => ProxyDb::new_with_sqlite(db_name, dir.to_string()),
-                    DatabaseType::Mysql => todo!(),
+                    DatabaseType::Mysql => ProxyDb::new_with_mysql(
+                        db_name,
+                        settings
+                            .mysql_config
+                            .clone()
+                            .expect("missing setting: 'mysql_config'"),
+                    ),
                     DatabaseType::Postgres => todo!(),
                     DatabaseType::Sqlserver => todo!(),
                 };
 
-                let service = RcdProxy { settings, db };
+                let jwt = Self::get_jwt_auth(&settings);
+                let cores = Self::get_core_cache(&settings);
+                let service = RcdProxy {
+                    settings,
+                    db,
+                    jwt,
+                    cores,
+                    limiter: RateLimiter::default(),
+                    usage: UsageCache::new(USAGE_MAX_AGE_IN_SECONDS),
+                };
 
                 return Ok(service);
             }
@@ -263,6 +367,82 @@ impl RcdProxy {
 
         let db_type = DatabaseType::from_u32(db_type.parse().unwrap());
 
+        let mysql_config = settings
+            .get::<DbiConfigMySql>(&String::from("mysql_config"))
+            .ok();
+
+        // a jwt_config that is there but can't be used is an error rather than a reason to
+        // quietly sign with a random secret
+        let jwt_config = match settings.get::<JwtConfig>(&String::from("jwt_config")) {
+            Ok(jwt_config) => {
+                JwtAuth::from_config(&jwt_config).map_err(|e| {
+                    RcdProxyErr::InvalidSetting("jwt_config".to_string(), e.to_string())
+                })?;
+                Some(jwt_config)
+            }
+            Err(ConfigError::NotFound(_)) => {
+                error!("missing setting: 'jwt_config', tokens will not be valid after a restart");
+                None
+            }
+            Err(e) => {
+                return Err(RcdProxyErr::InvalidSetting(
+                    "jwt_config".to_string(),
+                    e.to_string(),
+                ))
+            }
+        };
+
+        let result_core_cache_capacity = settings.get_int("core_cache_capacity");
+
+        let core_cache_capacity = match result_core_cache_capacity {
+            Ok(capacity) => capacity as usize,
+            Err(_) => {
+                error!(
+                    "missing setting: 'core_cache_capacity', using default {CORE_CACHE_CAPACITY}"
+                );
+                CORE_CACHE_CAPACITY
+            }
+        };
+
+        let result_core_cache_idle_timeout = settings.get_int("core_cache_idle_timeout_in_seconds");
+
+        let core_cache_idle_timeout_in_seconds = match result_core_cache_idle_timeout {
+            Ok(timeout) => timeout as u64,
+            Err(_) => {
+                error!(
+                    "missing setting: 'core_cache_idle_timeout_in_seconds', using default {CORE_CACHE_IDLE_TIMEOUT_IN_SECONDS}"
+                );
+                CORE_CACHE_IDLE_TIMEOUT_IN_SECONDS
+            }
+        };
+
+        let default_quota = settings
+            .get::<TenantQuota>(&String::from("default_quota"))
+            .ok();
+
+        let admin_logins: Vec<String> = match settings.get_array("admin_logins") {
+            Ok(logins) => logins
+                .into_iter()
+                .filter_map(|login| login.into_string().ok())
+                .collect(),
+            Err(_) => {
+                error!("missing setting: 'admin_logins', the admin api will refuse every login");
+                Vec::new()
+            }
+        };
+
+        let result_grace_period = settings.get_int("tenant_delete_grace_period_in_seconds");
+
+        let tenant_delete_grace_period_in_seconds = match result_grace_period {
+            Ok(period) => period as u64,
+            Err(_) => {
+                error!(
+                    "missing setting: 'tenant_delete_grace_period_in_seconds', using default {TENANT_DELETE_GRACE_PERIOD_IN_SECONDS}"
+                );
+                TENANT_DELETE_GRACE_PERIOD_IN_SECONDS
+            }
+        };
+
         Ok(RcdProxySettings {
             use_grpc: true,
             use_http: true,
@@ -275,9 +455,308 @@ impl RcdProxy {
             database_name: db_name.clone(),
             proxy_http_addr: proxy_http_addr,
             proxy_http_port: proxy_http_port.parse().unwrap(),
+            mysql_config,
+            jwt_config,
+            core_cache_capacity,
+            core_cache_idle_timeout_in_seconds,
+            default_quota,
+            admin_logins,
+            tenant_delete_grace_period_in_seconds,
         })
     }
 
+    fn get_jwt_auth(settings: &RcdProxySettings) -> JwtAuth {
+        match &settings.jwt_config {
+            Some(jwt_config) => {
+                JwtAuth::from_config(jwt_config).expect("invalid setting: 'jwt_config'")
+            }
+            None => JwtAuth::default(),
+        }
+    }
+
+    fn get_core_cache(settings: &RcdProxySettings) -> CoreCache {
+        CoreCache::new(
+            settings.core_cache_capacity,
+            settings.core_cache_idle_timeout_in_seconds,
+        )
+    }
+
+    /// returns how often the proxy has been able to reuse a host's `Rcd` rather than rebuild it
+    pub fn core_cache_metrics(&self) -> CoreCacheMetrics {
+        self.cores.metrics()
+    }
+
+    /// returns the quota saved for the login, or the proxy's default if the login has none
+    pub fn get_quota_for_login(&self, un: &str) -> Result<TenantQuota, RcdProxyErr> {
+        match self.db.get_quota(un)? {
+            Some(quota) => Ok(quota),
+            None => Ok(self.settings.default_quota.clone().unwrap_or_default()),
+        }
+    }
+
+    pub fn set_quota_for_login(&self, un: &str, quota: &TenantQuota) -> Result<(), RcdProxyErr> {
+        self.db.save_quota(un, quota)
+    }
+
+    /// measures the databases, storage and rows in the login's folder. rows are counted by the
+    /// login's `Rcd`, which holds the keys of its unlocked databases
+    pub fn get_usage_for_login(&self, un: &str) -> Result<TenantUsage, RcdProxyErr> {
+        let u = self.db.get_user(un)?;
+        let full_folder_path = self.get_user_root_dir(&u)?;
+        let settings = self.get_default_rcd_setings(un);
+
+        let core =
+            u.id.as_ref()
+                .map(|id| self.get_rcd_core_for_existing_host(id));
+
+        self.usage.get_usage(
+            &full_folder_path,
+            &settings.backing_database_name,
+            |db_name| match &core {
+                Some(Ok(core)) => core.count_user_rows(db_name).map_err(|e| e.to_string()),
+                Some(Err(e)) => Err(e.to_string()),
+                None => Err(format!("login '{un}' has no host")),
+            },
+        )
+    }
+
+    /// checks that the host's login has room in its quota for the action. hosts that can't be
+    /// found are let through so that authentication can report them
+    pub(crate) fn check_quota(&self, host_id: &str, action: QuotaAction) -> Result<(), QuotaError> {
+        let u = match self.db.get_host(host_id) {
+            Ok(u) => u,
+            Err(_) => return Ok(()),
+        };
+
+        // a login whose quota or usage can't be read can't be shown to be under its quota, so
+        // only requests that can't add to its usage are let through
+        let quota = match self.get_quota_for_login(&u.username) {
+            Ok(quota) => quota,
+            Err(e) => {
+                warn!("[{}]: unable to read quota: {e:?}", function_name!());
+
+                if action == QuotaAction::Request {
+                    return Ok(());
+                }
+
+                return Err(quota::unmeasured_error(&u.username, &e));
+            }
+        };
+
+        if let Some(limit) = quota.max_requests_per_second {
+            if !self.limiter.try_acquire(host_id, limit) {
+                return Err(quota::quota_error(
+                    &u.username,
+                    QuotaKind::RequestsPerSecond,
+                    limit as u64,
+                    limit as u64,
+                ));
+            }
+        }
+
+        let has_usage_limits = quota.max_databases.is_some()
+            || quota.max_storage_bytes.is_some()
+            || quota.max_rows.is_some();
+
+        if action == QuotaAction::Request || !has_usage_limits {
+            return Ok(());
+        }
+
+        match self.get_usage_for_login(&u.username) {
+            Ok(usage) => {
+                quota::check_usage(&u.username, &quota, &usage, action)?;
+
+                // a new database counts against the quota as soon as it's made, rather than
+                // once the measure is out of date
+                if action == QuotaAction::CreateDatabase {
+                    if let Ok(folder) = self.get_user_root_dir(&u) {
+                        self.usage.invalidate(&folder);
+                    }
+                }
+
+                Ok(())
+            }
+            Err(e) => {
+                warn!("[{}]: unable to measure usage: {e:?}", function_name!());
+                Err(quota::unmeasured_error(&u.username, &e))
+            }
+        }
+    }
+
+    pub fn is_admin_login(&self, un: &str) -> bool {
+        self.settings.admin_logins.iter().any(|login| login == un)
+    }
+
+    /// checks the operator's credentials, returning their login if they are an admin
+    pub fn verify_admin_login(&self, un: &str, pw: &str) -> Result<String, RcdProxyErr> {
+        if self.verify_login(un, pw)? && self.is_admin_login(un) {
+            return Ok(un.to_string());
+        }
+
+        Err(RcdProxyErr::NotAnAdmin(un.to_string()))
+    }
+
+    /// checks the operator's token, returning their login if they are an admin
+    pub fn verify_admin_token(&self, jwt: &str) -> Result<String, RcdProxyErr> {
+        match self.get_login_for_token(jwt)? {
+            Some(login) if self.is_admin_login(&login) => Ok(login),
+            Some(login) => Err(RcdProxyErr::NotAnAdmin(login)),
+            None => Err(RcdProxyErr::NotAnAdmin("".to_string())),
+        }
+    }
+
+    pub fn get_tenant_state(&self, un: &str) -> Result<TenantState, RcdProxyErr> {
+        self.db.get_user(un)?;
+        Ok(self
+            .db
+            .get_tenant_state(un)?
+            .unwrap_or_else(TenantState::active))
+    }
+
+    /// Moves the account to the status. Suspending or deleting an account revokes its tokens and
+    /// drops its cached `Rcd`, and a deleted account is removed by `purge_deleted_tenants` once
+    /// the grace period has passed. Setting it back to `Active` resumes it, cancelling any delete.
+    pub fn change_tenant_status(
+        &self,
+        un: &str,
+        status: TenantStatus,
+    ) -> Result<TenantState, RcdProxyErr> {
+        let u = self.db.get_user(un)?;
+
+        let state = match status {
+            TenantStatus::Active | TenantStatus::Suspended => TenantState {
+                status,
+                delete_after_utc: None,
+            },
+            TenantStatus::PendingDelete => {
+                let grace_period = self.settings.tenant_delete_grace_period_in_seconds as i64;
+                TenantState {
+                    status,
+                    delete_after_utc: Some(Utc::now() + Duration::seconds(grace_period)),
+                }
+            }
+            TenantStatus::Unknown => {
+                return Err(RcdProxyErr::InvalidTenantStatus(TenantStatus::to_u32(
+                    status,
+                )))
+            }
+        };
+
+        self.db.save_tenant_state(un, &state)?;
+
+        if status != TenantStatus::Active {
+            self.db.revoke_tokens_for_login(un);
+        }
+
+        if let Some(id) = &u.id {
+            self.cores.invalidate(id);
+        }
+
+        info!("[{}]: {un} is now {status:?}", function_name!());
+
+        Ok(state)
+    }
+
+    /// removes the folder and login of every account whose delete grace period has passed,
+    /// returning the logins that were removed
+    pub fn purge_deleted_tenants(&self) -> Result<Vec<String>, RcdProxyErr> {
+        let logins = self.db.get_tenants_to_delete(Utc::now())?;
+
+        for login in &logins {
+            let u = self.db.get_user(login)?;
+
+            if let Some(id) = &u.id {
+                self.cores.invalidate(id);
+            }
+
+            if let Ok(folder) = self.get_user_root_dir(&u) {
+                if Path::new(&folder).exists() {
+                    fs::remove_dir_all(&folder)
+                        .map_err(|e| RcdProxyErr::FolderNotRemoved(e.to_string()))?;
+                }
+            }
+
+            self.db.delete_user(login)?;
+            info!("[{}]: removed {login}", function_name!());
+        }
+
+        Ok(logins)
+    }
+
+    /// writes an archive of the account's databases to the proxy's exports folder, returning
+    /// the archive's path and size. The archive is meant to be sent with `send_export`, which
+    /// removes it once it has been sent
+    pub fn export_tenant(&self, un: &str) -> Result<(PathBuf, u64), RcdProxyErr> {
+        let u = self.db.get_user(un)?;
+        let full_folder_path = self.get_user_root_dir(&u)?;
+
+        let file_name = format!("{un}-{}.tar", Utc::now().format("%Y%m%d%H%M%S"));
+        let archive_path = self.get_exports_dir().join(file_name);
+
+        let size = tenant::write_archive(&full_folder_path, &archive_path)
+            .map_err(|e| RcdProxyErr::ExportFailed(e.to_string()))?;
+
+        Ok((archive_path, size))
+    }
+
+    /// sends an archive written by `export_tenant` a chunk at a time, removing it afterwards
+    pub fn send_export(&self, archive_path: PathBuf) -> Receiver<std::io::Result<Vec<u8>>> {
+        tenant::send_archive(archive_path)
+    }
+
+    /// removes the exports that were never sent, returning what was removed
+    pub fn remove_stale_exports(&self) -> Result<Vec<PathBuf>, RcdProxyErr> {
+        tenant::remove_stale_exports(
+            &self.get_exports_dir(),
+            std::time::Duration::from_secs(EXPORT_MAX_AGE_IN_SECONDS),
+        )
+        .map_err(|e| RcdProxyErr::FolderNotRemoved(e.to_string()))
+    }
+
+    fn get_exports_dir(&self) -> PathBuf {
+        Path::new(&self.settings.root_dir).join(EXPORTS_DIR)
+    }
+
+    /// rejects accounts that have been suspended or are pending delete
+    fn ensure_tenant_is_active(&self, u: &UserInfo) -> Result<(), RcdProxyErr> {
+        match self.db.get_tenant_state(&u.username)? {
+            Some(state) if state.status != TenantStatus::Active => {
+                Err(RcdProxyErr::TenantNotActive(u.username.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// periodically removes the accounts whose delete grace period has passed and the exports
+    /// that were never sent, until triggered
+    pub async fn start_tenant_purge_with_trigger(&self) -> Trigger {
+        let (trigger, listener) = triggered::trigger();
+        let proxy = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                TENANT_PURGE_INTERVAL_IN_SECONDS,
+            ));
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = proxy.purge_deleted_tenants() {
+                            warn!("[{}]: {e:?}", function_name!());
+                        }
+
+                        if let Err(e) = proxy.remove_stale_exports() {
+                            warn!("[{}]: {e:?}", function_name!());
+                        }
+                    }
+                    _ = listener.clone() => break,
+                }
+            }
+        });
+
+        trigger
+    }
+
     pub fn output_settings(&self) {
         let settings = &self.settings.clone();
         info!("{settings:?}");
@@ -370,8 +849,11 @@ impl RcdProxy {
 
         let addr = addr.parse().unwrap();
 
+        let admin = ProxyAdminGrpc::new(settings.proxy.clone());
+
         Server::builder()
             .add_service(SqlClientServer::new(client))
+            .add_service(ProxyAdminServer::new(admin))
             .add_service(service)
             .serve_with_shutdown(addr, listener)
             .await
@@ -456,7 +938,7 @@ impl RcdProxy {
     }
 
     fn create_token_for_login(&self, login: &str) -> (String, DateTime<Utc>) {
-        let token_data = auth::create_jwt("rcd-proxy", login);
+        let token_data = self.jwt.create_jwt("rcd-proxy", login);
         self.db
             .save_token(login, &token_data.0, token_data.1)
             .unwrap();
@@ -466,6 +948,7 @@ impl RcdProxy {
     pub fn verify_login(&self, un: &str, pw: &str) -> Result<bool, RcdProxyErr> {
         if self.db.has_user(un) {
             let u = self.db.get_user(un)?;
+            self.ensure_tenant_is_active(&u)?;
 
             let mut padded = [0u8; 128];
             u.hash.iter().enumerate().for_each(|(i, val)| {
@@ -478,7 +961,7 @@ impl RcdProxy {
     }
 
     pub fn verify_token(&self, jwt: &str) -> Result<bool, RcdProxyErr> {
-        Ok(self.db.verify_token(jwt))
+        Ok(self.jwt.verify_jwt(jwt) && self.db.verify_token(jwt))
     }
 
     /// checks to see if the specified user name already exists
@@ -491,7 +974,7 @@ impl RcdProxy {
     }
 
     pub fn get_host_id_for_token(&self, token: &str) -> Result<Option<String>, RcdProxyErr> {
-        if self.db.verify_token(token) {
+        if self.verify_token(token)? {
             let u = self.db.get_user_with_token(token)?;
             return Ok(u.id);
         }
@@ -499,6 +982,15 @@ impl RcdProxy {
         return Ok(None);
     }
 
+    pub fn get_login_for_token(&self, token: &str) -> Result<Option<String>, RcdProxyErr> {
+        if self.verify_token(token)? {
+            let u = self.db.get_user_with_token(token)?;
+            return Ok(Some(u.username));
+        }
+
+        return Ok(None);
+    }
+
     pub fn get_host_id_for_user(&self, un: &str) -> Result<Option<String>, RcdProxyErr> {
         let u = self.db.get_user(un)?;
         return Ok(u.id);
@@ -520,7 +1012,7 @@ impl RcdProxy {
             u.folder = Some(full_folder_path);
         }
 
-        self.db.update_user(&u)?;
+        self.update_user(&u)?;
 
         trace!("create_rcd_instance: {u:?}");
 
@@ -541,7 +1033,7 @@ impl RcdProxy {
 
         if u.folder.is_none() {
             u.folder = Some(full_folder_path.to_string());
-            self.db.update_user(&u).unwrap();
+            self.update_user(&u).unwrap();
         }
 
         let mut service = rcdx::rcd_service::get_service_from_config(settings, full_folder_path);
@@ -555,12 +1047,27 @@ impl RcdProxy {
 
         if u.id.is_none() {
             u.id = Some(host_id.clone());
-            self.db.update_user(&u).unwrap();
+            self.update_user(&u).unwrap();
         }
 
+        // the service may have been set up again over an existing host
+        self.cores.invalidate(&host_id);
+
         Ok(host_id)
     }
 
+    /// saves the changes to the account, and drops any cached `Rcd` for it so that the next call
+    /// picks the changes up
+    fn update_user(&self, u: &UserInfo) -> Result<(), RcdProxyErr> {
+        self.db.update_user(u)?;
+
+        if let Some(id) = &u.id {
+            self.cores.invalidate(id);
+        }
+
+        Ok(())
+    }
+
     pub fn get_rcd_service_for_existing_user(&self, un: &str) -> Result<RcdService, RcdProxyErr> {
         let u = self.db.get_user(un)?;
         let full_folder_path = self.get_user_root_dir(&u)?;
@@ -573,9 +1080,7 @@ impl RcdProxy {
     }
 
     pub fn get_rcd_core_for_existing_host(&self, id: &str) -> Result<Rcd, RcdProxyErr> {
-        let mut service = self.get_rcd_service_for_existing_host(id)?;
-        service.with_core_grpc(&self.settings.grpc_db_addr_port, 60);
-        Ok(service.core().clone())
+        self.get_rcd_core_for_existing_host_grpc(id, &self.settings.grpc_db_addr_port, 60)
     }
 
     pub fn get_rcd_core_data_for_existing_host_grpc(
@@ -584,8 +1089,17 @@ impl RcdProxy {
         proxy_grpc_addr_port: &str,
         proxy_grpc_timeout_in_sec: u32,
     ) -> Result<RcdData, RcdProxyErr> {
-        let mut service = self.get_rcd_service_for_existing_host(id)?;
-        service.with_core_grpc(proxy_grpc_addr_port, proxy_grpc_timeout_in_sec);
+        self.ensure_tenant_is_active(&self.db.get_host(id)?)?;
+
+        if let Some(core_data) =
+            self.cores
+                .get_core_data(id, proxy_grpc_addr_port, proxy_grpc_timeout_in_sec)
+        {
+            return Ok(core_data);
+        }
+
+        let service =
+            self.start_rcd_service_for_cache(id, proxy_grpc_addr_port, proxy_grpc_timeout_in_sec)?;
         Ok(service.core_data().clone())
     }
 
@@ -595,9 +1109,39 @@ impl RcdProxy {
         proxy_grpc_addr_port: &str,
         proxy_grpc_timeout_in_sec: u32,
     ) -> Result<Rcd, RcdProxyErr> {
+        self.ensure_tenant_is_active(&self.db.get_host(id)?)?;
+
+        if let Some(core) = self
+            .cores
+            .get_core(id, proxy_grpc_addr_port, proxy_grpc_timeout_in_sec)
+        {
+            return Ok(core);
+        }
+
+        let service =
+            self.start_rcd_service_for_cache(id, proxy_grpc_addr_port, proxy_grpc_timeout_in_sec)?;
+        Ok(service.core().clone())
+    }
+
+    /// starts the service for the host and caches its `Rcd` and `RcdData` for the calls after
+    fn start_rcd_service_for_cache(
+        &self,
+        id: &str,
+        proxy_grpc_addr_port: &str,
+        proxy_grpc_timeout_in_sec: u32,
+    ) -> Result<RcdService, RcdProxyErr> {
         let mut service = self.get_rcd_service_for_existing_host(id)?;
         service.with_core_grpc(proxy_grpc_addr_port, proxy_grpc_timeout_in_sec);
-        Ok(service.core().clone())
+
+        self.cores.insert(
+            id,
+            proxy_grpc_addr_port,
+            proxy_grpc_timeout_in_sec,
+            service.core().clone(),
+            service.core_data().clone(),
+        );
+
+        Ok(service)
     }
 
     pub fn get_rcd_service_for_existing_host(&self, id: &str) -> Result<RcdService, RcdProxyErr> {
@@ -620,8 +1164,12 @@ impl RcdProxy {
             grpc_data_service_addr_port: self.settings.grpc_db_addr_port.clone(),
             client_grpc_timeout_in_seconds: 60,
             data_grpc_timeout_in_seconds: 60,
+            max_concurrent_participant_reads: defaults::MAX_CONCURRENT_PARTICIPANT_READS,
             http_addr: self.settings.http_ip.clone(),
             http_port: self.settings.http_port as u16,
+            postgres_config: None,
+            mysql_config: None,
+            jwt_config: self.settings.jwt_config.clone(),
         }
     }
 
@@ -670,6 +1218,40 @@ fn test_output_settings() {
     proxy.output_settings();
 }
 
+#[test]
+fn test_invalid_jwt_config_is_an_error() {
+    use rcd_test_harness_common::get_test_temp_dir;
+
+    let dir = get_test_temp_dir("rcd-proxy-unit-test-invalid-jwt-config");
+    let dir = dir.as_str();
+    let settings = Path::new(dir).join(SETTINGS);
+
+    // a lifetime that isn't a number
+    fs::write(
+        &settings,
+        "[jwt_config]\nalgorithm = \"HS256\"\nsecret = \"secret\"\ntoken_lifetime_in_minutes = \"soon\"\n",
+    )
+    .unwrap();
+    assert!(matches!(
+        RcdProxy::get_settings(dir),
+        Err(RcdProxyErr::InvalidSetting(..))
+    ));
+
+    // an HS algorithm without a secret
+    fs::write(&settings, "[jwt_config]\nalgorithm = \"HS256\"\n").unwrap();
+    assert!(matches!(
+        RcdProxy::get_settings(dir),
+        Err(RcdProxyErr::InvalidSetting(..))
+    ));
+
+    fs::write(
+        &settings,
+        "[jwt_config]\nalgorithm = \"HS256\"\nsecret = \"secret\"\n",
+    )
+    .unwrap();
+    assert!(RcdProxy::get_settings(dir).unwrap().jwt_config.is_some());
+}
+
 #[test]
 pub fn test_new_with_sqlite() {
     let proxy = test_setup("rcd-proxy-db-unit-test-new");
@@ -721,6 +1303,243 @@ pub fn test_get_rcd_for_user() {
     assert!(true);
 }
 
+#[test]
+pub fn test_core_cache_reuses_and_invalidates_core() {
+    let proxy = test_rcd_common_setup("rcd-proxy-unit-test-core-cache").unwrap();
+    let host_id = proxy.get_host_id_for_user("test").unwrap().unwrap();
+
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+
+    let metrics = proxy.core_cache_metrics();
+    assert_eq!(metrics.misses, 1);
+    assert_eq!(metrics.hits, 1);
+    assert_eq!(metrics.entries, 1);
+    assert_eq!(metrics.hit_rate, 0.5);
+
+    // a change to the account means the next call has to start the host again
+    let u = proxy.db.get_user("test").unwrap();
+    proxy.update_user(&u).unwrap();
+
+    let metrics = proxy.core_cache_metrics();
+    assert_eq!(metrics.invalidations, 1);
+    assert_eq!(metrics.entries, 0);
+
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+    assert_eq!(proxy.core_cache_metrics().misses, 2);
+}
+
+#[test]
+pub fn test_core_cache_evicts_least_recently_used() {
+    let mut proxy = test_rcd_common_setup("rcd-proxy-unit-test-core-cache-evict").unwrap();
+    proxy.cores = CoreCache::new(1, 600);
+
+    proxy.register_user("other", "1234").unwrap();
+    let other_host_id = proxy.create_rcd_instance("other", false).unwrap();
+    let host_id = proxy.get_host_id_for_user("test").unwrap().unwrap();
+
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+    proxy
+        .get_rcd_core_for_existing_host(&other_host_id)
+        .unwrap();
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+
+    let metrics = proxy.core_cache_metrics();
+    assert_eq!(metrics.entries, 1);
+    assert_eq!(metrics.misses, 3);
+    assert_eq!(metrics.evictions, 2);
+}
+
+#[test]
+pub fn test_core_cache_drops_idle_core() {
+    let mut proxy = test_rcd_common_setup("rcd-proxy-unit-test-core-cache-idle").unwrap();
+    proxy.cores = CoreCache::new(16, 0);
+
+    let host_id = proxy.get_host_id_for_user("test").unwrap().unwrap();
+
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+
+    let metrics = proxy.core_cache_metrics();
+    assert_eq!(metrics.hits, 0);
+    assert_eq!(metrics.misses, 2);
+    assert_eq!(metrics.expirations, 2);
+}
+
+#[test]
+pub fn test_suspended_tenant_is_refused() {
+    let proxy = test_rcd_common_setup("rcd-proxy-unit-test-tenant-suspend").unwrap();
+    let host_id = proxy.get_host_id_for_user("test").unwrap().unwrap();
+    proxy.get_rcd_core_for_existing_host(&host_id).unwrap();
+
+    let state = proxy
+        .change_tenant_status("test", TenantStatus::Suspended)
+        .unwrap();
+    assert_eq!(state.status, TenantStatus::Suspended);
+    assert_eq!(proxy.core_cache_metrics().entries, 0);
+
+    let not_active = Err(RcdProxyErr::TenantNotActive("test".to_string()));
+    assert_eq!(proxy.verify_login("test", "1234"), not_active);
+    assert!(proxy.auth_for_token("test", "1234").is_err());
+    assert!(proxy.get_rcd_core_for_existing_host(&host_id).is_err());
+
+    proxy
+        .change_tenant_status("test", TenantStatus::Active)
+        .unwrap();
+    assert_eq!(proxy.verify_login("test", "1234"), Ok(true));
+    assert!(proxy.get_rcd_core_for_existing_host(&host_id).is_ok());
+}
+
+#[test]
+pub fn test_deleted_tenant_is_purged_after_grace_period() {
+    let mut proxy = test_rcd_common_setup("rcd-proxy-unit-test-tenant-delete").unwrap();
+    let u = proxy.db.get_user("test").unwrap();
+    let folder = proxy.get_user_root_dir(&u).unwrap();
+
+    // still within the grace period, so the account is only suspended
+    let state = proxy
+        .change_tenant_status("test", TenantStatus::PendingDelete)
+        .unwrap();
+    assert_eq!(state.status, TenantStatus::PendingDelete);
+    assert!(state.delete_after_utc.is_some());
+    assert!(proxy.verify_login("test", "1234").is_err());
+    assert!(proxy.purge_deleted_tenants().unwrap().is_empty());
+    assert!(Path::new(&folder).exists());
+
+    proxy.settings.tenant_delete_grace_period_in_seconds = 0;
+    proxy
+        .change_tenant_status("test", TenantStatus::PendingDelete)
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+
+    let removed = proxy.purge_deleted_tenants().unwrap();
+    assert_eq!(removed, vec!["test".to_string()]);
+    assert!(!Path::new(&folder).exists());
+    assert!(!proxy.db.has_user("test"));
+}
+
+#[test]
+pub fn test_export_tenant() {
+    let proxy = test_rcd_common_setup("rcd-proxy-unit-test-tenant-export").unwrap();
+
+    let (archive_path, size) = proxy.export_tenant("test").unwrap();
+    assert!(archive_path.exists());
+    assert!(size > 0);
+    assert!(!archive_path.with_extension("snapshot").exists());
+
+    let mut archive = tar::Archive::new(fs::File::open(&archive_path).unwrap());
+    let mut backing_db_header = Vec::new();
+    let mut has_database_files = false;
+
+    for mut entry in archive.entries().unwrap().flatten() {
+        let path = entry.path().unwrap().to_string_lossy().to_string();
+
+        if path.ends_with("rcd.db") {
+            std::io::Read::read_to_end(&mut entry, &mut backing_db_header).unwrap();
+        }
+
+        if path.ends_with("-wal") || path.ends_with("-shm") || path.ends_with("-journal") {
+            has_database_files = true;
+        }
+    }
+
+    // the backing database is archived as a snapshot, which is a whole database on its own
+    assert!(backing_db_header.starts_with(b"SQLite format 3\0"));
+    assert!(!has_database_files);
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let sent = runtime.block_on(async {
+        let mut chunks = proxy.send_export(archive_path.clone());
+        let mut sent = Vec::new();
+        while let Some(chunk) = chunks.recv().await {
+            sent.extend(chunk.unwrap());
+        }
+        sent
+    });
+
+    assert_eq!(sent.len() as u64, size);
+    assert!(!archive_path.exists());
+}
+
+#[test]
+pub fn test_stale_exports_are_removed() {
+    let proxy = test_rcd_common_setup("rcd-proxy-unit-test-tenant-export-stale").unwrap();
+
+    let (archive_path, _) = proxy.export_tenant("test").unwrap();
+    assert!(proxy.remove_stale_exports().unwrap().is_empty());
+    assert!(archive_path.exists());
+
+    let removed =
+        tenant::remove_stale_exports(&proxy.get_exports_dir(), std::time::Duration::from_secs(0))
+            .unwrap();
+    assert_eq!(removed, vec![archive_path.clone()]);
+    assert!(!archive_path.exists());
+}
+
+#[test]
+pub fn test_admin_login() {
+    let mut proxy = test_setup("rcd-proxy-unit-test-admin-login");
+    proxy.register_user("operator", "1234").unwrap();
+    proxy.register_user("test", "1234").unwrap();
+    proxy.settings.admin_logins = vec!["operator".to_string()];
+
+    assert_eq!(
+        proxy.verify_admin_login("operator", "1234"),
+        Ok("operator".to_string())
+    );
+    assert_eq!(
+        proxy.verify_admin_login("test", "1234"),
+        Err(RcdProxyErr::NotAnAdmin("test".to_string()))
+    );
+    assert!(proxy.verify_admin_login("operator", "wrong").is_err());
+}
+
+#[test]
+pub fn test_get_usage_counts_user_tables_only() {
+    use rusqlite::Connection;
+
+    let mut proxy = test_rcd_common_setup("rcd-proxy-unit-test-usage").unwrap();
+
+    // measure the folder again on every call
+    proxy.usage = UsageCache::new(0);
+
+    let u = proxy.db.get_user("test").unwrap();
+    let folder = proxy.get_user_root_dir(&u).unwrap();
+
+    let conn = Connection::open(Path::new(&folder).join("usage.db")).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE EMPLOYEE (ID INT);
+        INSERT INTO EMPLOYEE VALUES (1), (2);
+        CREATE TABLE EMPLOYEE_COOP_METADATA (ROW_ID INT);
+        INSERT INTO EMPLOYEE_COOP_METADATA VALUES (1);
+        CREATE TABLE COOP_REMOTES (TABLENAME VARCHAR(255));
+        INSERT INTO COOP_REMOTES VALUES ('EMPLOYEE');",
+    )
+    .unwrap();
+
+    let usage = proxy.get_usage_for_login("test").unwrap();
+    assert_eq!(usage.databases, 1);
+    assert_eq!(usage.rows, 2);
+
+    conn.execute("INSERT INTO EMPLOYEE VALUES (3)", []).unwrap();
+    let usage = proxy.get_usage_for_login("test").unwrap();
+    assert_eq!(usage.rows, 3);
+
+    // a database whose rows can't be counted, such as one that is still locked, counts against
+    // the login's storage only
+    fs::write(Path::new(&folder).join("locked.db"), vec![1; 4096]).unwrap();
+    let locked_usage = proxy.get_usage_for_login("test").unwrap();
+    assert_eq!(locked_usage.databases, 2);
+    assert_eq!(locked_usage.rows, 3);
+    assert!(locked_usage.storage_bytes >= usage.storage_bytes + 4096);
+
+    // a measure is reused until it is older than the max age
+    proxy.usage = UsageCache::new(600);
+    let usage = proxy.get_usage_for_login("test").unwrap();
+    conn.execute("INSERT INTO EMPLOYEE VALUES (4)", []).unwrap();
+    assert_eq!(proxy.get_usage_for_login("test").unwrap(), usage);
+}
+
 #[cfg(test)]
 /// common test code - sets up a test folder and returns a rcd proxy
 pub fn test_setup(test_name: &str) -> RcdProxy {
@@ -1,6 +1,10 @@
 use chrono::{DateTime, Utc};
+use rcd_common::db::DbiConfigMySql;
+use rcd_messages::proxy::server_messages::TenantQuota;
 
+use crate::proxy_db_mysql::ProxyMySql;
 use crate::proxy_db_sqlite::ProxySqlite;
+use crate::tenant::TenantState;
 use crate::user_info::UserInfo;
 use crate::RcdProxyErr;
 
@@ -11,7 +15,10 @@ pub struct DbConfigSqlite {
 }
 
 #[derive(Clone, Debug)]
-pub struct DbConfigMySql {}
+pub struct DbConfigMySql {
+    pub db_name: String,
+    pub connection: DbiConfigMySql,
+}
 
 #[derive(Clone, Debug)]
 pub struct DbConfigPostgres {}
@@ -29,6 +36,7 @@ pub enum ProxyDbConfig {
 pub struct ProxyDb {
     config: ProxyDbConfig,
     sqlite: Option<ProxySqlite>,
+    mysql: Option<ProxyMySql>,
 }
 
 impl ProxyDb {
@@ -39,6 +47,21 @@ impl ProxyDb {
         Self {
             config: ProxyDbConfig::Sqlite(config),
             sqlite: Some(sqlite),
+            mysql: None,
+        }
+    }
+
+    pub fn new_with_mysql(db_name: String, connection: DbiConfigMySql) -> Self {
+        let config = DbConfigMySql {
+            db_name,
+            connection,
+        };
+        let mysql = ProxyMySql::new(config.clone());
+
+        Self {
+            config: ProxyDbConfig::MySql(config),
+            sqlite: None,
+            mysql: Some(mysql),
         }
     }
 
@@ -49,7 +72,9 @@ impl ProxyDb {
             ProxyDbConfig::Sqlite(_) => {
                 self.sqlite().config();
             }
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => {
+                self.mysql().config();
+            }
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -58,7 +83,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().register_user(un, hash),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().register_user(un, hash),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -72,7 +97,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().save_token(login, token, expiration),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().save_token(login, token, expiration),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -82,7 +107,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().revoke_tokens_for_login(un),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().revoke_tokens_for_login(un),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -92,7 +117,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().login_has_token(un),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().login_has_token(un),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -102,7 +127,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Sqlite(_) => self.sqlite().get_user_with_token(&token),
             ProxyDbConfig::Unknown => unimplemented!(),
-            ProxyDbConfig::MySql(_) => unimplemented!(),
+            ProxyDbConfig::MySql(_) => self.mysql().get_user_with_token(&token),
             ProxyDbConfig::Postgres(_) => unimplemented!(),
         }
     }
@@ -112,7 +137,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Sqlite(_) => self.sqlite().verify_token(&token),
             ProxyDbConfig::Unknown => unimplemented!(),
-            ProxyDbConfig::MySql(_) => unimplemented!(),
+            ProxyDbConfig::MySql(_) => self.mysql().verify_token(&token),
             ProxyDbConfig::Postgres(_) => unimplemented!(),
         }
     }
@@ -123,7 +148,9 @@ impl ProxyDb {
                 self.sqlite().delete_expired_tokens();
             }
             ProxyDbConfig::Unknown => unimplemented!(),
-            ProxyDbConfig::MySql(_) => unimplemented!(),
+            ProxyDbConfig::MySql(_) => {
+                self.mysql().delete_expired_tokens();
+            }
             ProxyDbConfig::Postgres(_) => unimplemented!(),
         }
     }
@@ -133,7 +160,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().has_user(un),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().has_user(un),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -143,7 +170,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().has_host(id),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().has_host(id),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -152,7 +179,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().get_host(id),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().get_host(id),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -161,7 +188,7 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().get_user(un),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().get_user(un),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -170,7 +197,61 @@ impl ProxyDb {
         match self.config {
             ProxyDbConfig::Unknown => todo!(),
             ProxyDbConfig::Sqlite(_) => self.sqlite().update_user(u),
-            ProxyDbConfig::MySql(_) => todo!(),
+            ProxyDbConfig::MySql(_) => self.mysql().update_user(u),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn get_quota(&self, un: &str) -> Result<Option<TenantQuota>, RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().get_quota(un),
+            ProxyDbConfig::MySql(_) => self.mysql().get_quota(un),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn save_quota(&self, un: &str, quota: &TenantQuota) -> Result<(), RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().save_quota(un, quota),
+            ProxyDbConfig::MySql(_) => self.mysql().save_quota(un, quota),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn get_tenant_state(&self, un: &str) -> Result<Option<TenantState>, RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().get_tenant_state(un),
+            ProxyDbConfig::MySql(_) => self.mysql().get_tenant_state(un),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn save_tenant_state(&self, un: &str, state: &TenantState) -> Result<(), RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().save_tenant_state(un, state),
+            ProxyDbConfig::MySql(_) => self.mysql().save_tenant_state(un, state),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn get_tenants_to_delete(&self, now: DateTime<Utc>) -> Result<Vec<String>, RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().get_tenants_to_delete(now),
+            ProxyDbConfig::MySql(_) => self.mysql().get_tenants_to_delete(now),
+            ProxyDbConfig::Postgres(_) => todo!(),
+        }
+    }
+
+    pub fn delete_user(&self, un: &str) -> Result<(), RcdProxyErr> {
+        match self.config {
+            ProxyDbConfig::Unknown => todo!(),
+            ProxyDbConfig::Sqlite(_) => self.sqlite().delete_user(un),
+            ProxyDbConfig::MySql(_) => self.mysql().delete_user(un),
             ProxyDbConfig::Postgres(_) => todo!(),
         }
     }
@@ -178,4 +259,8 @@ impl ProxyDb {
     fn sqlite(&self) -> &ProxySqlite {
         self.sqlite.as_ref().unwrap()
     }
+
+    fn mysql(&self) -> &ProxyMySql {
+        self.mysql.as_ref().unwrap()
+    }
 }
@@ -0,0 +1,330 @@
+use crate::sql_text::mysql::{
+    ADD_LOGIN, ADD_QUOTA, ADD_TENANT_STATUS, ADD_TOKEN, DELETE_LOGIN, DELETE_QUOTA,
+    DELETE_TENANT_STATUS, GET_HOST, GET_QUOTA, GET_TENANTS_TO_DELETE, GET_TENANT_STATUS, GET_USER,
+    MYSQL_CREATE_LOGIN_TABLE, MYSQL_CREATE_QUOTAS_TABLE, MYSQL_CREATE_TENANT_STATUS_TABLE,
+    MYSQL_CREATE_TOKENS_TABLE, UPDATE_USER,
+};
+use crate::tenant::TenantState;
+use crate::user_info::UserInfo;
+use crate::{proxy_db::DbConfigMySql, RcdProxyErr};
+use chrono::{DateTime, Utc};
+use mysql::prelude::Queryable;
+use mysql::Conn;
+use rcd_enum::tenant_status::TenantStatus;
+use rcd_messages::proxy::server_messages::TenantQuota;
+use stdext::function_name;
+use thiserror::Error;
+use tracing::{trace, warn};
+
+#[derive(Debug, Error)]
+pub enum ProxyMySqlErr {
+    #[error("Error `{0}`")]
+    General(String),
+}
+
+/// The proxy's backing store on a MySQL server. The `LOGIN`, `TOKENS`, `QUOTAS` and
+/// `TENANT_STATUS` tables are the same as the Sqlite version and live in the database named
+/// `db_name` on the server.
+#[derive(Clone, Debug)]
+pub struct ProxyMySql {
+    config: DbConfigMySql,
+}
+
+impl ProxyMySql {
+    pub fn new(config: DbConfigMySql) -> Self {
+        Self { config }
+    }
+
+    pub fn config(&self) {
+        self.write(MYSQL_CREATE_LOGIN_TABLE).unwrap();
+        self.write(MYSQL_CREATE_TOKENS_TABLE).unwrap();
+        self.write(MYSQL_CREATE_QUOTAS_TABLE).unwrap();
+        self.write(MYSQL_CREATE_TENANT_STATUS_TABLE).unwrap();
+    }
+
+    pub fn save_token(
+        &self,
+        login: &str,
+        token: &str,
+        expiration: DateTime<Utc>,
+    ) -> Result<(), RcdProxyErr> {
+        let issued = Utc::now().to_rfc3339();
+        let expiration = expiration.to_rfc3339();
+
+        let mut conn = self.conn()?;
+        conn.exec_drop(ADD_TOKEN, (login, token, issued, expiration))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    pub fn get_user_with_token(&self, token: &str) -> Result<UserInfo, RcdProxyErr> {
+        let mut conn = self.conn()?;
+        let un: Option<String> = conn
+            .exec_first("SELECT username FROM TOKENS WHERE token = ?", (token,))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        self.get_user(&un.unwrap_or_default())
+    }
+
+    pub fn verify_token(&self, token: &str) -> bool {
+        self.has_any_rows("SELECT COUNT(*) FROM TOKENS WHERE token = ?", token)
+    }
+
+    pub fn revoke_tokens_for_login(&self, login: &str) -> bool {
+        let result = self.conn().and_then(|mut conn| {
+            conn.exec_drop("DELETE FROM TOKENS WHERE username = ?", (login,))
+                .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+            Ok(conn.affected_rows() > 0)
+        });
+
+        match result {
+            Ok(revoked) => revoked,
+            Err(e) => {
+                warn!("unable to revoke tokens for {login}: {e}");
+                false
+            }
+        }
+    }
+
+    pub fn login_has_token(&self, login: &str) -> bool {
+        self.has_any_rows("SELECT COUNT(*) FROM TOKENS WHERE username = ?", login)
+    }
+
+    pub fn delete_expired_tokens(&self) {
+        let now = Utc::now().to_rfc3339();
+        match self.conn() {
+            Ok(mut conn) => {
+                let _ = conn.exec_drop("DELETE FROM TOKENS WHERE expiration_utc < ?", (now,));
+            }
+            Err(e) => warn!("unable to delete expired tokens: {e}"),
+        }
+    }
+
+    pub fn register_user(&self, un: &str, hash: &str) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            let mut conn = self.conn()?;
+            conn.exec_drop(ADD_LOGIN, (un, hash.as_bytes().to_vec()))
+                .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+            if conn.affected_rows() > 0 {
+                Ok(())
+            } else {
+                Err(RcdProxyErr::DbError("Unable to add user".to_string()))
+            }
+        } else {
+            let msg = format!("User '{}' already exists", un);
+            Err(RcdProxyErr::UserAlreadyExists(msg))
+        }
+    }
+
+    pub fn has_user(&self, un: &str) -> bool {
+        self.has_any_rows("SELECT COUNT(*) FROM LOGIN WHERE username = ?", un)
+    }
+
+    pub fn has_host(&self, id: &str) -> bool {
+        self.has_any_rows("SELECT COUNT(*) FROM LOGIN WHERE host_id = ?", id)
+    }
+
+    pub fn get_host(&self, id: &str) -> Result<UserInfo, RcdProxyErr> {
+        if !self.has_host(id) {
+            return Err(RcdProxyErr::HostIdNotFound(id.to_string()));
+        }
+
+        self.get_login(GET_HOST, id)
+    }
+
+    pub fn get_user(&self, un: &str) -> Result<UserInfo, RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        self.get_login(GET_USER, un)
+    }
+
+    pub fn update_user(&self, u: &UserInfo) -> Result<(), RcdProxyErr> {
+        if !self.has_user(&u.username) {
+            return Err(RcdProxyErr::UserNotFound(u.username.to_string()));
+        }
+
+        let mut conn = self.conn()?;
+        let r = conn.exec_drop(
+            UPDATE_USER,
+            (
+                u.folder.clone().unwrap(),
+                u.hash.clone(),
+                u.id.clone(),
+                u.username.clone(),
+            ),
+        );
+
+        // MySQL only counts rows whose values changed, and we've already checked that the
+        // user exists, so any successful update is treated as applied
+        match r {
+            Ok(_) => Ok(()),
+            Err(e) => Err(RcdProxyErr::DbError(e.to_string())),
+        }
+    }
+
+    /// returns the quota saved for the login, if one has been set
+    pub fn get_quota(&self, un: &str) -> Result<Option<TenantQuota>, RcdProxyErr> {
+        let mut conn = self.conn()?;
+        let quota: Option<(Option<u64>, Option<u64>, Option<u64>, Option<u32>)> = conn
+            .exec_first(GET_QUOTA, (un,))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        Ok(quota.map(
+            |(max_databases, max_storage_bytes, max_rows, max_requests_per_second)| TenantQuota {
+                max_databases,
+                max_storage_bytes,
+                max_rows,
+                max_requests_per_second,
+            },
+        ))
+    }
+
+    /// replaces any quota saved for the login with the one provided
+    pub fn save_quota(&self, un: &str, quota: &TenantQuota) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        let mut conn = self.conn()?;
+        conn.exec_drop(DELETE_QUOTA, (un,))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        conn.exec_drop(
+            ADD_QUOTA,
+            (
+                un,
+                quota.max_databases,
+                quota.max_storage_bytes,
+                quota.max_rows,
+                quota.max_requests_per_second,
+            ),
+        )
+        .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    /// returns the lifecycle state saved for the login, if it has left `Active`
+    pub fn get_tenant_state(&self, un: &str) -> Result<Option<TenantState>, RcdProxyErr> {
+        let mut conn = self.conn()?;
+        let state: Option<(u32, Option<String>)> = conn
+            .exec_first(GET_TENANT_STATUS, (un,))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        Ok(state.map(|(status, delete_after_utc)| TenantState {
+            status: TenantStatus::from_u32(status),
+            delete_after_utc: TenantState::parse_utc(delete_after_utc),
+        }))
+    }
+
+    /// replaces any lifecycle state saved for the login with the one provided
+    pub fn save_tenant_state(&self, un: &str, state: &TenantState) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        let mut conn = self.conn()?;
+        conn.exec_drop(DELETE_TENANT_STATUS, (un,))
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        conn.exec_drop(
+            ADD_TENANT_STATUS,
+            (
+                un,
+                TenantStatus::to_u32(state.status),
+                Utc::now().to_rfc3339(),
+                state.delete_after_utc.map(|x| x.to_rfc3339()),
+            ),
+        )
+        .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    /// returns the logins that are pending delete and whose grace period ended before `now`
+    pub fn get_tenants_to_delete(&self, now: DateTime<Utc>) -> Result<Vec<String>, RcdProxyErr> {
+        let mut conn = self.conn()?;
+        conn.exec(
+            GET_TENANTS_TO_DELETE,
+            (
+                TenantStatus::to_u32(TenantStatus::PendingDelete),
+                now.to_rfc3339(),
+            ),
+        )
+        .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    /// removes the login along with its tokens, quota and lifecycle state
+    pub fn delete_user(&self, un: &str) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        self.revoke_tokens_for_login(un);
+
+        let mut conn = self.conn()?;
+        for cmd in [DELETE_QUOTA, DELETE_TENANT_STATUS, DELETE_LOGIN] {
+            conn.exec_drop(cmd, (un,))
+                .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// returns the single `LOGIN` row for the query, which takes one parameter
+    fn get_login(&self, cmd: &str, param: &str) -> Result<UserInfo, RcdProxyErr> {
+        let mut conn = self.conn()?;
+        let users = conn
+            .exec_map(
+                cmd,
+                (param,),
+                |(username, hash, folder, id): (
+                    String,
+                    Vec<u8>,
+                    Option<String>,
+                    Option<String>,
+                )| UserInfo {
+                    username,
+                    hash,
+                    folder,
+                    id,
+                },
+            )
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        Ok(users.last().unwrap().clone())
+    }
+
+    fn write(&self, sql: &str) -> Result<u64, ProxyMySqlErr> {
+        let mut conn = self
+            .conn()
+            .map_err(|e| ProxyMySqlErr::General(e.to_string()))?;
+        let result = conn.query_drop(sql);
+
+        match result {
+            Ok(_) => Ok(conn.affected_rows()),
+            Err(e) => Err(ProxyMySqlErr::General(e.to_string())),
+        }
+    }
+
+    fn conn(&self) -> Result<Conn, RcdProxyErr> {
+        trace!("[{}]: {:?}", function_name!(), self.config.db_name);
+        rcd_mysql::mysql::get_db_conn(&self.config.connection, &self.config.db_name)
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    fn has_any_rows(&self, cmd: &str, param: &str) -> bool {
+        trace!("[{}]: {cmd:?}", function_name!());
+
+        let result = self.conn().and_then(|mut conn| {
+            conn.exec_first::<u32, _, _>(cmd, (param,))
+                .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+        });
+
+        match result {
+            Ok(count) => count.unwrap_or_default() > 0,
+            Err(e) => {
+                warn!("unable to run {cmd}: {e}");
+                false
+            }
+        }
+    }
+}
@@ -1,12 +1,17 @@
 use crate::sql_text::sqlite::{
-    ADD_LOGIN, GET_HOST, GET_USER, SQLITE_CREATE_LOGIN_TABLE, SQLITE_CREATE_TOKENS_TABLE,
-    UPDATE_USER,
+    ADD_LOGIN, ADD_QUOTA, ADD_TENANT_STATUS, DELETE_LOGIN, DELETE_QUOTA, DELETE_TENANT_STATUS,
+    GET_HOST, GET_QUOTA, GET_TENANTS_TO_DELETE, GET_TENANT_STATUS, GET_USER,
+    SQLITE_CREATE_LOGIN_TABLE, SQLITE_CREATE_QUOTAS_TABLE, SQLITE_CREATE_TENANT_STATUS_TABLE,
+    SQLITE_CREATE_TOKENS_TABLE, UPDATE_USER,
 };
+use crate::tenant::TenantState;
 use crate::user_info::UserInfo;
 #[allow(unused_imports)]
 use crate::PROXY_DB;
 use crate::{proxy_db::DbConfigSqlite, RcdProxyErr};
 use chrono::{DateTime, Utc};
+use rcd_enum::tenant_status::TenantStatus;
+use rcd_messages::proxy::server_messages::TenantQuota;
 use stdext::function_name;
 use tracing::{debug, trace, warn};
 use rusqlite::named_params;
@@ -33,6 +38,8 @@ impl ProxySqlite {
     pub fn config(&self) {
         self.write(SQLITE_CREATE_LOGIN_TABLE).unwrap();
         self.write(SQLITE_CREATE_TOKENS_TABLE).unwrap();
+        self.write(SQLITE_CREATE_QUOTAS_TABLE).unwrap();
+        self.write(SQLITE_CREATE_TENANT_STATUS_TABLE).unwrap();
     }
 
     pub fn save_token(
@@ -252,6 +259,136 @@ impl ProxySqlite {
         }
     }
 
+    /// returns the quota saved for the login, if one has been set
+    pub fn get_quota(&self, un: &str) -> Result<Option<TenantQuota>, RcdProxyErr> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(GET_QUOTA).unwrap();
+
+        let quotas = statement
+            .query_map(named_params! { ":un": un }, |row| {
+                Ok(TenantQuota {
+                    max_databases: row.get::<_, Option<i64>>(0)?.map(|x| x as u64),
+                    max_storage_bytes: row.get::<_, Option<i64>>(1)?.map(|x| x as u64),
+                    max_rows: row.get::<_, Option<i64>>(2)?.map(|x| x as u64),
+                    max_requests_per_second: row.get::<_, Option<i64>>(3)?.map(|x| x as u32),
+                })
+            })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        match quotas.last() {
+            Some(quota) => Ok(Some(
+                quota.map_err(|e| RcdProxyErr::DbError(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// replaces any quota saved for the login with the one provided
+    pub fn save_quota(&self, un: &str, quota: &TenantQuota) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        let conn = self.conn();
+        conn.execute(DELETE_QUOTA, named_params! { ":un": un })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        let mut statement = conn.prepare(ADD_QUOTA).unwrap();
+        statement
+            .execute(named_params! {
+                ":un": un,
+                ":max_databases": quota.max_databases.map(|x| x as i64),
+                ":max_storage_bytes": quota.max_storage_bytes.map(|x| x as i64),
+                ":max_rows": quota.max_rows.map(|x| x as i64),
+                ":max_requests_per_second": quota.max_requests_per_second,
+            })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// returns the lifecycle state saved for the login, if it has left `Active`
+    pub fn get_tenant_state(&self, un: &str) -> Result<Option<TenantState>, RcdProxyErr> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(GET_TENANT_STATUS).unwrap();
+
+        let states = statement
+            .query_map(named_params! { ":un": un }, |row| {
+                Ok(TenantState {
+                    status: TenantStatus::from_u32(row.get(0)?),
+                    delete_after_utc: TenantState::parse_utc(row.get(1)?),
+                })
+            })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        match states.last() {
+            Some(state) => Ok(Some(
+                state.map_err(|e| RcdProxyErr::DbError(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// replaces any lifecycle state saved for the login with the one provided
+    pub fn save_tenant_state(&self, un: &str, state: &TenantState) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        let conn = self.conn();
+        conn.execute(DELETE_TENANT_STATUS, named_params! { ":un": un })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        let mut statement = conn.prepare(ADD_TENANT_STATUS).unwrap();
+        statement
+            .execute(named_params! {
+                ":un": un,
+                ":status": TenantStatus::to_u32(state.status),
+                ":changed": Utc::now().to_rfc3339(),
+                ":delete_after": state.delete_after_utc.map(|x| x.to_rfc3339()),
+            })
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// returns the logins that are pending delete and whose grace period ended before `now`
+    pub fn get_tenants_to_delete(&self, now: DateTime<Utc>) -> Result<Vec<String>, RcdProxyErr> {
+        let conn = self.conn();
+        let mut statement = conn.prepare(GET_TENANTS_TO_DELETE).unwrap();
+
+        let logins = statement
+            .query_map(
+                named_params! {
+                    ":status": TenantStatus::to_u32(TenantStatus::PendingDelete),
+                    ":now": now.to_rfc3339(),
+                },
+                |row| row.get(0),
+            )
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+
+        logins
+            .collect::<Result<Vec<String>>>()
+            .map_err(|e| RcdProxyErr::DbError(e.to_string()))
+    }
+
+    /// removes the login along with its tokens, quota and lifecycle state
+    pub fn delete_user(&self, un: &str) -> Result<(), RcdProxyErr> {
+        if !self.has_user(un) {
+            return Err(RcdProxyErr::UserNotFound(un.to_string()));
+        }
+
+        self.revoke_tokens_for_login(un);
+
+        let conn = self.conn();
+        for cmd in [DELETE_QUOTA, DELETE_TENANT_STATUS, DELETE_LOGIN] {
+            conn.execute(cmd, named_params! { ":un": un })
+                .map_err(|e| RcdProxyErr::DbError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
     fn write(&self, sql: &str) -> Result<usize, ProxySqliteErr> {
         let result = self.conn().execute(sql, []);
 
@@ -1,12 +1,51 @@
-use crate::RcdProxy;
+use crate::quota::QuotaAction;
+use crate::tenant::TenantState;
+use crate::{RcdProxy, RcdProxyErr};
 use chrono::Utc;
 use tracing::{debug, warn};
 use rcd_common::defaults;
 use rcd_core::{rcd::Rcd, rcd_data::RcdData};
+use rcd_enum::tenant_status::TenantStatus;
 use rcdproto::rcdp::data_service_server::DataService;
+use rcdproto::rcdp::proxy_admin_server::ProxyAdmin;
 use rcdproto::rcdp::sql_client_server::SqlClient;
 use rcdproto::rcdp::*;
-use tonic::{Request, Response, Status};
+use std::pin::Pin;
+use tokio_stream::{wrappers::ReceiverStream, Stream, StreamExt};
+use tonic::{Code, Request, Response, Status};
+
+/// Refuses the request with `ResourceExhausted` if the host in the auth request is over its quota
+/// for the action. The `QuotaError` is serialized as json into the status details. Only requests
+/// that authenticated are charged, so that a caller without the host's credentials can't use up
+/// its quota.
+fn check_quota<T>(
+    proxy: &RcdProxy,
+    auth: &Option<AuthRequest>,
+    auth_result: &Result<T, AuthResult>,
+    action: QuotaAction,
+) -> Result<(), Status> {
+    if auth_result.is_err() {
+        return Ok(());
+    }
+
+    let id = match auth.as_ref().and_then(|auth| auth.id.as_ref()) {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    match proxy.check_quota(id, action) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            warn!("quota exceeded: {e:?}");
+            let details = serde_json::to_vec(&e).unwrap_or_default();
+            Err(Status::with_details(
+                Code::ResourceExhausted,
+                e.message.clone(),
+                details.into(),
+            ))
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
@@ -40,10 +79,30 @@ impl ProxyClientGrpc {
     /// - Exists
     /// - Exists and has a host id
     /// - Exists, has a host id, and we have an account with that host id
+    /// - Exists, has a host id, we have an account with that host id, and its login or token is
+    ///   valid for that account
     ///
     /// If the above conditions are true, it will return the `core` for that account,
     /// otherwise, we will return an `AuthResult` with an error message
     fn validate_auth_request(&self, auth: &Option<AuthRequest>) -> Result<Rcd, AuthResult> {
+        let core = self.get_core_for_auth_request(auth)?;
+        let auth = auth.as_ref().unwrap().clone();
+        let un = auth.user_name.clone();
+
+        if !core.verify_login(auth).0 {
+            return Err(AuthResult {
+                user_name: un,
+                token: "".to_string(),
+                is_authenticated: false,
+                authentication_message: "Login failed".to_string(),
+            });
+        }
+
+        Ok(core)
+    }
+
+    /// Returns the `core` for the host id in the auth request, without checking its login
+    fn get_core_for_auth_request(&self, auth: &Option<AuthRequest>) -> Result<Rcd, AuthResult> {
         if auth.is_some() {
             let auth = auth.as_ref().unwrap().clone();
             let un = auth.user_name.clone();
@@ -110,6 +169,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -134,6 +199,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -159,6 +230,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -184,6 +261,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -209,6 +292,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -234,6 +323,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -257,6 +352,12 @@ impl SqlClient for ProxyClientGrpc {
     ) -> Result<Response<GetDeletesToHostBehaviorReply>, Status> {
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -274,14 +375,38 @@ impl SqlClient for ProxyClientGrpc {
         }
     }
 
-    #[allow(dead_code, unused_variables)]
     async fn get_versions(
         &self,
         request: Request<AuthRequest>,
     ) -> Result<Response<VersionReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        // need to write an HTTP version as well
-        todo!()
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&Some(request.clone()));
+        check_quota(
+            &self.proxy,
+            &Some(request.clone()),
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.get_versions(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = VersionReply {
+                    authentication_result: Some(auth_result),
+                    rcdx: String::from(""),
+                    rcd_core: String::from(""),
+                    rcdproto: String::from(""),
+                    protocol_version: 0,
+                    rcd_schema_version: 0,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
     }
 
     async fn get_host_info(
@@ -291,6 +416,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&Some(request.clone()));
+        check_quota(
+            &self.proxy,
+            &Some(request.clone()),
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -315,6 +446,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&Some(request.clone()));
+        check_quota(
+            &self.proxy,
+            &Some(request.clone()),
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -339,6 +476,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&Some(request.clone()));
+        check_quota(
+            &self.proxy,
+            &Some(request.clone()),
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -365,6 +508,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -390,6 +539,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -417,6 +572,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -441,6 +602,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -458,6 +625,126 @@ impl SqlClient for ProxyClientGrpc {
         }
     }
 
+    async fn get_pending_writes_at_host(
+        &self,
+        request: Request<GetPendingWritesAtHostRequest>,
+    ) -> Result<Response<GetPendingWritesAtHostReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.get_pending_writes_at_host(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = GetPendingWritesAtHostReply {
+                    authentication_result: Some(auth_result),
+                    pending_writes: Vec::new(),
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
+    async fn reject_pending_action_at_participant(
+        &self,
+        request: Request<RejectPendingActionRequest>,
+    ) -> Result<Response<RejectPendingActionReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.reject_pending_action_at_participant(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = RejectPendingActionReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
+    async fn get_pending_action_ttl_at_participant(
+        &self,
+        request: Request<GetPendingActionTtlRequest>,
+    ) -> Result<Response<GetPendingActionTtlReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.get_pending_action_ttl_at_participant(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = GetPendingActionTtlReply {
+                    authentication_result: Some(auth_result),
+                    ttl_seconds: 0,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
+    async fn set_pending_action_ttl_at_participant(
+        &self,
+        request: Request<SetPendingActionTtlRequest>,
+    ) -> Result<Response<SetPendingActionTtlReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.set_pending_action_ttl_at_participant(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = SetPendingActionTtlReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
     async fn get_pending_actions_at_participant(
         &self,
         request: Request<GetPendingActionsRequest>,
@@ -465,6 +752,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -482,22 +775,64 @@ impl SqlClient for ProxyClientGrpc {
         }
     }
 
-    #[allow(dead_code, unused_variables)]
     async fn get_data_log_table_status_at_participant(
         &self,
         request: Request<GetDataLogTableStatusRequest>,
     ) -> Result<Response<GetDataLogTableStatusReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        unimplemented!();
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.get_data_log_table_status_at_participant(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = GetDataLogTableStatusReply {
+                    authentication_result: Some(auth_result),
+                    use_data_log: false,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
     }
 
-    #[allow(dead_code, unused_variables)]
     async fn set_data_log_table_status_at_participant(
         &self,
         request: Request<SetDataLogTableStatusRequest>,
     ) -> Result<Response<SetDataLogTableStatusReply>, Status> {
         debug!("Request from {:?}", request.remote_addr());
-        unimplemented!();
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.set_data_log_table_status_at_participant(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = SetDataLogTableStatusReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
     }
 
     async fn generate_host_info(
@@ -508,6 +843,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -533,6 +874,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::CreateDatabase,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -559,6 +906,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -585,6 +938,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -613,6 +972,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -641,6 +1006,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -668,6 +1039,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -695,6 +1072,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -706,6 +1089,7 @@ impl SqlClient for ProxyClientGrpc {
                     authentication_result: Some(auth_result),
                     is_successful: false,
                     total_rows_affected: 0,
+                    participant_statuses: Vec::new(),
                 };
 
                 return Ok(Response::new(reply));
@@ -720,6 +1104,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -744,6 +1134,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -769,6 +1165,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -779,6 +1181,7 @@ impl SqlClient for ProxyClientGrpc {
                 let reply = GetLogicalStoragePolicyReply {
                     authentication_result: Some(auth_result),
                     policy_mode: 0,
+                    participant_owned_columns: Vec::new(),
                 };
 
                 return Ok(Response::new(reply));
@@ -794,6 +1197,12 @@ impl SqlClient for ProxyClientGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -819,6 +1228,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -844,6 +1259,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -869,6 +1290,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         debug!("review_pending_contracts: {request:?}");
 
@@ -895,6 +1322,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::CreateDatabase,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -913,12 +1346,35 @@ impl SqlClient for ProxyClientGrpc {
         }
     }
 
-    #[allow(dead_code, unused_variables)]
     async fn reject_pending_contract(
         &self,
-        _request: tonic::Request<RejectPendingContractRequest>,
-    ) -> Result<tonic::Response<RejectPendingContractReply>, tonic::Status> {
-        unimplemented!();
+        request: Request<RejectPendingContractRequest>,
+    ) -> Result<Response<RejectPendingContractReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.reject_pending_contract(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = RejectPendingContractReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                    message: "".to_string(),
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
     }
 
     async fn change_host_status(
@@ -928,6 +1384,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -953,6 +1415,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -964,6 +1432,8 @@ impl SqlClient for ProxyClientGrpc {
                     authentication_result: Some(auth_result),
                     is_successful: false,
                     message: "".to_string(),
+                    protocol_version: 0,
+                    error: None,
                 };
 
                 return Ok(Response::new(reply));
@@ -978,6 +1448,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1003,6 +1479,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1028,6 +1510,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1053,6 +1541,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1078,6 +1572,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1102,6 +1602,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1126,6 +1632,12 @@ impl SqlClient for ProxyClientGrpc {
         debug!("Request from {:?}", request.remote_addr());
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1142,6 +1654,100 @@ impl SqlClient for ProxyClientGrpc {
             }
         }
     }
+
+    async fn rehash_partial_database(
+        &self,
+        request: Request<RehashPartialDatabaseRequest>,
+    ) -> Result<Response<RehashPartialDatabaseReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.rehash_partial_database(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = RehashPartialDatabaseReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                    total_rows_rehashed: 0,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
+    async fn unlock_database(
+        &self,
+        request: Request<UnlockDatabaseRequest>,
+    ) -> Result<Response<UnlockDatabaseReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.unlock_database(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = UnlockDatabaseReply {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                    message: String::from(""),
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
+    type ExportDatabaseStream =
+        Pin<Box<dyn Stream<Item = Result<ExportDatabaseReply, Status>> + Send>>;
+
+    async fn export_database(
+        &self,
+        request: Request<ExportDatabaseRequest>,
+    ) -> Result<Response<Self::ExportDatabaseStream>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        let replies: Self::ExportDatabaseStream = match auth_result {
+            Ok(core) => Box::pin(ReceiverStream::new(core.export_database(request).await).map(Ok)),
+            Err(auth_result) => Box::pin(tokio_stream::once(Ok(ExportDatabaseReply {
+                authentication_result: Some(auth_result),
+                is_successful: false,
+                message: String::from(""),
+                manifest: None,
+                file_name: String::from(""),
+                data: Vec::new(),
+            }))),
+        };
+
+        Ok(Response::new(replies))
+    }
 }
 
 #[allow(dead_code, unused_variables)]
@@ -1172,11 +1778,42 @@ impl ProxyDbGrpc {
     /// - Exists
     /// - Exists and has a host id
     /// - Exists, has a host id, and we have an account with that host id
+    /// - Exists, has a host id, we have an account with that host id, and it comes from a host
+    ///   that account knows
     ///
     /// If the above conditions are true, it will return the `core` for that account,
     /// otherwise, we will return an `AuthResult` with an error message
-    #[allow(dead_code, unused_variables)]
     fn validate_auth_request(&self, auth: &Option<AuthRequest>) -> Result<RcdData, AuthResult> {
+        let core = self.get_core_for_auth_request(auth)?;
+        let (is_authenticated, auth_result) =
+            core.authenticate_host(auth.as_ref().unwrap().clone());
+
+        if !is_authenticated {
+            return Err(auth_result);
+        }
+
+        Ok(core)
+    }
+
+    /// As `validate_auth_request`, for a request that comes from a participant of `db_name`
+    fn validate_participant_auth_request(
+        &self,
+        auth: &Option<AuthRequest>,
+        db_name: &str,
+    ) -> Result<RcdData, AuthResult> {
+        let core = self.get_core_for_auth_request(auth)?;
+        let (is_authenticated, auth_result) =
+            core.authenticate_participant(auth.as_ref().unwrap().clone(), db_name);
+
+        if !is_authenticated {
+            return Err(auth_result);
+        }
+
+        Ok(core)
+    }
+
+    /// Returns the `core` for the host id in the auth request, without checking who sent it
+    fn get_core_for_auth_request(&self, auth: &Option<AuthRequest>) -> Result<RcdData, AuthResult> {
         if auth.is_some() {
             let auth = auth.as_ref().unwrap().clone();
             let un = auth.user_name.clone();
@@ -1244,6 +1881,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::CreateDatabase,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1272,6 +1915,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1302,6 +1951,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1330,6 +1985,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Write,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1343,6 +2004,7 @@ impl DataService for ProxyDbGrpc {
                     message: "".to_string(),
                     rows: Vec::new(),
                     update_status: 0,
+                    pending_action_id: 0,
                 };
 
                 return Ok(Response::new(reply));
@@ -1358,6 +2020,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1370,6 +2038,7 @@ impl DataService for ProxyDbGrpc {
                     is_successful: false,
                     message: "".to_string(),
                     rows: Vec::new(),
+                    pending_action_id: 0,
                 };
 
                 return Ok(Response::new(reply));
@@ -1385,6 +2054,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1404,6 +2079,39 @@ impl DataService for ProxyDbGrpc {
         }
     }
 
+    async fn get_rows_from_partial_database(
+        &self,
+        request: Request<GetRowsFromPartialDatabaseRequest>,
+    ) -> Result<Response<GetRowsFromPartialDatabaseResult>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+
+        let request = request.into_inner().clone();
+        let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.get_rows_from_partial_database(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = GetRowsFromPartialDatabaseResult {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                    rows: Vec::new(),
+                    result_message: "".to_string(),
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
     async fn save_contract(
         &self,
         request: Request<SaveContractRequest>,
@@ -1421,7 +2129,9 @@ impl DataService for ProxyDbGrpc {
             id: request.id.clone(),
         };
 
-        let auth_result = self.validate_auth_request(&Some(auth));
+        // a contract comes from a host the participant doesn't know yet, so there are no
+        // credentials to check, and nothing is charged to the participant's quota
+        let auth_result = self.get_core_for_auth_request(&Some(auth));
 
         match auth_result {
             Ok(core) => {
@@ -1459,7 +2169,8 @@ impl DataService for ProxyDbGrpc {
             id: Some(id),
         };
 
-        let auth_result = self.validate_auth_request(&Some(auth));
+        // the participant's reply to a contract has no credentials to check either
+        let auth_result = self.get_core_for_auth_request(&Some(auth));
 
         match auth_result {
             Ok(core) => {
@@ -1477,6 +2188,44 @@ impl DataService for ProxyDbGrpc {
         }
     }
 
+    async fn reject_contract(
+        &self,
+        request: Request<ParticipantRejectsContractRequest>,
+    ) -> Result<Response<ParticipantRejectsContractResult>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+
+        let request = request.into_inner().clone();
+
+        let id = request.id.as_ref().unwrap().clone();
+
+        let auth = AuthRequest {
+            user_name: "".to_string(),
+            pw: "".to_string(),
+            pw_hash: Vec::new(),
+            token: Vec::new(),
+            jwt: "".to_string(),
+            id: Some(id),
+        };
+
+        // the participant's reply to a contract has no credentials to check either
+        let auth_result = self.get_core_for_auth_request(&Some(auth));
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.reject_contract(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = ParticipantRejectsContractResult {
+                    contract_rejection_is_acknowledged: false,
+                    error_message: auth_result.authentication_message.clone(),
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
     async fn update_row_data_hash_for_host(
         &self,
         request: Request<UpdateRowDataHashForHostRequest>,
@@ -1484,7 +2233,14 @@ impl DataService for ProxyDbGrpc {
         debug!("Request from {:?}", request.remote_addr());
 
         let request = request.into_inner().clone();
-        let auth_result = self.validate_auth_request(&request.authentication);
+        let auth_result =
+            self.validate_participant_auth_request(&request.authentication, &request.database_name);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1502,6 +2258,38 @@ impl DataService for ProxyDbGrpc {
         }
     }
 
+    async fn notify_host_of_pending_action(
+        &self,
+        request: Request<NotifyHostOfPendingActionRequest>,
+    ) -> Result<Response<NotifyHostOfPendingActionResponse>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+
+        let request = request.into_inner().clone();
+        let auth_result =
+            self.validate_participant_auth_request(&request.authentication, &request.database_name);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.notify_host_of_pending_action(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = NotifyHostOfPendingActionResponse {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                };
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
     async fn notify_host_of_removed_row(
         &self,
         request: Request<NotifyHostOfRemovedRowRequest>,
@@ -1512,7 +2300,14 @@ impl DataService for ProxyDbGrpc {
         );
 
         let request = request.into_inner().clone();
-        let auth_result = self.validate_auth_request(&request.authentication);
+        let auth_result =
+            self.validate_participant_auth_request(&request.authentication, &request.database_name);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1532,6 +2327,43 @@ impl DataService for ProxyDbGrpc {
         }
     }
 
+    async fn notify_host_of_inserted_row(
+        &self,
+        request: Request<NotifyHostOfInsertedRowRequest>,
+    ) -> Result<Response<NotifyHostOfInsertedRowResponse>, Status> {
+        debug!(
+            "notify_host_of_inserted_row: Request from {:?}",
+            request.remote_addr()
+        );
+
+        let request = request.into_inner().clone();
+        let auth_result =
+            self.validate_participant_auth_request(&request.authentication, &request.database_name);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
+
+        match auth_result {
+            Ok(core) => {
+                let response = core.notify_host_of_inserted_row(request).await;
+                return Ok(Response::new(response));
+            }
+            Err(auth_result) => {
+                let reply = NotifyHostOfInsertedRowResponse {
+                    authentication_result: Some(auth_result),
+                    is_successful: false,
+                };
+
+                warn!("notify_host_of_inserted_row: {reply:?}");
+
+                return Ok(Response::new(reply));
+            }
+        }
+    }
+
     async fn try_auth(
         &self,
         request: Request<TryAuthRequest>,
@@ -1540,6 +2372,12 @@ impl DataService for ProxyDbGrpc {
 
         let request = request.into_inner().clone();
         let auth_result = self.validate_auth_request(&request.authentication);
+        check_quota(
+            &self.proxy,
+            &request.authentication,
+            &auth_result,
+            QuotaAction::Request,
+        )?;
 
         match auth_result {
             Ok(core) => {
@@ -1549,6 +2387,8 @@ impl DataService for ProxyDbGrpc {
             Err(auth_result) => {
                 let reply = TryAuthResult {
                     authentication_result: Some(auth_result),
+                    protocol_version: 0,
+                    error: None,
                 };
 
                 return Ok(Response::new(reply));
@@ -1556,3 +2396,199 @@ impl DataService for ProxyDbGrpc {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+/// Implements the `ProxyAdmin` definition from the protobuff file, for the operators of the proxy
+pub struct ProxyAdminGrpc {
+    proxy: RcdProxy,
+}
+
+impl ProxyAdminGrpc {
+    pub fn new(proxy: RcdProxy) -> Self {
+        Self { proxy }
+    }
+
+    /// Checks that the auth request is for a login listed in the proxy's `admin_logins`
+    fn validate_admin_request(&self, auth: &Option<AuthRequest>) -> Result<(), AuthResult> {
+        match auth {
+            Some(auth) => match self.proxy.verify_admin_login(&auth.user_name, &auth.pw) {
+                Ok(_) => Ok(()),
+                Err(e) => Err(AuthResult {
+                    user_name: auth.user_name.clone(),
+                    token: "".to_string(),
+                    is_authenticated: false,
+                    authentication_message: e.to_string(),
+                }),
+            },
+            None => Err(AuthResult {
+                user_name: "".to_string(),
+                token: "".to_string(),
+                is_authenticated: false,
+                authentication_message: "No authentication provided".to_string(),
+            }),
+        }
+    }
+
+    fn status_reply(login: &str, result: Result<TenantState, RcdProxyErr>) -> TenantStatusReply {
+        match result {
+            Ok(state) => TenantStatusReply {
+                authentication_result: None,
+                is_successful: true,
+                login: login.to_string(),
+                status: TenantStatus::to_u32(state.status),
+                delete_after_utc: state.delete_after_utc.map(|x| x.to_rfc3339()),
+                error: None,
+            },
+            Err(e) => TenantStatusReply {
+                authentication_result: None,
+                is_successful: false,
+                login: login.to_string(),
+                status: TenantStatus::to_u32(TenantStatus::Unknown),
+                delete_after_utc: None,
+                error: Some(RcdError {
+                    number: 0,
+                    message: e.to_string(),
+                    help: String::from(""),
+                }),
+            },
+        }
+    }
+
+    fn export_failed_reply(message: String) -> ExportTenantReply {
+        ExportTenantReply {
+            authentication_result: None,
+            is_successful: false,
+            archive_size_in_bytes: 0,
+            error: Some(RcdError {
+                number: 0,
+                message,
+                help: String::from(""),
+            }),
+            archive_name: String::from(""),
+            data: Vec::new(),
+        }
+    }
+
+    fn archive_chunk_reply(chunk: std::io::Result<Vec<u8>>) -> ExportTenantReply {
+        match chunk {
+            Ok(data) => ExportTenantReply {
+                authentication_result: None,
+                is_successful: true,
+                archive_size_in_bytes: 0,
+                error: None,
+                archive_name: String::from(""),
+                data,
+            },
+            Err(e) => Self::export_failed_reply(e.to_string()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ProxyAdmin for ProxyAdminGrpc {
+    async fn get_tenant_status(
+        &self,
+        request: Request<TenantRequest>,
+    ) -> Result<Response<TenantStatusReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner();
+
+        if let Err(auth_result) = self.validate_admin_request(&request.authentication) {
+            let reply = TenantStatusReply {
+                authentication_result: Some(auth_result),
+                is_successful: false,
+                login: request.login.clone(),
+                status: TenantStatus::to_u32(TenantStatus::Unknown),
+                delete_after_utc: None,
+                error: None,
+            };
+
+            return Ok(Response::new(reply));
+        }
+
+        let result = self.proxy.get_tenant_state(&request.login);
+        Ok(Response::new(Self::status_reply(&request.login, result)))
+    }
+
+    async fn change_tenant_status(
+        &self,
+        request: Request<ChangeTenantStatusRequest>,
+    ) -> Result<Response<TenantStatusReply>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner();
+
+        if let Err(auth_result) = self.validate_admin_request(&request.authentication) {
+            let reply = TenantStatusReply {
+                authentication_result: Some(auth_result),
+                is_successful: false,
+                login: request.login.clone(),
+                status: TenantStatus::to_u32(TenantStatus::Unknown),
+                delete_after_utc: None,
+                error: None,
+            };
+
+            return Ok(Response::new(reply));
+        }
+
+        let result = match request.status {
+            0..=3 => self
+                .proxy
+                .change_tenant_status(&request.login, TenantStatus::from_u32(request.status)),
+            _ => Err(RcdProxyErr::InvalidTenantStatus(request.status)),
+        };
+
+        Ok(Response::new(Self::status_reply(&request.login, result)))
+    }
+
+    type ExportTenantStream = Pin<Box<dyn Stream<Item = Result<ExportTenantReply, Status>> + Send>>;
+
+    /// the first reply names the archive and gives its size, and the ones after it carry the
+    /// archive a chunk at a time; if reading it fails the last reply is unsuccessful
+    async fn export_tenant(
+        &self,
+        request: Request<TenantRequest>,
+    ) -> Result<Response<Self::ExportTenantStream>, Status> {
+        debug!("Request from {:?}", request.remote_addr());
+        let request = request.into_inner();
+
+        if let Err(auth_result) = self.validate_admin_request(&request.authentication) {
+            let reply = ExportTenantReply {
+                authentication_result: Some(auth_result),
+                is_successful: false,
+                archive_size_in_bytes: 0,
+                error: None,
+                archive_name: String::from(""),
+                data: Vec::new(),
+            };
+
+            return Ok(Response::new(Box::pin(tokio_stream::once(Ok(reply)))));
+        }
+
+        let replies: Self::ExportTenantStream = match self.proxy.export_tenant(&request.login) {
+            Ok((archive_path, archive_size_in_bytes)) => {
+                let reply = ExportTenantReply {
+                    authentication_result: None,
+                    is_successful: true,
+                    archive_size_in_bytes,
+                    error: None,
+                    archive_name: archive_path
+                        .file_name()
+                        .unwrap()
+                        .to_string_lossy()
+                        .to_string(),
+                    data: Vec::new(),
+                };
+
+                let chunks = ReceiverStream::new(self.proxy.send_export(archive_path))
+                    .map(|chunk| Ok(Self::archive_chunk_reply(chunk)));
+
+                Box::pin(tokio_stream::once(Ok(reply)).chain(chunks))
+            }
+            Err(e) => Box::pin(tokio_stream::once(Ok(Self::export_failed_reply(
+                e.to_string(),
+            )))),
+        };
+
+        Ok(Response::new(replies))
+    }
+}
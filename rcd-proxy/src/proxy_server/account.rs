@@ -1,6 +1,7 @@
 use tracing::debug;
 use rcd_messages::proxy::server_messages::{
-    AuthForTokenReply, AuthForTokenRequest, RegisterLoginReply, RegisterLoginRequest,
+    AuthForTokenReply, AuthForTokenRequest, RegisterLoginReply, RegisterLoginRequest, UsageReply,
+    UsageRequest,
 };
 use rocket::{http::Status, post, serde::json::Json, State};
 
@@ -89,3 +90,57 @@ pub async fn revoke_token(
 
     (Status::Ok, Json(response))
 }
+
+#[post("/account/usage", format = "application/json", data = "<request>")]
+pub async fn usage(
+    request: Json<UsageRequest>,
+    state: &State<RcdProxy>,
+) -> (Status, Json<UsageReply>) {
+    debug!("{request:?}");
+
+    let request = request.into_inner();
+
+    let login = match state.get_login_for_token(&request.jwt) {
+        Ok(Some(login)) => login,
+        Ok(None) => {
+            let response = UsageReply {
+                is_successful: false,
+                quota: None,
+                usage: None,
+                error: Some("token is not valid".to_string()),
+            };
+
+            return (Status::Ok, Json(response));
+        }
+        Err(e) => {
+            let response = UsageReply {
+                is_successful: false,
+                quota: None,
+                usage: None,
+                error: Some(e.to_string()),
+            };
+
+            return (Status::Ok, Json(response));
+        }
+    };
+
+    let result_quota = state.get_quota_for_login(&login);
+    let result_usage = state.get_usage_for_login(&login);
+
+    let response = match (result_quota, result_usage) {
+        (Ok(quota), Ok(usage)) => UsageReply {
+            is_successful: true,
+            quota: Some(quota),
+            usage: Some(usage),
+            error: None,
+        },
+        (Err(e), _) | (_, Err(e)) => UsageReply {
+            is_successful: false,
+            quota: None,
+            usage: None,
+            error: Some(e.to_string()),
+        },
+    };
+
+    (Status::Ok, Json(response))
+}
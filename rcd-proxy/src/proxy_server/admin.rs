@@ -0,0 +1,139 @@
+use rcd_enum::tenant_status::TenantStatus;
+use rcd_messages::proxy::server_messages::{
+    ChangeTenantStatusRequest, ExportTenantReply, TenantRequest, TenantStatusReply,
+};
+use rocket::{
+    http::Status,
+    post,
+    response::stream::TextStream,
+    serde::json::{self, Json},
+    State,
+};
+use tracing::debug;
+
+use crate::{tenant::TenantState, RcdProxy, RcdProxyErr};
+
+#[post(
+    "/admin/tenant/status",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn tenant_status(
+    request: Json<TenantRequest>,
+    state: &State<RcdProxy>,
+) -> (Status, Json<TenantStatusReply>) {
+    debug!("{request:?}");
+
+    let request = request.into_inner();
+    let result = state
+        .verify_admin_token(&request.jwt)
+        .and_then(|_| state.get_tenant_state(&request.login));
+
+    (Status::Ok, Json(status_reply(&request.login, result)))
+}
+
+#[post(
+    "/admin/tenant/status/change",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn change_tenant_status(
+    request: Json<ChangeTenantStatusRequest>,
+    state: &State<RcdProxy>,
+) -> (Status, Json<TenantStatusReply>) {
+    debug!("{request:?}");
+
+    let request = request.into_inner();
+    let result = state.verify_admin_token(&request.jwt).and_then(|_| {
+        if request.status > TenantStatus::to_u32(TenantStatus::PendingDelete) {
+            return Err(RcdProxyErr::InvalidTenantStatus(request.status));
+        }
+
+        state.change_tenant_status(&request.login, TenantStatus::from_u32(request.status))
+    });
+
+    (Status::Ok, Json(status_reply(&request.login, result)))
+}
+
+/// Sends the archive back as it is read, one JSON reply per line
+#[post(
+    "/admin/tenant/export",
+    format = "application/json",
+    data = "<request>"
+)]
+pub async fn export_tenant(
+    request: Json<TenantRequest>,
+    state: &State<RcdProxy>,
+) -> TextStream![String] {
+    debug!("{request:?}");
+
+    let request = request.into_inner();
+    let result = state
+        .verify_admin_token(&request.jwt)
+        .and_then(|_| state.export_tenant(&request.login));
+    let proxy = state.inner().clone();
+
+    TextStream! {
+        match result {
+            Ok((archive_path, archive_size_in_bytes)) => {
+                let reply = ExportTenantReply {
+                    is_successful: true,
+                    archive_name: archive_path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string()),
+                    archive_size_in_bytes,
+                    data: Vec::new(),
+                    error: None,
+                };
+                yield json::to_string(&reply).unwrap() + "\n";
+
+                let mut chunks = proxy.send_export(archive_path);
+                while let Some(chunk) = chunks.recv().await {
+                    let reply = match chunk {
+                        Ok(data) => ExportTenantReply {
+                            is_successful: true,
+                            archive_name: None,
+                            archive_size_in_bytes: 0,
+                            data,
+                            error: None,
+                        },
+                        Err(e) => export_failed_reply(e.to_string()),
+                    };
+                    yield json::to_string(&reply).unwrap() + "\n";
+                }
+            }
+            Err(e) => {
+                yield json::to_string(&export_failed_reply(e.to_string())).unwrap() + "\n";
+            }
+        }
+    }
+}
+
+fn export_failed_reply(error: String) -> ExportTenantReply {
+    ExportTenantReply {
+        is_successful: false,
+        archive_name: None,
+        archive_size_in_bytes: 0,
+        data: Vec::new(),
+        error: Some(error),
+    }
+}
+
+fn status_reply(login: &str, result: Result<TenantState, RcdProxyErr>) -> TenantStatusReply {
+    match result {
+        Ok(state) => TenantStatusReply {
+            is_successful: true,
+            login: login.to_string(),
+            status: TenantStatus::to_u32(state.status),
+            delete_after_utc: state.delete_after_utc.map(|x| x.to_rfc3339()),
+            error: None,
+        },
+        Err(e) => TenantStatusReply {
+            is_successful: false,
+            login: login.to_string(),
+            status: TenantStatus::to_u32(TenantStatus::Unknown),
+            delete_after_utc: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
@@ -1,8 +1,11 @@
 use tracing::debug;
-use rcd_messages::proxy::server_messages::{ExecuteReply, ExecuteRequest};
+use rcd_messages::proxy::{
+    request_type::RequestType,
+    server_messages::{ExecuteReply, ExecuteRequest},
+};
 use rocket::{http::Status, post, serde::json::Json, State};
 
-use crate::{proxy_server::process::process_request, RcdProxy, RcdProxyErr};
+use crate::{proxy_server::process::process_request, quota::QuotaAction, RcdProxy, RcdProxyErr};
 
 #[post("/execute", format = "application/json", data = "<request>")]
 pub async fn execute_request(
@@ -25,6 +28,7 @@ pub async fn execute_request(
                         login_success: false,
                         execute_success: false,
                         reply: None,
+                        quota_error: None,
                     };
 
                     return (Status::Ok, Json(response));
@@ -35,6 +39,7 @@ pub async fn execute_request(
                     login_success: false,
                     execute_success: false,
                     reply: None,
+                    quota_error: None,
                 };
 
                 return (Status::Ok, Json(response));
@@ -51,6 +56,7 @@ pub async fn execute_request(
                         login_success: false,
                         execute_success: false,
                         reply: None,
+                        quota_error: None,
                     };
 
                     return (Status::Ok, Json(response));
@@ -61,6 +67,7 @@ pub async fn execute_request(
                     login_success: false,
                     execute_success: false,
                     reply: None,
+                    quota_error: None,
                 };
 
                 return (Status::Ok, Json(response));
@@ -72,6 +79,7 @@ pub async fn execute_request(
         login_success: false,
         execute_success: false,
         reply: None,
+        quota_error: None,
     };
 
     if let Some(login) = request.login.clone() {
@@ -99,6 +107,15 @@ async fn execute(
     match result_id {
         Ok(id) => match id {
             Some(id) => {
+                if let Err(e) = proxy.check_quota(&id, quota_action(request)) {
+                    return ExecuteReply {
+                        login_success: true,
+                        execute_success: false,
+                        reply: Some(e.message.clone()),
+                        quota_error: Some(e),
+                    };
+                }
+
                 let result_core = proxy.get_rcd_core_for_existing_host(&id);
                 match result_core {
                     Ok(core) => {
@@ -108,11 +125,13 @@ async fn execute(
                                 login_success: true,
                                 execute_success: true,
                                 reply: Some(reply),
+                                quota_error: None,
                             },
                             Err(e) => ExecuteReply {
                                 login_success: true,
                                 execute_success: false,
                                 reply: Some(e.to_string()),
+                                quota_error: None,
                             },
                         }
                     }
@@ -120,6 +139,7 @@ async fn execute(
                         login_success: false,
                         execute_success: false,
                         reply: Some(e.to_string()),
+                        quota_error: None,
                     },
                 }
             }
@@ -127,12 +147,28 @@ async fn execute(
                 login_success: false,
                 execute_success: false,
                 reply: None,
+                quota_error: None,
             },
         },
         Err(e) => ExecuteReply {
             login_success: false,
             execute_success: false,
             reply: Some(e.to_string()),
+            quota_error: None,
         },
     }
 }
+
+/// what the request could do to the login's usage, used to decide which quotas it is checked against
+fn quota_action(request: &ExecuteRequest) -> QuotaAction {
+    match RequestType::try_from(request.request_type) {
+        Ok(RequestType::CreateUserDatabase) | Ok(RequestType::AcceptPendingContract) => {
+            QuotaAction::CreateDatabase
+        }
+        Ok(RequestType::WriteAtHost)
+        | Ok(RequestType::WriteAtPart)
+        | Ok(RequestType::CooperativeWriteAtHost)
+        | Ok(RequestType::AcceptPendingAction) => QuotaAction::Write,
+        _ => QuotaAction::Request,
+    }
+}
@@ -0,0 +1,35 @@
+use rcd_messages::proxy::server_messages::{CoreCacheMetricsReply, CoreCacheMetricsRequest};
+use rocket::{http::Status, post, serde::json::Json, State};
+use tracing::debug;
+
+use crate::RcdProxy;
+
+#[post("/metrics/core-cache", format = "application/json", data = "<request>")]
+pub async fn core_cache(
+    request: Json<CoreCacheMetricsRequest>,
+    state: &State<RcdProxy>,
+) -> (Status, Json<CoreCacheMetricsReply>) {
+    debug!("{request:?}");
+
+    let request = request.into_inner();
+
+    let response = match state.verify_admin_token(&request.jwt) {
+        Ok(_) => {
+            let metrics = state.core_cache_metrics();
+            debug!("{metrics:?}");
+
+            CoreCacheMetricsReply {
+                is_successful: true,
+                metrics: Some(metrics),
+                error: None,
+            }
+        }
+        Err(e) => CoreCacheMetricsReply {
+            is_successful: false,
+            metrics: None,
+            error: Some(e.to_string()),
+        },
+    };
+
+    (Status::Ok, Json(response))
+}
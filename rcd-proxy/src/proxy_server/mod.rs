@@ -11,13 +11,17 @@ use rocket::{
 use rocket::{Config, Shutdown};
 use rocket::{Request, Response};
 
-use crate::proxy_server::account::{register, revoke_token, token};
+use crate::proxy_server::account::{register, revoke_token, token, usage};
+use crate::proxy_server::admin::{change_tenant_status, export_tenant, tenant_status};
 use crate::proxy_server::execute::execute_request;
+use crate::proxy_server::metrics::core_cache;
 use crate::RcdProxy;
 
 mod account;
+mod admin;
 mod execute;
 pub mod http_endpoint;
+mod metrics;
 mod process;
 
 #[derive(Debug, Clone)]
@@ -51,7 +55,18 @@ impl ProxyServer {
             .attach(CORS)
             .mount(
                 "/",
-                routes![index, register, execute_request, token, revoke_token],
+                routes![
+                    index,
+                    register,
+                    execute_request,
+                    token,
+                    revoke_token,
+                    usage,
+                    core_cache,
+                    tenant_status,
+                    change_tenant_status,
+                    export_tenant
+                ],
             )
             .manage(proxy)
             .launch()
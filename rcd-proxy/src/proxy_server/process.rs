@@ -7,13 +7,16 @@ use rcdproto::rcdp::{
     ChangeHostStatusRequest, ChangeUpdatesFromHostBehaviorRequest,
     ChangeUpdatesToHostBehaviorRequest, CreateUserDatabaseRequest,
     EnableCoooperativeFeaturesRequest, ExecuteCooperativeWriteRequest, ExecuteReadRequest,
-    ExecuteWriteRequest, GenerateContractRequest, GenerateHostInfoRequest,
-    GetActiveContractRequest, GetCooperativeHostsRequest, GetDataHashRequest, GetDatabasesRequest,
-    GetDeletesFromHostBehaviorRequest, GetDeletesToHostBehaviorRequest,
-    GetLogicalStoragePolicyRequest, GetLogsByLastNumberRequest, GetParticipantsRequest,
-    GetPendingActionsRequest, GetReadRowIdsRequest, GetSettingsRequest,
+    ExecuteWriteRequest, ExportDatabaseRequest, GenerateContractRequest, GenerateHostInfoRequest,
+    GetActiveContractRequest, GetCooperativeHostsRequest, GetDataHashRequest,
+    GetDataLogTableStatusRequest, GetDatabasesRequest, GetDeletesFromHostBehaviorRequest,
+    GetDeletesToHostBehaviorRequest, GetLogicalStoragePolicyRequest, GetLogsByLastNumberRequest,
+    GetParticipantsRequest, GetPendingActionTtlRequest, GetPendingActionsRequest,
+    GetPendingWritesAtHostRequest, GetReadRowIdsRequest, GetSettingsRequest,
     GetUpdatesFromHostBehaviorRequest, GetUpdatesToHostBehaviorRequest, HasTableRequest,
-    SendParticipantContractRequest, SetLogicalStoragePolicyRequest, TryAuthAtParticipantRequest,
+    RehashPartialDatabaseRequest, RejectPendingActionRequest, RejectPendingContractRequest,
+    SendParticipantContractRequest, SetDataLogTableStatusRequest, SetLogicalStoragePolicyRequest,
+    SetPendingActionTtlRequest, TryAuthAtParticipantRequest, UnlockDatabaseRequest,
     ViewPendingContractsRequest,
 };
 
@@ -171,7 +174,15 @@ pub async fn process_request(request: &ExecuteRequest, core: &Rcd) -> Result<Str
                     }
                 }
                 RequestType::RejectPendingContract => {
-                    todo!()
+                    let result_request =
+                        serde_json::from_str::<RejectPendingContractRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.reject_pending_contract(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
                 }
                 RequestType::GenerateHostInfo => {
                     let result_request =
@@ -277,9 +288,29 @@ pub async fn process_request(request: &ExecuteRequest, core: &Rcd) -> Result<Str
                     }
                 }
                 RequestType::GetDataLogTableStatus => {
-                    todo!()
+                    let result_request =
+                        serde_json::from_str::<GetDataLogTableStatusRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply =
+                                core.get_data_log_table_status_at_participant(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::SetDataLogTableStatus => {
+                    let result_request =
+                        serde_json::from_str::<SetDataLogTableStatusRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply =
+                                core.set_data_log_table_status_at_participant(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
                 }
-                RequestType::SetDataLogTableStatus => todo!(),
                 RequestType::GetPendingActions => {
                     let result_request =
                         serde_json::from_str::<GetPendingActionsRequest>(&request.request_json);
@@ -461,6 +492,91 @@ pub async fn process_request(request: &ExecuteRequest, core: &Rcd) -> Result<Str
                         Err(e) => return Err(e.to_string()),
                     }
                 }
+                RequestType::RehashPartialDatabase => {
+                    let result_request =
+                        serde_json::from_str::<RehashPartialDatabaseRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.rehash_partial_database(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::UnlockDatabase => {
+                    let result_request =
+                        serde_json::from_str::<UnlockDatabaseRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.unlock_database(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::ExportDatabase => {
+                    let result_request =
+                        serde_json::from_str::<ExportDatabaseRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            // this relay answers with a single message, so the chunks are
+                            // gathered up here rather than streamed
+                            let mut receiver = core.export_database(request).await;
+                            let mut replies = Vec::new();
+                            while let Some(reply) = receiver.recv().await {
+                                replies.push(reply);
+                            }
+
+                            return Ok(serde_json::to_string(&replies).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::GetPendingWritesAtHost => {
+                    let result_request = serde_json::from_str::<GetPendingWritesAtHostRequest>(
+                        &request.request_json,
+                    );
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.get_pending_writes_at_host(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::RejectPendingAction => {
+                    let result_request =
+                        serde_json::from_str::<RejectPendingActionRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.reject_pending_action_at_participant(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::GetPendingActionTtl => {
+                    let result_request =
+                        serde_json::from_str::<GetPendingActionTtlRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.get_pending_action_ttl_at_participant(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
+                RequestType::SetPendingActionTtl => {
+                    let result_request =
+                        serde_json::from_str::<SetPendingActionTtlRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.set_pending_action_ttl_at_participant(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
                 RequestType::ViewHostInfo => {
                     let result_request = serde_json::from_str::<AuthRequest>(&request.request_json);
                     match result_request {
@@ -471,6 +587,16 @@ pub async fn process_request(request: &ExecuteRequest, core: &Rcd) -> Result<Str
                         Err(e) => return Err(e.to_string()),
                     }
                 }
+                RequestType::GetVersions => {
+                    let result_request = serde_json::from_str::<AuthRequest>(&request.request_json);
+                    match result_request {
+                        Ok(request) => {
+                            let reply = core.get_versions(request).await;
+                            return Ok(serde_json::to_string(&reply).unwrap());
+                        }
+                        Err(e) => return Err(e.to_string()),
+                    }
+                }
             }
         }
         Err(e) => Err(e.to_string()),
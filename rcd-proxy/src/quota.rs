@@ -0,0 +1,355 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::RcdProxyErr;
+use rcd_messages::proxy::server_messages::{QuotaError, QuotaKind, TenantQuota, TenantUsage};
+use stdext::function_name;
+use tracing::{trace, warn};
+
+/// What a request is about to do, which decides the quotas it is checked against
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuotaAction {
+    /// counts against the login's requests per second only
+    Request,
+    /// may grow the login's storage or rows
+    Write,
+    /// may add a database to the login's folder
+    CreateDatabase,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Allows each host up to its quota of requests per second, with a burst of up to one second's
+/// worth. Clones share the same buckets.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// takes a request from the host's bucket, returning false if it has none left
+    pub fn try_acquire(&self, host_id: &str, max_requests_per_second: u32) -> bool {
+        let max = max_requests_per_second as f64;
+        let mut buckets = self.buckets.lock().unwrap();
+
+        let bucket = buckets
+            .entry(host_id.to_string())
+            .or_insert_with(|| TokenBucket {
+                tokens: max,
+                last_refill: Instant::now(),
+            });
+
+        let elapsed = bucket.last_refill.elapsed().as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * max).min(max);
+        bucket.last_refill = Instant::now();
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// What a database file looked like when its rows were counted: the change counter from its
+/// header, which a write outside of WAL mode bumps, and the size and last modified time of the
+/// file and of its write-ahead log
+#[derive(Debug, PartialEq)]
+struct FileStamp {
+    change_counter: Option<[u8; 4]>,
+    files: [Option<(u64, SystemTime)>; 2],
+}
+
+#[derive(Debug)]
+struct RowCount {
+    stamp: FileStamp,
+    rows: u64,
+}
+
+#[derive(Debug)]
+struct MeasuredUsage {
+    usage: TenantUsage,
+    measured_at: Instant,
+}
+
+/// Remembers what each login's folder was using, so that the folder is only walked again once
+/// the last measure is older than the max age, and the number of rows in each database, so that
+/// only the databases that have been written to since they were last counted are counted again.
+/// A login can go over its storage and row quotas by what it writes within the max age. Clones
+/// share the same measures.
+#[derive(Debug, Clone)]
+pub struct UsageCache {
+    max_age: Duration,
+    usages: Arc<Mutex<HashMap<PathBuf, MeasuredUsage>>>,
+    row_counts: Arc<Mutex<HashMap<PathBuf, RowCount>>>,
+}
+
+impl UsageCache {
+    pub fn new(max_age_in_seconds: u64) -> Self {
+        Self {
+            max_age: Duration::from_secs(max_age_in_seconds),
+            usages: Arc::new(Mutex::new(HashMap::new())),
+            row_counts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Measures what the login's folder is using. Every Sqlite file other than the rcd instance's
+    /// own backing database counts as a database, and `count_rows` is asked for the rows of each
+    /// database at the top of the folder. A database whose rows can't be counted, such as one
+    /// that is still locked, counts against the login's storage only.
+    pub fn get_usage<F, E>(
+        &self,
+        folder: &str,
+        backing_database_name: &str,
+        count_rows: F,
+    ) -> Result<TenantUsage, RcdProxyErr>
+    where
+        F: Fn(&str) -> Result<u64, E>,
+        E: Display,
+    {
+        let dir = Path::new(folder);
+
+        if let Some(measured) = self.usages.lock().unwrap().get(dir) {
+            if measured.measured_at.elapsed() < self.max_age {
+                return Ok(measured.usage.clone());
+            }
+        }
+
+        let mut usage = TenantUsage::default();
+        self.add_usage_for_dir(dir, dir, backing_database_name, &count_rows, &mut usage)?;
+        trace!("[{}]: {folder:?} {usage:?}", function_name!());
+
+        self.usages.lock().unwrap().insert(
+            dir.to_path_buf(),
+            MeasuredUsage {
+                usage: usage.clone(),
+                measured_at: Instant::now(),
+            },
+        );
+
+        Ok(usage)
+    }
+
+    /// Drops the measure of the folder, so that the next call measures it again
+    pub fn invalidate(&self, folder: &str) {
+        self.usages.lock().unwrap().remove(Path::new(folder));
+    }
+
+    fn add_usage_for_dir<F, E>(
+        &self,
+        root: &Path,
+        dir: &Path,
+        backing_database_name: &str,
+        count_rows: &F,
+        usage: &mut TenantUsage,
+    ) -> Result<(), RcdProxyErr>
+    where
+        F: Fn(&str) -> Result<u64, E>,
+        E: Display,
+    {
+        let entries = fs::read_dir(dir)
+            .map_err(|e| RcdProxyErr::UsageUnavailable(format!("{dir:?}: {e}")))?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.is_dir() {
+                self.add_usage_for_dir(root, &path, backing_database_name, count_rows, usage)?;
+                continue;
+            }
+
+            if let Ok(metadata) = entry.metadata() {
+                usage.storage_bytes += metadata.len();
+            }
+
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let is_database = file_name.ends_with(".db") || file_name.ends_with(".dbpart");
+
+            if is_database && file_name != backing_database_name {
+                usage.databases += 1;
+
+                // the rcd instance only knows the databases at the top of its folder
+                if dir == root {
+                    usage.rows += self.get_row_count(&path, &file_name, count_rows);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_row_count<F, E>(&self, path: &Path, db_name: &str, count_rows: &F) -> u64
+    where
+        F: Fn(&str) -> Result<u64, E>,
+        E: Display,
+    {
+        let stamp = get_file_stamp(path);
+
+        if let Some(count) = self.row_counts.lock().unwrap().get(path) {
+            if count.stamp == stamp {
+                return count.rows;
+            }
+        }
+
+        match count_rows(db_name) {
+            Ok(rows) => {
+                self.row_counts
+                    .lock()
+                    .unwrap()
+                    .insert(path.to_path_buf(), RowCount { stamp, rows });
+                rows
+            }
+            Err(e) => {
+                warn!(
+                    "[{}]: unable to count rows of {path:?}, counting its storage only: {e}",
+                    function_name!()
+                );
+                0
+            }
+        }
+    }
+}
+
+fn get_file_stamp(path: &Path) -> FileStamp {
+    let mut wal_path = path.as_os_str().to_owned();
+    wal_path.push("-wal");
+
+    let change_counter = fs::File::open(path)
+        .and_then(|mut file| {
+            let mut counter = [0; 4];
+            file.seek(SeekFrom::Start(24))?;
+            file.read_exact(&mut counter)?;
+            Ok(counter)
+        })
+        .ok();
+
+    let files = [path, Path::new(&wal_path)].map(|path| {
+        fs::metadata(path)
+            .and_then(|metadata| Ok((metadata.len(), metadata.modified()?)))
+            .ok()
+    });
+
+    FileStamp {
+        change_counter,
+        files,
+    }
+}
+
+/// Checks the usage against the quota for what the request is about to do. Usage that is at or
+/// over a limit refuses anything that could add to it.
+pub fn check_usage(
+    login: &str,
+    quota: &TenantQuota,
+    usage: &TenantUsage,
+    action: QuotaAction,
+) -> Result<(), QuotaError> {
+    if action == QuotaAction::Request {
+        return Ok(());
+    }
+
+    if action == QuotaAction::CreateDatabase {
+        if let Some(limit) = quota.max_databases {
+            if usage.databases >= limit {
+                return Err(quota_error(
+                    login,
+                    QuotaKind::Databases,
+                    limit,
+                    usage.databases,
+                ));
+            }
+        }
+    }
+
+    if let Some(limit) = quota.max_storage_bytes {
+        if usage.storage_bytes >= limit {
+            return Err(quota_error(
+                login,
+                QuotaKind::StorageBytes,
+                limit,
+                usage.storage_bytes,
+            ));
+        }
+    }
+
+    if let Some(limit) = quota.max_rows {
+        if usage.rows >= limit {
+            return Err(quota_error(login, QuotaKind::Rows, limit, usage.rows));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn quota_error(login: &str, kind: QuotaKind, limit: u64, usage: u64) -> QuotaError {
+    QuotaError {
+        login: login.to_string(),
+        kind,
+        limit,
+        usage,
+        message: format!("login '{login}' is at its {kind:?} quota: {usage} of {limit}"),
+    }
+}
+
+/// The error for a login whose quota or usage can't be read, which can't be shown to be under
+/// its quota
+pub fn unmeasured_error(login: &str, e: &RcdProxyErr) -> QuotaError {
+    QuotaError {
+        login: login.to_string(),
+        kind: QuotaKind::Unmeasured,
+        limit: 0,
+        usage: 0,
+        message: format!("quota or usage of login '{login}' could not be read: {e}"),
+    }
+}
+
+#[test]
+fn test_rate_limiter_refuses_past_limit() {
+    let limiter = RateLimiter::default();
+
+    assert!(limiter.try_acquire("host", 2));
+    assert!(limiter.try_acquire("host", 2));
+    assert!(!limiter.try_acquire("host", 2));
+
+    // each host has its own bucket
+    assert!(limiter.try_acquire("other", 2));
+}
+
+#[test]
+fn test_check_usage() {
+    let quota = TenantQuota {
+        max_databases: Some(1),
+        max_storage_bytes: None,
+        max_rows: Some(10),
+        max_requests_per_second: None,
+    };
+
+    let usage = TenantUsage {
+        databases: 1,
+        storage_bytes: 4096,
+        rows: 5,
+    };
+
+    assert!(check_usage("test", &quota, &usage, QuotaAction::Request).is_ok());
+    assert!(check_usage("test", &quota, &usage, QuotaAction::Write).is_ok());
+
+    let error = check_usage("test", &quota, &usage, QuotaAction::CreateDatabase).unwrap_err();
+    assert_eq!(error.kind, QuotaKind::Databases);
+    assert_eq!(error.limit, 1);
+    assert_eq!(error.usage, 1);
+
+    let usage = TenantUsage { rows: 10, ..usage };
+    let error = check_usage("test", &quota, &usage, QuotaAction::Write).unwrap_err();
+    assert_eq!(error.kind, QuotaKind::Rows);
+}
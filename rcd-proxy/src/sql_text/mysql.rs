@@ -0,0 +1,201 @@
+pub const MYSQL_CREATE_LOGIN_TABLE: &str = "CREATE TABLE IF NOT EXISTS LOGIN
+(
+  username VARCHAR(50) not null,
+  token BLOB not null,
+  folder VARCHAR(256),
+  host_id VARCHAR(256)
+)
+;";
+
+pub const MYSQL_CREATE_TOKENS_TABLE: &str = "CREATE TABLE IF NOT EXISTS TOKENS
+(
+    username VARCHAR(50) NOT NULL,
+    token TEXT NOT NULL,
+    issued_utc VARCHAR(50),
+    expiration_utc VARCHAR(50)
+);";
+
+/// 1. username
+/// 2. hash
+pub const ADD_LOGIN: &str = "INSERT INTO LOGIN
+(
+  username,
+  token
+)
+VALUES
+(
+  ?,
+  ?
+)
+;";
+
+/// 1. username
+/// 2. token
+/// 3. issued_utc
+/// 4. expiration_utc
+pub const ADD_TOKEN: &str = "INSERT INTO TOKENS
+(
+    username,
+    token,
+    issued_utc,
+    expiration_utc
+)
+VALUES
+(
+    ?,
+    ?,
+    ?,
+    ?
+)
+;";
+
+/// 1. host_id
+pub const GET_HOST: &str = "
+SELECT
+    username,
+    token,
+    folder,
+    host_id
+FROM
+    LOGIN
+WHERE
+    host_id = ?
+;
+";
+
+/// 1. username
+pub const GET_USER: &str = "
+SELECT
+    username,
+    token,
+    folder,
+    host_id
+FROM
+    LOGIN
+WHERE
+    username = ?
+;
+";
+
+/// 1. folder
+/// 2. hash
+/// 3. host_id
+/// 4. username
+pub const UPDATE_USER: &str = "
+    UPDATE LOGIN
+    SET
+        folder = ?,
+        token = ?,
+        host_id = ?
+    WHERE
+        username = ?
+;
+";
+
+pub const MYSQL_CREATE_QUOTAS_TABLE: &str = "CREATE TABLE IF NOT EXISTS QUOTAS
+(
+    username VARCHAR(50) NOT NULL,
+    max_databases BIGINT,
+    max_storage_bytes BIGINT,
+    max_rows BIGINT,
+    max_requests_per_second INT
+);";
+
+/// 1. username
+pub const GET_QUOTA: &str = "
+SELECT
+    max_databases,
+    max_storage_bytes,
+    max_rows,
+    max_requests_per_second
+FROM
+    QUOTAS
+WHERE
+    username = ?
+;
+";
+
+/// 1. username
+pub const DELETE_QUOTA: &str = "DELETE FROM QUOTAS WHERE username = ?;";
+
+/// 1. username
+/// 2. max_databases
+/// 3. max_storage_bytes
+/// 4. max_rows
+/// 5. max_requests_per_second
+pub const ADD_QUOTA: &str = "INSERT INTO QUOTAS
+(
+    username,
+    max_databases,
+    max_storage_bytes,
+    max_rows,
+    max_requests_per_second
+)
+VALUES
+(
+    ?,
+    ?,
+    ?,
+    ?,
+    ?
+)
+;";
+
+/// 1. username
+pub const DELETE_LOGIN: &str = "DELETE FROM LOGIN WHERE username = ?;";
+
+pub const MYSQL_CREATE_TENANT_STATUS_TABLE: &str = "CREATE TABLE IF NOT EXISTS TENANT_STATUS
+(
+    username VARCHAR(50) NOT NULL,
+    status INT NOT NULL,
+    changed_utc VARCHAR(50),
+    delete_after_utc VARCHAR(50)
+);";
+
+/// 1. username
+pub const GET_TENANT_STATUS: &str = "
+SELECT
+    status,
+    delete_after_utc
+FROM
+    TENANT_STATUS
+WHERE
+    username = ?
+;
+";
+
+/// 1. status
+/// 2. now
+pub const GET_TENANTS_TO_DELETE: &str = "
+SELECT
+    username
+FROM
+    TENANT_STATUS
+WHERE
+    status = ?
+    AND delete_after_utc < ?
+;
+";
+
+/// 1. username
+pub const DELETE_TENANT_STATUS: &str = "DELETE FROM TENANT_STATUS WHERE username = ?;";
+
+/// 1. username
+/// 2. status
+/// 3. changed_utc
+/// 4. delete_after_utc
+pub const ADD_TENANT_STATUS: &str = "INSERT INTO TENANT_STATUS
+(
+    username,
+    status,
+    changed_utc,
+    delete_after_utc
+)
+VALUES
+(
+    ?,
+    ?,
+    ?,
+    ?
+)
+;";
@@ -71,3 +71,95 @@ pub const UPDATE_USER: &str = "
         username = :un
 ;
 ";
+
+pub const SQLITE_CREATE_QUOTAS_TABLE: &str = "CREATE TABLE IF NOT EXISTS QUOTAS
+(
+    username VARCHAR(50) NOT NULL,
+    max_databases INT,
+    max_storage_bytes INT,
+    max_rows INT,
+    max_requests_per_second INT
+);";
+
+pub const GET_QUOTA: &str = "
+SELECT
+    max_databases,
+    max_storage_bytes,
+    max_rows,
+    max_requests_per_second
+FROM
+    QUOTAS
+WHERE
+    username = :un
+;
+";
+
+pub const DELETE_QUOTA: &str = "DELETE FROM QUOTAS WHERE username = :un;";
+
+pub const ADD_QUOTA: &str = "INSERT INTO QUOTAS
+(
+    username,
+    max_databases,
+    max_storage_bytes,
+    max_rows,
+    max_requests_per_second
+)
+VALUES
+(
+    :un,
+    :max_databases,
+    :max_storage_bytes,
+    :max_rows,
+    :max_requests_per_second
+)
+;";
+
+pub const DELETE_LOGIN: &str = "DELETE FROM LOGIN WHERE username = :un;";
+
+pub const SQLITE_CREATE_TENANT_STATUS_TABLE: &str = "CREATE TABLE IF NOT EXISTS TENANT_STATUS
+(
+    username VARCHAR(50) NOT NULL,
+    status INT NOT NULL,
+    changed_utc DATETIME,
+    delete_after_utc DATETIME
+);";
+
+pub const GET_TENANT_STATUS: &str = "
+SELECT
+    status,
+    delete_after_utc
+FROM
+    TENANT_STATUS
+WHERE
+    username = :un
+;
+";
+
+pub const GET_TENANTS_TO_DELETE: &str = "
+SELECT
+    username
+FROM
+    TENANT_STATUS
+WHERE
+    status = :status
+    AND delete_after_utc < :now
+;
+";
+
+pub const DELETE_TENANT_STATUS: &str = "DELETE FROM TENANT_STATUS WHERE username = :un;";
+
+pub const ADD_TENANT_STATUS: &str = "INSERT INTO TENANT_STATUS
+(
+    username,
+    status,
+    changed_utc,
+    delete_after_utc
+)
+VALUES
+(
+    :un,
+    :status,
+    :changed,
+    :delete_after
+)
+;";
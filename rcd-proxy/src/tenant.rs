@@ -0,0 +1,270 @@
+use std::{
+    collections::HashSet,
+    ffi::{OsStr, OsString},
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use rcd_common::defaults;
+use rcd_enum::tenant_status::TenantStatus;
+use rusqlite::{Connection, OpenFlags};
+use stdext::function_name;
+use tokio::{
+    io::AsyncReadExt,
+    sync::mpsc::{self, Receiver, Sender},
+};
+use tracing::{trace, warn};
+
+/// The databases in an account's folder, which are snapshotted when the account is exported
+const DATABASE_EXTENSIONS: [&str; 2] = ["db", "dbpart"];
+/// The files sqlite keeps alongside a database
+const DATABASE_FILE_SUFFIXES: [&str; 3] = ["-wal", "-shm", "-journal"];
+/// Where the snapshots of an account's databases are staged while it is archived, next to
+/// the archive
+const SNAPSHOT_EXTENSION: &str = "snapshot";
+
+/// The lifecycle of an account at the proxy. Accounts without a saved state are `Active`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantState {
+    pub status: TenantStatus,
+    // when a `PendingDelete` account is to be removed
+    pub delete_after_utc: Option<DateTime<Utc>>,
+}
+
+impl TenantState {
+    pub fn active() -> Self {
+        Self {
+            status: TenantStatus::Active,
+            delete_after_utc: None,
+        }
+    }
+
+    pub fn parse_utc(value: Option<String>) -> Option<DateTime<Utc>> {
+        value
+            .and_then(|value| DateTime::parse_from_rfc3339(&value).ok())
+            .map(|value| value.with_timezone(&Utc))
+    }
+}
+
+/// How many chunks of an archive can be waiting to be sent before we stop reading the next one
+const ARCHIVE_BUFFERED_CHUNKS: usize = 4;
+
+/// Writes the account's folder to a tar archive at `archive_path`, returning the size of the
+/// archive. The databases in the folder may be in use, so each one is snapshotted with
+/// `VACUUM INTO` and the snapshot is archived in its place, without its `-wal`, `-shm` or
+/// `-journal` files. A database that can't be opened (e.g. one that is encrypted) is copied
+/// as it is.
+pub fn write_archive(folder: &str, archive_path: &Path) -> io::Result<u64> {
+    trace!("[{}]: {folder:?} to {archive_path:?}", function_name!());
+
+    if let Some(dir) = archive_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let snapshot_dir = archive_path.with_extension(SNAPSHOT_EXTENSION);
+    if snapshot_dir.exists() {
+        fs::remove_dir_all(&snapshot_dir)?;
+    }
+    fs::create_dir_all(&snapshot_dir)?;
+
+    let result = append_folder_to_archive(folder, archive_path, &snapshot_dir);
+    fs::remove_dir_all(&snapshot_dir)?;
+    result?;
+
+    Ok(fs::metadata(archive_path)?.len())
+}
+
+fn append_folder_to_archive(
+    folder: &str,
+    archive_path: &Path,
+    snapshot_dir: &Path,
+) -> io::Result<()> {
+    let file = File::create(archive_path)?;
+    let mut archive = tar::Builder::new(file);
+    let mut snapshot_count = 0;
+
+    archive.append_dir(".", folder)?;
+    append_dir(
+        &mut archive,
+        Path::new(folder),
+        Path::new("."),
+        snapshot_dir,
+        &mut snapshot_count,
+    )?;
+
+    archive.into_inner()?.sync_all()
+}
+
+fn append_dir(
+    archive: &mut tar::Builder<File>,
+    dir: &Path,
+    name: &Path,
+    snapshot_dir: &Path,
+    snapshot_count: &mut usize,
+) -> io::Result<()> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<PathBuf>>>()?;
+    paths.sort();
+
+    // the databases go first, so we know which of them were snapshotted when we get to
+    // the files sqlite keeps alongside them
+    let (databases, others): (Vec<PathBuf>, Vec<PathBuf>) =
+        paths.into_iter().partition(|path| is_database(path));
+    let mut snapshotted = HashSet::new();
+
+    for path in databases {
+        let file_name = path.file_name().unwrap().to_os_string();
+        *snapshot_count += 1;
+        let snapshot = snapshot_dir.join(snapshot_count.to_string());
+
+        match snapshot_database(&path, &snapshot) {
+            Ok(()) => {
+                archive.append_path_with_name(&snapshot, name.join(&file_name))?;
+                snapshotted.insert(file_name);
+            }
+            Err(e) => {
+                warn!(
+                    "[{}]: unable to snapshot {path:?}, copying it as it is: {e}",
+                    function_name!()
+                );
+                archive.append_path_with_name(&path, name.join(&file_name))?;
+            }
+        }
+    }
+
+    for path in others {
+        let file_name = path.file_name().unwrap().to_os_string();
+
+        if path.is_dir() {
+            archive.append_dir(name.join(&file_name), &path)?;
+            append_dir(
+                archive,
+                &path,
+                &name.join(&file_name),
+                snapshot_dir,
+                snapshot_count,
+            )?;
+        } else if !is_snapshotted_database_file(&file_name, &snapshotted) {
+            archive.append_path_with_name(&path, name.join(&file_name))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_database(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| DATABASE_EXTENSIONS.contains(&extension))
+            .unwrap_or(false)
+}
+
+/// if the file is one sqlite keeps alongside a database that was snapshotted; the
+/// snapshot already holds what was in it
+fn is_snapshotted_database_file(file_name: &OsStr, snapshotted: &HashSet<OsString>) -> bool {
+    let file_name = file_name.to_string_lossy();
+
+    DATABASE_FILE_SUFFIXES.iter().any(|suffix| {
+        file_name
+            .strip_suffix(suffix)
+            .map(|database| snapshotted.contains(OsStr::new(database)))
+            .unwrap_or(false)
+    })
+}
+
+/// Writes a consistent copy of the database to `snapshot`, including anything that is still
+/// only in its write-ahead log
+fn snapshot_database(path: &Path, snapshot: &Path) -> rusqlite::Result<()> {
+    // without SQLITE_OPEN_CREATE, so that we never leave an empty database behind
+    let conn = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+
+    conn.execute("VACUUM INTO ?1", [snapshot.to_string_lossy()])?;
+    Ok(())
+}
+
+/// Sends the archive a chunk at a time as the receiver takes them, removing it once it has
+/// been sent or the receiver has gone away. If reading the archive fails the last item is
+/// the error.
+pub fn send_archive(archive_path: PathBuf) -> Receiver<io::Result<Vec<u8>>> {
+    let (tx, rx) = mpsc::channel(ARCHIVE_BUFFERED_CHUNKS);
+
+    tokio::spawn(async move {
+        if let Err(e) = send_chunks(&archive_path, &tx).await {
+            let _ = tx.send(Err(e)).await;
+        }
+
+        if let Err(e) = tokio::fs::remove_file(&archive_path).await {
+            warn!(
+                "[{}]: unable to remove {archive_path:?}: {e}",
+                function_name!()
+            );
+        }
+    });
+
+    rx
+}
+
+async fn send_chunks(archive_path: &Path, tx: &Sender<io::Result<Vec<u8>>>) -> io::Result<()> {
+    let mut file = tokio::fs::File::open(archive_path).await?;
+
+    loop {
+        let mut chunk = Vec::with_capacity(defaults::EXPORT_CHUNK_SIZE_IN_BYTES);
+        (&mut file)
+            .take(defaults::EXPORT_CHUNK_SIZE_IN_BYTES as u64)
+            .read_to_end(&mut chunk)
+            .await?;
+
+        // a tar archive is never empty, so the last chunk is the one that comes up short
+        let is_last_chunk = chunk.len() < defaults::EXPORT_CHUNK_SIZE_IN_BYTES;
+
+        if !chunk.is_empty() && tx.send(Ok(chunk)).await.is_err() {
+            return Ok(());
+        }
+
+        if is_last_chunk {
+            return Ok(());
+        }
+    }
+}
+
+/// Removes the archives (and any snapshots left behind by an export that didn't finish) in
+/// `exports_dir` that are older than `max_age`, returning what was removed. An archive is
+/// removed once it has been sent, so these are the ones that never were.
+pub fn remove_stale_exports(exports_dir: &Path, max_age: Duration) -> io::Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+
+    if !exports_dir.exists() {
+        return Ok(removed);
+    }
+
+    for entry in fs::read_dir(exports_dir)? {
+        let path = entry?.path();
+        let age = fs::metadata(&path)?
+            .modified()?
+            .elapsed()
+            .unwrap_or_default();
+
+        if age < max_age {
+            continue;
+        }
+
+        if path.is_dir() {
+            fs::remove_dir_all(&path)?;
+        } else {
+            fs::remove_file(&path)?;
+        }
+
+        removed.push(path);
+    }
+
+    Ok(removed)
+}
@@ -1,13 +1,18 @@
 use rcd_enum::{database_type::DatabaseType, dml_type::DmlType};
 pub mod sqlite;
 
+// the DML that rcd needs to inspect (table names, INSERT values, statement type)
+// is plain ANSI SQL, so Postgres and MySQL statements are parsed with the SQLite grammar.
+// The SQLite grammar already accepts MySQL's backtick quoted identifiers; Postgres statements
+// first have their `::type` casts removed, since those are the one piece of Postgres syntax
+// in ordinary DML that the SQLite grammar rejects
+
 /// Takes a SQL statement and returns a list of tables involved in that SQL statement
 pub fn get_table_names(cmd: &str, db_type: DatabaseType) -> Vec<String> {
     match db_type {
         DatabaseType::Unknown => todo!(),
-        DatabaseType::Sqlite => sqlite::get_table_names(cmd),
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
+        DatabaseType::Sqlite | DatabaseType::Mysql => sqlite::get_table_names(cmd),
+        DatabaseType::Postgres => sqlite::get_table_names(&remove_postgres_casts(cmd)),
         DatabaseType::Sqlserver => todo!(),
     }
 }
@@ -18,9 +23,12 @@ pub fn get_values_from_insert_statement(
 ) -> Vec<String> {
     match db_type {
         DatabaseType::Unknown => todo!(),
-        DatabaseType::Sqlite => sqlite::get_values_from_insert_statement(insert_statement),
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
+        DatabaseType::Sqlite | DatabaseType::Mysql => {
+            sqlite::get_values_from_insert_statement(insert_statement)
+        }
+        DatabaseType::Postgres => {
+            sqlite::get_values_from_insert_statement(&remove_postgres_casts(insert_statement))
+        }
         DatabaseType::Sqlserver => todo!(),
     }
 }
@@ -28,19 +36,92 @@ pub fn get_values_from_insert_statement(
 pub fn get_table_name(cmd: &str, db_type: DatabaseType) -> String {
     match db_type {
         DatabaseType::Unknown => todo!(),
-        DatabaseType::Sqlite => sqlite::get_table_name(cmd),
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
+        DatabaseType::Sqlite | DatabaseType::Mysql => sqlite::get_table_name(cmd),
+        DatabaseType::Postgres => sqlite::get_table_name(&remove_postgres_casts(cmd)),
         DatabaseType::Sqlserver => todo!(),
     }
 }
 
 pub fn determine_dml_type(cmd: &str, db_type: DatabaseType) -> DmlType {
     match db_type {
-        DatabaseType::Mysql => unimplemented!(),
         DatabaseType::Unknown => panic!(),
-        DatabaseType::Sqlite => sqlite::determine_statement_type(cmd.to_string()),
-        DatabaseType::Postgres => unimplemented!(),
+        DatabaseType::Sqlite | DatabaseType::Mysql => {
+            sqlite::determine_statement_type(cmd.to_string())
+        }
+        DatabaseType::Postgres => sqlite::determine_statement_type(remove_postgres_casts(cmd)),
         DatabaseType::Sqlserver => unimplemented!(),
     }
 }
+
+/// Removes every `::type` cast outside of quoted strings and identifiers, including any
+/// `(precision)` or `[]` that follows the type name, e.g. `'1'::numeric(10, 2)` becomes `'1'`
+pub fn remove_postgres_casts(cmd: &str) -> String {
+    let chars: Vec<char> = cmd.chars().collect();
+    let mut result = String::with_capacity(cmd.len());
+    let mut quote: Option<char> = None;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if let Some(q) = quote {
+            result.push(c);
+            if c == q {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+
+        if c == '\'' || c == '"' {
+            quote = Some(c);
+            result.push(c);
+            i += 1;
+            continue;
+        }
+
+        if c == ':' && chars.get(i + 1) == Some(&':') {
+            i += 2;
+
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+
+            if chars.get(i) == Some(&'(') {
+                while i < chars.len() && chars[i] != ')' {
+                    i += 1;
+                }
+                i += 1;
+            }
+
+            while chars.get(i) == Some(&'[') && chars.get(i + 1) == Some(&']') {
+                i += 2;
+            }
+
+            continue;
+        }
+
+        result.push(c);
+        i += 1;
+    }
+
+    result
+}
+
+#[test]
+fn test_remove_postgres_casts() {
+    assert_eq!(
+        remove_postgres_casts("SELECT id::text FROM employees WHERE hired > '2023-01-01'::date"),
+        "SELECT id FROM employees WHERE hired > '2023-01-01'"
+    );
+    assert_eq!(
+        remove_postgres_casts("INSERT INTO t (a, b) VALUES ('1.5'::numeric(10, 2), '{}'::int[])"),
+        "INSERT INTO t (a, b) VALUES ('1.5', '{}')"
+    );
+
+    // a :: inside a string literal is data, not a cast
+    assert_eq!(
+        remove_postgres_casts("SELECT * FROM t WHERE name = 'a::b'"),
+        "SELECT * FROM t WHERE name = 'a::b'"
+    );
+}
@@ -1,13 +1,24 @@
 use self::db_part::get_partial_db_connection;
-use rcd_common::{db::DbiConfigSqlite, defaults, table::*};
+use guid_create::GUID;
+use rcd_common::{
+    db::{is_reserved_table_name, DbiConfigSqlite},
+    defaults,
+    table::*,
+};
 use rcd_enum::column_type::ColumnType;
 use rcd_error::rcd_db_error::RcdDbError;
 use rcd_sqlite_log::{log_entry::LogEntry, SqliteLog};
 use rcdproto::rcdp::{ColumnSchema, RowValue};
-use rusqlite::{types::Type, Connection, Result};
-use std::path::Path;
+use rusqlite::{
+    params_from_iter,
+    types::{Type, Value as SqlValue},
+    Connection, OpenFlags, Result,
+};
+use std::path::{Path, PathBuf};
 use tracing::{debug, error, info, trace, warn};
+pub mod backend;
 pub mod db;
+pub mod db_key;
 pub mod db_part;
 pub mod rcd_db;
 mod sql_text;
@@ -174,9 +185,15 @@ pub fn execute_read_on_connection_for_row(
                 _ => String::from(""),
             };
 
+            // a blob has no string form, so its bytes are sent as they are
+            let value = match dt {
+                Type::Blob => row.get::<_, Vec<u8>>(i)?,
+                _ => string_value.as_bytes().to_vec(),
+            };
+
             let mut row_value = RowValue {
                 column: None,
-                is_null_value: false,
+                is_null_value: dt == Type::Null,
                 value: Vec::new(),
                 string_value: String::from(""),
             };
@@ -189,7 +206,7 @@ pub fn execute_read_on_connection_for_row(
 
             let col = column.last().unwrap().clone();
             row_value.column = Some(col);
-            row_value.value = string_value.as_bytes().to_vec();
+            row_value.value = value;
             row_value.string_value = string_value.clone();
             values.push(row_value);
         }
@@ -239,6 +256,15 @@ pub fn execute_read_on_connection_for_row(
 }
 
 pub fn execute_read(cmd: &str, conn: &Connection) -> Result<Table, RcdDbError> {
+    execute_read_with_params(cmd, &[], conn)
+}
+
+/// Runs the statement with `params` bound to its `?N` placeholders
+fn execute_read_with_params(
+    cmd: &str,
+    params: &[SqlValue],
+    conn: &Connection,
+) -> Result<Table, RcdDbError> {
     let mut statement = conn.prepare(cmd)?;
     let total_columns = statement.column_count();
     let cols = statement.columns();
@@ -270,7 +296,7 @@ pub fn execute_read(cmd: &str, conn: &Connection) -> Result<Table, RcdDbError> {
         table.add_column(c);
     }
 
-    let mut rows = statement.query([])?;
+    let mut rows = statement.query(params_from_iter(params))?;
 
     while let Some(row) = rows.next()? {
         let mut data_row = rcd_common::table::Row::new();
@@ -286,12 +312,16 @@ pub fn execute_read(cmd: &str, conn: &Connection) -> Result<Table, RcdDbError> {
                 _ => String::from(""),
             };
 
-            let string_value = string_value;
+            let data_byte = match dt {
+                Type::Blob => row.get::<_, Vec<u8>>(i)?,
+                _ => Vec::new(),
+            };
+
             let col = table.get_column_by_index(i).unwrap();
 
             let data_item = Data {
                 data_string: string_value,
-                data_byte: Vec::new(),
+                data_byte,
             };
 
             let data_value = Value {
@@ -334,6 +364,119 @@ pub fn execute_read_at_host(
     execute_read(cmd, &conn)
 }
 
+/// Copies the rows we got from participants into temp tables named after each cooperative table.
+/// Sqlite looks in the temp schema before main, so `cmd` can run as written and read those rows
+/// instead of the host's copy of the table.
+pub fn execute_read_at_host_with_cooperative_rows(
+    db_name: &str,
+    cmd: &str,
+    cooperative_tables: &[String],
+    cooperative_rows: &[rcdproto::rcdp::Row],
+    config: DbiConfigSqlite,
+) -> core::result::Result<Table, RcdDbError> {
+    if !has_database(&config, db_name) {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let conn = get_db_conn(&config, db_name);
+
+    for table_name in cooperative_tables {
+        let create_temp_table =
+            format!("CREATE TEMP TABLE {table_name} AS SELECT * FROM main.{table_name} WHERE 0;");
+        conn.execute(&create_temp_table, [])?;
+
+        let add_row_key = format!(
+            "ALTER TABLE temp.{table_name} ADD COLUMN {table_name}{} INT;",
+            defaults::ROW_KEY_COLUMN_SUFFIX
+        );
+        conn.execute(&add_row_key, [])?;
+    }
+
+    for (row_key, row) in cooperative_rows.iter().enumerate() {
+        let (insert_row, params) = get_insert_for_cooperative_row(row, row_key)?;
+        trace!("[{}]: {insert_row:?}", function_name!());
+        conn.execute(&insert_row, params_from_iter(params))?;
+    }
+
+    execute_read(cmd, &conn)
+}
+
+/// Builds an `INSERT` of a participant's row into the temp table of the same name, along with
+/// the row's values to bind to it
+fn get_insert_for_cooperative_row(
+    row: &rcdproto::rcdp::Row,
+    row_key: usize,
+) -> core::result::Result<(String, Vec<SqlValue>), RcdDbError> {
+    let (mut col_names, mut params) = get_row_params(row)?;
+
+    col_names.push(format!(
+        "{}{}",
+        row.table_name,
+        defaults::ROW_KEY_COLUMN_SUFFIX
+    ));
+    params.push(SqlValue::Integer(row_key as i64));
+
+    let cmd = get_insert_with_params(&format!("temp.{}", row.table_name), &col_names);
+
+    Ok((cmd, params))
+}
+
+/// Returns the column names of a row along with its values to bind to them. Each value is bound
+/// as its column's type, so that a blob is stored as its bytes and a number compares as a number.
+fn get_row_params(
+    row: &rcdproto::rcdp::Row,
+) -> core::result::Result<(Vec<String>, Vec<SqlValue>), RcdDbError> {
+    let mut col_names: Vec<String> = Vec::new();
+    let mut params: Vec<SqlValue> = Vec::new();
+
+    for value in &row.values {
+        let column = value.column.as_ref().ok_or_else(|| {
+            RcdDbError::General(format!("a value of {} has no column", row.table_name))
+        })?;
+
+        col_names.push(column.column_name.clone());
+        params.push(get_typed_value(column, value));
+    }
+
+    Ok((col_names, params))
+}
+
+/// Builds an `INSERT` into `table_name` with a `?N` placeholder for each column
+fn get_insert_with_params(table_name: &str, col_names: &[String]) -> String {
+    let placeholders: Vec<String> = (1..=col_names.len()).map(|i| format!("?{i}")).collect();
+
+    format!(
+        "INSERT INTO {table_name} ({}) VALUES ({});",
+        col_names.join(", "),
+        placeholders.join(", ")
+    )
+}
+
+/// Returns the value as the type of its column, falling back to its text when it doesn't parse
+/// as that type
+fn get_typed_value(column: &ColumnSchema, value: &RowValue) -> SqlValue {
+    if value.is_null_value {
+        return SqlValue::Null;
+    }
+
+    let text = || SqlValue::Text(value.string_value.clone());
+
+    match ColumnType::from_u32(column.column_type) {
+        ColumnType::Int | ColumnType::Bit => value
+            .string_value
+            .parse()
+            .map(SqlValue::Integer)
+            .unwrap_or_else(|_| text()),
+        ColumnType::Decimal => value
+            .string_value
+            .parse()
+            .map(SqlValue::Real)
+            .unwrap_or_else(|_| text()),
+        ColumnType::Binary | ColumnType::Varbinary => SqlValue::Blob(value.value.clone()),
+        _ => text(),
+    }
+}
+
 /// Runs any SQL statement that returns a single vlaue and attempts
 /// to return the result as a u32
 fn get_scalar_as_string(cmd: String, conn: &Connection) -> String {
@@ -358,6 +501,33 @@ fn has_table(table_name: &str, conn: &Connection) -> bool {
     has_any_rows(cmd, conn)
 }
 
+fn has_column(table_name: &str, column_name: &str, conn: &Connection) -> bool {
+    let mut cmd = String::from(
+        "SELECT count(*) AS COLUMNCOUNT FROM pragma_table_info(':table_name') WHERE name = ':column_name'",
+    );
+    cmd = cmd.replace(":table_name", table_name);
+    cmd = cmd.replace(":column_name", column_name);
+    has_any_rows(cmd, conn)
+}
+
+/// Adds a column to a table created by an earlier version of rcd
+fn add_column_if_missing(
+    table_name: &str,
+    column_name: &str,
+    column_type: &str,
+    conn: &Connection,
+) {
+    if !has_column(table_name, column_name, conn) {
+        info!(
+            "[{}]: adding {column_name} to {table_name}",
+            function_name!()
+        );
+
+        let cmd = format!("ALTER TABLE {table_name} ADD COLUMN {column_name} {column_type};");
+        conn.execute(&cmd, []).unwrap();
+    }
+}
+
 #[instrument]
 pub fn has_database(config: &DbiConfigSqlite, db_name: &str) -> bool {
     let mut db_exists_as_regular_db = false;
@@ -392,12 +562,79 @@ pub fn has_database(config: &DbiConfigSqlite, db_name: &str) -> bool {
 pub fn get_db_conn(config: &DbiConfigSqlite, db_name: &str) -> Connection {
     let db_path = Path::new(&config.root_folder).join(db_name);
     trace!("[{}]: {db_path:?}", function_name!());
-    Connection::open(db_path).unwrap()
+    let conn = Connection::open(&db_path).unwrap();
+    db_key::apply_key(&db_path, &conn);
+    conn
 }
 
 pub fn get_db_conn_with_result(config: &DbiConfigSqlite, db_name: &str) -> Result<Connection> {
     let db_path = Path::new(&config.root_folder).join(db_name);
-    Connection::open(db_path)
+    let conn = Connection::open(&db_path)?;
+    db_key::apply_key(&db_path, &conn);
+    Ok(conn)
+}
+
+/// Copies a host or partial database with `VACUUM INTO`, which gives a consistent copy even
+/// while the database is being written to, and returns the path of the copy. An encrypted
+/// database is copied with the same key, so its key is still needed to open the copy.
+pub fn snapshot_database(
+    db_name: &str,
+    config: &DbiConfigSqlite,
+) -> core::result::Result<PathBuf, RcdDbError> {
+    let file_name = match db_key::get_db_file_name(db_name, config) {
+        Some(file_name) => file_name,
+        None => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let db_path = Path::new(&config.root_folder).join(&file_name);
+    trace!("[{}]: {db_path:?}", function_name!());
+
+    if !db_path.exists() {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let snapshot_path =
+        Path::new(&config.root_folder).join(format!("{}.{}.snapshot", file_name, GUID::rand()));
+
+    let conn = Connection::open_with_flags(
+        &db_path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    db_key::apply_key(&db_path, &conn);
+
+    conn.execute("VACUUM INTO ?1", [snapshot_path.to_string_lossy()])?;
+
+    Ok(snapshot_path)
+}
+
+/// Counts the rows of a host or partial database's own tables, leaving out the tables rcd keeps
+/// for itself. An encrypted database is read with its key, so it can't be counted while locked.
+pub fn count_user_rows(
+    db_name: &str,
+    config: &DbiConfigSqlite,
+) -> core::result::Result<u64, RcdDbError> {
+    db_key::check_database_key(db_name, config)?;
+
+    let file_name = match db_key::get_db_file_name(db_name, config) {
+        Some(file_name) => file_name,
+        None => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let conn = get_db_conn_with_result(config, &file_name)?;
+
+    let tables: Vec<String> = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table'")?
+        .query_map([], |row| row.get(0))?
+        .collect::<Result<_>>()?;
+
+    let mut rows = 0;
+
+    for table in tables.iter().filter(|table| !is_reserved_table_name(table)) {
+        let cmd = format!("SELECT COUNT(*) FROM [{table}]");
+        rows += conn.query_row(&cmd, [], |row| row.get::<_, i64>(0))? as u64;
+    }
+
+    Ok(rows)
 }
 
 pub fn execute_write_on_connection_at_host(
@@ -423,16 +660,113 @@ pub fn execute_write_on_connection_at_host(
     }
 }
 
+/// Deletes every row of the host's table and inserts `rows` in their place, in a single
+/// transaction
+pub fn replace_rows_at_host(
+    db_name: &str,
+    table_name: &str,
+    rows: &[rcdproto::rcdp::Row],
+    config: &DbiConfigSqlite,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name) {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(config, db_name);
+    let transaction = conn.transaction()?;
+    transaction.execute(&format!("DELETE FROM {table_name};"), [])?;
+
+    let mut total_rows = 0;
+
+    for row in rows {
+        let (col_names, params) = get_row_params(row)?;
+        let cmd = get_insert_with_params(table_name, &col_names);
+        trace!("[{}]: {cmd:?}", function_name!());
+        total_rows += transaction.execute(&cmd, params_from_iter(params))?;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
+/// Reads the rows of the host's table whose key columns hold one of `keys`. Each key has a value
+/// for each key column, which is bound as the type of its column.
+pub fn get_rows_at_host_by_key(
+    db_name: &str,
+    table_name: &str,
+    keys: &[Vec<RowValue>],
+    config: &DbiConfigSqlite,
+) -> Result<Table, RcdDbError> {
+    if !has_database(config, db_name) {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut predicates: Vec<String> = Vec::new();
+    let mut params: Vec<SqlValue> = Vec::new();
+
+    for key in keys {
+        let mut terms: Vec<String> = Vec::new();
+
+        for value in key {
+            let column = value.column.as_ref().ok_or_else(|| {
+                RcdDbError::General(format!("a key of {table_name} has no column"))
+            })?;
+
+            params.push(get_typed_value(column, value));
+            terms.push(format!("{} = ?{}", column.column_name, params.len()));
+        }
+
+        predicates.push(format!("({})", terms.join(" AND ")));
+    }
+
+    if predicates.is_empty() {
+        return Ok(Table::new());
+    }
+
+    let cmd = format!(
+        "SELECT * FROM {table_name} WHERE {}",
+        predicates.join(" OR ")
+    );
+
+    let conn = get_db_conn(config, db_name);
+    execute_read_with_params(&cmd, &params, &conn)
+}
+
+/// Runs the statements in a single transaction, which is rolled back if any of them fail
+pub fn execute_writes_on_connection_at_host(
+    db_name: &str,
+    cmds: &[String],
+    config: &DbiConfigSqlite,
+) -> Result<usize, RcdDbError> {
+    if !has_database(config, db_name) {
+        return Err(RcdDbError::DbNotFound(db_name.to_string()));
+    }
+
+    let mut conn = get_db_conn(config, db_name);
+    let transaction = conn.transaction()?;
+    let mut total_rows = 0;
+
+    for cmd in cmds {
+        trace!("[{}]: {cmd:?}", function_name!());
+        total_rows += transaction.execute(cmd, [])?;
+    }
+
+    transaction.commit()?;
+
+    Ok(total_rows)
+}
+
 pub fn execute_write_on_connection_at_participant(
     db_name: &str,
     cmd: &str,
     config: &DbiConfigSqlite,
-) -> usize {
+) -> Result<usize, RcdDbError> {
     let conn = get_partial_db_connection(db_name, &config.root_folder);
-    let result = conn.execute(cmd, []).unwrap();
+    let result = conn.execute(cmd, [])?;
     let _ = conn.close();
 
-    result
+    Ok(result)
 }
 
 pub fn get_table_col_names_with_data_type_as_string(
@@ -21,6 +21,7 @@ pub mod contract;
 pub mod logical_storage_policy;
 pub mod metadata;
 pub mod participant;
+pub mod pending_write;
 
 pub fn create_database(db_name: &str, config: DbiConfigSqlite) -> Result<Connection, Error> {
     Ok(get_db_conn(&config, db_name))
@@ -50,10 +51,7 @@ pub fn has_cooperative_tables(
 
         if let Ok(policy) = result {
             match policy {
-                LogicalStoragePolicy::Mirror => {
-                    has_cooperative_tables = true;
-                    break;
-                }
+                // a mirrored table is read from the host's own copy
                 LogicalStoragePolicy::ParticpantOwned => {
                     has_cooperative_tables = true;
                     break;
@@ -86,9 +84,7 @@ pub fn get_cooperative_tables(db_name: &str, cmd: &str, config: DbiConfigSqlite)
 
         if let Ok(policy) = result {
             match policy {
-                LogicalStoragePolicy::Mirror => {
-                    cooperative_tables.push(table.clone());
-                }
+                // a mirrored table is read from the host's own copy
                 LogicalStoragePolicy::ParticpantOwned => {
                     cooperative_tables.push(table.clone());
                 }
@@ -401,9 +397,10 @@ pub fn get_db_schema(db_name: &str, config: DbiConfigSqlite) -> DatabaseSchema {
                         cs.is_nullable = item.data_string.parse().unwrap();
                     }
 
-                    if val.col.name == "IsPK" {
+                    if val.col.name == "pk" {
                         let item = val.data.clone().unwrap();
-                        cs.is_primary_key = item.data_string.parse().unwrap();
+                        // the position of the column in the key, or 0 if it isn't part of it
+                        cs.is_primary_key = item.data_string != "0";
                     }
                 }
 
@@ -485,9 +482,10 @@ pub fn get_db_schema(db_name: &str, config: DbiConfigSqlite) -> DatabaseSchema {
                             cs.is_nullable = item.data_string.parse().unwrap();
                         }
 
-                        if val.col.name == "IsPK" {
+                        if val.col.name == "pk" {
                             let item = val.data.clone().unwrap();
-                            cs.is_primary_key = item.data_string.parse().unwrap();
+                            // the position of the column in the key, or 0 if it isn't part of it
+                            cs.is_primary_key = item.data_string != "0";
                         }
                     }
 
@@ -582,9 +580,10 @@ pub fn get_db_schema(db_name: &str, config: DbiConfigSqlite) -> DatabaseSchema {
                     cs.is_nullable = item.data_string.parse().unwrap();
                 }
 
-                if val.col.name == "IsPK" {
+                if val.col.name == "pk" {
                     let item = val.data.clone().unwrap();
-                    cs.is_primary_key = item.data_string.parse().unwrap();
+                    // the position of the column in the key, or 0 if it isn't part of it
+                    cs.is_primary_key = item.data_string != "0";
                 }
             }
 
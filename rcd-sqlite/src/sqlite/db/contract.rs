@@ -386,6 +386,37 @@ pub fn update_participant_accepts_contract(
     rows_affected > 0
 }
 
+/// Records that the participant rejected the contract we sent it
+pub fn update_participant_rejects_contract(
+    db_name: &str,
+    participant: CoopDatabaseParticipant,
+    config: DbiConfigSqlite,
+) -> bool {
+    let conn = get_db_conn(&config, db_name);
+
+    let cmd = String::from(
+        "
+    UPDATE 
+        COOP_PARTICIPANT
+    SET 
+        CONTRACT_STATUS = 4
+    WHERE 
+        INTERNAL_PARTICIPANT_ID = :iid
+    ;
+    ",
+    );
+
+    let mut statement = conn.prepare(&cmd).unwrap();
+
+    let rows_affected = statement
+        .execute(named_params! {
+            ":iid" : participant.internal_id.to_string(),
+        })
+        .unwrap();
+
+    rows_affected > 0
+}
+
 pub fn get_active_contract(db_name: &str, config: DbiConfigSqlite) -> CoopDatabaseContract {
     let conn = &get_db_conn(&config, db_name);
 
@@ -1,5 +1,6 @@
 use crate::sqlite::{
-    execute_write_on_connection_at_host, get_db_conn, get_scalar_as_u32, has_any_rows, has_table,
+    execute_read, execute_write, execute_write_on_connection_at_host, get_db_conn,
+    get_scalar_as_u32, has_any_rows, has_table, sql_text,
 };
 use rcd_common::db::DbiConfigSqlite;
 use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
@@ -137,3 +138,74 @@ pub fn get_logical_storage_policy(
 
     Ok(policy)
 }
+
+/// Replaces the columns of a `Shared` table whose values are kept at the participant. An empty
+/// list means the host keeps every column of the table.
+pub fn set_participant_owned_columns(
+    db_name: &str,
+    table_name: &str,
+    column_names: &[String],
+    config: &DbiConfigSqlite,
+) -> Result<bool, RcdDbError> {
+    let conn = get_db_conn(config, db_name);
+
+    if !has_table(table_name, &conn) {
+        let err = RcdDbError::TableNotFoundInDatabase(table_name.to_string(), db_name.to_string());
+        return Err(err);
+    }
+
+    execute_write(&conn, &sql_text::Coop::text_create_shared_columns_table());
+
+    let mut cmd = String::from("DELETE FROM COOP_SHARED_COLUMNS WHERE TABLENAME = ':table_name';");
+    cmd = cmd.replace(":table_name", table_name);
+    execute_write_on_connection_at_host(db_name, &cmd, config)?;
+
+    for column_name in column_names {
+        let mut cmd = String::from(
+            "INSERT INTO COOP_SHARED_COLUMNS
+            (
+                TABLENAME,
+                COLUMNNAME
+            )
+            VALUES
+            (
+                ':table_name',
+                ':column_name'
+            );",
+        );
+
+        cmd = cmd.replace(":table_name", table_name);
+        cmd = cmd.replace(":column_name", column_name);
+        execute_write_on_connection_at_host(db_name, &cmd, config)?;
+    }
+
+    Ok(true)
+}
+
+/// Returns the columns of a `Shared` table whose values are kept at the participant
+pub fn get_participant_owned_columns(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigSqlite,
+) -> Result<Vec<String>, RcdDbError> {
+    let conn = get_db_conn(config, db_name);
+    let mut column_names: Vec<String> = Vec::new();
+
+    if !has_table("COOP_SHARED_COLUMNS", &conn) {
+        return Ok(column_names);
+    }
+
+    let mut cmd =
+        String::from("SELECT COLUMNNAME FROM COOP_SHARED_COLUMNS WHERE TABLENAME = ':table_name';");
+    cmd = cmd.replace(":table_name", table_name);
+
+    let columns = execute_read(&cmd, &conn)?;
+
+    for row in columns.rows {
+        for val in row.vals {
+            column_names.push(val.data.unwrap().data_string);
+        }
+    }
+
+    Ok(column_names)
+}
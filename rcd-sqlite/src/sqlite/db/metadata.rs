@@ -1,8 +1,12 @@
-use crate::sqlite::{execute_write, get_db_conn, get_scalar_as_u64, has_table, sql_text};
+use crate::sqlite::{
+    add_column_if_missing, db_part::get_row_hashes, execute_write, get_db_conn, get_scalar_as_u64,
+    has_table, sql_text,
+};
 use stdext::function_name;
 use tracing::trace;
 use rcd_common::db::{get_metadata_table_name, DbiConfigSqlite};
-use rusqlite::named_params;
+use rcd_error::rcd_db_error::RcdDbError;
+use rusqlite::{named_params, Connection};
 
 pub fn get_data_hash_at_host(
     db_name: &str,
@@ -19,6 +23,24 @@ pub fn get_data_hash_at_host(
     get_scalar_as_u64(cmd, &conn).unwrap()
 }
 
+/// Hashes each of the rows in the host's own copy of the table
+pub fn get_row_hashes_at_host(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigSqlite,
+) -> Result<Vec<u64>, RcdDbError> {
+    let conn = get_db_conn(config, db_name);
+
+    if !has_table(table_name, &conn) {
+        return Err(RcdDbError::TableNotFoundInDatabase(
+            table_name.to_string(),
+            db_name.to_string(),
+        ));
+    }
+
+    Ok(get_row_hashes(table_name, &conn))
+}
+
 pub fn remove_remote_row_reference_from_host(
     db_name: &str,
     table_name: &str,
@@ -47,6 +69,19 @@ pub fn remove_remote_row_reference_from_host(
     rows > 0
 }
 
+/// Creates the metadata table for the specified table if it does not already exist, or adds
+/// the columns that a metadata table created by an earlier version of rcd is missing
+pub fn create_metadata_table_if_not_exists(metadata_table_name: &str, conn: &Connection) {
+    if !has_table(metadata_table_name, conn) {
+        let mut cmd = sql_text::Coop::text_create_metadata_table();
+        cmd = cmd.replace(":table_name", metadata_table_name);
+        execute_write(conn, &cmd);
+    } else {
+        add_column_if_missing(metadata_table_name, "IS_OUT_OF_SYNC", "INT", conn);
+        add_column_if_missing(metadata_table_name, "IS_REMOTE_DELETED", "INT", conn);
+    }
+}
+
 pub fn insert_metadata_into_host_db(
     db_name: &str,
     table_name: &str,
@@ -58,12 +93,7 @@ pub fn insert_metadata_into_host_db(
     let conn = get_db_conn(&config, db_name);
     let metadata_table_name = get_metadata_table_name(table_name);
 
-    if !has_table(&metadata_table_name, &conn) {
-        //  need to create table
-        let mut cmd = sql_text::Coop::text_create_metadata_table();
-        cmd = cmd.replace(":table_name", &metadata_table_name);
-        execute_write(&conn, &cmd);
-    }
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
 
     let mut cmd = sql_text::Coop::text_insert_row_metadata_table();
     cmd = cmd.replace(":table_name", &metadata_table_name);
@@ -76,22 +106,18 @@ pub fn insert_metadata_into_host_db(
     rows > 0
 }
 
+/// Removes the metadata for each of the rows, returning if every one of them was removed
 pub fn delete_metadata_in_host_db(
     db_name: &str,
     table_name: &str,
-    row_id: u32,
+    row_ids: &[u32],
     internal_participant_id: &str,
     config: DbiConfigSqlite,
 ) -> bool {
     let conn = get_db_conn(&config, db_name);
     let metadata_table_name = get_metadata_table_name(table_name);
 
-    if !has_table(&metadata_table_name, &conn) {
-        //  need to create table
-        let mut cmd = sql_text::Coop::text_create_metadata_table();
-        cmd = cmd.replace(":table_name", &metadata_table_name);
-        execute_write(&conn, &cmd);
-    }
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
 
     let mut cmd = sql_text::Coop::text_delete_row_metadata_table();
     cmd = cmd.replace(":table_name", &metadata_table_name);
@@ -99,40 +125,149 @@ pub fn delete_metadata_in_host_db(
 
     trace!("[{}]: statement: {statement:?}", function_name!());
 
-    let rows = statement
-        .execute(named_params! {":row": row_id, ":pid" : internal_participant_id })
-        .unwrap();
+    let mut rows = 0;
+    let mut has_every_row = true;
+
+    for row_id in row_ids {
+        let affected_rows = statement
+            .execute(named_params! {":row": row_id, ":pid" : internal_participant_id })
+            .unwrap();
+        rows += affected_rows;
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
 
     trace!("[{}]: rows affected: {rows:?}", function_name!());
-    
-    rows > 0
+
+    has_every_row
 }
 
+/// Saves the new hash for each of the `(row_id, hash)` rows, returning if every one of them
+/// was updated
 pub fn update_metadata_in_host_db(
     db_name: &str,
     table_name: &str,
-    row_id: u32,
-    hash: u64,
+    rows: &[(u32, u64)],
     internal_participant_id: &str,
     config: DbiConfigSqlite,
 ) -> bool {
     let conn = get_db_conn(&config, db_name);
     let metadata_table_name = get_metadata_table_name(table_name);
 
-    if !has_table(&metadata_table_name, &conn) {
-        //  need to create table
-        let mut cmd = sql_text::Coop::text_create_metadata_table();
-        cmd = cmd.replace(":table_name", &metadata_table_name);
-        execute_write(&conn, &cmd);
-    }
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
 
     let mut cmd = sql_text::Coop::text_update_row_metadata_table();
     cmd = cmd.replace(":table_name", &metadata_table_name);
     let mut statement = conn.prepare(&cmd).unwrap();
+    let mut has_every_row = true;
 
-    let rows = statement
-        .execute(named_params! {":row": row_id, ":hash" : hash.to_ne_bytes(), ":pid" : internal_participant_id })
+    for (row_id, hash) in rows {
+        let affected_rows = statement
+            .execute(named_params! {":row": row_id, ":hash" : hash.to_ne_bytes(), ":pid" : internal_participant_id })
+            .unwrap();
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
+
+    has_every_row
+}
+
+/// Flags each of the rows as no longer matching what the participant holds, returning if every
+/// one of them was flagged
+pub fn mark_metadata_out_of_sync_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_ids: &[u32],
+    internal_participant_id: &str,
+    config: DbiConfigSqlite,
+) -> bool {
+    let conn = get_db_conn(&config, db_name);
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
+
+    let mut cmd = sql_text::Coop::text_mark_row_metadata_out_of_sync();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+    let mut statement = conn.prepare(&cmd).unwrap();
+    let mut total_rows = 0;
+    let mut has_every_row = true;
+
+    for row_id in row_ids {
+        let affected_rows = statement
+            .execute(named_params! {":row": row_id, ":pid" : internal_participant_id })
+            .unwrap();
+        total_rows += affected_rows;
+
+        // a row can have more than one reference, so each row is checked on its own
+        if affected_rows == 0 {
+            has_every_row = false;
+        }
+    }
+
+    trace!("[{}]: rows affected: {total_rows:?}", function_name!());
+
+    has_every_row
+}
+
+/// Keeps the row's metadata but flags it as deleted at the participant
+pub fn mark_metadata_remote_deleted_in_host_db(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    internal_participant_id: &str,
+    config: DbiConfigSqlite,
+) -> bool {
+    let conn = get_db_conn(&config, db_name);
+    let metadata_table_name = get_metadata_table_name(table_name);
+
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
+
+    let mut cmd = sql_text::Coop::text_mark_row_metadata_remote_deleted();
+    cmd = cmd.replace(":table_name", &metadata_table_name);
+    let mut statement = conn.prepare(&cmd).unwrap();
+
+    let total_rows = statement
+        .execute(named_params! {":row": row_id, ":pid" : internal_participant_id })
         .unwrap();
 
-    rows > 0
+    trace!("[{}]: rows affected: {total_rows:?}", function_name!());
+
+    total_rows > 0
+}
+
+#[test]
+fn test_create_metadata_table_adds_missing_columns() {
+    use crate::sqlite::has_column;
+
+    let conn = Connection::open_in_memory().unwrap();
+
+    // a metadata table as it was before rows could be flagged out of sync or deleted remotely
+    conn.execute(
+        "CREATE TABLE EMPLOYEE_COOP_METADATA
+        (
+            ROW_ID INT,
+            HASH BLOB,
+            INTERNAL_PARTICIPANT_ID CHAR(36)
+        );",
+        [],
+    )
+    .unwrap();
+
+    create_metadata_table_if_not_exists("EMPLOYEE_COOP_METADATA", &conn);
+    assert!(has_column(
+        "EMPLOYEE_COOP_METADATA",
+        "IS_OUT_OF_SYNC",
+        &conn
+    ));
+    assert!(has_column(
+        "EMPLOYEE_COOP_METADATA",
+        "IS_REMOTE_DELETED",
+        &conn
+    ));
 }
@@ -1,6 +1,9 @@
 use guid_create::GUID;
 use rcd_common::{
-    coop_database_participant::{CoopDatabaseParticipant, CoopDatabaseParticipantData},
+    coop_database_participant::{
+        CoopDatabaseParticipant, CoopDatabaseParticipantData, CoopDatabaseParticipantRow,
+        NewCoopDatabaseParticipant,
+    },
     db::{get_metadata_table_name, DbiConfigSqlite},
     defaults,
 };
@@ -9,7 +12,8 @@ use rcd_error::rcd_db_error::RcdDbError;
 use rcdproto::rcdp::{Participant, ParticipantStatus};
 use rusqlite::{named_params, Connection, Result};
 
-use crate::sqlite::{execute_write, get_db_conn, has_any_rows, has_table, sql_text};
+use super::metadata::create_metadata_table_if_not_exists;
+use crate::sqlite::{add_column_if_missing, get_db_conn, has_any_rows, has_table};
 
 /// Creates the COOP_PARTICIPANT table if it does not exist. This holds
 /// the participant information that are cooperating with this database.
@@ -27,13 +31,31 @@ pub fn create_participant_table(conn: &Connection) {
         TOKEN BLOB NOT NULL,
         PARTICIPANT_ID CHAR(36),
         HTTP_ADDR VARCHAR(50),
-        HTTP_PORT INT
+        HTTP_PORT INT,
+        PROTOCOL_VERSION INT DEFAULT 0
     );",
     );
 
     conn.execute(&cmd, []).unwrap();
 }
 
+/// Returns a connection to the host database, with COOP_PARTICIPANT brought up to the current
+/// layout if it was created by an earlier version of rcd
+fn get_participant_conn(config: &DbiConfigSqlite, db_name: &str) -> Connection {
+    let conn = get_db_conn(config, db_name);
+
+    if has_table("COOP_PARTICIPANT", &conn) {
+        add_column_if_missing(
+            "COOP_PARTICIPANT",
+            "PROTOCOL_VERSION",
+            "INT DEFAULT 0",
+            &conn,
+        );
+    }
+
+    conn
+}
+
 pub fn save_participant(participant: CoopDatabaseParticipant, conn: Connection) {
     if has_participant_at_conn(&participant.alias, &conn) {
         // this is an update
@@ -130,28 +152,32 @@ pub fn save_participant(participant: CoopDatabaseParticipant, conn: Connection)
 
 pub fn add_participant(
     db_name: &str,
-    alias: &str,
-    ip4addr: &str,
-    db_port: u32,
+    participant: NewCoopDatabaseParticipant,
     config: DbiConfigSqlite,
-    http_addr: String,
-    http_port: u16,
-    id: Option<String>,
 ) -> bool {
     let conn = get_db_conn(&config, db_name);
 
+    let NewCoopDatabaseParticipant {
+        alias,
+        ip4addr,
+        db_port,
+        http_addr,
+        http_port,
+        id,
+    } = participant;
+
     let db_host_id = match id {
         Some(id) => GUID::parse(&id).unwrap(),
         None => GUID::parse(defaults::EMPTY_GUID).unwrap(),
     };
 
-    let is_added: bool = if has_participant(db_name, alias, config) {
+    let is_added: bool = if has_participant(db_name, &alias, config) {
         false
     } else {
         let participant = CoopDatabaseParticipant {
             internal_id: GUID::rand(),
-            alias: alias.to_string(),
-            ip4addr: ip4addr.to_string(),
+            alias,
+            ip4addr,
             ip6addr: String::from(""),
             db_port,
             contract_status: ContractStatus::NotSent,
@@ -160,6 +186,7 @@ pub fn add_participant(
             token: Vec::new(),
             http_addr,
             http_port,
+            protocol_version: 0,
         };
         save_participant(participant, conn);
         true
@@ -173,7 +200,7 @@ pub fn get_participant_by_internal_id(
     internal_id: &str,
     config: &DbiConfigSqlite,
 ) -> CoopDatabaseParticipant {
-    let conn = get_db_conn(config, db_name);
+    let conn = get_participant_conn(config, db_name);
     let cmd = String::from(
         "
         SELECT 
@@ -187,7 +214,8 @@ pub fn get_participant_by_internal_id(
             TOKEN,
             PARTICIPANT_ID,
             HTTP_ADDR,
-            HTTP_PORT
+            HTTP_PORT,
+            PROTOCOL_VERSION
         FROM
             COOP_PARTICIPANT
         WHERE
@@ -207,7 +235,8 @@ pub fn get_participant_by_internal_id(
                               token: Vec<u8>,
                               id: String,
                               http_addr: String,
-                              http_port: u16|
+                              http_port: u16,
+                              protocol_version: u32|
      -> Result<CoopDatabaseParticipant> {
         let participant = CoopDatabaseParticipant {
             internal_id: GUID::parse(&internal_id).unwrap(),
@@ -221,6 +250,7 @@ pub fn get_participant_by_internal_id(
             id: GUID::parse(&id).unwrap(),
             http_addr,
             http_port,
+            protocol_version,
         };
 
         Ok(participant)
@@ -243,6 +273,7 @@ pub fn get_participant_by_internal_id(
                 row.get(8).unwrap(),
                 row.get(9).unwrap(),
                 row.get(10).unwrap(),
+                row.get(11).unwrap(),
             )
         })
         .unwrap();
@@ -259,7 +290,7 @@ pub fn get_participant_by_id(
     id: &str,
     config: DbiConfigSqlite,
 ) -> Option<CoopDatabaseParticipant> {
-    let conn = get_db_conn(&config, db_name);
+    let conn = get_participant_conn(&config, db_name);
     let cmd = String::from(
         "
         SELECT 
@@ -273,7 +304,8 @@ pub fn get_participant_by_id(
             TOKEN,
             PARTICIPANT_ID,
             HTTP_ADDR,
-            HTTP_PORT
+            HTTP_PORT,
+            PROTOCOL_VERSION
         FROM
             COOP_PARTICIPANT
         WHERE
@@ -296,7 +328,8 @@ pub fn get_participant_by_id(
                               token: Vec<u8>,
                               id: String,
                               http_addr: String,
-                              http_port: u16|
+                              http_port: u16,
+                              protocol_version: u32|
      -> Result<CoopDatabaseParticipant> {
         let participant = CoopDatabaseParticipant {
             internal_id: GUID::parse(&internal_id).unwrap(),
@@ -310,6 +343,7 @@ pub fn get_participant_by_id(
             id: GUID::parse(&id).unwrap(),
             http_addr,
             http_port,
+            protocol_version,
         };
 
         Ok(participant)
@@ -332,6 +366,7 @@ pub fn get_participant_by_id(
                 row.get(8).unwrap(),
                 row.get(9).unwrap(),
                 row.get(10).unwrap(),
+                row.get(11).unwrap(),
             )
         })
         .unwrap();
@@ -352,7 +387,7 @@ pub fn get_participant_by_alias(
     alias: &str,
     config: DbiConfigSqlite,
 ) -> Option<CoopDatabaseParticipant> {
-    let conn = get_db_conn(&config, db_name);
+    let conn = get_participant_conn(&config, db_name);
     let cmd = String::from(
         "
         SELECT 
@@ -366,7 +401,8 @@ pub fn get_participant_by_alias(
             TOKEN,
             PARTICIPANT_ID,
             HTTP_ADDR,
-            HTTP_PORT
+            HTTP_PORT,
+            PROTOCOL_VERSION
         FROM
             COOP_PARTICIPANT
         WHERE
@@ -389,7 +425,8 @@ pub fn get_participant_by_alias(
                               token: Vec<u8>,
                               id: String,
                               http_addr: String,
-                              http_port: u16|
+                              http_port: u16,
+                              protocol_version: u32|
      -> Result<CoopDatabaseParticipant> {
         let participant = CoopDatabaseParticipant {
             internal_id: GUID::parse(&internal_id).unwrap(),
@@ -403,6 +440,7 @@ pub fn get_participant_by_alias(
             id: GUID::parse(&id).unwrap(),
             http_addr,
             http_port,
+            protocol_version,
         };
 
         Ok(participant)
@@ -425,6 +463,7 @@ pub fn get_participant_by_alias(
                 row.get(8).unwrap(),
                 row.get(9).unwrap(),
                 row.get(10).unwrap(),
+                row.get(11).unwrap(),
             )
         })
         .unwrap();
@@ -446,7 +485,7 @@ pub fn get_participants_for_database(
 ) -> core::result::Result<Vec<ParticipantStatus>, RcdDbError> {
     let mut result: Vec<ParticipantStatus> = Vec::new();
 
-    let conn = get_db_conn(config, db_name);
+    let conn = get_participant_conn(config, db_name);
 
     // if the table doesn't exist, we should return an error here
     if !has_table("COOP_PARTICIPANT", &conn) {
@@ -537,12 +576,7 @@ pub fn get_participants_for_table(
     let conn = get_db_conn(&config, db_name);
     let metadata_table_name = get_metadata_table_name(table_name);
 
-    if !has_table(&metadata_table_name, &conn) {
-        //  need to create table
-        let mut cmd = sql_text::Coop::text_create_metadata_table();
-        cmd = cmd.replace(":table_name", &metadata_table_name);
-        execute_write(&conn, &cmd);
-    }
+    create_metadata_table_if_not_exists(&metadata_table_name, &conn);
 
     let conn = get_db_conn(&config, db_name);
 
@@ -577,14 +611,27 @@ pub fn get_participants_for_table(
         db_participants.push(participant);
     }
 
-    let row_to_data = |row_id: u32, hash: Vec<u8>| -> Result<(u32, Vec<u8>)> { Ok((row_id, hash)) };
+    let row_to_data = |row_id: u32,
+                       hash: Vec<u8>,
+                       is_out_of_sync: u32,
+                       is_remote_deleted: u32|
+     -> Result<CoopDatabaseParticipantRow> {
+        Ok(CoopDatabaseParticipantRow {
+            row_id,
+            hash,
+            is_out_of_sync: is_out_of_sync > 0,
+            is_remote_deleted: is_remote_deleted > 0,
+        })
+    };
 
     for p in &db_participants {
         cmd = String::from(
             "
             SELECT
                 ROW_ID,
-                HASH
+                HASH,
+                COALESCE(IS_OUT_OF_SYNC, 0),
+                COALESCE(IS_REMOTE_DELETED, 0)
             FROM
                 :table_name
             WHERE
@@ -598,11 +645,16 @@ pub fn get_participants_for_table(
 
         let row_data = statement
             .query_and_then([], |row| {
-                row_to_data(row.get(0).unwrap(), row.get(1).unwrap())
+                row_to_data(
+                    row.get(0).unwrap(),
+                    row.get(1).unwrap(),
+                    row.get(2).unwrap(),
+                    row.get(3).unwrap(),
+                )
             })
             .unwrap();
 
-        let mut row_data_results: Vec<(u32, Vec<u8>)> = Vec::new();
+        let mut row_data_results: Vec<CoopDatabaseParticipantRow> = Vec::new();
 
         for data in row_data {
             row_data_results.push(data.unwrap());
@@ -635,3 +687,20 @@ pub fn has_participant(db_name: &str, alias: &str, config: DbiConfigSqlite) -> b
     cmd = cmd.replace(":alias", alias);
     has_any_rows(cmd, conn)
 }
+
+pub fn update_participant_protocol_version(
+    db_name: &str,
+    alias: &str,
+    protocol_version: u32,
+    config: DbiConfigSqlite,
+) -> bool {
+    let conn = get_participant_conn(&config, db_name);
+    let cmd = "UPDATE COOP_PARTICIPANT SET PROTOCOL_VERSION = :version WHERE ALIAS = :alias";
+
+    let mut statement = conn.prepare(cmd).unwrap();
+    let result = statement
+        .execute(named_params! {":version": protocol_version, ":alias": alias})
+        .unwrap();
+
+    result > 0
+}
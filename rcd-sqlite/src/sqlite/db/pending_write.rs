@@ -0,0 +1,135 @@
+use crate::sqlite::{execute_write, get_db_conn, has_table, sql_text};
+use rcd_common::db::DbiConfigSqlite;
+use rcdproto::rcdp::PendingWrite;
+use rusqlite::{named_params, Connection};
+use stdext::function_name;
+use tracing::trace;
+
+fn create_pending_writes_table_if_not_exists(conn: &Connection) {
+    if !has_table("COOP_PENDING_WRITES", conn) {
+        let cmd = sql_text::Coop::text_create_pending_writes_table();
+        execute_write(conn, &cmd);
+    }
+}
+
+pub fn add_pending_write_at_host(
+    db_name: &str,
+    internal_participant_id: &str,
+    pending_write: &PendingWrite,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_db_conn(config, db_name);
+    create_pending_writes_table_if_not_exists(&conn);
+
+    let cmd = "
+        INSERT INTO COOP_PENDING_WRITES
+        (
+            TABLE_NAME,
+            INTERNAL_PARTICIPANT_ID,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        )
+        VALUES
+        (
+            :table_name,
+            :pid,
+            :alias,
+            :action_id,
+            :statement,
+            :where_clause,
+            :action,
+            :ts
+        )
+    ;";
+
+    let mut statement = conn.prepare(cmd).unwrap();
+    let rows = statement
+        .execute(named_params! {
+            ":table_name": pending_write.table_name,
+            ":pid": internal_participant_id,
+            ":alias": pending_write.participant_alias,
+            ":action_id": pending_write.pending_action_id,
+            ":statement": pending_write.statement,
+            ":where_clause": pending_write.where_clause,
+            ":action": pending_write.action,
+            ":ts": pending_write.requested_ts_utc,
+        })
+        .unwrap();
+
+    rows > 0
+}
+
+pub fn remove_pending_write_at_host(
+    db_name: &str,
+    table_name: &str,
+    internal_participant_id: &str,
+    pending_action_id: u32,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_db_conn(config, db_name);
+    create_pending_writes_table_if_not_exists(&conn);
+
+    let cmd = "
+        DELETE FROM COOP_PENDING_WRITES
+        WHERE
+            TABLE_NAME = :table_name
+        AND
+            INTERNAL_PARTICIPANT_ID = :pid
+        AND
+            PENDING_ACTION_ID = :action_id
+    ;";
+
+    let mut statement = conn.prepare(cmd).unwrap();
+    let rows = statement
+        .execute(named_params! {
+            ":table_name": table_name,
+            ":pid": internal_participant_id,
+            ":action_id": pending_action_id,
+        })
+        .unwrap();
+
+    trace!("[{}]: rows affected: {rows:?}", function_name!());
+
+    rows > 0
+}
+
+pub fn get_pending_writes_at_host(db_name: &str, config: &DbiConfigSqlite) -> Vec<PendingWrite> {
+    let conn = get_db_conn(config, db_name);
+    create_pending_writes_table_if_not_exists(&conn);
+
+    let cmd = "
+        SELECT
+            TABLE_NAME,
+            PARTICIPANT_ALIAS,
+            PENDING_ACTION_ID,
+            STATEMENT,
+            WHERE_CLAUSE,
+            ACTION,
+            REQUESTED_TS_UTC
+        FROM
+            COOP_PENDING_WRITES
+    ;";
+
+    let mut statement = conn.prepare(cmd).unwrap();
+    let pending_writes = statement
+        .query_and_then([], |row| -> rusqlite::Result<PendingWrite> {
+            Ok(PendingWrite {
+                table_name: row.get(0)?,
+                participant_alias: row.get(1)?,
+                pending_action_id: row.get(2)?,
+                statement: row.get(3)?,
+                where_clause: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                action: row.get(5)?,
+                requested_ts_utc: row.get(6)?,
+            })
+        })
+        .unwrap();
+
+    pending_writes
+        .map(|pending_write| pending_write.unwrap())
+        .collect()
+}
@@ -0,0 +1,287 @@
+use super::rcd_db::{
+    clear_database_encrypted, get_rcd_db_type, is_database_encrypted, set_database_encrypted,
+};
+use rcd_common::db::DbiConfigSqlite;
+use rcd_enum::rcd_database_type::RcdDatabaseType;
+use rcd_error::rcd_db_error::RcdDbError;
+use rusqlite::Connection;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{OnceLock, RwLock},
+};
+use stdext::function_name;
+use tracing::trace;
+
+/// The keys for databases that are encrypted at rest (SQLCipher), by the path of the
+/// database file. Keys are handed to us by the client (for a partial database, the
+/// participant) and are only ever held in memory; after a restart an encrypted database is
+/// locked until its key is supplied again.
+fn keys() -> &'static RwLock<HashMap<PathBuf, String>> {
+    static KEYS: OnceLock<RwLock<HashMap<PathBuf, String>>> = OnceLock::new();
+    KEYS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Keys a newly opened connection to the database file at `db_path`, if we hold a key for it.
+/// This needs to happen before anything else is done with the connection.
+pub fn apply_key(db_path: &Path, conn: &Connection) {
+    if let Some(key) = keys().read().unwrap().get(db_path) {
+        trace!("[{}]: {db_path:?}", function_name!());
+        conn.pragma_update(None, "key", key).unwrap();
+    }
+}
+
+/// Holds `key` for a database that has not been created yet, so that it is encrypted from
+/// the first connection onwards, and records in the rcd db that it is encrypted
+pub fn set_database_key(
+    db_name: &str,
+    db_type: RcdDatabaseType,
+    key: &str,
+    config: &DbiConfigSqlite,
+) -> Result<(), RcdDbError> {
+    let file_name = match db_type {
+        RcdDatabaseType::Host => db_name.to_string(),
+        RcdDatabaseType::Partial => get_partial_db_file_name(db_name),
+        _ => {
+            return Err(RcdDbError::General(format!(
+                "only host and partial databases can be encrypted, not `{db_name}`"
+            )))
+        }
+    };
+
+    let db_path = Path::new(&config.root_folder).join(&file_name);
+
+    if db_path.exists() {
+        return Err(RcdDbError::General(format!(
+            "the database `{db_name}` already exists; a database can only be encrypted when it is created"
+        )));
+    }
+
+    keys().write().unwrap().insert(db_path, key.to_string());
+
+    if !set_database_encrypted(&file_name, config) {
+        return Err(RcdDbError::General(format!(
+            "unable to record that `{db_name}` is encrypted"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Forgets the key held for a database that was never created and removes the record that it
+/// is encrypted. A database that exists keeps both, since it can't be read without the key.
+pub fn clear_database_key(
+    db_name: &str,
+    db_type: RcdDatabaseType,
+    config: &DbiConfigSqlite,
+) -> Result<(), RcdDbError> {
+    let file_name = match db_type {
+        RcdDatabaseType::Host => db_name.to_string(),
+        RcdDatabaseType::Partial => get_partial_db_file_name(db_name),
+        _ => return Ok(()),
+    };
+
+    let db_path = Path::new(&config.root_folder).join(&file_name);
+
+    if db_path.exists() {
+        return Err(RcdDbError::General(format!(
+            "the database `{db_name}` exists; its key can't be cleared"
+        )));
+    }
+
+    keys().write().unwrap().remove(&db_path);
+
+    if !clear_database_encrypted(&file_name, config) {
+        return Err(RcdDbError::General(format!(
+            "unable to clear that `{db_name}` is encrypted"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Checks that `key` opens the encrypted database and holds on to it for later requests
+pub fn unlock_database(
+    db_name: &str,
+    key: &str,
+    config: &DbiConfigSqlite,
+) -> Result<(), RcdDbError> {
+    let db_path = match get_db_file_name(db_name, config) {
+        Some(file_name) => Path::new(&config.root_folder).join(file_name),
+        None => return Err(RcdDbError::DbNotFound(db_name.to_string())),
+    };
+
+    let conn = Connection::open(&db_path)?;
+    conn.pragma_update(None, "key", key)?;
+
+    // sqlcipher only checks the key once the first page is read
+    let opened = conn.query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| {
+        row.get::<usize, i64>(0)
+    });
+
+    if opened.is_err() {
+        return Err(RcdDbError::InvalidDatabaseKey(db_name.to_string()));
+    }
+
+    keys().write().unwrap().insert(db_path, key.to_string());
+
+    Ok(())
+}
+
+/// Returns an error if the database is encrypted and we don't hold its key. Databases that
+/// don't exist are left for the caller to report.
+pub fn check_database_key(db_name: &str, config: &DbiConfigSqlite) -> Result<(), RcdDbError> {
+    if let Some(file_name) = get_db_file_name(db_name, config) {
+        let db_path = Path::new(&config.root_folder).join(&file_name);
+
+        if is_database_encrypted(&file_name, config)
+            && !keys().read().unwrap().contains_key(&db_path)
+        {
+            return Err(RcdDbError::DatabaseLocked(db_name.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
+/// The name of the file that `db_name` is kept in, if it's a host or partial database
+pub(crate) fn get_db_file_name(db_name: &str, config: &DbiConfigSqlite) -> Option<String> {
+    match get_rcd_db_type(db_name, config) {
+        RcdDatabaseType::Host => Some(db_name.to_string()),
+        RcdDatabaseType::Partial => Some(get_partial_db_file_name(db_name)),
+        _ => None,
+    }
+}
+
+fn get_partial_db_file_name(db_name: &str) -> String {
+    let mut db_part_name = db_name.replace(".dbpart", "");
+    db_part_name = db_part_name.replace(".db", "");
+    format!("{}{}", db_part_name, String::from(".dbpart"))
+}
+
+#[test]
+fn test_locked_database_needs_its_key() {
+    use super::get_db_conn;
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-locked-db");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+    super::rcd_db::configure_rcd_db(&config);
+
+    set_database_key("locked.db", RcdDatabaseType::Host, "the key", &config).unwrap();
+    let conn = get_db_conn(&config, "locked.db");
+    conn.execute("CREATE TABLE EMPLOYEE (ID INT);", []).unwrap();
+    drop(conn);
+
+    // forget the key, as a restart would
+    keys().write().unwrap().remove(&root.join("locked.db"));
+
+    assert!(matches!(
+        check_database_key("locked.db", &config),
+        Err(RcdDbError::DatabaseLocked(_))
+    ));
+
+    // the file really can't be read without the key
+    let conn = get_db_conn(&config, "locked.db");
+    assert!(conn
+        .query_row("SELECT COUNT(*) FROM EMPLOYEE", [], |row| row
+            .get::<usize, i64>(0))
+        .is_err());
+    drop(conn);
+
+    assert!(matches!(
+        unlock_database("locked.db", "the wrong key", &config),
+        Err(RcdDbError::InvalidDatabaseKey(_))
+    ));
+    assert!(matches!(
+        check_database_key("locked.db", &config),
+        Err(RcdDbError::DatabaseLocked(_))
+    ));
+
+    unlock_database("locked.db", "the key", &config).unwrap();
+    assert!(check_database_key("locked.db", &config).is_ok());
+}
+
+#[test]
+fn test_clear_database_key_before_create() {
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-clear-key");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+    super::rcd_db::configure_rcd_db(&config);
+
+    set_database_key("never_created.db", RcdDatabaseType::Host, "key", &config).unwrap();
+    assert!(is_database_encrypted("never_created.db", &config));
+
+    clear_database_key("never_created.db", RcdDatabaseType::Host, &config).unwrap();
+    assert!(!is_database_encrypted("never_created.db", &config));
+    assert!(!keys()
+        .read()
+        .unwrap()
+        .contains_key(&root.join("never_created.db")));
+}
+
+#[test]
+fn test_snapshot_of_encrypted_database_keeps_its_key() {
+    use super::{get_db_conn, snapshot_database};
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-snapshot-key");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+    super::rcd_db::configure_rcd_db(&config);
+
+    set_database_key("snapshot.db", RcdDatabaseType::Host, "the key", &config).unwrap();
+    let conn = get_db_conn(&config, "snapshot.db");
+    conn.execute("CREATE TABLE EMPLOYEE (ID INT);", []).unwrap();
+    conn.execute("INSERT INTO EMPLOYEE (ID) VALUES (1);", [])
+        .unwrap();
+    drop(conn);
+
+    let snapshot = snapshot_database("snapshot.db", &config).unwrap();
+
+    let conn = Connection::open(&snapshot).unwrap();
+    assert!(conn
+        .query_row("SELECT COUNT(*) FROM EMPLOYEE", [], |row| row
+            .get::<usize, i64>(0))
+        .is_err());
+    drop(conn);
+
+    let conn = Connection::open(&snapshot).unwrap();
+    conn.pragma_update(None, "key", "the key").unwrap();
+    let count = conn
+        .query_row("SELECT COUNT(*) FROM EMPLOYEE", [], |row| {
+            row.get::<usize, i64>(0)
+        })
+        .unwrap();
+    assert_eq!(count, 1);
+}
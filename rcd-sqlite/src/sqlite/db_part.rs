@@ -1,20 +1,24 @@
 use super::{
-    execute_read_at_participant, execute_read_on_connection_for_row, execute_write,
-    get_db_conn_with_result, get_scalar_as_string, get_scalar_as_u32, get_scalar_as_u64,
-    get_table_col_names, get_table_col_names_with_data_type_as_string, has_table, sql_text,
+    db_key, execute_read_at_participant, execute_read_on_connection_for_row, execute_write,
+    get_db_conn_with_result, get_scalar_as_string, get_scalar_as_u64, get_table_col_names,
+    get_table_col_names_with_data_type_as_string, has_table, sql_text,
 };
 use chrono::Utc;
 use stdext::function_name;
 use tracing::{debug, trace, warn};
+use rcd_common::crypt::{self, RowValue};
 use rcd_common::db::{
     get_data_log_table_name, get_data_queue_table_name, get_metadata_table_name, DbiConfigSqlite,
-    PartialDataResult,
+    PartialDataResult, RehashedRow,
 };
+use rcd_common::defaults;
 use rcd_common::table::Table;
 use rcd_enum::column_type::ColumnType;
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rcd_error::rcd_db_error::RcdDbError;
 use rcdproto::rcdp::{ColumnSchema, Contract, PendingStatement, TableSchema};
 use rusqlite::types::Type;
-use rusqlite::{Connection, Result};
+use rusqlite::{named_params, Connection, OptionalExtension, Result};
 use std::path::Path;
 
 pub mod delete;
@@ -33,6 +37,7 @@ pub fn accept_pending_action_at_participant(
         data_hash: None,
         partial_data_status: None,
         action: None,
+        affected_rows: Vec::new(),
     };
 
     let conn = get_partial_db_connection(db_name, &config.root_folder);
@@ -78,6 +83,48 @@ pub fn accept_pending_action_at_participant(
     action_result
 }
 
+/// Removes the action from the table's queue without running it
+pub fn reject_pending_action_at_participant(
+    db_name: &str,
+    table_name: &str,
+    row_id: u32,
+    config: &DbiConfigSqlite,
+) -> PartialDataResult {
+    let conn = get_partial_db_connection(db_name, &config.root_folder);
+    let queue_table_name = get_data_queue_table_name(table_name);
+
+    let mut cmd = String::from("SELECT ACTION FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let action = match get_scalar_as_string(cmd, &conn).as_str() {
+        "UPDATE" => Some(PartialDataResultAction::Update),
+        "DELETE" => Some(PartialDataResultAction::Delete),
+        _ => None,
+    };
+
+    let mut cmd = String::from("DELETE FROM :table_name WHERE ID = :rid");
+    cmd = cmd.replace(":table_name", &queue_table_name);
+    cmd = cmd.replace(":rid", &row_id.to_string());
+
+    let total_rows = if action.is_some() {
+        execute_write(&conn, &cmd)
+    } else {
+        0
+    };
+
+    trace!("[{}]: total rows rejected: {total_rows}", function_name!());
+
+    PartialDataResult {
+        is_successful: total_rows > 0,
+        row_id,
+        data_hash: None,
+        partial_data_status: None,
+        action,
+        affected_rows: Vec::new(),
+    }
+}
+
 pub fn get_data_hash_at_participant(
     db_name: &str,
     table_name: &str,
@@ -93,6 +140,153 @@ pub fn get_data_hash_at_participant(
     get_scalar_as_u64(cmd, &conn).unwrap()
 }
 
+/// Recomputes the hash of every row tracked in the metadata tables of the partial database,
+/// saving the new hashes and returning them so they can be sent to the host
+pub fn rehash_partial_database(
+    db_name: &str,
+    config: &DbiConfigSqlite,
+) -> Result<Vec<RehashedRow>, RcdDbError> {
+    let conn = get_partial_db_connection(db_name, &config.root_folder);
+
+    let mut statement = conn.prepare("SELECT name FROM sqlite_master WHERE type = 'table'")?;
+    let mut table_names: Vec<String> = Vec::new();
+
+    for name in statement.query_map([], |row| row.get::<_, String>(0))? {
+        if let Some(table_name) = name?.strip_suffix(defaults::METADATA_TABLE_SUFFIX) {
+            table_names.push(table_name.to_string());
+        }
+    }
+    drop(statement);
+
+    let mut rehashed_rows: Vec<RehashedRow> = Vec::new();
+
+    for table_name in &table_names {
+        let metadata_table_name = get_metadata_table_name(table_name);
+
+        let cmd = format!("SELECT ROW_ID FROM {metadata_table_name}");
+        let mut statement = conn.prepare(&cmd)?;
+        let row_ids: Vec<u32> = statement
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_>>()?;
+        drop(statement);
+
+        let cmd = format!("UPDATE {metadata_table_name} SET HASH = :hash WHERE ROW_ID = :rid");
+
+        for row_id in row_ids {
+            if let Some(hash) = get_row_hash(table_name, row_id, &conn)? {
+                conn.execute(
+                    &cmd,
+                    named_params! {":hash": hash.to_ne_bytes(), ":rid": row_id},
+                )?;
+
+                rehashed_rows.push(RehashedRow {
+                    table_name: table_name.clone(),
+                    row_id,
+                    hash,
+                });
+            }
+        }
+    }
+
+    set_row_hash_version(&conn)?;
+
+    Ok(rehashed_rows)
+}
+
+/// Records that the hashes saved in the partial database were calculated with the current
+/// `ROW_HASH_VERSION`
+fn set_row_hash_version(conn: &Connection) -> Result<(), RcdDbError> {
+    conn.execute(&sql_text::Coop::text_create_row_hash_version_table(), [])?;
+    conn.execute("DELETE FROM COOP_ROW_HASH_VERSION;", [])?;
+    conn.execute(
+        "INSERT INTO COOP_ROW_HASH_VERSION (VERSION) VALUES (:version);",
+        named_params! {":version": crypt::ROW_HASH_VERSION},
+    )?;
+
+    Ok(())
+}
+
+/// Returns the `ROW_HASH_VERSION` the hashes saved in the partial database were calculated with,
+/// or `None` if it was created before the version was recorded
+pub fn get_row_hash_version(
+    db_name: &str,
+    config: &DbiConfigSqlite,
+) -> Result<Option<u8>, RcdDbError> {
+    let conn = get_partial_db_connection(db_name, &config.root_folder);
+
+    if !has_table("COOP_ROW_HASH_VERSION", &conn) {
+        return Ok(None);
+    }
+
+    let version = conn
+        .query_row("SELECT VERSION FROM COOP_ROW_HASH_VERSION", [], |row| {
+            row.get(0)
+        })
+        .optional()?;
+
+    Ok(version)
+}
+
+/// Hashes the values currently stored in the row, or `None` if the row doesn't exist
+pub fn get_row_hash(
+    table_name: &str,
+    row_id: u32,
+    conn: &Connection,
+) -> Result<Option<u64>, RcdDbError> {
+    let col_names = get_table_col_names(table_name.to_string(), conn);
+    let cmd = format!(
+        "SELECT {} FROM {table_name} WHERE ROWID = {row_id}",
+        col_names.join(",")
+    );
+
+    let mut statement = conn.prepare(&cmd)?;
+    let mut rows = statement.query([])?;
+
+    match rows.next()? {
+        Some(row) => {
+            let row_values = get_row_values(row, col_names.len());
+            Ok(Some(crypt::calculate_hash_for_row(&row_values)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Hashes the values of every row in the table the same way `get_row_hash` does, so that a
+/// host's own copy of a table can be compared against the hashes a participant has sent it
+pub fn get_row_hashes(table_name: &str, conn: &Connection) -> Vec<u64> {
+    let col_names = get_table_col_names(table_name.to_string(), conn);
+    let cmd = format!("SELECT {} FROM {table_name}", col_names.join(","));
+
+    let mut statement = conn.prepare(&cmd).unwrap();
+    let mut rows = statement.query([]).unwrap();
+    let mut hashes: Vec<u64> = Vec::new();
+
+    while let Some(row) = rows.next().unwrap() {
+        let row_values = get_row_values(row, col_names.len());
+        hashes.push(crypt::calculate_hash_for_row(&row_values));
+    }
+
+    hashes
+}
+
+fn get_row_values(row: &rusqlite::Row, total_columns: usize) -> Vec<RowValue> {
+    let mut row_values: Vec<RowValue> = Vec::new();
+    for i in 0..total_columns {
+        let value = row.get_ref_unwrap(i);
+        let row_value = match value.data_type() {
+            Type::Null => RowValue::Null,
+            Type::Integer => RowValue::Text(value.as_i64().unwrap().to_string()),
+            Type::Real => RowValue::Text(value.as_f64().unwrap().to_string()),
+            Type::Text => RowValue::Text(value.as_str().unwrap().to_string()),
+            Type::Blob => RowValue::Blob(value.as_blob().unwrap().to_vec()),
+        };
+
+        row_values.push(row_value);
+    }
+
+    row_values
+}
+
 pub fn get_pending_actions(
     db_name: &str,
     table_name: &str,
@@ -103,6 +297,12 @@ pub fn get_pending_actions(
 
     let mut pending_statements: Vec<PendingStatement> = Vec::new();
 
+    // nothing has been queued for the table yet
+    let conn = get_partial_db_connection(db_name, &config.root_folder);
+    if !has_table(&update_queue, &conn) {
+        return pending_statements;
+    }
+
     let mut cmd = String::from(
         "
         SELECT 
@@ -198,6 +398,11 @@ pub fn create_partial_database_from_contract(
         create_table_from_schema(table, &conn);
     }
 
+    if let Err(e) = set_row_hash_version(&conn) {
+        warn!("unable to record the row hash version of {db_name}: {e}");
+        return false;
+    }
+
     true
 }
 
@@ -246,17 +451,20 @@ pub fn save_contract(db_name: &str, table_name: &str, row_data: Table) -> String
     unimplemented!();
 }
 
-pub fn read_row_id_from_part_db(
+pub fn read_row_ids_from_part_db(
     db_name: &str,
     table_name: &str,
     where_clause: &str,
     config: &DbiConfigSqlite,
-) -> u32 {
+) -> Result<Vec<u32>, RcdDbError> {
     let conn = get_partial_db_connection(db_name, &config.root_folder);
     let mut cmd = String::from("SELECT ROWID FROM :table_name WHERE :where_clause");
     cmd = cmd.replace(":table_name", table_name);
     cmd = cmd.replace(":where_clause", where_clause);
-    get_scalar_as_u32(cmd, &conn)
+
+    let mut statement = conn.prepare(&cmd)?;
+    let row_ids = statement.query_map([], |row| row.get(0))?;
+    Ok(row_ids.collect::<Result<Vec<u32>, _>>()?)
 }
 
 pub fn get_partial_db_connection(db_name: &str, cwd: &str) -> Connection {
@@ -264,7 +472,9 @@ pub fn get_partial_db_connection(db_name: &str, cwd: &str) -> Connection {
     db_part_name = db_part_name.replace(".dbpart", "");
     db_part_name = format!("{}{}", db_part_name, String::from(".dbpart"));
     let db_path = Path::new(&cwd).join(&db_part_name);
-    Connection::open(db_path).unwrap()
+    let conn = Connection::open(&db_path).unwrap();
+    db_key::apply_key(&db_path, &conn);
+    conn
 }
 
 fn create_table_from_schema(table_schema: &TableSchema, conn: &Connection) {
@@ -380,9 +590,12 @@ fn add_record_to_log_table(
                 Type::Integer => row.get_ref_unwrap(i).as_i64().unwrap().to_string(),
                 Type::Real => row.get_ref_unwrap(i).as_f64().unwrap().to_string(),
                 Type::Text => {
-                    format!("'{}'", row.get_ref_unwrap(i).as_str().unwrap())
+                    format!(
+                        "'{}'",
+                        row.get_ref_unwrap(i).as_str().unwrap().replace('\'', "''")
+                    )
                 }
-                _ => String::from(""),
+                Type::Null => String::from("NULL"),
             };
 
             // add the value to the list of values to insert
@@ -407,3 +620,50 @@ fn add_record_to_log_table(
 
     true
 }
+
+#[test]
+fn test_rehash_partial_database_records_row_hash_version() {
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-row-hash-version");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+
+    // a partial database created before the version was recorded
+    let conn = get_partial_db_connection("hash_version.db", &config.root_folder);
+    conn.execute("CREATE TABLE EMPLOYEE (ID INT, NAME TEXT);", [])
+        .unwrap();
+    conn.execute("INSERT INTO EMPLOYEE (ID, NAME) VALUES (1, 'A');", [])
+        .unwrap();
+    conn.execute(
+        "CREATE TABLE EMPLOYEE_COOP_METADATA (ROW_ID INT, HASH BLOB);",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO EMPLOYEE_COOP_METADATA (ROW_ID, HASH) VALUES (1, x'00');",
+        [],
+    )
+    .unwrap();
+
+    assert_eq!(
+        get_row_hash_version("hash_version.db", &config).unwrap(),
+        None
+    );
+
+    let rehashed_rows = rehash_partial_database("hash_version.db", &config).unwrap();
+    assert_eq!(rehashed_rows.len(), 1);
+    assert_eq!(
+        get_row_hash_version("hash_version.db", &config).unwrap(),
+        Some(crypt::ROW_HASH_VERSION)
+    );
+}
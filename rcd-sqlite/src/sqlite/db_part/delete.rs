@@ -4,10 +4,12 @@ use stdext::function_name;
 use tracing::{debug, trace};
 
 use crate::sqlite::{
-    execute_write, get_scalar_as_u32, has_table, rcd_db::get_deletes_from_host_behavior, sql_text,
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::{get_data_log_table_status, get_deletes_from_host_behavior},
+    sql_text,
 };
 use rcd_common::{
-    db::{get_data_queue_table_name, DbiConfigSqlite, PartialDataResult},
+    db::{get_data_queue_table_name, AffectedRow, DbiConfigSqlite, PartialDataResult},
     defaults,
 };
 
@@ -86,6 +88,7 @@ pub fn delete_data_into_partial_db_queue(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Delete),
+        affected_rows: Vec::new(),
     }
 }
 
@@ -104,21 +107,22 @@ pub fn delete_data_in_partial_db(
     match behavior {
         DeletesFromHostBehavior::Unknown => todo!(),
         DeletesFromHostBehavior::AllowRemoval => {
-            execute_delete(db_name, table_name, cmd, where_clause, config)
+            execute_delete_and_log_if_enabled(db_name, table_name, cmd, where_clause, config)
+        }
+        DeletesFromHostBehavior::QueueForReview | DeletesFromHostBehavior::QueueForReviewAndLog => {
+            delete_data_into_partial_db_queue(
+                db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                config,
+            )
         }
-        DeletesFromHostBehavior::QueueForReview => delete_data_into_partial_db_queue(
-            db_name,
-            table_name,
-            cmd,
-            where_clause,
-            host_id,
-            config,
-        ),
         DeletesFromHostBehavior::DeleteWithLog => {
             execute_delete_with_log(db_name, table_name, cmd, where_clause, config)
         }
         DeletesFromHostBehavior::Ignore => todo!(),
-        DeletesFromHostBehavior::QueueForReviewAndLog => todo!(),
     }
 }
 
@@ -196,6 +200,13 @@ fn execute_delete(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Delete),
+        affected_rows: row_ids
+            .iter()
+            .map(|row_id| AffectedRow {
+                row_id: *row_id,
+                data_hash: None,
+            })
+            .collect(),
     };
 
     trace!("[{}]: {result:?}", function_name!());
@@ -222,10 +233,11 @@ pub fn handle_delete_pending_action(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Delete),
+        affected_rows: Vec::new(),
     };
 
     if behavior == DeletesFromHostBehavior::QueueForReview {
-        update_result = execute_delete(
+        update_result = execute_delete_and_log_if_enabled(
             db_name,
             table_name,
             sql_update_statement,
@@ -269,3 +281,18 @@ fn execute_delete_with_log(
     add_record_to_log_table(db_name, table_name, where_clause, "DELETE", config);
     execute_delete(db_name, table_name, cmd, where_clause, config)
 }
+
+/// Removes the rows, logging them first if the data log table is switched on for the table
+fn execute_delete_and_log_if_enabled(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigSqlite,
+) -> PartialDataResult {
+    if get_data_log_table_status(db_name, table_name, config) {
+        execute_delete_with_log(db_name, table_name, cmd, where_clause, config)
+    } else {
+        execute_delete(db_name, table_name, cmd, where_clause, config)
+    }
+}
@@ -1,14 +1,14 @@
 use crate::sqlite::{
-    db_part::get_partial_db_connection, execute_write, get_scalar_as_u32, has_table, sql_text,
+    db_part::{add_record_to_log_table, get_partial_db_connection, get_row_hash},
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::get_data_log_table_status,
+    sql_text,
 };
-use tracing::debug;
+use tracing::{debug, error, warn};
 use rcd_common::db::*;
-use rcd_common::{
-    crypt,
-    db::{DbiConfigSqlite, PartialDataResult},
-};
-use rcd_enum::{database_type::DatabaseType, partial_data_result_action::PartialDataResultAction};
-use rusqlite::named_params;
+use rcd_common::db::{AffectedRow, DbiConfigSqlite, PartialDataResult};
+use rcd_enum::partial_data_result_action::PartialDataResultAction;
+use rusqlite::{named_params, Connection};
 
 pub fn insert_data_into_partial_db(
     db_name: &str,
@@ -16,20 +16,34 @@ pub fn insert_data_into_partial_db(
     cmd: &str,
     config: &DbiConfigSqlite,
 ) -> PartialDataResult {
-    let conn = get_partial_db_connection(db_name, &config.root_folder);
-    let mut row_id = 0;
+    let mut conn = get_partial_db_connection(db_name, &config.root_folder);
+
+    let total_rows = match insert_single_row(&mut conn, cmd) {
+        Ok(total_rows) => total_rows,
+        Err(e) => {
+            error!("unable to insert into {table_name}: {e}");
+            return failed_insert_result();
+        }
+    };
 
-    let total_rows = execute_write(&conn, cmd);
-    if total_rows > 0 {
-        let cmd = String::from("select last_insert_rowid()");
-        row_id = get_scalar_as_u32(cmd, &conn);
+    if total_rows != 1 {
+        warn!(
+            "the insert into {table_name} would have added {total_rows} rows, it was rolled back"
+        );
+        return failed_insert_result();
     }
 
-    // we need to parse the values of this row
-    // and create a data hash for it
-    let insert_values =
-        rcd_query::query_parser::get_values_from_insert_statement(cmd, DatabaseType::Sqlite);
-    let hash_value = crypt::calculate_hash_for_struct(&insert_values);
+    let cmd = String::from("select last_insert_rowid()");
+    let row_id = get_scalar_as_u32(cmd, &conn);
+
+    // we need to hash the values of this row as they were stored
+    let hash_value = match get_row_hash(table_name, row_id, &conn) {
+        Ok(hash_value) => hash_value.unwrap_or_default(),
+        Err(e) => {
+            error!("unable to hash row {row_id} of {table_name}: {e}");
+            return failed_insert_result();
+        }
+    };
 
     // we need to determine if there is a metadata table for this table or not
     // and if there is not one, create it
@@ -54,11 +68,49 @@ pub fn insert_data_into_partial_db(
         .execute(named_params! {":row": row_id, ":hash" : hash_value.to_ne_bytes() })
         .unwrap();
 
+    // there's nothing to keep from before an insert, so the data log gets the row as it was inserted
+    if get_data_log_table_status(db_name, table_name, config) {
+        let where_clause = format!("ROWID = {row_id}");
+        add_record_to_log_table(db_name, table_name, &where_clause, "INSERT", config);
+    }
+
     PartialDataResult {
-        is_successful: total_rows > 0,
+        is_successful: true,
         row_id,
         data_hash: Some(hash_value),
         partial_data_status: None,
         action: Some(PartialDataResultAction::Insert),
+        affected_rows: vec![AffectedRow {
+            row_id,
+            data_hash: Some(hash_value),
+        }],
+    }
+}
+
+/// Runs the INSERT in a transaction that is only committed if it added exactly one row. We
+/// track a row's metadata and tell the host about it one row at a time, so the rows of a
+/// multi-row INSERT would otherwise be left behind without a reference at the host
+fn insert_single_row(conn: &mut Connection, cmd: &str) -> rusqlite::Result<usize> {
+    let tx = conn.transaction()?;
+    let total_rows = tx.execute(cmd, [])?;
+
+    if total_rows == 1 {
+        tx.commit()?;
+    } else {
+        tx.rollback()?;
+    }
+
+    Ok(total_rows)
+}
+
+/// The result of an INSERT that was rolled back or could not be run
+fn failed_insert_result() -> PartialDataResult {
+    PartialDataResult {
+        is_successful: false,
+        row_id: 0,
+        data_hash: None,
+        partial_data_status: None,
+        action: Some(PartialDataResultAction::Insert),
+        affected_rows: Vec::new(),
     }
 }
@@ -1,20 +1,22 @@
 use crate::sqlite::{
-    execute_write, get_scalar_as_u32, get_table_col_names, has_table,
-    rcd_db::get_updates_from_host_behavior, sql_text,
+    execute_write, get_scalar_as_u32, has_table,
+    rcd_db::{get_data_log_table_status, get_updates_from_host_behavior},
+    sql_text,
 };
 
-use super::{add_record_to_log_table, get_partial_db_connection};
+use super::{add_record_to_log_table, get_partial_db_connection, get_row_hash};
 use chrono::Utc;
 use rcd_common::{
-    crypt,
-    db::{get_data_queue_table_name, DbiConfigSqlite, PartialDataResult},
+    db::{get_data_queue_table_name, AffectedRow, DbiConfigSqlite, PartialDataResult},
     defaults,
 };
 use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
 use rcd_enum::{
     partial_data_result_action::PartialDataResultAction, partial_data_status::PartialDataStatus,
 };
-use rusqlite::{named_params, types::Type};
+use rusqlite::named_params;
+use stdext::function_name;
+use tracing::warn;
 
 pub fn update_data_into_partial_db_queue(
     db_name: &str,
@@ -81,6 +83,7 @@ pub fn update_data_into_partial_db_queue(
         data_hash: None,
         partial_data_status: Some(PartialDataStatus::to_u32(PartialDataStatus::Pending)),
         action: Some(PartialDataResultAction::Update),
+        affected_rows: Vec::new(),
     }
 }
 
@@ -95,22 +98,35 @@ pub fn update_data_into_partial_db(
     let behavior = get_updates_from_host_behavior(db_name, table_name, config);
     match behavior {
         UpdatesFromHostBehavior::AllowOverwrite => {
-            execute_update_overwrite(db_name, table_name, cmd, where_clause, config)
+            execute_update_and_log_if_enabled(db_name, table_name, cmd, where_clause, config)
+        }
+        UpdatesFromHostBehavior::QueueForReview | UpdatesFromHostBehavior::QueueForReviewAndLog => {
+            update_data_into_partial_db_queue(
+                db_name,
+                table_name,
+                cmd,
+                where_clause,
+                host_id,
+                config,
+            )
         }
-        UpdatesFromHostBehavior::Unknown => todo!(),
-        UpdatesFromHostBehavior::QueueForReview => update_data_into_partial_db_queue(
-            db_name,
-            table_name,
-            cmd,
-            where_clause,
-            host_id,
-            config,
-        ),
         UpdatesFromHostBehavior::OverwriteWithLog => {
             execute_update_with_log(db_name, table_name, cmd, where_clause, config)
         }
-        UpdatesFromHostBehavior::Ignore => todo!(),
-        UpdatesFromHostBehavior::QueueForReviewAndLog => todo!(),
+        UpdatesFromHostBehavior::Unknown | UpdatesFromHostBehavior::Ignore => {
+            warn!(
+                "[{}]: not applying update to {db_name}.{table_name}, behavior is {behavior:?}",
+                function_name!()
+            );
+            PartialDataResult {
+                is_successful: false,
+                row_id: 0,
+                data_hash: None,
+                partial_data_status: Some(PartialDataStatus::to_u32(PartialDataStatus::Ignored)),
+                action: Some(PartialDataResultAction::Update),
+                affected_rows: Vec::new(),
+            }
+        }
     }
 }
 
@@ -160,55 +176,13 @@ fn execute_update_overwrite(
     }
 
     // now we need to update the data hashes for every row that was changed
-    // ... how do we do that?
-    let col_names = get_table_col_names(table_name.to_string(), &conn);
-    let mut cmd;
-    cmd = String::from("SELECT :col_names FROM :table_name WHERE ROWID = :rid");
-    cmd = cmd.replace(":table_name", table_name);
-
-    let mut col_name_list = String::from("");
-
-    for name in &col_names {
-        col_name_list = col_name_list + name + ",";
-    }
-
-    let completed_col_name_list = &col_name_list[0..&col_name_list.len() - 1];
-    // trace!("{}", completed_col_name_list);
-
-    cmd = cmd.replace(":col_names", completed_col_name_list);
-
-    // trace!("{:?}", cmd);
-
     let mut row_hashes: Vec<(u32, u64)> = Vec::new();
 
     for id in &row_ids {
-        let sql = cmd.replace(":rid", &id.to_string());
-
-        // trace!("{:?}", sql);
-
-        let mut stmt = conn.prepare(&sql).unwrap();
-        let mut rows = stmt.query([]).unwrap();
-
-        // for a single row
-        while let Some(row) = rows.next().unwrap() {
-            let mut row_values: Vec<String> = Vec::new();
-            for i in 0..col_names.len() {
-                let dt = row.get_ref_unwrap(i).data_type();
-
-                let string_value: String = match dt {
-                    Type::Blob => String::from(""),
-                    Type::Integer => row.get_ref_unwrap(i).as_i64().unwrap().to_string(),
-                    Type::Real => row.get_ref_unwrap(i).as_f64().unwrap().to_string(),
-                    Type::Text => row.get_ref_unwrap(i).as_str().unwrap().to_string(),
-                    _ => String::from(""),
-                };
-
-                row_values.push(string_value);
-            }
-
-            let hash_value = crypt::calculate_hash_for_struct(&row_values);
-            let row_hash: (u32, u64) = (*id, hash_value);
-            row_hashes.push(row_hash);
+        match get_row_hash(table_name, *id, &conn) {
+            Ok(Some(hash_value)) => row_hashes.push((*id, hash_value)),
+            Ok(None) => {}
+            Err(e) => warn!("unable to hash row {id} of {table_name}: {e}"),
         }
     }
 
@@ -224,14 +198,24 @@ fn execute_update_overwrite(
             .unwrap();
     }
 
-    let row_data = row_hashes.first().unwrap();
+    let (row_id, data_hash) = match row_hashes.first() {
+        Some(row_data) => (row_data.0, Some(row_data.1)),
+        None => (0, None),
+    };
 
     PartialDataResult {
         is_successful: true,
-        row_id: row_data.0,
-        data_hash: Some(row_data.1),
+        row_id,
+        data_hash,
         partial_data_status: Some(1),
         action: Some(PartialDataResultAction::Update),
+        affected_rows: row_hashes
+            .iter()
+            .map(|row| AffectedRow {
+                row_id: row.0,
+                data_hash: Some(row.1),
+            })
+            .collect(),
     }
 }
 
@@ -246,6 +230,21 @@ fn execute_update_with_log(
     execute_update_overwrite(db_name, table_name, cmd, where_clause, config)
 }
 
+/// Overwrites the rows, logging them first if the data log table is switched on for the table
+fn execute_update_and_log_if_enabled(
+    db_name: &str,
+    table_name: &str,
+    cmd: &str,
+    where_clause: &str,
+    config: &DbiConfigSqlite,
+) -> PartialDataResult {
+    if get_data_log_table_status(db_name, table_name, config) {
+        execute_update_with_log(db_name, table_name, cmd, where_clause, config)
+    } else {
+        execute_update_overwrite(db_name, table_name, cmd, where_clause, config)
+    }
+}
+
 pub fn handle_update_pending_action(
     db_name: &str,
     table_name: &str,
@@ -265,10 +264,11 @@ pub fn handle_update_pending_action(
         data_hash: None,
         partial_data_status: None,
         action: Some(PartialDataResultAction::Update),
+        affected_rows: Vec::new(),
     };
 
     if behavior == UpdatesFromHostBehavior::QueueForReview {
-        update_result = execute_update_overwrite(
+        update_result = execute_update_and_log_if_enabled(
             db_name,
             table_name,
             sql_update_statement,
@@ -1,4 +1,7 @@
-use super::{get_scalar_as_string, get_scalar_as_u32, has_any_rows, sql_text::Cds};
+use super::{
+    add_column_if_missing, get_scalar_as_string, get_scalar_as_u32, has_any_rows, has_table,
+    sql_text::Cds,
+};
 use crate::sqlite::get_db_conn;
 use ::rcd_enum::rcd_database_type::RcdDatabaseType;
 use chrono::DateTime;
@@ -9,6 +12,7 @@ use tracing::instrument;
 use tracing::{info, trace, warn};
 use rcd_common::crypt;
 use rcd_common::db::*;
+use rcd_common::defaults;
 use rcd_common::host_info::*;
 use rcd_common::user::*;
 use rcd_enum::deletes_from_host_behavior::DeletesFromHostBehavior;
@@ -363,6 +367,117 @@ pub fn change_deletes_to_host_behavior(
     result > 0
 }
 
+/// Returns if changes to the table in the partial database are copied to its data log table
+pub fn get_data_log_table_status(
+    db_name: &str,
+    table_name: &str,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_rcd_conn(config);
+    let db_name = check_database_name_for_contract_format(db_name, &conn);
+    let mut cmd = String::from(
+        "
+        SELECT 
+            USE_DATA_LOG_TABLE
+        FROM
+            CDS_CONTRACTS_TABLES 
+        WHERE
+            DATABASE_NAME = ':db_name'
+        AND
+            TABLE_NAME = ':table_name'
+        ;",
+    );
+    cmd = cmd.replace(":db_name", &db_name);
+    cmd = cmd.replace(":table_name", table_name);
+
+    get_scalar_as_u32(cmd, &conn) > 0
+}
+
+pub fn set_data_log_table_status(
+    db_name: &str,
+    table_name: &str,
+    use_data_log: bool,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_rcd_conn(config);
+    let db_name = check_database_name_for_contract_format(db_name, &conn);
+    let cmd = String::from(
+        "
+        UPDATE CDS_CONTRACTS_TABLES 
+        SET USE_DATA_LOG_TABLE = :use_data_log 
+        WHERE
+            DATABASE_NAME = :db_name
+        AND
+            TABLE_NAME = :table_name
+        ;",
+    );
+
+    let mut statement = conn.prepare(&cmd).unwrap();
+    let result = statement
+        .execute(named_params! {
+            ":use_data_log": use_data_log as u32,
+            ":db_name": db_name,
+            ":table_name": table_name,
+        })
+        .unwrap();
+
+    result > 0
+}
+
+/// Returns how many seconds an action can sit in the table's queue before it expires, or 0 if
+/// queued actions never expire
+pub fn get_pending_action_ttl(db_name: &str, table_name: &str, config: &DbiConfigSqlite) -> u32 {
+    let conn = get_rcd_conn(config);
+    let db_name = check_database_name_for_contract_format(db_name, &conn);
+    let mut cmd = String::from(
+        "
+        SELECT 
+            PENDING_ACTION_TTL_SECONDS
+        FROM
+            CDS_CONTRACTS_TABLES 
+        WHERE
+            DATABASE_NAME = ':db_name'
+        AND
+            TABLE_NAME = ':table_name'
+        ;",
+    );
+    cmd = cmd.replace(":db_name", &db_name);
+    cmd = cmd.replace(":table_name", table_name);
+
+    get_scalar_as_u32(cmd, &conn)
+}
+
+pub fn set_pending_action_ttl(
+    db_name: &str,
+    table_name: &str,
+    ttl_seconds: u32,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_rcd_conn(config);
+    let db_name = check_database_name_for_contract_format(db_name, &conn);
+    let cmd = String::from(
+        "
+        UPDATE CDS_CONTRACTS_TABLES 
+        SET PENDING_ACTION_TTL_SECONDS = :ttl 
+        WHERE
+            DATABASE_NAME = :db_name
+        AND
+            TABLE_NAME = :table_name
+        ;",
+    );
+
+    let mut statement = conn.prepare(&cmd).unwrap();
+    let result = statement
+        .execute(named_params! {
+            ":ttl": ttl_seconds,
+            ":db_name": db_name,
+            ":table_name": table_name,
+        })
+        .unwrap();
+
+    result > 0
+}
+
 pub fn change_host_status_by_id(host_id: &str, status: u32, config: &DbiConfigSqlite) -> bool {
     let conn = get_rcd_conn(config);
 
@@ -378,6 +493,25 @@ pub fn change_host_status_by_id(host_id: &str, status: u32, config: &DbiConfigSq
     result > 0
 }
 
+pub fn update_host_protocol_version(
+    host_id_or_name: &str,
+    protocol_version: u32,
+    config: &DbiConfigSqlite,
+) -> bool {
+    let conn = get_rcd_conn(config);
+
+    let cmd = String::from(
+        "
+    UPDATE CDS_HOSTS SET PROTOCOL_VERSION = :version WHERE HOST_ID = :host OR HOST_NAME = :host",
+    );
+    let mut statement = conn.prepare(&cmd).unwrap();
+    let result = statement
+        .execute(named_params! {":version": protocol_version, ":host": host_id_or_name})
+        .unwrap();
+
+    result > 0
+}
+
 pub fn change_host_status_by_name(host_name: &str, status: u32, config: &DbiConfigSqlite) -> bool {
     let conn = get_rcd_conn(config);
 
@@ -576,6 +710,8 @@ pub fn configure_rcd_db(config: &DbiConfigSqlite) {
         create_contracts_table_table(&db_conn);
         create_contracts_table_table_schemas(&db_conn);
         create_user_tokens_table(&db_conn);
+        create_encrypted_databases_table(&db_conn);
+        create_schema_version_table(&db_conn);
 
         let db_has_role = role::has_role_name(&String::from("SysAdmin"), config).unwrap();
 
@@ -586,9 +722,27 @@ pub fn configure_rcd_db(config: &DbiConfigSqlite) {
     } else {
         trace!("[{}]: dir already exists: {db_path:?}", function_name!());
         trace!("[{}]: this can happen if RCD is running via a proxy", function_name!());
+
+        migrate_rcd_db(config);
     }
 }
 
+/// Brings an rcd database configured by an earlier version of rcd up to the current layout
+fn migrate_rcd_db(config: &DbiConfigSqlite) {
+    let conn = get_rcd_conn(config);
+
+    add_column_if_missing(
+        "CDS_CONTRACTS_TABLES",
+        "PENDING_ACTION_TTL_SECONDS",
+        "INT",
+        &conn,
+    );
+
+    add_column_if_missing("CDS_HOSTS", "PROTOCOL_VERSION", "INT DEFAULT 0", &conn);
+
+    set_schema_version(&conn);
+}
+
 pub fn get_cooperative_hosts(config: &DbiConfigSqlite) -> Vec<CdsHosts> {
     let mut cds_host_infos: Vec<CdsHosts> = Vec::new();
 
@@ -605,7 +759,8 @@ pub fn get_cooperative_hosts(config: &DbiConfigSqlite) -> Vec<CdsHosts> {
         LAST_COMMUNICATION_UTC,
         HTTP_ADDR,
         HTTP_PORT,
-        HOST_STATUS
+        HOST_STATUS,
+        PROTOCOL_VERSION
     FROM
         CDS_HOSTS
     ;",
@@ -622,7 +777,8 @@ pub fn get_cooperative_hosts(config: &DbiConfigSqlite) -> Vec<CdsHosts> {
                        last_comm_utc: String,
                        http_addr: String,
                        http_port: u32,
-                       status: u32|
+                       status: u32,
+                       protocol_version: u32|
      -> Result<CdsHosts> {
         let host = CdsHosts {
             host_id,
@@ -635,6 +791,7 @@ pub fn get_cooperative_hosts(config: &DbiConfigSqlite) -> Vec<CdsHosts> {
             http_addr,
             http_port,
             status: HostStatus::from_u32(status),
+            protocol_version,
         };
         Ok(host)
     };
@@ -652,6 +809,7 @@ pub fn get_cooperative_hosts(config: &DbiConfigSqlite) -> Vec<CdsHosts> {
                 row.get(7).unwrap(),
                 row.get(8).unwrap(),
                 row.get(9).unwrap(),
+                row.get(10).unwrap(),
             )
         })
         .unwrap();
@@ -693,7 +851,8 @@ pub fn get_cds_host_for_part_db(db_name: &str, config: &DbiConfigSqlite) -> Opti
             LAST_COMMUNICATION_UTC,
             HTTP_ADDR,
             HTTP_PORT,
-            HOST_STATUS
+            HOST_STATUS,
+            PROTOCOL_VERSION
         FROM
             CDS_HOSTS
         WHERE
@@ -712,7 +871,8 @@ pub fn get_cds_host_for_part_db(db_name: &str, config: &DbiConfigSqlite) -> Opti
                        last_comm_utc: String,
                        http_addr: String,
                        http_port: u32,
-                       status: u32|
+                       status: u32,
+                       protocol_version: u32|
      -> Result<CdsHosts> {
         let host = CdsHosts {
             host_id,
@@ -725,6 +885,7 @@ pub fn get_cds_host_for_part_db(db_name: &str, config: &DbiConfigSqlite) -> Opti
             http_addr,
             http_port,
             status: HostStatus::from_u32(status),
+            protocol_version,
         };
         Ok(host)
     };
@@ -742,6 +903,7 @@ pub fn get_cds_host_for_part_db(db_name: &str, config: &DbiConfigSqlite) -> Opti
                 row.get(7).unwrap(),
                 row.get(8).unwrap(),
                 row.get(9).unwrap(),
+                row.get(10).unwrap(),
             )
         })
         .unwrap();
@@ -802,6 +964,88 @@ fn create_cds_hosts_table(conn: &Connection) {
         .unwrap();
 }
 
+fn create_encrypted_databases_table(conn: &Connection) {
+    conn.execute(&Cds::text_create_cds_encrypted_databases_table(), [])
+        .unwrap();
+}
+
+fn create_schema_version_table(conn: &Connection) {
+    conn.execute(&Cds::text_create_cds_schema_version_table(), [])
+        .unwrap();
+
+    conn.execute(
+        "INSERT INTO CDS_SCHEMA_VERSION (VERSION) VALUES (:version);",
+        named_params! {":version": defaults::RCD_SCHEMA_VERSION},
+    )
+    .unwrap();
+}
+
+/// Records that the rcd database is at the current layout, for an rcd database configured before
+/// the version was recorded or brought up from an older one
+fn set_schema_version(conn: &Connection) {
+    if !has_table("CDS_SCHEMA_VERSION", conn) {
+        create_schema_version_table(conn);
+        return;
+    }
+
+    conn.execute(
+        "UPDATE CDS_SCHEMA_VERSION SET VERSION = :version WHERE VERSION < :version;",
+        named_params! {":version": defaults::RCD_SCHEMA_VERSION},
+    )
+    .unwrap();
+}
+
+/// Returns the version of the layout of the rcd database, or 0 if it was configured before
+/// the version was recorded
+pub fn get_rcd_schema_version(config: &DbiConfigSqlite) -> u32 {
+    let conn = get_rcd_conn(config);
+
+    if !has_table("CDS_SCHEMA_VERSION", &conn) {
+        return 0;
+    }
+
+    get_scalar_as_u32(String::from("SELECT VERSION FROM CDS_SCHEMA_VERSION"), &conn)
+}
+
+/// Records that the database file `db_name` is encrypted at rest
+pub fn set_database_encrypted(db_name: &str, config: &DbiConfigSqlite) -> bool {
+    let conn = get_rcd_conn(config);
+
+    // rcd dbs configured before encryption was supported won't have this table yet
+    create_encrypted_databases_table(&conn);
+
+    let cmd = "INSERT OR IGNORE INTO CDS_ENCRYPTED_DATABASES (DATABASE_NAME, ENCRYPTED_DATE_UTC) VALUES (:db_name, :now);";
+    let result = conn.execute(
+        cmd,
+        named_params! {":db_name": db_name, ":now": Utc::now().to_rfc3339()},
+    );
+
+    result.is_ok()
+}
+
+/// Removes the record that the database file `db_name` is encrypted at rest
+pub fn clear_database_encrypted(db_name: &str, config: &DbiConfigSqlite) -> bool {
+    let conn = get_rcd_conn(config);
+    create_encrypted_databases_table(&conn);
+
+    let cmd = "DELETE FROM CDS_ENCRYPTED_DATABASES WHERE DATABASE_NAME = :db_name;";
+    let result = conn.execute(cmd, named_params! {":db_name": db_name});
+
+    result.is_ok()
+}
+
+/// Checks if the database file `db_name` was created encrypted at rest
+pub fn is_database_encrypted(db_name: &str, config: &DbiConfigSqlite) -> bool {
+    let conn = get_rcd_conn(config);
+    create_encrypted_databases_table(&conn);
+
+    let mut cmd = String::from(
+        "SELECT COUNT(*) FROM CDS_ENCRYPTED_DATABASES WHERE DATABASE_NAME = ':db_name'",
+    );
+    cmd = cmd.replace(":db_name", db_name);
+    has_any_rows(cmd, &conn)
+}
+
 pub fn get_host_info(config: DbiConfigSqlite) -> Option<HostInfo> {
     let conn = get_rcd_conn(&config);
     let cmd = String::from(
@@ -948,3 +1192,121 @@ fn do_vecs_match<T: PartialEq>(a: &Vec<T>, b: &Vec<T>) -> bool {
     let matching = a.iter().zip(b.iter()).filter(|&(a, b)| a == b).count();
     matching == a.len() && matching == b.len()
 }
+
+#[test]
+fn test_configure_rcd_db_adds_missing_columns() {
+    use super::has_column;
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-migrate-rcd-db");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+    configure_rcd_db(&config);
+
+    // CDS_CONTRACTS_TABLES as it was before PENDING_ACTION_TTL_SECONDS was added
+    let conn = get_rcd_conn(&config);
+    conn.execute("DROP TABLE CDS_CONTRACTS_TABLES;", [])
+        .unwrap();
+    conn.execute(
+        "CREATE TABLE CDS_CONTRACTS_TABLES
+        (
+            DATABASE_ID CHAR(36) NOT NULL,
+            DATABASE_NAME VARCHAR(50) NOT NULL,
+            TABLE_ID CHAR(36) NOT NULL,
+            TABLE_NAME VARCHAR(50) NOT NULL,
+            LOGICAL_STORAGE_POLICY INT,
+            UPDATES_FROM_HOST_BEHAVIOR INT,
+            DELETES_FROM_HOST_BEHAVIOR INT,
+            UPDATES_TO_HOST_BEHAVIOR INT,
+            DELETES_TO_HOST_BEHAVIOR INT,
+            USE_DATA_LOG_TABLE INT
+        );",
+        [],
+    )
+    .unwrap();
+    assert!(!has_column(
+        "CDS_CONTRACTS_TABLES",
+        "PENDING_ACTION_TTL_SECONDS",
+        &conn
+    ));
+
+    configure_rcd_db(&config);
+    assert!(has_column(
+        "CDS_CONTRACTS_TABLES",
+        "PENDING_ACTION_TTL_SECONDS",
+        &conn
+    ));
+
+    // running again against an up to date database changes nothing
+    configure_rcd_db(&config);
+}
+
+#[test]
+fn test_configure_rcd_db_migrates_schema_version_1() {
+    use super::has_column;
+    use std::{env, fs};
+
+    let root = env::temp_dir()
+        .join("RCD_TESTS")
+        .join("rcd-sqlite-unit-test-migrate-schema-version-1");
+    if root.exists() {
+        fs::remove_dir_all(&root).unwrap();
+    }
+    fs::create_dir_all(&root).unwrap();
+
+    let config = DbiConfigSqlite {
+        root_folder: root.to_str().unwrap().to_string(),
+        rcd_db_name: "rcd.db".to_string(),
+    };
+    configure_rcd_db(&config);
+
+    // CDS_HOSTS as it was at schema version 1, before PROTOCOL_VERSION was added
+    let conn = get_rcd_conn(&config);
+    conn.execute("DROP TABLE CDS_HOSTS;", []).unwrap();
+    conn.execute(
+        "CREATE TABLE CDS_HOSTS
+        (
+            HOST_ID CHAR(36) NOT NULL,
+            HOST_NAME VARCHAR(50),
+            TOKEN BLOB,
+            IP4ADDRESS VARCHAR(25),
+            IP6ADDRESS VARCHAR(25),
+            PORT INT,
+            LAST_COMMUNICATION_UTC DATETIME,
+            HOST_STATUS INT,
+            HTTP_ADDR VARCHAR(50),
+            HTTP_PORT INT
+        );",
+        [],
+    )
+    .unwrap();
+    conn.execute("UPDATE CDS_SCHEMA_VERSION SET VERSION = 1;", [])
+        .unwrap();
+    assert_eq!(get_rcd_schema_version(&config), 1);
+
+    configure_rcd_db(&config);
+    assert!(has_column("CDS_HOSTS", "PROTOCOL_VERSION", &conn));
+    assert_eq!(
+        get_rcd_schema_version(&config),
+        defaults::RCD_SCHEMA_VERSION
+    );
+
+    // an rcd database configured before the version was recorded is brought up as well
+    conn.execute("DROP TABLE CDS_SCHEMA_VERSION;", []).unwrap();
+    assert_eq!(get_rcd_schema_version(&config), 0);
+
+    configure_rcd_db(&config);
+    assert_eq!(
+        get_rcd_schema_version(&config),
+        defaults::RCD_SCHEMA_VERSION
+    );
+}
@@ -16,6 +16,19 @@ use rcd_enum::{contract_status::ContractStatus, host_status::HostStatus};
 use rusqlite::{named_params, Connection, Result};
 
 pub fn accept_pending_contract(host_name: &str, config: &DbiConfigSqlite) -> bool {
+    update_pending_contract_status(host_name, ContractStatus::Accepted, config)
+}
+
+pub fn reject_pending_contract(host_name: &str, config: &DbiConfigSqlite) -> bool {
+    update_pending_contract_status(host_name, ContractStatus::Rejected, config)
+}
+
+/// Sets the status of the pending contract from the host, returning `false` if there isn't one
+fn update_pending_contract_status(
+    host_name: &str,
+    contract_status: ContractStatus,
+    config: &DbiConfigSqlite,
+) -> bool {
     let conn = get_rcd_conn(config);
 
     let mut cmd = String::from("SELECT HOST_ID FROM CDS_HOSTS WHERE HOST_NAME = ':hostname'");
@@ -31,11 +44,6 @@ pub fn accept_pending_contract(host_name: &str, config: &DbiConfigSqlite) -> boo
     let has_pending_contract = has_any_rows(cmd, &conn);
 
     if has_pending_contract {
-        // 1 - we need to update the rcd_db record that we are accepting this contract
-        // 2 - then we actually need to create the database with the properties of the
-        // contract
-        // 3 - we need to notify the host that we have accepted the contract
-
         cmd = String::from(
             "SELECT CONTRACT_ID FROM CDS_CONTRACTS WHERE HOST_ID = ':hid' AND CONTRACT_STATUS = 2",
         );
@@ -43,8 +51,13 @@ pub fn accept_pending_contract(host_name: &str, config: &DbiConfigSqlite) -> boo
 
         let cid = get_scalar_as_string(cmd, &conn);
 
-        cmd =
-            String::from("UPDATE CDS_CONTRACTS SET CONTRACT_STATUS = 3 WHERE CONTRACT_ID = ':cid'");
+        cmd = String::from(
+            "UPDATE CDS_CONTRACTS SET CONTRACT_STATUS = :status WHERE CONTRACT_ID = ':cid'",
+        );
+        cmd = cmd.replace(
+            ":status",
+            &ContractStatus::to_u32(contract_status).to_string(),
+        );
         cmd = cmd.replace(":cid", &cid);
 
         let total_count = execute_write(&conn, &cmd);
@@ -258,7 +271,8 @@ pub fn get_contracts_by_status(
             LAST_COMMUNICATION_UTC,
             HTTP_ADDR,
             HTTP_PORT,
-            HOST_STATUS
+            HOST_STATUS,
+            PROTOCOL_VERSION
         FROM
             CDS_HOSTS
         WHERE
@@ -277,7 +291,8 @@ pub fn get_contracts_by_status(
                        last_comm_utc: String,
                        http_addr: String,
                        http_port: u32,
-                       status: u32|
+                       status: u32,
+                       protocol_version: u32|
      -> Result<CdsHosts> {
         let host = CdsHosts {
             host_id,
@@ -290,6 +305,7 @@ pub fn get_contracts_by_status(
             http_addr,
             http_port,
             status: HostStatus::from_u32(status),
+            protocol_version,
         };
         Ok(host)
     };
@@ -310,6 +326,7 @@ pub fn get_contracts_by_status(
                     row.get(7).unwrap(),
                     row.get(8).unwrap(),
                     row.get(9).unwrap(),
+                    row.get(10).unwrap(),
                 )
             })
             .unwrap();
@@ -545,7 +562,8 @@ fn save_contract_table_data(contract: &Contract, conn: &Connection) {
         DELETES_FROM_HOST_BEHAVIOR,
         UPDATES_TO_HOST_BEHAVIOR,
         DELETES_TO_HOST_BEHAVIOR,
-        USE_DATA_LOG_TABLE
+        USE_DATA_LOG_TABLE,
+        PENDING_ACTION_TTL_SECONDS
     )
     VALUES
     (
@@ -558,6 +576,7 @@ fn save_contract_table_data(contract: &Contract, conn: &Connection) {
         1,
         1,
         1,
+        0,
         0
     )
     ;
@@ -6,6 +6,17 @@ pub struct Cds {}
 pub struct Coop {}
 
 impl Cds {
+    /// Returns create table statement for recording the version of the layout of the rcd
+    /// database, see `defaults::RCD_SCHEMA_VERSION`
+    pub fn text_create_cds_schema_version_table() -> String {
+        String::from(
+            "CREATE TABLE IF NOT EXISTS CDS_SCHEMA_VERSION
+        (
+            VERSION INT NOT NULL
+        );",
+        )
+    }
+
     pub fn text_create_user_tokens_table() -> String {
         String::from(
             "
@@ -100,7 +111,8 @@ impl Cds {
             LAST_COMMUNICATION_UTC DATETIME,
             HOST_STATUS INT,
             HTTP_ADDR VARCHAR(50),
-            HTTP_PORT INT
+            HTTP_PORT INT,
+            PROTOCOL_VERSION INT DEFAULT 0
         );",
         )
     }
@@ -138,7 +150,8 @@ impl Cds {
             DELETES_FROM_HOST_BEHAVIOR INT,
             UPDATES_TO_HOST_BEHAVIOR INT,
             DELETES_TO_HOST_BEHAVIOR INT,
-            USE_DATA_LOG_TABLE INT
+            USE_DATA_LOG_TABLE INT,
+            PENDING_ACTION_TTL_SECONDS INT
         );",
         )
     }
@@ -159,6 +172,18 @@ impl Cds {
         );",
         )
     }
+
+    /// Returns create table statement for recording which user and partial databases are
+    /// encrypted at rest. The keys themselves are never stored.
+    pub fn text_create_cds_encrypted_databases_table() -> String {
+        String::from(
+            "CREATE TABLE IF NOT EXISTS CDS_ENCRYPTED_DATABASES
+        (
+            DATABASE_NAME VARCHAR(50) NOT NULL UNIQUE,
+            ENCRYPTED_DATE_UTC DATETIME
+        );",
+        )
+    }
 }
 
 impl Coop {
@@ -178,6 +203,26 @@ impl Coop {
         )
     }
 
+    /// Returns create table statement for tracking the writes we sent to participants that they
+    /// have queued for review
+    pub fn text_create_pending_writes_table() -> String {
+        String::from(
+            "
+        CREATE TABLE IF NOT EXISTS COOP_PENDING_WRITES
+        (
+            TABLE_NAME VARCHAR(500) NOT NULL,
+            INTERNAL_PARTICIPANT_ID CHAR(36) NOT NULL,
+            PARTICIPANT_ALIAS VARCHAR(50) NOT NULL,
+            PENDING_ACTION_ID INT NOT NULL,
+            STATEMENT TEXT NOT NULL,
+            WHERE_CLAUSE TEXT,
+            ACTION VARCHAR(10) NOT NULL,
+            REQUESTED_TS_UTC DATETIME NOT NULL
+        )
+        ;",
+        )
+    }
+
     pub fn text_create_data_log_table() -> String {
         String::from(
             "
@@ -209,7 +254,36 @@ impl Coop {
             "UPDATE 
                 :table_name
             SET 
-                HASH = :hash
+                HASH = :hash,
+                IS_OUT_OF_SYNC = 0
+            WHERE 
+                ROW_ID = :row 
+            AND 
+                INTERNAL_PARTICIPANT_ID = :pid
+        ;",
+        )
+    }
+
+    pub fn text_mark_row_metadata_out_of_sync() -> String {
+        String::from(
+            "UPDATE 
+                :table_name
+            SET 
+                IS_OUT_OF_SYNC = 1
+            WHERE 
+                ROW_ID = :row 
+            AND 
+                INTERNAL_PARTICIPANT_ID = :pid
+        ;",
+        )
+    }
+
+    pub fn text_mark_row_metadata_remote_deleted() -> String {
+        String::from(
+            "UPDATE 
+                :table_name
+            SET 
+                IS_REMOTE_DELETED = 1
             WHERE 
                 ROW_ID = :row 
             AND 
@@ -243,12 +317,25 @@ impl Coop {
         (
             ROW_ID INT,
             HASH BLOB,
-            INTERNAL_PARTICIPANT_ID CHAR(36)
+            INTERNAL_PARTICIPANT_ID CHAR(36),
+            IS_OUT_OF_SYNC INT,
+            IS_REMOTE_DELETED INT
         );
         ",
         )
     }
 
+    /// Returns create table statement for storing the `ROW_HASH_VERSION` the hashes in a partial
+    /// database's metadata tables were calculated with
+    pub fn text_create_row_hash_version_table() -> String {
+        String::from(
+            "CREATE TABLE IF NOT EXISTS COOP_ROW_HASH_VERSION
+        (
+            VERSION INT NOT NULL
+        );",
+        )
+    }
+
     /// Returns create table statement for storing the database id when we 1st enable cooperative features
     pub fn text_create_data_host_table() -> String {
         String::from(
@@ -338,6 +425,18 @@ impl Coop {
         )
     }
 
+    /// Returns create table statement for storing the columns of a `Shared` table whose values are
+    /// kept at the participant rather than at the host
+    pub fn text_create_shared_columns_table() -> String {
+        String::from(
+            "CREATE TABLE IF NOT EXISTS COOP_SHARED_COLUMNS
+        (
+            TABLENAME VARCHAR(255) NOT NULL,
+            COLUMNNAME VARCHAR(255) NOT NULL
+        );",
+        )
+    }
+
     pub fn text_get_logical_storage_policy_tables() -> String {
         String::from(
             "
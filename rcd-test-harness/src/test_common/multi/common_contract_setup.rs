@@ -1,9 +1,64 @@
 use crate::{get_rcd_client, CoreTestConfig, RcdClientConfig, ServiceAddr};
+use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
+use rcd_enum::remote_delete_behavior::RemoteDeleteBehavior;
 use tracing::{debug, trace, warn};
 
 /// has a main and participant establish a new contract and verifies that the main has read/write
 /// to the participant
 pub async fn main_and_participant_setup(config: CoreTestConfig) -> bool {
+    let table = TableSetup {
+        create_table_statement: "CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT, Name TEXT);",
+        policy: LogicalStoragePolicy::ParticpantOwned,
+        participant_owned_columns: &[],
+    };
+
+    setup(config, table, RemoteDeleteBehavior::Ignore).await
+}
+
+/// the same as `main_and_participant_setup`, but with the contract generated with the supplied
+/// behavior for how the host handles rows deleted at the participant
+pub async fn main_and_participant_setup_with_delete_behavior(
+    config: CoreTestConfig,
+    behavior: RemoteDeleteBehavior,
+) -> bool {
+    let table = TableSetup {
+        create_table_statement: "CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT, Name TEXT);",
+        policy: LogicalStoragePolicy::ParticpantOwned,
+        participant_owned_columns: &[],
+    };
+
+    setup(config, table, behavior).await
+}
+
+/// the same as `main_and_participant_setup`, but with `EMPLOYEE` set to the supplied policy. The
+/// table is keyed on `Id` so that a shared table's rows at the host and participant can be matched
+pub async fn main_and_participant_setup_with_policy(
+    config: CoreTestConfig,
+    policy: LogicalStoragePolicy,
+    participant_owned_columns: &[&str],
+) -> bool {
+    let table = TableSetup {
+        create_table_statement:
+            "CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT PRIMARY KEY, Name TEXT);",
+        policy,
+        participant_owned_columns,
+    };
+
+    setup(config, table, RemoteDeleteBehavior::Ignore).await
+}
+
+/// how the `EMPLOYEE` table is created at the main before the contract is generated
+struct TableSetup<'a> {
+    create_table_statement: &'a str,
+    policy: LogicalStoragePolicy,
+    participant_owned_columns: &'a [&'a str],
+}
+
+async fn setup(
+    config: CoreTestConfig,
+    table: TableSetup<'_>,
+    behavior: RemoteDeleteBehavior,
+) -> bool {
     debug!("main_and_participant_setup: {config:?}");
 
     let mc = config.main_client.clone();
@@ -13,7 +68,8 @@ pub async fn main_and_participant_setup(config: CoreTestConfig) -> bool {
     let contract = config.contract_desc.as_ref().unwrap().clone();
     let participant_id = config.participant_id;
 
-    let client_sent_contract = client(&db, &mc, &pdb, &contract, participant_id).await;
+    let client_sent_contract =
+        send_contract(&db, &mc, &pdb, &contract, participant_id, &table, behavior).await;
 
     assert!(client_sent_contract);
 
@@ -28,16 +84,43 @@ pub async fn main_and_participant_setup(config: CoreTestConfig) -> bool {
     true
 }
 
+/// has the main create the `EMPLOYEE` table and send a contract for it to the participant, without
+/// the participant accepting it
 pub async fn client(
     db_name: &str,
     config: &RcdClientConfig,
     participant_db_addr: &ServiceAddr,
     contract_desc: &str,
     participant_id: Option<String>,
+) -> bool {
+    let table = TableSetup {
+        create_table_statement: "CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT, Name TEXT);",
+        policy: LogicalStoragePolicy::ParticpantOwned,
+        participant_owned_columns: &[],
+    };
+
+    send_contract(
+        db_name,
+        config,
+        participant_db_addr,
+        contract_desc,
+        participant_id,
+        &table,
+        RemoteDeleteBehavior::Ignore,
+    )
+    .await
+}
+
+async fn send_contract(
+    db_name: &str,
+    config: &RcdClientConfig,
+    participant_db_addr: &ServiceAddr,
+    contract_desc: &str,
+    participant_id: Option<String>,
+    table: &TableSetup<'_>,
+    behavior: RemoteDeleteBehavior,
 ) -> bool {
     use rcd_enum::database_type::DatabaseType;
-    use rcd_enum::logical_storage_policy::LogicalStoragePolicy;
-    use rcd_enum::remote_delete_behavior::RemoteDeleteBehavior;
 
     let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
 
@@ -50,22 +133,22 @@ pub async fn client(
         .await
         .unwrap();
 
-    let create_table_statement =
-        String::from("CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT, Name TEXT);");
-
     client
-        .execute_write_at_host(db_name, &create_table_statement, database_type, "")
+        .execute_write_at_host(db_name, table.create_table_statement, database_type, "")
         .await
         .unwrap();
 
-    let logical_storage_policy = LogicalStoragePolicy::ParticpantOwned;
-
-    client
-        .set_logical_storage_policy(db_name, "EMPLOYEE", logical_storage_policy)
-        .await
-        .unwrap();
-
-    let behavior = RemoteDeleteBehavior::Ignore;
+    if table.policy == LogicalStoragePolicy::Shared {
+        client
+            .set_shared_logical_storage_policy(db_name, "EMPLOYEE", table.participant_owned_columns)
+            .await
+            .unwrap();
+    } else {
+        client
+            .set_logical_storage_policy(db_name, "EMPLOYEE", table.policy)
+            .await
+            .unwrap();
+    }
 
     client
         .generate_contract(db_name, "tester", contract_desc, behavior)
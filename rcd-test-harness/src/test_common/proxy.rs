@@ -55,6 +55,13 @@ pub fn configure_proxy_for_test(test_name: &str, proxy_type: RcdProxyTestType) -
                 proxy_http_addr: "127.0.0.1".to_string(),
                 proxy_http_port: proxy_http_port as usize,
                 root_dir: root_dir,
+                mysql_config: None,
+                jwt_config: None,
+                core_cache_capacity: 16,
+                core_cache_idle_timeout_in_seconds: 600,
+                default_quota: None,
+                admin_logins: Vec::new(),
+                tenant_delete_grace_period_in_seconds: 604800,
             };
 
             let proxy = RcdProxy::get_proxy_with_config(settings);
@@ -93,6 +100,13 @@ pub fn configure_proxy_for_test(test_name: &str, proxy_type: RcdProxyTestType) -
                 proxy_http_addr: "127.0.0.1".to_string(),
                 proxy_http_port: port as usize,
                 root_dir: root_dir,
+                mysql_config: None,
+                jwt_config: None,
+                core_cache_capacity: 16,
+                core_cache_idle_timeout_in_seconds: 600,
+                default_quota: None,
+                admin_logins: Vec::new(),
+                tenant_delete_grace_period_in_seconds: 604800,
             };
 
             let proxy = RcdProxy::get_proxy_with_config(settings);
@@ -3,3 +3,6 @@ mod auth_for_token;
 
 #[path = "client_service_tests-auth/revoke_token/mod.rs"]
 mod revoke_token;
+
+#[path = "client_service_tests-auth/token_profile/mod.rs"]
+mod token_profile;
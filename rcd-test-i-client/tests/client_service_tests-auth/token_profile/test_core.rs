@@ -0,0 +1,76 @@
+use rcd_test_harness::{CoreTestConfig, RcdClientConfig};
+
+pub fn test_core(config: CoreTestConfig) {
+    let mc = config.main_client.clone();
+    let db = config.test_db_name;
+    let response = client(&db, &mc);
+    assert!(response);
+}
+
+#[cfg(test)]
+#[tokio::main]
+async fn client(db_name: &str, config: &RcdClientConfig) -> bool {
+    use rcd_client::profile::RcdClientProfile;
+    use rcd_client::token_cache::TokenCache;
+    use rcd_client::RcdClient;
+    use std::{env, fs};
+
+    let (user_name, pw) = match &config.auth {
+        Some(auth) => (auth.un.clone(), auth.pw.clone()),
+        None => (String::from("tester"), String::from("123456")),
+    };
+
+    let profile = RcdClientProfile {
+        client_type: config.client_type.clone(),
+        grpc_addr_port: config.addr.to_full_string_with_http(),
+        http_addr: config.addr.ip4_addr.clone(),
+        http_port: config.addr.port,
+        user_name,
+        pw,
+        timeout_in_seconds: 60,
+        host_id: config.host_id.clone(),
+        use_token: true,
+    };
+
+    // a token can't be issued until the instance has host info
+    let mut client = rcd_test_harness::get_rcd_client(config).await;
+    client.generate_host_info("tester").await.unwrap();
+
+    let cache_path = env::temp_dir().join(format!("{db_name}.tokens.json"));
+    let _ = fs::remove_file(&cache_path);
+    let cache = TokenCache::new(cache_path, "test");
+
+    // the first client logs in on its first call and caches the token it was issued
+    let mut first = RcdClient::new_from_profile(&profile, Some(cache.clone())).await;
+    first.create_user_database(db_name).await.unwrap();
+
+    assert!(cache.load().is_some());
+
+    // the second client picks up the cached token instead of logging in, and then revokes it
+    let mut second = RcdClient::new_from_profile(&profile, Some(cache.clone())).await;
+    let revoke = second.revoke_token().await.unwrap();
+    assert!(revoke.is_successful);
+    assert!(cache.load().is_none());
+
+    // the first client still holds the revoked token, so it should log in again and retry
+    let databases = first.get_databases().await.unwrap();
+
+    let has_database = databases
+        .databases
+        .iter()
+        .any(|db| db.database_name == db_name);
+
+    // refreshing while the cached token is still valid should hand out a token in its place
+    let refresh = first.auth_for_token().await.unwrap();
+    assert!(refresh.is_successful);
+    assert!(!refresh.jwt.is_empty());
+    assert_eq!(cache.load().unwrap().jwt, refresh.jwt);
+
+    let databases = first.get_databases().await.unwrap();
+    let has_database_after_refresh = databases
+        .databases
+        .iter()
+        .any(|db| db.database_name == db_name);
+
+    has_database && has_database_after_refresh && cache.load().is_some()
+}
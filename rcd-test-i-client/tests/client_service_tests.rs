@@ -1,6 +1,9 @@
 #[path = "client_service_tests/create_user_database/mod.rs"]
 mod create_user_database;
 
+#[path = "client_service_tests/create_encrypted_user_database/mod.rs"]
+mod create_encrypted_user_database;
+
 #[path = "client_service_tests/create_db_enable_coop_read_write/mod.rs"]
 mod create_db_enable_coop_read_write;
 
@@ -13,6 +16,9 @@ mod get_set_logical_storage_policy;
 #[path = "client_service_tests/get_settings/mod.rs"]
 mod get_settings;
 
+#[path = "client_service_tests/get_versions/mod.rs"]
+mod get_versions;
+
 #[path = "client_service_tests/has_table/mod.rs"]
 mod has_table;
 
@@ -0,0 +1,65 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::CoreTestConfig;
+use rcd_test_harness::RcdClientConfig;
+use tracing::debug;
+
+pub fn test_core(config: CoreTestConfig) {
+    let mc = config.main_client.clone();
+    let db = config.test_db_name;
+
+    let response = client(&db, &mc);
+    debug!("create_encrypted_user_database: got: {response}");
+
+    assert!(response);
+}
+
+#[tokio::main]
+async fn client(db_name: &str, client: &RcdClientConfig) -> bool {
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+    let mut client = rcd_test_harness::get_rcd_client(client).await;
+
+    let is_created = client
+        .create_user_database_with_key(db_name, Some("correct horse battery staple"))
+        .await
+        .unwrap();
+    assert!(is_created);
+
+    client
+        .execute_write_at_host(
+            db_name,
+            "CREATE TABLE IF NOT EXISTS EMPLOYEE (Id INT, Name TEXT);",
+            database_type,
+            "",
+        )
+        .await
+        .unwrap();
+
+    client
+        .execute_write_at_host(
+            db_name,
+            "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 999, 'Rando');",
+            database_type,
+            "",
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .execute_read_at_host(db_name, "SELECT Name FROM EMPLOYEE", database_type)
+        .await
+        .unwrap();
+    assert_eq!(result.rows.len(), 1);
+
+    let wrong_key = client
+        .unlock_database(db_name, "the wrong key")
+        .await
+        .unwrap();
+    assert!(!wrong_key.is_successful);
+
+    let right_key = client
+        .unlock_database(db_name, "correct horse battery staple")
+        .await
+        .unwrap();
+
+    right_key.is_successful
+}
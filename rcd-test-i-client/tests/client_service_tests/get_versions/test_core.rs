@@ -0,0 +1,19 @@
+use rcd_common::defaults;
+use rcd_test_harness::{CoreTestConfig, RcdClientConfig};
+
+pub fn test_core(config: CoreTestConfig) {
+    let mc = config.main_client;
+    client(&mc);
+}
+
+#[tokio::main]
+async fn client(config: &RcdClientConfig) {
+    let mut client = rcd_test_harness::get_rcd_client(config).await;
+    let versions = client.get_versions().await.unwrap();
+
+    assert_eq!(versions.rcdx, rcdx::rcd_service::VERSION);
+    assert_eq!(versions.rcd_core, rcd_core::VERSION);
+    assert_eq!(versions.rcdproto, rcdproto::VERSION);
+    assert_eq!(versions.protocol_version, rcdproto::PROTOCOL_VERSION);
+    assert_eq!(versions.rcd_schema_version, defaults::RCD_SCHEMA_VERSION);
+}
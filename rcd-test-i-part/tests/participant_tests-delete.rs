@@ -1,2 +1,17 @@
 #[path = "participant_tests-delete/validate_delete_behaviors/mod.rs"]
 mod validate_delete_behaviors;
+
+#[path = "participant_tests-delete/delete_multiple_rows/mod.rs"]
+mod delete_multiple_rows;
+
+#[path = "participant_tests-delete/delete_queue_with_log/mod.rs"]
+mod delete_queue_with_log;
+
+#[path = "participant_tests-delete/remote_delete_ignored/mod.rs"]
+mod remote_delete_ignored;
+
+#[path = "participant_tests-delete/remote_delete_auto_delete/mod.rs"]
+mod remote_delete_auto_delete;
+
+#[path = "participant_tests-delete/remote_delete_update_status_only/mod.rs"]
+mod remote_delete_update_status_only;
@@ -0,0 +1,15 @@
+use rcd_test_harness::test_common::multi::runner::{RunnerConfig, TestRunner};
+
+use super::test_core::test_core;
+
+#[test]
+fn test() {
+    let test_name = "delete_multiple_rows_http";
+    let config = RunnerConfig {
+        test_name: test_name.to_string(),
+        contract_desc: Some("".to_string()),
+        use_internal_logging: false,
+    };
+
+    TestRunner::run_http_test_multi(config, test_core);
+}
@@ -0,0 +1,80 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    for id in [1000, 1001, 1002] {
+        let statement = format!("INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( {id}, 'MULTI');");
+        let insert_is_successful = mc
+            .execute_cooperative_write_at_host(&db_name, &statement, "participant", "")
+            .await
+            .unwrap();
+
+        assert!(insert_is_successful);
+    }
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'ASDF'")
+        .await
+        .unwrap();
+
+    let remaining_row_id = *row_ids.first().unwrap();
+
+    // the where clause matches every row we just added
+    let delete_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "DELETE FROM EMPLOYEE WHERE NAME = 'MULTI'",
+            "participant",
+            "NAME = 'MULTI'",
+        )
+        .await
+        .unwrap();
+
+    assert!(delete_is_successful);
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'MULTI'")
+        .await
+        .unwrap();
+
+    assert!(row_ids.is_empty());
+
+    // the host should only have metadata left for the row from setup
+    let read_result = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT ROW_ID FROM EMPLOYEE_COOP_METADATA",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    let host_row_ids: Vec<String> = read_result
+        .rows
+        .iter()
+        .map(|row| String::from_utf8(row.values[0].value.clone()).unwrap())
+        .collect();
+
+    assert_eq!(host_row_ids, vec![remaining_row_id.to_string()]);
+}
@@ -0,0 +1,100 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_enum::deletes_from_host_behavior::DeletesFromHostBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_deletes_from_host_behavior(
+            &db_name,
+            "EMPLOYEE",
+            DeletesFromHostBehavior::QueueForReviewAndLog,
+        )
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    let delete_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "DELETE FROM EMPLOYEE WHERE ID = 999",
+            "participant",
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(delete_is_successful);
+
+    // the row is still there until the participant accepts the delete
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "ID = 999")
+        .await
+        .unwrap();
+
+    assert_eq!(row_ids.len(), 1);
+
+    let pending_writes = mc.get_pending_writes_at_host(&db_name).await.unwrap();
+    trace!("{pending_writes:?}");
+
+    assert_eq!(pending_writes.pending_writes.len(), 1);
+    assert_eq!(
+        pending_writes.pending_writes.first().unwrap().action,
+        "DELETE"
+    );
+
+    let pending_deletes = pc
+        .get_pending_actions_at_participant(&db_name, "EMPLOYEE", "DELETE")
+        .await
+        .unwrap();
+
+    let pending_delete = pending_deletes.pending_statements.first().unwrap();
+
+    let accept_result = pc
+        .accept_pending_action_at_participant(&db_name, "EMPLOYEE", pending_delete.row_id)
+        .await
+        .unwrap();
+
+    assert!(accept_result.is_successful);
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "ID = 999")
+        .await
+        .unwrap();
+
+    assert!(row_ids.is_empty());
+
+    // the host has dropped both the pending write and its metadata for the row
+    let pending_writes = mc.get_pending_writes_at_host(&db_name).await.unwrap();
+    assert!(pending_writes.pending_writes.is_empty());
+
+    let read_result = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT ROW_ID FROM EMPLOYEE_COOP_METADATA",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    assert!(read_result.rows.is_empty());
+}
@@ -0,0 +1,26 @@
+use super::test_core::test_core;
+use rcd_test_harness::test_common::multi::runner::{RunnerConfig, TestRunner};
+
+#[test]
+fn test() {
+    let test_name = "remote_delete_auto_delete_grpc";
+    let config = RunnerConfig {
+        test_name: test_name.to_string(),
+        contract_desc: Some("".to_string()),
+        use_internal_logging: false,
+    };
+
+    TestRunner::run_grpc_test_multi(config, test_core);
+}
+
+#[test]
+fn proxy() {
+    let test_name = "remote_delete_auto_delete_grpc-proxy";
+    let config = RunnerConfig {
+        test_name: test_name.to_string(),
+        contract_desc: Some("".to_string()),
+        use_internal_logging: false,
+    };
+
+    TestRunner::run_grpc_proxy_test_multi(config, test_core);
+}
@@ -0,0 +1,77 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_enum::deletes_to_host_behavior::DeletesToHostBehavior;
+use rcd_enum::remote_delete_behavior::RemoteDeleteBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup_with_delete_behavior,
+    CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup_with_delete_behavior(
+        config.clone(),
+        RemoteDeleteBehavior::AutoDelete,
+    )
+    .await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_deletes_to_host_behavior(
+            &db_name,
+            "EMPLOYEE",
+            DeletesToHostBehavior::SendNotification,
+        )
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    // the participant deletes the row the setup inserted, and tells the host about it
+    let delete_is_successful = pc
+        .execute_write_at_participant(
+            &db_name,
+            "DELETE FROM EMPLOYEE WHERE ID = 999",
+            database_type,
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(delete_is_successful);
+
+    let metadata = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT IS_REMOTE_DELETED FROM EMPLOYEE_COOP_METADATA",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{metadata:?}");
+
+    // the host drops its reference to the row
+    assert!(metadata.rows.is_empty());
+
+    let read_result = mc
+        .execute_read_at_host(&db_name, "SELECT NAME FROM EMPLOYEE", database_type)
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    assert!(read_result.rows.is_empty());
+}
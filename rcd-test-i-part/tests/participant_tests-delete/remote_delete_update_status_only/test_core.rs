@@ -0,0 +1,106 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_enum::deletes_to_host_behavior::DeletesToHostBehavior;
+use rcd_enum::remote_delete_behavior::RemoteDeleteBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup_with_delete_behavior,
+    CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup_with_delete_behavior(
+        config.clone(),
+        RemoteDeleteBehavior::UpdateStatusOnly,
+    )
+    .await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_deletes_to_host_behavior(
+            &db_name,
+            "EMPLOYEE",
+            DeletesToHostBehavior::SendNotification,
+        )
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    // the participant deletes the row the setup inserted, and tells the host about it
+    let delete_is_successful = pc
+        .execute_write_at_participant(
+            &db_name,
+            "DELETE FROM EMPLOYEE WHERE ID = 999",
+            database_type,
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(delete_is_successful);
+
+    let metadata = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT IS_REMOTE_DELETED FROM EMPLOYEE_COOP_METADATA",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{metadata:?}");
+
+    // the host keeps a tombstone for the row
+    assert_eq!(metadata.rows.len(), 1);
+    assert_eq!(metadata.rows[0].values[0].value, "1".as_bytes().to_vec());
+
+    // which a read reports back without values, flagged as deleted at the participant
+    let read_result = mc
+        .execute_read_at_host(&db_name, "SELECT NAME FROM EMPLOYEE", database_type)
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    assert_eq!(read_result.number_of_rows_affected, 1);
+    assert_eq!(read_result.rows.len(), 1);
+
+    let tombstone = read_result.rows.first().unwrap();
+    assert_eq!(tombstone.values.len(), 1);
+    assert!(tombstone.values[0].is_null_value);
+    let remote_metadata = tombstone.remote_metadata.as_ref().unwrap();
+    assert!(remote_metadata.is_remote_deleted);
+
+    // the tombstone isn't run through the statement's filters, so a filtered read reports it
+    // after its own rows as well
+    let read_result = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT NAME FROM EMPLOYEE WHERE ID = 999",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    assert_eq!(read_result.rows.len(), 1);
+    let remote_metadata = read_result.rows[0].remote_metadata.as_ref().unwrap();
+    assert!(remote_metadata.is_remote_deleted);
+    assert!(read_result
+        .result_message
+        .contains("rows have been deleted at participants"));
+}
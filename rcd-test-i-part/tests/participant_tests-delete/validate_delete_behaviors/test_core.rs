@@ -81,16 +81,16 @@ async fn go(config: CoreTestConfig) {
     .await
     .unwrap();
 
-    // at the host, we should have zero rows, but we get 1 row back
+    // the host still references the row, but the participant no longer has it, so we should
+    // get zero rows back
     let cmd = String::from("SELECT NAME FROM EMPLOYEE WHERE Id = 999");
     let read_result = mc
         .execute_read_at_host(&db_name, &cmd, DatabaseType::to_u32(DatabaseType::Sqlite))
         .await;
-    // we should expect to get zero rows back, but we don't
 
     trace!("{read_result:?}");
 
-    assert!(!read_result.unwrap().rows.is_empty());
+    assert!(read_result.unwrap().rows.is_empty());
 
     // lets check a normal situation, reset and add a record
     let cmd = "INSERT INTO EMPLOYEE (ID, NAME) VALUES (999, 'TESTER')";
@@ -136,19 +136,19 @@ async fn go(config: CoreTestConfig) {
         .await
         .unwrap();
 
-    // delete should fail
+    // the delete is queued at the participant, and the host keeps it as a pending write
 
     let cmd = String::from("DELETE FROM EMPLOYEE WHERE Id = 999");
     let delete_statement = "DELETE FROM EMPLOYEE WHERE Id = 999";
     let mut has_statement = false;
     let mut statement_row_id = 0;
 
-    let should_fail = mc
+    let is_queued = mc
         .execute_cooperative_write_at_host(&db_name, &cmd, "participant", "Id = 999")
         .await
         .unwrap();
 
-    assert!(!should_fail);
+    assert!(is_queued);
 
     // participant gets and approves pending delete
     let pending_deletes = pc
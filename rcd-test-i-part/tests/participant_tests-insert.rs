@@ -0,0 +1,2 @@
+#[path = "participant_tests-insert/insert_at_participant/mod.rs"]
+mod insert_at_participant;
@@ -0,0 +1,73 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let mc = config.main_client.clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+
+    // the participant adds a row on its own, which the host should be told about
+    let statement =
+        String::from("INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1000, 'PARTICIPANT' );");
+    let insert_ok = pc
+        .execute_write_at_participant(
+            &db_name,
+            &statement,
+            DatabaseType::to_u32(DatabaseType::Sqlite),
+            "",
+        )
+        .await
+        .unwrap();
+
+    assert!(insert_ok);
+
+    // the host should now be able to read the row back from the participant
+    let cmd = String::from("SELECT NAME FROM EMPLOYEE WHERE Id = 1000");
+    let results = mc
+        .execute_read_at_host(&db_name, &cmd, DatabaseType::to_u32(DatabaseType::Sqlite))
+        .await
+        .unwrap();
+
+    let has_row = results
+        .rows
+        .iter()
+        .any(|row| row.values[0].value == "PARTICIPANT".as_bytes().to_vec());
+
+    trace!("{results:?}");
+    assert!(has_row);
+
+    // and the data hash the host saved should match the participant's
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'PARTICIPANT'")
+        .await
+        .unwrap();
+
+    let row_id = *row_ids.first().unwrap();
+
+    let participant_data_hash = pc
+        .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let host_data_hash = mc
+        .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    assert_eq!(participant_data_hash, host_data_hash);
+}
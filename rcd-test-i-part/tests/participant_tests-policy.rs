@@ -0,0 +1,4 @@
+#[path = "participant_tests-policy/mirror_table/mod.rs"]
+mod mirror_table;
+#[path = "participant_tests-policy/shared_table/mod.rs"]
+mod shared_table;
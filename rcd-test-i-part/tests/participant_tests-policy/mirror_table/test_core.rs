@@ -0,0 +1,119 @@
+use rcd_enum::{database_type::DatabaseType, logical_storage_policy::LogicalStoragePolicy};
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup_with_policy,
+    CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result =
+        main_and_participant_setup_with_policy(config.clone(), LogicalStoragePolicy::Mirror, &[])
+            .await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let insert_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1000, 'MIRROR' );",
+            "participant",
+            "",
+        )
+        .await
+        .unwrap();
+
+    assert!(insert_is_successful);
+
+    // both sides should hold the row
+    let cmd = "SELECT NAME FROM EMPLOYEE WHERE Id = 1000";
+
+    let host_results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{host_results:?}");
+
+    assert_eq!(host_results.rows.len(), 1);
+    assert_eq!(
+        host_results.rows[0].values[0].value,
+        "MIRROR".as_bytes().to_vec()
+    );
+    assert!(host_results.result_message.is_empty());
+
+    let participant_results = pc
+        .execute_read_at_participant(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{participant_results:?}");
+
+    assert_eq!(participant_results.rows.len(), 1);
+    assert_eq!(
+        participant_results.rows[0].values[0].value,
+        "MIRROR".as_bytes().to_vec()
+    );
+
+    // an update from the host is made to both copies, which should still agree
+    let update_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'MIRRORED' WHERE Id = 1000",
+            "participant",
+            "Id = 1000",
+        )
+        .await
+        .unwrap();
+
+    assert!(update_is_successful);
+
+    let host_results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        host_results.rows[0].values[0].value,
+        "MIRRORED".as_bytes().to_vec()
+    );
+    assert!(host_results.result_message.is_empty());
+
+    // changing only the host's copy leaves it out of step with the participant's, so the next
+    // read replaces it with the participant's rows
+    let local_update_is_successful = mc
+        .execute_write_at_host(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'HOST ONLY' WHERE Id = 1000",
+            database_type,
+            "",
+        )
+        .await
+        .unwrap();
+
+    assert!(local_update_is_successful);
+
+    let host_results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{host_results:?}");
+
+    assert_eq!(
+        host_results.rows[0].values[0].value,
+        "MIRRORED".as_bytes().to_vec()
+    );
+    assert!(host_results.result_message.contains("was replaced"));
+}
@@ -0,0 +1,97 @@
+use rcd_enum::{database_type::DatabaseType, logical_storage_policy::LogicalStoragePolicy};
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup_with_policy,
+    CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    // the host keeps each employee's id, and the participant keeps their name
+    let result = main_and_participant_setup_with_policy(
+        config.clone(),
+        LogicalStoragePolicy::Shared,
+        &["Name"],
+    )
+    .await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let insert_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1000, 'SHARED' );",
+            "participant",
+            "",
+        )
+        .await
+        .unwrap();
+
+    assert!(insert_is_successful);
+
+    // a read at the host puts the row back together from both sides
+    let cmd = "SELECT Id, Name FROM EMPLOYEE WHERE Id = 1000";
+
+    let host_results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{host_results:?}");
+
+    assert_eq!(host_results.rows.len(), 1);
+    assert_eq!(
+        host_results.rows[0].values[0].value,
+        "1000".as_bytes().to_vec()
+    );
+    assert_eq!(
+        host_results.rows[0].values[1].value,
+        "SHARED".as_bytes().to_vec()
+    );
+
+    let participant_results = pc
+        .execute_read_at_participant(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{participant_results:?}");
+
+    assert_eq!(participant_results.rows.len(), 1);
+    assert_eq!(
+        participant_results.rows[0].values[1].value,
+        "SHARED".as_bytes().to_vec()
+    );
+
+    // reading the host's own copy shows it holds the row, but not the participant's column
+    let policy_is_set = mc
+        .set_logical_storage_policy(&db_name, "EMPLOYEE", LogicalStoragePolicy::HostOnly)
+        .await
+        .unwrap();
+
+    assert!(policy_is_set);
+
+    let host_only_results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{host_only_results:?}");
+
+    assert_eq!(host_only_results.rows.len(), 1);
+    assert_eq!(
+        host_only_results.rows[0].values[0].value,
+        "1000".as_bytes().to_vec()
+    );
+    assert!(host_only_results.rows[0].values[1].is_null_value);
+}
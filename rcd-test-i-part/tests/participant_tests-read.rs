@@ -0,0 +1,4 @@
+#[path = "participant_tests-read/read_with_filter/mod.rs"]
+mod read_with_filter;
+#[path = "participant_tests-read/read_out_of_sync_row/mod.rs"]
+mod read_out_of_sync_row;
@@ -0,0 +1,103 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_enum::updates_to_host_behavior::UpdatesToHostBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let cmd = "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1000, 'IN SYNC');";
+    let insert_ok = mc
+        .execute_cooperative_write_at_host(&db_name, cmd, "participant", "")
+        .await
+        .unwrap();
+
+    assert!(insert_ok);
+
+    // a row that ends up with the same values as the changed row, but is in sync
+    let cmd = "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1001, 'CHANGED');";
+    let insert_ok = mc
+        .execute_cooperative_write_at_host(&db_name, cmd, "participant", "")
+        .await
+        .unwrap();
+
+    assert!(insert_ok);
+
+    // the participant changes a row without telling the host, so the host's hash goes stale
+    let behavior_is_changed = pc
+        .change_updates_to_host_behavior(&db_name, "EMPLOYEE", UpdatesToHostBehavior::DoNothing)
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    let update_is_successful = pc
+        .execute_write_at_participant(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'CHANGED' WHERE ID = 999",
+            database_type,
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(update_is_successful);
+
+    // only the projected column comes back, but the row still says it is out of sync
+    let cmd = "SELECT Name FROM EMPLOYEE ORDER BY Id";
+    let results = mc
+        .execute_read_at_host(&db_name, cmd, database_type)
+        .await
+        .unwrap();
+
+    trace!("{results:?}");
+
+    assert_eq!(results.rows.len(), 3);
+
+    let changed = &results.rows[0];
+    assert_eq!(changed.values[0].value, "CHANGED".as_bytes().to_vec());
+    assert!(
+        changed
+            .remote_metadata
+            .as_ref()
+            .unwrap()
+            .is_hash_out_of_sync_with_host
+    );
+
+    let in_sync = &results.rows[1];
+    assert_eq!(in_sync.values[0].value, "IN SYNC".as_bytes().to_vec());
+    assert!(
+        !in_sync
+            .remote_metadata
+            .as_ref()
+            .unwrap()
+            .is_hash_out_of_sync_with_host
+    );
+
+    // the rows are told apart by their row keys, not their values
+    let same_values = &results.rows[2];
+    assert_eq!(same_values.values[0].value, "CHANGED".as_bytes().to_vec());
+    assert!(
+        !same_values
+            .remote_metadata
+            .as_ref()
+            .unwrap()
+            .is_hash_out_of_sync_with_host
+    );
+}
@@ -0,0 +1,58 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let mc = config.main_client.clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+
+    // setup already added 999, add a second row so there is something to filter out
+    let cmd = "INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( 1000, 'FILTERED');";
+    let insert_ok = mc
+        .execute_cooperative_write_at_host(&db_name, cmd, "participant", "")
+        .await
+        .unwrap();
+
+    assert!(insert_ok);
+
+    // only the matching row should come back, and only with the selected column
+    let cmd = "SELECT Name FROM EMPLOYEE WHERE Id = 1000";
+    let results = mc
+        .execute_read_at_host(&db_name, cmd, DatabaseType::to_u32(DatabaseType::Sqlite))
+        .await
+        .unwrap();
+
+    trace!("{results:?}");
+
+    assert_eq!(results.rows.len(), 1);
+
+    let row = results.rows.first().unwrap();
+    assert_eq!(row.values.len(), 1);
+    assert_eq!(row.values[0].value, "FILTERED".as_bytes().to_vec());
+
+    // ordering and limits apply to rows from the participant as well
+    let cmd = "SELECT Id FROM EMPLOYEE ORDER BY Id DESC LIMIT 1";
+    let results = mc
+        .execute_read_at_host(&db_name, cmd, DatabaseType::to_u32(DatabaseType::Sqlite))
+        .await
+        .unwrap();
+
+    trace!("{results:?}");
+
+    assert_eq!(results.rows.len(), 1);
+
+    let row = results.rows.first().unwrap();
+    assert_eq!(row.values[0].value, "1000".as_bytes().to_vec());
+}
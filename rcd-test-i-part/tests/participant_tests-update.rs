@@ -3,3 +3,18 @@ mod update_from_host_queue_with_log;
 
 #[path = "participant_tests-update/validate_update_behaviors/mod.rs"]
 mod validate_update_behaviors;
+
+#[path = "participant_tests-update/update_multiple_rows/mod.rs"]
+mod update_multiple_rows;
+
+#[path = "participant_tests-update/pending_writes_at_host/mod.rs"]
+mod pending_writes_at_host;
+
+#[path = "participant_tests-update/reject_pending_update/mod.rs"]
+mod reject_pending_update;
+
+#[path = "participant_tests-update/pending_action_ttl/mod.rs"]
+mod pending_action_ttl;
+
+#[path = "participant_tests-update/update_ignored_at_participant/mod.rs"]
+mod update_ignored_at_participant;
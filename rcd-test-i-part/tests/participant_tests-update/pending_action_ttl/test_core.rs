@@ -0,0 +1,82 @@
+use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use std::{thread, time::Duration};
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_updates_from_host_behavior(
+            &db_name,
+            "EMPLOYEE",
+            UpdatesFromHostBehavior::QueueForReview,
+        )
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    let ttl = pc
+        .get_pending_action_ttl_at_participant(&db_name, "EMPLOYEE")
+        .await
+        .unwrap();
+
+    assert_eq!(ttl, 0);
+
+    let ttl_is_changed = pc
+        .set_pending_action_ttl_at_participant(&db_name, "EMPLOYEE", 1)
+        .await
+        .unwrap();
+
+    assert!(ttl_is_changed);
+
+    let ttl = pc
+        .get_pending_action_ttl_at_participant(&db_name, "EMPLOYEE")
+        .await
+        .unwrap();
+
+    assert_eq!(ttl, 1);
+
+    let update_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'TESTER' WHERE ID = 999",
+            "participant",
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(update_is_successful);
+
+    let pending_writes = mc.get_pending_writes_at_host(&db_name).await.unwrap();
+    assert_eq!(pending_writes.pending_writes.len(), 1);
+
+    thread::sleep(Duration::from_secs(2));
+
+    // looking at the queue expires the update and tells the host it was rejected
+    let pending_updates = pc
+        .get_pending_actions_at_participant(&db_name, "EMPLOYEE", "UPDATE")
+        .await
+        .unwrap();
+
+    assert!(pending_updates.pending_statements.is_empty());
+
+    let pending_writes = mc.get_pending_writes_at_host(&db_name).await.unwrap();
+    assert!(pending_writes.pending_writes.is_empty());
+}
@@ -0,0 +1,104 @@
+use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_updates_from_host_behavior(
+            &db_name,
+            "EMPLOYEE",
+            UpdatesFromHostBehavior::QueueForReview,
+        )
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "ID = 999")
+        .await
+        .unwrap();
+
+    let row_id = *row_ids.first().unwrap();
+
+    let original_data_hash = mc
+        .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let update_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'TESTER' WHERE ID = 999",
+            "participant",
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    assert!(update_is_successful);
+
+    let pending_updates = pc
+        .get_pending_actions_at_participant(&db_name, "EMPLOYEE", "UPDATE")
+        .await
+        .unwrap();
+
+    let pending_update = pending_updates.pending_statements.first().unwrap();
+
+    let reject_result = pc
+        .reject_pending_action_at_participant(&db_name, "EMPLOYEE", pending_update.row_id)
+        .await
+        .unwrap();
+
+    trace!("{reject_result:?}");
+    assert!(reject_result.is_successful);
+
+    // the queue is empty and the host has stopped waiting on the update
+    let pending_updates = pc
+        .get_pending_actions_at_participant(&db_name, "EMPLOYEE", "UPDATE")
+        .await
+        .unwrap();
+
+    assert!(pending_updates.pending_statements.is_empty());
+
+    let pending_writes = mc.get_pending_writes_at_host(&db_name).await.unwrap();
+    assert!(pending_writes.pending_writes.is_empty());
+
+    // and neither side's copy of the row has changed
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'TESTER'")
+        .await
+        .unwrap();
+
+    assert!(row_ids.is_empty());
+
+    let participant_data_hash = pc
+        .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let host_data_hash = mc
+        .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    assert_eq!(host_data_hash, original_data_hash);
+    assert_eq!(participant_data_hash, host_data_hash);
+}
@@ -98,7 +98,7 @@ async fn main_read_updated_row_should_fail(
 
     let row = results.rows.first().unwrap();
 
-    let value = &row.values[1].value.clone();
+    let value = &row.values[0].value.clone();
 
     trace!("{value:?}");
 
@@ -165,7 +165,7 @@ async fn main_read_updated_row_should_succed(
 
     let row = results.rows.first().unwrap();
 
-    let value = &row.values[1].value.clone();
+    let value = &row.values[0].value.clone();
 
     trace!("{value:?}");
 
@@ -0,0 +1,92 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_enum::partial_data_status::PartialDataStatus;
+use rcd_enum::updates_from_host_behavior::UpdatesFromHostBehavior;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let behavior_is_changed = pc
+        .change_updates_from_host_behavior(&db_name, "EMPLOYEE", UpdatesFromHostBehavior::Ignore)
+        .await
+        .unwrap();
+
+    assert!(behavior_is_changed);
+
+    let reply = mc
+        .execute_cooperative_write_at_host_with_reply(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'TESTER' WHERE ID = 999",
+            "participant",
+            "ID = 999",
+        )
+        .await
+        .unwrap();
+
+    trace!("{reply:?}");
+
+    // the host should be told the participant ignored the update rather than fall over
+    assert!(!reply.is_successful);
+    assert_eq!(reply.participant_statuses.len(), 1);
+
+    let participant_status = reply.participant_statuses.first().unwrap();
+    assert_eq!(participant_status.participant_alias, "participant");
+    assert_eq!(
+        PartialDataStatus::from_u32(participant_status.write_status),
+        PartialDataStatus::Ignored
+    );
+
+    // and the row it matched is flagged as no longer in sync with the host
+    let read_result = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT IS_OUT_OF_SYNC FROM EMPLOYEE_COOP_METADATA",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    let flags: Vec<String> = read_result
+        .rows
+        .iter()
+        .map(|row| String::from_utf8(row.values[0].value.clone()).unwrap())
+        .collect();
+
+    assert_eq!(flags, vec!["1".to_string()]);
+
+    // which a read of the row reports back
+    let read_result = mc
+        .execute_read_at_host(
+            &db_name,
+            "SELECT ID, NAME FROM EMPLOYEE WHERE ID = 999",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    assert_eq!(read_result.rows.len(), 1);
+
+    let metadata = read_result.rows[0].remote_metadata.as_ref().unwrap();
+    assert!(metadata.is_remote_out_of_sync_with_host);
+}
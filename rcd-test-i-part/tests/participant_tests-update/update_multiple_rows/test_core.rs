@@ -0,0 +1,87 @@
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    for id in [1000, 1001, 1002] {
+        let statement = format!("INSERT INTO EMPLOYEE ( Id, Name ) VALUES ( {id}, 'MULTI');");
+        let insert_is_successful = mc
+            .execute_cooperative_write_at_host(&db_name, &statement, "participant", "")
+            .await
+            .unwrap();
+
+        assert!(insert_is_successful);
+    }
+
+    // the where clause matches every row we just added
+    let update_is_successful = mc
+        .execute_cooperative_write_at_host(
+            &db_name,
+            "UPDATE EMPLOYEE SET NAME = 'UPDATED' WHERE NAME = 'MULTI'",
+            "participant",
+            "NAME = 'MULTI'",
+        )
+        .await
+        .unwrap();
+
+    assert!(update_is_successful);
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'UPDATED'")
+        .await
+        .unwrap();
+
+    trace!("{row_ids:?}");
+    assert_eq!(row_ids.len(), 3);
+
+    // and the host should have the new hash of each of them, not just the first
+    for row_id in row_ids {
+        let participant_data_hash = pc
+            .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+            .await
+            .unwrap();
+
+        let host_data_hash = mc
+            .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+            .await
+            .unwrap();
+
+        assert_eq!(participant_data_hash, host_data_hash);
+    }
+
+    // the row from setup wasn't matched, so it should still agree too
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'ASDF'")
+        .await
+        .unwrap();
+
+    let row_id = *row_ids.first().unwrap();
+
+    let participant_data_hash = pc
+        .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let host_data_hash = mc
+        .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    assert_eq!(participant_data_hash, host_data_hash);
+}
@@ -58,7 +58,7 @@ async fn go(config: CoreTestConfig) {
 
         let results = read_result.unwrap();
         let row = results.rows.first().unwrap();
-        let value = &row.values[1].value.clone();
+        let value = &row.values[0].value.clone();
 
         trace!("{value:?}");
 
@@ -214,7 +214,7 @@ async fn go(config: CoreTestConfig) {
                 .unwrap();
 
             let row = results.rows.first().unwrap();
-            let value = &row.values[1].value.clone();
+            let value = &row.values[0].value.clone();
 
             trace!("{value:?}");
 
@@ -259,7 +259,7 @@ async fn go(config: CoreTestConfig) {
 
             let results = read_result.unwrap();
             let row = results.rows.first().unwrap();
-            let value = &row.values[1].value.clone();
+            let value = &row.values[0].value.clone();
 
             trace!("{value:?}");
 
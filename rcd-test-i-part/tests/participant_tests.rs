@@ -3,3 +3,18 @@ mod get_host_info;
 
 #[path = "participant_tests/reject_host/mod.rs"]
 mod reject_host;
+
+#[path = "participant_tests/negotiate_protocol_version/mod.rs"]
+mod negotiate_protocol_version;
+
+#[path = "participant_tests/rehash_partial_db/mod.rs"]
+mod rehash_partial_db;
+
+#[path = "participant_tests/export_partial_db/mod.rs"]
+mod export_partial_db;
+
+#[path = "participant_tests/reject_contract/mod.rs"]
+mod reject_contract;
+
+#[path = "participant_tests/data_log_table/mod.rs"]
+mod data_log_table;
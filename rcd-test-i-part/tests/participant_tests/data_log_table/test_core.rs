@@ -0,0 +1,116 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+    let database_type = DatabaseType::to_u32(DatabaseType::Sqlite);
+
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let use_data_log = pc
+        .get_data_log_table_status_at_participant(&db_name, "EMPLOYEE")
+        .await
+        .unwrap();
+    assert!(!use_data_log);
+
+    let is_set = pc
+        .set_data_log_table_status_at_participant(&db_name, "EMPLOYEE", true)
+        .await
+        .unwrap();
+    assert!(is_set);
+
+    let use_data_log = pc
+        .get_data_log_table_status_at_participant(&db_name, "EMPLOYEE")
+        .await
+        .unwrap();
+    assert!(use_data_log);
+
+    // a change from the host and then one of our own
+    mc.execute_cooperative_write_at_host(
+        &db_name,
+        "UPDATE EMPLOYEE SET NAME = 'TEST' WHERE ID = 999",
+        "participant",
+        "ID = 999",
+    )
+    .await
+    .unwrap();
+
+    pc.execute_write_at_participant(
+        &db_name,
+        "UPDATE EMPLOYEE SET NAME = 'LOCAL' WHERE ID = 999",
+        database_type,
+        "ID = 999",
+    )
+    .await
+    .unwrap();
+
+    let read_result = pc
+        .execute_read_at_participant(
+            &db_name,
+            "SELECT NAME, ACTION FROM EMPLOYEE_COOP_DATA_LOG ORDER BY ROWID",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    trace!("{read_result:?}");
+
+    let logged: Vec<(String, String)> = read_result
+        .rows
+        .iter()
+        .map(|row| {
+            (
+                String::from_utf8(row.values[0].value.clone()).unwrap(),
+                String::from_utf8(row.values[1].value.clone()).unwrap(),
+            )
+        })
+        .collect();
+
+    // each entry is the row as it was before it was changed
+    assert_eq!(
+        logged,
+        vec![
+            ("ASDF".to_string(), "UPDATE".to_string()),
+            ("TEST".to_string(), "UPDATE".to_string()),
+        ]
+    );
+
+    // once it's switched off nothing more is logged
+    pc.set_data_log_table_status_at_participant(&db_name, "EMPLOYEE", false)
+        .await
+        .unwrap();
+
+    pc.execute_write_at_participant(
+        &db_name,
+        "UPDATE EMPLOYEE SET NAME = 'AGAIN' WHERE ID = 999",
+        database_type,
+        "ID = 999",
+    )
+    .await
+    .unwrap();
+
+    let read_result = pc
+        .execute_read_at_participant(
+            &db_name,
+            "SELECT NAME FROM EMPLOYEE_COOP_DATA_LOG",
+            database_type,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(read_result.rows.len(), 2);
+}
@@ -0,0 +1,103 @@
+use rcd_enum::export_format::ExportFormat;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use rcdproto::rcdp::ExportDatabaseReply;
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let replies = pc
+        .export_database(&db_name, ExportFormat::Json)
+        .await
+        .unwrap();
+    trace!("{replies:?}");
+
+    let first = replies.first().unwrap();
+    assert!(first.is_successful);
+
+    // the manifest should have the contract we accepted and the hash of the row the host
+    // inserted during setup
+    let manifest = first.manifest.as_ref().unwrap();
+    assert!(manifest.active_contract.is_some());
+    assert!(manifest.tables.iter().any(|t| t.table_name == "EMPLOYEE"));
+
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'ASDF'")
+        .await
+        .unwrap();
+
+    let row_id = *row_ids.first().unwrap();
+
+    let participant_data_hash = pc
+        .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let row_hash = manifest
+        .row_hashes
+        .iter()
+        .find(|h| h.table_name == "EMPLOYEE" && h.row_id == row_id)
+        .unwrap();
+
+    assert_eq!(row_hash.hash, participant_data_hash);
+
+    let employees = get_file(&replies, "EMPLOYEE.ndjson");
+    assert!(employees.contains("\"Name\":\"ASDF\""));
+
+    let replies = pc.export_database(&db_name, ExportFormat::Csv).await.unwrap();
+    assert!(replies.first().unwrap().is_successful);
+
+    let employees = get_file(&replies, "EMPLOYEE.csv");
+    let mut lines = employees.lines();
+    assert!(lines.next().unwrap().contains("Name"));
+    assert!(lines.next().unwrap().contains("ASDF"));
+
+    let replies = pc
+        .export_database(&db_name, ExportFormat::Sqlite)
+        .await
+        .unwrap();
+    assert!(replies.first().unwrap().is_successful);
+
+    let file_name = replies
+        .first()
+        .unwrap()
+        .manifest
+        .as_ref()
+        .unwrap()
+        .file_names
+        .first()
+        .unwrap()
+        .clone();
+
+    let contents: Vec<u8> = replies
+        .iter()
+        .filter(|r| r.file_name == file_name)
+        .flat_map(|r| r.data.clone())
+        .collect();
+
+    assert!(contents.starts_with(b"SQLite format 3"));
+}
+
+/// Puts the chunks of an exported file back together
+fn get_file(replies: &[ExportDatabaseReply], file_name: &str) -> String {
+    let contents: Vec<u8> = replies
+        .iter()
+        .filter(|r| r.file_name == file_name)
+        .flat_map(|r| r.data.clone())
+        .collect();
+
+    String::from_utf8(contents).unwrap()
+}
@@ -0,0 +1,42 @@
+use rcd_enum::database_type::DatabaseType;
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let mc = config.main_client.clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+
+    // both sides run the same build, so they should settle on our version
+    let reply = mc
+        .try_auth_at_participant_with_reply("participant", "", &db_name)
+        .await;
+
+    trace!("{reply:?}");
+
+    assert!(reply.is_successful);
+    assert!(reply.error.is_none());
+    assert_eq!(reply.protocol_version, rcdproto::PROTOCOL_VERSION);
+
+    // having saved the participant's version, the host should still talk to it
+    let cmd = "SELECT Name FROM EMPLOYEE WHERE Id = 999";
+    let results = mc
+        .execute_read_at_host(&db_name, cmd, DatabaseType::to_u32(DatabaseType::Sqlite))
+        .await
+        .unwrap();
+
+    trace!("{results:?}");
+
+    assert_eq!(results.rows.len(), 1);
+}
@@ -0,0 +1,48 @@
+use rcd_test_harness::{
+    test_common::multi::common_contract_setup::main_and_participant_setup, CoreTestConfig,
+};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let result = main_and_participant_setup(config.clone()).await;
+    assert!(result);
+
+    let db_name = config.test_db_name.clone();
+
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let mut pc = rcd_test_harness::get_rcd_client(&pc).await;
+
+    let mc = config.main_client.clone();
+    let mut mc = rcd_test_harness::get_rcd_client(&mc).await;
+
+    let reply = pc.rehash_partial_database(&db_name).await.unwrap();
+    trace!("{reply:?}");
+
+    assert!(reply.is_successful);
+    assert_eq!(reply.total_rows_rehashed, 1);
+
+    // the host should have been sent the new hash for the row it inserted during setup
+    let row_ids = pc
+        .get_row_id_at_participant(&db_name, "EMPLOYEE", "NAME = 'ASDF'")
+        .await
+        .unwrap();
+
+    let row_id = *row_ids.first().unwrap();
+
+    let participant_data_hash = pc
+        .get_data_hash_at_participant(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    let host_data_hash = mc
+        .get_data_hash_at_host(&db_name, "EMPLOYEE", row_id)
+        .await
+        .unwrap();
+
+    assert_eq!(participant_data_hash, host_data_hash);
+}
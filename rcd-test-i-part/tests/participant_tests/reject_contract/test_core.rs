@@ -0,0 +1,49 @@
+use rcd_enum::contract_status::ContractStatus;
+use rcd_test_harness::{test_common::multi::common_contract_setup, CoreTestConfig};
+use tracing::trace;
+
+pub fn test_core(config: CoreTestConfig) {
+    go(config)
+}
+
+#[tokio::main]
+async fn go(config: CoreTestConfig) {
+    let mc = config.main_client.clone();
+    let pc = config.participant_client.as_ref().unwrap().clone();
+    let pdb = config.participant_db_addr.as_ref().unwrap().clone();
+    let db = config.test_db_name.clone();
+    let contract = config.contract_desc.as_ref().unwrap().clone();
+
+    let sent_contract =
+        common_contract_setup::client(&db, &mc, &pdb, &contract, config.participant_id.clone())
+            .await;
+    assert!(sent_contract);
+
+    let mut participant = rcd_test_harness::get_rcd_client(&pc).await;
+    participant.generate_host_info("participant").await.unwrap();
+
+    let pending_contracts = participant.view_pending_contracts().await.unwrap();
+    assert!(pending_contracts.iter().any(|c| c.description == contract));
+
+    let rejected_contract = participant.reject_pending_contract("tester").await.unwrap();
+    assert!(rejected_contract);
+
+    // the contract is no longer pending and the host knows we rejected it
+    let pending_contracts = participant.view_pending_contracts().await.unwrap();
+    assert!(pending_contracts.is_empty());
+
+    let mut main = rcd_test_harness::get_rcd_client(&mc).await;
+    let participants = main.get_participants_for_database(&db).await.unwrap();
+    trace!("{participants:?}");
+
+    let participant_status = participants
+        .participants
+        .iter()
+        .find(|p| p.participant.as_ref().unwrap().alias == "participant")
+        .unwrap();
+
+    assert_eq!(
+        ContractStatus::from_u32(participant_status.contract_status),
+        ContractStatus::Rejected
+    );
+}
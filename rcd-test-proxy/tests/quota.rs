@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use tracing::debug;
+use rcd_messages::{
+    client::{AuthRequest, CreateUserDatabaseReply, CreateUserDatabaseRequest},
+    proxy::{
+        request_type::RequestType,
+        server_messages::{
+            ExecuteReply, ExecuteRequest, QuotaKind, RegisterLoginReply, RegisterLoginRequest,
+            TenantQuota,
+        },
+    },
+};
+use rcd_proxy::{proxy_server::ProxyServer, RcdProxy};
+use rcd_test_harness::{
+    init_log_to_screen,
+    test_common::proxy::{configure_proxy_for_test, RcdProxyTestType},
+};
+use rcd_test_proxy::get_http_result;
+
+#[tokio::test]
+async fn create_database_over_quota() {
+    init_log_to_screen(log::LevelFilter::Debug);
+
+    let setup = configure_proxy_for_test("proxy-i-quota-make-db", RcdProxyTestType::Grpc);
+    {
+        let proxy = setup.proxy.clone();
+        let server = ProxyServer::new(proxy.clone());
+        tokio::spawn(async move {
+            proxy.start_grpc_client().await;
+            proxy.start_grpc_data().await;
+            server.start().await.unwrap();
+        });
+    }
+
+    // give the http server a moment to start listening before we register
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let proxy = setup.proxy.clone();
+    let id = register(&proxy).await;
+
+    let quota = TenantQuota {
+        max_databases: Some(1),
+        ..TenantQuota::default()
+    };
+    proxy.set_quota_for_login("tester", &quota).unwrap();
+
+    let result = create_database(&proxy, &id, "quota_first.db").await;
+    assert!(result.login_success && result.execute_success);
+    assert!(result.quota_error.is_none());
+
+    let db_result: CreateUserDatabaseReply =
+        serde_json::from_str(&result.reply.as_ref().unwrap().clone()).unwrap();
+    assert!(db_result.is_created);
+
+    let result = create_database(&proxy, &id, "quota_second.db").await;
+    assert!(result.login_success);
+    assert!(!result.execute_success);
+
+    let quota_error = result.quota_error.unwrap();
+    assert_eq!(quota_error.kind, QuotaKind::Databases);
+    assert_eq!(quota_error.limit, 1);
+    assert_eq!(quota_error.usage, 1);
+}
+
+async fn register(proxy: &RcdProxy) -> String {
+    let request = RegisterLoginRequest {
+        login: "tester".to_string(),
+        pw: "1234".to_string(),
+    };
+
+    let url = format!(
+        "http://{}:{}/account/register",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    debug!("{url:?}");
+    let result: RegisterLoginReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    assert!(result.is_successful);
+
+    result.host_id.unwrap()
+}
+
+async fn create_database(proxy: &RcdProxy, id: &str, db_name: &str) -> ExecuteReply {
+    let request_type: u16 = RequestType::CreateUserDatabase.into();
+
+    let auth = AuthRequest {
+        user_name: "tester".to_string(),
+        pw: "1234".to_string(),
+        pw_hash: Vec::new(),
+        token: Vec::new(),
+        jwt: "".to_string(),
+        id: Some(id.to_string()),
+    };
+
+    let request = CreateUserDatabaseRequest {
+        authentication: Some(auth),
+        database_name: db_name.to_string(),
+    };
+
+    let request = ExecuteRequest {
+        login: Some("tester".to_string()),
+        pw: Some("1234".to_string()),
+        jwt: None,
+        request_type,
+        request_json: serde_json::to_string(&request).unwrap(),
+    };
+
+    let url = format!(
+        "http://{}:{}/execute",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    debug!("{url:?}");
+    let result: ExecuteReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    result
+}
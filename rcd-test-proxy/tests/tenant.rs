@@ -0,0 +1,152 @@
+use std::time::Duration;
+
+use tracing::debug;
+use rcd_enum::tenant_status::TenantStatus;
+use rcd_messages::{
+    client::{AuthRequest, CreateUserDatabaseRequest},
+    proxy::{
+        request_type::RequestType,
+        server_messages::{
+            AuthForTokenReply, AuthForTokenRequest, ExecuteReply, ExecuteRequest,
+            RegisterLoginReply, RegisterLoginRequest, TenantRequest, TenantStatusReply,
+        },
+    },
+};
+use rcd_proxy::{proxy_server::ProxyServer, RcdProxy};
+use rcd_test_harness::{
+    init_log_to_screen,
+    test_common::proxy::{configure_proxy_for_test, RcdProxyTestType},
+};
+use rcd_test_proxy::get_http_result;
+
+#[tokio::test]
+async fn suspended_tenant_is_refused() {
+    init_log_to_screen(log::LevelFilter::Debug);
+
+    let setup = configure_proxy_for_test("proxy-i-tenant-suspend", RcdProxyTestType::Grpc);
+    {
+        let proxy = setup.proxy.clone();
+        let server = ProxyServer::new(proxy.clone());
+        tokio::spawn(async move {
+            proxy.start_grpc_client().await;
+            proxy.start_grpc_data().await;
+            server.start().await.unwrap();
+        });
+    }
+
+    // give the http server a moment to start listening before we register
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let proxy = setup.proxy.clone();
+    let id = register(&proxy).await;
+
+    let result = create_database(&proxy, &id, "tenant_active.db").await;
+    assert!(result.login_success && result.execute_success);
+
+    // the tester is not listed in admin_logins, so it can't use the admin endpoints
+    let jwt = get_token(&proxy).await.unwrap();
+    let request = TenantRequest {
+        jwt,
+        login: "tester".to_string(),
+    };
+
+    let url = format!(
+        "http://{}:{}/admin/tenant/status",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    let result: TenantStatusReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    assert!(!result.is_successful);
+
+    proxy
+        .change_tenant_status("tester", TenantStatus::Suspended)
+        .unwrap();
+
+    let result = create_database(&proxy, &id, "tenant_suspended.db").await;
+    assert!(!result.login_success);
+    assert!(get_token(&proxy).await.is_none());
+
+    proxy
+        .change_tenant_status("tester", TenantStatus::Active)
+        .unwrap();
+
+    let result = create_database(&proxy, &id, "tenant_resumed.db").await;
+    assert!(result.login_success && result.execute_success);
+}
+
+async fn register(proxy: &RcdProxy) -> String {
+    let request = RegisterLoginRequest {
+        login: "tester".to_string(),
+        pw: "1234".to_string(),
+    };
+
+    let url = format!(
+        "http://{}:{}/account/register",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    debug!("{url:?}");
+    let result: RegisterLoginReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    assert!(result.is_successful);
+
+    result.host_id.unwrap()
+}
+
+async fn get_token(proxy: &RcdProxy) -> Option<String> {
+    let request = AuthForTokenRequest {
+        login: "tester".to_string(),
+        pw: "1234".to_string(),
+    };
+
+    let url = format!(
+        "http://{}:{}/account/token",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    debug!("{url:?}");
+    let result: AuthForTokenReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    result.jwt
+}
+
+async fn create_database(proxy: &RcdProxy, id: &str, db_name: &str) -> ExecuteReply {
+    let request_type: u16 = RequestType::CreateUserDatabase.into();
+
+    let auth = AuthRequest {
+        user_name: "tester".to_string(),
+        pw: "1234".to_string(),
+        pw_hash: Vec::new(),
+        token: Vec::new(),
+        jwt: "".to_string(),
+        id: Some(id.to_string()),
+    };
+
+    let request = CreateUserDatabaseRequest {
+        authentication: Some(auth),
+        database_name: db_name.to_string(),
+    };
+
+    let request = ExecuteRequest {
+        login: Some("tester".to_string()),
+        pw: Some("1234".to_string()),
+        jwt: None,
+        request_type,
+        request_json: serde_json::to_string(&request).unwrap(),
+    };
+
+    let url = format!(
+        "http://{}:{}/execute",
+        proxy.http_endpoint_addr(),
+        proxy.http_endpoint_port()
+    );
+
+    debug!("{url:?}");
+    let result: ExecuteReply = get_http_result(url, request).await;
+    debug!("{result:?}");
+    result
+}
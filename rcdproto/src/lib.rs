@@ -1 +1,15 @@
 pub mod rcdp;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The version of the messages in `rcdp`, bumped whenever a change breaks compatibility with
+/// instances built against an earlier version
+pub const PROTOCOL_VERSION: u32 = 1;
+/// The oldest protocol version this build can still talk to a peer in
+pub const MIN_SUPPORTED_PROTOCOL_VERSION: u32 = 1;
+/// Recorded for a peer that has not reported a protocol version: one we haven't authenticated
+/// against yet, or one built before versions were exchanged
+pub const UNKNOWN_PROTOCOL_VERSION: u32 = 0;
+/// The version a peer with an unknown protocol version is taken to speak. Versions were added
+/// without changing any messages, so builds from before then speak version 1; once support for
+/// version 1 is dropped these peers are refused until they report a newer version.
+pub const UNVERSIONED_PROTOCOL_VERSION: u32 = 1;
@@ -160,9 +160,16 @@ pub struct VersionReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     #[prost(string, tag = "2")]
     pub rcdx: ::prost::alloc::string::String,
-    /// ... so on
     #[prost(string, tag = "3")]
     pub rcd_core: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub rcdproto: ::prost::alloc::string::String,
+    /// bumped whenever a change to these messages breaks compatibility with older instances
+    #[prost(uint32, tag = "5")]
+    pub protocol_version: u32,
+    /// the layout version of the rcd system database
+    #[prost(uint32, tag = "6")]
+    pub rcd_schema_version: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -272,6 +279,71 @@ pub struct AcceptPendingActionReply {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectPendingActionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub row_id: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectPendingActionReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+}
+/// how long an action queued for review can wait before it is rejected; 0 never expires
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPendingActionTtlRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub ttl_seconds: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetPendingActionTtlReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingActionTtlRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub table_name: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingActionTtlReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(uint32, tag = "2")]
+    pub ttl_seconds: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetPendingActionsRequest {
     #[prost(message, optional, tag = "1")]
     pub authentication: ::core::option::Option<AuthRequest>,
@@ -309,6 +381,45 @@ pub struct PendingStatement {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingWritesAtHostRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingWritesAtHostReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(message, repeated, tag = "2")]
+    pub pending_writes: ::prost::alloc::vec::Vec<PendingWrite>,
+}
+/// a write the host sent to a participant that the participant has queued for review
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingWrite {
+    #[prost(string, tag = "1")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub participant_alias: ::prost::alloc::string::String,
+    /// the id the participant queued the statement under
+    #[prost(uint32, tag = "3")]
+    pub pending_action_id: u32,
+    #[prost(string, tag = "4")]
+    pub statement: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub where_clause: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub action: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub requested_ts_utc: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SetDataLogTableStatusRequest {
     #[prost(message, optional, tag = "1")]
     pub authentication: ::core::option::Option<AuthRequest>,
@@ -392,6 +503,116 @@ pub struct GetDataHashReply {
     #[prost(uint64, tag = "2")]
     pub data_hash: u64,
 }
+/// recomputes the hash of every row in a partial database and sends the new hashes to the host
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RehashPartialDatabaseRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RehashPartialDatabaseReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(uint32, tag = "3")]
+    pub total_rows_rehashed: u32,
+}
+/// supplies the key for a database that is encrypted at rest. keys are only held in memory, so
+/// this is needed again after rcd restarts
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnlockDatabaseRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub database_key: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnlockDatabaseReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+/// exports a host or partial database so that a user can take a copy of their data
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportDatabaseRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    /// rcd_enum::ExportFormat: 1 - a raw copy of the sqlite file, 2 - newline delimited JSON per table,
+    /// 3 - CSV per table
+    #[prost(uint32, tag = "3")]
+    pub format: u32,
+}
+/// one piece of an export. the first reply has the manifest and the ones after it each carry a chunk
+/// of an exported file, in order; the chunks for a file are appended together to rebuild it
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportDatabaseReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "4")]
+    pub manifest: ::core::option::Option<ExportManifest>,
+    #[prost(string, tag = "5")]
+    pub file_name: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "6")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+/// describes what was held in an exported database, so that a participant can prove what they had
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportManifest {
+    #[prost(string, tag = "1")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub format: u32,
+    #[prost(string, tag = "3")]
+    pub exported_utc: ::prost::alloc::string::String,
+    /// the contract the database is under, if it has one
+    #[prost(message, optional, tag = "4")]
+    pub active_contract: ::core::option::Option<Contract>,
+    #[prost(message, repeated, tag = "5")]
+    pub tables: ::prost::alloc::vec::Vec<TableSchema>,
+    #[prost(message, repeated, tag = "6")]
+    pub row_hashes: ::prost::alloc::vec::Vec<ExportRowHash>,
+    #[prost(string, repeated, tag = "7")]
+    pub file_names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportRowHash {
+    #[prost(string, tag = "1")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub row_id: u32,
+    #[prost(uint64, tag = "3")]
+    pub hash: u64,
+}
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -511,6 +732,11 @@ pub struct TryAuthAtPartipantReply {
     pub is_successful: bool,
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
+    /// the protocol version the participant speaks, or 0 if it could not be authenticated
+    #[prost(uint32, tag = "4")]
+    pub protocol_version: u32,
+    #[prost(message, optional, tag = "5")]
+    pub error: ::core::option::Option<RcdError>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -600,6 +826,9 @@ pub struct CreateUserDatabaseRequest {
     pub authentication: ::core::option::Option<AuthRequest>,
     #[prost(string, tag = "2")]
     pub database_name: ::prost::alloc::string::String,
+    /// if set, the database is encrypted at rest with this key (sqlite only). rcd does not store it
+    #[prost(string, optional, tag = "3")]
+    pub database_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -728,6 +957,11 @@ pub struct SetLogicalStoragePolicyRequest {
     pub table_name: ::prost::alloc::string::String,
     #[prost(uint32, tag = "4")]
     pub policy_mode: u32,
+    /// for a Shared table, the columns whose values are kept at the participant
+    #[prost(string, repeated, tag = "5")]
+    pub participant_owned_columns: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -759,6 +993,10 @@ pub struct GetLogicalStoragePolicyReply {
     pub authentication_result: ::core::option::Option<AuthResult>,
     #[prost(uint32, tag = "2")]
     pub policy_mode: u32,
+    #[prost(string, repeated, tag = "3")]
+    pub participant_owned_columns: ::prost::alloc::vec::Vec<
+        ::prost::alloc::string::String,
+    >,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -789,6 +1027,22 @@ pub struct ExecuteCooperativeWriteReply {
     pub is_successful: bool,
     #[prost(uint32, tag = "3")]
     pub total_rows_affected: u32,
+    #[prost(message, repeated, tag = "4")]
+    pub participant_statuses: ::prost::alloc::vec::Vec<ParticipantWriteStatus>,
+}
+/// what happened to a cooperative write at one participant
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParticipantWriteStatus {
+    #[prost(string, tag = "1")]
+    pub participant_alias: ::prost::alloc::string::String,
+    /// 0 - unknown
+    /// 1 - applied
+    /// 2 - pending (queued for review)
+    /// 3 - ignored
+    #[prost(uint32, tag = "2")]
+    pub write_status: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -846,6 +1100,9 @@ pub struct AcceptPendingContractRequest {
     pub authentication: ::core::option::Option<AuthRequest>,
     #[prost(string, tag = "2")]
     pub host_alias: ::prost::alloc::string::String,
+    /// if set, the partial database is encrypted at rest with this key (sqlite only). rcd does not store it
+    #[prost(string, optional, tag = "3")]
+    pub database_key: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -904,6 +1161,9 @@ pub struct EnableCoooperativeFeaturesReply {
 pub struct TryAuthRequest {
     #[prost(message, optional, tag = "1")]
     pub authentication: ::core::option::Option<AuthRequest>,
+    /// the protocol version the sender speaks
+    #[prost(uint32, tag = "2")]
+    pub protocol_version: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -911,6 +1171,12 @@ pub struct TryAuthRequest {
 pub struct TryAuthResult {
     #[prost(message, optional, tag = "1")]
     pub authentication_result: ::core::option::Option<AuthResult>,
+    /// the protocol version the receiver speaks
+    #[prost(uint32, tag = "2")]
+    pub protocol_version: u32,
+    /// set if the receiver no longer supports the sender's protocol version
+    #[prost(message, optional, tag = "3")]
+    pub error: ::core::option::Option<RcdError>,
 }
 /// a message for creating a table in a database
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -1027,6 +1293,9 @@ pub struct UpdateDataResult {
     /// 3 - ignored (ignore)
     #[prost(uint32, tag = "5")]
     pub update_status: u32,
+    /// the id the participant queued the statement under, if the update is pending
+    #[prost(uint32, tag = "6")]
+    pub pending_action_id: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1055,6 +1324,9 @@ pub struct DeleteDataResult {
     pub message: ::prost::alloc::string::String,
     #[prost(message, repeated, tag = "4")]
     pub rows: ::prost::alloc::vec::Vec<RowInfo>,
+    /// the id the participant queued the statement under, if the delete is pending
+    #[prost(uint32, tag = "5")]
+    pub pending_action_id: u32,
 }
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1080,6 +1352,36 @@ pub struct GetRowFromPartialDatabaseResult {
     #[prost(message, optional, tag = "4")]
     pub row: ::core::option::Option<Row>,
 }
+/// a request from a host to a participant for a set of rows in one table, so that
+/// the host does not need a round trip per row
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRowsFromPartialDatabaseRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(string, tag = "2")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, repeated, tag = "4")]
+    pub row_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(message, optional, tag = "5")]
+    pub message_info: ::core::option::Option<MessageInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRowsFromPartialDatabaseResult {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(string, tag = "3")]
+    pub result_message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "4")]
+    pub rows: ::prost::alloc::vec::Vec<Row>,
+}
 /// a message from a host to a participant to save a contract
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1132,6 +1434,32 @@ pub struct ParticipantAcceptsContractResult {
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParticipantRejectsContractRequest {
+    #[prost(message, optional, tag = "1")]
+    pub participant: ::core::option::Option<Participant>,
+    #[prost(string, tag = "2")]
+    pub contract_guid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub contract_version_guid: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub message_info: ::core::option::Option<MessageInfo>,
+    #[prost(string, optional, tag = "6")]
+    pub id: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ParticipantRejectsContractResult {
+    #[prost(bool, tag = "1")]
+    pub contract_rejection_is_acknowledged: bool,
+    #[prost(string, tag = "2")]
+    pub error_message: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRowDataHashForHostRequest {
     #[prost(message, optional, tag = "1")]
     pub authentication: ::core::option::Option<AuthRequest>,
@@ -1193,23 +1521,91 @@ pub struct NotifyHostOfRemovedRowResponse {
     #[prost(bool, tag = "2")]
     pub is_successful: bool,
 }
-/// A message for basic online testing
+/// sent by a participant to the host when the participant has added a row to its partial database,
+/// so that the host can save a reference to the row
 #[derive(serde::Serialize, serde::Deserialize)]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct TestRequest {
-    #[prost(string, tag = "1")]
-    pub request_time_utc: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub request_origin_url: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub request_origin_ip4: ::prost::alloc::string::String,
+pub struct NotifyHostOfInsertedRowRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(message, optional, tag = "2")]
+    pub message_info: ::core::option::Option<MessageInfo>,
+    #[prost(message, optional, tag = "3")]
+    pub host_info: ::core::option::Option<Host>,
     #[prost(string, tag = "4")]
-    pub request_origin_ip6: ::prost::alloc::string::String,
-    #[prost(uint32, tag = "5")]
-    pub request_port_number: u32,
-    #[prost(string, tag = "6")]
-    pub request_echo_message: ::prost::alloc::string::String,
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub database_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "7")]
+    pub table_id: u32,
+    #[prost(uint32, tag = "8")]
+    pub row_id: u32,
+    #[prost(uint64, tag = "9")]
+    pub hash: u64,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotifyHostOfInsertedRowResponse {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+}
+/// sent by a participant when it accepts or rejects a statement from the host that it had queued for review
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotifyHostOfPendingActionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    #[prost(message, optional, tag = "2")]
+    pub message_info: ::core::option::Option<MessageInfo>,
+    #[prost(message, optional, tag = "3")]
+    pub host_info: ::core::option::Option<Host>,
+    #[prost(string, tag = "4")]
+    pub database_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub table_name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "6")]
+    pub pending_action_id: u32,
+    #[prost(string, tag = "7")]
+    pub action: ::prost::alloc::string::String,
+    #[prost(bool, tag = "8")]
+    pub is_accepted: bool,
+    /// the rows the statement changed, if it was accepted
+    #[prost(message, repeated, tag = "9")]
+    pub rows: ::prost::alloc::vec::Vec<RowInfo>,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NotifyHostOfPendingActionResponse {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+}
+/// A message for basic online testing
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TestRequest {
+    #[prost(string, tag = "1")]
+    pub request_time_utc: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub request_origin_url: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub request_origin_ip4: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub request_origin_ip6: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "5")]
+    pub request_port_number: u32,
+    #[prost(string, tag = "6")]
+    pub request_echo_message: ::prost::alloc::string::String,
 }
 /// A message for basic online testing
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -1528,6 +1924,68 @@ pub struct RowParticipantAddress {
     #[prost(uint32, tag = "3")]
     pub row_id: u32,
 }
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TenantRequest {
+    /// the operator making the request
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    /// the login of the account to act on
+    #[prost(string, tag = "2")]
+    pub login: ::prost::alloc::string::String,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChangeTenantStatusRequest {
+    /// the operator making the request
+    #[prost(message, optional, tag = "1")]
+    pub authentication: ::core::option::Option<AuthRequest>,
+    /// the login of the account to act on
+    #[prost(string, tag = "2")]
+    pub login: ::prost::alloc::string::String,
+    /// rcd_enum::tenant_status::TenantStatus; Active resumes the account, PendingDelete schedules it for removal
+    #[prost(uint32, tag = "3")]
+    pub status: u32,
+}
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TenantStatusReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(string, tag = "3")]
+    pub login: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "4")]
+    pub status: u32,
+    /// when the account will be removed, if it is pending delete
+    #[prost(string, optional, tag = "5")]
+    pub delete_after_utc: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, optional, tag = "6")]
+    pub error: ::core::option::Option<RcdError>,
+}
+/// the first reply names the archive of the account's databases and gives its size, and each
+/// reply after it carries a chunk of the archive
+#[derive(serde::Serialize, serde::Deserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportTenantReply {
+    #[prost(message, optional, tag = "1")]
+    pub authentication_result: ::core::option::Option<AuthResult>,
+    #[prost(bool, tag = "2")]
+    pub is_successful: bool,
+    #[prost(uint64, tag = "4")]
+    pub archive_size_in_bytes: u64,
+    #[prost(message, optional, tag = "5")]
+    pub error: ::core::option::Option<RcdError>,
+    #[prost(string, tag = "6")]
+    pub archive_name: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "7")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
 /// Generated client implementations.
 pub mod sql_client_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -2115,6 +2573,66 @@ pub mod sql_client_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn rehash_partial_database(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RehashPartialDatabaseRequest>,
+        ) -> Result<tonic::Response<super::RehashPartialDatabaseReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/RehashPartialDatabase",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn unlock_database(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UnlockDatabaseRequest>,
+        ) -> Result<tonic::Response<super::UnlockDatabaseReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/UnlockDatabase",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn export_database(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportDatabaseRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportDatabaseReply>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/ExportDatabase",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
         pub async fn read_row_id_at_participant(
             &mut self,
             request: impl tonic::IntoRequest<super::GetReadRowIdsRequest>,
@@ -2210,6 +2728,82 @@ pub mod sql_client_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn get_pending_writes_at_host(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPendingWritesAtHostRequest>,
+        ) -> Result<tonic::Response<super::GetPendingWritesAtHostReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/GetPendingWritesAtHost",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn reject_pending_action_at_participant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RejectPendingActionRequest>,
+        ) -> Result<tonic::Response<super::RejectPendingActionReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/RejectPendingActionAtParticipant",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn get_pending_action_ttl_at_participant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPendingActionTtlRequest>,
+        ) -> Result<tonic::Response<super::GetPendingActionTtlReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/GetPendingActionTtlAtParticipant",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn set_pending_action_ttl_at_participant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetPendingActionTtlRequest>,
+        ) -> Result<tonic::Response<super::SetPendingActionTtlReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.SQLClient/SetPendingActionTtlAtParticipant",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// admin calls
         pub async fn get_databases(
             &mut self,
@@ -2697,6 +3291,28 @@ pub mod data_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn get_rows_from_partial_database(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRowsFromPartialDatabaseRequest>,
+        ) -> Result<
+            tonic::Response<super::GetRowsFromPartialDatabaseResult>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.DataService/GetRowsFromPartialDatabase",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         pub async fn save_contract(
             &mut self,
             request: impl tonic::IntoRequest<super::SaveContractRequest>,
@@ -2738,6 +3354,28 @@ pub mod data_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn reject_contract(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ParticipantRejectsContractRequest>,
+        ) -> Result<
+            tonic::Response<super::ParticipantRejectsContractResult>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.DataService/RejectContract",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         pub async fn update_row_data_hash_for_host(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateRowDataHashForHostRequest>,
@@ -2782,10 +3420,13 @@ pub mod data_service_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
-        pub async fn try_auth(
+        pub async fn notify_host_of_inserted_row(
             &mut self,
-            request: impl tonic::IntoRequest<super::TryAuthRequest>,
-        ) -> Result<tonic::Response<super::TryAuthResult>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::NotifyHostOfInsertedRowRequest>,
+        ) -> Result<
+            tonic::Response<super::NotifyHostOfInsertedRowResponse>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -2796,8 +3437,181 @@ pub mod data_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/rcdp.DataService/TryAuth");
-            self.inner.unary(request.into_request(), path, codec).await
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.DataService/NotifyHostOfInsertedRow",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn notify_host_of_pending_action(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NotifyHostOfPendingActionRequest>,
+        ) -> Result<
+            tonic::Response<super::NotifyHostOfPendingActionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.DataService/NotifyHostOfPendingAction",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn try_auth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TryAuthRequest>,
+        ) -> Result<tonic::Response<super::TryAuthResult>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/rcdp.DataService/TryAuth");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod proxy_admin_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// a service for the operators of a rcd-proxy to manage the accounts it hosts
+    #[derive(Debug, Clone)]
+    pub struct ProxyAdminClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ProxyAdminClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ProxyAdminClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ProxyAdminClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            ProxyAdminClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        pub async fn get_tenant_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TenantRequest>,
+        ) -> Result<tonic::Response<super::TenantStatusReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.ProxyAdmin/GetTenantStatus",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn change_tenant_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ChangeTenantStatusRequest>,
+        ) -> Result<tonic::Response<super::TenantStatusReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.ProxyAdmin/ChangeTenantStatus",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn export_tenant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TenantRequest>,
+        ) -> Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportTenantReply>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/rcdp.ProxyAdmin/ExportTenant",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
         }
     }
 }
@@ -2927,6 +3741,24 @@ pub mod sql_client_server {
             &self,
             request: tonic::Request<super::GetDataHashRequest>,
         ) -> Result<tonic::Response<super::GetDataHashReply>, tonic::Status>;
+        async fn rehash_partial_database(
+            &self,
+            request: tonic::Request<super::RehashPartialDatabaseRequest>,
+        ) -> Result<tonic::Response<super::RehashPartialDatabaseReply>, tonic::Status>;
+        async fn unlock_database(
+            &self,
+            request: tonic::Request<super::UnlockDatabaseRequest>,
+        ) -> Result<tonic::Response<super::UnlockDatabaseReply>, tonic::Status>;
+        /// Server streaming response type for the ExportDatabase method.
+        type ExportDatabaseStream: futures_core::Stream<
+                Item = Result<super::ExportDatabaseReply, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn export_database(
+            &self,
+            request: tonic::Request<super::ExportDatabaseRequest>,
+        ) -> Result<tonic::Response<Self::ExportDatabaseStream>, tonic::Status>;
         async fn read_row_id_at_participant(
             &self,
             request: tonic::Request<super::GetReadRowIdsRequest>,
@@ -2947,6 +3779,22 @@ pub mod sql_client_server {
             &self,
             request: tonic::Request<super::AcceptPendingActionRequest>,
         ) -> Result<tonic::Response<super::AcceptPendingActionReply>, tonic::Status>;
+        async fn get_pending_writes_at_host(
+            &self,
+            request: tonic::Request<super::GetPendingWritesAtHostRequest>,
+        ) -> Result<tonic::Response<super::GetPendingWritesAtHostReply>, tonic::Status>;
+        async fn reject_pending_action_at_participant(
+            &self,
+            request: tonic::Request<super::RejectPendingActionRequest>,
+        ) -> Result<tonic::Response<super::RejectPendingActionReply>, tonic::Status>;
+        async fn get_pending_action_ttl_at_participant(
+            &self,
+            request: tonic::Request<super::GetPendingActionTtlRequest>,
+        ) -> Result<tonic::Response<super::GetPendingActionTtlReply>, tonic::Status>;
+        async fn set_pending_action_ttl_at_participant(
+            &self,
+            request: tonic::Request<super::SetPendingActionTtlRequest>,
+        ) -> Result<tonic::Response<super::SetPendingActionTtlReply>, tonic::Status>;
         /// admin calls
         async fn get_databases(
             &self,
@@ -4134,6 +4982,127 @@ pub mod sql_client_server {
                     };
                     Box::pin(fut)
                 }
+                "/rcdp.SQLClient/RehashPartialDatabase" => {
+                    #[allow(non_camel_case_types)]
+                    struct RehashPartialDatabaseSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::RehashPartialDatabaseRequest>
+                    for RehashPartialDatabaseSvc<T> {
+                        type Response = super::RehashPartialDatabaseReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RehashPartialDatabaseRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).rehash_partial_database(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RehashPartialDatabaseSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/UnlockDatabase" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnlockDatabaseSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::UnlockDatabaseRequest>
+                    for UnlockDatabaseSvc<T> {
+                        type Response = super::UnlockDatabaseReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UnlockDatabaseRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).unlock_database(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = UnlockDatabaseSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/ExportDatabase" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportDatabaseSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::ServerStreamingService<super::ExportDatabaseRequest>
+                    for ExportDatabaseSvc<T> {
+                        type Response = super::ExportDatabaseReply;
+                        type ResponseStream = T::ExportDatabaseStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportDatabaseRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).export_database(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportDatabaseSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/rcdp.SQLClient/ReadRowIdAtParticipant" => {
                     #[allow(non_camel_case_types)]
                     struct ReadRowIdAtParticipantSvc<T: SqlClient>(pub Arc<T>);
@@ -4342,14 +5311,178 @@ pub mod sql_client_server {
                     };
                     Box::pin(fut)
                 }
-                "/rcdp.SQLClient/GetDatabases" => {
+                "/rcdp.SQLClient/GetPendingWritesAtHost" => {
                     #[allow(non_camel_case_types)]
-                    struct GetDatabasesSvc<T: SqlClient>(pub Arc<T>);
+                    struct GetPendingWritesAtHostSvc<T: SqlClient>(pub Arc<T>);
                     impl<
                         T: SqlClient,
-                    > tonic::server::UnaryService<super::GetDatabasesRequest>
-                    for GetDatabasesSvc<T> {
-                        type Response = super::GetDatabasesReply;
+                    > tonic::server::UnaryService<super::GetPendingWritesAtHostRequest>
+                    for GetPendingWritesAtHostSvc<T> {
+                        type Response = super::GetPendingWritesAtHostReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPendingWritesAtHostRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).get_pending_writes_at_host(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPendingWritesAtHostSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/RejectPendingActionAtParticipant" => {
+                    #[allow(non_camel_case_types)]
+                    struct RejectPendingActionAtParticipantSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::RejectPendingActionRequest>
+                    for RejectPendingActionAtParticipantSvc<T> {
+                        type Response = super::RejectPendingActionReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RejectPendingActionRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).reject_pending_action_at_participant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RejectPendingActionAtParticipantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/GetPendingActionTtlAtParticipant" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPendingActionTtlAtParticipantSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::GetPendingActionTtlRequest>
+                    for GetPendingActionTtlAtParticipantSvc<T> {
+                        type Response = super::GetPendingActionTtlReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPendingActionTtlRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner)
+                                    .get_pending_action_ttl_at_participant(request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPendingActionTtlAtParticipantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/SetPendingActionTtlAtParticipant" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetPendingActionTtlAtParticipantSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::SetPendingActionTtlRequest>
+                    for SetPendingActionTtlAtParticipantSvc<T> {
+                        type Response = super::SetPendingActionTtlReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetPendingActionTtlRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner)
+                                    .set_pending_action_ttl_at_participant(request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SetPendingActionTtlAtParticipantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.SQLClient/GetDatabases" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDatabasesSvc<T: SqlClient>(pub Arc<T>);
+                    impl<
+                        T: SqlClient,
+                    > tonic::server::UnaryService<super::GetDatabasesRequest>
+                    for GetDatabasesSvc<T> {
+                        type Response = super::GetDatabasesReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -4981,6 +6114,13 @@ pub mod data_service_server {
             tonic::Response<super::GetRowFromPartialDatabaseResult>,
             tonic::Status,
         >;
+        async fn get_rows_from_partial_database(
+            &self,
+            request: tonic::Request<super::GetRowsFromPartialDatabaseRequest>,
+        ) -> Result<
+            tonic::Response<super::GetRowsFromPartialDatabaseResult>,
+            tonic::Status,
+        >;
         async fn save_contract(
             &self,
             request: tonic::Request<super::SaveContractRequest>,
@@ -4992,6 +6132,13 @@ pub mod data_service_server {
             tonic::Response<super::ParticipantAcceptsContractResult>,
             tonic::Status,
         >;
+        async fn reject_contract(
+            &self,
+            request: tonic::Request<super::ParticipantRejectsContractRequest>,
+        ) -> Result<
+            tonic::Response<super::ParticipantRejectsContractResult>,
+            tonic::Status,
+        >;
         async fn update_row_data_hash_for_host(
             &self,
             request: tonic::Request<super::UpdateRowDataHashForHostRequest>,
@@ -5006,6 +6153,20 @@ pub mod data_service_server {
             tonic::Response<super::NotifyHostOfRemovedRowResponse>,
             tonic::Status,
         >;
+        async fn notify_host_of_inserted_row(
+            &self,
+            request: tonic::Request<super::NotifyHostOfInsertedRowRequest>,
+        ) -> Result<
+            tonic::Response<super::NotifyHostOfInsertedRowResponse>,
+            tonic::Status,
+        >;
+        async fn notify_host_of_pending_action(
+            &self,
+            request: tonic::Request<super::NotifyHostOfPendingActionRequest>,
+        ) -> Result<
+            tonic::Response<super::NotifyHostOfPendingActionResponse>,
+            tonic::Status,
+        >;
         async fn try_auth(
             &self,
             request: tonic::Request<super::TryAuthRequest>,
@@ -5350,6 +6511,49 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rcdp.DataService/GetRowsFromPartialDatabase" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRowsFromPartialDatabaseSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<
+                        super::GetRowsFromPartialDatabaseRequest,
+                    > for GetRowsFromPartialDatabaseSvc<T> {
+                        type Response = super::GetRowsFromPartialDatabaseResult;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetRowsFromPartialDatabaseRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).get_rows_from_partial_database(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetRowsFromPartialDatabaseSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/rcdp.DataService/SaveContract" => {
                     #[allow(non_camel_case_types)]
                     struct SaveContractSvc<T: DataService>(pub Arc<T>);
@@ -5433,6 +6637,49 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rcdp.DataService/RejectContract" => {
+                    #[allow(non_camel_case_types)]
+                    struct RejectContractSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<
+                        super::ParticipantRejectsContractRequest,
+                    > for RejectContractSvc<T> {
+                        type Response = super::ParticipantRejectsContractResult;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::ParticipantRejectsContractRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).reject_contract(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = RejectContractSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/rcdp.DataService/UpdateRowDataHashForHost" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateRowDataHashForHostSvc<T: DataService>(pub Arc<T>);
@@ -5515,6 +6762,91 @@ pub mod data_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/rcdp.DataService/NotifyHostOfInsertedRow" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotifyHostOfInsertedRowSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<super::NotifyHostOfInsertedRowRequest>
+                    for NotifyHostOfInsertedRowSvc<T> {
+                        type Response = super::NotifyHostOfInsertedRowResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::NotifyHostOfInsertedRowRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).notify_host_of_inserted_row(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotifyHostOfInsertedRowSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.DataService/NotifyHostOfPendingAction" => {
+                    #[allow(non_camel_case_types)]
+                    struct NotifyHostOfPendingActionSvc<T: DataService>(pub Arc<T>);
+                    impl<
+                        T: DataService,
+                    > tonic::server::UnaryService<
+                        super::NotifyHostOfPendingActionRequest,
+                    > for NotifyHostOfPendingActionSvc<T> {
+                        type Response = super::NotifyHostOfPendingActionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::NotifyHostOfPendingActionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).notify_host_of_pending_action(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = NotifyHostOfPendingActionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/rcdp.DataService/TryAuth" => {
                     #[allow(non_camel_case_types)]
                     struct TryAuthSvc<T: DataService>(pub Arc<T>);
@@ -5592,3 +6924,247 @@ pub mod data_service_server {
         const NAME: &'static str = "rcdp.DataService";
     }
 }
+/// Generated server implementations.
+pub mod proxy_admin_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ProxyAdminServer.
+    #[async_trait]
+    pub trait ProxyAdmin: Send + Sync + 'static {
+        async fn get_tenant_status(
+            &self,
+            request: tonic::Request<super::TenantRequest>,
+        ) -> Result<tonic::Response<super::TenantStatusReply>, tonic::Status>;
+        async fn change_tenant_status(
+            &self,
+            request: tonic::Request<super::ChangeTenantStatusRequest>,
+        ) -> Result<tonic::Response<super::TenantStatusReply>, tonic::Status>;
+        /// Server streaming response type for the ExportTenant method.
+        type ExportTenantStream: futures_core::Stream<
+                Item = Result<super::ExportTenantReply, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn export_tenant(
+            &self,
+            request: tonic::Request<super::TenantRequest>,
+        ) -> Result<tonic::Response<Self::ExportTenantStream>, tonic::Status>;
+    }
+    /// a service for the operators of a rcd-proxy to manage the accounts it hosts
+    #[derive(Debug)]
+    pub struct ProxyAdminServer<T: ProxyAdmin> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ProxyAdmin> ProxyAdminServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ProxyAdminServer<T>
+    where
+        T: ProxyAdmin,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/rcdp.ProxyAdmin/GetTenantStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTenantStatusSvc<T: ProxyAdmin>(pub Arc<T>);
+                    impl<T: ProxyAdmin> tonic::server::UnaryService<super::TenantRequest>
+                    for GetTenantStatusSvc<T> {
+                        type Response = super::TenantStatusReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TenantRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).get_tenant_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTenantStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.ProxyAdmin/ChangeTenantStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct ChangeTenantStatusSvc<T: ProxyAdmin>(pub Arc<T>);
+                    impl<
+                        T: ProxyAdmin,
+                    > tonic::server::UnaryService<super::ChangeTenantStatusRequest>
+                    for ChangeTenantStatusSvc<T> {
+                        type Response = super::TenantStatusReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ChangeTenantStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).change_tenant_status(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ChangeTenantStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/rcdp.ProxyAdmin/ExportTenant" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportTenantSvc<T: ProxyAdmin>(pub Arc<T>);
+                    impl<
+                        T: ProxyAdmin,
+                    > tonic::server::ServerStreamingService<super::TenantRequest>
+                    for ExportTenantSvc<T> {
+                        type Response = super::ExportTenantReply;
+                        type ResponseStream = T::ExportTenantStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TenantRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).export_tenant(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportTenantSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: ProxyAdmin> Clone for ProxyAdminServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+            }
+        }
+    }
+    impl<T: ProxyAdmin> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: ProxyAdmin> tonic::server::NamedService for ProxyAdminServer<T> {
+        const NAME: &'static str = "rcdp.ProxyAdmin";
+    }
+}
@@ -0,0 +1,472 @@
+use rcd_client::client_type::RcdClientType;
+use rcd_enum::database_type::DatabaseType;
+use std::env;
+
+pub const USAGE: &str = "usage: rcdt [options] <command> [args]
+
+options:
+    --profile <name>        connect with a profile from ~/.rcd/profiles.toml (or RCD_PROFILES)
+    --grpc <addr:port>      talk to rcd over gRPC (default: http://127.0.0.1:50051)
+    --http <addr:port>      talk to rcd over HTTP instead of gRPC
+    -u, --user <name>       the user name to authenticate with (or RCDT_USER)
+    -p, --pw <pw>           the password to authenticate with (or RCDT_PW)
+    --timeout <seconds>     the timeout for each request (default: 60)
+    --json                  print replies as JSON instead of tables
+
+commands:
+    sql [db] [--participant] [--type <sqlite|mysql|postgres>]
+                            start an interactive SQL session (the default command)
+    login                   authenticate and print the issued token
+    databases               list databases
+    host-info               show this instance's host information
+    versions                show the versions of the instance
+    logs [count]            show the last log entries (default: 25)
+    hosts                   list cooperating hosts
+    contracts pending       list contracts waiting for a decision
+    contracts active <db>   show the active contract for a database
+    contracts accept <host> accept the pending contract from a host alias
+    contracts reject <host> reject the pending contract from a host alias
+    participants <db>       list the participants of a database
+    behaviors get <db> <table>
+    behaviors set <db> <table> <deletes-to-host|updates-to-host|deletes-from-host|updates-from-host> <value>
+    pending list <db> <table> <action>
+    pending accept <db> <table> <row_id>
+    pending reject <db> <table> <row_id>
+    help                    show this message";
+
+const DEFAULT_GRPC_ADDR: &str = "http://127.0.0.1:50051";
+const DEFAULT_TIMEOUT_IN_SECONDS: u32 = 60;
+const DEFAULT_LOG_COUNT: u32 = 25;
+
+/// where SQL statements are sent: to databases we host or to partial databases we participate in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Host,
+    Participant,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum BehaviorKind {
+    DeletesToHost,
+    UpdatesToHost,
+    DeletesFromHost,
+    UpdatesFromHost,
+}
+
+#[derive(Debug, Clone)]
+pub enum ContractCommand {
+    Pending,
+    Active { db_name: String },
+    Accept { host_alias: String },
+    Reject { host_alias: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum BehaviorCommand {
+    Get {
+        db_name: String,
+        table_name: String,
+    },
+    Set {
+        db_name: String,
+        table_name: String,
+        kind: BehaviorKind,
+        value: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum PendingCommand {
+    List {
+        db_name: String,
+        table_name: String,
+        action: String,
+    },
+    Accept {
+        db_name: String,
+        table_name: String,
+        row_id: u32,
+    },
+    Reject {
+        db_name: String,
+        table_name: String,
+        row_id: u32,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum Command {
+    Sql {
+        db_name: Option<String>,
+        target: Target,
+        db_type: u32,
+    },
+    Login,
+    Databases,
+    HostInfo,
+    Versions,
+    Logs {
+        count: u32,
+    },
+    Hosts,
+    Contracts(ContractCommand),
+    Participants {
+        db_name: String,
+    },
+    Behaviors(BehaviorCommand),
+    Pending(PendingCommand),
+    Help,
+}
+
+/// the parsed command line for `rcdt`
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub client_type: RcdClientType,
+    pub grpc_addr_port: String,
+    pub http_addr: String,
+    pub http_port: u32,
+    pub user_name: String,
+    pub pw: String,
+    pub timeout_in_seconds: u32,
+    pub profile: Option<String>,
+    pub format: OutputFormat,
+    pub command: Command,
+}
+
+/// parses the arguments passed to `rcdt`, where `args[0]` is the name of the binary
+pub fn parse(args: &[String]) -> Result<Options, String> {
+    let mut client_type = RcdClientType::Grpc;
+    let mut grpc_addr_port = DEFAULT_GRPC_ADDR.to_string();
+    let mut http_addr = String::new();
+    let mut http_port: u32 = 0;
+    let mut user_name = env::var("RCDT_USER").unwrap_or_default();
+    let mut pw = env::var("RCDT_PW").unwrap_or_default();
+    let mut timeout_in_seconds = DEFAULT_TIMEOUT_IN_SECONDS;
+    let mut profile = None;
+    let mut format = OutputFormat::Table;
+    let mut rest: Vec<String> = Vec::new();
+
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--grpc" => {
+                let addr = next_value(&mut iter, arg)?;
+                grpc_addr_port = if addr.starts_with("http") {
+                    addr
+                } else {
+                    format!("http://{addr}")
+                };
+                client_type = RcdClientType::Grpc;
+            }
+            "--http" => {
+                let addr = next_value(&mut iter, arg)?;
+                let (addr, port) = split_addr_port(&addr)?;
+                http_addr = addr;
+                http_port = port;
+                client_type = RcdClientType::Http;
+            }
+            "-u" | "--user" => user_name = next_value(&mut iter, arg)?,
+            "-p" | "--pw" => pw = next_value(&mut iter, arg)?,
+            "--timeout" => timeout_in_seconds = parse_u32(&next_value(&mut iter, arg)?)?,
+            "--profile" => profile = Some(next_value(&mut iter, arg)?),
+            "--json" => format = OutputFormat::Json,
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    let command = parse_command(&rest)?;
+
+    if user_name.is_empty() && profile.is_none() && !matches!(command, Command::Help) {
+        return Err("a user name is required, pass --user, --profile or set RCDT_USER".to_string());
+    }
+
+    Ok(Options {
+        client_type,
+        grpc_addr_port,
+        http_addr,
+        http_port,
+        user_name,
+        pw,
+        timeout_in_seconds,
+        profile,
+        format,
+        command,
+    })
+}
+
+fn parse_command(args: &[String]) -> Result<Command, String> {
+    let words: Vec<&str> = args.iter().map(|a| a.as_str()).collect();
+
+    match words.as_slice() {
+        [] => Ok(Command::Sql {
+            db_name: None,
+            target: Target::Host,
+            db_type: DatabaseType::to_u32(DatabaseType::Sqlite),
+        }),
+        ["sql", sql_args @ ..] => parse_sql_command(sql_args),
+        ["login"] => Ok(Command::Login),
+        ["databases"] => Ok(Command::Databases),
+        ["host-info"] => Ok(Command::HostInfo),
+        ["versions"] => Ok(Command::Versions),
+        ["logs"] => Ok(Command::Logs {
+            count: DEFAULT_LOG_COUNT,
+        }),
+        ["logs", count] => Ok(Command::Logs {
+            count: parse_u32(count)?,
+        }),
+        ["hosts"] => Ok(Command::Hosts),
+        ["contracts", "pending"] => Ok(Command::Contracts(ContractCommand::Pending)),
+        ["contracts", "active", db_name] => Ok(Command::Contracts(ContractCommand::Active {
+            db_name: db_name.to_string(),
+        })),
+        ["contracts", "accept", host_alias] => Ok(Command::Contracts(ContractCommand::Accept {
+            host_alias: host_alias.to_string(),
+        })),
+        ["contracts", "reject", host_alias] => Ok(Command::Contracts(ContractCommand::Reject {
+            host_alias: host_alias.to_string(),
+        })),
+        ["participants", db_name] => Ok(Command::Participants {
+            db_name: db_name.to_string(),
+        }),
+        ["behaviors", "get", db_name, table_name] => Ok(Command::Behaviors(BehaviorCommand::Get {
+            db_name: db_name.to_string(),
+            table_name: table_name.to_string(),
+        })),
+        ["behaviors", "set", db_name, table_name, kind, value] => {
+            Ok(Command::Behaviors(BehaviorCommand::Set {
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+                kind: parse_behavior_kind(kind)?,
+                value: value.to_string(),
+            }))
+        }
+        ["pending", "list", db_name, table_name, action] => {
+            Ok(Command::Pending(PendingCommand::List {
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+                action: action.to_string(),
+            }))
+        }
+        ["pending", "accept", db_name, table_name, row_id] => {
+            Ok(Command::Pending(PendingCommand::Accept {
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+                row_id: parse_u32(row_id)?,
+            }))
+        }
+        ["pending", "reject", db_name, table_name, row_id] => {
+            Ok(Command::Pending(PendingCommand::Reject {
+                db_name: db_name.to_string(),
+                table_name: table_name.to_string(),
+                row_id: parse_u32(row_id)?,
+            }))
+        }
+        ["help"] | ["--help"] | ["-h"] => Ok(Command::Help),
+        _ => Err(format!("unrecognized command: {}", words.join(" "))),
+    }
+}
+
+fn parse_sql_command(args: &[&str]) -> Result<Command, String> {
+    let mut db_name = None;
+    let mut target = Target::Host;
+    let mut db_type = DatabaseType::Sqlite;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "--participant" => target = Target::Participant,
+            "--host" => target = Target::Host,
+            "--type" => {
+                let value = iter.next().ok_or("--type requires a value")?;
+                db_type = parse_database_type(value)?;
+            }
+            _ if db_name.is_none() => db_name = Some(arg.to_string()),
+            _ => return Err(format!("unexpected argument to sql: {arg}")),
+        }
+    }
+
+    Ok(Command::Sql {
+        db_name,
+        target,
+        db_type: DatabaseType::to_u32(db_type),
+    })
+}
+
+pub fn parse_database_type(value: &str) -> Result<DatabaseType, String> {
+    match value.to_lowercase().as_str() {
+        "sqlite" => Ok(DatabaseType::Sqlite),
+        "mysql" => Ok(DatabaseType::Mysql),
+        "postgres" => Ok(DatabaseType::Postgres),
+        _ => Err(format!("unknown database type: {value}")),
+    }
+}
+
+fn parse_behavior_kind(value: &str) -> Result<BehaviorKind, String> {
+    match value {
+        "deletes-to-host" => Ok(BehaviorKind::DeletesToHost),
+        "updates-to-host" => Ok(BehaviorKind::UpdatesToHost),
+        "deletes-from-host" => Ok(BehaviorKind::DeletesFromHost),
+        "updates-from-host" => Ok(BehaviorKind::UpdatesFromHost),
+        _ => Err(format!("unknown behavior: {value}")),
+    }
+}
+
+fn parse_u32(value: &str) -> Result<u32, String> {
+    value
+        .parse::<u32>()
+        .map_err(|_| format!("expected a number, got: {value}"))
+}
+
+fn next_value<'a>(
+    iter: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<String, String> {
+    iter.next()
+        .cloned()
+        .ok_or_else(|| format!("{flag} requires a value"))
+}
+
+fn split_addr_port(value: &str) -> Result<(String, u32), String> {
+    let value = value
+        .trim_start_matches("http://")
+        .trim_start_matches("https://");
+
+    match value.rsplit_once(':') {
+        Some((addr, port)) => Ok((addr.to_string(), parse_u32(port)?)),
+        None => Err(format!("expected <addr:port>, got: {value}")),
+    }
+}
+
+#[cfg(test)]
+fn parse_line(line: &str) -> Result<Options, String> {
+    let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+    parse(&args)
+}
+
+#[test]
+fn test_parse_defaults_to_a_sql_session_over_grpc() {
+    let options = parse_line("rcdt -u tester -p 1234").unwrap();
+
+    assert!(matches!(options.client_type, RcdClientType::Grpc));
+    assert_eq!(options.grpc_addr_port, DEFAULT_GRPC_ADDR);
+    assert_eq!(options.user_name, "tester");
+    assert_eq!(options.pw, "1234");
+    assert_eq!(options.timeout_in_seconds, DEFAULT_TIMEOUT_IN_SECONDS);
+    assert_eq!(options.format, OutputFormat::Table);
+    assert!(matches!(
+        options.command,
+        Command::Sql {
+            db_name: None,
+            target: Target::Host,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_parse_connection_options() {
+    let options =
+        parse_line("rcdt --grpc 10.0.0.1:50051 --user tester --json --timeout 5 databases")
+            .unwrap();
+    assert_eq!(options.grpc_addr_port, "http://10.0.0.1:50051");
+    assert_eq!(options.format, OutputFormat::Json);
+    assert_eq!(options.timeout_in_seconds, 5);
+    assert!(matches!(options.command, Command::Databases));
+
+    let options = parse_line("rcdt --http http://localhost:50055 -u tester hosts").unwrap();
+    assert!(matches!(options.client_type, RcdClientType::Http));
+    assert_eq!(options.http_addr, "localhost");
+    assert_eq!(options.http_port, 50055);
+    assert!(matches!(options.command, Command::Hosts));
+
+    assert!(parse_line("rcdt --http localhost -u tester hosts").is_err());
+    assert!(parse_line("rcdt -u tester --timeout").is_err());
+    assert!(parse_line("rcdt -u tester --timeout soon").is_err());
+}
+
+#[test]
+fn test_parse_sql_command() {
+    let options =
+        parse_line("rcdt -u tester sql --participant --type postgres part_example.dbpart").unwrap();
+
+    match options.command {
+        Command::Sql {
+            db_name,
+            target,
+            db_type,
+        } => {
+            assert_eq!(db_name.as_deref(), Some("part_example.dbpart"));
+            assert_eq!(target, Target::Participant);
+            assert_eq!(db_type, DatabaseType::to_u32(DatabaseType::Postgres));
+        }
+        command => panic!("expected a sql command, got {command:?}"),
+    }
+
+    assert!(parse_line("rcdt -u tester sql --type oracle").is_err());
+    assert!(parse_line("rcdt -u tester sql first.db second.db").is_err());
+}
+
+#[test]
+fn test_parse_admin_commands() {
+    let options = parse_line("rcdt -u tester logs 10").unwrap();
+    assert!(matches!(options.command, Command::Logs { count: 10 }));
+
+    let options = parse_line("rcdt -u tester logs").unwrap();
+    assert!(matches!(
+        options.command,
+        Command::Logs {
+            count: DEFAULT_LOG_COUNT
+        }
+    ));
+
+    let options = parse_line("rcdt -u tester contracts accept example_host").unwrap();
+    match options.command {
+        Command::Contracts(ContractCommand::Accept { host_alias }) => {
+            assert_eq!(host_alias, "example_host")
+        }
+        command => panic!("expected contracts accept, got {command:?}"),
+    }
+
+    let options =
+        parse_line("rcdt -u tester behaviors set example.db employee deletes-to-host 2").unwrap();
+    match options.command {
+        Command::Behaviors(BehaviorCommand::Set {
+            db_name,
+            table_name,
+            kind,
+            value,
+        }) => {
+            assert_eq!(db_name, "example.db");
+            assert_eq!(table_name, "employee");
+            assert_eq!(kind, BehaviorKind::DeletesToHost);
+            assert_eq!(value, "2");
+        }
+        command => panic!("expected behaviors set, got {command:?}"),
+    }
+
+    let options = parse_line("rcdt -u tester pending reject example.db employee 7").unwrap();
+    assert!(matches!(
+        options.command,
+        Command::Pending(PendingCommand::Reject { row_id: 7, .. })
+    ));
+
+    assert!(parse_line("rcdt -u tester behaviors set example.db employee sideways 2").is_err());
+    assert!(parse_line("rcdt -u tester pending accept example.db employee first").is_err());
+    assert!(parse_line("rcdt -u tester contracts").is_err());
+    assert!(parse_line("rcdt -u tester frobnicate").is_err());
+}
+
+#[test]
+fn test_parse_help_needs_no_user() {
+    for line in ["rcdt help", "rcdt --help", "rcdt -h"] {
+        let options = parse_line(line).unwrap();
+        assert!(matches!(options.command, Command::Help));
+    }
+}
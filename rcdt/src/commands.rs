@@ -0,0 +1,366 @@
+use crate::args::{
+    BehaviorCommand, BehaviorKind, Command, ContractCommand, OutputFormat, PendingCommand,
+};
+use crate::output::{self, contract_table, host_table, kv_table, table};
+use rcd_client::RcdClient;
+use rcd_enum::{
+    contract_status::ContractStatus, database_type::DatabaseType,
+    deletes_from_host_behavior::DeletesFromHostBehavior,
+    deletes_to_host_behavior::DeletesToHostBehavior, host_status::HostStatus,
+    updates_from_host_behavior::UpdatesFromHostBehavior,
+    updates_to_host_behavior::UpdatesToHostBehavior,
+};
+use serde_json::json;
+use std::error::Error;
+
+/// runs a single non-interactive command against the instance and prints the result
+pub async fn run(
+    client: &mut RcdClient,
+    command: Command,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Login => {
+            let reply = client.auth_for_token().await?;
+            output::print(format, &reply, |r| {
+                kv_table(&[
+                    ("Authenticated", r.is_successful.to_string()),
+                    ("Token", r.jwt.clone()),
+                    ("Expires UTC", r.expiration_utc.clone()),
+                ])
+            })
+        }
+        Command::Databases => {
+            let reply = client.get_databases().await?;
+            output::print(format, &reply, |r| {
+                let rows = r
+                    .databases
+                    .iter()
+                    .map(|db| {
+                        vec![
+                            db.database_name.clone(),
+                            format!("{:?}", DatabaseType::from_u32(db.database_type)),
+                            db.cooperation_enabled.to_string(),
+                            db.has_participants.to_string(),
+                            db.tables.len().to_string(),
+                        ]
+                    })
+                    .collect();
+
+                table(
+                    &["Database", "Type", "Cooperative", "Participants", "Tables"],
+                    rows,
+                )
+            })
+        }
+        Command::HostInfo => {
+            let reply = client.get_host_info().await?;
+            output::print(format, &reply, |r| match &r.host_info {
+                Some(host) => host_table(host),
+                None => "no host info has been generated".to_string(),
+            })
+        }
+        Command::Versions => {
+            let reply = client.get_versions().await?;
+            output::print(format, &reply, |r| {
+                kv_table(&[
+                    ("rcdx", r.rcdx.clone()),
+                    ("rcd-core", r.rcd_core.clone()),
+                    ("rcdproto", r.rcdproto.clone()),
+                    ("Protocol", r.protocol_version.to_string()),
+                    ("Schema", r.rcd_schema_version.to_string()),
+                ])
+            })
+        }
+        Command::Logs { count } => {
+            let reply = client.get_last_log_entries(count).await?;
+            output::print(format, &reply, |r| {
+                let rows = r
+                    .logs
+                    .iter()
+                    .map(|l| vec![l.dt_utc.clone(), l.level.clone(), l.message.clone()])
+                    .collect();
+
+                table(&["UTC", "Level", "Message"], rows)
+            })
+        }
+        Command::Hosts => {
+            let reply = client.get_cooperative_hosts().await?;
+            output::print(format, &reply, |r| {
+                let rows = r
+                    .hosts
+                    .iter()
+                    .map(|h| {
+                        let (name, id) = match &h.host {
+                            Some(host) => (host.host_name.clone(), host.host_guid.clone()),
+                            None => (String::new(), String::new()),
+                        };
+                        vec![
+                            name,
+                            id,
+                            HostStatus::from_u32(h.status).as_string(),
+                            h.last_communcation_utc.clone(),
+                        ]
+                    })
+                    .collect();
+
+                table(&["Host", "Id", "Status", "Last Communication UTC"], rows)
+            })
+        }
+        Command::Contracts(command) => run_contract_command(client, command, format).await,
+        Command::Participants { db_name } => {
+            let reply = client.get_participants_for_database(&db_name).await?;
+            output::print(format, &reply, |r| {
+                if r.is_error {
+                    let message = r.error.as_ref().map(|e| e.message.clone());
+                    return format!("error: {}", message.unwrap_or_default());
+                }
+
+                let rows = r
+                    .participants
+                    .iter()
+                    .map(|p| {
+                        let status = format!("{:?}", ContractStatus::from_u32(p.contract_status));
+                        match &p.participant {
+                            Some(participant) => vec![
+                                participant.alias.clone(),
+                                participant.participant_guid.clone(),
+                                format!(
+                                    "{}:{}",
+                                    participant.ip4_address, participant.database_port_number
+                                ),
+                                format!("{}:{}", participant.http_addr, participant.http_port),
+                                status,
+                            ],
+                            None => vec![
+                                String::new(),
+                                String::new(),
+                                String::new(),
+                                String::new(),
+                                status,
+                            ],
+                        }
+                    })
+                    .collect();
+
+                table(&["Alias", "Id", "gRPC", "HTTP", "Contract Status"], rows)
+            })
+        }
+        Command::Behaviors(command) => run_behavior_command(client, command, format).await,
+        Command::Pending(command) => run_pending_command(client, command, format).await,
+        Command::Sql { .. } | Command::Help => unreachable!(),
+    }
+}
+
+async fn run_contract_command(
+    client: &mut RcdClient,
+    command: ContractCommand,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        ContractCommand::Pending => {
+            let contracts = client.view_pending_contracts().await?;
+            output::print(format, &contracts, |c| {
+                if c.is_empty() {
+                    return "no pending contracts".to_string();
+                }
+
+                c.iter()
+                    .map(contract_table)
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+        }
+        ContractCommand::Active { db_name } => {
+            let reply = client.get_active_contract(&db_name).await?;
+            output::print(format, &reply, |r| match &r.contract {
+                Some(contract) => contract_table(contract),
+                None => format!("no active contract for {db_name}"),
+            })
+        }
+        ContractCommand::Accept { host_alias } => {
+            let is_accepted = client.accept_pending_contract(&host_alias).await?;
+            print_outcome(format, "accepted", is_accepted)
+        }
+        ContractCommand::Reject { host_alias } => {
+            let is_rejected = client.reject_pending_contract(&host_alias).await?;
+            print_outcome(format, "rejected", is_rejected)
+        }
+    }
+}
+
+async fn run_behavior_command(
+    client: &mut RcdClient,
+    command: BehaviorCommand,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        BehaviorCommand::Get {
+            db_name,
+            table_name,
+        } => {
+            let deletes_to_host = client
+                .get_deletes_to_host_behavior(&db_name, &table_name)
+                .await?
+                .behavior;
+            let updates_to_host = client
+                .get_updates_to_host_behavior(&db_name, &table_name)
+                .await?
+                .behavior;
+            let deletes_from_host = client
+                .get_deletes_from_host_behavior(&db_name, &table_name)
+                .await?
+                .behavior;
+            let updates_from_host = client
+                .get_updates_from_host_behavior(&db_name, &table_name)
+                .await?
+                .behavior;
+
+            let behaviors = [
+                (
+                    "deletes-to-host",
+                    DeletesToHostBehavior::from_u32(deletes_to_host).as_string(),
+                ),
+                (
+                    "updates-to-host",
+                    UpdatesToHostBehavior::from_u32(updates_to_host).as_string(),
+                ),
+                (
+                    "deletes-from-host",
+                    DeletesFromHostBehavior::from_u32(deletes_from_host).as_string(),
+                ),
+                (
+                    "updates-from-host",
+                    UpdatesFromHostBehavior::from_u32(updates_from_host).as_string(),
+                ),
+            ];
+
+            let value: serde_json::Map<String, serde_json::Value> = behaviors
+                .iter()
+                .map(|(k, v)| (k.to_string(), json!(v)))
+                .collect();
+
+            output::print(format, &value, |_| kv_table(&behaviors))
+        }
+        BehaviorCommand::Set {
+            db_name,
+            table_name,
+            kind,
+            value,
+        } => {
+            let is_changed = match kind {
+                BehaviorKind::DeletesToHost => {
+                    let behavior = DeletesToHostBehavior::from_str(&value);
+                    if behavior == DeletesToHostBehavior::Unknown {
+                        return Err(format!("unknown deletes-to-host behavior: {value}").into());
+                    }
+                    client
+                        .change_deletes_to_host_behavior(&db_name, &table_name, behavior)
+                        .await?
+                }
+                BehaviorKind::UpdatesToHost => {
+                    let behavior = UpdatesToHostBehavior::from_str(&value);
+                    if behavior == UpdatesToHostBehavior::Unknown {
+                        return Err(format!("unknown updates-to-host behavior: {value}").into());
+                    }
+                    client
+                        .change_updates_to_host_behavior(&db_name, &table_name, behavior)
+                        .await?
+                }
+                BehaviorKind::DeletesFromHost => {
+                    let behavior = DeletesFromHostBehavior::from_str(&value);
+                    if behavior == DeletesFromHostBehavior::Unknown {
+                        return Err(format!("unknown deletes-from-host behavior: {value}").into());
+                    }
+                    client
+                        .change_deletes_from_host_behavior(&db_name, &table_name, behavior)
+                        .await?
+                }
+                BehaviorKind::UpdatesFromHost => {
+                    let behavior = UpdatesFromHostBehavior::from_str(&value);
+                    if behavior == UpdatesFromHostBehavior::Unknown {
+                        return Err(format!("unknown updates-from-host behavior: {value}").into());
+                    }
+                    client
+                        .change_updates_from_host_behavior(&db_name, &table_name, behavior)
+                        .await?
+                }
+            };
+
+            print_outcome(format, "changed", is_changed)
+        }
+    }
+}
+
+async fn run_pending_command(
+    client: &mut RcdClient,
+    command: PendingCommand,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        PendingCommand::List {
+            db_name,
+            table_name,
+            action,
+        } => {
+            let reply = client
+                .get_pending_actions_at_participant(&db_name, &table_name, &action)
+                .await?;
+            output::print(format, &reply, |r| {
+                let rows = r
+                    .pending_statements
+                    .iter()
+                    .map(|s| {
+                        vec![
+                            s.row_id.to_string(),
+                            s.action.clone(),
+                            s.statement.clone(),
+                            s.requested_ts_utc.clone(),
+                            s.host_id.clone(),
+                        ]
+                    })
+                    .collect();
+
+                table(
+                    &["Row Id", "Action", "Statement", "Requested UTC", "Host"],
+                    rows,
+                )
+            })
+        }
+        PendingCommand::Accept {
+            db_name,
+            table_name,
+            row_id,
+        } => {
+            let reply = client
+                .accept_pending_action_at_participant(&db_name, &table_name, row_id)
+                .await?;
+            output::print(format, &reply, |r| {
+                kv_table(&[("Accepted", r.is_successful.to_string())])
+            })
+        }
+        PendingCommand::Reject {
+            db_name,
+            table_name,
+            row_id,
+        } => {
+            let reply = client
+                .reject_pending_action_at_participant(&db_name, &table_name, row_id)
+                .await?;
+            output::print(format, &reply, |r| {
+                kv_table(&[("Rejected", r.is_successful.to_string())])
+            })
+        }
+    }
+}
+
+fn print_outcome(
+    format: OutputFormat,
+    action: &str,
+    is_successful: bool,
+) -> Result<(), Box<dyn Error>> {
+    let value = json!({ action: is_successful });
+    output::print(format, &value, |_| {
+        kv_table(&[(action, is_successful.to_string())])
+    })
+}
@@ -1,3 +1,76 @@
-fn main() {
-    println!("Hello, world!");
+use args::{Command, Options};
+use rcd_client::{client_type::RcdClientType, error::RcdClientError, RcdClient};
+use std::{env, process};
+
+mod args;
+mod commands;
+mod output;
+mod repl;
+
+#[tokio::main]
+async fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    let options = match args::parse(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            eprintln!("{}", args::USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Command::Help = options.command {
+        println!("{}", args::USAGE);
+        return;
+    }
+
+    let mut client = match get_client(&options).await {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    };
+
+    let result = match options.command.clone() {
+        Command::Sql {
+            db_name,
+            target,
+            db_type,
+        } => repl::run(&mut client, db_name, target, db_type, options.format).await,
+        command => commands::run(&mut client, command, options.format).await,
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+async fn get_client(options: &Options) -> Result<RcdClient, RcdClientError> {
+    if let Some(profile) = &options.profile {
+        return RcdClient::from_profile(profile).await;
+    }
+
+    let client = match options.client_type {
+        RcdClientType::Grpc => {
+            RcdClient::new_grpc_client(
+                options.grpc_addr_port.clone(),
+                options.user_name.clone(),
+                options.pw.clone(),
+                options.timeout_in_seconds,
+            )
+            .await
+        }
+        RcdClientType::Http => RcdClient::new_http_client(
+            options.user_name.clone(),
+            options.pw.clone(),
+            options.timeout_in_seconds,
+            options.http_addr.clone(),
+            options.http_port,
+        ),
+    };
+
+    Ok(client)
 }
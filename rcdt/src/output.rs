@@ -0,0 +1,120 @@
+use crate::args::OutputFormat;
+use indexmap::IndexMap;
+use rcd_markdown::{markdown_kv_table, markdown_table};
+use rcd_messages::formatter::markdown::{contract, db, host};
+use serde::{de::DeserializeOwned, Serialize};
+use std::error::Error;
+
+/// prints a reply as JSON, or hands it to `to_table` to be printed as a markdown table
+pub fn print<T: Serialize>(
+    format: OutputFormat,
+    value: &T,
+    to_table: impl FnOnce(&T) -> String,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+        OutputFormat::Table => println!("{}", to_table(value)),
+    }
+
+    Ok(())
+}
+
+/// the `rcd-messages` formatters work on the `rcd-messages` mirror of each `rcdproto` message,
+/// which shares the same serialized shape
+pub fn to_message<T: Serialize, U: DeserializeOwned>(value: &T) -> Result<U, serde_json::Error> {
+    serde_json::from_value(serde_json::to_value(value)?)
+}
+
+/// printed in place of a table when a reply doesn't have the shape its formatter expects
+fn format_error(e: serde_json::Error) -> String {
+    format!("error: unable to format reply: {e}")
+}
+
+pub fn kv_table(items: &[(&str, String)]) -> String {
+    let mut kv: IndexMap<String, String> = IndexMap::new();
+
+    for (key, value) in items {
+        kv.insert(key.to_string(), value.clone());
+    }
+
+    markdown_kv_table::build_table(kv)
+}
+
+pub fn table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    markdown_table::build_table(headers, &rows)
+}
+
+pub fn resultset_table(result: &rcdproto::rcdp::StatementResultset) -> String {
+    if result.is_error {
+        return format!("error: {}", result.execution_error_message);
+    }
+
+    if result.rows.is_empty() {
+        return "(0 rows)".to_string();
+    }
+
+    let result: rcd_messages::client::StatementResultset = match to_message(result) {
+        Ok(result) => result,
+        Err(e) => return format_error(e),
+    };
+    let mut table = rcd_messages::formatter::rows_to_string_markdown_table(&result.rows);
+    table += &format!("({} rows)", result.rows.len());
+
+    table
+}
+
+pub fn contract_table(value: &rcdproto::rcdp::Contract) -> String {
+    let value: rcd_messages::client::Contract = match to_message(value) {
+        Ok(value) => value,
+        Err(e) => return format_error(e),
+    };
+
+    if value.schema.is_none() || value.host_info.is_none() {
+        return kv_table(&[
+            ("GUID", value.contract_guid),
+            ("Description", value.description),
+            ("Version", value.contract_version),
+        ]);
+    }
+
+    contract::contract_to_markdown_table(&value)
+}
+
+pub fn host_table(value: &rcdproto::rcdp::Host) -> String {
+    match to_message::<_, rcd_messages::client::Host>(value) {
+        Ok(value) => host::host_to_markdown_table(&value),
+        Err(e) => format_error(e),
+    }
+}
+
+pub fn database_tables(value: &rcdproto::rcdp::DatabaseSchema) -> String {
+    match to_message::<_, rcd_messages::client::DatabaseSchema>(value) {
+        Ok(value) => db::database_schema_to_markdown_table(&value),
+        Err(e) => format_error(e),
+    }
+}
+
+#[test]
+fn test_to_message_converts_to_the_rcd_messages_mirror() {
+    let host = rcdproto::rcdp::Host {
+        host_guid: "guid".to_string(),
+        host_name: "host".to_string(),
+        ip4_address: "127.0.0.1".to_string(),
+        ip6_address: String::new(),
+        database_port_number: 50052,
+        token: vec![1, 2, 3],
+        http_addr: "localhost".to_string(),
+        http_port: 50055,
+    };
+
+    let value: rcd_messages::client::Host = to_message(&host).unwrap();
+    assert_eq!(value.host_name, "host");
+    assert_eq!(value.database_port_number, 50052);
+    assert_eq!(value.token, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_to_message_returns_an_error_for_a_mismatched_shape() {
+    let result = to_message::<_, rcd_messages::client::Host>(&"not a host");
+    assert!(result.is_err());
+}
@@ -0,0 +1,212 @@
+use crate::args::{self, Command, OutputFormat, Target};
+use crate::{commands, output};
+use rcd_client::RcdClient;
+use rcd_enum::database_type::DatabaseType;
+use serde_json::json;
+use std::error::Error;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+const REPL_HELP: &str = "statements end with ';' and may span lines. SELECT statements are reads, anything else is a write.
+
+    \\use <db>          switch the current database
+    \\host              send statements to databases we host
+    \\participant       send statements to partial databases we participate in
+    \\type <db type>    set the backing database type (sqlite, mysql, postgres)
+    \\databases         list databases
+    \\tables            list the tables in the current database
+    \\json, \\table      switch the output format
+    \\help              show this message
+    \\q                 quit";
+
+/// the state of an interactive SQL session
+struct Session {
+    db_name: Option<String>,
+    target: Target,
+    db_type: u32,
+    format: OutputFormat,
+}
+
+/// starts an interactive SQL session, reading statements from stdin until EOF or `\q`
+pub async fn run(
+    client: &mut RcdClient,
+    db_name: Option<String>,
+    target: Target,
+    db_type: u32,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let mut session = Session {
+        db_name,
+        target,
+        db_type,
+        format,
+    };
+
+    let stdin = io::stdin();
+    let is_interactive = stdin.is_terminal();
+
+    if is_interactive {
+        println!("rcdt - type \\help for help, \\q to quit");
+    }
+
+    let mut statement = String::new();
+    let mut lines = stdin.lock().lines();
+
+    loop {
+        if is_interactive {
+            print_prompt(&session, statement.is_empty())?;
+        }
+
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+
+        let trimmed = line.trim();
+
+        if statement.is_empty() {
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            if let Some(meta) = trimmed.strip_prefix('\\') {
+                match run_meta_command(client, &mut session, meta).await {
+                    Ok(true) => continue,
+                    Ok(false) => break,
+                    Err(e) => {
+                        eprintln!("error: {e}");
+                        continue;
+                    }
+                }
+            }
+        }
+
+        statement += &line;
+        statement += "\n";
+
+        if trimmed.ends_with(';') {
+            let sql = statement.trim().trim_end_matches(';').trim().to_string();
+            statement.clear();
+
+            if let Err(e) = execute(client, &session, &sql).await {
+                eprintln!("error: {e}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_prompt(session: &Session, is_new_statement: bool) -> io::Result<()> {
+    if is_new_statement {
+        let db_name = session.db_name.as_deref().unwrap_or("(none)");
+        let target = match session.target {
+            Target::Host => "host",
+            Target::Participant => "participant",
+        };
+        print!("{db_name}@{target}> ");
+    } else {
+        print!("...> ");
+    }
+
+    io::stdout().flush()
+}
+
+/// runs a `\` command. returns `false` if the session should end
+async fn run_meta_command(
+    client: &mut RcdClient,
+    session: &mut Session,
+    meta: &str,
+) -> Result<bool, Box<dyn Error>> {
+    let words: Vec<&str> = meta.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["q"] | ["quit"] => return Ok(false),
+        ["use", db_name] => session.db_name = Some(db_name.to_string()),
+        ["host"] => session.target = Target::Host,
+        ["participant"] => session.target = Target::Participant,
+        ["type", db_type] => {
+            session.db_type = DatabaseType::to_u32(args::parse_database_type(db_type)?)
+        }
+        ["json"] => session.format = OutputFormat::Json,
+        ["table"] => session.format = OutputFormat::Table,
+        ["databases"] | ["l"] => {
+            commands::run(client, Command::Databases, session.format).await?;
+        }
+        ["tables"] | ["dt"] => {
+            let db_name = current_db_name(session)?;
+            let reply = client.get_databases().await?;
+            let database = reply
+                .databases
+                .iter()
+                .find(|db| db.database_name == db_name)
+                .ok_or_else(|| format!("database not found: {db_name}"))?;
+
+            output::print(session.format, database, output::database_tables)?;
+        }
+        ["help"] | ["?"] => println!("{REPL_HELP}"),
+        _ => return Err(format!("unknown command: \\{meta}").into()),
+    }
+
+    Ok(true)
+}
+
+async fn execute(
+    client: &mut RcdClient,
+    session: &Session,
+    sql: &str,
+) -> Result<(), Box<dyn Error>> {
+    let db_name = current_db_name(session)?;
+
+    if is_read(sql) {
+        let result = match session.target {
+            Target::Host => {
+                client
+                    .execute_read_at_host(&db_name, sql, session.db_type)
+                    .await?
+            }
+            Target::Participant => {
+                client
+                    .execute_read_at_participant(&db_name, sql, session.db_type)
+                    .await?
+            }
+        };
+
+        return output::print(session.format, &result, output::resultset_table);
+    }
+
+    let is_written = match session.target {
+        Target::Host => {
+            client
+                .execute_write_at_host(&db_name, sql, session.db_type, "")
+                .await?
+        }
+        Target::Participant => {
+            client
+                .execute_write_at_participant(&db_name, sql, session.db_type, "")
+                .await?
+        }
+    };
+
+    let value = json!({ "written": is_written });
+    output::print(session.format, &value, |_| {
+        if is_written {
+            "OK".to_string()
+        } else {
+            "the write was not applied".to_string()
+        }
+    })
+}
+
+fn current_db_name(session: &Session) -> Result<String, String> {
+    session
+        .db_name
+        .clone()
+        .ok_or_else(|| "no database selected, use \\use <db>".to_string())
+}
+
+fn is_read(sql: &str) -> bool {
+    sql.split_whitespace()
+        .next()
+        .map(|word| word.eq_ignore_ascii_case("select"))
+        .unwrap_or(false)
+}
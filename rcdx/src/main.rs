@@ -1,5 +1,4 @@
 use tracing::{info};
-use rcd_common::defaults;
 
 use rcd_enum::{
     logical_storage_policy::LogicalStoragePolicy, remote_delete_behavior::RemoteDeleteBehavior,
@@ -22,7 +21,7 @@ pub mod rcd_service;
 
 #[tokio::main]
 async fn main() {
-    let version_message = format!("rcdx version {}.", defaults::VERSION);
+    let version_message = format!("rcdx version {}.", rcd_service::VERSION);
     println!("{version_message}");
     // set_default_logging();
     use_sqlite_logging();
@@ -91,6 +90,7 @@ async fn main() {
             db_interface: Some(dbi_core_clone),
             remote_client: Some(remote_client),
             settings: Some(rcd_service_settings),
+            rcdx_version: rcd_service::VERSION.to_string(),
         };
 
         let data = RcdData {
@@ -165,6 +165,7 @@ admin_un = "tester"
 admin_pw = "123456"
 client_grpc_timeout_in_seconds = 60
 data_grpc_timeout_in_seconds = 60
+max_concurrent_participant_reads = 8
     "#,
     );
 
@@ -239,7 +240,7 @@ fn make_test_db() {
         let dbi = service.get_dbi();
 
         dbi.create_database(test_db_name).unwrap();
-        dbi.enable_coooperative_features(test_db_name);
+        dbi.enable_coooperative_features(test_db_name).unwrap();
 
         let drop_table = "DROP TABLE IF EXISTS Example";
 
@@ -1,27 +1,34 @@
-use config::Config;
+use config::{Config, ConfigError};
 use guid_create::GUID;
-use rcd_common::db::DbiConfigSqlite;
-use rcd_common::rcd_settings::RcdSettings;
+use rcd_common::db::{DbiConfigMySql, DbiConfigPostgres, DbiConfigSqlite};
+use rcd_common::defaults;
+use rcd_common::rcd_settings::{JwtConfig, RcdSettings};
+use rcd_core::auth::JwtAuth;
 use rcd_core::comm::{RcdCommunication, RcdRemoteDbClient};
 use rcd_core::dbi::Dbi;
 use rcd_core::rcd::Rcd;
 use rcd_core::rcd_data::RcdData;
 use rcd_core::remote_grpc::RemoteGrpc;
+use rcd_enum::database_type::DatabaseType;
 use rcd_sqlite_log::SqliteLog;
-use stdext::function_name;
 use std::env;
 use std::path::Path;
 use std::sync::mpsc::{Receiver, Sender};
+use stdext::function_name;
+use tracing::{error, info, instrument, trace, warn};
 use triggered::Listener;
-use tracing::{error, info, trace, instrument};
-use rcd_enum::database_type::DatabaseType;
 
 mod grpc;
 mod http;
 
+/// The version of this build of rcdx
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 /// Intalizes the backing cds with the specified admin values. Intended to only be called by an rcd-proxy upon inital sign-up
 fn init_backing_store_at_dir_with_hash(
     db_type: DatabaseType,
+    postgres_config: Option<DbiConfigPostgres>,
+    mysql_config: Option<DbiConfigMySql>,
     backing_db_name: &str,
     root_dir: &str,
     admin_un: &str,
@@ -34,18 +41,29 @@ fn init_backing_store_at_dir_with_hash(
                 rcd_db_name: backing_db_name.to_string(),
             };
 
-            let dbi = Dbi {
-                db_type: DatabaseType::Sqlite,
-                mysql_config: None,
-                postgres_config: None,
-                sqlite_config: Some(config),
-            };
+            let dbi = Dbi::new_with_sqlite(config);
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin_hash(admin_un, admin_hash)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Mysql => {
+            let dbi = Dbi::new_with_mysql(mysql_config.unwrap());
 
-            dbi.configure_rcd_db();
-            dbi.configure_admin_hash(admin_un, admin_hash);
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin_hash(admin_un, admin_hash)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Postgres => {
+            let dbi = Dbi::new_with_postgres(postgres_config.unwrap());
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin_hash(admin_un, admin_hash)
+                .expect("unable to configure the admin login");
         }
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
         DatabaseType::Sqlserver => todo!(),
         _ => panic!("Unknown db type"),
     }
@@ -53,6 +71,8 @@ fn init_backing_store_at_dir_with_hash(
 
 fn configure_backing_store_at_existing_dir(
     db_type: DatabaseType,
+    postgres_config: Option<DbiConfigPostgres>,
+    mysql_config: Option<DbiConfigMySql>,
     backing_db_name: &str,
     root_dir: &str,
 ) {
@@ -63,17 +83,23 @@ fn configure_backing_store_at_existing_dir(
                 rcd_db_name: backing_db_name.to_string(),
             };
 
-            let dbi = Dbi {
-                db_type: DatabaseType::Sqlite,
-                mysql_config: None,
-                postgres_config: None,
-                sqlite_config: Some(config),
-            };
+            let dbi = Dbi::new_with_sqlite(config);
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+        }
+        DatabaseType::Mysql => {
+            let dbi = Dbi::new_with_mysql(mysql_config.unwrap());
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+        }
+        DatabaseType::Postgres => {
+            let dbi = Dbi::new_with_postgres(postgres_config.unwrap());
 
-            dbi.configure_rcd_db();
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
         }
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
         DatabaseType::Sqlserver => todo!(),
         _ => panic!("Unknown db type"),
     }
@@ -84,6 +110,8 @@ fn configure_backing_store_at_existing_dir(
 /// Configures the backing cds based on the type in the apps current working directory
 fn configure_backing_store_at_dir(
     db_type: DatabaseType,
+    postgres_config: Option<DbiConfigPostgres>,
+    mysql_config: Option<DbiConfigMySql>,
     backing_db_name: &str,
     admin_un: &str,
     admin_pw: &str,
@@ -96,18 +124,29 @@ fn configure_backing_store_at_dir(
                 rcd_db_name: backing_db_name.to_string(),
             };
 
-            let dbi = Dbi {
-                db_type: DatabaseType::Sqlite,
-                mysql_config: None,
-                postgres_config: None,
-                sqlite_config: Some(config),
-            };
+            let dbi = Dbi::new_with_sqlite(config);
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Mysql => {
+            let dbi = Dbi::new_with_mysql(mysql_config.unwrap());
 
-            dbi.configure_rcd_db();
-            dbi.configure_admin(admin_un, admin_pw);
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Postgres => {
+            let dbi = Dbi::new_with_postgres(postgres_config.unwrap());
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
         }
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
         DatabaseType::Sqlserver => todo!(),
         _ => panic!("Unknown db type"),
     }
@@ -116,6 +155,8 @@ fn configure_backing_store_at_dir(
 /// Configures the backing cds based on the type in the apps current working directory
 fn configure_backing_store(
     db_type: DatabaseType,
+    postgres_config: Option<DbiConfigPostgres>,
+    mysql_config: Option<DbiConfigMySql>,
     backing_db_name: &str,
     admin_un: &str,
     admin_pw: &str,
@@ -129,18 +170,29 @@ fn configure_backing_store(
                 rcd_db_name: backing_db_name.to_string(),
             };
 
-            let dbi = Dbi {
-                db_type: DatabaseType::Sqlite,
-                mysql_config: None,
-                postgres_config: None,
-                sqlite_config: Some(config),
-            };
+            let dbi = Dbi::new_with_sqlite(config);
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Mysql => {
+            let dbi = Dbi::new_with_mysql(mysql_config.unwrap());
+
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
+        }
+        DatabaseType::Postgres => {
+            let dbi = Dbi::new_with_postgres(postgres_config.unwrap());
 
-            dbi.configure_rcd_db();
-            dbi.configure_admin(admin_un, admin_pw);
+            dbi.configure_rcd_db()
+                .expect("unable to configure the rcd database");
+            dbi.configure_admin(admin_un, admin_pw)
+                .expect("unable to configure the admin login");
         }
-        DatabaseType::Mysql => todo!(),
-        DatabaseType::Postgres => todo!(),
         DatabaseType::Sqlserver => todo!(),
         _ => panic!("Unknown db type"),
     }
@@ -174,6 +226,7 @@ impl RcdService {
             db_interface: Some(self.db_interface.as_ref().unwrap().clone()),
             remote_client: Some(remote_client),
             settings: Some(self.rcd_settings.clone()),
+            rcdx_version: VERSION.to_string(),
         };
 
         let core_data = RcdData {
@@ -210,6 +263,8 @@ impl RcdService {
 
         init_backing_store_at_dir_with_hash(
             self.rcd_settings.database_type,
+            self.rcd_settings.postgres_config.clone(),
+            self.rcd_settings.mysql_config.clone(),
             &self.rcd_settings.backing_database_name,
             root_dir,
             admin_un,
@@ -220,6 +275,8 @@ impl RcdService {
     pub fn start_at_existing_dir(&mut self, root_dir: &str) {
         configure_backing_store_at_existing_dir(
             self.rcd_settings.database_type,
+            self.rcd_settings.postgres_config.clone(),
+            self.rcd_settings.mysql_config.clone(),
             &self.rcd_settings.backing_database_name,
             root_dir,
         );
@@ -232,18 +289,22 @@ impl RcdService {
                     rcd_db_name: self.rcd_settings.backing_database_name.clone(),
                 };
 
-                let config = Dbi {
-                    db_type,
-                    mysql_config: None,
-                    postgres_config: None,
-                    sqlite_config: Some(sqlite_config),
-                };
+                let config = Dbi::new_with_sqlite(sqlite_config);
+
+                self.set_dbi(config);
+            }
+
+            DatabaseType::Mysql => {
+                let config = Dbi::new_with_mysql(self.rcd_settings.mysql_config.clone().unwrap());
 
-                self.db_interface = Some(config);
+                self.set_dbi(config);
             }
+            DatabaseType::Postgres => {
+                let config =
+                    Dbi::new_with_postgres(self.rcd_settings.postgres_config.clone().unwrap());
 
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
+                self.set_dbi(config);
+            }
             DatabaseType::Sqlserver => unimplemented!(),
             _ => panic!("Unknown db type"),
         }
@@ -255,6 +316,8 @@ impl RcdService {
 
         configure_backing_store_at_dir(
             self.rcd_settings.database_type,
+            self.rcd_settings.postgres_config.clone(),
+            self.rcd_settings.mysql_config.clone(),
             &self.rcd_settings.backing_database_name,
             &self.rcd_settings.admin_un,
             &self.rcd_settings.admin_pw,
@@ -270,18 +333,22 @@ impl RcdService {
                     rcd_db_name: self.rcd_settings.backing_database_name.clone(),
                 };
 
-                let config = Dbi {
-                    db_type,
-                    mysql_config: None,
-                    postgres_config: None,
-                    sqlite_config: Some(sqlite_config),
-                };
+                let config = Dbi::new_with_sqlite(sqlite_config);
 
-                self.db_interface = Some(config);
+                self.set_dbi(config);
             }
 
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
+            DatabaseType::Mysql => {
+                let config = Dbi::new_with_mysql(self.rcd_settings.mysql_config.clone().unwrap());
+
+                self.set_dbi(config);
+            }
+            DatabaseType::Postgres => {
+                let config =
+                    Dbi::new_with_postgres(self.rcd_settings.postgres_config.clone().unwrap());
+
+                self.set_dbi(config);
+            }
             DatabaseType::Sqlserver => unimplemented!(),
             _ => panic!("Unknown db type"),
         }
@@ -301,6 +368,8 @@ impl RcdService {
     pub fn start(&mut self) {
         configure_backing_store(
             self.rcd_settings.database_type,
+            self.rcd_settings.postgres_config.clone(),
+            self.rcd_settings.mysql_config.clone(),
             &self.rcd_settings.backing_database_name,
             &self.rcd_settings.admin_un,
             &self.rcd_settings.admin_pw,
@@ -315,18 +384,22 @@ impl RcdService {
                     rcd_db_name: self.rcd_settings.backing_database_name.clone(),
                 };
 
-                let config = Dbi {
-                    db_type,
-                    mysql_config: None,
-                    postgres_config: None,
-                    sqlite_config: Some(sqlite_config),
-                };
+                let config = Dbi::new_with_sqlite(sqlite_config);
 
-                self.db_interface = Some(config);
+                self.set_dbi(config);
             }
 
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
+            DatabaseType::Mysql => {
+                let config = Dbi::new_with_mysql(self.rcd_settings.mysql_config.clone().unwrap());
+
+                self.set_dbi(config);
+            }
+            DatabaseType::Postgres => {
+                let config =
+                    Dbi::new_with_postgres(self.rcd_settings.postgres_config.clone().unwrap());
+
+                self.set_dbi(config);
+            }
             DatabaseType::Sqlserver => unimplemented!(),
             _ => panic!("Unknown db type"),
         }
@@ -344,10 +417,11 @@ impl RcdService {
 
         trace!("{db:?}");
 
-        let host_info = db.rcd_get_host_info();
+        let host_info = db.rcd_get_host_info().expect("unable to read host info");
         if host_info.is_none() {
             let id = GUID::rand();
-            db.rcd_generate_host_info(&id.to_string());
+            db.rcd_generate_host_info(&id.to_string())
+                .expect("unable to generate host info");
         } else {
             let host_id = host_info.unwrap().id;
             panic!("a host id has already been set: {host_id}")
@@ -356,7 +430,10 @@ impl RcdService {
 
     pub fn get_host_id(&self) -> String {
         let db = self.db_interface.as_ref().unwrap();
-        db.rcd_get_host_info().expect("no host info is set").id
+        db.rcd_get_host_info()
+            .expect("unable to read host info")
+            .expect("no host info is set")
+            .id
     }
 
     #[tokio::main]
@@ -416,6 +493,17 @@ impl RcdService {
         return grpc::start_grpc_client_service_at_addr(self, address_port, root_folder);
     }
 
+    /// Keeps `dbi` as the service's database interface, signing tokens with the keys from
+    /// `jwt_config` if there are any
+    fn set_dbi(&mut self, mut dbi: Dbi) {
+        if let Some(jwt_config) = &self.rcd_settings.jwt_config {
+            let jwt = JwtAuth::from_config(jwt_config).expect("invalid setting: 'jwt_config'");
+            dbi = dbi.with_jwt_auth(jwt);
+        }
+
+        self.db_interface = Some(dbi);
+    }
+
     fn init_dbi(&mut self) {
         let db_type = self.rcd_settings.database_type;
 
@@ -426,19 +514,23 @@ impl RcdService {
                     rcd_db_name: self.rcd_settings.backing_database_name.clone(),
                 };
 
-                let config = Dbi {
-                    db_type,
-                    mysql_config: None,
-                    postgres_config: None,
-                    sqlite_config: Some(sqlite_config),
-                };
+                let config = Dbi::new_with_sqlite(sqlite_config);
 
                 trace!("{config:?}");
 
-                self.db_interface = Some(config);
+                self.set_dbi(config);
+            }
+            DatabaseType::Mysql => {
+                let config = Dbi::new_with_mysql(self.rcd_settings.mysql_config.clone().unwrap());
+
+                self.set_dbi(config);
+            }
+            DatabaseType::Postgres => {
+                let config =
+                    Dbi::new_with_postgres(self.rcd_settings.postgres_config.clone().unwrap());
+
+                self.set_dbi(config);
             }
-            DatabaseType::Mysql => unimplemented!(),
-            DatabaseType::Postgres => unimplemented!(),
             DatabaseType::Sqlserver => unimplemented!(),
             _ => panic!("Unknown db type"),
         }
@@ -535,6 +627,11 @@ pub fn get_config_from_settings_file(settings_filename: Option<String>) -> RcdSe
     let client_timeout_in_seconds: u32 = s_client_timeout.parse().unwrap();
     let data_timeout_in_seconds: u32 = s_data_timeout.parse().unwrap();
 
+    let max_concurrent_participant_reads = settings
+        .get_int(&String::from("max_concurrent_participant_reads"))
+        .map(|limit| limit as u32)
+        .unwrap_or(defaults::MAX_CONCURRENT_PARTICIPANT_READS);
+
     let d_client_service_addr_port = settings
         .get_string(&String::from("grpc_data_service_addr_port"))
         .unwrap();
@@ -546,6 +643,30 @@ pub fn get_config_from_settings_file(settings_filename: Option<String>) -> RcdSe
     let http_addr = settings.get_string(&String::from("http_addr")).unwrap();
     let http_port = settings.get_int(&String::from("http_port")).unwrap() as u16;
 
+    let postgres_config = settings
+        .get::<DbiConfigPostgres>(&String::from("postgres_config"))
+        .ok();
+
+    let mysql_config = settings
+        .get::<DbiConfigMySql>(&String::from("mysql_config"))
+        .ok();
+
+    // a jwt_config that is there but can't be used stops startup rather than quietly signing
+    // with a random secret
+    let jwt_config = match settings.get::<JwtConfig>(&String::from("jwt_config")) {
+        Ok(jwt_config) => {
+            if let Err(e) = JwtAuth::from_config(&jwt_config) {
+                panic!("invalid setting: 'jwt_config': {e}");
+            }
+            Some(jwt_config)
+        }
+        Err(ConfigError::NotFound(_)) => {
+            warn!("missing setting: 'jwt_config', tokens will not be valid after a restart");
+            None
+        }
+        Err(e) => panic!("invalid setting: 'jwt_config': {e}"),
+    };
+
     RcdSettings {
         admin_un,
         admin_pw,
@@ -555,8 +676,12 @@ pub fn get_config_from_settings_file(settings_filename: Option<String>) -> RcdSe
         grpc_data_service_addr_port: d_client_service_addr_port,
         client_grpc_timeout_in_seconds: client_timeout_in_seconds,
         data_grpc_timeout_in_seconds: data_timeout_in_seconds,
+        max_concurrent_participant_reads,
         http_addr,
         http_port,
+        postgres_config,
+        mysql_config,
+        jwt_config,
     }
 }
 
@@ -117,6 +117,7 @@ pub fn start_grpc_at_addrs_with_shutdown(
         db_interface: Some(dbi1.unwrap()),
         remote_client: Some(remote_client),
         settings,
+        rcdx_version: super::VERSION.to_string(),
     };
 
     let core_data = RcdData {
@@ -182,6 +183,7 @@ pub async fn start_grpc_client_service_alt(
         db_interface: Some(dbi2),
         remote_client: Some(remote_client),
         settings: Some(service.rcd_settings.clone()),
+        rcdx_version: super::VERSION.to_string(),
     };
 
     let sql_client = SqlClientImpl {
@@ -271,5 +273,6 @@ fn configure_core_for_grpc(
         db_interface: Some(dbi.clone()),
         remote_client: Some(remote_client),
         settings,
+        rcdx_version: super::VERSION.to_string(),
     }
 }
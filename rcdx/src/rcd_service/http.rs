@@ -31,6 +31,7 @@ pub fn start_http_at_addr(service: &mut RcdService, http_addr: String, http_port
             db_interface: Some(dbi_settings),
             remote_client: Some(remote_client),
             settings: Some(settings),
+            rcdx_version: super::VERSION.to_string(),
         };
 
         let data = RcdData {
@@ -49,9 +50,7 @@ pub fn start_http_at_addr_and_dir(
     service: &mut RcdService,
     http_addr: String,
     http_port: u16,
-    root_dir: String,
+    _root_dir: String,
 ) {
-    let dbi_settings = service.get_dbi();
-    dbi_settings.sqlite_config.unwrap().root_folder = root_dir;
     start_http_at_addr(service, http_addr, http_port)
 }